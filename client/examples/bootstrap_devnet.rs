@@ -0,0 +1,67 @@
+//! Stand up a development deployment on a fresh cluster: the master contract,
+//! a treasury with some SOL in it and three oracles signed by the local wallet.
+//! The program must be built with its `devnet` feature.
+//!
+//! cargo run -p siglab-contract-client --features rpc,devnet --example bootstrap_devnet [RPC_URL]
+
+use siglab_contract::instructions::BootstrapOracle;
+use siglab_contract::state::{OracleType, SignatureScheme, TokenType};
+use siglab_contract_client::rpc::SiglabClient;
+use siglab_contract_client::{instructions, pda, InitializeParams};
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+const DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+const RESERVE_RATIO_BPS: u16 = 2_000;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_url = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_RPC_URL.to_string());
+    let keypair_path = format!("{}/.config/solana/id.json", std::env::var("HOME")?);
+    let admin = read_keypair_file(&keypair_path)?;
+    let client = SiglabClient::new_with_url(rpc_url);
+
+    let params = InitializeParams {
+        reserve_ratio_bps: RESERVE_RATIO_BPS,
+        max_oracles: 10,
+        min_consensus_threshold: 3,
+        activation_window: 7 * 86400,
+        param_timelock_secs: 0,
+        global_config: None,
+        single_oracle_mode: false,
+    };
+    let oracles = (1..=3)
+        .map(|n| BootstrapOracle {
+            oracle_id: format!("dev-oracle-{n}"),
+            oracle_type: OracleType::Pyth,
+            data_feed_address: "rainfall-mm".to_string(),
+            signature_scheme: SignatureScheme::Ed25519,
+            signer_address: Vec::new(),
+            authority: admin.pubkey(),
+        })
+        .collect();
+
+    let transactions = instructions::bootstrap_dev_environment(&admin.pubkey(), params, RESERVE_RATIO_BPS, oracles);
+    for transaction in transactions {
+        let signature = client.send(&transaction, &admin, &[]).await?;
+        println!("Sent {} instruction(s): {}", transaction.len(), signature);
+    }
+
+    // Seed the treasury so policies can be underwritten straight away
+    let (treasury, _) = pda::treasury_pda();
+    client
+        .send(
+            &[instructions::deposit_funds(&admin.pubkey(), &treasury, 10 * LAMPORTS_PER_SOL, TokenType::SOL)],
+            &admin,
+            &[],
+        )
+        .await?;
+
+    let master = client.fetch_master_contract().await?;
+    println!("Master contract: {}", pda::master_contract_pda().0);
+    println!("Treasury: {}", master.treasury_account);
+    for oracle in &master.oracle_registry {
+        println!("Oracle: {}", oracle);
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Create a policy and pay its first premium on a local validator.
+//!
+//! cargo run -p siglab-contract-client --features rpc --example create_policy
+
+use siglab_contract::state::{
+    ComparisonOperator, DeductibleMode, InsuranceType, OracleConfig, PremiumFrequency, TokenType,
+    TriggerConditions,
+};
+use siglab_contract_client::rpc::SiglabClient;
+use siglab_contract_client::{instructions, pda, CreatePolicyParams};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+const RPC_URL: &str = "http://127.0.0.1:8899";
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let keypair_path = format!("{}/.config/solana/id.json", std::env::var("HOME")?);
+    let holder = read_keypair_file(&keypair_path)?;
+    let client = SiglabClient::new_with_url(RPC_URL);
+
+    let master = client.fetch_master_contract().await?;
+    let policy_index = master.policies_issued;
+    let (policy_account, _) = pda::policy_pda(&holder.pubkey(), policy_index);
+
+    let premium_amount = LAMPORTS_PER_SOL / 100;
+    let params = CreatePolicyParams {
+        insurance_type: InsuranceType::Weather,
+        coverage_amount: LAMPORTS_PER_SOL,
+        premium_amount,
+        deductible: 0,
+        policy_duration_days: 30,
+        trigger_conditions: TriggerConditions {
+            threshold_value: 50.0,
+            comparison_operator: ComparisonOperator::GreaterThan,
+            data_source: "rainfall".to_string(),
+            grace_period: 0,
+            compound: None,
+        },
+        oracle_config: OracleConfig {
+            oracle_address: Pubkey::default(),
+            data_feed_id: "rainfall-mm".to_string(),
+            required_confirmations: 1,
+            staleness_threshold: 300,
+            max_confidence_ratio_bps: None,
+        },
+        risk_assessment_score: 10,
+        max_payout_per_incident: LAMPORTS_PER_SOL,
+        // The shortest waiting period weather cover allows
+        waiting_period_hours: 24,
+        premium_payment_frequency: PremiumFrequency::Monthly,
+        auto_renewal: false,
+        metadata: "{}".to_string(),
+        metadata_uri: None,
+        discount_code: None,
+        mint_policy_token: false,
+        deductible_mode: DeductibleMode::SubtractAfterScaling,
+        installment_plan: None,
+        quorum_signatures: 0,
+        premium_currency: TokenType::SOL,
+        payout_currency: TokenType::SOL,
+        min_severity_bps: None,
+        payout_vesting: None,
+        // Weather cover names the region insured
+        region_code: Some(*b"US-CA\0\0\0"),
+    };
+
+    let signature = client
+        .send(
+            &[
+                instructions::create_policy(
+                    &holder.pubkey(),
+                    &holder.pubkey(),
+                    &master.treasury_account,
+                    None,
+                    false,
+                    policy_index,
+                    params,
+                ),
+                instructions::pay_premium(
+                    &holder.pubkey(),
+                    &policy_account,
+                    &master.treasury_account,
+                    None,
+                    None,
+                    None,
+                    false,
+                    None,
+                    premium_amount,
+                ),
+            ],
+            &holder,
+            &[],
+        )
+        .await?;
+
+    let policy = client.fetch_policy(&policy_account).await?;
+    println!("Created policy {} at {} ({})", policy.display_id(), policy_account, signature);
+    println!("Status: {:?}", policy.status);
+    Ok(())
+}
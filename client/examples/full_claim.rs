@@ -0,0 +1,191 @@
+//! Run a full claim on a local validator: initialize the contract if needed,
+//! create and activate a policy, trigger a payout, approve it when required
+//! and execute it.
+//!
+//! cargo run -p siglab-contract-client --features rpc --example full_claim
+
+use siglab_contract::state::{
+    ComparisonOperator, DeductibleMode, InsuranceType, InsuranceTypeConfig, OracleConfig, PayoutStatus,
+    PremiumFrequency, TokenType, TriggerConditions,
+};
+use siglab_contract_client::rpc::SiglabClient;
+use siglab_contract_client::{instructions, pda, CreatePolicyParams, InitializeParams};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+const RPC_URL: &str = "http://127.0.0.1:8899";
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let keypair_path = format!("{}/.config/solana/id.json", std::env::var("HOME")?);
+    // The local wallet acts as both admin and policyholder
+    let wallet = read_keypair_file(&keypair_path)?;
+    let client = SiglabClient::new_with_url(RPC_URL);
+
+    // Initialize the master contract and treasury on a fresh validator
+    if client.fetch_master_contract().await.is_err() {
+        let params = InitializeParams {
+            reserve_ratio_bps: 2_000,
+            max_oracles: 10,
+            min_consensus_threshold: 3,
+            activation_window: 7 * 86400,
+            param_timelock_secs: 2 * 86400,
+            global_config: None,
+            single_oracle_mode: false,
+        };
+        client
+            .send(
+                &[
+                    instructions::initialize_master_contract(&wallet.pubkey(), params),
+                    instructions::initialize_treasury(&wallet.pubkey(), 2_000),
+                    instructions::init_type_config(&wallet.pubkey(), InsuranceType::Weather),
+                ],
+                &wallet,
+                &[],
+            )
+            .await?;
+        println!("Initialized master contract, treasury and weather configuration");
+    }
+
+    // Custom policies need no waiting period, so the claim can follow at once
+    let (custom_config, _) = pda::type_config_pda(&InsuranceType::Custom);
+    if client.fetch::<InsuranceTypeConfig>(&custom_config).await.is_err() {
+        client
+            .send(&[instructions::init_type_config(&wallet.pubkey(), InsuranceType::Custom)], &wallet, &[])
+            .await?;
+        println!("Initialized custom configuration");
+    }
+
+    // Create and activate a policy
+    let master = client.fetch_master_contract().await?;
+    let policy_index = master.policies_issued;
+    let (policy_account, _) = pda::policy_pda(&wallet.pubkey(), policy_index);
+
+    let premium_amount = LAMPORTS_PER_SOL / 100;
+    let params = CreatePolicyParams {
+        insurance_type: InsuranceType::Custom,
+        coverage_amount: LAMPORTS_PER_SOL,
+        premium_amount,
+        deductible: 0,
+        policy_duration_days: 30,
+        trigger_conditions: TriggerConditions {
+            threshold_value: 50.0,
+            comparison_operator: ComparisonOperator::GreaterThan,
+            data_source: "rainfall".to_string(),
+            grace_period: 0,
+            compound: None,
+        },
+        oracle_config: OracleConfig {
+            oracle_address: Pubkey::default(),
+            data_feed_id: "rainfall-mm".to_string(),
+            required_confirmations: 1,
+            staleness_threshold: 300,
+            max_confidence_ratio_bps: None,
+        },
+        risk_assessment_score: 10,
+        max_payout_per_incident: LAMPORTS_PER_SOL,
+        waiting_period_hours: 0,
+        premium_payment_frequency: PremiumFrequency::Monthly,
+        auto_renewal: false,
+        metadata: "{}".to_string(),
+        metadata_uri: None,
+        discount_code: None,
+        mint_policy_token: false,
+        deductible_mode: DeductibleMode::SubtractAfterScaling,
+        installment_plan: None,
+        quorum_signatures: 0,
+        premium_currency: TokenType::SOL,
+        payout_currency: TokenType::SOL,
+        min_severity_bps: None,
+        payout_vesting: None,
+        region_code: None,
+    };
+    client
+        .send(
+            &[
+                instructions::create_policy(
+                    &wallet.pubkey(),
+                    &wallet.pubkey(),
+                    &master.treasury_account,
+                    None,
+                    false,
+                    policy_index,
+                    params,
+                ),
+                instructions::pay_premium(
+                    &wallet.pubkey(),
+                    &policy_account,
+                    &master.treasury_account,
+                    None,
+                    None,
+                    None,
+                    false,
+                    None,
+                    premium_amount,
+                ),
+            ],
+            &wallet,
+            &[],
+        )
+        .await?;
+    let policy = client.fetch_policy(&policy_account).await?;
+    println!("Activated policy {} at {}", policy.display_id(), policy_account);
+    let treasury = client.fetch_master_contract().await?.treasury_account;
+
+    // Report a reading above the threshold, dated at the start of coverage
+    client
+        .send(
+            &[instructions::trigger_payout(
+                &wallet.pubkey(),
+                &wallet.pubkey(),
+                &policy_account,
+                &treasury,
+                policy.id,
+                &policy.insurance_type,
+                None,
+                false,
+                &[],
+                75,
+                0,
+                None,
+                None,
+                policy.booked_region(),
+                policy.start_date,
+            )],
+            &wallet,
+            &[],
+        )
+        .await?;
+    let pending_payout = client.fetch_pending_payout(policy.id).await?;
+    println!("Payout of {} lamports is {:?}", pending_payout.amount, pending_payout.status);
+
+    if pending_payout.status == PayoutStatus::PendingApproval {
+        client.send(&[instructions::approve_payout(&wallet.pubkey(), policy.id)], &wallet, &[]).await?;
+        println!("Approved payout");
+    }
+
+    let signature = client
+        .send(
+            &[instructions::execute_payout(
+                &wallet.pubkey(),
+                &wallet.pubkey(),
+                &wallet.pubkey(),
+                &policy_account,
+                &treasury,
+                None,
+                policy.payout_vesting.is_some(),
+                policy.booked_region(),
+                policy.policy_mint.as_ref(),
+                policy.id,
+            )],
+            &wallet,
+            &[],
+        )
+        .await?;
+    println!("Executed payout ({})", signature);
+
+    let policy = client.fetch_policy(&policy_account).await?;
+    println!("Policy status: {:?}", policy.status);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::{AccountDeserialize, Discriminator, Result};
+use siglab_contract::state::{
+    BreachWatch, ConsensusSnapshot, DiscountCode, MasterInsuranceContract, Oracle, PendingPayout, Policy,
+    PolicyTokenMetadata, PolicyTransferProposal, Treasury,
+};
+
+/// Decode raw account data, checking the Anchor discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+pub fn decode_master_contract(data: &[u8]) -> Result<MasterInsuranceContract> {
+    decode(data)
+}
+
+pub fn decode_policy(data: &[u8]) -> Result<Policy> {
+    decode(data)
+}
+
+/// Oracles use a zero-copy layout, so they are read as plain bytes rather
+/// than Borsh-deserialized
+pub fn decode_oracle(data: &[u8]) -> Result<Oracle> {
+    let discriminator = Oracle::DISCRIMINATOR;
+    if data.len() < discriminator.len() || &data[..discriminator.len()] != discriminator {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    if data.len() < Oracle::space() {
+        return Err(ErrorCode::AccountDidNotDeserialize.into());
+    }
+    Ok(bytemuck::pod_read_unaligned(&data[8..Oracle::space()]))
+}
+
+pub fn decode_treasury(data: &[u8]) -> Result<Treasury> {
+    decode(data)
+}
+
+pub fn decode_pending_payout(data: &[u8]) -> Result<PendingPayout> {
+    decode(data)
+}
+
+pub fn decode_breach_watch(data: &[u8]) -> Result<BreachWatch> {
+    decode(data)
+}
+
+pub fn decode_consensus_snapshot(data: &[u8]) -> Result<ConsensusSnapshot> {
+    decode(data)
+}
+
+pub fn decode_policy_token_metadata(data: &[u8]) -> Result<PolicyTokenMetadata> {
+    decode(data)
+}
+
+pub fn decode_policy_transfer_proposal(data: &[u8]) -> Result<PolicyTransferProposal> {
+    decode(data)
+}
+
+pub fn decode_discount_code(data: &[u8]) -> Result<DiscountCode> {
+    decode(data)
+}
@@ -0,0 +1,2054 @@
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use siglab_contract::state::{
+    ConsensusFallbackMode, FeeRebateTier, InsuranceType, OracleData, OracleLookup, OracleType, ParamChange,
+    RetirementPlan, SignatureScheme, TokenType, TriggerConditions, WithdrawalReason,
+};
+use siglab_contract::instructions::{
+    CreateDiscountCodeParams, CreatePolicyParams, CreateProductTemplateParams, CreateSubsidyParams, InitializeParams,
+    MigrateTreasuryParams,
+};
+#[cfg(feature = "devnet")]
+use siglab_contract::instructions::{BootstrapOracle, BootstrapParams};
+use siglab_contract::instructions::oracle::create_oracle_message;
+use siglab_contract::{accounts, instruction};
+use siglab_contract::ID as PROGRAM_ID;
+use solana_ed25519_program::new_ed25519_instruction_with_signature;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::pda::*;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Append the oracle accounts read by compound triggers as remaining accounts
+fn with_feed_oracles(mut ix: Instruction, feed_oracles: &[Pubkey]) -> Instruction {
+    ix.accounts
+        .extend(feed_oracles.iter().map(|oracle| AccountMeta::new_readonly(*oracle, false)));
+    ix
+}
+
+/// Mark whether `key` signs `ix`
+fn set_signer(ix: &mut Instruction, key: &Pubkey, is_signer: bool) {
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == *key) {
+        meta.is_signer = is_signer;
+    }
+}
+
+/// Append the configured SOL/USD price oracle, needed when the global
+/// monetary limits are in a different currency than the policy, and when a
+/// USDC payout pays for the beneficiary's new token account
+pub fn with_price_oracle(ix: Instruction, price_oracle: &Pubkey) -> Instruction {
+    with_feed_oracles(ix, &[*price_oracle])
+}
+
+// === Admin ===
+
+pub fn initialize_master_contract(admin: &Pubkey, params: InitializeParams) -> Instruction {
+    build(
+        accounts::InitializeMasterContract {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeMasterContract { params },
+    )
+}
+
+/// Pause the contract; premiums stay payable unless `block_premiums`
+pub fn pause_contract(admin: &Pubkey, block_premiums: bool) -> Instruction {
+    build(
+        accounts::PauseContract {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::PauseContract { block_premiums },
+    )
+}
+
+/// Pin the program's clock to `timestamp`, or release it with `None`
+#[cfg(feature = "test-clock")]
+pub fn set_test_clock(admin: &Pubkey, timestamp: Option<i64>) -> Instruction {
+    build(
+        accounts::SetTestClock {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetTestClock { timestamp },
+    )
+}
+
+pub fn resume_contract(admin: &Pubkey) -> Instruction {
+    build(
+        accounts::ResumeContract {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::ResumeContract {},
+    )
+}
+
+pub fn update_reserve_ratio(admin: &Pubkey, treasury: &Pubkey, new_reserve_ratio_bps: u16) -> Instruction {
+    build(
+        accounts::UpdateReserveRatio {
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            admin: *admin,
+        },
+        instruction::UpdateReserveRatio { new_reserve_ratio_bps },
+    )
+}
+
+pub fn update_oracle_fee(admin: &Pubkey, new_fee_lamports: u64) -> Instruction {
+    build(
+        accounts::UpdateOracleFee {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::UpdateOracleFee { new_fee_lamports },
+    )
+}
+
+pub fn set_consensus_fallback(
+    admin: &Pubkey,
+    fallback: ConsensusFallbackMode,
+    timeout_secs: i64,
+) -> Instruction {
+    build(
+        accounts::SetConsensusFallback {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetConsensusFallback { fallback, timeout_secs },
+    )
+}
+
+/// Set the reputation below which oracles are left out of consensus
+pub fn set_min_oracle_reputation(admin: &Pubkey, min_reputation: u8) -> Instruction {
+    build(
+        accounts::SetMinOracleReputation {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetMinOracleReputation { min_reputation },
+    )
+}
+
+/// Waive the late fee accrued on `policy`'s overdue premium
+pub fn waive_late_fee(admin: &Pubkey, policy: &Pubkey) -> Instruction {
+    build(
+        accounts::WaiveLateFee {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            admin_audit_log: admin_audit_log_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::WaiveLateFee {},
+    )
+}
+
+/// Configure `insurance_type` with its default parameters, allowing policies of that type
+pub fn init_type_config(admin: &Pubkey, insurance_type: InsuranceType) -> Instruction {
+    build(
+        accounts::InitTypeConfig {
+            type_config: type_config_pda(&insurance_type).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitTypeConfig { insurance_type },
+    )
+}
+
+/// Queue `change` as proposal `proposal_id`, which must be the master
+/// contract's current `param_proposal_count`
+pub fn propose_param_change(admin: &Pubkey, proposal_id: u64, change: ParamChange) -> Instruction {
+    build(
+        accounts::ProposeParamChange {
+            proposal: param_proposal_pda(proposal_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::ProposeParamChange { change },
+    )
+}
+
+/// Apply a proposal once its timelock has elapsed; `change` is the proposed
+/// change, used to pass the account it targets
+pub fn execute_param_change(admin: &Pubkey, proposal_id: u64, change: &ParamChange) -> Instruction {
+    let type_config = match change {
+        ParamChange::TypeConfig { insurance_type, .. } => Some(type_config_pda(insurance_type).0),
+        ParamChange::GlobalConfig(_)
+        | ParamChange::OracleRiskParams(_)
+        | ParamChange::RegionLimits(_)
+        | ParamChange::BeginWinddown => None,
+    };
+
+    build(
+        accounts::ExecuteParamChange {
+            proposal: param_proposal_pda(proposal_id).0,
+            master_contract: master_contract_pda().0,
+            type_config,
+            admin: *admin,
+        },
+        instruction::ExecuteParamChange {},
+    )
+}
+
+pub fn cancel_param_change(admin: &Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::CancelParamChange {
+            proposal: param_proposal_pda(proposal_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::CancelParamChange {},
+    )
+}
+
+pub fn set_consensus_rent_collector(admin: &Pubkey, new_collector: &Pubkey) -> Instruction {
+    build(
+        accounts::SetConsensusRentCollector {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetConsensusRentCollector { new_collector: *new_collector },
+    )
+}
+
+/// Name the key co-signing large policies; `Pubkey::default()` hands the
+/// role back to the authority
+pub fn set_underwriter(admin: &Pubkey, underwriter: &Pubkey) -> Instruction {
+    build(
+        accounts::SetUnderwriter {
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetUnderwriter { underwriter: *underwriter },
+    )
+}
+
+/// Open `region_code` so policies sold in it have their exposure and daily
+/// payouts tracked; `admin` pays the region account's rent
+pub fn open_region(admin: &Pubkey, region_code: [u8; 8]) -> Instruction {
+    build(
+        accounts::OpenRegion {
+            region_exposure: region_exposure_pda(&region_code).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::OpenRegion { region_code },
+    )
+}
+
+/// Withdraw from the treasury to `recipient`. USDC withdrawals pass the
+/// treasury's `usdc_mint` and go to the recipient's associated token account
+pub fn withdraw_treasury(
+    admin: &Pubkey,
+    treasury: &Pubkey,
+    recipient: &Pubkey,
+    usdc_mint: Option<&Pubkey>,
+    amount: u64,
+    token_type: TokenType,
+) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            admin: *admin,
+            recipient: *recipient,
+            recipient_usdc_account: usdc_mint.map(|mint| get_associated_token_address(recipient, mint)),
+        },
+        instruction::WithdrawTreasury { amount, token_type },
+    )
+}
+
+/// Withdraw everything left in the treasury to `recipient` once a wind-down
+/// has no active policies. `usdc` is the treasury's (token account, mint)
+/// to also empty its USDC into the recipient's associated token account.
+pub fn final_settlement(
+    admin: &Pubkey,
+    treasury: &Pubkey,
+    recipient: &Pubkey,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+) -> Instruction {
+    build(
+        accounts::FinalSettlement {
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            admin: *admin,
+            recipient: *recipient,
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            recipient_usdc_account: usdc.map(|(_, mint)| get_associated_token_address(recipient, mint)),
+            token_program: usdc.map(|_| token::ID),
+        },
+        instruction::FinalSettlement {},
+    )
+}
+
+/// Nominate `new_admin` as the authority; it takes over once it accepts
+pub fn transfer_authority(current_admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::TransferAuthority {
+            master_contract: master_contract_pda().0,
+            current_admin: *current_admin,
+            new_admin: *new_admin,
+        },
+        instruction::TransferAuthority {},
+    )
+}
+
+/// Accept the authority as the nominated `new_admin`, naming the governance
+/// program when `new_admin` is its PDA and accepts through it via CPI
+pub fn accept_authority(new_admin: &Pubkey, governance_program: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            master_contract: master_contract_pda().0,
+            new_admin: *new_admin,
+            governance_program: governance_program.copied(),
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+pub fn create_discount_code(admin: &Pubkey, params: CreateDiscountCodeParams) -> Instruction {
+    build(
+        accounts::CreateDiscountCode {
+            discount_code: discount_code_pda(&params.code_hash).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateDiscountCode { params },
+    )
+}
+
+pub fn create_product_template(admin: &Pubkey, params: CreateProductTemplateParams) -> Instruction {
+    build(
+        accounts::CreateProductTemplate {
+            product_template: product_template_pda(params.product_id, params.version).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProductTemplate { params },
+    )
+}
+
+/// Allow or stop issuing policies from a template version
+pub fn set_product_template_active(admin: &Pubkey, product_id: u32, version: u16, active: bool) -> Instruction {
+    build(
+        accounts::SetProductTemplateActive {
+            product_template: product_template_pda(product_id, version).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetProductTemplateActive { active },
+    )
+}
+
+// === Policy ===
+
+/// Create a policy. `policy_index` is the master contract's current
+/// `policies_issued`. Discount and policy token accounts are derived
+/// from `params` when requested. `rent_payer` funds every account created
+/// and may be the holder itself. `treasury` is the current treasury, which
+/// tracks coverage exposure per payout currency. `co_signer` is the
+/// underwriter (or authority) signing coverage at or above the co-signing
+/// threshold, which pays the underwriting bond and books the coverage in
+/// its ledger, opened with `open_underwriter_ledger`. `track_region` books the
+/// coverage in the opened region of `params.region_code`.
+pub fn create_policy(
+    policy_holder: &Pubkey,
+    rent_payer: &Pubkey,
+    treasury: &Pubkey,
+    co_signer: Option<&Pubkey>,
+    track_region: bool,
+    policy_index: u64,
+    params: CreatePolicyParams,
+) -> Instruction {
+    let policy_account = policy_pda(policy_holder, policy_index).0;
+
+    let (discount_code, discount_redemption) = match params.discount_code {
+        Some(ref preimage) => {
+            let code = discount_code_pda(&hash(preimage.as_bytes()).to_bytes()).0;
+            (Some(code), Some(discount_redemption_pda(&code, policy_holder).0))
+        }
+        None => (None, None),
+    };
+
+    let (policy_mint, holder_token_account, policy_token_metadata) = if params.mint_policy_token {
+        let mint = policy_mint_pda(&policy_account).0;
+        (
+            Some(mint),
+            Some(get_associated_token_address(policy_holder, &mint)),
+            Some(policy_token_metadata_pda(&mint).0),
+        )
+    } else {
+        (None, None, None)
+    };
+    let token_program = policy_mint.map(|_| token::ID);
+    let associated_token_program = policy_mint.map(|_| associated_token::ID);
+
+    build(
+        accounts::CreatePolicy {
+            policy_holder: *policy_holder,
+            rent_payer: *rent_payer,
+            master_contract: master_contract_pda().0,
+            policy_account,
+            treasury: *treasury,
+            type_config: type_config_pda(&params.insurance_type).0,
+            co_signer: co_signer.copied(),
+            underwriter_ledger: co_signer.map(|co_signer| underwriter_ledger_pda(co_signer).0),
+            region_exposure: params
+                .region_code
+                .filter(|_| track_region)
+                .map(|region_code| region_exposure_pda(&region_code).0),
+            discount_code,
+            discount_redemption,
+            policy_mint,
+            holder_token_account,
+            policy_token_metadata,
+            token_program,
+            associated_token_program,
+            system_program: system_program::ID,
+        },
+        instruction::CreatePolicy { params },
+    )
+}
+
+/// Create a policy from version `version` of template `product_id`, whose
+/// insurance type is `insurance_type`. As with `create_policy`,
+/// `policy_index` is the master contract's current `policies_issued` and
+/// `co_signer` signs coverage at or above the co-signing threshold.
+/// `track_region` books the coverage in the opened region of `region_code`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_policy_from_template(
+    policy_holder: &Pubkey,
+    rent_payer: &Pubkey,
+    treasury: &Pubkey,
+    co_signer: Option<&Pubkey>,
+    track_region: bool,
+    policy_index: u64,
+    product_id: u32,
+    version: u16,
+    insurance_type: &InsuranceType,
+    threshold: f64,
+    coverage_amount: u64,
+    duration_days: u32,
+    region_code: Option<[u8; 8]>,
+) -> Instruction {
+    build(
+        accounts::CreatePolicyFromTemplate {
+            product_template: product_template_pda(product_id, version).0,
+            policy_holder: *policy_holder,
+            rent_payer: *rent_payer,
+            master_contract: master_contract_pda().0,
+            policy_account: policy_pda(policy_holder, policy_index).0,
+            type_config: type_config_pda(insurance_type).0,
+            treasury: *treasury,
+            co_signer: co_signer.copied(),
+            underwriter_ledger: co_signer.map(|co_signer| underwriter_ledger_pda(co_signer).0),
+            region_exposure: region_code
+                .filter(|_| track_region)
+                .map(|region_code| region_exposure_pda(&region_code).0),
+            system_program: system_program::ID,
+        },
+        instruction::CreatePolicyFromTemplate { threshold, coverage_amount, duration_days, region_code },
+    )
+}
+
+/// Pay a premium. `policy_mint` is the policy's mint for tokenized policies;
+/// `subsidy` co-pays its share of the premium into the given (current) treasury.
+/// Pay a premium into `treasury`. USDC-denominated policies pass `usdc` as
+/// the treasury's (token account, mint) and pay from the payer's associated
+/// token account. Overdue premiums pass `fee_vault`, which receives their
+/// late fee, into the vault's associated token account for USDC. A
+/// co-signed policy paying a late fee passes its `co_signer`, whose ledger
+/// books the fee toward its rebate.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_premium(
+    payer: &Pubkey,
+    policy_account: &Pubkey,
+    treasury: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    subsidy: Option<&Pubkey>,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+    fee_vault: bool,
+    co_signer: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let fee_vault = fee_vault.then(|| fee_vault_pda().0);
+    build(
+        accounts::PayPremium {
+            payer: *payer,
+            policy_account: *policy_account,
+            policy_history: policy_history_pda(policy_account).0,
+            master_contract: master_contract_pda().0,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(payer, mint)),
+            subsidy: subsidy.copied(),
+            treasury: *treasury,
+            payer_token_account: usdc.map(|(_, mint)| get_associated_token_address(payer, mint)),
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            fee_vault,
+            fee_vault_usdc_account: fee_vault
+                .zip(usdc)
+                .map(|(vault, (_, mint))| get_associated_token_address(&vault, mint)),
+            underwriter_ledger: co_signer.map(|co_signer| underwriter_ledger_pda(co_signer).0),
+            token_program: usdc.map(|_| token::ID),
+            system_program: system_program::ID,
+        },
+        instruction::PayPremium { amount },
+    )
+}
+
+pub fn update_policy_metadata(
+    owner: &Pubkey,
+    policy_account: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    metadata: String,
+    metadata_uri: Option<String>,
+) -> Instruction {
+    build(
+        accounts::UpdatePolicyMetadata {
+            policy_account: *policy_account,
+            master_contract: master_contract_pda().0,
+            owner: *owner,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+        },
+        instruction::UpdatePolicyMetadata { metadata, metadata_uri },
+    )
+}
+
+/// Let `keeper` (default for anyone) file payouts for the policy, or stop it
+pub fn set_auto_claim(
+    owner: &Pubkey,
+    policy_account: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    enabled: bool,
+    keeper: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetAutoClaim {
+            policy_account: *policy_account,
+            master_contract: master_contract_pda().0,
+            owner: *owner,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+        },
+        instruction::SetAutoClaim { enabled, keeper },
+    )
+}
+
+/// Change a policy's coverage; `region` is the region its coverage is
+/// booked in, if tracked
+/// Change a policy's coverage, paying the extra premium into `treasury` or
+/// taking the refund out of it. USDC-denominated policies pass `usdc` as the
+/// treasury's (token account, mint), settling with the owner's associated
+/// token account.
+#[allow(clippy::too_many_arguments)]
+pub fn adjust_coverage(
+    owner: &Pubkey,
+    policy_account: &Pubkey,
+    treasury: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    region: Option<&[u8; 8]>,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+    new_coverage_amount: u64,
+    max_additional_premium: u64,
+) -> Instruction {
+    build(
+        accounts::AdjustCoverage {
+            policy_account: *policy_account,
+            policy_history: policy_history_pda(policy_account).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            owner: *owner,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+            region_exposure: region.map(|region_code| region_exposure_pda(region_code).0),
+            owner_usdc_account: usdc.map(|(_, mint)| get_associated_token_address(owner, mint)),
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            token_program: usdc.map(|_| token::ID),
+            system_program: system_program::ID,
+        },
+        instruction::AdjustCoverage { new_coverage_amount, max_additional_premium },
+    )
+}
+
+/// Close a settled policy; `rent_payer` is the policy's recorded rent payer,
+/// `treasury` the current treasury and `region` the region its coverage is
+/// booked in, if tracked
+pub fn close_policy(
+    owner: &Pubkey,
+    rent_payer: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    region: Option<&[u8; 8]>,
+) -> Instruction {
+    build(
+        accounts::ClosePolicy {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            owner: *owner,
+            rent_payer: *rent_payer,
+            policy_mint: policy_mint.copied(),
+            owner_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+            policy_token_metadata: policy_mint.map(|mint| policy_token_metadata_pda(mint).0),
+            token_program: policy_mint.map(|_| token::ID),
+            region_exposure: region.map(|region_code| region_exposure_pda(region_code).0),
+        },
+        instruction::ClosePolicy {},
+    )
+}
+
+/// Expire `policy` once past its end date; anyone may crank it. `region` is
+/// the region its coverage is booked in, if tracked.
+pub fn expire_policy(
+    cranker: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    region: Option<&[u8; 8]>,
+) -> Instruction {
+    build(
+        accounts::ExpirePolicy {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            cranker: *cranker,
+            region_exposure: region.map(|region_code| region_exposure_pda(region_code).0),
+        },
+        instruction::ExpirePolicy {},
+    )
+}
+
+/// Return the underwriting bond of an ended `policy` to `co_signer`, less
+/// any slash; anyone may crank it
+pub fn release_underwriting_bond(
+    cranker: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    co_signer: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ReleaseUnderwritingBond {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            co_signer: *co_signer,
+            cranker: *cranker,
+        },
+        instruction::ReleaseUnderwritingBond {},
+    )
+}
+
+/// Replace the underwriter fee rebate schedule held by the fee vault
+pub fn set_fee_rebate_tiers(admin: &Pubkey, tiers: Vec<FeeRebateTier>) -> Instruction {
+    build(
+        accounts::SetFeeRebateTiers {
+            fee_vault: fee_vault_pda().0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetFeeRebateTiers { tiers },
+    )
+}
+
+/// Open `underwriter`'s ledger at `payer`'s expense, needed before it
+/// co-signs a policy
+pub fn open_underwriter_ledger(payer: &Pubkey, underwriter: &Pubkey) -> Instruction {
+    build(
+        accounts::OpenUnderwriterLedger {
+            underwriter_ledger: underwriter_ledger_pda(underwriter).0,
+            underwriter: *underwriter,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::OpenUnderwriterLedger {},
+    )
+}
+
+/// Claim `underwriter`'s fee rebate for a closed `epoch`. `usdc_mint` also
+/// pays the USDC part from the fee vault's associated token account into the
+/// underwriter's.
+pub fn claim_fee_rebate(underwriter: &Pubkey, epoch: u64, usdc_mint: Option<&Pubkey>) -> Instruction {
+    let fee_vault = fee_vault_pda().0;
+    build(
+        accounts::ClaimFeeRebate {
+            fee_vault,
+            underwriter_ledger: underwriter_ledger_pda(underwriter).0,
+            master_contract: master_contract_pda().0,
+            underwriter: *underwriter,
+            fee_vault_usdc_account: usdc_mint.map(|mint| get_associated_token_address(&fee_vault, mint)),
+            underwriter_usdc_account: usdc_mint.map(|mint| get_associated_token_address(underwriter, mint)),
+            token_program: usdc_mint.map(|_| token::ID),
+        },
+        instruction::ClaimFeeRebate { epoch },
+    )
+}
+
+pub fn propose_policy_transfer(
+    current_owner: &Pubkey,
+    policy: &Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ProposePolicyTransfer {
+            policy: *policy,
+            transfer_proposal: policy_transfer_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            current_owner: *current_owner,
+            system_program: system_program::ID,
+        },
+        instruction::ProposePolicyTransfer { new_owner },
+    )
+}
+
+pub fn accept_policy_transfer(new_owner: &Pubkey, proposer: &Pubkey, policy: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptPolicyTransfer {
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            transfer_proposal: policy_transfer_pda(policy).0,
+            proposer: *proposer,
+            master_contract: master_contract_pda().0,
+            new_owner: *new_owner,
+            system_program: system_program::ID,
+        },
+        instruction::AcceptPolicyTransfer {},
+    )
+}
+
+pub fn cancel_policy_transfer(proposer: &Pubkey, policy: &Pubkey) -> Instruction {
+    build(
+        accounts::CancelPolicyTransfer {
+            policy: *policy,
+            transfer_proposal: policy_transfer_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            proposer: *proposer,
+        },
+        instruction::CancelPolicyTransfer {},
+    )
+}
+
+/// Propose replacing `policy`'s trigger conditions, paying at most
+/// `max_additional_premium` if approval re-prices it upward
+pub fn propose_trigger_amendment(
+    owner: &Pubkey,
+    policy: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+    new_conditions: TriggerConditions,
+    max_additional_premium: u64,
+) -> Instruction {
+    build(
+        accounts::ProposeTriggerAmendment {
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            owner: *owner,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+        },
+        instruction::ProposeTriggerAmendment { new_conditions, max_additional_premium },
+    )
+}
+
+/// Apply `policy`'s pending trigger amendment at `risk_assessment_score`;
+/// `feed_oracles` publish the feeds of an amended compound trigger
+pub fn approve_trigger_amendment(
+    admin: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    insurance_type: &InsuranceType,
+    risk_assessment_score: u8,
+    feed_oracles: &[Pubkey],
+) -> Instruction {
+    let ix = build(
+        accounts::ApproveTriggerAmendment {
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            type_config: type_config_pda(insurance_type).0,
+            admin: *admin,
+        },
+        instruction::ApproveTriggerAmendment { risk_assessment_score },
+    );
+    with_feed_oracles(ix, feed_oracles)
+}
+
+// === Subsidies ===
+
+pub fn create_subsidy(sponsor: &Pubkey, params: CreateSubsidyParams) -> Instruction {
+    build(
+        accounts::CreateSubsidy {
+            subsidy: subsidy_pda(sponsor, params.subsidy_id).0,
+            master_contract: master_contract_pda().0,
+            sponsor: *sponsor,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSubsidy { params },
+    )
+}
+
+pub fn top_up_subsidy(sponsor: &Pubkey, subsidy_id: u64, amount: u64) -> Instruction {
+    build(
+        accounts::TopUpSubsidy {
+            subsidy: subsidy_pda(sponsor, subsidy_id).0,
+            master_contract: master_contract_pda().0,
+            sponsor: *sponsor,
+            system_program: system_program::ID,
+        },
+        instruction::TopUpSubsidy { amount },
+    )
+}
+
+/// Close a subsidy, returning its unspent budget to the sponsor
+pub fn close_subsidy(sponsor: &Pubkey, subsidy_id: u64) -> Instruction {
+    build(
+        accounts::CloseSubsidy {
+            subsidy: subsidy_pda(sponsor, subsidy_id).0,
+            master_contract: master_contract_pda().0,
+            sponsor: *sponsor,
+        },
+        instruction::CloseSubsidy {},
+    )
+}
+
+// === Payout ===
+
+/// Trigger a payout claim. `with_breach_watch` must be set for policies with a
+/// grace period; `feed_oracles` lists the oracle accounts read by compound triggers.
+///
+/// Policies with a quorum must be preceded by ed25519 program instructions in
+/// which oracle authorities sign
+/// [`siglab_contract::instructions::payout::quorum_message`] for `attested_at`;
+/// their oracle accounts go in `feed_oracles`. Other policies ignore `attested_at`.
+///
+/// Feed oracles are passed writable so they can accrue the oracle fee.
+///
+/// When `consensus_round` is set, that round's snapshot supplies the value
+/// and `oracle_value` is ignored. Under the `HaltPayouts` fallback, any
+/// timed-out oracle in `feed_oracles` fails the trigger.
+///
+/// `incident_timestamp` is the claimed time of the insured event; it must lie
+/// within coverage, and the trigger's evidence within the insurance type's
+/// incident tolerance of it.
+///
+/// `rent_payer` funds the pending payout and gets its rent back when the
+/// payout closes; it may be the beneficiary itself. The beneficiary signs;
+/// see [`filed_by_keeper`] for a keeper filing on its behalf.
+///
+/// An `observation_proof` of the policy's oracle holding the trigger value is
+/// kept as the payout's evidence. `region` is the region the coverage is
+/// booked in, if tracked, released should the policy turn out expired.
+#[allow(clippy::too_many_arguments)]
+pub fn trigger_payout(
+    beneficiary: &Pubkey,
+    rent_payer: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_id: u64,
+    insurance_type: &InsuranceType,
+    policy_mint: Option<&Pubkey>,
+    with_breach_watch: bool,
+    feed_oracles: &[Pubkey],
+    oracle_value: u64,
+    attested_at: i64,
+    consensus_round: Option<u64>,
+    observation_proof: Option<&Pubkey>,
+    region: Option<&[u8; 8]>,
+    incident_timestamp: i64,
+) -> Instruction {
+    let mut ix = build(
+        accounts::TriggerPayout {
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            pending_payout: pending_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            type_config: type_config_pda(insurance_type).0,
+            beneficiary: *beneficiary,
+            rent_payer: *rent_payer,
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: policy_mint.map(|_| token::ID),
+            breach_watch: with_breach_watch.then(|| breach_watch_pda(policy).0),
+            consensus_snapshot: consensus_round.map(|round| consensus_snapshot_pda(round).0),
+            observation_proof: observation_proof.copied(),
+            region_exposure: region.map(|region_code| region_exposure_pda(region_code).0),
+            instructions_sysvar: sysvar::instructions::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::TriggerPayout {
+            policy_id,
+            oracle_value,
+            attested_at,
+            consensus_round,
+            incident_timestamp,
+        },
+    );
+    set_signer(&mut ix, beneficiary, true);
+    ix.accounts
+        .extend(feed_oracles.iter().map(|oracle| AccountMeta::new(*oracle, false)));
+    ix
+}
+
+/// Turn a `trigger_payout` instruction into one filed by its rent payer as
+/// the keeper of a holder who enabled automatic claims, which `beneficiary`
+/// does not sign
+pub fn filed_by_keeper(mut ix: Instruction, beneficiary: &Pubkey) -> Instruction {
+    set_signer(&mut ix, beneficiary, false);
+    ix
+}
+
+/// File a claim for `incident_timestamp`, to be assessed by `evaluate_claim`
+pub fn file_claim(
+    beneficiary: &Pubkey,
+    policy: &Pubkey,
+    policy_id: u64,
+    insurance_type: &InsuranceType,
+    policy_mint: Option<&Pubkey>,
+    incident_timestamp: i64,
+) -> Instruction {
+    build(
+        accounts::FileClaim {
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            pending_payout: pending_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            type_config: type_config_pda(insurance_type).0,
+            beneficiary: *beneficiary,
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: policy_mint.map(|_| token::ID),
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::FileClaim {
+            policy_id,
+            incident_timestamp,
+        },
+    )
+}
+
+/// Assess a filed claim; `cranker` must be the beneficiary unless the value is authenticated
+///
+/// `rent_payer` is the claim's recorded rent payer, refunded if the claim is rejected.
+/// An `observation_proof` is kept as evidence, as in [`trigger_payout`].
+#[allow(clippy::too_many_arguments)]
+pub fn evaluate_claim(
+    cranker: &Pubkey,
+    beneficiary: &Pubkey,
+    rent_payer: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_id: u64,
+    insurance_type: &InsuranceType,
+    policy_mint: Option<&Pubkey>,
+    with_breach_watch: bool,
+    feed_oracles: &[Pubkey],
+    oracle_value: u64,
+    attested_at: i64,
+    consensus_round: Option<u64>,
+    observation_proof: Option<&Pubkey>,
+) -> Instruction {
+    let mut ix = build(
+        accounts::EvaluateClaim {
+            pending_payout: pending_payout_pda(policy_id).0,
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            type_config: type_config_pda(insurance_type).0,
+            beneficiary: *beneficiary,
+            rent_payer: *rent_payer,
+            cranker: *cranker,
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: policy_mint.map(|_| token::ID),
+            breach_watch: with_breach_watch.then(|| breach_watch_pda(policy).0),
+            consensus_snapshot: consensus_round.map(|round| consensus_snapshot_pda(round).0),
+            observation_proof: observation_proof.copied(),
+            instructions_sysvar: sysvar::instructions::ID,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::EvaluateClaim {
+            oracle_value,
+            attested_at,
+            consensus_round,
+        },
+    );
+    ix.accounts
+        .extend(feed_oracles.iter().map(|oracle| AccountMeta::new(*oracle, false)));
+    ix
+}
+
+pub fn observe_breach(
+    observer: &Pubkey,
+    policy: &Pubkey,
+    feed_oracles: &[Pubkey],
+    oracle_value: u64,
+) -> Instruction {
+    let ix = build(
+        accounts::ObserveBreach {
+            policy: *policy,
+            breach_watch: breach_watch_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            observer: *observer,
+            system_program: system_program::ID,
+        },
+        instruction::ObserveBreach { oracle_value },
+    );
+    with_feed_oracles(ix, feed_oracles)
+}
+
+pub fn clear_breach_watch(
+    observer: &Pubkey,
+    policy: &Pubkey,
+    feed_oracles: &[Pubkey],
+    oracle_value: u64,
+) -> Instruction {
+    let ix = build(
+        accounts::ClearBreachWatch {
+            policy: *policy,
+            breach_watch: breach_watch_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            observer: *observer,
+        },
+        instruction::ClearBreachWatch { oracle_value },
+    );
+    with_feed_oracles(ix, feed_oracles)
+}
+
+pub fn approve_payout(admin: &Pubkey, policy_id: u64) -> Instruction {
+    build(
+        accounts::ApprovePayout {
+            pending_payout: pending_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::ApprovePayout {},
+    )
+}
+
+/// Refuse a payout awaiting approval, returning the policy to coverage.
+/// Tokenized policies pass their `policy_mint`, whose token is thawed.
+#[allow(clippy::too_many_arguments)]
+pub fn reject_payout(
+    admin: &Pubkey,
+    beneficiary: &Pubkey,
+    rent_payer: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_id: u64,
+    policy_mint: Option<&Pubkey>,
+    reason: String,
+) -> Instruction {
+    build(
+        accounts::RejectPayout {
+            pending_payout: pending_payout_pda(policy_id).0,
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            rent_payer: *rent_payer,
+            admin: *admin,
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: policy_mint.map(|_| token::ID),
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::RejectPayout { reason },
+    )
+}
+
+/// Persist the aged priority of a waiting payout; anyone may crank it
+pub fn recompute_priority(cranker: &Pubkey, policy_id: u64) -> Instruction {
+    build(
+        accounts::RecomputePriority {
+            pending_payout: pending_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            cranker: *cranker,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::RecomputePriority {},
+    )
+}
+
+/// Quote a claim without mutating state. The `PayoutAssessment` is returned
+/// as Borsh-encoded return data when the transaction is simulated.
+pub fn simulate_payout(
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    with_breach_watch: bool,
+    feed_oracles: &[Pubkey],
+    oracle_value: u64,
+) -> Instruction {
+    let ix = build(
+        accounts::SimulatePayout {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            breach_watch: with_breach_watch.then(|| breach_watch_pda(policy).0),
+        },
+        instruction::SimulatePayout { oracle_value },
+    );
+    with_feed_oracles(ix, feed_oracles)
+}
+
+/// Execute a ready payout, funded by the current `treasury`
+///
+/// Anyone may execute; the funds always go to `beneficiary`, and an
+/// `executor` other than the beneficiary earns the crank tip. The payout's
+/// rent goes back to its recorded `rent_payer`. USDC policies pass
+/// `usdc` as the treasury's (token account, mint); the funds go to the
+/// beneficiary's associated token account, created if missing with the
+/// executor's rent reimbursed by the treasury and withheld from the payout,
+/// which then needs `with_price_oracle`. Policies with payout vesting
+/// set `vesting` to open the vesting payout the later tranches stream from.
+/// `region` is the policy's opened region, which counts the payout against
+/// its daily cap. Tokenized policies pass their `policy_mint`, whose token
+/// is thawed when the payout leaves the policy in force.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_payout(
+    executor: &Pubkey,
+    beneficiary: &Pubkey,
+    rent_payer: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+    vesting: bool,
+    region: Option<&[u8; 8]>,
+    policy_mint: Option<&Pubkey>,
+    policy_id: u64,
+) -> Instruction {
+    build(
+        accounts::ExecutePayout {
+            pending_payout: pending_payout_pda(policy_id).0,
+            policy: *policy,
+            policy_history: policy_history_pda(policy).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            beneficiary: *beneficiary,
+            rent_payer: *rent_payer,
+            executor: *executor,
+            vesting_payout: vesting.then(|| vesting_payout_pda(policy_id).0),
+            region_exposure: region.map(|region_code| region_exposure_pda(region_code).0),
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            beneficiary_usdc_account: usdc.map(|(_, mint)| get_associated_token_address(beneficiary, mint)),
+            usdc_mint: usdc.map(|(_, mint)| *mint),
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: (usdc.is_some() || policy_mint.is_some()).then_some(token::ID),
+            associated_token_program: usdc.map(|_| associated_token::ID),
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::ExecutePayout {},
+    )
+}
+
+/// Pay the next due tranche of a vesting payout; anyone may submit it
+///
+/// As with `execute_payout`, the funds go to `beneficiary` and an
+/// `executor` other than the beneficiary earns the crank tip. The last
+/// tranche returns the vesting payout's rent to its `rent_payer`.
+pub fn release_payout_tranche(
+    executor: &Pubkey,
+    beneficiary: &Pubkey,
+    rent_payer: &Pubkey,
+    treasury: &Pubkey,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+    policy_id: u64,
+) -> Instruction {
+    build(
+        accounts::ReleasePayoutTranche {
+            vesting_payout: vesting_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            beneficiary: *beneficiary,
+            rent_payer: *rent_payer,
+            executor: *executor,
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            beneficiary_usdc_account: usdc.map(|(_, mint)| get_associated_token_address(beneficiary, mint)),
+            token_program: usdc.map(|_| token::ID),
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::ReleasePayoutTranche {},
+    )
+}
+
+/// Stop a vesting payout, returning its unreleased tranches to reserves
+pub fn cancel_vesting_payout(
+    admin: &Pubkey,
+    rent_payer: &Pubkey,
+    treasury: &Pubkey,
+    policy_id: u64,
+    reason: String,
+) -> Instruction {
+    build(
+        accounts::CancelVestingPayout {
+            vesting_payout: vesting_payout_pda(policy_id).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            rent_payer: *rent_payer,
+            admin: *admin,
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::CancelVestingPayout { reason },
+    )
+}
+
+/// Settle a payout left unexecuted past its expiry; anyone may submit it
+///
+/// The payout's rent goes back to its recorded `rent_payer`.
+pub fn expire_payout(
+    rent_payer: &Pubkey,
+    beneficiary: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_id: u64,
+    policy_mint: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ExpirePayout {
+            pending_payout: pending_payout_pda(policy_id).0,
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            rent_payer: *rent_payer,
+            policy_mint: policy_mint.copied(),
+            beneficiary_token_account: policy_mint
+                .map(|mint| get_associated_token_address(beneficiary, mint)),
+            token_program: policy_mint.map(|_| token::ID),
+            event_authority: event_authority_pda().0,
+            program: PROGRAM_ID,
+        },
+        instruction::ExpirePayout {},
+    )
+}
+
+// === Views ===
+//
+// View instructions mutate nothing. Simulate them and decode the return data
+// with `crate::views`.
+
+/// Report a policy's status; pass `policy_id` to include its pending payout
+pub fn get_policy_status(policy: &Pubkey, pending_payout_for: Option<u64>) -> Instruction {
+    build(
+        accounts::GetPolicyStatus {
+            policy: *policy,
+            pending_payout: pending_payout_for.map(|policy_id| pending_payout_pda(policy_id).0),
+            master_contract: master_contract_pda().0,
+        },
+        instruction::GetPolicyStatus {},
+    )
+}
+
+/// Report the treasury's reserve ratio, solvency and available liquidity
+pub fn get_treasury_health(treasury: &Pubkey) -> Instruction {
+    build(
+        accounts::GetTreasuryHealth {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+        },
+        instruction::GetTreasuryHealth {},
+    )
+}
+
+/// Report whether `trigger_payout` would accept a claim on the policy now, or
+/// why not; `feed_oracles` must include the oracle publishing the policy's feed
+pub fn check_claim_eligibility(
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    with_breach_watch: bool,
+    feed_oracles: &[Pubkey],
+) -> Instruction {
+    let ix = build(
+        accounts::CheckClaimEligibility {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            breach_watch: with_breach_watch.then(|| breach_watch_pda(policy).0),
+        },
+        instruction::CheckClaimEligibility {},
+    );
+    with_feed_oracles(ix, feed_oracles)
+}
+
+/// Resolve an oracle id to its account, or an account to its id hash
+pub fn resolve_oracle(lookup: OracleLookup) -> Instruction {
+    build(
+        accounts::ResolveOracle { oracle_directory: oracle_directory_pda().0 },
+        instruction::ResolveOracle { lookup },
+    )
+}
+
+// === Oracle ===
+
+/// Register an oracle; `registered` must hold every oracle in the master
+/// contract's registry
+#[allow(clippy::too_many_arguments)]
+pub fn register_oracle(
+    admin: &Pubkey,
+    oracle_authority: &Pubkey,
+    registered: &[Pubkey],
+    oracle_id: &str,
+    oracle_type: OracleType,
+    data_feed_address: String,
+    signature_scheme: SignatureScheme,
+    signer_address: Vec<u8>,
+) -> Instruction {
+    let ix = build(
+        accounts::RegisterOracle {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            oracle_directory: oracle_directory_pda().0,
+            admin: *admin,
+            oracle_authority: *oracle_authority,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterOracle {
+            oracle_id: oracle_id.to_string(),
+            oracle_type,
+            data_feed_address,
+            signature_scheme,
+            signer_address,
+        },
+    );
+    with_feed_oracles(ix, registered)
+}
+
+/// Request registration of an oracle run by `operator`, bonding the
+/// registration stake until the admin approves or denies it
+pub fn request_oracle_registration(
+    operator: &Pubkey,
+    oracle_id: &str,
+    oracle_type: OracleType,
+    data_feed_address: String,
+    signature_scheme: SignatureScheme,
+    signer_address: Vec<u8>,
+) -> Instruction {
+    build(
+        accounts::RequestOracleRegistration {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            operator: *operator,
+            system_program: system_program::ID,
+        },
+        instruction::RequestOracleRegistration {
+            oracle_id: oracle_id.to_string(),
+            oracle_type,
+            data_feed_address,
+            signature_scheme,
+            signer_address,
+        },
+    )
+}
+
+pub fn approve_oracle(admin: &Pubkey, oracle_id: &str) -> Instruction {
+    build(
+        accounts::ApproveOracle {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            oracle_directory: oracle_directory_pda().0,
+            admin: *admin,
+        },
+        instruction::ApproveOracle {},
+    )
+}
+
+/// Deny a pending oracle, refunding rent and stake to `operator`
+pub fn deny_oracle(admin: &Pubkey, operator: &Pubkey, oracle_id: &str, reason: String) -> Instruction {
+    build(
+        accounts::DenyOracle {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            oracle_authority: *operator,
+            admin: *admin,
+        },
+        instruction::DenyOracle { reason },
+    )
+}
+
+/// Unregister an oracle; `plan` decides what the `repoint_policies` crank
+/// does with the policies and templates referencing it
+pub fn unregister_oracle(admin: &Pubkey, oracle_id: &str, plan: RetirementPlan) -> Instruction {
+    let oracle = oracle_pda(oracle_id).0;
+    build(
+        accounts::UnregisterOracle {
+            oracle,
+            master_contract: master_contract_pda().0,
+            oracle_retirement: oracle_retirement_pda(&oracle).0,
+            oracle_directory: oracle_directory_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::UnregisterOracle { plan },
+    )
+}
+
+/// Apply the retirement of `oracle` to the `policies` and product
+/// `templates` that reference it; anyone may submit it
+pub fn repoint_policies(
+    cranker: &Pubkey,
+    oracle: &Pubkey,
+    policies: &[Pubkey],
+    templates: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        accounts::RepointPolicies {
+            oracle_retirement: oracle_retirement_pda(oracle).0,
+            master_contract: master_contract_pda().0,
+            cranker: *cranker,
+        },
+        instruction::RepointPolicies {},
+    );
+    let policies = policies.iter().flat_map(|policy| [*policy, policy_history_pda(policy).0]);
+    ix.accounts
+        .extend(policies.chain(templates.iter().copied()).map(|account| AccountMeta::new(account, false)));
+    ix
+}
+
+/// Name the replacement of an oracle retired with its policies suspended
+pub fn set_oracle_replacement(admin: &Pubkey, oracle: &Pubkey, replacement: Pubkey) -> Instruction {
+    build(
+        accounts::SetOracleReplacement {
+            oracle_retirement: oracle_retirement_pda(oracle).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetOracleReplacement { replacement },
+    )
+}
+
+pub fn close_oracle_retirement(admin: &Pubkey, oracle: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseOracleRetirement {
+            oracle_retirement: oracle_retirement_pda(oracle).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::CloseOracleRetirement {},
+    )
+}
+
+/// Accrue the downtime of `policy`'s `oracle`, if it is unavailable; anyone
+/// may crank it
+pub fn record_oracle_outage(cranker: &Pubkey, policy: &Pubkey, oracle: &Pubkey) -> Instruction {
+    build(
+        accounts::RecordOracleOutage {
+            policy: *policy,
+            oracle: *oracle,
+            master_contract: master_contract_pda().0,
+            cranker: *cranker,
+        },
+        instruction::RecordOracleOutage {},
+    )
+}
+
+/// Refund the premium for the oracle downtime of an expired policy
+pub fn claim_outage_refund(
+    owner: &Pubkey,
+    policy: &Pubkey,
+    treasury: &Pubkey,
+    policy_mint: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ClaimOutageRefund {
+            policy: *policy,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            owner: *owner,
+            policy_token_account: policy_mint.map(|mint| get_associated_token_address(owner, mint)),
+        },
+        instruction::ClaimOutageRefund {},
+    )
+}
+
+/// Prune registry entries whose accounts in `oracles` no longer hold a live oracle
+pub fn sync_oracle_registry(admin: &Pubkey, oracles: &[Pubkey]) -> Instruction {
+    let ix = build(
+        accounts::SyncOracleRegistry {
+            master_contract: master_contract_pda().0,
+            oracle_directory: oracle_directory_pda().0,
+            admin: *admin,
+        },
+        instruction::SyncOracleRegistry {},
+    );
+    with_feed_oracles(ix, oracles)
+}
+
+/// Create the oracle directory; `registered` must hold every oracle in the
+/// master contract's registry
+pub fn initialize_oracle_directory(admin: &Pubkey, registered: &[Pubkey]) -> Instruction {
+    let ix = build(
+        accounts::InitializeOracleDirectory {
+            oracle_directory: oracle_directory_pda().0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeOracleDirectory {},
+    );
+    with_feed_oracles(ix, registered)
+}
+
+/// Copy `oracle`'s reading reported at `observed_at`, or its latest one, into
+/// a proof under `nonce`
+pub fn snapshot_oracle_observation(
+    creator: &Pubkey,
+    oracle: &Pubkey,
+    nonce: u64,
+    observed_at: Option<i64>,
+) -> Instruction {
+    build(
+        accounts::SnapshotOracleObservation {
+            observation_proof: observation_proof_pda(oracle, nonce).0,
+            oracle: *oracle,
+            master_contract: master_contract_pda().0,
+            creator: *creator,
+            system_program: system_program::ID,
+        },
+        instruction::SnapshotOracleObservation { nonce, observed_at },
+    )
+}
+
+/// Close a proof past its retention period, returning its rent to `creator`
+pub fn close_observation_proof(creator: &Pubkey, observation_proof: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseObservationProof {
+            observation_proof: *observation_proof,
+            master_contract: master_contract_pda().0,
+            creator: *creator,
+        },
+        instruction::CloseObservationProof {},
+    )
+}
+
+/// The message an oracle authority signs for `data`, bound to this program and
+/// to the oracle at `oracle_id`
+pub fn oracle_message(oracle_id: &str, data: &OracleData) -> Vec<u8> {
+    create_oracle_message(&oracle_pda(oracle_id).0, oracle_id, data)
+}
+
+/// Sign `data` as `oracle_authority` and return the ed25519 program instruction
+/// attesting it, to be placed right before the update or reveal carrying `data`
+pub fn attest_oracle_data(oracle_authority: &Keypair, oracle_id: &str, data: &mut OracleData) -> Instruction {
+    let message = oracle_message(oracle_id, data);
+    data.signature = oracle_authority.sign_message(&message).into();
+    new_ed25519_instruction_with_signature(&message, &data.signature, &oracle_authority.pubkey().to_bytes())
+}
+
+/// Ed25519 oracles must be preceded by [`attest_oracle_data`]; secp256k1 oracles
+/// by a secp256k1 program instruction signing [`oracle_message`]
+pub fn update_oracle_data(oracle_authority: &Pubkey, oracle_id: &str, data: OracleData) -> Instruction {
+    build(
+        accounts::UpdateOracleData {
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+            master_contract: master_contract_pda().0,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        instruction::UpdateOracleData { data },
+    )
+}
+
+/// Commit to the next update of a commit-reveal oracle
+///
+/// `commitment` is `oracle_commitment(value, salt, nonce)` of the update later
+/// passed to [`reveal_oracle_data`].
+pub fn commit_oracle_data(oracle_authority: &Pubkey, oracle_id: &str, commitment: [u8; 32]) -> Instruction {
+    build(
+        accounts::CommitOracleData {
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+            master_contract: master_contract_pda().0,
+        },
+        instruction::CommitOracleData { commitment },
+    )
+}
+
+/// Reveal the update committed to with `salt`, at least `MIN_REVEAL_DELAY_SLOTS` later
+pub fn reveal_oracle_data(
+    oracle_authority: &Pubkey,
+    oracle_id: &str,
+    data: OracleData,
+    salt: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::UpdateOracleData {
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+            master_contract: master_contract_pda().0,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        instruction::RevealOracleData { data, salt },
+    )
+}
+
+/// Pause `oracle_id` for maintenance for `duration_secs` (at most 24 hours)
+pub fn pause_oracle_self(oracle_authority: &Pubkey, oracle_id: &str, duration_secs: i64) -> Instruction {
+    build(
+        accounts::PauseOracleSelf {
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+            master_contract: master_contract_pda().0,
+        },
+        instruction::PauseOracleSelf { duration_secs },
+    )
+}
+
+/// Resign `oracle_id`, deactivating it until `complete_oracle_resignation`
+pub fn resign_oracle(oracle_authority: &Pubkey, oracle_id: &str) -> Instruction {
+    build(
+        accounts::ResignOracle {
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+            master_contract: master_contract_pda().0,
+        },
+        instruction::ResignOracle {},
+    )
+}
+
+/// Remove resigned `oracle_id` from the registry once its cooldown has
+/// elapsed, refunding its rent and stake
+pub fn complete_oracle_resignation(oracle_authority: &Pubkey, oracle_id: &str) -> Instruction {
+    let oracle = oracle_pda(oracle_id).0;
+    build(
+        accounts::CompleteOracleResignation {
+            oracle,
+            master_contract: master_contract_pda().0,
+            oracle_retirement: oracle_retirement_pda(&oracle).0,
+            oracle_directory: oracle_directory_pda().0,
+            oracle_authority: *oracle_authority,
+            system_program: system_program::ID,
+        },
+        instruction::CompleteOracleResignation {},
+    )
+}
+
+/// Claim the fees accrued by `oracle_id`, capped at the treasury's free liquidity
+pub fn claim_oracle_fees(oracle_authority: &Pubkey, treasury: &Pubkey, oracle_id: &str) -> Instruction {
+    build(
+        accounts::ClaimOracleFees {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            oracle_authority: *oracle_authority,
+        },
+        instruction::ClaimOracleFees {},
+    )
+}
+
+/// Compute consensus over `oracles` as round `round`, which must be the
+/// master contract's current `consensus_round`
+pub fn compute_consensus(payer: &Pubkey, round: u64, oracles: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        accounts::ComputeConsensus {
+            master_contract: master_contract_pda().0,
+            consensus_snapshot: consensus_snapshot_pda(round).0,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::ComputeConsensus {},
+    );
+    ix.accounts
+        .extend(oracles.iter().map(|oracle| AccountMeta::new_readonly(*oracle, false)));
+    ix
+}
+
+pub fn dispute_consensus(
+    oracle_authority: &Pubkey,
+    oracle_id: &str,
+    round: u64,
+    counter_value: u64,
+) -> Instruction {
+    build(
+        accounts::DisputeConsensus {
+            consensus_snapshot: consensus_snapshot_pda(round).0,
+            master_contract: master_contract_pda().0,
+            oracle: oracle_pda(oracle_id).0,
+            oracle_authority: *oracle_authority,
+        },
+        instruction::DisputeConsensus { round, counter_value },
+    )
+}
+
+/// Resolve a disputed round. `slashed_oracle` must be the oracle returned by
+/// [`siglab_contract::state::ConsensusSnapshot::dispute_loser`] for `resolved_value`.
+pub fn resolve_consensus_dispute(
+    admin: &Pubkey,
+    round: u64,
+    resolved_value: u64,
+    slashed_oracle: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::ResolveConsensusDispute {
+            consensus_snapshot: consensus_snapshot_pda(round).0,
+            master_contract: master_contract_pda().0,
+            slashed_oracle: *slashed_oracle,
+            admin: *admin,
+        },
+        instruction::ResolveConsensusDispute { round, resolved_value },
+    )
+}
+
+pub fn close_consensus_snapshot(cranker: &Pubkey, round: u64, rent_collector: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseConsensusSnapshot {
+            consensus_snapshot: consensus_snapshot_pda(round).0,
+            master_contract: master_contract_pda().0,
+            rent_collector: *rent_collector,
+            cranker: *cranker,
+        },
+        instruction::CloseConsensusSnapshot {},
+    )
+}
+
+pub fn update_oracle_status(admin: &Pubkey, oracle_id: &str, is_active: bool) -> Instruction {
+    build(
+        accounts::UpdateOracleStatus {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::UpdateOracleStatus { is_active },
+    )
+}
+
+pub fn set_oracle_update_interval(admin: &Pubkey, oracle_id: &str, min_update_interval_secs: i64) -> Instruction {
+    build(
+        accounts::SetOracleUpdateInterval {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetOracleUpdateInterval { min_update_interval_secs },
+    )
+}
+
+/// Require `oracle_id`'s updates to be committed before they are revealed, or stop requiring it
+pub fn set_oracle_commit_reveal(admin: &Pubkey, oracle_id: &str, enabled: bool) -> Instruction {
+    build(
+        accounts::SetOracleCommitReveal {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetOracleCommitReveal { enabled },
+    )
+}
+
+/// Register data provider `provider_id` under `name`
+pub fn register_data_provider(admin: &Pubkey, provider_id: u32, name: String) -> Instruction {
+    build(
+        accounts::RegisterDataProvider {
+            data_provider: data_provider_pda(provider_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterDataProvider { provider_id, name },
+    )
+}
+
+/// Attribute `oracle_id` to data provider `provider_id`, or clear its provider with `None`
+pub fn set_oracle_provider(admin: &Pubkey, oracle_id: &str, provider_id: Option<u32>) -> Instruction {
+    build(
+        accounts::SetOracleProvider {
+            oracle: oracle_pda(oracle_id).0,
+            data_provider: provider_id.map(|provider_id| data_provider_pda(provider_id).0),
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetOracleProvider {},
+    )
+}
+
+pub fn emergency_oracle_override(
+    admin: &Pubkey,
+    oracle_id: &str,
+    corrected_data: OracleData,
+    reason: String,
+) -> Instruction {
+    build(
+        accounts::EmergencyOracleOverride {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::EmergencyOracleOverride { corrected_data, reason },
+    )
+}
+
+pub fn reset_oracle_circuit_breaker(admin: &Pubkey, oracle_id: &str) -> Instruction {
+    build(
+        accounts::ResetOracleCircuitBreaker {
+            oracle: oracle_pda(oracle_id).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::ResetOracleCircuitBreaker {},
+    )
+}
+
+// === Treasury ===
+
+/// Transactions standing up a development deployment: the master contract,
+/// treasury, oracle directory and first `MAX_BOOTSTRAP_ORACLES` oracles in one, and the
+/// remaining oracles, when there are any, in a second
+#[cfg(feature = "devnet")]
+pub fn bootstrap_dev_environment(
+    admin: &Pubkey,
+    master_contract: InitializeParams,
+    minimum_reserve_ratio: u16,
+    mut oracles: Vec<BootstrapOracle>,
+) -> Vec<Vec<Instruction>> {
+    use siglab_contract::constants::MAX_BOOTSTRAP_ORACLES;
+
+    let remaining = oracles.split_off(oracles.len().min(MAX_BOOTSTRAP_ORACLES));
+    let mut bootstrap = build(
+        accounts::BootstrapDevEnvironment {
+            master_contract: master_contract_pda().0,
+            treasury: treasury_pda().0,
+            oracle_directory: oracle_directory_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::BootstrapDevEnvironment {
+            params: BootstrapParams { master_contract, minimum_reserve_ratio, oracles: oracles.clone() },
+        },
+    );
+    bootstrap
+        .accounts
+        .extend(oracles.iter().map(|oracle| AccountMeta::new(oracle_pda(&oracle.oracle_id).0, false)));
+
+    let mut transactions = vec![vec![bootstrap]];
+    if !remaining.is_empty() {
+        // Each registration lists the oracles registered before it
+        let mut registered: Vec<Pubkey> = oracles.iter().map(|oracle| oracle_pda(&oracle.oracle_id).0).collect();
+        transactions.push(
+            remaining
+                .into_iter()
+                .map(|oracle| {
+                    let oracle_key = oracle_pda(&oracle.oracle_id).0;
+                    let ix = register_oracle(
+                        admin,
+                        &oracle.authority,
+                        &registered,
+                        &oracle.oracle_id,
+                        oracle.oracle_type,
+                        oracle.data_feed_address,
+                        oracle.signature_scheme,
+                        oracle.signer_address,
+                    );
+                    registered.push(oracle_key);
+                    ix
+                })
+                .collect(),
+        );
+    }
+    transactions
+}
+
+pub fn initialize_treasury(admin: &Pubkey, minimum_reserve_ratio: u16) -> Instruction {
+    build(
+        accounts::InitializeTreasury {
+            treasury: treasury_pda().0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeTreasury { minimum_reserve_ratio },
+    )
+}
+
+/// Open the late fee vault; `usdc_mint` also takes USDC fees into the
+/// vault's associated token account, which must already exist
+pub fn initialize_fee_vault(admin: &Pubkey, treasury: &Pubkey, usdc_mint: Option<&Pubkey>) -> Instruction {
+    let fee_vault = fee_vault_pda().0;
+    build(
+        accounts::InitializeFeeVault {
+            fee_vault,
+            master_contract: master_contract_pda().0,
+            treasury: *treasury,
+            usdc_token_account: usdc_mint.map(|mint| get_associated_token_address(&fee_vault, mint)),
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeFeeVault {},
+    )
+}
+
+pub fn deposit_funds(depositor: &Pubkey, treasury: &Pubkey, amount: u64, token_type: TokenType) -> Instruction {
+    build(
+        accounts::DepositFunds {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+            depositor: *depositor,
+        },
+        instruction::DepositFunds { amount, token_type },
+    )
+}
+
+/// Gift funds to the pool with no claim on them. SOL by default; USDC
+/// donations pass `usdc` as the treasury's (token account, mint) and are
+/// paid from the donor's associated token account.
+pub fn donate_to_treasury(
+    donor: &Pubkey,
+    treasury: &Pubkey,
+    usdc: Option<(&Pubkey, &Pubkey)>,
+    amount: u64,
+    memo: Option<String>,
+) -> Instruction {
+    let token_type = if usdc.is_some() { TokenType::USDC } else { TokenType::SOL };
+    build(
+        accounts::DonateToTreasury {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+            donor: *donor,
+            donor_token_account: usdc.map(|(_, mint)| get_associated_token_address(donor, mint)),
+            treasury_usdc_account: usdc.map(|(account, _)| *account),
+            token_program: usdc.map(|_| token::ID),
+            system_program: system_program::ID,
+        },
+        instruction::DonateToTreasury { amount, token_type, memo },
+    )
+}
+
+/// Withdraw from the treasury to `recipient` for `reason`; `usdc_mint` as for
+/// [`withdraw_treasury`]
+pub fn withdraw_funds(
+    admin: &Pubkey,
+    treasury: &Pubkey,
+    recipient: &Pubkey,
+    usdc_mint: Option<&Pubkey>,
+    amount: u64,
+    token_type: TokenType,
+    reason: WithdrawalReason,
+) -> Instruction {
+    build(
+        accounts::WithdrawFunds {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            recipient: *recipient,
+            recipient_usdc_account: usdc_mint.map(|mint| get_associated_token_address(recipient, mint)),
+        },
+        instruction::WithdrawFunds { amount, token_type, reason },
+    )
+}
+
+pub fn set_crank_tip(admin: &Pubkey, treasury: &Pubkey, tip_lamports: u64, budget_lamports: u64) -> Instruction {
+    build(
+        accounts::SetCrankTip {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+        },
+        instruction::SetCrankTip { tip_lamports, budget_lamports },
+    )
+}
+
+/// Move the balances of `old_treasury` to the treasury at `params.version`;
+/// the contract must be paused with no open payouts. `old_usdc_account` is
+/// the old treasury's USDC account, once it has one.
+pub fn migrate_treasury(
+    admin: &Pubkey,
+    old_treasury: &Pubkey,
+    old_usdc_account: Option<&Pubkey>,
+    params: MigrateTreasuryParams,
+) -> Instruction {
+    // A re-keyed USDC account receives the old one's balance
+    let new_usdc_account = old_usdc_account
+        .and(params.usdc_token_account)
+        .filter(|account| Some(account) != old_usdc_account);
+    build(
+        accounts::MigrateTreasury {
+            old_treasury: *old_treasury,
+            new_treasury: treasury_pda_v(params.version).0,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            old_usdc_account: old_usdc_account.copied(),
+            new_usdc_account,
+            token_program: old_usdc_account.map(|_| token::ID),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateTreasury { params },
+    )
+}
+
+/// `usdc_account` is the retired treasury's USDC account, if it still has one
+pub fn close_retired_treasury(admin: &Pubkey, treasury: &Pubkey, usdc_account: Option<&Pubkey>) -> Instruction {
+    build(
+        accounts::CloseRetiredTreasury {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+            admin: *admin,
+            usdc_account: usdc_account.copied(),
+        },
+        instruction::CloseRetiredTreasury {},
+    )
+}
+
+pub fn update_treasury_balance(treasury: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateTreasuryBalance {
+            treasury: *treasury,
+            master_contract: master_contract_pda().0,
+        },
+        instruction::UpdateTreasuryBalance {},
+    )
+}
+
+// === Portfolio ===
+
+/// `report_index` must equal the master contract's `portfolio_report_count`;
+/// the opened `regions` are reported with their exposure
+pub fn publish_portfolio_report(publisher: &Pubkey, report_index: u64, regions: &[[u8; 8]]) -> Instruction {
+    let mut ix = build(
+        accounts::PublishPortfolioReport {
+            portfolio_report: portfolio_report_pda(report_index).0,
+            master_contract: master_contract_pda().0,
+            publisher: *publisher,
+            system_program: system_program::ID,
+        },
+        instruction::PublishPortfolioReport {},
+    );
+    ix.accounts.extend(
+        regions
+            .iter()
+            .map(|region_code| AccountMeta::new_readonly(region_exposure_pda(region_code).0, false)),
+    );
+    ix
+}
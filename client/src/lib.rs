@@ -0,0 +1,25 @@
+//! Off-chain client for the siglab_contract program
+//!
+//! - [`pda`]: program-derived address helpers
+//! - [`instructions`]: typed builders returning [`solana_sdk::instruction::Instruction`]
+//! - [`accounts`]: account decoding helpers
+//! - [`queue`]: ordering and statistics over fetched pending payouts
+//! - [`views`]: decoders for the return data of view instructions
+//! - `rpc` (feature `rpc`): async wrapper over the nonblocking `RpcClient`
+//! - [`siglab_core`]: the program's pure payout, trigger, consensus and reserve
+//!   math, for off-chain previews
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+pub mod queue;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+pub mod views;
+
+pub use siglab_contract::ID as PROGRAM_ID;
+pub use siglab_contract::instructions::{
+    CreateDiscountCodeParams, CreatePolicyParams, CreateProductTemplateParams, InitializeParams,
+};
+pub use siglab_contract::state;
+pub use siglab_core;
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::Pubkey;
+use siglab_contract::constants::*;
+use siglab_contract::state::InsuranceType;
+use siglab_contract::ID as PROGRAM_ID;
+
+/// Master insurance contract singleton
+pub fn master_contract_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MASTER_CONTRACT_SEED], &PROGRAM_ID)
+}
+
+/// Event authority signing `emit_cpi!` self-invocations
+pub fn event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &PROGRAM_ID)
+}
+
+/// Original (version 0) treasury
+///
+/// Migrations move the treasury to `treasury_pda_v`; read the current one
+/// from the master contract's `treasury_account`.
+pub fn treasury_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED], &PROGRAM_ID)
+}
+
+/// Treasury created by the migration to `version`
+pub fn treasury_pda_v(version: u8) -> (Pubkey, u8) {
+    if version == 0 {
+        return treasury_pda();
+    }
+    Pubkey::find_program_address(&[TREASURY_SEED, &version.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Vault holding late fees paid on overdue premiums
+pub fn fee_vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_SEED], &PROGRAM_ID)
+}
+
+/// Ledger of the coverage `underwriter` co-signed and the late fees its policies paid
+pub fn underwriter_ledger_pda(underwriter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNDERWRITER_LEDGER_SEED, underwriter.as_ref()], &PROGRAM_ID)
+}
+
+/// Log of recent admin actions
+pub fn admin_audit_log_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ADMIN_AUDIT_LOG_SEED], &PROGRAM_ID)
+}
+
+/// Policy created by `holder` when the master contract's `policies_issued` was `index`
+pub fn policy_pda(holder: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POLICY_SEED, holder.as_ref(), &index.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Registered oracle
+pub fn oracle_pda(oracle_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORACLE_SEED, oracle_id.as_bytes()], &PROGRAM_ID)
+}
+
+/// Reverse index of the oracle registry
+pub fn oracle_directory_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORACLE_DIRECTORY_SEED], &PROGRAM_ID)
+}
+
+/// Upstream data provider registered as `provider_id`
+pub fn data_provider_pda(provider_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DATA_PROVIDER_SEED, &provider_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Retirement record of an unregistered oracle
+pub fn oracle_retirement_pda(oracle: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORACLE_RETIREMENT_SEED, oracle.as_ref()], &PROGRAM_ID)
+}
+
+/// Proof of an oracle reading, taken under `nonce`
+pub fn observation_proof_pda(oracle: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OBSERVATION_PROOF_SEED, oracle.as_ref(), &nonce.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Exposure and daily payouts of a region
+pub fn region_exposure_pda(region_code: &[u8; 8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REGION_EXPOSURE_SEED, region_code], &PROGRAM_ID)
+}
+
+/// Pending payout for a policy
+pub fn pending_payout_pda(policy_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_PAYOUT_SEED, &policy_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Vesting payout streaming a policy's claim in tranches
+pub fn vesting_payout_pda(policy_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VESTING_PAYOUT_SEED, &policy_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Payout, premium and endorsement records of a policy
+pub fn policy_history_pda(policy: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POLICY_HISTORY_SEED, policy.as_ref()], &PROGRAM_ID)
+}
+
+/// Breach watch tracking a policy's grace period
+pub fn breach_watch_pda(policy: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BREACH_WATCH_SEED, policy.as_ref()], &PROGRAM_ID)
+}
+
+/// Single-supply mint of a tokenized policy
+pub fn policy_mint_pda(policy: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POLICY_MINT_SEED, policy.as_ref()], &PROGRAM_ID)
+}
+
+/// On-chain metadata of a policy token
+pub fn policy_token_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POLICY_TOKEN_METADATA_SEED, mint.as_ref()], &PROGRAM_ID)
+}
+
+/// Pending ownership transfer proposal for a policy
+pub fn policy_transfer_pda(policy: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POLICY_TRANSFER_SEED, policy.as_ref()], &PROGRAM_ID)
+}
+
+/// Discount code keyed by the SHA-256 hash of its preimage
+pub fn discount_code_pda(code_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISCOUNT_CODE_SEED, code_hash.as_ref()], &PROGRAM_ID)
+}
+
+/// Per-holder redemption marker of a discount code
+pub fn discount_redemption_pda(discount_code: &Pubkey, holder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISCOUNT_REDEMPTION_SEED, discount_code.as_ref(), holder.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Consensus snapshot of a round
+pub fn consensus_snapshot_pda(round: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONSENSUS_SEED, &round.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Configuration of an insurance type
+pub fn type_config_pda(insurance_type: &InsuranceType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TYPE_CONFIG_SEED, &insurance_type.seed()], &PROGRAM_ID)
+}
+
+/// Version `version` of standard product `product_id`
+pub fn product_template_pda(product_id: u32, version: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PRODUCT_TEMPLATE_SEED, &product_id.to_le_bytes(), &version.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Timelocked parameter change proposal
+pub fn param_proposal_pda(proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PARAM_PROPOSAL_SEED, &proposal_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Premium subsidy funded by `sponsor`
+pub fn subsidy_pda(sponsor: &Pubkey, subsidy_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SUBSIDY_SEED, sponsor.as_ref(), &subsidy_id.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Portfolio report published as number `report_index`
+pub fn portfolio_report_pda(report_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PORTFOLIO_REPORT_SEED, &report_index.to_le_bytes()], &PROGRAM_ID)
+}
@@ -0,0 +1,88 @@
+//! Payout queue views over fetched pending payouts
+//!
+//! Each pending payout lives in its own account, so the queue is whatever
+//! set of accounts the caller has fetched. The helpers borrow that slice and
+//! report positions in it instead of cloning accounts, and allocate nothing.
+//!
+//! Payouts are ordered by their stored `priority`, the one on-chain ordering
+//! uses; aging counts once `recompute_priority` has persisted it.
+
+use siglab_contract::state::PendingPayout;
+
+/// Whether `a` is processed before `b`: higher stored priority first, then older first
+fn goes_before(a: &PendingPayout, b: &PendingPayout) -> bool {
+    a.priority > b.priority || (a.priority == b.priority && a.timestamp <= b.timestamp)
+}
+
+/// Fill `batch` with the indices of the next payouts to execute
+///
+/// Only ready, unexpired payouts are considered. Returns the filled prefix of
+/// `batch`, ordered by stored priority (descending) then trigger time
+/// (ascending); ties keep the order of `payouts`.
+pub fn next_payout_batch<'a>(
+    payouts: &[PendingPayout],
+    current_timestamp: i64,
+    batch: &'a mut [usize],
+) -> &'a [usize] {
+    let mut len = 0;
+    
+    for (index, payout) in payouts.iter().enumerate() {
+        if !payout.is_ready_for_execution() || payout.is_expired(current_timestamp) {
+            continue;
+        }
+        
+        let position = batch[..len]
+            .partition_point(|&queued| goes_before(&payouts[queued], payout));
+        if position == batch.len() {
+            continue;
+        }
+        
+        // Shift later entries back, dropping the last one when the batch is full
+        if len < batch.len() {
+            len += 1;
+        }
+        batch[position..len].rotate_right(1);
+        batch[position] = index;
+    }
+    
+    &batch[..len]
+}
+
+/// Indices of payouts past their expiry, ready to be cleaned up
+pub fn expired_payouts(
+    payouts: &[PendingPayout],
+    current_timestamp: i64,
+) -> impl Iterator<Item = usize> + '_ {
+    payouts
+        .iter()
+        .enumerate()
+        .filter(move |(_, payout)| payout.is_expired(current_timestamp))
+        .map(|(index, _)| index)
+}
+
+/// Queue statistics for monitoring
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueStatistics {
+    pub total_count: usize,
+    pub ready_count: usize,
+    pub pending_approval_count: usize,
+    pub expired_count: usize,
+    pub total_amount: u64,
+    pub oldest_timestamp: i64,
+}
+
+/// Summarize `payouts`; `oldest_timestamp` is `current_timestamp` when empty
+pub fn queue_statistics(payouts: &[PendingPayout], current_timestamp: i64) -> QueueStatistics {
+    QueueStatistics {
+        total_count: payouts.len(),
+        ready_count: payouts.iter().filter(|p| p.is_ready_for_execution()).count(),
+        pending_approval_count: payouts.iter().filter(|p| p.requires_approval()).count(),
+        expired_count: expired_payouts(payouts, current_timestamp).count(),
+        total_amount: payouts.iter().map(|p| p.amount).sum(),
+        oldest_timestamp: payouts
+            .iter()
+            .map(|p| p.timestamp)
+            .min()
+            .unwrap_or(current_timestamp),
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use anchor_lang::AccountDeserialize;
+use base64::Engine;
+use siglab_contract::state::{
+    MasterInsuranceContract, Oracle, OracleDirectoryView, OracleLookup, PendingPayout, Policy, PolicyStatusView,
+    Treasury, TreasuryHealthView, View,
+};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Error as RpcError;
+use solana_rpc_client_api::config::RpcSimulateTransactionConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::accounts::{decode, decode_oracle};
+use crate::instructions;
+use crate::pda::*;
+use crate::views::{decode_view, ViewError};
+
+#[derive(Debug)]
+pub enum ClientError {
+    Rpc(Box<RpcError>),
+    Decode(anchor_lang::error::Error),
+    /// A simulated view instruction failed
+    Simulation(TransactionError),
+    /// A simulated view instruction returned no usable response
+    View(ViewError),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Rpc(err) => write!(f, "rpc error: {}", err),
+            ClientError::Decode(err) => write!(f, "account decode error: {}", err),
+            ClientError::Simulation(err) => write!(f, "simulation failed: {}", err),
+            ClientError::View(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<RpcError> for ClientError {
+    fn from(err: RpcError) -> Self {
+        ClientError::Rpc(Box::new(err))
+    }
+}
+
+impl From<anchor_lang::error::Error> for ClientError {
+    fn from(err: anchor_lang::error::Error) -> Self {
+        ClientError::Decode(err)
+    }
+}
+
+impl From<ViewError> for ClientError {
+    fn from(err: ViewError) -> Self {
+        ClientError::View(err)
+    }
+}
+
+pub type ClientResult<T> = std::result::Result<T, ClientError>;
+
+/// Thin async wrapper over `RpcClient` for fetching and decoding program accounts
+pub struct SiglabClient {
+    rpc: RpcClient,
+}
+
+impl SiglabClient {
+    pub fn new(rpc: RpcClient) -> Self {
+        Self { rpc }
+    }
+
+    pub fn new_with_url(url: impl Into<String>) -> Self {
+        Self::new(RpcClient::new(url.into()))
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Fetch and decode any program account
+    pub async fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> ClientResult<T> {
+        let data = self.rpc.get_account_data(address).await?;
+        Ok(decode(&data)?)
+    }
+
+    pub async fn fetch_master_contract(&self) -> ClientResult<MasterInsuranceContract> {
+        self.fetch(&master_contract_pda().0).await
+    }
+
+    /// Fetch the treasury currently linked to the master contract
+    pub async fn fetch_treasury(&self) -> ClientResult<Treasury> {
+        let master_contract = self.fetch_master_contract().await?;
+        self.fetch(&master_contract.treasury_account).await
+    }
+
+    pub async fn fetch_policy(&self, address: &Pubkey) -> ClientResult<Policy> {
+        self.fetch(address).await
+    }
+
+    pub async fn fetch_oracle(&self, oracle_id: &str) -> ClientResult<Oracle> {
+        let data = self.rpc.get_account_data(&oracle_pda(oracle_id).0).await?;
+        Ok(decode_oracle(&data)?)
+    }
+
+    pub async fn fetch_pending_payout(&self, policy_id: u64) -> ClientResult<PendingPayout> {
+        self.fetch(&pending_payout_pda(policy_id).0).await
+    }
+
+    /// Simulate a view instruction and decode its return data
+    ///
+    /// The transaction is not signed; `payer` only needs to be a funded account.
+    pub async fn simulate_view<T: View>(&self, instruction: Instruction, payer: &Pubkey) -> ClientResult<T> {
+        let transaction = Transaction::new_with_payer(&[instruction], Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self.rpc.simulate_transaction_with_config(&transaction, config).await?.value;
+        if let Some(err) = result.err {
+            return Err(ClientError::Simulation(err));
+        }
+
+        let data = result
+            .return_data
+            .filter(|return_data| return_data.program_id == crate::PROGRAM_ID.to_string())
+            .and_then(|return_data| base64::engine::general_purpose::STANDARD.decode(return_data.data.0).ok())
+            .unwrap_or_default();
+        Ok(decode_view(&data)?)
+    }
+
+    pub async fn policy_status(
+        &self,
+        policy: &Pubkey,
+        pending_payout_for: Option<u64>,
+        payer: &Pubkey,
+    ) -> ClientResult<PolicyStatusView> {
+        self.simulate_view(instructions::get_policy_status(policy, pending_payout_for), payer).await
+    }
+
+    pub async fn treasury_health(&self, payer: &Pubkey) -> ClientResult<TreasuryHealthView> {
+        let treasury = self.fetch_master_contract().await?.treasury_account;
+        self.simulate_view(instructions::get_treasury_health(&treasury), payer).await
+    }
+
+    pub async fn resolve_oracle(&self, lookup: OracleLookup, payer: &Pubkey) -> ClientResult<OracleDirectoryView> {
+        self.simulate_view(instructions::resolve_oracle(lookup), payer).await
+    }
+
+    /// Sign with `payer` and `signers`, send and confirm
+    pub async fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        Ok(self.rpc.send_and_confirm_transaction(&transaction).await?)
+    }
+}
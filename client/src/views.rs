@@ -0,0 +1,52 @@
+use std::fmt;
+
+use anchor_lang::AnchorDeserialize;
+use siglab_contract::state::{
+    ClaimEligibilityView, OracleDirectoryView, PolicyStatusView, TreasuryHealthView, View, ViewResponse,
+};
+
+#[derive(Debug)]
+pub enum ViewError {
+    /// The response was encoded with a schema this client does not know
+    UnsupportedVersion { expected: u8, found: u8 },
+    /// The return data is not a valid response
+    Decode(std::io::Error),
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViewError::UnsupportedVersion { expected, found } => {
+                write!(f, "unsupported view version {} (expected {})", found, expected)
+            }
+            ViewError::Decode(err) => write!(f, "view decode error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ViewError {}
+
+/// Decode the return data of a view instruction, checking the schema version
+pub fn decode_view<T: View>(data: &[u8]) -> Result<T, ViewError> {
+    let response = ViewResponse::<T>::try_from_slice(data).map_err(ViewError::Decode)?;
+    if response.version != T::VERSION {
+        return Err(ViewError::UnsupportedVersion { expected: T::VERSION, found: response.version });
+    }
+    Ok(response.data)
+}
+
+pub fn decode_policy_status(data: &[u8]) -> Result<PolicyStatusView, ViewError> {
+    decode_view(data)
+}
+
+pub fn decode_treasury_health(data: &[u8]) -> Result<TreasuryHealthView, ViewError> {
+    decode_view(data)
+}
+
+pub fn decode_oracle_directory(data: &[u8]) -> Result<OracleDirectoryView, ViewError> {
+    decode_view(data)
+}
+
+pub fn decode_claim_eligibility(data: &[u8]) -> Result<ClaimEligibilityView, ViewError> {
+    decode_view(data)
+}
@@ -0,0 +1,174 @@
+//! Compute-unit regression tests, run against the built program
+//!
+//! They load `siglab_contract.so`, so run them with `cargo test-sbf --features test-sbf`.
+
+#![cfg(feature = "test-sbf")]
+
+use anchor_lang::prelude::{Clock, Pubkey, Rent};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, ZeroCopy};
+use bytemuck::Zeroable;
+use siglab_contract::constants::MAX_ORACLES;
+use siglab_contract::state::{MasterInsuranceContract, Oracle, OracleData, OracleObservation};
+use siglab_contract_client::{instructions, pda};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+/// Upper bound for `update_oracle_data` on an oracle whose history ring
+/// buffer is full, so every update overwrites the oldest observation
+const ORACLE_UPDATE_CU_BUDGET: u64 = 25_000;
+
+/// Upper bound for `compute_consensus` over `MAX_ORACLES` (10) fresh oracles,
+/// one of them an outlier, including the consensus snapshot it creates:
+/// 80,000 CU, well inside the default 200,000 per instruction
+const CONSENSUS_CU_BUDGET: u64 = 80_000;
+
+const ORACLE_ID: &str = "rainfall-mm";
+
+fn blank<T: AccountDeserialize + Discriminator>() -> T {
+    let data = [T::DISCRIMINATOR, &[0; 16_384]].concat();
+    T::try_deserialize(&mut &data[..]).unwrap()
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: siglab_contract::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn zero_copy_account<T: ZeroCopy + Discriminator>(state: &T) -> Account {
+    program_account([T::DISCRIMINATOR, bytemuck::bytes_of(state)].concat())
+}
+
+/// A master contract administered by `authority` with `oracles` registered,
+/// adjusted by `configure`
+fn master_contract_account(
+    authority: Pubkey,
+    oracles: &[Pubkey],
+    configure: impl FnOnce(&mut MasterInsuranceContract),
+) -> Account {
+    let mut state: MasterInsuranceContract = blank();
+    state.authority = authority;
+    state.bump = pda::master_contract_pda().1;
+    state.oracle_registry = oracles.to_vec();
+    state.max_oracles = 10;
+    configure(&mut state);
+
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+    data.resize(MasterInsuranceContract::space(), 0);
+    program_account(data)
+}
+
+/// An active oracle run by `authority` whose history holds `observations`
+fn oracle_state(
+    oracle_id: &str,
+    authority: Pubkey,
+    observations: impl IntoIterator<Item = OracleObservation>,
+) -> Oracle {
+    let mut state = Oracle::zeroed();
+    state.authority = authority;
+    state.set_oracle_id(oracle_id);
+    state.bump = pda::oracle_pda(oracle_id).1;
+    state.set_active(true);
+    for observation in observations {
+        state.record_observation(observation);
+    }
+    state
+}
+
+/// Start the program with its SBF build and return the context and the cluster time
+async fn start() -> (ProgramTestContext, i64) {
+    let mut test = ProgramTest::new("siglab_contract", siglab_contract::ID, None);
+    test.prefer_bpf(true);
+    let context = test.start_with_context().await;
+    let now = context.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    (context, now)
+}
+
+/// Compute units consumed by `instructions`, which must succeed
+async fn compute_units(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> u64 {
+    let signers = [&[&context.payer], signers].concat();
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+    let outcome = context.banks_client.process_transaction_with_metadata(transaction).await.unwrap();
+    outcome.result.unwrap();
+    outcome.metadata.unwrap().compute_units_consumed
+}
+
+#[tokio::test]
+async fn oracle_update_with_full_history_stays_within_budget() {
+    let (mut context, now) = start().await;
+    let authority = Keypair::new();
+    let oracle = pda::oracle_pda(ORACLE_ID).0;
+
+    // Three more observations than the ring buffer holds, so it has wrapped
+    let history = (0..Oracle::HISTORY_LENGTH as u64 + 3).map(|index| OracleObservation {
+        value: 100 + index,
+        timestamp: now - 600 + index as i64,
+        confidence: 95,
+    });
+    let mut state = oracle_state(ORACLE_ID, authority.pubkey(), history);
+    state.last_nonce = Oracle::HISTORY_LENGTH as u64 + 3;
+    assert_eq!(state.history_len as usize, Oracle::HISTORY_LENGTH);
+    context.set_account(&oracle, &zero_copy_account(&state).into());
+    let master = master_contract_account(context.payer.pubkey(), &[oracle], |_| {});
+    context.set_account(&pda::master_contract_pda().0, &master.into());
+
+    let mut data = OracleData {
+        version: 0x10,
+        value: 140,
+        timestamp: now - 5,
+        confidence: 95,
+        signature: [0; 64],
+        nonce: state.last_nonce + 1,
+        extensions: Vec::new(),
+    };
+    let attestation = instructions::attest_oracle_data(&authority, ORACLE_ID, &mut data);
+    let update = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, data);
+    let consumed = compute_units(&mut context, &[attestation, update], &[&authority]).await;
+    assert!(consumed <= ORACLE_UPDATE_CU_BUDGET, "oracle update used {consumed} CU");
+}
+
+#[tokio::test]
+async fn consensus_over_ten_oracles_stays_within_budget() {
+    let (mut context, now) = start().await;
+    let values = [1_000, 1_010, 990, 1_005, 995, 1_002, 998, 1_003, 997, 2_000];
+    assert_eq!(values.len(), MAX_ORACLES);
+
+    let oracles: Vec<Pubkey> = values
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| {
+            let oracle_id = format!("consensus-{index}");
+            let observation = OracleObservation { value, timestamp: now - 5, confidence: 95 };
+            let state = oracle_state(&oracle_id, Pubkey::new_unique(), [observation]);
+            let oracle = pda::oracle_pda(&oracle_id).0;
+            context.set_account(&oracle, &zero_copy_account(&state).into());
+            oracle
+        })
+        .collect();
+    let master = master_contract_account(context.payer.pubkey(), &oracles, |master| {
+        master.min_consensus_threshold = 3;
+        master.consensus_timeout_secs = 600;
+    });
+    context.set_account(&pda::master_contract_pda().0, &master.into());
+
+    let payer = context.payer.pubkey();
+    let consumed = compute_units(&mut context, &[instructions::compute_consensus(&payer, 0, &oracles)], &[]).await;
+    assert!(consumed <= CONSENSUS_CU_BUDGET, "consensus over {} oracles used {consumed} CU", oracles.len());
+}
@@ -0,0 +1,257 @@
+use anchor_lang::error::ErrorCode::ConstraintSeeds;
+use anchor_lang::prelude::{ProgramError, Pubkey};
+use siglab_contract::constants::{MAX_ORACLES, MIN_ORACLES_FOR_CONSENSUS};
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{
+    AdminAction, AdminAuditEntry, AdminAuditLog, MasterInsuranceContract, Policy, PremiumPause, RegionExposure,
+    TokenType,
+};
+use siglab_contract_client::{instructions, pda, InitializeParams};
+
+use crate::fixtures::{Env, REGION};
+use crate::harness::{redirect, World, NOW};
+
+fn initialize_params(max_oracles: u8, min_consensus_threshold: u8, single_oracle_mode: bool) -> InitializeParams {
+    InitializeParams {
+        reserve_ratio_bps: 2000,
+        max_oracles,
+        min_consensus_threshold,
+        activation_window: 86_400,
+        param_timelock_secs: 0,
+        global_config: None,
+        single_oracle_mode,
+    }
+}
+
+#[test]
+fn initialize_master_contract() {
+    let mut world = World::new();
+    let admin = world.wallet();
+    let master = pda::master_contract_pda().0;
+    world.preallocate(master, MasterInsuranceContract::space());
+    let max = MAX_ORACLES as u8;
+    let floor = MIN_ORACLES_FOR_CONSENSUS as u8;
+
+    let rejected = [
+        (initialize_params(0, 0, true), InsuranceError::InvalidMaxOracles),
+        (initialize_params(max + 1, floor, false), InsuranceError::InvalidMaxOracles),
+        (initialize_params(floor - 1, floor - 1, false), InsuranceError::InvalidMaxOracles),
+        (initialize_params(max, floor - 1, false), InsuranceError::ConsensusThresholdTooLow),
+        (initialize_params(max, 0, true), InsuranceError::ConsensusThresholdTooLow),
+        (initialize_params(floor, floor + 1, false), InsuranceError::ConsensusThresholdAboveMaxOracles),
+    ];
+    for (params, error) in rejected {
+        world.expect_error(&instructions::initialize_master_contract(&admin, params), error);
+    }
+
+    // Test deployments may run on a single oracle, but only by opting in
+    world.process(&instructions::initialize_master_contract(&admin, initialize_params(1, 1, true))).unwrap();
+    let state: MasterInsuranceContract = world.get(&master);
+    assert!(state.single_oracle_mode);
+    assert_eq!((state.max_oracles, state.min_consensus_threshold), (1, 1));
+}
+
+#[test]
+fn pause_contract() {
+    let mut env = Env::new();
+    env.world.expect_error(&instructions::pause_contract(&env.intruder, false), InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(instructions::pause_contract(&env.admin, false), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    // Premiums stay payable unless the pause blocks them
+    env.world.process(&instructions::pause_contract(&env.admin, false)).unwrap();
+    let master: MasterInsuranceContract = env.world.get(&env.master);
+    assert!(master.is_paused);
+    assert!(!master.premiums_paused());
+    env.world.expect_error(&instructions::pause_contract(&env.admin, true), InsuranceError::ContractPaused);
+}
+
+#[test]
+fn resume_contract() {
+    let mut env = Env::new();
+    env.world.expect_error(&instructions::resume_contract(&env.admin), InsuranceError::ContractMustBePaused);
+
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.is_paused = true);
+    env.world.expect_error(&instructions::resume_contract(&env.intruder), InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(instructions::resume_contract(&env.admin), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    // Resuming records how long premiums were blocked
+    let mut master: MasterInsuranceContract = env.world.get(&env.master);
+    master.premiums_paused_at = Some(NOW - 3 * 86_400);
+    env.world.set_sized(env.master, &master, MasterInsuranceContract::space());
+    env.world.process(&instructions::resume_contract(&env.admin)).unwrap();
+    let master: MasterInsuranceContract = env.world.get(&env.master);
+    assert_eq!(master.premiums_paused_at, None);
+    assert_eq!(master.premium_pauses, vec![PremiumPause { started_at: NOW - 3 * 86_400, ended_at: NOW }]);
+}
+
+#[test]
+fn update_reserve_ratio() {
+    let mut env = Env::new();
+    let ix = instructions::update_reserve_ratio(&env.intruder, &env.treasury, 2_500);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::update_reserve_ratio(&env.admin, &elsewhere, 2_500);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+}
+
+#[test]
+fn transfer_authority() {
+    let mut env = Env::new();
+    let ix = instructions::transfer_authority(&env.intruder, &env.intruder);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(instructions::transfer_authority(&env.admin, &env.holder), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    // The nominee takes over only once it accepts
+    env.world.process(&instructions::transfer_authority(&env.admin, &env.holder)).unwrap();
+    let master: MasterInsuranceContract = env.world.get(&env.master);
+    assert_eq!((master.authority, master.pending_authority), (env.admin, Some(env.holder)));
+}
+
+#[test]
+fn accept_authority() {
+    let mut env = Env::new();
+    let ix = instructions::accept_authority(&env.holder, None);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    env.world.process(&instructions::transfer_authority(&env.admin, &env.holder)).unwrap();
+    env.world.expect_error(&instructions::accept_authority(&env.intruder, None), InsuranceError::Unauthorized);
+    assert_eq!(env.world.process_via_cpi(&ix), Err(ProgramError::Custom(InsuranceError::CpiNotAllowed.into())));
+
+    env.world.process(&ix).unwrap();
+    let master: MasterInsuranceContract = env.world.get(&env.master);
+    assert_eq!((master.authority, master.pending_authority, master.governance_program), (env.holder, None, None));
+}
+
+#[test]
+fn governance_authority() {
+    let mut env = Env::new();
+    let governance_program = env.world.program();
+    let (governance, _) = Pubkey::find_program_address(&[b"governance"], &governance_program);
+    let pause = instructions::pause_contract(&governance, false);
+    env.world.process(&instructions::transfer_authority(&env.admin, &governance)).unwrap();
+
+    // Before the DAO takes over, admin instructions refuse CPIs
+    let accept = instructions::accept_authority(&governance, Some(&governance_program));
+    let not_a_program = instructions::accept_authority(&governance, Some(&env.holder));
+    env.world.expect_error(&not_a_program, InsuranceError::InvalidGovernanceProgram);
+    env.world.expect_error(&accept, InsuranceError::GovernanceInvocationRequired);
+    env.world.process(&instructions::pause_contract(&env.admin, false)).unwrap();
+    env.world.process(&instructions::resume_contract(&env.admin)).unwrap();
+    let via_cpi = env.world.process_via_cpi(&instructions::pause_contract(&env.admin, false));
+    assert_eq!(via_cpi, Err(ProgramError::Custom(InsuranceError::CpiNotAllowed.into())));
+
+    // The governance program's PDA accepts and then acts as admin via CPI
+    env.world.process_via_cpi(&accept).unwrap();
+    let master: MasterInsuranceContract = env.world.get(&env.master);
+    assert_eq!((master.authority, master.governance_program), (governance, Some(governance_program)));
+    env.world.expect_error(&instructions::pause_contract(&env.admin, false), InsuranceError::Unauthorized);
+    env.world.process_via_cpi(&pause).unwrap();
+    assert!(env.world.get::<MasterInsuranceContract>(&env.master).is_paused);
+
+    // Handing the authority back to a wallet ends governance
+    env.world.process_via_cpi(&instructions::transfer_authority(&governance, &env.admin)).unwrap();
+    env.world.process(&instructions::accept_authority(&env.admin, None)).unwrap();
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).governance_program, None);
+}
+
+#[test]
+fn waive_late_fee() {
+    let mut env = Env::new();
+    env.world
+        .expect_error(&instructions::waive_late_fee(&env.intruder, &env.policy), InsuranceError::Unauthorized);
+
+    // The policy's own creator and index no longer lead to its address
+    let elsewhere = env.misplace(env.policy);
+    env.world.expect_error(&instructions::waive_late_fee(&env.admin, &elsewhere), ConstraintSeeds);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.index = 7);
+    env.world.expect_error(&instructions::waive_late_fee(&env.admin, &env.policy), ConstraintSeeds);
+}
+
+#[test]
+fn waive_late_fee_is_audited() {
+    let mut env = Env::new();
+    env.overdue_premium(2_000_000, 3);
+    let (audit_log, _) = pda::admin_audit_log_pda();
+
+    env.world.process(&instructions::waive_late_fee(&env.admin, &env.policy)).unwrap();
+    let log: AdminAuditLog = env.world.get(&audit_log);
+    let entry = AdminAuditEntry {
+        action: AdminAction::LateFeeWaived,
+        admin: env.admin,
+        target: env.policy,
+        amount: 60_000,
+        timestamp: NOW,
+    };
+    assert_eq!((log.entries, log.total_entries), (vec![entry], 1));
+    env.world.expect_error(&instructions::waive_late_fee(&env.admin, &env.policy), InsuranceError::NoLateFeeDue);
+}
+
+#[test]
+fn final_settlement() {
+    let mut env = Env::new();
+    let ix = instructions::final_settlement(&env.admin, &env.treasury, &env.admin, None);
+    env.world.expect_error(&ix, InsuranceError::WinddownNotStarted);
+
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.winddown = true);
+    let ix = instructions::final_settlement(&env.intruder, &env.treasury, &env.intruder, None);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let ix = instructions::final_settlement(&env.admin, &env.treasury, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::InvalidRecipient);
+}
+
+#[test]
+fn withdraw_treasury() {
+    let mut env = Env::new();
+    let ix = instructions::withdraw_treasury(&env.intruder, &env.treasury, &env.intruder, None, 1, TokenType::SOL);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::withdraw_treasury(&env.admin, &elsewhere, &env.admin, None, 1, TokenType::SOL);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+}
+
+#[test]
+fn set_underwriter() {
+    let mut env = Env::new();
+    let ix = instructions::set_underwriter(&env.intruder, &env.intruder);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(instructions::set_underwriter(&env.admin, &env.holder), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    // The authority co-signs large policies until an underwriter is named
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).policy_co_signer(), env.admin);
+    env.world.process(&instructions::set_underwriter(&env.admin, &env.holder)).unwrap();
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).policy_co_signer(), env.holder);
+}
+
+#[test]
+fn open_region() {
+    let mut env = Env::new();
+    let region = pda::region_exposure_pda(&REGION).0;
+    env.world.preallocate(region, RegionExposure::space());
+    env.world.expect_error(&instructions::open_region(&env.intruder, REGION), InsuranceError::Unauthorized);
+
+    // An all-zero code names no region
+    env.world.preallocate(pda::region_exposure_pda(&[0; 8]).0, RegionExposure::space());
+    env.world.expect_error(&instructions::open_region(&env.admin, [0; 8]), InsuranceError::InvalidRegionCode);
+
+    env.world.process(&instructions::open_region(&env.admin, REGION)).unwrap();
+    let region_exposure: RegionExposure = env.world.get(&region);
+    assert_eq!(region_exposure.region_code, REGION);
+    assert_eq!(region_exposure.payout_day, NOW / 86_400);
+    assert_eq!(region_exposure.opened_at, NOW);
+}
@@ -0,0 +1,153 @@
+//! A deployed program with one of everything: a linked treasury, a fee
+//! vault, an admin audit log, an active oracle listed in the oracle
+//! directory, an active policy and a payout waiting on that policy.
+
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+use siglab_contract::state::{
+    AdminAuditLog, FeeVault, GlobalConfig, MasterInsuranceContract, Oracle, OracleDirectory, PayoutStatus,
+    PendingPayout, Policy, PolicyHistory, PolicyStatus, PremiumFrequency, TokenType, Treasury, TreasuryStatus,
+};
+use siglab_contract_client::pda;
+
+use crate::harness::{blank, World, NOW};
+
+pub const ORACLE_ID: &str = "rainfall-feed";
+pub const POLICY_ID: u64 = 1;
+pub const REGION: [u8; 8] = *b"US-CA\0\0\0";
+
+pub struct Env {
+    pub world: World,
+    pub admin: Pubkey,
+    /// A funded wallet with no role anywhere
+    pub intruder: Pubkey,
+    pub holder: Pubkey,
+    pub oracle_authority: Pubkey,
+    pub master: Pubkey,
+    pub treasury: Pubkey,
+    pub oracle: Pubkey,
+    pub oracle_directory: Pubkey,
+    pub policy: Pubkey,
+    pub payout: Pubkey,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        let mut world = World::new();
+        let admin = world.wallet();
+        let intruder = world.wallet();
+        let holder = world.wallet();
+        let oracle_authority = world.wallet();
+
+        let (master, master_bump) = pda::master_contract_pda();
+        let (treasury, treasury_bump) = pda::treasury_pda();
+        let (oracle, oracle_bump) = pda::oracle_pda(ORACLE_ID);
+        let (oracle_directory, directory_bump) = pda::oracle_directory_pda();
+        let (policy, policy_bump) = pda::policy_pda(&holder, 0);
+        let (payout, payout_bump) = pda::pending_payout_pda(POLICY_ID);
+
+        let mut state: MasterInsuranceContract = blank();
+        state.authority = admin;
+        state.bump = master_bump;
+        state.treasury_account = treasury;
+        state.oracle_registry = vec![oracle];
+        state.max_oracles = 10;
+        state.next_policy_id = POLICY_ID + 1;
+        state.policies_issued = 1;
+        state.open_payout_count = 1;
+        world.set_sized(master, &state, MasterInsuranceContract::space());
+
+        let mut state: Treasury = blank();
+        state.authority = admin;
+        state.bump = treasury_bump;
+        state.status = TreasuryStatus::Active;
+        state.minimum_reserve_ratio = 2_000;
+        world.set(treasury, &state);
+
+        let mut state = Oracle::zeroed();
+        state.authority = oracle_authority;
+        state.set_oracle_id(ORACLE_ID);
+        state.bump = oracle_bump;
+        state.set_active(true);
+        world.set_zero_copy(oracle, &state);
+
+        let mut state = OracleDirectory::zeroed();
+        state.bump = directory_bump;
+        state.record_registration(ORACLE_ID, oracle, oracle_authority).unwrap();
+        world.set_zero_copy(oracle_directory, &state);
+
+        let mut state: Policy = blank();
+        state.id = POLICY_ID;
+        state.user = holder;
+        state.creator = holder;
+        state.index = 0;
+        state.bump = policy_bump;
+        state.status = PolicyStatus::Active;
+        state.start_date = NOW - 86_400;
+        state.end_date = NOW + 30 * 86_400;
+        world.set_sized(policy, &state, Policy::space());
+
+        let (policy_history, history_bump) = pda::policy_history_pda(&policy);
+        let mut state: PolicyHistory = blank();
+        state.policy = policy;
+        state.bump = history_bump;
+        world.set_sized(policy_history, &state, PolicyHistory::space());
+
+        let mut state: PendingPayout = blank();
+        state.policy_id = POLICY_ID;
+        state.status = PayoutStatus::PendingApproval;
+        state.beneficiary = holder;
+        state.rent_payer = holder;
+        state.bump = payout_bump;
+        state.expires_at = NOW + 86_400;
+        world.set(payout, &state);
+
+        let (fee_vault, fee_vault_bump) = pda::fee_vault_pda();
+        let mut state: FeeVault = blank();
+        state.bump = fee_vault_bump;
+        world.set_sized(fee_vault, &state, FeeVault::space());
+
+        let (audit_log, audit_log_bump) = pda::admin_audit_log_pda();
+        let mut state: AdminAuditLog = blank();
+        state.bump = audit_log_bump;
+        world.set_sized(audit_log, &state, AdminAuditLog::space());
+
+        Self {
+            world,
+            admin,
+            intruder,
+            holder,
+            oracle_authority,
+            master,
+            treasury,
+            oracle,
+            oracle_directory,
+            policy,
+            payout,
+        }
+    }
+
+    /// Leave the policy's monthly `premium` `days` days overdue, with late
+    /// fees of 1% of the premium per day
+    pub fn overdue_premium(&mut self, premium: u64, days: i64) {
+        self.world.update(self.master, |master: &mut MasterInsuranceContract| {
+            master.global_config = GlobalConfig::default();
+            master.global_config.late_fee_bps = 100;
+        });
+        self.world.update(self.policy, |policy: &mut Policy| {
+            policy.status = PolicyStatus::Active;
+            policy.premium_currency = TokenType::SOL;
+            policy.premium_amount = premium;
+            policy.premium_payment_frequency = PremiumFrequency::Monthly;
+            policy.last_premium_paid = NOW - (30 + days) * 86_400;
+        });
+    }
+
+    /// Copy `key` to an address derived from seeds no instruction expects,
+    /// keeping its stored bump and seed fields
+    pub fn misplace(&mut self, key: Pubkey) -> Pubkey {
+        let (elsewhere, _) = Pubkey::find_program_address(&[b"elsewhere", key.as_ref()], &siglab_contract::ID);
+        self.world.copy(&key, elsewhere);
+        elsewhere
+    }
+}
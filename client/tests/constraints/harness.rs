@@ -0,0 +1,486 @@
+//! Runs program instructions in-process against an in-memory account set.
+//!
+//! Account validation happens before a handler touches the runtime, so the
+//! program's `entry` can be called directly with syscall stubs standing in
+//! for the clock and the stack height. CPIs that move lamports or tokens,
+//! create associated token accounts, or freeze or thaw token accounts are
+//! carried out; any other CPI does nothing, so instructions that `init` an account fail unless the account
+//! is staged with `preallocate`, since Anchor creates it by CPI before
+//! checking the others. Precompiles don't run
+//! either: a transaction laid out with `set_transaction` only shows the
+//! program its instructions. Most cases here are decided during account
+//! validation or early in the handler.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::{AccountInfo, Clock, ProgramError, Rent};
+use anchor_lang::solana_program::entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, ZeroCopy};
+use anchor_spl::associated_token;
+use anchor_spl::token::{self, spl_token};
+use siglab_contract::ID as PROGRAM_ID;
+use solana_instruction::{BorrowedAccountMeta, BorrowedInstruction};
+use solana_instructions_sysvar::{construct_instructions_data, store_current_index_checked};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+/// Unix time every instruction sees
+pub const NOW: i64 = 1_700_000_000;
+const WALLET_LAMPORTS: u64 = 100_000_000_000;
+
+thread_local! {
+    static RETURN_DATA: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    static STACK_HEIGHT: Cell<u64> = const { Cell::new(1) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { slot: 1_000, unix_timestamp: NOW, ..Clock::default() };
+        unsafe { std::ptr::write(var_addr as *mut Clock, clock) };
+        0
+    }
+
+    // Optional `init` accounts fetch the rent sysvar even when absent
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write(var_addr as *mut Rent, Rent::default()) };
+        0
+    }
+
+    // View instructions answer through return data
+    fn sol_set_return_data(&self, data: &[u8]) {
+        RETURN_DATA.with(|returned| *returned.borrow_mut() = data.to_vec());
+    }
+
+    // Instructions run at the top level of their transaction unless
+    // `process_via_cpi` runs them one program down
+    fn sol_get_stack_height(&self) -> u64 {
+        STACK_HEIGHT.with(Cell::get)
+    }
+
+    // Lamport and token transfers move funds, and token accounts are
+    // created, change hands and are frozen or thawed as the token programs
+    // would; every other CPI does nothing
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let info = |index: usize| {
+            let key = instruction.accounts[index].pubkey;
+            account_infos.iter().find(|info| *info.key == key).unwrap()
+        };
+        let data = &instruction.data;
+        if instruction.program_id == system_program::ID && data.len() == 12 && data[..4] == [2, 0, 0, 0] {
+            let amount = u64::from_le_bytes(data[4..].try_into().unwrap());
+            let (from, to) = (info(0), info(1));
+            let remaining = from.lamports().checked_sub(amount).ok_or(ProgramError::Custom(1))?;
+            **from.try_borrow_mut_lamports()? = remaining;
+            **to.try_borrow_mut_lamports()? += amount;
+        } else if instruction.program_id == token::ID && data.len() == 9 && data[0] == 3 {
+            let amount = u64::from_le_bytes(data[1..].try_into().unwrap());
+            let (from, to) = (info(0), info(1));
+            let mut source = spl_token::state::Account::unpack(&from.try_borrow_data()?)?;
+            let mut destination = spl_token::state::Account::unpack(&to.try_borrow_data()?)?;
+            source.amount = source.amount.checked_sub(amount).ok_or(ProgramError::Custom(1))?;
+            destination.amount += amount;
+            source.pack_into_slice(&mut from.try_borrow_mut_data()?);
+            destination.pack_into_slice(&mut to.try_borrow_mut_data()?);
+        } else if instruction.program_id == token::ID && data.len() == 35 && data[..3] == [6, 2, 1] {
+            let account = info(0);
+            let mut state = spl_token::state::Account::unpack(&account.try_borrow_data()?)?;
+            state.owner = Pubkey::new_from_array(data[3..].try_into().unwrap());
+            state.pack_into_slice(&mut account.try_borrow_mut_data()?);
+        } else if instruction.program_id == associated_token::ID && data[..] == [0] {
+            let (payer, account, wallet, mint) = (info(0), info(1), info(2), info(3));
+            let rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+            let remaining = payer.lamports().checked_sub(rent).ok_or(ProgramError::Custom(1))?;
+            **payer.try_borrow_mut_lamports()? = remaining;
+            **account.try_borrow_mut_lamports()? += rent;
+            account.resize(spl_token::state::Account::LEN)?;
+            account.assign(&token::ID);
+            let state = spl_token::state::Account {
+                mint: *mint.key,
+                owner: *wallet.key,
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            };
+            state.pack_into_slice(&mut account.try_borrow_mut_data()?);
+        } else if instruction.program_id == token::ID && (data[..] == [10] || data[..] == [11]) {
+            let account = info(0);
+            let mut state = spl_token::state::Account::unpack(&account.try_borrow_data()?)?;
+            let freeze = data[0] == 10;
+            // The token program refuses to freeze a frozen account or thaw a thawed one
+            if state.is_frozen() == freeze {
+                return Err(spl_token::error::TokenError::InvalidState.into());
+            }
+            state.state = if freeze {
+                spl_token::state::AccountState::Frozen
+            } else {
+                spl_token::state::AccountState::Initialized
+            };
+            state.pack_into_slice(&mut account.try_borrow_mut_data()?);
+        }
+        Ok(())
+    }
+}
+
+/// An account as stored between instructions
+#[derive(Clone)]
+struct Stored {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+    executable: bool,
+}
+
+/// A key preceded by its account's original data length, which `resize`
+/// reads to bound how far the data may grow
+#[repr(C)]
+struct KeySlot {
+    original_data_len: u32,
+    key: Pubkey,
+}
+
+/// An account laid out as the runtime serializes it for a program: the
+/// current data length just before the data, and room after it to grow into,
+/// so `resize` and `close` work in place
+struct Serialized {
+    key: KeySlot,
+    lamports: u64,
+    owner: Pubkey,
+    executable: bool,
+    /// The data length, then the data and its growth room
+    buffer: Vec<u64>,
+}
+
+impl Serialized {
+    fn new(key: Pubkey, stored: Stored) -> Self {
+        let len = stored.data.len();
+        let mut buffer = vec![0u64; 1 + (len + MAX_PERMITTED_DATA_INCREASE).div_ceil(8)];
+        buffer[0] = len as u64;
+        bytemuck::cast_slice_mut::<u64, u8>(&mut buffer[1..])[..len].copy_from_slice(&stored.data);
+        Self {
+            key: KeySlot { original_data_len: len as u32, key },
+            lamports: stored.lamports,
+            owner: stored.owner,
+            executable: stored.executable,
+            buffer,
+        }
+    }
+
+    fn info(&mut self, signer: bool, writable: bool) -> AccountInfo<'_> {
+        let len = self.buffer[0] as usize;
+        let data = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut self.buffer[1..])[..len];
+        AccountInfo::new(
+            &self.key.key,
+            signer,
+            writable,
+            &mut self.lamports,
+            data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+
+    fn into_stored(self) -> Stored {
+        let len = self.buffer[0] as usize;
+        Stored {
+            lamports: self.lamports,
+            data: bytemuck::cast_slice::<u64, u8>(&self.buffer[1..])[..len].to_vec(),
+            // `assign` writes the owner through a shared reference
+            owner: unsafe { std::ptr::read_volatile(&self.owner) },
+            executable: self.executable,
+        }
+    }
+}
+
+/// The accounts instructions run against
+#[derive(Default)]
+pub struct World {
+    accounts: HashMap<Pubkey, Stored>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut world = Self::default();
+        for program in [PROGRAM_ID, system_program::ID, anchor_spl::token::ID, anchor_spl::associated_token::ID] {
+            world.accounts.insert(
+                program,
+                Stored { lamports: 1, data: Vec::new(), owner: Pubkey::default(), executable: true },
+            );
+        }
+        world
+    }
+
+    /// A funded system-owned wallet
+    pub fn wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.accounts.insert(
+            key,
+            Stored { lamports: WALLET_LAMPORTS, data: Vec::new(), owner: system_program::ID, executable: false },
+        );
+        key
+    }
+
+    /// An executable account standing in for another deployed program
+    pub fn program(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.accounts
+            .insert(key, Stored { lamports: 1, data: Vec::new(), owner: Pubkey::default(), executable: true });
+        key
+    }
+
+    /// Store a program account, discriminator included
+    pub fn set<T: AccountSerialize>(&mut self, key: Pubkey, account: &T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        self.set_data(key, PROGRAM_ID, data);
+    }
+
+    /// Store a program account padded to `space`, as allocated on chain, so
+    /// handlers can grow its variable-length fields
+    pub fn set_sized<T: AccountSerialize>(&mut self, key: Pubkey, account: &T, space: usize) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        self.set_data(key, PROGRAM_ID, data);
+    }
+
+    /// Store a zero-copy program account, discriminator included
+    pub fn set_zero_copy<T: ZeroCopy + Discriminator>(&mut self, key: Pubkey, account: &T) {
+        let data = [T::DISCRIMINATOR, bytemuck::bytes_of(account)].concat();
+        self.set_data(key, PROGRAM_ID, data);
+    }
+
+    /// Store an initialized SPL token mint
+    pub fn set_mint(&mut self, key: Pubkey) {
+        let mint = spl_token::state::Mint { is_initialized: true, ..Default::default() };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        mint.pack_into_slice(&mut data);
+        self.set_data(key, token::ID, data);
+    }
+
+    /// Store an initialized SPL token account of `mint` held by `owner`
+    pub fn set_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey) {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        account.pack_into_slice(&mut data);
+        self.set_data(key, token::ID, data);
+    }
+
+    /// Overwrite the balance of a stored SPL token account
+    pub fn set_token_balance(&mut self, key: Pubkey, amount: u64) {
+        let data = &mut self.accounts.get_mut(&key).unwrap().data;
+        let mut account = spl_token::state::Account::unpack(data).unwrap();
+        account.amount = amount;
+        account.pack_into_slice(data);
+    }
+
+    /// Freeze a stored SPL token account, as a pending payout leaves it
+    pub fn freeze_token_account(&mut self, key: Pubkey) {
+        let data = &mut self.accounts.get_mut(&key).unwrap().data;
+        let mut account = spl_token::state::Account::unpack(data).unwrap();
+        account.state = spl_token::state::AccountState::Frozen;
+        account.pack_into_slice(data);
+    }
+
+    /// Stage the account an `init` creates: CPIs do nothing here, so Anchor
+    /// goes on to accept a zeroed program account of the right size
+    pub fn preallocate(&mut self, key: Pubkey, space: usize) {
+        self.set_data(key, PROGRAM_ID, vec![0; space]);
+    }
+
+    pub fn set_data(&mut self, key: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        self.accounts.insert(key, Stored { lamports: WALLET_LAMPORTS, data, owner, executable: false });
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts[key].lamports
+    }
+
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        T::try_deserialize(&mut &self.accounts[key].data[..]).unwrap()
+    }
+
+    pub fn token_account(&self, key: &Pubkey) -> spl_token::state::Account {
+        spl_token::state::Account::unpack(&self.accounts[key].data).unwrap()
+    }
+
+    pub fn get_zero_copy<T: ZeroCopy>(&self, key: &Pubkey) -> T {
+        let data = &self.accounts[key].data[8..];
+        bytemuck::pod_read_unaligned(&data[..std::mem::size_of::<T>()])
+    }
+
+    /// Rewrite a stored program account in place, keeping its allocated size
+    pub fn update<T: AccountSerialize + AccountDeserialize>(&mut self, key: Pubkey, f: impl FnOnce(&mut T)) {
+        let mut account = self.get(&key);
+        f(&mut account);
+        let space = self.accounts[&key].data.len();
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(data.len().max(space), 0);
+        self.set_data(key, PROGRAM_ID, data);
+    }
+
+    /// Rewrite a stored zero-copy program account in place
+    pub fn update_zero_copy<T: ZeroCopy + Discriminator>(&mut self, key: Pubkey, f: impl FnOnce(&mut T)) {
+        let mut account = self.get_zero_copy(&key);
+        f(&mut account);
+        self.set_zero_copy(key, &account);
+    }
+
+    /// Store a copy of the account at `from` under `to`
+    pub fn copy(&mut self, from: &Pubkey, to: Pubkey) {
+        let account = self.accounts[from].clone();
+        self.accounts.insert(to, account);
+    }
+
+    /// Lay out the instructions sysvar for a transaction of `instructions`,
+    /// currently running the one at `current`
+    pub fn set_transaction(&mut self, instructions: &[Instruction], current: u16) {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|ix| BorrowedInstruction {
+                program_id: &ix.program_id,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: &ix.data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index_checked(&mut data, current).unwrap();
+        self.set_data(sysvar::instructions::ID, sysvar::ID, data);
+    }
+
+    /// Run `ix` with the signers and writability its metas declare, keeping
+    /// the account changes only when it succeeds
+    pub fn process(&mut self, ix: &Instruction) -> Result<(), ProgramError> {
+        let mut keys: Vec<Pubkey> = Vec::new();
+        for meta in &ix.accounts {
+            if !keys.contains(&meta.pubkey) {
+                keys.push(meta.pubkey);
+            }
+        }
+        // Accounts the world has never seen don't exist yet: empty and system-owned
+        let mut serialized: Vec<Serialized> = keys
+            .iter()
+            .map(|key| {
+                let stored = self.accounts.get(key).cloned().unwrap_or(Stored {
+                    lamports: 0,
+                    data: Vec::new(),
+                    owner: system_program::ID,
+                    executable: false,
+                });
+                Serialized::new(*key, stored)
+            })
+            .collect();
+
+        let result = {
+            let unique: Vec<AccountInfo> = serialized
+                .iter_mut()
+                .map(|account| {
+                    let key = account.key.key;
+                    let signer = ix.accounts.iter().any(|meta| meta.pubkey == key && meta.is_signer);
+                    let writable = ix.accounts.iter().any(|meta| meta.pubkey == key && meta.is_writable);
+                    account.info(signer, writable)
+                })
+                .collect();
+            let infos: Vec<AccountInfo> = ix
+                .accounts
+                .iter()
+                .map(|meta| unique[keys.iter().position(|key| *key == meta.pubkey).unwrap()].clone())
+                .collect();
+            siglab_contract::entry(&ix.program_id, &infos, &ix.data)
+        };
+
+        if result.is_ok() {
+            for (key, account) in keys.into_iter().zip(serialized) {
+                self.accounts.insert(key, account.into_stored());
+            }
+        }
+        result
+    }
+
+    /// Run `instructions` as one transaction, each seeing the changes of
+    /// those before it, keeping the changes only when all succeed
+    pub fn process_transaction(&mut self, instructions: &[Instruction]) -> Result<(), ProgramError> {
+        let before = self.accounts.clone();
+        for (current, ix) in instructions.iter().enumerate() {
+            self.set_transaction(instructions, current as u16);
+            if let Err(error) = self.process(ix) {
+                self.accounts = before;
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `ix` as if another program invoked it, its signers signing
+    /// through that program's `invoke_signed`
+    pub fn process_via_cpi(&mut self, ix: &Instruction) -> Result<(), ProgramError> {
+        STACK_HEIGHT.with(|height| height.set(2));
+        let result = self.process(ix);
+        STACK_HEIGHT.with(|height| height.set(1));
+        result
+    }
+
+    /// Run the view instruction `ix` and return its return data
+    #[track_caller]
+    pub fn view(&mut self, ix: &Instruction) -> Vec<u8> {
+        RETURN_DATA.with(|returned| returned.borrow_mut().clear());
+        self.process(ix).unwrap();
+        RETURN_DATA.with(|returned| returned.take())
+    }
+
+    /// Run `ix` and check it fails with exactly `expected`
+    #[track_caller]
+    pub fn expect_error(&mut self, ix: &Instruction, expected: impl Into<u32>) {
+        let expected = expected.into();
+        assert_eq!(self.process(ix), Err(ProgramError::Custom(expected)), "expected error {expected}");
+    }
+}
+
+/// `ix` with every meta for `from` pointing at `to` instead
+pub fn redirect(mut ix: Instruction, from: &Pubkey, to: &Pubkey) -> Instruction {
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == *from) {
+        meta.pubkey = *to;
+    }
+    ix
+}
+
+/// A program account with every field zeroed, to be filled in by fixtures
+pub fn blank<T: AccountDeserialize + Discriminator>() -> T {
+    let data = [T::DISCRIMINATOR, &[0; 16_384]].concat();
+    T::try_deserialize(&mut &data[..]).unwrap()
+}
@@ -0,0 +1,14 @@
+//! Negative tests for the account constraints guarding each instruction:
+//! the wrong signer, a PDA derived from the wrong seeds, an account in the
+//! wrong state and a spoofed program, each rejected with its exact error
+//! code.
+
+mod fixtures;
+mod harness;
+
+mod admin;
+mod oracle;
+mod payout;
+mod policy;
+mod programs;
+mod treasury;
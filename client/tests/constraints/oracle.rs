@@ -0,0 +1,584 @@
+use anchor_lang::error::ErrorCode::ConstraintSeeds;
+use anchor_lang::prelude::{ProgramError, Pubkey};
+use anchor_lang::system_program;
+use bytemuck::Zeroable;
+use siglab_contract::constants::{OBSERVATION_PROOF_RETENTION, ORACLE_RESIGNATION_COOLDOWN};
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{
+    ConsensusSnapshot, DataProvider, EndorsementKind, MasterInsuranceContract, ObservationProof, Oracle,
+    OracleData, OracleDirectory, OracleLookup, OracleObservation, OracleRetirement, OracleType, Policy,
+    PolicyHistory, PolicyStatus, RetirementPlan, SignatureScheme,
+};
+use siglab_contract_client::{instructions, pda};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{Env, ORACLE_ID};
+use crate::harness::{blank, redirect, NOW};
+
+fn reading() -> OracleData {
+    OracleData {
+        version: 0x10,
+        value: 42,
+        timestamp: NOW,
+        confidence: 95,
+        signature: [1; 64],
+        nonce: 1,
+        extensions: Vec::new(),
+    }
+}
+
+#[test]
+fn approve_oracle() {
+    let mut env = Env::new();
+    env.world.expect_error(&instructions::approve_oracle(&env.admin, ORACLE_ID), InsuranceError::OracleNotPending);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_pending(true));
+    env.world.expect_error(&instructions::approve_oracle(&env.intruder, ORACLE_ID), InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.oracle);
+    let ix = redirect(instructions::approve_oracle(&env.admin, ORACLE_ID), &env.oracle, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+#[test]
+fn unregister_oracle() {
+    let mut env = Env::new();
+    let retirement = pda::oracle_retirement_pda(&env.oracle).0;
+    env.world.preallocate(retirement, OracleRetirement::space());
+    env.world.expect_error(
+        &instructions::unregister_oracle(&env.intruder, ORACLE_ID, RetirementPlan::SuspendCoverage),
+        InsuranceError::Unauthorized,
+    );
+
+    // A replacement must itself be registered
+    let unregistered = env.world.wallet();
+    env.world.expect_error(
+        &instructions::unregister_oracle(&env.admin, ORACLE_ID, RetirementPlan::Replace(unregistered)),
+        InsuranceError::OracleNotRegistered,
+    );
+
+    // An oracle whose stored id no longer derives its address
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_oracle_id("other-feed"));
+    env.world.expect_error(
+        &instructions::unregister_oracle(&env.admin, ORACLE_ID, RetirementPlan::SuspendCoverage),
+        ConstraintSeeds,
+    );
+}
+
+#[test]
+fn register_oracle_reuses_directory_entry() {
+    let mut env = Env::new();
+    let other = pda::oracle_pda("wind-feed").0;
+    let mut master: MasterInsuranceContract = env.world.get(&env.master);
+    master.oracle_registry = vec![other];
+    env.world.set_sized(env.master, &master, MasterInsuranceContract::space());
+    env.world.update_zero_copy(env.oracle_directory, |directory: &mut OracleDirectory| {
+        directory.record_unregistration(&env.oracle);
+        directory.record_registration("wind-feed", other, env.oracle_authority).unwrap();
+    });
+    env.world.copy(&env.oracle, other);
+    env.world.preallocate(env.oracle, Oracle::space());
+    let authority = env.world.wallet();
+    let register = |admin| {
+        instructions::register_oracle(
+            admin,
+            &authority,
+            &[other],
+            ORACLE_ID,
+            OracleType::Pyth,
+            String::new(),
+            SignatureScheme::Ed25519,
+            Vec::new(),
+        )
+    };
+    env.world.expect_error(&register(&env.intruder), InsuranceError::Unauthorized);
+
+    // Registering the id again takes back its old slot
+    env.world.process(&register(&env.admin)).unwrap();
+    let directory: OracleDirectory = env.world.get_zero_copy(&env.oracle_directory);
+    let entry = directory.entries[0];
+    assert_eq!(entry.id_hash, OracleDirectory::id_hash(ORACLE_ID));
+    assert_eq!(entry.oracle, env.oracle);
+    assert_eq!(entry.authority, authority);
+    assert!(entry.is_registered());
+    assert_eq!(directory.entries[1].oracle, other);
+    assert_eq!(directory.entries[2], Default::default());
+}
+
+#[test]
+fn register_oracle_at_capacity() {
+    let mut env = Env::new();
+    let mut master: MasterInsuranceContract = env.world.get(&env.master);
+    master.max_oracles = 2;
+    env.world.set_sized(env.master, &master, MasterInsuranceContract::space());
+    let [wind, flood] = ["wind-feed", "flood-feed"].map(|oracle_id| pda::oracle_pda(oracle_id).0);
+    for oracle in [wind, flood] {
+        env.world.preallocate(oracle, Oracle::space());
+    }
+    let authority = env.world.wallet();
+    let register = |oracle_id, registered: &[Pubkey]| {
+        instructions::register_oracle(
+            &env.admin,
+            &authority,
+            registered,
+            oracle_id,
+            OracleType::Pyth,
+            String::new(),
+            SignatureScheme::Ed25519,
+            Vec::new(),
+        )
+    };
+
+    // Two registrations racing for the last slot in one transaction: the
+    // second sees the first's, and neither is kept
+    let race = [register("wind-feed", &[env.oracle]), register("flood-feed", &[env.oracle, wind])];
+    assert_eq!(
+        env.world.process_transaction(&race),
+        Err(ProgramError::Custom(InsuranceError::MaxOraclesExceeded.into()))
+    );
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).oracle_registry, vec![env.oracle]);
+
+    // Every registered oracle is passed, and must still exist
+    env.world.expect_error(&register("wind-feed", &[]), InsuranceError::OracleNotRegistered);
+    env.world.set_data(env.oracle, system_program::ID, Vec::new());
+    env.world.expect_error(&register("wind-feed", &[env.oracle]), InsuranceError::OracleRegistryStale);
+
+    env.world.process(&instructions::sync_oracle_registry(&env.admin, &[env.oracle])).unwrap();
+    env.world.process(&register("wind-feed", &[])).unwrap();
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).oracle_registry, vec![wind]);
+}
+
+#[test]
+fn initialize_oracle_directory() {
+    let mut env = Env::new();
+    env.world.preallocate(env.oracle_directory, OracleDirectory::space());
+    let ix = instructions::initialize_oracle_directory(&env.intruder, &[env.oracle]);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    // Every registered oracle must be passed to fill the directory
+    let ix = instructions::initialize_oracle_directory(&env.admin, &[]);
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+    let ix = instructions::initialize_oracle_directory(&env.admin, &[env.policy]);
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+
+    env.world.process(&instructions::initialize_oracle_directory(&env.admin, &[env.oracle])).unwrap();
+    let directory: OracleDirectory = env.world.get_zero_copy(&env.oracle_directory);
+    assert_eq!(directory.bump, pda::oracle_directory_pda().1);
+    let entry = directory.find_by_id(ORACLE_ID).unwrap();
+    assert_eq!((entry.oracle, entry.authority), (env.oracle, env.oracle_authority));
+    assert!(entry.is_registered());
+}
+
+#[test]
+fn resolve_oracle() {
+    let mut env = Env::new();
+    env.world.process(&instructions::resolve_oracle(OracleLookup::Id(ORACLE_ID.into()))).unwrap();
+    env.world.process(&instructions::resolve_oracle(OracleLookup::Address(env.oracle))).unwrap();
+
+    let ix = instructions::resolve_oracle(OracleLookup::Id("wind-feed".into()));
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+    let ix = instructions::resolve_oracle(OracleLookup::Address(env.policy));
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+
+    let elsewhere = env.misplace(env.oracle_directory);
+    let ix = redirect(
+        instructions::resolve_oracle(OracleLookup::Address(env.oracle)),
+        &env.oracle_directory,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+#[test]
+fn repoint_policies() {
+    let mut env = Env::new();
+    let retired = env.oracle;
+    let (retirement, bump) = pda::oracle_retirement_pda(&retired);
+    let mut state: OracleRetirement = blank();
+    state.oracle = retired;
+    state.plan = RetirementPlan::SuspendCoverage;
+    state.bump = bump;
+    env.world.set_sized(retirement, &state, OracleRetirement::space());
+    let mut policy: Policy = env.world.get(&env.policy);
+    policy.oracle_config.oracle_address = retired;
+    env.world.set_sized(env.policy, &policy, Policy::space());
+
+    // Each policy must come with its history
+    let unpaired = instructions::repoint_policies(&env.intruder, &retired, &[], &[env.policy]);
+    env.world.expect_error(&unpaired, InsuranceError::InvalidInput);
+
+    // Without a replacement the crank suspends coverage, pausing expiry
+    let crank = instructions::repoint_policies(&env.intruder, &retired, &[env.policy], &[]);
+    env.world.process(&crank).unwrap();
+    assert_eq!(env.world.get::<Policy>(&env.policy).coverage_suspended_at, Some(NOW));
+    assert_eq!(env.world.get::<OracleRetirement>(&retirement).policies_suspended, 1);
+    env.world.expect_error(
+        &instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, None),
+        InsuranceError::CoverageSuspended,
+    );
+
+    let replacement = env.world.wallet();
+    let ix = instructions::set_oracle_replacement(&env.intruder, &retired, replacement);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    let ix = instructions::set_oracle_replacement(&env.admin, &retired, replacement);
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.oracle_registry.push(replacement));
+    env.world.process(&ix).unwrap();
+
+    // Re-pointing resumes coverage and gives back the suspended time
+    let mut policy: Policy = env.world.get(&env.policy);
+    policy.coverage_suspended_at = Some(NOW - 3_600);
+    env.world.set_sized(env.policy, &policy, Policy::space());
+    env.world.process(&crank).unwrap();
+    let policy: Policy = env.world.get(&env.policy);
+    assert_eq!(policy.oracle_config.oracle_address, replacement);
+    assert_eq!(policy.coverage_suspended_at, None);
+    assert_eq!(policy.end_date, NOW + 30 * 86_400 + 3_600);
+    let history: PolicyHistory = env.world.get(&pda::policy_history_pda(&env.policy).0);
+    assert_eq!(history.endorsements.last().unwrap().kind, EndorsementKind::OracleRepoint);
+    assert_eq!(env.world.get::<OracleRetirement>(&retirement).policies_repointed, 1);
+
+    // Policies already re-pointed are skipped
+    env.world.process(&crank).unwrap();
+    assert_eq!(env.world.get::<PolicyHistory>(&pda::policy_history_pda(&env.policy).0).endorsements.len(), 1);
+}
+
+#[test]
+fn record_oracle_outage() {
+    let mut env = Env::new();
+    let mut policy: Policy = env.world.get(&env.policy);
+    policy.oracle_config.oracle_address = env.oracle;
+    env.world.set_sized(env.policy, &policy, Policy::space());
+    let elsewhere = env.misplace(env.oracle);
+    let ix = instructions::record_oracle_outage(&env.intruder, &env.policy, &elsewhere);
+    env.world.expect_error(&ix, InsuranceError::PolicyOracleMismatch);
+
+    // A silent oracle counts as down since it timed out, from the start of the term
+    let crank = instructions::record_oracle_outage(&env.intruder, &env.policy, &env.oracle);
+    env.world.process(&crank).unwrap();
+    let policy: Policy = env.world.get(&env.policy);
+    assert_eq!((policy.oracle_outage_secs, policy.oracle_outage_since), (86_400, Some(NOW)));
+
+    // Once it reports again the outage closes, keeping the downtime counted
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.last_update_timestamp = NOW);
+    env.world.process(&crank).unwrap();
+    let policy: Policy = env.world.get(&env.policy);
+    assert_eq!((policy.oracle_outage_secs, policy.oracle_outage_since), (86_400, None));
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Expired);
+    env.world.expect_error(&crank, InsuranceError::PolicyNotActive);
+}
+
+#[test]
+fn update_oracle_data() {
+    let mut env = Env::new();
+    let ix = instructions::update_oracle_data(&env.intruder, ORACLE_ID, reading());
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.oracle);
+    let ix = redirect(
+        instructions::update_oracle_data(&env.oracle_authority, ORACLE_ID, reading()),
+        &env.oracle,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_active(false));
+    let ix = instructions::update_oracle_data(&env.oracle_authority, ORACLE_ID, reading());
+    env.world.expect_error(&ix, InsuranceError::OracleInactive);
+}
+
+#[test]
+fn update_oracle_data_attestation() {
+    let mut env = Env::new();
+    let authority = Keypair::new();
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.authority = authority.pubkey());
+
+    // A second oracle run by the same authority
+    let other_id = "other-feed";
+    let (other, other_bump) = pda::oracle_pda(other_id);
+    env.world.copy(&env.oracle, other);
+    env.world.update_zero_copy(other, |oracle: &mut Oracle| {
+        oracle.set_oracle_id(other_id);
+        oracle.bump = other_bump;
+    });
+
+    // No attestation at all
+    let update = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, reading());
+    env.world.set_transaction(std::slice::from_ref(&update), 0);
+    env.world.expect_error(&update, InsuranceError::MissingEd25519Instruction);
+
+    // A signature valid for the first oracle is no good for the second
+    let mut data = reading();
+    let attestation = instructions::attest_oracle_data(&authority, ORACLE_ID, &mut data);
+    let replay = instructions::update_oracle_data(&authority.pubkey(), other_id, data.clone());
+    env.world.set_transaction(&[attestation.clone(), replay.clone()], 1);
+    env.world.expect_error(&replay, InsuranceError::Ed25519AttestationMismatch);
+
+    let update = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, data);
+    env.world.set_transaction(&[attestation, update.clone()], 1);
+    env.world.process(&update).unwrap();
+    assert_eq!(env.world.get_zero_copy::<Oracle>(&env.oracle).last_nonce, 1);
+}
+
+#[test]
+fn update_oracle_data_timestamp() {
+    let mut env = Env::new();
+    let authority = Keypair::new();
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.authority = authority.pubkey());
+    let skew = env.world.get::<MasterInsuranceContract>(&env.master).oracle_risk_params.max_clock_skew_secs as i64;
+
+    let dated = |timestamp: i64| OracleData { timestamp, ..reading() };
+    let ix = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, dated(0));
+    env.world.expect_error(&ix, InsuranceError::OracleTimestampMissing);
+    let ix = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, dated(NOW + skew + 1));
+    env.world.expect_error(&ix, InsuranceError::OracleTimestampInFuture);
+
+    // Data at the edge of the allowed skew is accepted
+    let mut data = dated(NOW + skew);
+    let attestation = instructions::attest_oracle_data(&authority, ORACLE_ID, &mut data);
+    let update = instructions::update_oracle_data(&authority.pubkey(), ORACLE_ID, data);
+    env.world.set_transaction(&[attestation, update.clone()], 1);
+    env.world.process(&update).unwrap();
+    assert_eq!(env.world.get_zero_copy::<Oracle>(&env.oracle).latest_data().unwrap().timestamp, NOW + skew);
+}
+
+#[test]
+fn pause_oracle_self() {
+    let mut env = Env::new();
+    let ix = instructions::pause_oracle_self(&env.intruder, ORACLE_ID, 3_600);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(
+        instructions::pause_oracle_self(&env.oracle_authority, ORACLE_ID, 3_600),
+        &env.master,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_active(false));
+    let ix = instructions::pause_oracle_self(&env.oracle_authority, ORACLE_ID, 3_600);
+    env.world.expect_error(&ix, InsuranceError::OracleInactive);
+}
+
+#[test]
+fn resign_oracle() {
+    let mut env = Env::new();
+    let ix = instructions::resign_oracle(&env.intruder, ORACLE_ID);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    // A recent reporter waits out the cooldown so claims on its readings settle
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.last_update_timestamp = NOW - 60);
+    let ix = instructions::resign_oracle(&env.oracle_authority, ORACLE_ID);
+    env.world.process(&ix).unwrap();
+    let oracle: Oracle = env.world.get_zero_copy(&env.oracle);
+    assert!(!oracle.is_active());
+    assert_eq!(oracle.retires_at, NOW + ORACLE_RESIGNATION_COOLDOWN);
+    env.world.expect_error(&ix, InsuranceError::OracleResigning);
+    let reactivate = instructions::update_oracle_status(&env.admin, ORACLE_ID, true);
+    env.world.expect_error(&reactivate, InsuranceError::OracleResigning);
+
+    // An oracle silent for the whole cooldown leaves at once
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.retires_at = 0;
+        oracle.last_update_timestamp = NOW - ORACLE_RESIGNATION_COOLDOWN;
+    });
+    env.world.process(&ix).unwrap();
+    assert_eq!(env.world.get_zero_copy::<Oracle>(&env.oracle).retires_at, NOW);
+}
+
+#[test]
+fn complete_oracle_resignation() {
+    let mut env = Env::new();
+    let retirement = pda::oracle_retirement_pda(&env.oracle).0;
+    env.world.preallocate(retirement, OracleRetirement::space());
+    let ix = instructions::complete_oracle_resignation(&env.oracle_authority, ORACLE_ID);
+    env.world.expect_error(&ix, InsuranceError::OracleNotResigning);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.retires_at = NOW + 1);
+    let stolen = instructions::complete_oracle_resignation(&env.intruder, ORACLE_ID);
+    env.world.expect_error(&stolen, InsuranceError::Unauthorized);
+    env.world.expect_error(&ix, InsuranceError::OracleResignationCooldown);
+}
+
+#[test]
+fn update_oracle_status() {
+    let mut env = Env::new();
+    let ix = instructions::update_oracle_status(&env.intruder, ORACLE_ID, false);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.oracle);
+    let ix = redirect(instructions::update_oracle_status(&env.admin, ORACLE_ID, false), &env.oracle, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_pending(true));
+    let ix = instructions::update_oracle_status(&env.admin, ORACLE_ID, false);
+    env.world.expect_error(&ix, InsuranceError::OraclePendingApproval);
+}
+
+#[test]
+fn set_oracle_update_interval() {
+    let mut env = Env::new();
+    let ix = instructions::set_oracle_update_interval(&env.intruder, ORACLE_ID, 60);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| oracle.set_pending(true));
+    let ix = instructions::set_oracle_update_interval(&env.admin, ORACLE_ID, 60);
+    env.world.expect_error(&ix, InsuranceError::OraclePendingApproval);
+}
+
+#[test]
+fn observation_proof() {
+    let mut env = Env::new();
+    let proof = pda::observation_proof_pda(&env.oracle, 7).0;
+    env.world.preallocate(proof, ObservationProof::space());
+
+    // An oracle without readings has nothing to prove
+    let snapshot = instructions::snapshot_oracle_observation(&env.intruder, &env.oracle, 7, None);
+    env.world.expect_error(&snapshot, InsuranceError::ObservationNotFound);
+
+    let observation = OracleObservation { value: 42, timestamp: NOW - 60, confidence: 95 };
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.record_observation(observation);
+        oracle.reputation_score = 80;
+    });
+    let ix = instructions::snapshot_oracle_observation(&env.intruder, &env.oracle, 7, Some(NOW));
+    env.world.expect_error(&ix, InsuranceError::ObservationNotFound);
+    let ix = instructions::snapshot_oracle_observation(&env.intruder, &env.oracle, 7, Some(NOW - 60));
+    env.world.process(&ix).unwrap();
+    let state: ObservationProof = env.world.get(&proof);
+    assert_eq!((state.value, state.observed_at, state.confidence), (42, NOW - 60, 95));
+    assert_eq!(state.reputation_score, 80);
+    assert_eq!((state.creator, state.created_at), (env.intruder, NOW));
+
+    // Only its creator may close a proof, and only once it is no longer retained
+    let ix = instructions::close_observation_proof(&env.intruder, &proof);
+    env.world.expect_error(&ix, InsuranceError::ObservationProofRetained);
+    let mut state: ObservationProof = env.world.get(&proof);
+    state.created_at = NOW - OBSERVATION_PROOF_RETENTION;
+    env.world.set(proof, &state);
+    let ix = instructions::close_observation_proof(&env.admin, &proof);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+}
+
+#[test]
+fn register_data_provider() {
+    let mut env = Env::new();
+    let provider = pda::data_provider_pda(7).0;
+    env.world.preallocate(provider, DataProvider::space());
+    let ix = instructions::register_data_provider(&env.intruder, 7, "NOAA".into());
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    for name in [String::new(), "n".repeat(DataProvider::MAX_NAME_LENGTH + 1)] {
+        let ix = instructions::register_data_provider(&env.admin, 7, name);
+        env.world.expect_error(&ix, InsuranceError::InvalidDataProviderName);
+    }
+
+    let ix = instructions::register_data_provider(&env.admin, 7, "NOAA".into());
+    env.world.process(&ix).unwrap();
+    let state: DataProvider = env.world.get(&provider);
+    assert_eq!((state.provider_id, state.name.as_str()), (7, "NOAA"));
+}
+
+#[test]
+fn set_oracle_provider() {
+    let mut env = Env::new();
+    let (provider, bump) = pda::data_provider_pda(7);
+    let mut state: DataProvider = blank();
+    state.provider_id = 7;
+    state.bump = bump;
+    env.world.set(provider, &state);
+
+    let ix = instructions::set_oracle_provider(&env.intruder, ORACLE_ID, Some(7));
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    let ix = instructions::set_oracle_provider(&env.admin, ORACLE_ID, Some(7));
+    let elsewhere = env.misplace(provider);
+    env.world.expect_error(&redirect(ix.clone(), &provider, &elsewhere), ConstraintSeeds);
+
+    env.world.process(&ix).unwrap();
+    assert_eq!(env.world.get_zero_copy::<Oracle>(&env.oracle).provider(), Some(provider));
+    env.world.process(&instructions::set_oracle_provider(&env.admin, ORACLE_ID, None)).unwrap();
+    assert_eq!(env.world.get_zero_copy::<Oracle>(&env.oracle).provider(), None);
+
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.oracle_registry.clear());
+    env.world.expect_error(&ix, InsuranceError::OracleNotRegistered);
+}
+
+#[test]
+fn compute_consensus_counts_each_provider_once() {
+    let mut env = Env::new();
+    let [noaa, ecmwf] = [1u32, 2].map(|provider_id| pda::data_provider_pda(provider_id).0);
+    let oracles =
+        [(ORACLE_ID, 100), ("rainfall-mirror", 100), ("rainfall-backup", 130)].map(|(oracle_id, value)| {
+            let (oracle, bump) = pda::oracle_pda(oracle_id);
+            let mut state = Oracle::zeroed();
+            state.set_oracle_id(oracle_id);
+            state.bump = bump;
+            state.set_active(true);
+            state.set_provider(Some(noaa));
+            state.record_observation(OracleObservation { value, timestamp: NOW, confidence: 95 });
+            env.world.set_zero_copy(oracle, &state);
+            oracle
+        });
+    let mut master: MasterInsuranceContract = env.world.get(&env.master);
+    master.oracle_registry = oracles.to_vec();
+    master.min_consensus_threshold = 2;
+    env.world.set_sized(env.master, &master, MasterInsuranceContract::space());
+    env.world.preallocate(pda::consensus_snapshot_pda(0).0, ConsensusSnapshot::space());
+
+    // Three fresh readings relayed from one provider are one source
+    let ix = instructions::compute_consensus(&env.admin, 0, &oracles);
+    env.world.expect_error(&ix, InsuranceError::ProviderQuorumNotMet);
+
+    // A second provider makes the quorum, and every reading still feeds the aggregate
+    env.world.update_zero_copy(oracles[2], |oracle: &mut Oracle| oracle.set_provider(Some(ecmwf)));
+    env.world.process(&ix).unwrap();
+    let snapshot: ConsensusSnapshot = env.world.get(&pda::consensus_snapshot_pda(0).0);
+    assert_eq!(snapshot.consensus.oracle_count, 3);
+    assert_eq!(snapshot.consensus.aggregated_value, 110);
+}
+
+#[test]
+fn compute_consensus_matches_golden_vectors() {
+    let vectors: serde_json::Value =
+        serde_json::from_str(include_str!("../../../tests/fixtures/consensus_vectors.json")).unwrap();
+    for vector in vectors.as_array().unwrap() {
+        let name = vector["name"].as_str().unwrap();
+        let field = |key: &str| vector[key].as_u64().unwrap();
+        let mut env = Env::new();
+        let oracles: Vec<Pubkey> = vector["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let oracle_id = format!("golden-{index}");
+                let (oracle, bump) = pda::oracle_pda(&oracle_id);
+                let mut state = Oracle::zeroed();
+                state.set_oracle_id(&oracle_id);
+                state.bump = bump;
+                state.set_active(true);
+                state.record_observation(OracleObservation {
+                    value: value.as_u64().unwrap(),
+                    timestamp: NOW,
+                    confidence: 95,
+                });
+                env.world.set_zero_copy(oracle, &state);
+                oracle
+            })
+            .collect();
+        let mut master: MasterInsuranceContract = env.world.get(&env.master);
+        master.oracle_registry = oracles.clone();
+        master.min_consensus_threshold = 1;
+        env.world.set_sized(env.master, &master, MasterInsuranceContract::space());
+        env.world.preallocate(pda::consensus_snapshot_pda(0).0, ConsensusSnapshot::space());
+
+        env.world.process(&instructions::compute_consensus(&env.admin, 0, &oracles)).unwrap();
+        let consensus = env.world.get::<ConsensusSnapshot>(&pda::consensus_snapshot_pda(0).0).consensus;
+        assert_eq!(consensus.oracle_count as u64, field("oracle_count"), "{name}");
+        assert_eq!(consensus.aggregated_value, field("aggregated_value"), "{name}");
+        assert_eq!(consensus.median_value, field("median_value"), "{name}");
+        assert_eq!(consensus.standard_deviation, field("standard_deviation"), "{name}");
+        assert_eq!(consensus.confidence_score as u64, field("confidence_score"), "{name}");
+    }
+}
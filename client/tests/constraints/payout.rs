@@ -0,0 +1,582 @@
+use anchor_lang::error::ErrorCode::ConstraintSeeds;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::prelude::Rent;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token::state::Account as TokenAccount;
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{
+    ClaimVerdict, ComparisonOperator, CompoundTrigger, InsuranceType, InsuranceTypeConfig,
+    MasterInsuranceContract, Oracle, OracleObservation, PayoutRecord, PayoutStatus, PendingPayout, Policy,
+    PolicyHistory, PolicyStatus, SeverityMode, TokenType, Treasury, TriggerConnective, TriggerLeaf, VestingPayout,
+};
+use siglab_contract_client::views::decode_claim_eligibility;
+use siglab_contract_client::{instructions, pda};
+
+use crate::fixtures::{Env, POLICY_ID};
+use crate::harness::{blank, redirect, NOW};
+
+fn set_status(env: &mut Env, status: PayoutStatus) {
+    env.world.update(env.payout, |payout: &mut PendingPayout| payout.status = status);
+}
+
+#[test]
+fn approve_payout() {
+    let mut env = Env::new();
+    env.world.expect_error(&instructions::approve_payout(&env.intruder, POLICY_ID), InsuranceError::Unauthorized);
+
+    for status in [PayoutStatus::Pending, PayoutStatus::Ready, PayoutStatus::Rejected] {
+        set_status(&mut env, status);
+        let ix = instructions::approve_payout(&env.admin, POLICY_ID);
+        env.world.expect_error(&ix, InsuranceError::PayoutConditionsNotMet);
+    }
+}
+
+#[test]
+fn reject_payout() {
+    let mut env = Env::new();
+    let reject = |env: &Env, admin: &Pubkey, reason: &str| {
+        instructions::reject_payout(
+            admin,
+            &env.holder,
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            POLICY_ID,
+            None,
+            reason.into(),
+        )
+    };
+    env.world.expect_error(&reject(&env, &env.intruder, "fraud"), InsuranceError::Unauthorized);
+    env.world.expect_error(&reject(&env, &env.admin, &"x".repeat(129)), InsuranceError::InvalidInput);
+
+    for status in [PayoutStatus::Pending, PayoutStatus::Ready] {
+        set_status(&mut env, status);
+        env.world.expect_error(&reject(&env, &env.admin, "fraud"), InsuranceError::PayoutConditionsNotMet);
+    }
+
+    // A rejected payout returns its earmark and the policy to coverage
+    set_status(&mut env, PayoutStatus::PendingApproval);
+    env.world.update(env.payout, |payout: &mut PendingPayout| payout.amount = 1_000);
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::PendingPayout);
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.reserved_for_payouts = 1_000);
+    let holder_lamports = env.world.lamports(&env.holder);
+    let payout_rent = env.world.lamports(&env.payout);
+    env.world.process(&reject(&env, &env.admin, "fraud")).unwrap();
+
+    assert_eq!(env.world.get::<Policy>(&env.policy).status, PolicyStatus::Active);
+    assert_eq!(env.world.get::<Treasury>(&env.treasury).reserved_for_payouts, 0);
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).open_payout_count, 0);
+    assert_eq!(env.world.lamports(&env.holder), holder_lamports + payout_rent);
+    assert_eq!(env.world.lamports(&env.payout), 0);
+}
+
+/// Anyone may execute a ready payout, so the executor is never the point of failure
+fn execute(env: &Env, beneficiary: &Pubkey, rent_payer: &Pubkey, policy: &Pubkey) -> Instruction {
+    instructions::execute_payout(
+        &env.intruder,
+        beneficiary,
+        rent_payer,
+        policy,
+        &env.treasury,
+        None,
+        false,
+        None,
+        None,
+        POLICY_ID,
+    )
+}
+
+#[test]
+fn execute_payout() {
+    let mut env = Env::new();
+    let ix = execute(&env, &env.holder, &env.holder, &env.policy);
+    env.world.expect_error(&ix, InsuranceError::PayoutConditionsNotMet);
+
+    set_status(&mut env, PayoutStatus::Ready);
+    let ix = execute(&env, &env.intruder, &env.holder, &env.policy);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    let ix = execute(&env, &env.holder, &env.intruder, &env.policy);
+    env.world.expect_error(&ix, InsuranceError::RentPayerMismatch);
+
+    let elsewhere = env.misplace(env.policy);
+    env.world.copy(&pda::policy_history_pda(&env.policy).0, pda::policy_history_pda(&elsewhere).0);
+    let ix = execute(&env, &env.holder, &env.holder, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    // A payout too small to make an empty beneficiary account rent-exempt
+    let empty = Pubkey::new_unique();
+    env.world.update(env.payout, |payout: &mut PendingPayout| {
+        payout.beneficiary = empty;
+        payout.amount = 1_000;
+    });
+    env.world.update(env.policy, |policy: &mut Policy| policy.payout_currency = TokenType::SOL);
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = 1_000);
+    let ix = execute(&env, &empty, &env.holder, &env.policy);
+    env.world.expect_error(&ix, InsuranceError::PayoutBelowRentExemption);
+
+    // A payout that pays the policy out releases all its coverage, from the
+    // total as from its currency
+    env.world.update(env.payout, |payout: &mut PendingPayout| {
+        payout.beneficiary = env.holder;
+        payout.amount = 2_000_000;
+    });
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::PendingPayout;
+        policy.coverage_amount = 5_000_000;
+        policy.max_payout_per_incident = 5_000_000;
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| {
+        treasury.total_sol_balance = 10_000_000;
+        treasury.total_coverage_exposure = 7_000_000;
+        treasury.sol_coverage_exposure = 7_000_000;
+    });
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.active_policies_count = 1);
+    let holder_lamports = env.world.lamports(&env.holder);
+    env.world.process(&execute(&env, &env.holder, &env.holder, &env.policy)).unwrap();
+
+    assert!(env.world.lamports(&env.holder) >= holder_lamports + 2_000_000);
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!((treasury.total_coverage_exposure, treasury.sol_coverage_exposure), (2_000_000, 2_000_000));
+    assert_eq!(env.world.get::<Policy>(&env.policy).status, PolicyStatus::PaidOut);
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).active_policies_count, 0);
+}
+
+#[test]
+fn execute_usdc_payout_withholds_token_account_rent() {
+    let mut env = Env::new();
+    let (mint, treasury_usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+    env.world.set_mint(mint);
+    env.world.set_token_account(treasury_usdc, mint, env.treasury);
+    env.world.set_token_balance(treasury_usdc, 50_000_000);
+    env.world.update(env.treasury, |treasury: &mut Treasury| {
+        treasury.usdc_mint = mint;
+        treasury.usdc_token_account = treasury_usdc;
+        treasury.usdc_decimals = 6;
+        treasury.total_usdc_balance = 50_000_000;
+        treasury.total_sol_balance = 10_000_000;
+    });
+    // SOL at $150
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.record_observation(OracleObservation { value: 150_000_000, timestamp: NOW, confidence: 0 });
+        oracle.last_update_timestamp = NOW;
+    });
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| {
+        master.global_config.price_oracle = env.oracle;
+    });
+    set_status(&mut env, PayoutStatus::Ready);
+    env.world.update(env.payout, |payout: &mut PendingPayout| payout.amount = 10_000_000);
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::PendingPayout;
+        policy.payout_currency = TokenType::USDC;
+        policy.coverage_amount = 10_000_000;
+        policy.max_payout_per_incident = 10_000_000;
+    });
+    let history = pda::policy_history_pda(&env.policy).0;
+    env.world.update(history, |history: &mut PolicyHistory| {
+        history.record_payout(PayoutRecord {
+            amount: 10_000_000,
+            timestamp: NOW,
+            transaction_id: String::new(),
+            oracle_data: String::new(),
+            incident_timestamp: NOW,
+            incident_fingerprint: [0; 32],
+            destination_rent: 0,
+            net_amount: 0,
+        })
+    });
+    let ix = instructions::execute_payout(
+        &env.intruder,
+        &env.holder,
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        Some((&treasury_usdc, &mint)),
+        false,
+        None,
+        None,
+        POLICY_ID,
+    );
+
+    // Pricing the new token account's rent takes the SOL/USD price
+    env.world.expect_error(&ix, InsuranceError::PriceUnavailable);
+
+    let executor_lamports = env.world.lamports(&env.intruder);
+    env.world.process(&instructions::with_price_oracle(ix, &env.oracle)).unwrap();
+
+    // The executor is made whole and the rent's $0.305892 stays in the treasury
+    let rent = Rent::default().minimum_balance(TokenAccount::LEN);
+    let net = 10_000_000 - 305_892;
+    let beneficiary_usdc = get_associated_token_address(&env.holder, &mint);
+    assert_eq!(env.world.lamports(&env.intruder), executor_lamports);
+    assert_eq!(env.world.token_account(&beneficiary_usdc).amount, net);
+    assert_eq!(env.world.token_account(&treasury_usdc).amount, 50_000_000 - net);
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!((treasury.total_usdc_balance, treasury.total_sol_balance), (50_000_000 - net, 10_000_000 - rent));
+    let record = env.world.get::<PolicyHistory>(&history).payout_history.pop().unwrap();
+    assert_eq!((record.destination_rent, record.net_amount), (rent, net));
+}
+
+#[test]
+fn execute_payout_keeps_multi_incident_policy_active() {
+    let mut env = Env::new();
+    set_status(&mut env, PayoutStatus::Ready);
+    env.world.update(env.payout, |payout: &mut PendingPayout| payout.amount = 2_000_000);
+    let mint = Pubkey::new_unique();
+    let token_account = get_associated_token_address(&env.holder, &mint);
+    env.world.set_mint(mint);
+    env.world.set_token_account(token_account, mint, env.holder);
+    env.world.set_token_balance(token_account, 1);
+    env.world.freeze_token_account(token_account);
+    // Capped below its coverage, the policy covers further incidents
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.policy_mint = Some(mint);
+        policy.status = PolicyStatus::PendingPayout;
+        policy.payout_currency = TokenType::SOL;
+        policy.coverage_amount = 5_000_000;
+        policy.max_payout_per_incident = 2_500_000;
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| {
+        treasury.total_sol_balance = 10_000_000;
+        treasury.total_coverage_exposure = 7_000_000;
+        treasury.sol_coverage_exposure = 7_000_000;
+    });
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.active_policies_count = 1);
+    env.world.expect_error(
+        &execute(&env, &env.holder, &env.holder, &env.policy),
+        InsuranceError::PolicyTokenAccountRequired,
+    );
+    let ix = instructions::execute_payout(
+        &env.intruder,
+        &env.holder,
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        None,
+        false,
+        None,
+        Some(&mint),
+        POLICY_ID,
+    );
+    env.world.process(&ix).unwrap();
+
+    // Only the coverage the payout consumed leaves the ledger, and the
+    // policy token is free for the next claim
+    assert!(!env.world.token_account(&token_account).is_frozen());
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!((treasury.total_coverage_exposure, treasury.sol_coverage_exposure), (5_000_000, 5_000_000));
+    let policy: Policy = env.world.get(&env.policy);
+    assert_eq!((policy.status, policy.coverage_amount), (PolicyStatus::Active, 3_000_000));
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).active_policies_count, 1);
+}
+
+#[test]
+fn keeper_trigger_payout() {
+    let mut env = Env::new();
+    let keeper = env.world.wallet();
+    // A second policy of the holder, with no payout open yet
+    let (policy, bump) = pda::policy_pda(&env.holder, 1);
+    env.world.copy(&env.policy, policy);
+    env.world.update(policy, |state: &mut Policy| {
+        state.id = POLICY_ID + 1;
+        state.index = 1;
+        state.bump = bump;
+    });
+    let (history, bump) = pda::policy_history_pda(&policy);
+    let mut state: PolicyHistory = env.world.get(&pda::policy_history_pda(&env.policy).0);
+    state.policy = policy;
+    state.bump = bump;
+    env.world.set_sized(history, &state, PolicyHistory::space());
+    let (type_config, bump) = pda::type_config_pda(&InsuranceType::Weather);
+    let mut state: InsuranceTypeConfig = blank();
+    state.bump = bump;
+    env.world.set(type_config, &state);
+    env.world.preallocate(pda::pending_payout_pda(POLICY_ID + 1).0, PendingPayout::space());
+
+    let trigger = |env: &Env, consensus_round: Option<u64>| {
+        let ix = instructions::trigger_payout(
+            &env.holder,
+            &keeper,
+            &policy,
+            &env.treasury,
+            POLICY_ID + 1,
+            &InsuranceType::Weather,
+            None,
+            false,
+            &[],
+            75,
+            0,
+            consensus_round,
+            None,
+            None,
+            NOW,
+        );
+        instructions::filed_by_keeper(ix, &env.holder)
+    };
+    env.world.expect_error(&trigger(&env, None), InsuranceError::AutoClaimNotEnabled);
+
+    env.world.update(policy, |state: &mut Policy| {
+        state.auto_claim = true;
+        state.claim_keeper = env.intruder;
+    });
+    env.world.expect_error(&trigger(&env, None), InsuranceError::Unauthorized);
+
+    // Any keeper may file, but not on a value of its own
+    env.world.update(policy, |state: &mut Policy| state.claim_keeper = Pubkey::default());
+    env.world.expect_error(&trigger(&env, None), InsuranceError::Unauthorized);
+
+    // A compound trigger reads its own oracles, here missing
+    let mut state: Policy = env.world.get(&policy);
+    state.trigger_conditions.compound = Some(CompoundTrigger {
+        connective: TriggerConnective::All,
+        leaves: vec![TriggerLeaf {
+            feed_id: "rainfall-mm".into(),
+            operator: ComparisonOperator::GreaterThan,
+            threshold: 50.0,
+        }],
+        severity_mode: SeverityMode::Worst,
+    });
+    env.world.set_sized(policy, &state, Policy::space());
+    env.world.expect_error(&trigger(&env, None), InsuranceError::OracleNotRegistered);
+
+    // Nothing is filed while the contract is paused
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.is_paused = true);
+    env.world.expect_error(&trigger(&env, None), InsuranceError::ContractPaused);
+}
+
+#[test]
+fn check_claim_eligibility() {
+    let mut env = Env::new();
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.set_data_feed_address("rainfall-mm");
+        oracle.record_observation(OracleObservation { value: 80, timestamp: NOW, confidence: 0 });
+        oracle.last_update_timestamp = NOW;
+    });
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.oracle_config.data_feed_id = "rainfall-mm".into();
+        policy.oracle_config.staleness_threshold = 3_600;
+        policy.trigger_conditions.comparison_operator = ComparisonOperator::GreaterThan;
+        policy.trigger_conditions.threshold_value = 50.0;
+        policy.coverage_amount = 1_000;
+        policy.max_payout_per_incident = 1_000;
+    });
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| {
+        master.global_config.max_open_payouts = 10;
+        master.consensus_timeout_secs = 3_600;
+    });
+    let ix = instructions::check_claim_eligibility(&env.policy, &env.treasury, false, &[env.oracle]);
+    let verdict = |env: &mut Env| decode_claim_eligibility(&env.world.view(&ix)).unwrap();
+    let verdict_of = |env: &mut Env| verdict(env).verdict;
+
+    // The trigger is assessed against the latest reading of the policy's feed
+    let missing = instructions::check_claim_eligibility(&env.policy, &env.treasury, false, &[]);
+    env.world.expect_error(&missing, InsuranceError::OracleNotRegistered);
+
+    let view = verdict(&mut env);
+    assert_eq!(view.verdict, ClaimVerdict::TreasuryInsolvent);
+    assert_eq!(view.oracle_value, Some(80));
+    let amount = view.payout_amount;
+    assert!(amount > 0);
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = amount);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::Eligible);
+
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.open_payout_count = 10);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::PayoutQueueFull);
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.open_payout_count = 1);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.trigger_conditions.threshold_value = 90.0);
+    let view = verdict(&mut env);
+    assert_eq!((view.verdict, view.oracle_value, view.payout_amount), (ClaimVerdict::TriggerNotMet, Some(80), 0));
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.waiting_period_hours = 48);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::WaitingPeriod);
+
+    // A reading older than the policy's staleness threshold, or an oracle past
+    // the consensus timeout, is stale
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.record_observation(OracleObservation { value: 80, timestamp: NOW - 7_200, confidence: 0 })
+    });
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::OracleStale);
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.record_observation(OracleObservation { value: 80, timestamp: NOW, confidence: 0 });
+        oracle.last_update_timestamp = NOW - 7_200;
+    });
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::OracleStale);
+
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.health_metrics.set_circuit_breaker_active(true)
+    });
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::CircuitBreaker);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.end_date = NOW);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::PolicyExpired);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Cancelled);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::PolicyLapsed);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::PendingPayout);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::PayoutPending);
+
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.is_paused = true);
+    assert_eq!(verdict_of(&mut env), ClaimVerdict::ContractPaused);
+}
+
+#[test]
+fn expire_payout() {
+    let mut env = Env::new();
+    let ix = instructions::expire_payout(&env.intruder, &env.holder, &env.policy, &env.treasury, POLICY_ID, None);
+    env.world.expect_error(&ix, InsuranceError::RentPayerMismatch);
+
+    let elsewhere = env.misplace(env.policy);
+    let ix = instructions::expire_payout(&env.holder, &env.holder, &elsewhere, &env.treasury, POLICY_ID, None);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::expire_payout(&env.holder, &env.holder, &env.policy, &elsewhere, POLICY_ID, None);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(
+        instructions::expire_payout(&env.holder, &env.holder, &env.policy, &env.treasury, POLICY_ID, None),
+        &env.master,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    set_status(&mut env, PayoutStatus::Executed);
+    let ix = instructions::expire_payout(&env.holder, &env.holder, &env.policy, &env.treasury, POLICY_ID, None);
+    env.world.expect_error(&ix, InsuranceError::PayoutConditionsNotMet);
+}
+
+#[test]
+fn expired_payout_thaws_policy_token() {
+    let mut env = Env::new();
+    // A tokenized policy, triggered by the latest reading of its feed
+    let mint = Pubkey::new_unique();
+    let token_account = get_associated_token_address(&env.holder, &mint);
+    env.world.set_mint(mint);
+    env.world.set_token_account(token_account, mint, env.holder);
+    env.world.set_token_balance(token_account, 1);
+    env.world.update_zero_copy(env.oracle, |oracle: &mut Oracle| {
+        oracle.set_data_feed_address("rainfall-mm");
+        oracle.record_observation(OracleObservation { value: 80, timestamp: NOW, confidence: 0 });
+        oracle.last_update_timestamp = NOW;
+    });
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.policy_mint = Some(mint);
+        policy.oracle_config.data_feed_id = "rainfall-mm".into();
+        policy.oracle_config.staleness_threshold = 3_600;
+        policy.trigger_conditions.comparison_operator = ComparisonOperator::GreaterThan;
+        policy.trigger_conditions.threshold_value = 50.0;
+        policy.coverage_amount = 1_000;
+        policy.max_payout_per_incident = 1_000;
+    });
+    let (type_config, bump) = pda::type_config_pda(&InsuranceType::Weather);
+    let mut state: InsuranceTypeConfig = blank();
+    state.bump = bump;
+    env.world.set(type_config, &state);
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = 1_000_000);
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| {
+        master.global_config.max_open_payouts = 10;
+        master.open_payout_count = 0;
+    });
+    env.world.preallocate(env.payout, PendingPayout::space());
+
+    let trigger = |env: &Env, incident_timestamp: i64| {
+        instructions::trigger_payout(
+            &env.holder,
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            POLICY_ID,
+            &InsuranceType::Weather,
+            Some(&mint),
+            false,
+            &[env.oracle],
+            80,
+            0,
+            None,
+            None,
+            None,
+            incident_timestamp,
+        )
+    };
+    let expire =
+        instructions::expire_payout(&env.holder, &env.holder, &env.policy, &env.treasury, POLICY_ID, Some(&mint));
+    let frozen = |env: &Env| env.world.token_account(&token_account).is_frozen();
+
+    env.world.process(&trigger(&env, NOW - 60)).unwrap();
+    assert!(frozen(&env));
+
+    env.world.update(env.payout, |payout: &mut PendingPayout| payout.expires_at = NOW - 1);
+    env.world.process(&expire).unwrap();
+    assert!(!frozen(&env));
+    assert_eq!(env.world.get::<Policy>(&env.policy).status, PolicyStatus::Active);
+
+    // The thawed token can be frozen again by the next trigger
+    env.world.preallocate(env.payout, PendingPayout::space());
+    env.world.process(&trigger(&env, NOW - 30)).unwrap();
+    assert!(frozen(&env));
+}
+
+/// A vesting payout with one of three tranches paid and the next due in an hour
+fn start_vesting(env: &mut Env) -> Pubkey {
+    let (vesting_payout, bump) = pda::vesting_payout_pda(POLICY_ID);
+    let mut state: VestingPayout = blank();
+    state.policy_id = POLICY_ID;
+    state.beneficiary = env.holder;
+    state.total_amount = 3_000;
+    state.released_amount = 1_000;
+    state.tranches = 3;
+    state.tranches_released = 1;
+    state.interval_secs = 3_600;
+    state.started_at = NOW;
+    state.next_release_at = NOW + 3_600;
+    state.rent_payer = env.holder;
+    state.bump = bump;
+    env.world.set(vesting_payout, &state);
+    vesting_payout
+}
+
+/// Anyone may release a due tranche, as with executing a payout
+fn release(env: &Env, beneficiary: &Pubkey, rent_payer: &Pubkey) -> Instruction {
+    instructions::release_payout_tranche(&env.intruder, beneficiary, rent_payer, &env.treasury, None, POLICY_ID)
+}
+
+#[test]
+fn release_payout_tranche() {
+    let mut env = Env::new();
+    let vesting_payout = start_vesting(&mut env);
+    let ix = release(&env, &env.holder, &env.holder);
+    env.world.expect_error(&ix, InsuranceError::TrancheNotDue);
+
+    env.world.update(vesting_payout, |state: &mut VestingPayout| state.next_release_at = NOW);
+    let ix = release(&env, &env.intruder, &env.holder);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    let ix = release(&env, &env.holder, &env.intruder);
+    env.world.expect_error(&ix, InsuranceError::RentPayerMismatch);
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = redirect(release(&env, &env.holder, &env.holder), &env.treasury, &elsewhere);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    let elsewhere = env.misplace(vesting_payout);
+    let ix = redirect(release(&env, &env.holder, &env.holder), &vesting_payout, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+#[test]
+fn cancel_vesting_payout() {
+    let mut env = Env::new();
+    start_vesting(&mut env);
+    let cancel = |env: &Env, admin: &Pubkey, rent_payer: &Pubkey, reason: &str| {
+        instructions::cancel_vesting_payout(admin, rent_payer, &env.treasury, POLICY_ID, reason.to_string())
+    };
+    let ix = cancel(&env, &env.intruder, &env.holder, "fraud");
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+    let ix = cancel(&env, &env.admin, &env.intruder, "fraud");
+    env.world.expect_error(&ix, InsuranceError::RentPayerMismatch);
+    let ix = cancel(&env, &env.admin, &env.holder, &"x".repeat(129));
+    env.world.expect_error(&ix, InsuranceError::InvalidInput);
+}
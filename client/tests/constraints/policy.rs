@@ -0,0 +1,494 @@
+use anchor_lang::error::ErrorCode::ConstraintSeeds;
+use anchor_lang::prelude::Pubkey;
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{
+    ArrearsMode, CurrencyAmount, FeeVault, GlobalConfig, InstallmentSchedule, MasterInsuranceContract, Policy,
+    PolicyStatus, ProductTemplate, RegionExposure, Subsidy, TokenType, Treasury, UnderwriterLedger,
+};
+use siglab_contract_client::{instructions, pda};
+
+use crate::fixtures::{Env, REGION};
+use crate::harness::{blank, redirect, NOW};
+
+#[test]
+fn update_policy_metadata() {
+    let mut env = Env::new();
+    let ix = instructions::update_policy_metadata(&env.intruder, &env.policy, None, "notes".into(), None);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let ix = instructions::update_policy_metadata(&env.holder, &env.policy, None, "notes".into(), None);
+    let elsewhere = env.misplace(env.master);
+    env.world.expect_error(&redirect(ix.clone(), &env.master, &elsewhere), ConstraintSeeds);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Expired);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotActive);
+}
+
+#[test]
+fn pay_premium() {
+    let mut env = Env::new();
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::PendingActivation;
+        policy.premium_currency = TokenType::SOL;
+        policy.premium_amount = 2_000_000;
+    });
+    env.world
+        .update(env.master, |master: &mut MasterInsuranceContract| master.global_config = GlobalConfig::default());
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix =
+        instructions::pay_premium(&env.holder, &env.policy, &elsewhere, None, None, None, false, None, 2_000_000);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+    let ix = instructions::pay_premium(
+        &env.intruder,
+        &env.policy,
+        &env.treasury,
+        None,
+        None,
+        None,
+        false,
+        None,
+        2_000_000,
+    );
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    // The holder's premium lands in the treasury before coverage starts
+    let holder_lamports = env.world.lamports(&env.holder);
+    let treasury_lamports = env.world.lamports(&env.treasury);
+    let ix = instructions::pay_premium(
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        None,
+        None,
+        None,
+        false,
+        None,
+        2_000_000,
+    );
+    env.world.process(&ix).unwrap();
+
+    assert_eq!(env.world.lamports(&env.holder), holder_lamports - 2_000_000);
+    assert_eq!(env.world.lamports(&env.treasury), treasury_lamports + 2_000_000);
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!(treasury.total_sol_balance, 2_000_000);
+    assert_eq!(treasury.total_premiums_collected_sol, 2_000_000);
+    assert_eq!(env.world.get::<Policy>(&env.policy).status, PolicyStatus::Active);
+
+    // A premium the payer can't fund moves nothing and leaves the books alone
+    let ix = instructions::pay_premium(
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        None,
+        None,
+        None,
+        false,
+        None,
+        u64::MAX / 2,
+    );
+    assert!(env.world.process(&ix).is_err());
+    assert_eq!(env.world.get::<Treasury>(&env.treasury).total_sol_balance, 2_000_000);
+}
+
+#[test]
+fn pay_premium_moves_late_fee_to_fee_vault() {
+    let mut env = Env::new();
+    env.overdue_premium(2_000_000, 3);
+    let late_fee = 60_000;
+
+    let ix = instructions::pay_premium(
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        None,
+        None,
+        None,
+        false,
+        None,
+        2_060_000,
+    );
+    env.world.expect_error(&ix, InsuranceError::FeeVaultRequired);
+
+    let (fee_vault, _) = pda::fee_vault_pda();
+    // The fee goes to the vault and only the premium reaches the treasury
+    let vault_lamports = env.world.lamports(&fee_vault);
+    let treasury_lamports = env.world.lamports(&env.treasury);
+    let ix = instructions::pay_premium(
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        None,
+        None,
+        None,
+        true,
+        None,
+        2_060_000,
+    );
+    env.world.process(&ix).unwrap();
+
+    assert_eq!(env.world.lamports(&fee_vault), vault_lamports + late_fee);
+    assert_eq!(env.world.lamports(&env.treasury), treasury_lamports + 2_000_000);
+    let vault: FeeVault = env.world.get(&fee_vault);
+    assert_eq!((vault.sol_collected, vault.usdc_collected), (late_fee, 0));
+    assert_eq!(env.world.get::<Treasury>(&env.treasury).total_sol_balance, 2_000_000);
+    assert_eq!(env.world.get::<MasterInsuranceContract>(&env.master).late_fees_collected, late_fee);
+}
+
+#[test]
+fn pay_premium_books_late_fee_to_underwriter_ledger() {
+    let mut env = Env::new();
+    env.overdue_premium(2_000_000, 3);
+    let (underwriter, other) = (env.world.wallet(), env.world.wallet());
+    for key in [underwriter, other] {
+        let (ledger, bump) = pda::underwriter_ledger_pda(&key);
+        let mut state: UnderwriterLedger = blank();
+        state.underwriter = key;
+        state.bump = bump;
+        env.world.set(ledger, &state);
+    }
+    env.world.update(env.policy, |policy: &mut Policy| policy.co_signer = Some(underwriter));
+    let pay = |co_signer: Option<&Pubkey>| {
+        instructions::pay_premium(
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            None,
+            None,
+            None,
+            true,
+            co_signer,
+            2_060_000,
+        )
+    };
+
+    // A co-signed policy's late fee counts toward its underwriter's rebate
+    env.world.expect_error(&pay(None), InsuranceError::UnderwriterLedgerRequired);
+    env.world.expect_error(&pay(Some(&other)), InsuranceError::UnderwriterLedgerMismatch);
+    env.world.process(&pay(Some(&underwriter))).unwrap();
+
+    let ledger: UnderwriterLedger = env.world.get(&pda::underwriter_ledger_pda(&underwriter).0);
+    let entry = ledger.entry(UnderwriterLedger::epoch_at(NOW)).unwrap();
+    assert_eq!((entry.sol_fees, entry.usdc_fees, entry.placed_coverage), (60_000, 0, 0));
+}
+
+#[test]
+fn pay_premium_with_subsidy() {
+    let mut env = Env::new();
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::PendingActivation;
+        policy.premium_currency = TokenType::USDC;
+        policy.premium_amount = 2_000_000;
+    });
+    // No SOL/USD price is staged, so no minimum premium needs converting
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| {
+        master.global_config = GlobalConfig::default();
+        master.global_config.min_premium = CurrencyAmount::sol(0);
+    });
+    let (subsidy, bump) = pda::subsidy_pda(&env.admin, 1);
+    let mut state: Subsidy = blank();
+    state.sponsor = env.admin;
+    state.subsidy_id = 1;
+    state.coverage_bps = 5_000;
+    state.budget_remaining = 10_000_000;
+    state.bump = bump;
+    env.world.set_sized(subsidy, &state, Subsidy::space());
+    let pay = |env: &Env| {
+        instructions::pay_premium(
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            None,
+            Some(&subsidy),
+            None,
+            false,
+            None,
+            2_000_000,
+        )
+    };
+
+    // The lamport budget can't co-pay a USDC premium
+    env.world.expect_error(&pay(&env), InsuranceError::SubsidyCurrencyMismatch);
+    assert_eq!(env.world.get::<Subsidy>(&subsidy).budget_remaining, 10_000_000);
+
+    // A SOL premium draws the sponsor's share from it
+    env.world.update(env.policy, |policy: &mut Policy| policy.premium_currency = TokenType::SOL);
+    env.world.process(&pay(&env)).unwrap();
+    assert_eq!(env.world.get::<Subsidy>(&subsidy).budget_remaining, 9_000_000);
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!((treasury.total_sol_balance, treasury.total_usdc_balance), (2_000_000, 0));
+}
+
+#[test]
+fn set_auto_claim() {
+    let mut env = Env::new();
+    let keeper = Pubkey::new_unique();
+    let ix = instructions::set_auto_claim(&env.intruder, &env.policy, None, true, keeper);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let ix = instructions::set_auto_claim(&env.holder, &env.policy, None, true, keeper);
+    env.world.process(&ix).unwrap();
+    let policy: Policy = env.world.get(&env.policy);
+    assert!(policy.auto_claim && policy.accepts_claim_keeper(&keeper));
+    assert!(!policy.accepts_claim_keeper(&env.intruder));
+
+    let ix = instructions::set_auto_claim(&env.holder, &env.policy, None, false, Pubkey::default());
+    env.world.process(&ix).unwrap();
+    assert!(!env.world.get::<Policy>(&env.policy).accepts_claim_keeper(&keeper));
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Expired);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotActive);
+}
+
+#[test]
+fn claim_outage_refund() {
+    let mut env = Env::new();
+    let ix = instructions::claim_outage_refund(&env.holder, &env.policy, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotExpired);
+
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::Expired;
+        policy.premium_currency = TokenType::SOL;
+        policy.total_premiums_paid = 31_000;
+        policy.oracle_outage_secs = 86_400;
+    });
+    env.world
+        .update(env.master, |master: &mut MasterInsuranceContract| master.global_config = GlobalConfig::default());
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = 10_000);
+    let elsewhere = env.misplace(env.treasury);
+    let misdirected = instructions::claim_outage_refund(&env.holder, &env.policy, &elsewhere, None);
+    env.world.expect_error(&misdirected, InsuranceError::TreasuryAccountMismatch);
+    let stolen = instructions::claim_outage_refund(&env.intruder, &env.policy, &env.treasury, None);
+    env.world.expect_error(&stolen, InsuranceError::Unauthorized);
+
+    // A day of a 31-day term is within the 5% tolerated; three days are not
+    env.world.expect_error(&ix, InsuranceError::OutageBelowThreshold);
+    env.world.update(env.policy, |policy: &mut Policy| policy.oracle_outage_secs = 3 * 86_400);
+    env.world.process(&ix).unwrap();
+
+    let policy: Policy = env.world.get(&env.policy);
+    assert!(policy.outage_refunded);
+    assert_eq!(policy.total_premiums_paid, 28_000);
+    assert_eq!(env.world.get::<Treasury>(&env.treasury).total_sol_balance, 7_000);
+    env.world.expect_error(&ix, InsuranceError::OutageRefundClaimed);
+}
+
+#[test]
+fn adjust_coverage() {
+    let mut env = Env::new();
+    let ix = instructions::adjust_coverage(&env.intruder, &env.policy, &env.treasury, None, None, None, 1, 1);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::adjust_coverage(&env.holder, &env.policy, &elsewhere, None, None, None, 1, 1);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    let ix = instructions::adjust_coverage(&env.holder, &env.policy, &env.treasury, None, None, None, 1, 1);
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| master.is_paused = true);
+    env.world.expect_error(&ix, InsuranceError::ContractPaused);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Cancelled);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotActive);
+}
+
+#[test]
+fn adjust_coverage_settles_premium() {
+    let mut env = Env::new();
+    env.world
+        .update(env.master, |master: &mut MasterInsuranceContract| master.global_config = GlobalConfig::default());
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.premium_currency = TokenType::SOL;
+        policy.payout_currency = TokenType::SOL;
+        policy.coverage_amount = 10_000_000;
+        policy.premium_amount = 1_000_000;
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = 50_000_000);
+    let adjust = |coverage| {
+        instructions::adjust_coverage(
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            None,
+            None,
+            None,
+            coverage,
+            u64::MAX,
+        )
+    };
+
+    // Added coverage is paid for by the owner into the treasury
+    let treasury_lamports = env.world.lamports(&env.treasury);
+    let holder_lamports = env.world.lamports(&env.holder);
+    env.world.process(&adjust(20_000_000)).unwrap();
+    let extra = env.world.get::<Policy>(&env.policy).total_premiums_paid;
+    assert!(extra > 0);
+    assert_eq!(env.world.lamports(&env.treasury), treasury_lamports + extra);
+    assert_eq!(env.world.lamports(&env.holder), holder_lamports - extra);
+
+    // Removed coverage is refunded out of it
+    env.world.process(&adjust(10_000_000)).unwrap();
+    let refund = extra - env.world.get::<Policy>(&env.policy).total_premiums_paid;
+    assert!(refund > 0);
+    assert_eq!(env.world.lamports(&env.treasury), treasury_lamports + extra - refund);
+    assert_eq!(env.world.lamports(&env.holder), holder_lamports - extra + refund);
+}
+
+#[test]
+fn adjust_coverage_refuses_installment_policies() {
+    let mut env = Env::new();
+    env.world
+        .update(env.master, |master: &mut MasterInsuranceContract| master.global_config = GlobalConfig::default());
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.premium_currency = TokenType::SOL;
+        policy.payout_currency = TokenType::SOL;
+        policy.coverage_amount = 10_000_000;
+        policy.premium_amount = 1_000_000;
+        policy.installment_schedule = Some(InstallmentSchedule {
+            count: 3,
+            amount: 100_000,
+            first_due: NOW - 86_400,
+            period_secs: 30 * 86_400,
+            arrears_mode: ArrearsMode::NoCoverageWhenBehind,
+        });
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.total_sol_balance = 50_000_000);
+
+    // Neither added nor removed coverage is settled against the schedule
+    for coverage in [20_000_000, 5_000_000] {
+        let ix = instructions::adjust_coverage(
+            &env.holder,
+            &env.policy,
+            &env.treasury,
+            None,
+            None,
+            None,
+            coverage,
+            u64::MAX,
+        );
+        env.world.expect_error(&ix, InsuranceError::InstallmentCoverageNotAdjustable);
+    }
+}
+
+#[test]
+fn expire_policy() {
+    let mut env = Env::new();
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotExpired);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.end_date = NOW - 1);
+    let elsewhere = env.misplace(env.policy);
+    env.world.expect_error(
+        &instructions::expire_policy(&env.intruder, &elsewhere, &env.treasury, None),
+        ConstraintSeeds,
+    );
+
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &elsewhere, None);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    // A policy never activated still releases the coverage booked at sale,
+    // from the total as from its currency
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.status = PolicyStatus::PendingActivation;
+        policy.coverage_amount = 3;
+        policy.payout_currency = TokenType::USDC;
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| {
+        treasury.total_coverage_exposure = 5;
+        treasury.usdc_coverage_exposure = 5;
+    });
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, None);
+    env.world.process(&ix).unwrap();
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!((treasury.total_coverage_exposure, treasury.usdc_coverage_exposure), (2, 2));
+}
+
+#[test]
+fn set_product_template_active() {
+    let mut env = Env::new();
+    let (template, bump) = pda::product_template_pda(7, 2);
+    let mut state: ProductTemplate = blank();
+    state.product_id = 7;
+    state.version = 2;
+    state.active = true;
+    state.bump = bump;
+    env.world.set(template, &state);
+
+    let ix = instructions::set_product_template_active(&env.intruder, 7, 2, false);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let ix = instructions::set_product_template_active(&env.admin, 7, 2, false);
+    let elsewhere = env.misplace(template);
+    env.world.expect_error(&redirect(ix.clone(), &template, &elsewhere), ConstraintSeeds);
+
+    env.world.process(&ix).unwrap();
+    assert!(!env.world.get::<ProductTemplate>(&template).active);
+}
+
+#[test]
+fn release_underwriting_bond() {
+    let mut env = Env::new();
+    let ix = instructions::release_underwriting_bond(&env.intruder, &env.policy, &env.treasury, &env.admin);
+    env.world.expect_error(&ix, InsuranceError::PolicyNotExpired);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.status = PolicyStatus::Expired);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    env.world.update(env.policy, |policy: &mut Policy| policy.co_signer = Some(env.admin));
+    env.world.expect_error(&ix, InsuranceError::NoUnderwritingBond);
+
+    let elsewhere = env.misplace(env.treasury);
+    let misdirected = instructions::release_underwriting_bond(&env.intruder, &env.policy, &elsewhere, &env.admin);
+    env.world.expect_error(&misdirected, InsuranceError::TreasuryAccountMismatch);
+
+    // Claims beyond the premiums are slashed from the bond into the reserves
+    env.world
+        .update(env.master, |master: &mut MasterInsuranceContract| master.global_config = GlobalConfig::default());
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.underwriting_bond = 3_000;
+        policy.premium_currency = TokenType::SOL;
+        policy.payout_currency = TokenType::SOL;
+        policy.total_premiums_paid = 1_000;
+        policy.claims_paid = 2_000;
+    });
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.underwriting_bonds = 3_000);
+    env.world.process(&ix).unwrap();
+
+    assert_eq!(env.world.get::<Policy>(&env.policy).underwriting_bond, 0);
+    let treasury: Treasury = env.world.get(&env.treasury);
+    assert_eq!(treasury.underwriting_bonds, 0);
+    assert_eq!(treasury.total_sol_balance, 1_000);
+    env.world.expect_error(&ix, InsuranceError::NoUnderwritingBond);
+}
+
+#[test]
+fn expire_tracked_policy() {
+    let mut env = Env::new();
+    let other = *b"JP-13\0\0\0";
+    for region_code in [REGION, other] {
+        let (region, bump) = pda::region_exposure_pda(&region_code);
+        let mut state: RegionExposure = blank();
+        state.region_code = region_code;
+        state.sol_exposure = 5;
+        state.bump = bump;
+        env.world.set(region, &state);
+    }
+    env.world.update(env.policy, |policy: &mut Policy| {
+        policy.end_date = NOW - 1;
+        policy.coverage_amount = 3;
+        policy.payout_currency = TokenType::SOL;
+        policy.region_code = Some(REGION);
+        policy.region_tracked = true;
+    });
+
+    // Coverage booked in a region leaves it with the policy
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::RegionExposureRequired);
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, Some(&other));
+    env.world.expect_error(&ix, InsuranceError::RegionMismatch);
+
+    let ix = instructions::expire_policy(&env.intruder, &env.policy, &env.treasury, Some(&REGION));
+    env.world.process(&ix).unwrap();
+    assert_eq!(env.world.get::<RegionExposure>(&pda::region_exposure_pda(&REGION).0).sol_exposure, 2);
+    assert_eq!(env.world.get::<RegionExposure>(&pda::region_exposure_pda(&other).0).sol_exposure, 5);
+}
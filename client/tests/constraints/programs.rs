@@ -0,0 +1,63 @@
+use anchor_lang::error::ErrorCode::InvalidProgramId;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use siglab_contract_client::instructions;
+
+use crate::fixtures::{Env, POLICY_ID};
+use crate::harness::redirect;
+
+/// A USDC mint and an account of it for the treasury
+fn usdc(env: &mut Env) -> (Pubkey, Pubkey) {
+    let (mint, account) = (Pubkey::new_unique(), Pubkey::new_unique());
+    env.world.set_mint(mint);
+    env.world.set_token_account(account, mint, env.treasury);
+    (mint, account)
+}
+
+/// Check each of `programs` is rejected when another executable stands in for it
+fn expect_spoofed_programs_rejected(env: &mut Env, ix: &Instruction, programs: &[Pubkey]) {
+    let spoofed = env.world.program();
+    for program in programs {
+        env.world.expect_error(&redirect(ix.clone(), program, &spoofed), InvalidProgramId);
+    }
+}
+
+#[test]
+fn execute_payout() {
+    let mut env = Env::new();
+    let (mint, account) = usdc(&mut env);
+    let ix = instructions::execute_payout(
+        &env.intruder,
+        &env.holder,
+        &env.holder,
+        &env.policy,
+        &env.treasury,
+        Some((&account, &mint)),
+        false,
+        None,
+        None,
+        POLICY_ID,
+    );
+    expect_spoofed_programs_rejected(&mut env, &ix, &[token::ID, associated_token::ID, system_program::ID]);
+}
+
+#[test]
+fn donate_to_treasury() {
+    let mut env = Env::new();
+    let (mint, account) = usdc(&mut env);
+    env.world.set_token_account(get_associated_token_address(&env.holder, &mint), mint, env.holder);
+    let ix = instructions::donate_to_treasury(&env.holder, &env.treasury, Some((&account, &mint)), 1, None);
+    expect_spoofed_programs_rejected(&mut env, &ix, &[token::ID, system_program::ID]);
+}
+
+#[test]
+fn final_settlement() {
+    let mut env = Env::new();
+    let (mint, account) = usdc(&mut env);
+    env.world.set_token_account(get_associated_token_address(&env.admin, &mint), mint, env.admin);
+    let ix = instructions::final_settlement(&env.admin, &env.treasury, &env.admin, Some((&account, &mint)));
+    expect_spoofed_programs_rejected(&mut env, &ix, &[token::ID]);
+}
@@ -0,0 +1,277 @@
+use anchor_lang::error::ErrorCode::{ConstraintSeeds, ConstraintTokenOwner};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token::TokenAccount;
+use siglab_contract::constants::{FEE_EPOCH_SECS, MAX_REBATE_TIERS, UNDERWRITER_LEDGER_EPOCHS};
+use siglab_contract::error::InsuranceError;
+use siglab_contract::instructions::MigrateTreasuryParams;
+use siglab_contract::state::{
+    FeeRebateTier, FeeVault, MasterInsuranceContract, TokenType, Treasury, TreasuryStatus, UnderwriterEpoch,
+    UnderwriterLedger, WithdrawalReason,
+};
+use siglab_contract_client::{instructions, pda};
+
+use crate::fixtures::Env;
+use crate::harness::{blank, redirect, NOW};
+
+fn withdraw(admin: &Pubkey, treasury: &Pubkey, recipient: &Pubkey) -> Instruction {
+    instructions::withdraw_funds(
+        admin,
+        treasury,
+        recipient,
+        None,
+        1,
+        TokenType::SOL,
+        WithdrawalReason::AdminWithdrawal,
+    )
+}
+
+#[test]
+fn withdraw_funds() {
+    let mut env = Env::new();
+    env.world.expect_error(&withdraw(&env.intruder, &env.treasury, &env.intruder), InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.treasury);
+    env.world.expect_error(&withdraw(&env.admin, &elsewhere, &env.admin), InsuranceError::TreasuryAccountMismatch);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(withdraw(&env.admin, &env.treasury, &env.admin), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+
+    env.world.expect_error(&withdraw(&env.admin, &env.treasury, &env.treasury), InsuranceError::InvalidRecipient);
+}
+
+#[test]
+fn deposit_funds() {
+    let mut env = Env::new();
+    let elsewhere = env.misplace(env.treasury);
+    let ix = instructions::deposit_funds(&env.intruder, &elsewhere, 1, TokenType::SOL);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(
+        instructions::deposit_funds(&env.intruder, &env.treasury, 1, TokenType::SOL),
+        &env.master,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+#[test]
+fn set_crank_tip() {
+    let mut env = Env::new();
+    let ix = instructions::set_crank_tip(&env.intruder, &env.treasury, 5_000, 1_000_000);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix = redirect(
+        instructions::set_crank_tip(&env.admin, &env.treasury, 5_000, 1_000_000),
+        &env.master,
+        &elsewhere,
+    );
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+const USDC: u64 = 5_000_000;
+
+/// A paused contract whose treasury holds `USDC` in its own token account,
+/// with the version 1 treasury staged for `migrate_treasury` to create
+fn usdc_treasury() -> (Env, Pubkey, Pubkey) {
+    let mut env = Env::new();
+    let (mint, usdc_account) = (Pubkey::new_unique(), Pubkey::new_unique());
+    env.world.set_mint(mint);
+    env.world.set_token_account(usdc_account, mint, env.treasury);
+    env.world.set_token_balance(usdc_account, USDC);
+    env.world.update(env.treasury, |treasury: &mut Treasury| {
+        treasury.usdc_mint = mint;
+        treasury.usdc_token_account = usdc_account;
+        treasury.total_usdc_balance = USDC;
+    });
+    env.world.update(env.master, |master: &mut MasterInsuranceContract| {
+        master.is_paused = true;
+        master.open_payout_count = 0;
+    });
+    env.world.preallocate(pda::treasury_pda_v(1).0, Treasury::space());
+    (env, mint, usdc_account)
+}
+
+fn migration(usdc_token_account: Option<Pubkey>) -> MigrateTreasuryParams {
+    MigrateTreasuryParams {
+        version: 1,
+        usdc_mint: None,
+        usdc_token_account,
+        sol_token_account: None,
+        usdc_decimals: None,
+    }
+}
+
+#[test]
+fn migrate_treasury_hands_over_usdc_account() {
+    let (mut env, _, usdc_account) = usdc_treasury();
+    let new_treasury = pda::treasury_pda_v(1).0;
+    let ix = instructions::migrate_treasury(&env.admin, &env.treasury, None, migration(None));
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+
+    let ix = instructions::migrate_treasury(&env.admin, &env.treasury, Some(&usdc_account), migration(None));
+    env.world.process(&ix).unwrap();
+
+    let account: TokenAccount = env.world.get(&usdc_account);
+    assert_eq!((account.owner, account.amount), (new_treasury, USDC));
+    assert_eq!(env.world.get::<Treasury>(&new_treasury).usdc_token_account, usdc_account);
+    assert_eq!(env.world.get::<Treasury>(&env.treasury).usdc_token_account, Pubkey::default());
+}
+
+#[test]
+fn migrate_treasury_moves_usdc_to_rekeyed_account() {
+    let (mut env, mint, usdc_account) = usdc_treasury();
+    let new_treasury = pda::treasury_pda_v(1).0;
+    let (stray, rekeyed) = (Pubkey::new_unique(), Pubkey::new_unique());
+    env.world.set_token_account(stray, mint, env.admin);
+    env.world.set_token_account(rekeyed, mint, new_treasury);
+
+    let ix =
+        instructions::migrate_treasury(&env.admin, &env.treasury, Some(&usdc_account), migration(Some(stray)));
+    env.world.expect_error(&ix, ConstraintTokenOwner);
+
+    let ix =
+        instructions::migrate_treasury(&env.admin, &env.treasury, Some(&usdc_account), migration(Some(rekeyed)));
+    env.world.process(&ix).unwrap();
+    assert_eq!(env.world.get::<TokenAccount>(&usdc_account).amount, 0);
+    assert_eq!(env.world.get::<TokenAccount>(&rekeyed).amount, USDC);
+    assert_eq!(env.world.get::<Treasury>(&new_treasury).total_usdc_balance, USDC);
+
+    // The emptied account stays on the retired treasury's books, so closing
+    // checks it holds nothing
+    let ix = instructions::close_retired_treasury(&env.admin, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::TreasuryAccountMismatch);
+    env.world.set_token_balance(usdc_account, 1);
+    let ix = instructions::close_retired_treasury(&env.admin, &env.treasury, Some(&usdc_account));
+    env.world.expect_error(&ix, InsuranceError::RetiredTreasuryHoldsUsdc);
+}
+
+#[test]
+fn close_retired_treasury() {
+    let mut env = Env::new();
+    let ix = instructions::close_retired_treasury(&env.admin, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::TreasuryNotRetired);
+
+    env.world.update(env.treasury, |treasury: &mut Treasury| treasury.status = TreasuryStatus::Retired);
+    let ix = instructions::close_retired_treasury(&env.intruder, &env.treasury, None);
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    let elsewhere = env.misplace(env.master);
+    let ix =
+        redirect(instructions::close_retired_treasury(&env.admin, &env.treasury, None), &env.master, &elsewhere);
+    env.world.expect_error(&ix, ConstraintSeeds);
+}
+
+/// 10% of the fees from 1,000 lamports of placed coverage, 25% from 10,000
+fn rebate_tiers() -> Vec<FeeRebateTier> {
+    vec![
+        FeeRebateTier { min_placed_coverage: 1_000, rebate_bps: 1_000 },
+        FeeRebateTier { min_placed_coverage: 10_000, rebate_bps: 2_500 },
+    ]
+}
+
+#[test]
+fn set_fee_rebate_tiers() {
+    let mut env = Env::new();
+    let ix = instructions::set_fee_rebate_tiers(&env.intruder, rebate_tiers());
+    env.world.expect_error(&ix, InsuranceError::Unauthorized);
+
+    // Thresholds must rise and rates never fall as coverage grows
+    let mut falling = rebate_tiers();
+    falling[1].rebate_bps = 500;
+    let ix = instructions::set_fee_rebate_tiers(&env.admin, falling);
+    env.world.expect_error(&ix, InsuranceError::InvalidRebateTiers);
+    let too_many = (1..=MAX_REBATE_TIERS as u64 + 1)
+        .map(|min_placed_coverage| FeeRebateTier { min_placed_coverage, rebate_bps: 100 })
+        .collect();
+    let ix = instructions::set_fee_rebate_tiers(&env.admin, too_many);
+    env.world.expect_error(&ix, InsuranceError::InvalidRebateTiers);
+
+    env.world.process(&instructions::set_fee_rebate_tiers(&env.admin, rebate_tiers())).unwrap();
+    assert_eq!(env.world.get::<FeeVault>(&pda::fee_vault_pda().0).rebate_tiers, rebate_tiers());
+}
+
+#[test]
+fn open_underwriter_ledger() {
+    let mut env = Env::new();
+    let ledger = pda::underwriter_ledger_pda(&env.holder).0;
+    env.world.preallocate(ledger, UnderwriterLedger::space());
+
+    // Anyone may open it, so the holder of a co-signed policy isn't held up
+    env.world.process(&instructions::open_underwriter_ledger(&env.intruder, &env.holder)).unwrap();
+    let state: UnderwriterLedger = env.world.get(&ledger);
+    assert_eq!((state.underwriter, state.bump), (env.holder, pda::underwriter_ledger_pda(&env.holder).1));
+}
+
+/// The epoch before the one `NOW` falls in
+const LAST_EPOCH: u64 = (NOW / FEE_EPOCH_SECS) as u64 - 1;
+
+/// An underwriter whose ledger books `placed_coverage` and `sol_fees` in
+/// `LAST_EPOCH`, with the fee vault holding the fees under `rebate_tiers`
+fn underwriter_with_fees(env: &mut Env, placed_coverage: u64, sol_fees: u64) -> Pubkey {
+    let underwriter = env.world.wallet();
+    let (ledger, bump) = pda::underwriter_ledger_pda(&underwriter);
+    let mut state: UnderwriterLedger = blank();
+    state.underwriter = underwriter;
+    state.bump = bump;
+    state.epochs[LAST_EPOCH as usize % UNDERWRITER_LEDGER_EPOCHS] =
+        UnderwriterEpoch { epoch: LAST_EPOCH, placed_coverage, sol_fees, ..UnderwriterEpoch::default() };
+    env.world.set(ledger, &state);
+
+    env.world.update(pda::fee_vault_pda().0, |vault: &mut FeeVault| {
+        vault.sol_collected += sol_fees;
+        vault.rebate_tiers = rebate_tiers();
+    });
+    underwriter
+}
+
+#[test]
+fn claim_fee_rebate() {
+    let mut env = Env::new();
+    let fee_vault = pda::fee_vault_pda().0;
+    let underwriter = underwriter_with_fees(&mut env, 10_000, 80_000);
+
+    // The current epoch is still taking business
+    let ix = instructions::claim_fee_rebate(&underwriter, LAST_EPOCH + 1, None);
+    env.world.expect_error(&ix, InsuranceError::FeeRebateEpochOpen);
+    // An epoch the ring has moved past can't be claimed
+    let ix = instructions::claim_fee_rebate(&underwriter, LAST_EPOCH - UNDERWRITER_LEDGER_EPOCHS as u64, None);
+    env.world.expect_error(&ix, InsuranceError::FeeRebateEpochUnavailable);
+
+    // Reaching the 10,000 tier earns a quarter of the fees, once
+    let vault_lamports = env.world.lamports(&fee_vault);
+    let underwriter_lamports = env.world.lamports(&underwriter);
+    let ix = instructions::claim_fee_rebate(&underwriter, LAST_EPOCH, None);
+    env.world.process(&ix).unwrap();
+    assert_eq!(env.world.lamports(&fee_vault), vault_lamports - 20_000);
+    assert_eq!(env.world.lamports(&underwriter), underwriter_lamports + 20_000);
+    assert_eq!(env.world.get::<FeeVault>(&fee_vault).sol_rebated, 20_000);
+    let ledger: UnderwriterLedger = env.world.get(&pda::underwriter_ledger_pda(&underwriter).0);
+    assert!(ledger.entry(LAST_EPOCH).unwrap().claimed);
+    env.world.expect_error(&ix, InsuranceError::FeeRebateAlreadyClaimed);
+
+    // Coverage below the lowest tier earns nothing
+    let underwriter = underwriter_with_fees(&mut env, 999, 80_000);
+    let ix = instructions::claim_fee_rebate(&underwriter, LAST_EPOCH, None);
+    env.world.expect_error(&ix, InsuranceError::NoFeeRebate);
+}
+
+#[test]
+fn claim_fee_rebate_never_exceeds_collected_fees() {
+    let mut env = Env::new();
+    let fee_vault = pda::fee_vault_pda().0;
+    let underwriter = underwriter_with_fees(&mut env, 1_000, 80_000);
+
+    // Rebates already paid leave less than this one's 8,000 on the books
+    env.world.update(fee_vault, |vault: &mut FeeVault| vault.sol_rebated = 75_000);
+    let ix = instructions::claim_fee_rebate(&underwriter, LAST_EPOCH, None);
+    env.world.expect_error(&ix, InsuranceError::InsufficientFeeVault);
+
+    env.world.update(fee_vault, |vault: &mut FeeVault| vault.sol_rebated = 72_000);
+    env.world.process(&ix).unwrap();
+    let vault: FeeVault = env.world.get(&fee_vault);
+    assert_eq!(vault.sol_rebated, vault.sol_collected);
+}
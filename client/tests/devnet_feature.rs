@@ -0,0 +1,18 @@
+//! Release builds leave out the `devnet` bootstrap instruction. Feature
+//! unification must not switch it on behind the client's back either.
+#![cfg(not(feature = "devnet"))]
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program_error::ProgramError;
+
+const _: () = assert!(!siglab_contract::DEVNET_BUILD, "the program was built with `devnet`");
+
+#[test]
+fn release_builds_do_not_dispatch_bootstrap() {
+    let discriminator = &hash(b"global:bootstrap_dev_environment").to_bytes()[..8];
+
+    let result = siglab_contract::entry(&siglab_contract::ID, &[], discriminator);
+
+    assert_eq!(result, Err(ProgramError::Custom(ErrorCode::InstructionFallbackNotFound as u32)));
+}
@@ -0,0 +1,83 @@
+//! Stored enums encode as fixed one-byte tags. Each `expected` match below
+//! is exhaustive, so a new variant does not build until its tag is pinned.
+
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use siglab_contract::state::{ComparisonOperator, InsuranceType, PayoutStatus, PolicyStatus, TokenType};
+
+fn assert_round_trips<T>(variants: &[T], expected: fn(&T) -> u8)
+where
+    T: AnchorSerialize + AnchorDeserialize + std::fmt::Debug,
+{
+    for (index, variant) in variants.iter().enumerate() {
+        let tag = expected(variant);
+        assert_eq!(tag as usize, index, "{:?} is out of tag order", variant);
+        assert_eq!(variant.try_to_vec().unwrap(), [tag], "{:?}", variant);
+        let decoded = T::try_from_slice(&[tag]).unwrap();
+        assert_eq!(expected(&decoded), tag);
+    }
+    assert!(T::try_from_slice(&[variants.len() as u8]).is_err());
+    assert!(T::try_from_slice(&[0xF0]).is_err());
+    assert!(T::try_from_slice(&[]).is_err());
+}
+
+#[test]
+fn insurance_type_tags() {
+    assert_round_trips(InsuranceType::VARIANTS, |insurance_type| match insurance_type {
+        InsuranceType::Weather => 0,
+        InsuranceType::Earthquake => 1,
+        InsuranceType::Flight => 2,
+        InsuranceType::Crop => 3,
+        InsuranceType::Custom => 4,
+    });
+}
+
+#[test]
+fn policy_status_tags() {
+    assert_round_trips(PolicyStatus::VARIANTS, |status| match status {
+        PolicyStatus::Active => 0,
+        PolicyStatus::Expired => 1,
+        PolicyStatus::Cancelled => 2,
+        PolicyStatus::PendingPayout => 3,
+        PolicyStatus::PaidOut => 4,
+        PolicyStatus::PendingActivation => 5,
+    });
+}
+
+#[test]
+fn payout_status_tags() {
+    assert_round_trips(PayoutStatus::VARIANTS, |status| match status {
+        PayoutStatus::Pending => 0,
+        PayoutStatus::PendingApproval => 1,
+        PayoutStatus::Ready => 2,
+        PayoutStatus::Executed => 3,
+        PayoutStatus::Rejected => 4,
+        PayoutStatus::Expired => 5,
+    });
+}
+
+#[test]
+fn token_type_tags() {
+    assert_round_trips(TokenType::VARIANTS, |currency| match currency {
+        TokenType::USDC => 0,
+        TokenType::SOL => 1,
+    });
+}
+
+#[test]
+fn comparison_operator_tags() {
+    assert_round_trips(ComparisonOperator::VARIANTS, |operator| match operator {
+        ComparisonOperator::GreaterThan => 0,
+        ComparisonOperator::LessThan => 1,
+        ComparisonOperator::Equals => 2,
+        ComparisonOperator::NotEquals => 3,
+    });
+}
+
+#[test]
+fn tags_match_discriminants() {
+    for status in PolicyStatus::VARIANTS {
+        assert_eq!(status.clone() as u8, status.tag());
+        assert_eq!(PolicyStatus::from_tag(status.tag()).as_ref(), Some(status));
+    }
+    assert_eq!(InsuranceType::from_tag(InsuranceType::COUNT as u8), None);
+}
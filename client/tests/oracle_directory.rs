@@ -0,0 +1,71 @@
+use bytemuck::Zeroable;
+use siglab_contract::constants::MAX_ORACLES;
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::OracleDirectory;
+use solana_sdk::pubkey::Pubkey;
+
+fn oracle(oracle_id: &str) -> Pubkey {
+    Pubkey::find_program_address(&[b"oracle", oracle_id.as_bytes()], &siglab_contract::ID).0
+}
+
+fn full_directory() -> OracleDirectory {
+    let mut directory = OracleDirectory::zeroed();
+    for index in 0..MAX_ORACLES {
+        let oracle_id = format!("feed-{}", index);
+        directory.record_registration(&oracle_id, oracle(&oracle_id), Pubkey::new_unique()).unwrap();
+    }
+    directory
+}
+
+#[test]
+fn resolves_both_ways() {
+    let mut directory = OracleDirectory::zeroed();
+    let authority = Pubkey::new_unique();
+    directory.record_registration("rainfall-feed", oracle("rainfall-feed"), authority).unwrap();
+
+    let entry = directory.find_by_id("rainfall-feed").unwrap();
+    assert_eq!(entry.oracle, oracle("rainfall-feed"));
+    assert_eq!(entry.authority, authority);
+    assert_eq!(directory.find_by_address(&oracle("rainfall-feed")), Some(entry));
+    assert_eq!(directory.find_by_id("wind-feed"), None);
+    assert_eq!(directory.find_by_address(&Pubkey::default()), None);
+}
+
+#[test]
+fn re_registration_reuses_the_slot() {
+    let mut directory = full_directory();
+    directory.record_unregistration(&oracle("feed-3"));
+    assert!(!directory.find_by_id("feed-3").unwrap().is_registered());
+
+    let authority = Pubkey::new_unique();
+    directory.record_registration("feed-3", oracle("feed-3"), authority).unwrap();
+    assert_eq!(directory.entries[3].authority, authority);
+    assert!(directory.entries[3].is_registered());
+    assert_eq!(directory.entries.iter().filter(|entry| entry.oracle == oracle("feed-3")).count(), 1);
+}
+
+#[test]
+fn new_ids_prefer_unused_slots() {
+    let mut directory = OracleDirectory::zeroed();
+    directory.record_registration("feed-0", oracle("feed-0"), Pubkey::new_unique()).unwrap();
+    directory.record_unregistration(&oracle("feed-0"));
+
+    // An unregistered id keeps its slot while unused ones remain
+    directory.record_registration("feed-1", oracle("feed-1"), Pubkey::new_unique()).unwrap();
+    assert_eq!(directory.entries[1].oracle, oracle("feed-1"));
+    assert!(directory.find_by_id("feed-0").is_some());
+}
+
+#[test]
+fn full_directory_recycles_unregistered_slots() {
+    let mut directory = full_directory();
+    assert_eq!(
+        directory.record_registration("wind-feed", oracle("wind-feed"), Pubkey::new_unique()).unwrap_err(),
+        InsuranceError::MaxOraclesExceeded.into()
+    );
+
+    directory.record_unregistration(&oracle("feed-5"));
+    directory.record_registration("wind-feed", oracle("wind-feed"), Pubkey::new_unique()).unwrap();
+    assert_eq!(directory.entries[5].oracle, oracle("wind-feed"));
+    assert_eq!(directory.find_by_id("feed-5"), None);
+}
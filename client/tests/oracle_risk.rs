@@ -0,0 +1,91 @@
+use bytemuck::Zeroable;
+use siglab_contract::instructions::oracle::update_oracle_health;
+use siglab_contract::state::{Oracle, OracleHealthMetrics, OracleRiskParams};
+
+const NOW: i64 = 1_700_000_000;
+
+fn risk(
+    failure_threshold: u32,
+    reputation_penalty: u8,
+    accuracy_penalty: u8,
+    recovery_rate: u8,
+) -> OracleRiskParams {
+    OracleRiskParams { failure_threshold, reputation_penalty, accuracy_penalty, recovery_rate, ..Default::default() }
+}
+
+/// Freshly registered oracle with perfect scores
+fn oracle() -> Oracle {
+    let mut oracle = Oracle::zeroed();
+    oracle.reputation_score = 100;
+    oracle.health_metrics = OracleHealthMetrics::new();
+    oracle
+}
+
+fn fail(oracle: &mut Oracle, times: u32, risk_params: &OracleRiskParams) {
+    for _ in 0..times {
+        update_oracle_health(oracle, false, NOW, risk_params).unwrap();
+    }
+}
+
+/// Failures it takes to trip the breaker, with the reputation and accuracy left at that point
+fn trip(risk_params: &OracleRiskParams) -> (u32, u8, u8) {
+    let mut oracle = oracle();
+    let mut failures = 0;
+    while !oracle.health_metrics.circuit_breaker_active() {
+        fail(&mut oracle, 1, risk_params);
+        failures += 1;
+    }
+    (failures, oracle.reputation_score, oracle.health_metrics.accuracy_score)
+}
+
+#[test]
+fn defaults_keep_the_original_trip_point_and_penalties() {
+    assert_eq!(OracleRiskParams::default(), risk(5, 3, 5, 1));
+    assert_eq!(trip(&OracleRiskParams::default()), (5, 85, 75));
+}
+
+#[test]
+fn breaker_trips_at_the_configured_failure_count() {
+    for (risk_params, expected) in [
+        (risk(1, 3, 5, 1), (1, 97, 95)),
+        (risk(3, 10, 20, 1), (3, 70, 40)),
+        (risk(4, 0, 0, 1), (4, 100, 100)),
+        // Scores bottom out at zero however harsh the penalties
+        (risk(10, 20, 15, 1), (10, 0, 0)),
+    ] {
+        assert_eq!(trip(&risk_params), expected, "{risk_params:?}");
+    }
+}
+
+#[test]
+fn breaker_stays_open_below_the_threshold() {
+    let risk_params = risk(8, 3, 5, 1);
+    let mut oracle = oracle();
+    fail(&mut oracle, 7, &risk_params);
+    assert!(!oracle.health_metrics.circuit_breaker_active());
+    fail(&mut oracle, 1, &risk_params);
+    assert!(oracle.health_metrics.circuit_breaker_active());
+}
+
+#[test]
+fn successful_updates_recover_at_the_configured_rate() {
+    for (recovery_rate, expected) in [(0, 70), (1, 73), (4, 82), (50, 100)] {
+        let risk_params = risk(5, 10, 10, recovery_rate);
+        let mut oracle = oracle();
+        fail(&mut oracle, 3, &risk_params);
+        for _ in 0..3 {
+            update_oracle_health(&mut oracle, true, NOW, &risk_params).unwrap();
+        }
+        let scores = (oracle.reputation_score, oracle.health_metrics.accuracy_score);
+        assert_eq!(scores, (expected, expected), "recovery rate {recovery_rate}");
+    }
+}
+
+#[test]
+fn validation_rejects_unreachable_breakers_and_out_of_scale_scores() {
+    assert!(risk(0, 3, 5, 1).validate().is_err());
+    assert!(risk(5, 101, 5, 1).validate().is_err());
+    assert!(risk(5, 3, 101, 1).validate().is_err());
+    assert!(risk(5, 3, 5, 101).validate().is_err());
+    assert!(risk(5, 100, 100, 100).validate().is_ok());
+}
@@ -0,0 +1,80 @@
+use siglab_contract::constants::DEFAULT_ORACLE_CLOCK_SKEW_SECS;
+use siglab_contract::error::InsuranceError;
+use siglab_contract::instructions::oracle::{extract_pyth_price_data, parse_pyth_format};
+use siglab_contract::utils::error_utils::validate_oracle_timestamp;
+
+const NOW: i64 = 1_700_000_000;
+const SKEW: u32 = DEFAULT_ORACLE_CLOCK_SKEW_SECS;
+
+/// Compact Pyth reading of 42 ± 1 published at `timestamp`
+fn pyth_reading(timestamp: i64) -> Vec<u8> {
+    [42u64.to_le_bytes(), timestamp.to_le_bytes(), 1u64.to_le_bytes()].concat()
+}
+
+/// Pyth price account holding 42 ± 1 published at `timestamp`
+fn pyth_account(timestamp: i64) -> Vec<u8> {
+    let mut data = vec![0; 264];
+    data[0..4].copy_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    data[208..216].copy_from_slice(&42i64.to_le_bytes());
+    data[216..224].copy_from_slice(&1u64.to_le_bytes());
+    data[256..264].copy_from_slice(&timestamp.to_le_bytes());
+    data
+}
+
+#[test]
+fn undated_readings_are_rejected() {
+    for timestamp in [0, -1, i64::MIN] {
+        assert_eq!(
+            validate_oracle_timestamp(timestamp, NOW, SKEW),
+            Err(InsuranceError::OracleTimestampMissing.into())
+        );
+    }
+    assert!(validate_oracle_timestamp(1, NOW, SKEW).is_ok());
+}
+
+#[test]
+fn readings_may_lead_the_clock_by_the_skew() {
+    assert!(validate_oracle_timestamp(NOW, NOW, SKEW).is_ok());
+    assert!(validate_oracle_timestamp(NOW + SKEW as i64, NOW, SKEW).is_ok());
+    assert_eq!(
+        validate_oracle_timestamp(NOW + SKEW as i64 + 1, NOW, SKEW),
+        Err(InsuranceError::OracleTimestampInFuture.into())
+    );
+}
+
+#[test]
+fn zero_skew_rejects_any_future_reading() {
+    assert!(validate_oracle_timestamp(NOW, NOW, 0).is_ok());
+    assert_eq!(validate_oracle_timestamp(NOW + 1, NOW, 0), Err(InsuranceError::OracleTimestampInFuture.into()));
+}
+
+#[test]
+fn pyth_readings_are_checked_on_parse() {
+    assert_eq!(parse_pyth_format(&pyth_reading(NOW), NOW, SKEW).unwrap().timestamp, NOW);
+    assert_eq!(
+        parse_pyth_format(&pyth_reading(0), NOW, SKEW).unwrap_err(),
+        InsuranceError::OracleTimestampMissing.into()
+    );
+    assert_eq!(
+        parse_pyth_format(&pyth_reading(NOW + SKEW as i64 + 1), NOW, SKEW).unwrap_err(),
+        InsuranceError::OracleTimestampInFuture.into()
+    );
+}
+
+#[test]
+fn pyth_accounts_are_checked_on_extraction() {
+    assert_eq!(extract_pyth_price_data(&pyth_account(NOW), NOW, SKEW), Ok((42, 1, NOW)));
+    assert_eq!(
+        extract_pyth_price_data(&pyth_account(NOW + SKEW as i64), NOW, SKEW),
+        Ok((42, 1, NOW + SKEW as i64))
+    );
+    // A malformed account with no publish time
+    assert_eq!(
+        extract_pyth_price_data(&pyth_account(0), NOW, SKEW),
+        Err(InsuranceError::OracleTimestampMissing.into())
+    );
+    assert_eq!(
+        extract_pyth_price_data(&pyth_account(NOW + SKEW as i64 + 1), NOW, SKEW),
+        Err(InsuranceError::OracleTimestampInFuture.into())
+    );
+}
@@ -0,0 +1,71 @@
+use anchor_lang::AccountSerialize;
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{PayoutStatus, PendingPayout, Policy};
+use solana_sdk::pubkey::Pubkey;
+
+const MAX_SIGNERS: usize = Policy::MAX_QUORUM_SIGNATURES as usize;
+
+fn payout() -> PendingPayout {
+    PendingPayout {
+        policy_id: 0,
+        amount: 0,
+        timestamp: 0,
+        priority: 0,
+        status: PayoutStatus::Pending,
+        beneficiary: Pubkey::default(),
+        trigger_oracle_data: Vec::new(),
+        severity_score: 0,
+        approval_timestamp: None,
+        approved_by: None,
+        expires_at: 0,
+        rejection_reason: None,
+        bump: 0,
+        quorum_signers: Vec::new(),
+        incident_timestamp: 0,
+        base_priority: 0,
+        rent_payer: Pubkey::default(),
+        trigger_evidence: None,
+    }
+}
+
+#[test]
+fn maximum_evidence_fills_the_reserved_space_exactly() {
+    let mut payout = payout();
+    payout.set_trigger_oracle_data(vec![0xff; PendingPayout::MAX_ORACLE_DATA_LENGTH]).unwrap();
+    payout.set_quorum_signers((0..MAX_SIGNERS).map(|_| Pubkey::new_unique()).collect()).unwrap();
+    payout.approval_timestamp = Some(i64::MAX);
+    payout.approved_by = Some(Pubkey::new_unique());
+    payout.rejection_reason = Some("x".repeat(PendingPayout::MAX_REJECTION_REASON_LENGTH));
+    payout.trigger_evidence = Some(Pubkey::new_unique());
+
+    let mut data = Vec::new();
+    payout.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), PendingPayout::space());
+}
+
+#[test]
+fn oversized_evidence_is_rejected_before_it_is_written() {
+    let mut payout = payout();
+    assert_eq!(
+        payout.set_trigger_oracle_data(vec![0; PendingPayout::MAX_ORACLE_DATA_LENGTH + 1]),
+        Err(InsuranceError::EvidenceTooLarge.into())
+    );
+    assert!(payout.trigger_oracle_data.is_empty());
+
+    let signers = (0..=MAX_SIGNERS).map(|_| Pubkey::new_unique()).collect();
+    assert_eq!(payout.set_quorum_signers(signers), Err(InsuranceError::EvidenceTooLarge.into()));
+    assert!(payout.quorum_signers.is_empty());
+}
+
+#[test]
+fn oversized_quorums_keep_the_most_reputable_signers() {
+    let signers: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+    let reputations = [40, 90, 60, 90, 10, 75];
+    let ranked = PendingPayout::most_reputable_signers(signers.iter().copied().zip(reputations).collect());
+
+    // Ties keep attestation order
+    assert_eq!(ranked, vec![signers[1], signers[3], signers[5], signers[2]]);
+
+    let few = PendingPayout::most_reputable_signers(vec![(signers[0], 10), (signers[1], 20)]);
+    assert_eq!(few, vec![signers[1], signers[0]]);
+}
@@ -0,0 +1,129 @@
+use siglab_contract::state::{PayoutStatus, PendingPayout};
+use siglab_contract_client::queue::{expired_payouts, next_payout_batch, queue_statistics};
+use solana_sdk::pubkey::Pubkey;
+
+const NOW: i64 = 1_700_000_000;
+const HOUR: i64 = 3600;
+
+fn payout(priority: u8, timestamp: i64, status: PayoutStatus) -> PendingPayout {
+    PendingPayout {
+        policy_id: 0,
+        amount: 1_000,
+        timestamp,
+        priority,
+        status,
+        beneficiary: Pubkey::default(),
+        trigger_oracle_data: Vec::new(),
+        severity_score: 0,
+        approval_timestamp: None,
+        approved_by: None,
+        expires_at: timestamp + 86400,
+        rejection_reason: None,
+        bump: 0,
+        quorum_signers: Vec::new(),
+        incident_timestamp: timestamp,
+        base_priority: priority,
+        rent_payer: Pubkey::default(),
+        trigger_evidence: None,
+    }
+}
+
+fn ready(priority: u8, timestamp: i64) -> PendingPayout {
+    payout(priority, timestamp, PayoutStatus::Ready)
+}
+
+#[test]
+fn batches_order_by_priority_then_age() {
+    let payouts = [ready(50, NOW - 10), ready(90, NOW - 5), ready(50, NOW - 20), ready(90, NOW - 30)];
+    let mut batch = [0; 4];
+    
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[3, 1, 2, 0]);
+}
+
+#[test]
+fn old_low_priority_claims_overtake_fresh_high_priority_ones_once_aged() {
+    let mut payouts = [ready(90, NOW), ready(50, NOW - 21 * HOUR)];
+    let mut batch = [0; 2];
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[0, 1]);
+    
+    // Ordering follows the priority `recompute_priority` persists
+    payouts[1].priority = payouts[1].effective_priority(NOW);
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[1, 0]);
+    
+    let mut payouts = [ready(90, NOW), ready(50, NOW - 19 * HOUR)];
+    payouts[1].priority = payouts[1].effective_priority(NOW);
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[0, 1]);
+}
+
+#[test]
+fn aging_adds_points_per_full_hour_up_to_a_cap() {
+    assert_eq!(ready(50, NOW).effective_priority(NOW), 50);
+    assert_eq!(ready(50, NOW - HOUR + 1).effective_priority(NOW), 50);
+    assert_eq!(ready(50, NOW - 10 * HOUR).effective_priority(NOW), 70);
+    assert_eq!(ready(50, NOW - 25 * HOUR).effective_priority(NOW), 100);
+    assert_eq!(ready(50, NOW - 100 * HOUR).effective_priority(NOW), 100);
+    assert_eq!(ready(50, NOW + HOUR).effective_priority(NOW), 50);
+}
+
+#[test]
+fn aging_starts_from_the_base_priority() {
+    let mut payout = ready(50, NOW - 10 * HOUR);
+    payout.priority = payout.effective_priority(NOW);
+    
+    assert_eq!(payout.effective_priority(NOW), 70);
+}
+
+#[test]
+fn batches_keep_only_the_first_entries() {
+    let payouts = [ready(10, NOW), ready(30, NOW), ready(20, NOW), ready(40, NOW)];
+    let mut batch = [0; 2];
+    
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[3, 1]);
+}
+
+#[test]
+fn batches_break_ties_by_position() {
+    let payouts = [ready(70, NOW), ready(70, NOW), ready(70, NOW)];
+    let mut batch = [0; 3];
+    
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[0, 1, 2]);
+}
+
+#[test]
+fn batches_skip_unready_and_expired_payouts() {
+    let payouts = [
+        payout(99, NOW, PayoutStatus::PendingApproval),
+        ready(99, NOW - 2 * 86400),
+        ready(10, NOW),
+    ];
+    let mut batch = [0; 3];
+    
+    assert_eq!(next_payout_batch(&payouts, NOW, &mut batch), &[2]);
+    assert!(next_payout_batch(&payouts, NOW, &mut []).is_empty());
+}
+
+#[test]
+fn counts_expired_payouts_for_cleanup() {
+    let payouts = [ready(10, NOW - 2 * 86400), ready(10, NOW), ready(10, NOW - 86400 - 1), ready(10, NOW - 86400)];
+    
+    assert_eq!(expired_payouts(&payouts, NOW).collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(expired_payouts(&payouts[1..2], NOW).count(), 0);
+}
+
+#[test]
+fn summarizes_the_queue() {
+    let payouts = [
+        ready(10, NOW - 2 * 86400),
+        ready(10, NOW - 100),
+        payout(10, NOW - 50, PayoutStatus::PendingApproval),
+    ];
+    let stats = queue_statistics(&payouts, NOW);
+    
+    assert_eq!(stats.total_count, 3);
+    assert_eq!(stats.ready_count, 2);
+    assert_eq!(stats.pending_approval_count, 1);
+    assert_eq!(stats.expired_count, 1);
+    assert_eq!(stats.total_amount, 3_000);
+    assert_eq!(stats.oldest_timestamp, NOW - 2 * 86400);
+    assert_eq!(queue_statistics(&[], NOW).oldest_timestamp, NOW);
+}
@@ -0,0 +1,38 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::Treasury;
+
+fn treasury(sol_balance: u64) -> Treasury {
+    let data = [Treasury::DISCRIMINATOR, &[0; 512]].concat();
+    let mut treasury = Treasury::try_deserialize(&mut &data[..]).unwrap();
+    treasury.total_sol_balance = sol_balance;
+    treasury.minimum_reserve_ratio = 2_000;
+    treasury
+}
+
+#[test]
+fn second_trigger_beyond_the_unreserved_balance_fails() {
+    let mut treasury = treasury(1_000);
+
+    treasury.reserve_for_payout(600).unwrap();
+    assert_eq!(treasury.reserve_for_payout(600), Err(InsuranceError::InsufficientTreasury.into()));
+    assert_eq!(treasury.reserved_for_payouts, 600);
+
+    // What is left can still be earmarked
+    treasury.reserve_for_payout(400).unwrap();
+    assert_eq!(treasury.unreserved_balance(), 0);
+}
+
+#[test]
+fn reservations_are_not_available_liquidity() {
+    let mut treasury = treasury(1_000);
+    treasury.total_coverage_exposure = 1_000;
+
+    treasury.reserve_for_payout(600).unwrap();
+    // 400 unreserved, 200 of it required against the exposure
+    assert_eq!(treasury.available_liquidity(), 200);
+
+    treasury.release_payout_reservation(600);
+    assert_eq!(treasury.available_liquidity(), 800);
+    assert_eq!(treasury.reserved_for_payouts, 0);
+}
@@ -0,0 +1,69 @@
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use siglab_contract::state::{EndorsementKind, PayoutRecord, Policy, PolicyHistory};
+use solana_sdk::pubkey::Pubkey;
+
+fn blank<T: AccountDeserialize + Discriminator>(space: usize) -> T {
+    let data = [T::DISCRIMINATOR, &vec![0; space][..]].concat();
+    T::try_deserialize(&mut &data[..]).unwrap()
+}
+
+fn payout(timestamp: i64) -> PayoutRecord {
+    PayoutRecord {
+        amount: u64::MAX,
+        timestamp,
+        transaction_id: "x".repeat(Policy::MAX_TRANSACTION_ID_LENGTH),
+        oracle_data: "9".repeat(Policy::MAX_PAYOUT_ORACLE_DATA_LENGTH),
+        incident_timestamp: timestamp,
+        incident_fingerprint: [0xff; 32],
+        destination_rent: u64::MAX,
+        net_amount: u64::MAX,
+    }
+}
+
+#[test]
+fn policy_no_longer_reserves_room_for_its_records() {
+    // Terms read on every instruction stay apart from the records appended to
+    assert!(Policy::space() < PolicyHistory::space());
+    let mut policy: Policy = blank(Policy::space());
+    policy.metadata = "m".repeat(Policy::MAX_METADATA_LENGTH);
+
+    let mut data = Vec::new();
+    policy.try_serialize(&mut data).unwrap();
+    assert!(data.len() <= Policy::space());
+}
+
+#[test]
+fn full_history_fills_the_reserved_space_exactly() {
+    let mut history: PolicyHistory = blank(PolicyHistory::space());
+    history.open(Pubkey::new_unique(), 255);
+    for timestamp in 0..PolicyHistory::MAX_PAYOUT_HISTORY as i64 {
+        history.record_payout(payout(timestamp));
+    }
+    for timestamp in 0..PolicyHistory::MAX_PREMIUM_HISTORY as i64 {
+        history.record_premium(u64::MAX, timestamp);
+    }
+    while history.can_endorse() {
+        history.endorse(EndorsementKind::OracleRepoint, [1; 32], [2; 32], Pubkey::new_unique(), 0).unwrap();
+    }
+
+    let mut data = Vec::new();
+    history.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), PolicyHistory::space());
+    let restored = PolicyHistory::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(restored.endorsements.len(), PolicyHistory::MAX_ENDORSEMENTS);
+}
+
+#[test]
+fn premiums_are_totalled_on_the_policy_and_ledgered_in_its_history() {
+    let mut policy: Policy = blank(Policy::space());
+    let mut history: PolicyHistory = blank(PolicyHistory::space());
+    let payments = PolicyHistory::MAX_PREMIUM_HISTORY as i64 + 1;
+    for timestamp in 0..payments {
+        policy.record_premium(&mut history, 100, timestamp).unwrap();
+    }
+
+    assert_eq!(policy.total_premiums_paid, 100 * payments as u64);
+    assert_eq!(history.premium_history.len(), PolicyHistory::MAX_PREMIUM_HISTORY);
+    assert_eq!(history.premium_history[0].timestamp, 1);
+    assert_eq!(history.latest_premium().unwrap().timestamp, payments - 1);
+}
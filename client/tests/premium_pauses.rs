@@ -0,0 +1,91 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use siglab_contract::state::{
+    ArrearsMode, InstallmentSchedule, MasterInsuranceContract, Policy, PolicyStatus, PremiumFrequency,
+    PremiumPause,
+};
+
+const DAY: i64 = 86_400;
+const PREMIUM: u64 = 1_000_000_000;
+
+fn blank<T: AccountDeserialize + Discriminator>(space: usize) -> T {
+    let data = [T::DISCRIMINATOR, &vec![0; space][..]].concat();
+    T::try_deserialize(&mut &data[..]).unwrap()
+}
+
+/// Master contract charging 1% of the premium per day overdue
+fn master() -> MasterInsuranceContract {
+    let mut master: MasterInsuranceContract = blank(MasterInsuranceContract::space());
+    master.global_config.late_fee_bps = 100;
+    master
+}
+
+/// Monthly policy whose next premium falls due on day 30
+fn policy() -> Policy {
+    let mut policy: Policy = blank(Policy::space());
+    policy.status = PolicyStatus::Active;
+    policy.premium_amount = PREMIUM;
+    policy.premium_payment_frequency = PremiumFrequency::Monthly;
+    policy.last_premium_paid = DAY;
+    policy.end_date = 365 * DAY;
+    policy
+}
+
+#[test]
+fn three_day_pause_spanning_the_due_date_is_not_overdue() {
+    let due = 31 * DAY;
+    let mut master = master();
+    let policy = policy();
+    assert_eq!(policy.premium_due_date(), Some(due));
+
+    // Paused a day before the premium fell due
+    master.premiums_paused_at = Some(due - DAY);
+    assert_eq!(policy.late_fee(&master, due + 2 * DAY), 0);
+
+    // Once payments reopen, only days past the due date while payable count
+    assert_eq!(master.end_premium_pause(due + 2 * DAY), 3 * DAY);
+    assert_eq!(policy.late_fee(&master, due + 2 * DAY), 0);
+    assert_eq!(policy.late_fee(&master, due + 3 * DAY), PREMIUM / 100);
+
+    // Without the pause the holder would owe three days' fees
+    assert_eq!(policy.late_fee(&self::master(), due + 3 * DAY), 3 * PREMIUM / 100);
+}
+
+#[test]
+fn pauses_that_allow_premiums_do_not_defer_them() {
+    let due = 31 * DAY;
+    let mut master = master();
+    master.is_paused = true;
+    assert_eq!(policy().late_fee(&master, due + 3 * DAY), 3 * PREMIUM / 100);
+}
+
+#[test]
+fn installments_falling_due_while_blocked_are_not_in_arrears() {
+    let mut policy = policy();
+    policy.installment_schedule = Some(InstallmentSchedule {
+        count: 3,
+        amount: PREMIUM,
+        first_due: 10 * DAY,
+        period_secs: 30 * DAY,
+        arrears_mode: ArrearsMode::NoCoverageWhenBehind,
+    });
+    let mut master = master();
+    master.premiums_paused_at = Some(9 * DAY);
+    assert_eq!(policy.payable_coverage_bps(&master, 11 * DAY), Some(10_000));
+
+    // Blocked from day 9 to 12: behind once a payable day has passed
+    master.end_premium_pause(12 * DAY);
+    assert_eq!(policy.payable_coverage_bps(&master, 12 * DAY), Some(10_000));
+    assert_eq!(policy.payable_coverage_bps(&master, 12 * DAY + 1), None);
+    assert_eq!(policy.payable_coverage_bps(&self::master(), 11 * DAY), None);
+}
+
+#[test]
+fn only_the_latest_pauses_are_kept() {
+    let mut master = master();
+    for day in 0..10 {
+        master.premiums_paused_at = Some(day * DAY);
+        master.end_premium_pause(day * DAY + 1);
+    }
+    assert_eq!(master.premium_pauses.len(), 8);
+    assert_eq!(master.premium_pauses[0], PremiumPause { started_at: 2 * DAY, ended_at: 2 * DAY + 1 });
+}
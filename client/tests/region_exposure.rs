@@ -0,0 +1,62 @@
+use siglab_contract::error::InsuranceError;
+use siglab_contract::state::{RegionExposure, RegionLimits, TokenType};
+
+const DAY: i64 = 86_400;
+const NOW: i64 = 19_700 * DAY + 3_600;
+
+fn region() -> RegionExposure {
+    RegionExposure {
+        region_code: *b"US-CA\0\0\0",
+        sol_exposure: 0,
+        usdc_exposure: 0,
+        payout_day: NOW / DAY,
+        sol_paid_today: 0,
+        usdc_paid_today: 0,
+        opened_at: NOW,
+        bump: 255,
+    }
+}
+
+#[test]
+fn exposure_is_capped_per_currency() {
+    let mut region = region();
+    region.add_exposure(TokenType::SOL, 60, Some(100)).unwrap();
+    region.add_exposure(TokenType::USDC, 90, Some(100)).unwrap();
+    assert_eq!(
+        region.add_exposure(TokenType::SOL, 41, Some(100)).unwrap_err(),
+        InsuranceError::RegionExposureCapExceeded.into()
+    );
+    region.add_exposure(TokenType::SOL, 40, Some(100)).unwrap();
+
+    // Released coverage makes room again, and nothing is refused while uncapped
+    region.release_exposure(TokenType::SOL, 50);
+    assert_eq!(region.exposure(TokenType::SOL), 50);
+    region.add_exposure(TokenType::SOL, u64::MAX - 50, None).unwrap();
+    assert_eq!(region.exposure(TokenType::USDC), 90);
+}
+
+#[test]
+fn daily_payouts_restart_each_day() {
+    let mut region = region();
+    region.record_payout(TokenType::SOL, 70, NOW, Some(100)).unwrap();
+    assert_eq!(
+        region.record_payout(TokenType::SOL, 31, NOW + 60, Some(100)).unwrap_err(),
+        InsuranceError::RegionDailyPayoutCapExceeded.into()
+    );
+    region.record_payout(TokenType::USDC, 100, NOW, Some(100)).unwrap();
+
+    let tomorrow = (NOW / DAY + 1) * DAY;
+    assert_eq!(region.paid_today(TokenType::SOL, tomorrow - 1), 70);
+    assert_eq!(region.paid_today(TokenType::SOL, tomorrow), 0);
+    region.record_payout(TokenType::SOL, 100, tomorrow, Some(100)).unwrap();
+    assert_eq!(region.payout_day, tomorrow / DAY);
+    assert_eq!(region.paid_today(TokenType::USDC, tomorrow), 0);
+}
+
+#[test]
+fn limits_reject_a_zero_cap() {
+    RegionLimits::default().validate().unwrap();
+    RegionLimits { max_exposure_bps: Some(2_500), max_daily_payout_bps: Some(500) }.validate().unwrap();
+    assert!(RegionLimits { max_exposure_bps: Some(0), max_daily_payout_bps: None }.validate().is_err());
+    assert!(RegionLimits { max_exposure_bps: None, max_daily_payout_bps: Some(0) }.validate().is_err());
+}
@@ -0,0 +1,113 @@
+use anchor_lang::AnchorSerialize;
+use siglab_contract::state::{
+    ClaimEligibilityView, ClaimVerdict, OracleDirectoryView, PayoutIneligibility, PolicyStatus, PolicyStatusView,
+    SolvencyState, TreasuryHealthView, ViewResponse,
+};
+use siglab_contract_client::views::{
+    decode_claim_eligibility, decode_oracle_directory, decode_policy_status, decode_treasury_health, ViewError,
+};
+use solana_sdk::pubkey::Pubkey;
+
+fn policy_status() -> PolicyStatusView {
+    PolicyStatusView {
+        status: PolicyStatus::PendingPayout,
+        days_remaining: 29,
+        premium_due_date: Some(1_702_592_000),
+        pending_payout: Some(Pubkey::new_from_array([7; 32])),
+    }
+}
+
+#[test]
+fn decodes_what_the_program_encodes() {
+    let view = policy_status();
+    let data = ViewResponse::new(view.clone()).try_to_vec().unwrap();
+
+    assert_eq!(decode_policy_status(&data).unwrap(), view);
+}
+
+#[test]
+fn policy_status_layout_is_stable() {
+    let view = PolicyStatusView {
+        status: PolicyStatus::Active,
+        days_remaining: 30,
+        premium_due_date: None,
+        pending_payout: None,
+    };
+    let data = ViewResponse::new(view).try_to_vec().unwrap();
+
+    // version, status, days_remaining (u32 LE), two absent options
+    assert_eq!(data, [1, 0, 30, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn treasury_health_layout_is_stable() {
+    let view = TreasuryHealthView {
+        reserve_ratio: 10_000,
+        minimum_reserve_ratio: 2_000,
+        solvency: SolvencyState::Undercapitalized,
+        available_liquidity: 5,
+        open_payout_count: 3,
+        max_open_payouts: 10_000,
+    };
+    let data = ViewResponse::new(view.clone()).try_to_vec().unwrap();
+
+    assert_eq!(
+        data,
+        [
+            2, 0x10, 0x27, 0xd0, 0x07, 1, 5, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0x10, 0x27, 0, 0, 0, 0, 0, 0
+        ]
+    );
+    assert_eq!(decode_treasury_health(&data).unwrap(), view);
+}
+
+#[test]
+fn oracle_directory_layout_is_stable() {
+    let view = OracleDirectoryView {
+        id_hash: [7; 32],
+        oracle: Pubkey::new_from_array([1; 32]),
+        authority: Pubkey::new_from_array([2; 32]),
+        registered: true,
+    };
+    let data = ViewResponse::new(view.clone()).try_to_vec().unwrap();
+
+    // version, id hash, oracle, authority, registered flag
+    assert_eq!(data, [&[1][..], &[7; 32], &[1; 32], &[2; 32], &[1]].concat());
+    assert_eq!(decode_oracle_directory(&data).unwrap(), view);
+}
+
+#[test]
+fn claim_eligibility_layout_is_stable() {
+    let view = ClaimEligibilityView {
+        verdict: ClaimVerdict::Ineligible(PayoutIneligibility::BelowMinimum),
+        oracle_value: Some(80),
+        payout_amount: 0,
+    };
+    let data = ViewResponse::new(view.clone()).try_to_vec().unwrap();
+
+    // version, verdict with its ineligibility, present oracle value, payout amount
+    assert_eq!(data, [&[1, 12, 7, 1, 80][..], &[0; 7], &[0; 8]].concat());
+    assert_eq!(decode_claim_eligibility(&data).unwrap(), view);
+
+    let view = ClaimEligibilityView { verdict: ClaimVerdict::WaitingPeriod, oracle_value: None, payout_amount: 0 };
+    assert_eq!(ViewResponse::new(view).try_to_vec().unwrap()[..3], [1, 8, 0]);
+}
+
+#[test]
+fn rejects_unknown_versions() {
+    let mut data = ViewResponse::new(policy_status()).try_to_vec().unwrap();
+    data[0] = 2;
+
+    assert!(matches!(
+        decode_policy_status(&data),
+        Err(ViewError::UnsupportedVersion { expected: 1, found: 2 })
+    ));
+}
+
+#[test]
+fn rejects_truncated_and_trailing_data() {
+    let data = ViewResponse::new(policy_status()).try_to_vec().unwrap();
+
+    assert!(matches!(decode_policy_status(&data[..data.len() - 1]), Err(ViewError::Decode(_))));
+    assert!(matches!(decode_policy_status(&[data.as_slice(), &[0]].concat()), Err(ViewError::Decode(_))));
+    assert!(matches!(decode_policy_status(&[]), Err(ViewError::Decode(_))));
+}
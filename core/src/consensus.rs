@@ -0,0 +1,175 @@
+/// Statistics agreed on by a set of oracle values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConsensusStats {
+    /// Mean of the values (unweighted for now)
+    pub aggregated_value: u64,
+    /// Median of the values
+    pub median_value: u64,
+    /// Integer population standard deviation
+    pub standard_deviation: u64,
+    /// Agreement score (0-100), lower as the values spread out
+    pub confidence_score: u8,
+}
+
+/// Integer mean of the values
+pub fn mean(values: &[u64]) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    let sum: u64 = values.iter().sum();
+    sum / values.len() as u64
+}
+
+/// Integer population variance around `mean`
+pub fn variance(values: &[u64], mean: u64) -> u64 {
+    if values.is_empty() {
+        return 0;
+    }
+    values
+        .iter()
+        .map(|&value| {
+            let diff = value.abs_diff(mean);
+            diff * diff
+        })
+        .sum::<u64>() / values.len() as u64
+}
+
+/// Simple integer square root using binary search
+pub fn integer_sqrt(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    
+    let mut left = 1u64;
+    let mut right = n;
+    let mut result = 0u64;
+    
+    while left <= right {
+        let mid = left + (right - left) / 2;
+        
+        if mid <= n / mid {
+            result = mid;
+            left = mid + 1;
+        } else {
+            right = mid - 1;
+        }
+    }
+    
+    result
+}
+
+/// Median of already sorted values
+pub fn median_sorted(sorted_values: &[u64]) -> u64 {
+    if sorted_values.is_empty() {
+        return 0;
+    }
+    
+    let len = sorted_values.len();
+    if len.is_multiple_of(2) {
+        (sorted_values[len / 2 - 1] + sorted_values[len / 2]) / 2
+    } else {
+        sorted_values[len / 2]
+    }
+}
+
+/// Confidence score from the spread of the values relative to their mean
+pub fn confidence_score(mean: u64, std_dev: u64) -> u8 {
+    if mean == 0 {
+        return 0;
+    }
+    
+    // Confidence decreases as the coefficient of variation increases
+    let coefficient_of_variation = (std_dev * 100) / mean;
+    if coefficient_of_variation > 100 {
+        0
+    } else {
+        (100 - coefficient_of_variation) as u8
+    }
+}
+
+/// Spread of the values as their standard deviation over their mean, in
+/// basis points; `u64::MAX` when a zero mean leaves it undefined
+pub fn coefficient_of_variation_bps(values: &[u64]) -> u64 {
+    let mean = mean(values);
+    let std_dev = integer_sqrt(variance(values, mean));
+    if std_dev == 0 {
+        return 0;
+    }
+    if mean == 0 {
+        return u64::MAX;
+    }
+    (std_dev as u128 * 10_000 / mean as u128).min(u64::MAX as u128) as u64
+}
+
+/// Inclusive range of values kept by [`remove_outliers`], two standard
+/// deviations either side of the mean
+///
+/// `None` for sets of two or fewer, which are kept whole.
+pub fn outlier_bounds(values: &[u64]) -> Option<(u64, u64)> {
+    if values.len() <= 2 {
+        return None;
+    }
+    
+    let mean = mean(values);
+    let std_dev = integer_sqrt(variance(values, mean));
+    
+    // Keep values within 2 standard deviations
+    let threshold = std_dev * 2;
+    Some((mean.saturating_sub(threshold), mean + threshold))
+}
+
+/// Drop values beyond two standard deviations of the mean, compacting the
+/// retained values to the front of the slice in their original order
+///
+/// Returns the number of retained values. Sets of two or fewer are kept whole.
+pub fn remove_outliers(values: &mut [u64]) -> usize {
+    let Some((lower_bound, upper_bound)) = outlier_bounds(values) else {
+        return values.len();
+    };
+    
+    let mut retained = 0;
+    for i in 0..values.len() {
+        let value = values[i];
+        if value >= lower_bound && value <= upper_bound {
+            values[retained] = value;
+            retained += 1;
+        }
+    }
+    
+    retained
+}
+
+/// Consensus statistics of `values`, which are sorted in place
+pub fn consensus_from_values(values: &mut [u64]) -> ConsensusStats {
+    values.sort_unstable();
+    
+    let aggregated_value = mean(values);
+    let standard_deviation = if values.len() <= 1 {
+        0
+    } else {
+        integer_sqrt(variance(values, aggregated_value))
+    };
+    
+    ConsensusStats {
+        aggregated_value,
+        median_value: median_sorted(values),
+        standard_deviation,
+        confidence_score: confidence_score(aggregated_value, standard_deviation),
+    }
+}
+
+/// Number of independent sources behind a set of readings: one per distinct
+/// source, and one for each reading without a source
+///
+/// Readings sharing a source are counted once, so a single upstream feed
+/// run through several oracles cannot make up a quorum on its own.
+pub fn independent_sources<T: PartialEq>(sources: &[Option<T>]) -> usize {
+    sources
+        .iter()
+        .enumerate()
+        .filter(|(index, source)| match source {
+            Some(source) => !sources[..*index].iter().any(|earlier| earlier.as_ref() == Some(source)),
+            None => true,
+        })
+        .count()
+}
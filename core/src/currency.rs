@@ -0,0 +1,52 @@
+//! Conversion of amounts between SOL and USD stablecoins.
+
+/// Decimals of SOL amounts, counted in lamports
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Decimals of SOL/USD prices: 150_000_000 is $150 per SOL
+pub const PRICE_DECIMALS: u8 = 6;
+
+/// Units a currency's amounts are counted in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Denomination {
+    /// Lamports, valued at the SOL/USD price
+    Lamports,
+    /// Base units of a USD stablecoin with `decimals` decimals
+    Usd { decimals: u8 },
+}
+
+/// Convert `amount` from one denomination to another, rounding down
+///
+/// `sol_price` is the USD price of one SOL with [`PRICE_DECIMALS`] decimals and
+/// only matters when converting between SOL and USD. Returns `None` on
+/// overflow, or when converting USD to SOL at a zero price.
+pub fn convert(amount: u64, from: Denomination, to: Denomination, sol_price: u64) -> Option<u64> {
+    if from == to {
+        return Some(amount);
+    }
+    
+    // Express both sides as numerator / denominator of whole USD, where a
+    // lamport is worth price / 10^(SOL_DECIMALS + PRICE_DECIMALS)
+    let (from_num, from_den) = usd_per_unit(from, sol_price)?;
+    let (to_num, to_den) = usd_per_unit(to, sol_price)?;
+    if to_num == 0 {
+        return None;
+    }
+    
+    let converted = (amount as u128)
+        .checked_mul(from_num)?
+        .checked_mul(to_den)?
+        / from_den.checked_mul(to_num)?;
+    u64::try_from(converted).ok()
+}
+
+/// USD value of one base unit as a fraction
+fn usd_per_unit(denomination: Denomination, sol_price: u64) -> Option<(u128, u128)> {
+    match denomination {
+        Denomination::Lamports => Some((
+            sol_price as u128,
+            10u128.checked_pow((SOL_DECIMALS + PRICE_DECIMALS) as u32)?,
+        )),
+        Denomination::Usd { decimals } => Some((1, 10u128.checked_pow(decimals as u32)?)),
+    }
+}
@@ -0,0 +1,33 @@
+//! Late fees on overdue premiums.
+
+/// Basis points representing the full premium
+pub const FULL_PREMIUM_BPS: u64 = 10_000;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Whole days `now` lies past `due_date`, 0 when not overdue
+pub fn days_overdue(due_date: i64, now: i64) -> u64 {
+    if now <= due_date {
+        return 0;
+    }
+    ((now - due_date) / SECONDS_PER_DAY) as u64
+}
+
+/// Late fee on `premium`: `late_fee_bps` of it per whole day overdue, capped at the premium
+pub fn late_fee(premium: u64, late_fee_bps: u16, due_date: i64, now: i64) -> u64 {
+    let fee = premium as u128 * late_fee_bps as u128 * days_overdue(due_date, now) as u128
+        / FULL_PREMIUM_BPS as u128;
+    core::cmp::min(fee, premium as u128) as u64
+}
+
+/// Seconds of `from..to` covered by `windows`, `(start, end)` spans during
+/// which premiums could not be paid
+///
+/// Delinquency excludes this time: a premium due at `due` is treated as due
+/// at `due + blocked_secs(windows, due, now)`.
+pub fn blocked_secs(windows: &[(i64, i64)], from: i64, to: i64) -> i64 {
+    windows
+        .iter()
+        .map(|&(start, end)| (end.min(to) - start.max(from)).max(0))
+        .sum()
+}
@@ -0,0 +1,33 @@
+//! Incident deduplication and coverage
+//!
+//! An incident is identified by the policy's feed and a coarse time bucket
+//! `min_gap` seconds wide. A new claim repeats an earlier incident when it
+//! lies within `min_gap` of it, so one event cannot be filed twice as
+//! incidents minutes apart.
+//!
+//! Cover starts once the waiting period after the policy's start has passed;
+//! evidence observed before then describes an event the holder may already
+//! have seen coming.
+
+const SECONDS_PER_HOUR: i64 = 3600;
+
+/// Start of cover: `start_date` plus the waiting period
+pub fn coverage_start(start_date: i64, waiting_period_hours: u32) -> i64 {
+    start_date.saturating_add(waiting_period_hours as i64 * SECONDS_PER_HOUR)
+}
+
+/// Whether evidence observed at `evidence_timestamp` is recent enough to
+/// support a claim, i.e. no earlier than the end of the waiting period
+pub fn evidence_within_coverage(evidence_timestamp: i64, start_date: i64, waiting_period_hours: u32) -> bool {
+    evidence_timestamp >= coverage_start(start_date, waiting_period_hours)
+}
+
+/// Coarse time bucket of `incident_timestamp` used in incident fingerprints
+pub fn incident_bucket(incident_timestamp: i64, min_gap: i64) -> i64 {
+    incident_timestamp.div_euclid(min_gap.max(1))
+}
+
+/// Whether an incident at `incident_timestamp` repeats one at `previous_timestamp`
+pub fn repeats_incident(incident_timestamp: i64, previous_timestamp: i64, min_gap: i64) -> bool {
+    incident_timestamp.abs_diff(previous_timestamp) < min_gap.max(0) as u64
+}
@@ -0,0 +1,69 @@
+//! Premium installment schedules and partial-payment tracking.
+
+/// Basis points representing the full coverage
+pub const FULL_COVERAGE_BPS: u32 = 10_000;
+
+/// Number of installments of `period_secs` needed to cover `term_secs`, at least 1
+pub fn installment_count(term_secs: i64, period_secs: i64) -> u16 {
+    if term_secs <= 0 || period_secs <= 0 {
+        return 1;
+    }
+    let count = (term_secs as u64).div_ceil(period_secs as u64);
+    u16::try_from(count).unwrap_or(u16::MAX)
+}
+
+/// Number of installments due by `now`: the first is due at `first_due`,
+/// each later one a period after the previous, capped at `count`
+pub fn installments_due(first_due: i64, period_secs: i64, count: u16, now: i64) -> u16 {
+    if now < first_due || period_secs <= 0 {
+        return 0;
+    }
+    let due = (now - first_due) / period_secs + 1;
+    core::cmp::min(due, count as i64) as u16
+}
+
+/// Schedule progress after applying a payment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstallmentProgress {
+    /// Installments paid in full
+    pub paid: u16,
+    /// Amount applied to the earliest unpaid installment
+    pub partial: u64,
+    /// Index of the installment the payment was applied to first
+    pub applied_to: u16,
+}
+
+/// Apply `payment` to the earliest unpaid installment, carrying any excess
+/// into later ones
+///
+/// Returns `None` when the payment exceeds what remains owed on the schedule.
+pub fn apply_installment_payment(
+    amount_each: u64,
+    count: u16,
+    paid: u16,
+    partial: u64,
+    payment: u64,
+) -> Option<InstallmentProgress> {
+    let owed = (count.saturating_sub(paid) as u128 * amount_each as u128).saturating_sub(partial as u128);
+    if payment as u128 > owed || amount_each == 0 {
+        return None;
+    }
+
+    let credited = partial as u128 + payment as u128;
+    let completed = credited / amount_each as u128;
+    Some(InstallmentProgress {
+        paid: paid + completed as u16,
+        partial: (credited % amount_each as u128) as u64,
+        applied_to: paid,
+    })
+}
+
+/// Share of the coverage payable with `paid` of `due` installments paid, in basis points
+///
+/// A holder who is not behind keeps the full coverage.
+pub fn pro_rata_coverage_bps(paid: u16, due: u16) -> u32 {
+    if paid >= due {
+        return FULL_COVERAGE_BPS;
+    }
+    paid as u32 * FULL_COVERAGE_BPS / due as u32
+}
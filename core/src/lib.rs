@@ -0,0 +1,47 @@
+//! Pure business logic of the insurance program.
+//!
+//! Everything here works on plain integers and floats, without accounts or
+//! Anchor types, so the on-chain handlers and off-chain previews share one
+//! implementation. The crate is `no_std` and allocation-free.
+
+#![no_std]
+
+pub mod consensus;
+pub mod currency;
+pub mod dunning;
+pub mod incident;
+pub mod installments;
+pub mod lifecycle;
+pub mod payout;
+pub mod portfolio;
+pub mod pricing;
+pub mod region;
+pub mod reserves;
+pub mod rolling;
+pub mod subsidy;
+pub mod trigger;
+pub mod underwriting;
+pub mod versioning;
+pub mod vesting;
+
+pub use consensus::{
+    coefficient_of_variation_bps, consensus_from_values, independent_sources, outlier_bounds, remove_outliers,
+    ConsensusStats,
+};
+pub use currency::{convert, Denomination};
+pub use dunning::{blocked_secs, late_fee};
+pub use incident::{coverage_start, evidence_within_coverage, incident_bucket, repeats_incident};
+pub use installments::{apply_installment_payment, installments_due, pro_rata_coverage_bps, InstallmentProgress};
+pub use lifecycle::{is_allowed_payout_transition, is_allowed_transition, PayoutState, PolicyState};
+pub use payout::{calculate_payout, calculate_payout_with_mode, meets_payout_floor, payout_priority, DeductibleMode};
+pub use portfolio::{average_severity, claim_frequency_bps, loss_ratio_bps};
+pub use pricing::{interpolated_rate_bps, outage_refund, reprice_for_risk, term_premium, unexpired_share};
+pub use region::{fits_region_cap, paid_today, payout_day, region_cap};
+pub use reserves::{
+    payout_exposure_release, payout_terminates, required_reserves, reserve_ratio_bps, solvency, CoverageExposure,
+    Solvency,
+};
+pub use subsidy::{split_premium, PremiumSplit};
+pub use trigger::{combine_leaves, confidence_within, evaluate_trigger, Comparison, Connective, SeverityMode};
+pub use underwriting::{bond_slash, fee_rebate, rebate_rate_bps, rebate_tiers_valid, underwriting_bond, RebateTier};
+pub use vesting::{tranche_amount, tranche_release_at, unreleased_amount};
@@ -0,0 +1,60 @@
+//! Policy and payout lifecycle state machines
+//!
+//! A policy awaits its first premium, is covered while active, and holds a
+//! single pending payout at a time. Paid out, cancelled and expired policies
+//! are final.
+//!
+//! A payout is assessed into `Ready` or `PendingApproval`, approved into
+//! `Ready`, and ends executed, rejected or expired.
+
+/// Lifecycle state of a policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyState {
+    PendingActivation,
+    Active,
+    PendingPayout,
+    PaidOut,
+    Cancelled,
+    Expired,
+}
+
+/// Whether a policy may move from `from` to `to`
+///
+/// A pending payout either settles (`PaidOut`) or is withdrawn, returning the
+/// policy to coverage (`Active`).
+pub fn is_allowed_transition(from: PolicyState, to: PolicyState) -> bool {
+    use PolicyState::*;
+    
+    matches!(
+        (from, to),
+        (PendingActivation, Active | Cancelled | Expired)
+            | (Active, PendingPayout | Cancelled | Expired)
+            | (PendingPayout, PaidOut | Active)
+    )
+}
+
+/// Lifecycle state of a pending payout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutState {
+    Pending,
+    PendingApproval,
+    Ready,
+    Executed,
+    Rejected,
+    Expired,
+}
+
+/// Whether a pending payout may move from `from` to `to`
+///
+/// `Pending` holds a claim filed before it could be assessed; assessment
+/// moves it on like a freshly triggered payout.
+pub fn is_allowed_payout_transition(from: PayoutState, to: PayoutState) -> bool {
+    use PayoutState::*;
+    
+    matches!(
+        (from, to),
+        (Pending, PendingApproval | Ready | Rejected | Expired)
+            | (PendingApproval, Ready | Rejected | Expired)
+            | (Ready, Executed | Expired)
+    )
+}
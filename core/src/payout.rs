@@ -0,0 +1,87 @@
+/// How a policy's deductible reduces a claim
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeductibleMode {
+    /// Scale the coverage by severity, then subtract the deductible
+    SubtractAfterScaling,
+    /// Subtract the deductible from the coverage, then scale by severity
+    SubtractBeforeScaling,
+    /// Pay the full scaled amount once it exceeds the deductible, else nothing
+    Franchise,
+}
+
+/// Payout for a claim: `severity_percentage` of the coverage, less the
+/// deductible, capped at `max_payout`
+///
+/// Returns 0 when the scaled coverage does not exceed the deductible. Same as
+/// [`calculate_payout_with_mode`] with [`DeductibleMode::SubtractAfterScaling`].
+pub fn calculate_payout(
+    coverage_amount: u64,
+    deductible: u64,
+    severity_percentage: u8,
+    max_payout: u64,
+) -> u64 {
+    calculate_payout_with_mode(
+        DeductibleMode::SubtractAfterScaling,
+        coverage_amount,
+        deductible,
+        severity_percentage,
+        max_payout,
+    )
+}
+
+/// `severity_percentage` of `amount`
+fn scale(amount: u64, severity_percentage: u8) -> u64 {
+    let scaled = (amount as u128 * severity_percentage as u128) / 100;
+    u64::try_from(scaled).unwrap_or(u64::MAX)
+}
+
+/// Payout for a claim with the deductible applied per `mode`, capped at `max_payout`
+pub fn calculate_payout_with_mode(
+    mode: DeductibleMode,
+    coverage_amount: u64,
+    deductible: u64,
+    severity_percentage: u8,
+    max_payout: u64,
+) -> u64 {
+    let payout = match mode {
+        DeductibleMode::SubtractAfterScaling => {
+            scale(coverage_amount, severity_percentage).saturating_sub(deductible)
+        }
+        DeductibleMode::SubtractBeforeScaling => {
+            scale(coverage_amount.saturating_sub(deductible), severity_percentage)
+        }
+        DeductibleMode::Franchise => {
+            let scaled = scale(coverage_amount, severity_percentage);
+            if scaled > deductible { scaled } else { 0 }
+        }
+    };
+    
+    // Apply maximum payout limit
+    core::cmp::min(payout, max_payout)
+}
+
+/// Whether a claim clears the dust floors: `amount` of at least `min_payout`
+/// and, when the policy sets one, a severity of at least `min_severity_bps`
+pub fn meets_payout_floor(
+    amount: u64,
+    severity_percentage: u8,
+    min_payout: u64,
+    min_severity_bps: Option<u16>,
+) -> bool {
+    let severity_met = match min_severity_bps {
+        Some(floor) => severity_percentage as u32 * 100 >= floor as u32,
+        None => true,
+    };
+    amount >= min_payout && severity_met
+}
+
+/// Highest queue priority a payout can have
+pub const MAX_PRIORITY: u8 = 100;
+
+/// Queue priority of a payout at trigger time: the insurance type's
+/// `base_priority` plus `severity_points` scaled by severity, capped at
+/// [`MAX_PRIORITY`]
+pub fn payout_priority(base_priority: u8, severity_percentage: u8, severity_points: u8) -> u8 {
+    let bonus = severity_percentage.min(100) as u16 * severity_points as u16 / 100;
+    core::cmp::min(base_priority as u16 + bonus, MAX_PRIORITY as u16) as u8
+}
@@ -0,0 +1,35 @@
+//! Portfolio ratios derived from per-type accumulators.
+
+/// Basis points representing a ratio of 1
+pub const RATIO_BPS: u32 = 10_000;
+
+/// `numerator / denominator` in basis points, saturating at `u32::MAX`
+///
+/// Without a denominator the ratio is reported as 0.
+pub fn ratio_bps(numerator: u64, denominator: u64) -> u32 {
+    if denominator == 0 {
+        return 0;
+    }
+    let bps = (numerator as u128 * RATIO_BPS as u128) / denominator as u128;
+    u32::try_from(bps).unwrap_or(u32::MAX)
+}
+
+/// Payouts disbursed per premium collected, in basis points
+///
+/// Loss ratios above 100% are reported as they are, not capped.
+pub fn loss_ratio_bps(payouts: u64, premiums: u64) -> u32 {
+    ratio_bps(payouts, premiums)
+}
+
+/// Claims filed per policy written, in basis points
+pub fn claim_frequency_bps(claims: u64, policies: u64) -> u32 {
+    ratio_bps(claims, policies)
+}
+
+/// Mean severity (0-100) of `count` payouts whose severities sum to `severity_sum`
+pub fn average_severity(severity_sum: u64, count: u64) -> u8 {
+    if count == 0 {
+        return 0;
+    }
+    core::cmp::min(severity_sum / count, 100) as u8
+}
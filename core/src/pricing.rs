@@ -0,0 +1,56 @@
+//! Pricing of template policies, and re-pricing of in-force policies after
+//! mid-term changes.
+
+/// Score added to every risk score, so a zero-risk policy keeps a base rate
+pub const BASE_RISK_SCORE: u64 = 100;
+
+/// Premium re-priced from `old_score` to `new_score`
+///
+/// The premium scales with `BASE_RISK_SCORE + score`, rounded down. `None`
+/// if the result overflows a `u64`.
+pub fn reprice_for_risk(premium: u64, old_score: u8, new_score: u8) -> Option<u64> {
+    let repriced = premium as u128 * (BASE_RISK_SCORE + new_score as u64) as u128
+        / (BASE_RISK_SCORE + old_score as u64) as u128;
+    u64::try_from(repriced).ok()
+}
+
+/// Share of `amount` falling in the unexpired part of the term `[start, end]`
+/// at `now`, rounded down
+pub fn unexpired_share(amount: u64, start: i64, end: i64, now: i64) -> u64 {
+    let term = (end - start).max(1);
+    let remaining = (end - now).clamp(0, term);
+    (amount as u128 * remaining as u128 / term as u128) as u64
+}
+
+/// Refund of `premiums_paid` for `outage_secs` of a `term_secs` term that
+/// could not be claimed against, pro rata and rounded down
+///
+/// `None` unless the downtime exceeds `threshold_bps` of the term; a threshold
+/// of 0 disables refunds.
+pub fn outage_refund(premiums_paid: u64, outage_secs: u64, term_secs: i64, threshold_bps: u16) -> Option<u64> {
+    if threshold_bps == 0 {
+        return None;
+    }
+    let term = term_secs.max(1) as u128;
+    let outage = (outage_secs as u128).min(term);
+    if outage * 10_000 <= term * threshold_bps as u128 {
+        return None;
+    }
+    Some((premiums_paid as u128 * outage / term) as u64)
+}
+
+/// Annual rate at `position_bps` along a range priced linearly from
+/// `rate_low_bps` at its low end (0) to `rate_high_bps` at its high end
+/// (10_000), rounded toward the low end's rate
+pub fn interpolated_rate_bps(rate_low_bps: u16, rate_high_bps: u16, position_bps: u16) -> u16 {
+    let position = position_bps.min(10_000) as i64;
+    let span = rate_high_bps as i64 - rate_low_bps as i64;
+    (rate_low_bps as i64 + span * position / 10_000) as u16
+}
+
+/// Premium of `coverage` for `duration_days` at an annual `rate_bps`,
+/// rounded down; `None` if it overflows a `u64`
+pub fn term_premium(coverage: u64, duration_days: u32, rate_bps: u16) -> Option<u64> {
+    let premium = coverage as u128 * rate_bps as u128 * duration_days as u128 / (10_000 * 365);
+    u64::try_from(premium).ok()
+}
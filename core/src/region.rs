@@ -0,0 +1,33 @@
+//! Concentration limits on the coverage and payouts of one region.
+
+/// Basis points representing the whole treasury balance
+pub const FULL_BALANCE_BPS: u64 = 10_000;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// `cap_bps` of a treasury `balance`, rounded down; caps above 10,000 bps
+/// allow more than the balance
+pub fn region_cap(balance: u64, cap_bps: u16) -> u64 {
+    let cap = balance as u128 * cap_bps as u128 / FULL_BALANCE_BPS as u128;
+    u64::try_from(cap).unwrap_or(u64::MAX)
+}
+
+/// Whether `amount` more fits on top of `current` under `cap`, if any
+pub fn fits_region_cap(current: u64, amount: u64, cap: Option<u64>) -> bool {
+    match (current.checked_add(amount), cap) {
+        (Some(total), Some(cap)) => total <= cap,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Day number of `timestamp`, counted in whole UTC days since the epoch
+pub fn payout_day(timestamp: i64) -> i64 {
+    timestamp.div_euclid(SECONDS_PER_DAY)
+}
+
+/// Amount paid so far on the day of `timestamp`, given a running total kept
+/// for `tracked_day`; a total from an earlier day no longer counts
+pub fn paid_today(tracked_day: i64, paid: u64, timestamp: i64) -> u64 {
+    if tracked_day == payout_day(timestamp) { paid } else { 0 }
+}
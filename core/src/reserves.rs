@@ -0,0 +1,95 @@
+/// Basis points representing 100%
+pub const FULL_RESERVE_BPS: u16 = 10_000;
+
+/// Ratio of `balance` to `exposure` in basis points, capped at 100%
+///
+/// Without exposure the reserves are considered full.
+pub fn reserve_ratio_bps(balance: u64, exposure: u64) -> u16 {
+    if exposure == 0 {
+        return FULL_RESERVE_BPS;
+    }
+    let bps = (balance as u128 * FULL_RESERVE_BPS as u128) / exposure as u128;
+    core::cmp::min(bps, FULL_RESERVE_BPS as u128) as u16
+}
+
+/// Reserves required to back `exposure` at `ratio_bps`, rounded down
+pub fn required_reserves(ratio_bps: u16, exposure: u64) -> u64 {
+    let required = (exposure as u128 * ratio_bps as u128) / FULL_RESERVE_BPS as u128;
+    u64::try_from(required).unwrap_or(u64::MAX)
+}
+
+/// Solvency of a pool relative to its minimum reserve ratio
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Solvency {
+    /// Reserves at or above the minimum ratio
+    Healthy,
+    /// Reserves below the minimum ratio
+    Undercapitalized,
+    /// Exposure with no reserves backing it
+    Insolvent,
+}
+
+/// Classify `balance` against `exposure` and the minimum ratio `minimum_bps`
+pub fn solvency(balance: u64, exposure: u64, minimum_bps: u16) -> Solvency {
+    if exposure > 0 && balance == 0 {
+        Solvency::Insolvent
+    } else if reserve_ratio_bps(balance, exposure) < minimum_bps {
+        Solvency::Undercapitalized
+    } else {
+        Solvency::Healthy
+    }
+}
+
+/// Coverage a treasury is exposed to, per payout currency and in total
+///
+/// Sales book coverage and settlements, cancellations and expiries release
+/// it through the same two calls, so the total stays the sum of the buckets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CoverageExposure {
+    pub total: u64,
+    pub sol: u64,
+    pub usdc: u64,
+}
+
+impl CoverageExposure {
+    /// Book `coverage` paying out in USDC when `usdc`, otherwise in SOL;
+    /// `None` on overflow
+    pub fn book(self, usdc: bool, coverage: u64) -> Option<Self> {
+        let mut next = self;
+        next.total = self.total.checked_add(coverage)?;
+        let bucket = if usdc { &mut next.usdc } else { &mut next.sol };
+        *bucket = bucket.checked_add(coverage)?;
+        Some(next)
+    }
+
+    /// Release `coverage` from the currency's bucket and the total, each
+    /// floored at zero
+    pub fn release(self, usdc: bool, coverage: u64) -> Self {
+        let mut next = self;
+        next.total = self.total.saturating_sub(coverage);
+        let bucket = if usdc { &mut next.usdc } else { &mut next.sol };
+        *bucket = bucket.saturating_sub(coverage);
+        next
+    }
+}
+
+/// Whether a payout of `paid` ends a policy with `coverage` left and a
+/// per-incident cap of `max_per_incident`
+///
+/// Policies whose cap is below their coverage cover several incidents and
+/// stay in force until a payout uses up what is left.
+pub fn payout_terminates(coverage: u64, max_per_incident: u64, paid: u64) -> bool {
+    max_per_incident >= coverage || paid >= coverage
+}
+
+/// Coverage exposure released when a payout of `paid` executes against `coverage`
+///
+/// A policy the payout terminates releases all of its coverage; one that
+/// stays live releases only the coverage the payout consumed.
+pub fn payout_exposure_release(coverage: u64, paid: u64, terminates: bool) -> u64 {
+    if terminates {
+        coverage
+    } else {
+        core::cmp::min(paid, coverage)
+    }
+}
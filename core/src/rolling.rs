@@ -0,0 +1,52 @@
+//! Rolling 24-hour counts kept as an hourly histogram
+//!
+//! Slot `hour % WINDOW_HOURS` holds the count for that hour. Buckets are
+//! cleared lazily as newer hours are recorded, so the histogram stays
+//! accurate without a periodic reset.
+
+/// Hourly buckets covering the last 24 hours
+pub const WINDOW_HOURS: usize = 24;
+
+const SECONDS_PER_HOUR: i64 = 3600;
+
+fn hour_of(timestamp: i64) -> i64 {
+    timestamp.div_euclid(SECONDS_PER_HOUR)
+}
+
+fn slot(hour: i64) -> usize {
+    hour.rem_euclid(WINDOW_HOURS as i64) as usize
+}
+
+/// Record one event at `timestamp`
+///
+/// `latest_hour` is the hour of the newest recorded event and is advanced
+/// when `timestamp` falls in a later hour. Events older than the window are
+/// dropped.
+pub fn record(buckets: &mut [u16; WINDOW_HOURS], latest_hour: &mut i64, timestamp: i64) {
+    let hour = hour_of(timestamp);
+    
+    if hour > *latest_hour {
+        // Clear the hours skipped since the latest event, at most a full window
+        let elapsed = core::cmp::min(hour - *latest_hour, WINDOW_HOURS as i64);
+        for offset in 0..elapsed {
+            buckets[slot(hour - offset)] = 0;
+        }
+        *latest_hour = hour;
+    } else if *latest_hour - hour >= WINDOW_HOURS as i64 {
+        return;
+    }
+    
+    let bucket = &mut buckets[slot(hour)];
+    *bucket = bucket.saturating_add(1);
+}
+
+/// Events recorded in the 24 hours up to and including the hour of `now`
+pub fn count_in_window(buckets: &[u16; WINDOW_HOURS], latest_hour: i64, now: i64) -> u32 {
+    let now_hour = hour_of(now);
+    
+    (0..WINDOW_HOURS as i64)
+        .map(|offset| latest_hour - offset)
+        .filter(|&hour| hour <= now_hour && now_hour - hour < WINDOW_HOURS as i64)
+        .map(|hour| buckets[slot(hour)] as u32)
+        .sum()
+}
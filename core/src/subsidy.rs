@@ -0,0 +1,27 @@
+//! Premium co-payment by a subsidy sponsor.
+
+/// Basis points representing 100% of a premium
+pub const FULL_COVERAGE_BPS: u16 = 10_000;
+
+/// How a premium is divided between a subsidy sponsor and the holder
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PremiumSplit {
+    /// Drawn from the subsidy budget
+    pub sponsor_share: u64,
+    /// Left for the holder to pay
+    pub holder_share: u64,
+}
+
+/// Split `premium` with the sponsor covering `coverage_bps` of it
+///
+/// The sponsor's share is rounded down and capped at the remaining
+/// `budget`, so an exhausted subsidy leaves the holder the full premium.
+pub fn split_premium(premium: u64, coverage_bps: u16, budget: u64) -> PremiumSplit {
+    let coverage_bps = core::cmp::min(coverage_bps, FULL_COVERAGE_BPS);
+    let share = (premium as u128 * coverage_bps as u128) / FULL_COVERAGE_BPS as u128;
+    let sponsor_share = core::cmp::min(share as u64, budget);
+    PremiumSplit {
+        sponsor_share,
+        holder_share: premium - sponsor_share,
+    }
+}
@@ -0,0 +1,93 @@
+/// How an oracle value is compared against a trigger threshold
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    GreaterThan,
+    LessThan,
+    Equals,
+    NotEquals,
+}
+
+/// How the leaves of a compound trigger are combined
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connective {
+    All,
+    Any,
+}
+
+/// How severity is derived from the satisfied leaves of a compound trigger
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeverityMode {
+    Worst,
+    Average,
+}
+
+/// Tolerance for `Equals`/`NotEquals` comparisons
+const EQUALITY_TOLERANCE: f64 = 0.01;
+
+fn abs(value: f64) -> f64 {
+    if value < 0.0 { -value } else { value }
+}
+
+/// Compare an oracle value against a threshold
+pub fn compare(operator: Comparison, oracle_value: u64, threshold: f64) -> bool {
+    let oracle_value = oracle_value as f64;
+    
+    match operator {
+        Comparison::GreaterThan => oracle_value > threshold,
+        Comparison::LessThan => oracle_value < threshold,
+        Comparison::Equals => abs(oracle_value - threshold) < EQUALITY_TOLERANCE,
+        Comparison::NotEquals => abs(oracle_value - threshold) >= EQUALITY_TOLERANCE,
+    }
+}
+
+/// Severity as the percentage deviation from the threshold (capped at 100%)
+pub fn severity_from_threshold(oracle_value: u64, threshold: f64) -> u8 {
+    let deviation = abs(oracle_value as f64 - threshold) / threshold;
+    (deviation * 100.0).min(100.0) as u8
+}
+
+/// Evaluate a single condition, returning its severity when it is met
+pub fn evaluate_trigger(operator: Comparison, oracle_value: u64, threshold: f64) -> Option<u8> {
+    compare(operator, oracle_value, threshold)
+        .then(|| severity_from_threshold(oracle_value, threshold))
+}
+
+/// Whether an oracle reading's confidence interval is at most `max_ratio_bps`
+/// basis points of its value; a limit of 0 accepts any interval
+pub fn confidence_within(value: u64, confidence: u64, max_ratio_bps: u16) -> bool {
+    max_ratio_bps == 0 || confidence as u128 * 10_000 <= value as u128 * max_ratio_bps as u128
+}
+
+/// Combine per-leaf results of a compound trigger (`None` for unmet leaves),
+/// returning the overall severity when the expression is met
+pub fn combine_leaves<I>(connective: Connective, mode: SeverityMode, leaves: I) -> Option<u8>
+where
+    I: IntoIterator<Item = Option<u8>>,
+{
+    let mut leaf_count = 0usize;
+    let mut satisfied_count = 0usize;
+    let mut worst_severity = 0u8;
+    let mut severity_sum = 0u32;
+    
+    for severity in leaves {
+        leaf_count += 1;
+        if let Some(severity) = severity {
+            satisfied_count += 1;
+            worst_severity = worst_severity.max(severity);
+            severity_sum += severity as u32;
+        }
+    }
+    
+    let met = match connective {
+        Connective::All => satisfied_count == leaf_count,
+        Connective::Any => satisfied_count > 0,
+    };
+    if !met || satisfied_count == 0 {
+        return None;
+    }
+    
+    Some(match mode {
+        SeverityMode::Worst => worst_severity,
+        SeverityMode::Average => (severity_sum / satisfied_count as u32) as u8,
+    })
+}
@@ -0,0 +1,60 @@
+//! Bonds underwriters lock against large policies they co-sign, and the fee
+//! rebates high-volume underwriters earn.
+
+/// Basis points representing the full coverage
+pub const FULL_COVERAGE_BPS: u64 = 10_000;
+
+/// Bond locked against `coverage`: `bond_bps` of it, rounded up so any
+/// nonzero rate locks something
+pub fn underwriting_bond(coverage: u64, bond_bps: u16) -> u64 {
+    let bond = (coverage as u128 * bond_bps as u128).div_ceil(FULL_COVERAGE_BPS as u128);
+    u64::try_from(bond).unwrap_or(u64::MAX)
+}
+
+/// Part of `bond` forfeited when a policy's `claims` exceeded
+/// `max_loss_ratio_bps` of its `premiums`
+///
+/// The claims above the bound are taken from the bond, up to all of it;
+/// claims within the bound forfeit nothing.
+pub fn bond_slash(bond: u64, premiums: u64, claims: u64, max_loss_ratio_bps: u16) -> u64 {
+    let allowed = premiums as u128 * max_loss_ratio_bps as u128 / FULL_COVERAGE_BPS as u128;
+    let excess = (claims as u128).saturating_sub(allowed);
+    core::cmp::min(excess, bond as u128) as u64
+}
+
+/// Fee rebate tier: underwriters placing at least `min_placed` coverage in
+/// an epoch are rebated `rebate_bps` of the fees their policies paid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RebateTier {
+    pub min_placed: u64,
+    pub rebate_bps: u16,
+}
+
+/// Whether `tiers` is a usable schedule: thresholds strictly rising, rates
+/// never falling and none above the full fee
+pub fn rebate_tiers_valid(tiers: &[RebateTier]) -> bool {
+    tiers.iter().all(|tier| tier.rebate_bps as u64 <= FULL_COVERAGE_BPS)
+        && tiers
+            .windows(2)
+            .all(|pair| pair[0].min_placed < pair[1].min_placed && pair[0].rebate_bps <= pair[1].rebate_bps)
+}
+
+/// Rebate rate of the highest tier `placed` reaches, 0 below the lowest
+pub fn rebate_rate_bps(tiers: &[RebateTier], placed: u64) -> u16 {
+    tiers
+        .iter()
+        .filter(|tier| placed >= tier.min_placed)
+        .map(|tier| tier.rebate_bps)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rebate owed to an underwriter that placed `placed` coverage in an epoch
+/// whose policies paid `fees_collected` in fees, rounded down
+///
+/// The rebate never exceeds `fees_collected`, so paying it cannot take more
+/// from the fees than that underwriter brought in.
+pub fn fee_rebate(tiers: &[RebateTier], placed: u64, fees_collected: u64) -> u64 {
+    let rate_bps = core::cmp::min(rebate_rate_bps(tiers, placed) as u64, FULL_COVERAGE_BPS);
+    (fees_collected as u128 * rate_bps as u128 / FULL_COVERAGE_BPS as u128) as u64
+}
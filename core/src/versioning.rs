@@ -0,0 +1,100 @@
+//! Versioned encoding of oracle data
+//!
+//! The version byte carries the major version in its high nibble and the
+//! minor version in its low nibble. Minor versions only append fields, which
+//! live in an extension section after the v1 fields. Readers skip extension
+//! bytes they do not know, so older code reads newer data unchanged and newer
+//! code fills in defaults for older data. A different major version may
+//! change the layout itself and is rejected.
+
+/// Major version understood by this code
+pub const ORACLE_DATA_MAJOR: u8 = 1;
+
+/// Base fields only: value, timestamp, confidence, signature and nonce
+pub const ORACLE_DATA_V1: u8 = 0x10;
+
+/// Adds the value exponent and the feed id as extensions
+pub const ORACLE_DATA_V2: u8 = 0x11;
+
+/// Version written by this code
+pub const ORACLE_DATA_VERSION: u8 = ORACLE_DATA_V2;
+
+/// Longest feed id carried in the extensions
+pub const MAX_FEED_ID_LEN: usize = 64;
+
+/// Largest extension section written by the current minor version
+pub const MAX_EXTENSIONS_LEN: usize = 4 + 1 + MAX_FEED_ID_LEN;
+
+/// Major version of a version byte
+pub const fn major(version: u8) -> u8 {
+    version >> 4
+}
+
+/// Minor version of a version byte
+pub const fn minor(version: u8) -> u8 {
+    version & 0x0f
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionError {
+    /// The data was written under a major version this code does not know
+    UnsupportedMajor(u8),
+    /// The extension section is shorter than its version requires
+    Malformed,
+}
+
+/// Accept any minor version of the known major version
+pub fn check_version(version: u8) -> Result<(), VersionError> {
+    if major(version) != ORACLE_DATA_MAJOR {
+        return Err(VersionError::UnsupportedMajor(major(version)));
+    }
+    Ok(())
+}
+
+/// Fields added to oracle data after v1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OracleDataExtensions<'a> {
+    /// Decimal exponent of the value (`value * 10^exponent`)
+    pub exponent: i32,
+    /// Feed the value was read from; empty when not given
+    pub feed_id: &'a [u8],
+}
+
+impl<'a> OracleDataExtensions<'a> {
+    /// Decode the extension section of data written under `version`
+    ///
+    /// Fields introduced after the writer's minor version keep their
+    /// defaults; bytes appended by later minor versions are ignored.
+    pub fn decode(version: u8, bytes: &'a [u8]) -> Result<Self, VersionError> {
+        check_version(version)?;
+        
+        let mut extensions = Self::default();
+        if minor(version) >= minor(ORACLE_DATA_V2) {
+            if bytes.len() < 5 {
+                return Err(VersionError::Malformed);
+            }
+            let mut exponent = [0u8; 4];
+            exponent.copy_from_slice(&bytes[..4]);
+            extensions.exponent = i32::from_le_bytes(exponent);
+            
+            let feed_id_len = bytes[4] as usize;
+            if feed_id_len > MAX_FEED_ID_LEN || bytes.len() < 5 + feed_id_len {
+                return Err(VersionError::Malformed);
+            }
+            extensions.feed_id = &bytes[5..5 + feed_id_len];
+        }
+        Ok(extensions)
+    }
+    
+    /// Encode under `ORACLE_DATA_VERSION`, returning the number of bytes written
+    pub fn encode(&self, out: &mut [u8; MAX_EXTENSIONS_LEN]) -> Result<usize, VersionError> {
+        let feed_id_len = self.feed_id.len();
+        if feed_id_len > MAX_FEED_ID_LEN {
+            return Err(VersionError::Malformed);
+        }
+        out[..4].copy_from_slice(&self.exponent.to_le_bytes());
+        out[4] = feed_id_len as u8;
+        out[5..5 + feed_id_len].copy_from_slice(self.feed_id);
+        Ok(5 + feed_id_len)
+    }
+}
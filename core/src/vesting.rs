@@ -0,0 +1,26 @@
+//! Tranche schedules of payouts disbursed over time.
+
+/// Amount of tranche `index` (from 0) when `total` is paid in `tranches`
+/// equal parts, the last taking the remainder; 0 outside the schedule
+pub fn tranche_amount(total: u64, tranches: u8, index: u8) -> u64 {
+    if tranches == 0 || index >= tranches {
+        return 0;
+    }
+    let base = total / tranches as u64;
+    if index + 1 == tranches {
+        total - base * (tranches as u64 - 1)
+    } else {
+        base
+    }
+}
+
+/// Release time of tranche `index`: the first at `started_at`, each later
+/// one `interval_secs` after the previous
+pub fn tranche_release_at(started_at: i64, interval_secs: i64, index: u8) -> i64 {
+    started_at.saturating_add(interval_secs.saturating_mul(index as i64))
+}
+
+/// Amount still owed once the first `released` tranches are paid
+pub fn unreleased_amount(total: u64, tranches: u8, released: u8) -> u64 {
+    (released..tranches).map(|index| tranche_amount(total, tranches, index)).sum()
+}
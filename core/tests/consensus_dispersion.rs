@@ -0,0 +1,24 @@
+use siglab_core::{coefficient_of_variation_bps, remove_outliers};
+
+#[test]
+fn agreeing_values_have_no_dispersion() {
+    assert_eq!(coefficient_of_variation_bps(&[100, 100, 100]), 0);
+}
+
+#[test]
+fn dispersion_is_the_deviation_over_the_mean() {
+    // Mean 100, population standard deviation 10
+    assert_eq!(coefficient_of_variation_bps(&[90, 110]), 1_000);
+}
+
+#[test]
+fn empty_sets_have_no_dispersion() {
+    assert_eq!(coefficient_of_variation_bps(&[]), 0);
+}
+
+#[test]
+fn one_stray_value_among_many_is_dropped() {
+    let mut values = [100, 100, 100, 100, 100, 100, 10_000];
+    let retained = remove_outliers(&mut values);
+    assert_eq!(&values[..retained], &[100; 6]);
+}
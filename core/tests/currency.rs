@@ -0,0 +1,56 @@
+use proptest::prelude::*;
+use siglab_core::currency::{convert, Denomination};
+
+const USDC: Denomination = Denomination::Usd { decimals: 6 };
+const SOL: Denomination = Denomination::Lamports;
+
+/// $150 per SOL
+const PRICE: u64 = 150_000_000;
+
+#[test]
+fn one_usdc_is_worth_its_share_of_a_sol() {
+    // $1 at $150/SOL is 1/150 SOL
+    assert_eq!(convert(1_000_000, USDC, SOL, PRICE), Some(6_666_666));
+    assert_eq!(convert(1_000_000_000, SOL, USDC, PRICE), Some(150_000_000));
+}
+
+#[test]
+fn the_same_minimum_prices_equally_in_either_currency() {
+    // A $1 minimum premium in USDC, and the same minimum quoted in lamports
+    let min_premium_sol = convert(1_000_000, USDC, SOL, PRICE).unwrap();
+    assert_eq!(convert(min_premium_sol, SOL, USDC, PRICE), Some(999_999));
+    assert_eq!(convert(min_premium_sol + 1, SOL, USDC, PRICE), Some(1_000_000));
+}
+
+#[test]
+fn stablecoins_rescale_by_decimals() {
+    let eighteen = Denomination::Usd { decimals: 18 };
+    assert_eq!(convert(1_500_000, USDC, eighteen, 0), Some(1_500_000_000_000_000_000));
+    assert_eq!(convert(1_500_000_000_000_000_000, eighteen, USDC, 0), Some(1_500_000));
+}
+
+#[test]
+fn same_denomination_needs_no_price() {
+    assert_eq!(convert(42, SOL, SOL, 0), Some(42));
+    assert_eq!(convert(42, USDC, USDC, 0), Some(42));
+}
+
+#[test]
+fn usd_cannot_convert_to_sol_without_a_price() {
+    assert_eq!(convert(1_000_000, USDC, SOL, 0), None);
+    assert_eq!(convert(1_000_000_000, SOL, USDC, 0), Some(0));
+}
+
+#[test]
+fn overflow_is_reported() {
+    assert_eq!(convert(u64::MAX, SOL, USDC, u64::MAX), None);
+}
+
+proptest! {
+    #[test]
+    fn round_trips_never_gain_value(amount in 0u64..1_000_000_000_000_000, price in 1u64..1_000_000_000_000) {
+        if let Some(usdc) = convert(amount, SOL, USDC, price) {
+            prop_assert!(convert(usdc, USDC, SOL, price).unwrap() <= amount);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use siglab_core::payout::{calculate_payout, calculate_payout_with_mode, DeductibleMode};
+use DeductibleMode::*;
+
+const SOL: u64 = 1_000_000_000;
+
+/// 100 SOL coverage, 5 SOL deductible, no effective cap
+fn payout(mode: DeductibleMode, severity: u8) -> u64 {
+    calculate_payout_with_mode(mode, 100 * SOL, 5 * SOL, severity, u64::MAX)
+}
+
+#[test]
+fn subtract_after_scaling_deducts_from_the_scaled_loss() {
+    assert_eq!(payout(SubtractAfterScaling, 10), 5 * SOL);
+    assert_eq!(payout(SubtractAfterScaling, 100), 95 * SOL);
+}
+
+#[test]
+fn subtract_before_scaling_scales_the_remaining_coverage() {
+    assert_eq!(payout(SubtractBeforeScaling, 10), 9_500_000_000);
+    assert_eq!(payout(SubtractBeforeScaling, 100), 95 * SOL);
+}
+
+#[test]
+fn franchise_pays_the_full_scaled_loss_above_the_deductible() {
+    assert_eq!(payout(Franchise, 10), 10 * SOL);
+    assert_eq!(payout(Franchise, 6), 6 * SOL);
+}
+
+#[test]
+fn zero_payout_boundaries() {
+    // A scaled loss equal to the deductible pays nothing, one point more pays
+    assert_eq!(payout(SubtractAfterScaling, 5), 0);
+    assert_eq!(payout(SubtractAfterScaling, 6), SOL);
+    assert_eq!(payout(Franchise, 5), 0);
+    assert_eq!(payout(Franchise, 6), 6 * SOL);
+    
+    // Before scaling, only zero severity or a deductible covering the policy pays nothing
+    assert_eq!(payout(SubtractBeforeScaling, 0), 0);
+    assert_eq!(payout(SubtractBeforeScaling, 1), 950_000_000);
+    assert_eq!(calculate_payout_with_mode(SubtractBeforeScaling, 5 * SOL, 5 * SOL, 100, u64::MAX), 0);
+    assert_eq!(calculate_payout_with_mode(SubtractBeforeScaling, 5 * SOL, 6 * SOL, 100, u64::MAX), 0);
+    
+    for mode in [SubtractAfterScaling, SubtractBeforeScaling, Franchise] {
+        assert_eq!(payout(mode, 0), 0, "{:?}", mode);
+    }
+}
+
+#[test]
+fn every_mode_respects_the_cap() {
+    for mode in [SubtractAfterScaling, SubtractBeforeScaling, Franchise] {
+        assert_eq!(calculate_payout_with_mode(mode, 100 * SOL, 5 * SOL, 50, 2 * SOL), 2 * SOL, "{:?}", mode);
+    }
+}
+
+#[test]
+fn calculate_payout_subtracts_after_scaling() {
+    for severity in [0, 5, 6, 10, 50, 100] {
+        assert_eq!(
+            calculate_payout(100 * SOL, 5 * SOL, severity, u64::MAX),
+            payout(SubtractAfterScaling, severity)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use siglab_core::dunning::{blocked_secs, days_overdue, late_fee};
+
+const DAY: i64 = 86_400;
+
+#[test]
+fn only_whole_days_count_as_overdue() {
+    assert_eq!(days_overdue(1_000, 1_000), 0);
+    assert_eq!(days_overdue(1_000, 999), 0);
+    assert_eq!(days_overdue(1_000, 1_000 + DAY - 1), 0);
+    assert_eq!(days_overdue(1_000, 1_000 + 3 * DAY), 3);
+}
+
+#[test]
+fn fee_accrues_per_day_overdue() {
+    // 1% per day on 1 SOL, three days late
+    assert_eq!(late_fee(1_000_000_000, 100, 0, 3 * DAY), 30_000_000);
+    assert_eq!(late_fee(1_000_000_000, 100, 0, DAY - 1), 0);
+}
+
+#[test]
+fn fee_never_exceeds_the_premium() {
+    assert_eq!(late_fee(1_000, 5_000, 0, 10 * DAY), 1_000);
+}
+
+#[test]
+fn zero_rate_charges_nothing() {
+    assert_eq!(late_fee(1_000_000, 0, 0, 100 * DAY), 0);
+}
+
+#[test]
+fn only_blocked_time_after_the_due_date_counts() {
+    // A 3-day pause spanning the due date
+    let pause = [(10 * DAY, 13 * DAY)];
+    assert_eq!(blocked_secs(&pause, 11 * DAY, 20 * DAY), 2 * DAY);
+    assert_eq!(blocked_secs(&pause, 11 * DAY, 12 * DAY), DAY);
+    assert_eq!(blocked_secs(&pause, 14 * DAY, 20 * DAY), 0);
+    assert_eq!(blocked_secs(&pause, 0, 5 * DAY), 0);
+
+    let pauses = [(DAY, 2 * DAY), (4 * DAY, 5 * DAY)];
+    assert_eq!(blocked_secs(&pauses, 0, 10 * DAY), 2 * DAY);
+}
@@ -0,0 +1,126 @@
+use proptest::prelude::*;
+use siglab_core::{payout_exposure_release, payout_terminates, CoverageExposure};
+
+/// One step in a policy portfolio's life, each booked the way its handler does
+#[derive(Clone, Debug)]
+enum Op {
+    /// `create_policy`: the sale books the coverage
+    Create { coverage: u64, max_per_incident: u64, usdc: bool },
+    /// `adjust_coverage`: the difference is booked or released
+    Adjust { policy: usize, coverage: u64 },
+    /// `execute_payout`: releases what the payout settled
+    Payout { policy: usize, paid: u64 },
+    /// `close_policy` of a lapsed policy
+    Close { policy: usize },
+    /// `expire_policy`, whatever the status it expires from
+    Expire { policy: usize },
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    let op = prop_oneof![
+        (1u64..1_000_000_000_000, 1u64..1_000_000_000_000, any::<bool>()).prop_map(|(coverage, cap, usdc)| {
+            Op::Create { coverage, max_per_incident: cap.min(coverage), usdc }
+        }),
+        (any::<usize>(), 1u64..1_000_000_000_000).prop_map(|(policy, coverage)| Op::Adjust { policy, coverage }),
+        (any::<usize>(), 0u64..2_000_000_000_000).prop_map(|(policy, paid)| Op::Payout { policy, paid }),
+        any::<usize>().prop_map(|policy| Op::Close { policy }),
+        any::<usize>().prop_map(|policy| Op::Expire { policy }),
+    ];
+    prop::collection::vec(op, 1..64)
+}
+
+/// A policy that can still claim
+#[derive(Clone, Copy)]
+struct Live {
+    coverage: u64,
+    max_per_incident: u64,
+    usdc: bool,
+}
+
+/// Live policy coverage, mirrored by the treasury's exposure ledger
+#[derive(Default)]
+struct Portfolio {
+    /// Each policy sold, `None` once it no longer claims
+    policies: Vec<Option<Live>>,
+    exposure: CoverageExposure,
+}
+
+impl Portfolio {
+    fn apply(&mut self, op: Op) {
+        match op {
+            Op::Create { coverage, max_per_incident, usdc } => {
+                self.exposure = self.exposure.book(usdc, coverage).unwrap();
+                self.policies.push(Some(Live { coverage, max_per_incident, usdc }));
+            }
+            Op::Adjust { policy, coverage } => {
+                let Some((index, live)) = self.live(policy) else { return };
+                self.exposure = if coverage > live.coverage {
+                    self.exposure.book(live.usdc, coverage - live.coverage).unwrap()
+                } else {
+                    self.exposure.release(live.usdc, live.coverage - coverage)
+                };
+                self.policies[index] = Some(Live { coverage, ..live });
+            }
+            Op::Payout { policy, paid } => {
+                let Some((index, live)) = self.live(policy) else { return };
+                let terminates = payout_terminates(live.coverage, live.max_per_incident, paid);
+                let released = payout_exposure_release(live.coverage, paid, terminates);
+                self.exposure = self.exposure.release(live.usdc, released);
+                self.policies[index] =
+                    if terminates { None } else { Some(Live { coverage: live.coverage - released, ..live }) };
+            }
+            Op::Close { policy } | Op::Expire { policy } => {
+                let Some((index, live)) = self.live(policy) else { return };
+                self.exposure = self.exposure.release(live.usdc, live.coverage);
+                self.policies[index] = None;
+            }
+        }
+    }
+
+    /// Pick a policy by `seed`, if it is still live
+    fn live(&self, seed: usize) -> Option<(usize, Live)> {
+        if self.policies.is_empty() {
+            return None;
+        }
+        let index = seed % self.policies.len();
+        self.policies[index].map(|live| (index, live))
+    }
+
+    fn live_coverage(&self, usdc: bool) -> u64 {
+        self.policies.iter().flatten().filter(|live| live.usdc == usdc).map(|live| live.coverage).sum()
+    }
+}
+
+#[test]
+fn terminating_payouts_release_all_coverage() {
+    assert_eq!(payout_exposure_release(1_000, 400, true), 1_000);
+    assert_eq!(payout_exposure_release(1_000, 0, true), 1_000);
+}
+
+#[test]
+fn continuing_payouts_release_consumed_coverage() {
+    assert_eq!(payout_exposure_release(1_000, 400, false), 400);
+    assert_eq!(payout_exposure_release(1_000, 1_500, false), 1_000);
+}
+
+#[test]
+fn booking_moves_total_with_the_bucket() {
+    let exposure = CoverageExposure::default().book(true, 700).unwrap().book(false, 300).unwrap();
+    assert_eq!(exposure, CoverageExposure { total: 1_000, sol: 300, usdc: 700 });
+    assert_eq!(exposure.release(true, 200), CoverageExposure { total: 800, sol: 300, usdc: 500 });
+    assert_eq!(CoverageExposure { total: u64::MAX, sol: u64::MAX, usdc: 0 }.book(false, 1), None);
+}
+
+proptest! {
+    #[test]
+    fn ledger_matches_live_policies(ops in ops()) {
+        let mut portfolio = Portfolio::default();
+        for op in ops {
+            portfolio.apply(op);
+            let exposure = portfolio.exposure;
+            prop_assert_eq!(exposure.sol, portfolio.live_coverage(false));
+            prop_assert_eq!(exposure.usdc, portfolio.live_coverage(true));
+            prop_assert_eq!(exposure.total, exposure.sol + exposure.usdc);
+        }
+    }
+}
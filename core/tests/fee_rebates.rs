@@ -0,0 +1,47 @@
+use siglab_core::underwriting::{fee_rebate, rebate_rate_bps, rebate_tiers_valid, RebateTier};
+
+const TIERS: [RebateTier; 3] = [
+    RebateTier { min_placed: 1_000_000, rebate_bps: 500 },
+    RebateTier { min_placed: 10_000_000, rebate_bps: 1_000 },
+    RebateTier { min_placed: 100_000_000, rebate_bps: 2_500 },
+];
+
+#[test]
+fn tiers_start_at_their_threshold() {
+    assert_eq!(rebate_rate_bps(&TIERS, 999_999), 0);
+    assert_eq!(rebate_rate_bps(&TIERS, 1_000_000), 500);
+    assert_eq!(rebate_rate_bps(&TIERS, 9_999_999), 500);
+    assert_eq!(rebate_rate_bps(&TIERS, 10_000_000), 1_000);
+    assert_eq!(rebate_rate_bps(&TIERS, 99_999_999), 1_000);
+    assert_eq!(rebate_rate_bps(&TIERS, 100_000_000), 2_500);
+    assert_eq!(rebate_rate_bps(&TIERS, u64::MAX), 2_500);
+}
+
+#[test]
+fn rebate_is_the_tier_share_of_the_fees_rounded_down() {
+    assert_eq!(fee_rebate(&TIERS, 999_999, 40_000), 0);
+    assert_eq!(fee_rebate(&TIERS, 1_000_000, 40_000), 2_000);
+    assert_eq!(fee_rebate(&TIERS, 10_000_000, 40_000), 4_000);
+    assert_eq!(fee_rebate(&TIERS, 100_000_000, 40_000), 10_000);
+    assert_eq!(fee_rebate(&TIERS, 1_000_000, 39), 1);
+    assert_eq!(fee_rebate(&TIERS, 1_000_000, 19), 0);
+}
+
+#[test]
+fn rebate_never_exceeds_the_fees_collected() {
+    let generous = [RebateTier { min_placed: 0, rebate_bps: u16::MAX }];
+    assert_eq!(fee_rebate(&generous, 0, 7_000), 7_000);
+    assert_eq!(fee_rebate(&generous, 0, u64::MAX), u64::MAX);
+    assert_eq!(fee_rebate(&TIERS, u64::MAX, 0), 0);
+    assert_eq!(fee_rebate(&[], u64::MAX, 7_000), 0);
+}
+
+#[test]
+fn tiers_must_rise_and_stay_within_the_fee() {
+    assert!(rebate_tiers_valid(&TIERS));
+    assert!(rebate_tiers_valid(&[]));
+    assert!(!rebate_tiers_valid(&[TIERS[1], TIERS[0]]));
+    assert!(!rebate_tiers_valid(&[TIERS[0], RebateTier { min_placed: 1_000_000, rebate_bps: 600 }]));
+    assert!(!rebate_tiers_valid(&[TIERS[1], RebateTier { min_placed: 20_000_000, rebate_bps: 900 }]));
+    assert!(!rebate_tiers_valid(&[RebateTier { min_placed: 0, rebate_bps: 10_001 }]));
+}
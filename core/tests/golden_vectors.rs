@@ -0,0 +1,67 @@
+//! Shared golden vectors. Payouts are also checked on-chain by `tests/golden_vectors.ts`, and
+//! consensus by `compute_consensus_matches_golden_vectors` in the client harness
+
+use serde::Deserialize;
+use siglab_core::{calculate_payout, consensus_from_values, evaluate_trigger, remove_outliers, Comparison};
+
+#[derive(Deserialize)]
+struct GoldenVector {
+    name: String,
+    coverage_amount: u64,
+    deductible: u64,
+    max_payout: u64,
+    threshold: f64,
+    oracle_value: u64,
+    severity_percentage: u8,
+    amount: u64,
+}
+
+/// Consensus over a set of fresh oracle values, recorded from the implementation
+/// that predates the allocation-free rewrite
+#[derive(Deserialize)]
+struct ConsensusVector {
+    name: String,
+    values: Vec<u64>,
+    oracle_count: usize,
+    aggregated_value: u64,
+    median_value: u64,
+    standard_deviation: u64,
+    confidence_score: u8,
+}
+
+fn golden_vectors() -> Vec<GoldenVector> {
+    serde_json::from_str(include_str!("../../tests/fixtures/golden_vectors.json"))
+        .expect("golden vectors should parse")
+}
+
+#[test]
+fn payouts_match_golden_vectors() {
+    for vector in golden_vectors() {
+        let severity = evaluate_trigger(Comparison::GreaterThan, vector.oracle_value, vector.threshold);
+        let amount = severity.map_or(0, |severity| {
+            calculate_payout(vector.coverage_amount, vector.deductible, severity, vector.max_payout)
+        });
+        
+        assert_eq!(severity.unwrap_or(0), vector.severity_percentage, "{}", vector.name);
+        assert_eq!(amount, vector.amount, "{}", vector.name);
+    }
+}
+
+#[test]
+fn consensus_matches_golden_vectors() {
+    let vectors: Vec<ConsensusVector> =
+        serde_json::from_str(include_str!("../../tests/fixtures/consensus_vectors.json"))
+            .expect("consensus vectors should parse");
+    
+    for vector in vectors {
+        let mut values = vector.values.clone();
+        let retained = remove_outliers(&mut values);
+        let stats = consensus_from_values(&mut values[..retained]);
+        
+        assert_eq!(retained, vector.oracle_count, "{}", vector.name);
+        assert_eq!(stats.aggregated_value, vector.aggregated_value, "{}", vector.name);
+        assert_eq!(stats.median_value, vector.median_value, "{}", vector.name);
+        assert_eq!(stats.standard_deviation, vector.standard_deviation, "{}", vector.name);
+        assert_eq!(stats.confidence_score, vector.confidence_score, "{}", vector.name);
+    }
+}
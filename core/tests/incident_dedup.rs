@@ -0,0 +1,40 @@
+use siglab_core::incident::{incident_bucket, repeats_incident};
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// Midnight of an arbitrary day
+const START: i64 = 1_700_006_400;
+
+#[test]
+fn claims_minutes_apart_repeat_the_incident() {
+    assert!(repeats_incident(START + 10 * MINUTE, START, DAY));
+    assert!(repeats_incident(START, START + 10 * MINUTE, DAY));
+}
+
+#[test]
+fn separate_incidents_are_distinct() {
+    assert!(!repeats_incident(START + 3 * DAY, START, DAY));
+    assert!(!repeats_incident(START, START + 3 * DAY, DAY));
+}
+
+#[test]
+fn incidents_exactly_the_gap_apart_are_distinct() {
+    assert!(repeats_incident(START + DAY - 1, START, DAY));
+    assert!(!repeats_incident(START + DAY, START, DAY));
+}
+
+#[test]
+fn a_zero_gap_bars_nothing() {
+    assert!(!repeats_incident(START, START, 0));
+    assert!(!repeats_incident(START + 1, START, 0));
+}
+
+#[test]
+fn buckets_group_incidents_by_gap() {
+    assert_eq!(incident_bucket(START, DAY), incident_bucket(START + 10 * MINUTE, DAY));
+    assert_ne!(incident_bucket(START, DAY), incident_bucket(START + DAY, DAY));
+    assert_eq!(incident_bucket(-1, DAY), -1);
+    assert_eq!(incident_bucket(START, 0), START);
+}
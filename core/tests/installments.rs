@@ -0,0 +1,57 @@
+use siglab_core::installments::{
+    apply_installment_payment, installment_count, installments_due, pro_rata_coverage_bps,
+    InstallmentProgress, FULL_COVERAGE_BPS,
+};
+
+const MONTH: i64 = 30 * 86400;
+
+#[test]
+fn annual_term_paid_monthly_has_thirteen_installments() {
+    // 365 days do not divide into 30-day periods, so a short thirteenth covers the tail
+    assert_eq!(installment_count(365 * 86400, MONTH), 13);
+    assert_eq!(installment_count(360 * 86400, MONTH), 12);
+    assert_eq!(installment_count(0, MONTH), 1);
+}
+
+#[test]
+fn installments_fall_due_one_period_apart() {
+    assert_eq!(installments_due(1_000, MONTH, 12, 999), 0);
+    assert_eq!(installments_due(1_000, MONTH, 12, 1_000), 1);
+    assert_eq!(installments_due(1_000, MONTH, 12, 1_000 + MONTH - 1), 1);
+    assert_eq!(installments_due(1_000, MONTH, 12, 1_000 + MONTH), 2);
+    assert_eq!(installments_due(1_000, MONTH, 12, 1_000 + 100 * MONTH), 12);
+}
+
+#[test]
+fn partial_payments_accumulate_on_the_earliest_installment() {
+    let first = apply_installment_payment(100, 12, 0, 0, 40).unwrap();
+    assert_eq!(first, InstallmentProgress { paid: 0, partial: 40, applied_to: 0 });
+
+    let second = apply_installment_payment(100, 12, first.paid, first.partial, 60).unwrap();
+    assert_eq!(second, InstallmentProgress { paid: 1, partial: 0, applied_to: 0 });
+}
+
+#[test]
+fn excess_carries_into_later_installments() {
+    assert_eq!(
+        apply_installment_payment(100, 12, 1, 50, 260),
+        Some(InstallmentProgress { paid: 4, partial: 10, applied_to: 1 })
+    );
+}
+
+#[test]
+fn payments_beyond_the_schedule_are_rejected() {
+    assert_eq!(apply_installment_payment(100, 2, 1, 50, 51), None);
+    assert_eq!(
+        apply_installment_payment(100, 2, 1, 50, 50),
+        Some(InstallmentProgress { paid: 2, partial: 0, applied_to: 1 })
+    );
+}
+
+#[test]
+fn coverage_scales_with_installments_paid() {
+    assert_eq!(pro_rata_coverage_bps(3, 3), FULL_COVERAGE_BPS);
+    assert_eq!(pro_rata_coverage_bps(4, 3), FULL_COVERAGE_BPS);
+    assert_eq!(pro_rata_coverage_bps(2, 4), 5_000);
+    assert_eq!(pro_rata_coverage_bps(0, 0), FULL_COVERAGE_BPS);
+}
@@ -0,0 +1,43 @@
+use siglab_core::{confidence_within, evaluate_trigger, Comparison};
+
+/// Rainfall trigger threshold in mm
+const THRESHOLD: f64 = 100.0;
+
+#[test]
+fn tight_interval_around_the_threshold_is_accepted() {
+    // 105 ± 5 mm: confident the threshold was crossed
+    assert!(confidence_within(105, 5, 1_000));
+    assert!(evaluate_trigger(Comparison::GreaterThan, 105, THRESHOLD).is_some());
+}
+
+#[test]
+fn wide_interval_around_the_threshold_is_rejected() {
+    // 105 ± 20 mm could equally be below the threshold
+    assert!(!confidence_within(105, 20, 1_000));
+    // Interval wider than the value itself
+    assert!(!confidence_within(105, 200, 10_000));
+}
+
+#[test]
+fn limit_is_inclusive() {
+    assert!(confidence_within(1_000, 100, 1_000));
+    assert!(!confidence_within(1_000, 101, 1_000));
+}
+
+#[test]
+fn zero_limit_accepts_any_interval() {
+    assert!(confidence_within(1, u64::MAX, 0));
+    assert!(confidence_within(0, 1, 0));
+}
+
+#[test]
+fn zero_value_needs_zero_confidence() {
+    assert!(confidence_within(0, 0, 1));
+    assert!(!confidence_within(0, 1, 10_000));
+}
+
+#[test]
+fn large_values_do_not_overflow() {
+    assert!(confidence_within(u64::MAX, u64::MAX, 10_000));
+    assert!(!confidence_within(u64::MAX / 2, u64::MAX, 10_000));
+}
@@ -0,0 +1,136 @@
+use siglab_core::versioning::{
+    check_version, OracleDataExtensions, VersionError, MAX_EXTENSIONS_LEN, ORACLE_DATA_V1,
+    ORACLE_DATA_V2,
+};
+
+/// Base fields shared by every minor version of major version 1
+#[derive(Debug, PartialEq)]
+struct BaseFields {
+    value: u64,
+    timestamp: i64,
+    confidence: u64,
+    signature: [u8; 64],
+    nonce: u64,
+}
+
+const BASE: BaseFields = BaseFields {
+    value: 1_250,
+    timestamp: 1_700_000_000,
+    confidence: 95,
+    signature: [7; 64],
+    nonce: 42,
+};
+
+/// Borsh layout of `OracleData`: version, base fields, then the
+/// length-prefixed extension section
+fn encode(version: u8, base: &BaseFields, extensions: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![version];
+    bytes.extend_from_slice(&base.value.to_le_bytes());
+    bytes.extend_from_slice(&base.timestamp.to_le_bytes());
+    bytes.extend_from_slice(&base.confidence.to_le_bytes());
+    bytes.extend_from_slice(&base.signature);
+    bytes.extend_from_slice(&base.nonce.to_le_bytes());
+    bytes.extend_from_slice(&(extensions.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(extensions);
+    bytes
+}
+
+/// Split encoded data into version, base fields and raw extensions
+fn decode(bytes: &[u8]) -> (u8, BaseFields, &[u8]) {
+    let u64_at = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+    let mut signature = [0u8; 64];
+    signature.copy_from_slice(&bytes[25..89]);
+    let base = BaseFields {
+        value: u64_at(1),
+        timestamp: u64_at(9) as i64,
+        confidence: u64_at(17),
+        signature,
+        nonce: u64_at(89),
+    };
+    let len = u32::from_le_bytes(bytes[97..101].try_into().unwrap()) as usize;
+    (bytes[0], base, &bytes[101..101 + len])
+}
+
+/// What v1 code reads: the version check and base fields, extensions untouched
+fn read_as_v1(bytes: &[u8]) -> Result<BaseFields, VersionError> {
+    let (version, base, _) = decode(bytes);
+    check_version(version)?;
+    Ok(base)
+}
+
+fn v2_extensions(exponent: i32, feed_id: &[u8]) -> Vec<u8> {
+    let mut out = [0u8; MAX_EXTENSIONS_LEN];
+    let len = OracleDataExtensions { exponent, feed_id }.encode(&mut out).unwrap();
+    out[..len].to_vec()
+}
+
+#[test]
+fn v2_code_reads_v1_data_with_defaults() {
+    let bytes = encode(ORACLE_DATA_V1, &BASE, &[]);
+    
+    let (version, base, extensions) = decode(&bytes);
+    assert_eq!(base, BASE);
+    assert_eq!(
+        OracleDataExtensions::decode(version, extensions),
+        Ok(OracleDataExtensions::default())
+    );
+}
+
+#[test]
+fn v1_code_reads_v2_data_ignoring_extensions() {
+    let bytes = encode(ORACLE_DATA_V2, &BASE, &v2_extensions(-2, b"rainfall-mm"));
+    
+    assert_eq!(read_as_v1(&bytes), Ok(BASE));
+}
+
+#[test]
+fn v2_round_trips_its_extensions() {
+    let bytes = encode(ORACLE_DATA_V2, &BASE, &v2_extensions(-8, b"BTC/USD"));
+    
+    let (version, base, extensions) = decode(&bytes);
+    assert_eq!(base, BASE);
+    assert_eq!(
+        OracleDataExtensions::decode(version, extensions),
+        Ok(OracleDataExtensions { exponent: -8, feed_id: b"BTC/USD" })
+    );
+}
+
+#[test]
+fn trailing_bytes_from_newer_minor_versions_are_ignored() {
+    let mut extensions = v2_extensions(3, b"rainfall-mm");
+    extensions.extend_from_slice(&[0xaa; 16]);
+    let bytes = encode(ORACLE_DATA_V2 + 1, &BASE, &extensions);
+    
+    let (version, base, extensions) = decode(&bytes);
+    assert_eq!(base, BASE);
+    assert_eq!(
+        OracleDataExtensions::decode(version, extensions),
+        Ok(OracleDataExtensions { exponent: 3, feed_id: b"rainfall-mm" })
+    );
+}
+
+#[test]
+fn unknown_major_versions_are_rejected() {
+    let bytes = encode(0x20, &BASE, &[]);
+    
+    assert_eq!(read_as_v1(&bytes), Err(VersionError::UnsupportedMajor(2)));
+    let (version, _, extensions) = decode(&bytes);
+    assert_eq!(
+        OracleDataExtensions::decode(version, extensions),
+        Err(VersionError::UnsupportedMajor(2))
+    );
+}
+
+#[test]
+fn truncated_v2_extensions_are_malformed() {
+    let extensions = v2_extensions(0, b"rainfall-mm");
+    
+    assert_eq!(
+        OracleDataExtensions::decode(ORACLE_DATA_V2, &extensions[..extensions.len() - 1]),
+        Err(VersionError::Malformed)
+    );
+    assert_eq!(
+        OracleDataExtensions::decode(ORACLE_DATA_V2, &[]),
+        Err(VersionError::Malformed)
+    );
+}
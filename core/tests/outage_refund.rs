@@ -0,0 +1,27 @@
+use siglab_core::pricing::outage_refund;
+
+const DAY: i64 = 86_400;
+const TERM: i64 = 100 * DAY;
+
+#[test]
+fn downtime_within_the_threshold_is_not_refunded() {
+    assert_eq!(outage_refund(10_000, 4 * DAY as u64, TERM, 500), None);
+    assert_eq!(outage_refund(10_000, 5 * DAY as u64, TERM, 500), None);
+}
+
+#[test]
+fn downtime_beyond_the_threshold_is_refunded_pro_rata() {
+    assert_eq!(outage_refund(10_000, 6 * DAY as u64, TERM, 500), Some(600));
+    assert_eq!(outage_refund(999, 50 * DAY as u64, TERM, 500), Some(499));
+}
+
+#[test]
+fn refund_never_exceeds_the_premiums_paid() {
+    assert_eq!(outage_refund(10_000, 2 * TERM as u64, TERM, 500), Some(10_000));
+    assert_eq!(outage_refund(u64::MAX, u64::MAX, TERM, 1), Some(u64::MAX));
+}
+
+#[test]
+fn zero_threshold_disables_refunds() {
+    assert_eq!(outage_refund(10_000, TERM as u64, TERM, 0), None);
+}
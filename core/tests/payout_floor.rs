@@ -0,0 +1,26 @@
+use siglab_core::meets_payout_floor;
+
+#[test]
+fn payout_at_the_minimum_clears_the_floor() {
+    assert!(meets_payout_floor(5_000, 50, 5_000, None));
+}
+
+#[test]
+fn payout_one_unit_below_the_minimum_is_dust() {
+    assert!(!meets_payout_floor(4_999, 50, 5_000, None));
+}
+
+#[test]
+fn severity_at_the_floor_clears_it() {
+    assert!(meets_payout_floor(5_000, 50, 0, Some(5_000)));
+}
+
+#[test]
+fn severity_one_basis_point_below_the_floor_is_dust() {
+    assert!(!meets_payout_floor(5_000, 50, 0, Some(5_001)));
+}
+
+#[test]
+fn no_floors_accept_any_payout() {
+    assert!(meets_payout_floor(1, 1, 0, None));
+}
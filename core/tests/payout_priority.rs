@@ -0,0 +1,28 @@
+use siglab_core::payout::MAX_PRIORITY;
+use siglab_core::payout_priority;
+
+#[test]
+fn severity_adds_its_share_of_the_points() {
+    assert_eq!(payout_priority(70, 0, 25), 70);
+    assert_eq!(payout_priority(70, 50, 25), 82);
+    assert_eq!(payout_priority(70, 100, 25), 95);
+}
+
+#[test]
+fn default_points_match_a_quarter_of_the_severity() {
+    for severity in 0..=100 {
+        assert_eq!(payout_priority(50, severity, 25), 50 + severity / 4);
+    }
+}
+
+#[test]
+fn zero_points_leave_the_base_priority() {
+    assert_eq!(payout_priority(60, 100, 0), 60);
+}
+
+#[test]
+fn priority_is_capped() {
+    assert_eq!(payout_priority(90, 100, 25), MAX_PRIORITY);
+    assert_eq!(payout_priority(100, 255, 100), MAX_PRIORITY);
+    assert_eq!(payout_priority(0, 255, 100), MAX_PRIORITY);
+}
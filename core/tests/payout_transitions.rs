@@ -0,0 +1,60 @@
+use siglab_core::lifecycle::{is_allowed_payout_transition, PayoutState};
+use PayoutState::*;
+
+const STATES: [PayoutState; 6] = [Pending, PendingApproval, Ready, Executed, Rejected, Expired];
+
+const ALLOWED: [(PayoutState, PayoutState); 9] = [
+    (Pending, PendingApproval),
+    (Pending, Ready),
+    (Pending, Rejected),
+    (Pending, Expired),
+    (PendingApproval, Ready),
+    (PendingApproval, Rejected),
+    (PendingApproval, Expired),
+    (Ready, Executed),
+    (Ready, Expired),
+];
+
+#[test]
+fn transition_matrix_matches_the_lifecycle() {
+    for from in STATES {
+        for to in STATES {
+            let expected = ALLOWED.contains(&(from, to));
+            assert_eq!(is_allowed_payout_transition(from, to), expected, "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn ready_payouts_execute() {
+    assert!(is_allowed_payout_transition(Ready, Executed));
+}
+
+#[test]
+fn payouts_awaiting_approval_can_be_rejected_but_not_executed() {
+    assert!(is_allowed_payout_transition(PendingApproval, Rejected));
+    assert!(!is_allowed_payout_transition(PendingApproval, Executed));
+}
+
+#[test]
+fn every_open_payout_can_expire() {
+    for from in [Pending, PendingApproval, Ready] {
+        assert!(is_allowed_payout_transition(from, Expired), "{:?}", from);
+    }
+}
+
+#[test]
+fn final_states_have_no_exits() {
+    for from in [Executed, Rejected, Expired] {
+        for to in STATES {
+            assert!(!is_allowed_payout_transition(from, to), "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn nothing_returns_to_pending() {
+    for from in STATES {
+        assert!(!is_allowed_payout_transition(from, Pending), "{:?}", from);
+    }
+}
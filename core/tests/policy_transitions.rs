@@ -0,0 +1,54 @@
+use siglab_core::lifecycle::{is_allowed_transition, PolicyState};
+use PolicyState::*;
+
+const STATES: [PolicyState; 6] = [PendingActivation, Active, PendingPayout, PaidOut, Cancelled, Expired];
+
+const ALLOWED: [(PolicyState, PolicyState); 8] = [
+    (PendingActivation, Active),
+    (PendingActivation, Cancelled),
+    (PendingActivation, Expired),
+    (Active, PendingPayout),
+    (Active, Cancelled),
+    (Active, Expired),
+    (PendingPayout, PaidOut),
+    (PendingPayout, Active),
+];
+
+#[test]
+fn transition_matrix_matches_the_lifecycle() {
+    for from in STATES {
+        for to in STATES {
+            let expected = ALLOWED.contains(&(from, to));
+            assert_eq!(is_allowed_transition(from, to), expected, "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn no_state_transitions_to_itself() {
+    for state in STATES {
+        assert!(!is_allowed_transition(state, state), "{:?}", state);
+    }
+}
+
+#[test]
+fn final_states_have_no_exits() {
+    for from in [PaidOut, Cancelled, Expired] {
+        for to in STATES {
+            assert!(!is_allowed_transition(from, to), "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn withdrawn_payouts_return_to_coverage() {
+    assert!(is_allowed_transition(PendingPayout, Active));
+    assert!(!is_allowed_transition(PaidOut, Active));
+}
+
+#[test]
+fn nothing_reenters_pending_activation() {
+    for from in STATES {
+        assert!(!is_allowed_transition(from, PendingActivation), "{:?}", from);
+    }
+}
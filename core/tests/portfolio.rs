@@ -0,0 +1,35 @@
+use siglab_core::portfolio::{average_severity, claim_frequency_bps, loss_ratio_bps};
+
+#[test]
+fn loss_ratio_is_payouts_over_premiums() {
+    assert_eq!(loss_ratio_bps(250, 1_000), 2_500);
+    assert_eq!(loss_ratio_bps(0, 1_000), 0);
+}
+
+#[test]
+fn loss_ratio_above_one_is_not_capped() {
+    assert_eq!(loss_ratio_bps(3_000, 1_000), 30_000);
+}
+
+#[test]
+fn ratios_without_a_denominator_are_zero() {
+    assert_eq!(loss_ratio_bps(500, 0), 0);
+    assert_eq!(claim_frequency_bps(3, 0), 0);
+}
+
+#[test]
+fn extreme_ratios_saturate() {
+    assert_eq!(loss_ratio_bps(u64::MAX, 1), u32::MAX);
+}
+
+#[test]
+fn claim_frequency_is_claims_per_policy() {
+    assert_eq!(claim_frequency_bps(1, 8), 1_250);
+}
+
+#[test]
+fn average_severity_rounds_down() {
+    assert_eq!(average_severity(0, 0), 0);
+    assert_eq!(average_severity(150, 2), 75);
+    assert_eq!(average_severity(100, 3), 33);
+}
@@ -0,0 +1,41 @@
+use siglab_core::pricing::{interpolated_rate_bps, reprice_for_risk, term_premium, unexpired_share};
+
+#[test]
+fn repricing_scales_with_the_offset_risk_score() {
+    assert_eq!(reprice_for_risk(1_000, 0, 0), Some(1_000));
+    assert_eq!(reprice_for_risk(1_000, 0, 100), Some(2_000));
+    assert_eq!(reprice_for_risk(2_000, 100, 0), Some(1_000));
+    assert_eq!(reprice_for_risk(1_000, 50, 20), Some(800));
+}
+
+#[test]
+fn repricing_rejects_overflow() {
+    assert_eq!(reprice_for_risk(u64::MAX, 0, 100), None);
+    assert_eq!(reprice_for_risk(u64::MAX, 100, 0), Some(u64::MAX / 2));
+}
+
+#[test]
+fn unexpired_share_runs_down_over_the_term() {
+    assert_eq!(unexpired_share(1_000, 0, 100, 0), 1_000);
+    assert_eq!(unexpired_share(1_000, 0, 100, 25), 750);
+    assert_eq!(unexpired_share(1_000, 0, 100, 100), 0);
+    assert_eq!(unexpired_share(1_000, 0, 100, 150), 0);
+    assert_eq!(unexpired_share(1_000, 10, 110, 0), 1_000);
+}
+
+#[test]
+fn rates_interpolate_across_the_range() {
+    assert_eq!(interpolated_rate_bps(200, 600, 0), 200);
+    assert_eq!(interpolated_rate_bps(200, 600, 5_000), 400);
+    assert_eq!(interpolated_rate_bps(200, 600, 10_000), 600);
+    assert_eq!(interpolated_rate_bps(600, 200, 2_500), 500);
+    assert_eq!(interpolated_rate_bps(200, 600, u16::MAX), 600);
+}
+
+#[test]
+fn term_premium_is_prorated_by_day() {
+    assert_eq!(term_premium(1_000_000, 365, 500), Some(50_000));
+    assert_eq!(term_premium(1_000_000, 73, 500), Some(10_000));
+    assert_eq!(term_premium(1_000_000, 0, 500), Some(0));
+    assert_eq!(term_premium(u64::MAX, 365 * 2, 10_000), None);
+}
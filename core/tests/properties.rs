@@ -0,0 +1,85 @@
+use proptest::prelude::*;
+use siglab_core::consensus::{consensus_from_values, remove_outliers};
+use siglab_core::reserves::{required_reserves, reserve_ratio_bps, FULL_RESERVE_BPS};
+use siglab_core::trigger::{combine_leaves, Connective, SeverityMode};
+use siglab_core::calculate_payout;
+
+/// Oracle readings small enough that sums and squared deviations cannot overflow
+fn oracle_values() -> impl Strategy<Value = Vec<u64>> {
+    prop::collection::vec(0u64..1_000_000_000, 1..10)
+}
+
+proptest! {
+    #[test]
+    fn payout_is_monotonic_in_severity(
+        coverage in any::<u64>(),
+        deductible in any::<u64>(),
+        max_payout in any::<u64>(),
+        severity in 0u8..100,
+    ) {
+        let lower = calculate_payout(coverage, deductible, severity, max_payout);
+        let higher = calculate_payout(coverage, deductible, severity + 1, max_payout);
+        prop_assert!(lower <= higher);
+    }
+    
+    #[test]
+    fn payout_never_exceeds_limits(
+        coverage in any::<u64>(),
+        deductible in any::<u64>(),
+        max_payout in any::<u64>(),
+        severity in 0u8..=100,
+    ) {
+        let payout = calculate_payout(coverage, deductible, severity, max_payout);
+        prop_assert!(payout <= max_payout);
+        prop_assert!(payout <= coverage);
+    }
+    
+    #[test]
+    fn consensus_stays_within_input_range(mut values in oracle_values()) {
+        let min = *values.iter().min().unwrap();
+        let max = *values.iter().max().unwrap();
+        
+        let stats = consensus_from_values(&mut values);
+        prop_assert!(stats.aggregated_value >= min && stats.aggregated_value <= max);
+        prop_assert!(stats.median_value >= min && stats.median_value <= max);
+        prop_assert!(stats.standard_deviation <= max - min);
+        prop_assert!(stats.confidence_score <= 100);
+    }
+    
+    #[test]
+    fn outlier_removal_keeps_a_subset(mut values in oracle_values()) {
+        let original = values.clone();
+        let retained = remove_outliers(&mut values);
+        
+        prop_assert!(retained <= original.len());
+        for value in &values[..retained] {
+            prop_assert!(original.contains(value));
+        }
+    }
+    
+    #[test]
+    fn required_reserves_never_exceed_exposure(ratio_bps in 0..=FULL_RESERVE_BPS, exposure in any::<u64>()) {
+        prop_assert!(required_reserves(ratio_bps, exposure) <= exposure);
+    }
+    
+    #[test]
+    fn reserve_ratio_is_capped(balance in any::<u64>(), exposure in any::<u64>()) {
+        prop_assert!(reserve_ratio_bps(balance, exposure) <= FULL_RESERVE_BPS);
+    }
+    
+    #[test]
+    fn compound_severity_is_bounded_by_satisfied_leaves(
+        leaves in prop::collection::vec(prop::option::of(0u8..=100), 1..=4),
+        any_connective in any::<bool>(),
+        worst in any::<bool>(),
+    ) {
+        let connective = if any_connective { Connective::Any } else { Connective::All };
+        let mode = if worst { SeverityMode::Worst } else { SeverityMode::Average };
+        
+        if let Some(severity) = combine_leaves(connective, mode, leaves.iter().copied()) {
+            let satisfied = leaves.iter().flatten();
+            prop_assert!(severity <= *satisfied.clone().max().unwrap());
+            prop_assert!(severity >= *satisfied.min().unwrap());
+        }
+    }
+}
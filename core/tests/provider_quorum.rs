@@ -0,0 +1,27 @@
+use siglab_core::{independent_sources, outlier_bounds, remove_outliers};
+
+#[test]
+fn readings_without_a_source_each_count() {
+    assert_eq!(independent_sources::<u8>(&[None, None, None]), 3);
+    assert_eq!(independent_sources::<u8>(&[]), 0);
+}
+
+#[test]
+fn readings_sharing_a_source_count_once() {
+    assert_eq!(independent_sources(&[Some(1), Some(1), Some(1)]), 1);
+    assert_eq!(independent_sources(&[Some(1), None, Some(2), Some(1), None]), 4);
+}
+
+#[test]
+fn outlier_bounds_match_the_values_kept() {
+    let mut values = [100, 100, 100, 100, 100, 100, 10_000];
+    let (lower, upper) = outlier_bounds(&values).unwrap();
+    let kept = values.iter().filter(|value| (lower..=upper).contains(value)).count();
+    assert_eq!(remove_outliers(&mut values), kept);
+    assert_eq!(kept, 6);
+}
+
+#[test]
+fn small_sets_have_no_outlier_bounds() {
+    assert_eq!(outlier_bounds(&[1, 1_000_000]), None);
+}
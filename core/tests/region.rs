@@ -0,0 +1,32 @@
+use siglab_core::region::{fits_region_cap, paid_today, payout_day, region_cap};
+
+const DAY: i64 = 86_400;
+
+#[test]
+fn cap_is_a_share_of_the_treasury_balance() {
+    assert_eq!(region_cap(1_000_000, 2_500), 250_000);
+    assert_eq!(region_cap(1_000_000, 0), 0);
+    // Caps may exceed the balance, as exposure is only partly reserved
+    assert_eq!(region_cap(1_000_000, 30_000), 3_000_000);
+    // Rounded down
+    assert_eq!(region_cap(3, 5_000), 1);
+}
+
+#[test]
+fn amounts_fit_up_to_the_cap_inclusive() {
+    assert!(fits_region_cap(700, 300, Some(1_000)));
+    assert!(!fits_region_cap(700, 301, Some(1_000)));
+    assert!(fits_region_cap(0, 0, Some(0)));
+    assert!(fits_region_cap(u64::MAX - 1, 1, None));
+    assert!(!fits_region_cap(u64::MAX, 1, None));
+}
+
+#[test]
+fn daily_totals_reset_at_midnight_utc() {
+    let day = payout_day(100 * DAY + 5);
+    assert_eq!(day, 100);
+    assert_eq!(paid_today(day, 500, 100 * DAY + DAY - 1), 500);
+    assert_eq!(paid_today(day, 500, 101 * DAY), 0);
+    // Timestamps before the epoch still fall on whole days
+    assert_eq!(payout_day(-1), -1);
+}
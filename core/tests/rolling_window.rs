@@ -0,0 +1,78 @@
+use siglab_core::rolling::{count_in_window, record, WINDOW_HOURS};
+
+const HOUR: i64 = 3600;
+const DAY: i64 = 24 * HOUR;
+
+/// Midnight of an arbitrary day
+const START: i64 = 1_700_006_400;
+
+struct Window {
+    buckets: [u16; WINDOW_HOURS],
+    latest_hour: i64,
+}
+
+impl Window {
+    fn new() -> Self {
+        Self { buckets: [0; WINDOW_HOURS], latest_hour: 0 }
+    }
+    
+    fn record(&mut self, timestamp: i64) {
+        record(&mut self.buckets, &mut self.latest_hour, timestamp);
+    }
+    
+    fn count(&self, now: i64) -> u32 {
+        count_in_window(&self.buckets, self.latest_hour, now)
+    }
+}
+
+#[test]
+fn counts_updates_within_the_last_day() {
+    let mut window = Window::new();
+    for hour in 0..6 {
+        window.record(START + hour * HOUR);
+        window.record(START + hour * HOUR + 1800);
+    }
+    
+    assert_eq!(window.count(START + 6 * HOUR), 12);
+}
+
+#[test]
+fn rolls_over_the_day_boundary_hour_by_hour() {
+    let mut window = Window::new();
+    // One update every hour of the first day, plus an extra one at 23:30
+    for hour in 0..24 {
+        window.record(START + hour * HOUR);
+    }
+    window.record(START + 23 * HOUR + 1800);
+    assert_eq!(window.count(START + 23 * HOUR + 1800), 25);
+    
+    // Just after midnight the first hour of the previous day drops out
+    window.record(START + DAY + 60);
+    assert_eq!(window.count(START + DAY + 60), 25);
+    
+    // Halfway through the next day without updates, only the last 12 hours remain
+    assert_eq!(window.count(START + DAY + 11 * HOUR), 14);
+}
+
+#[test]
+fn is_empty_after_a_full_day_of_silence() {
+    let mut window = Window::new();
+    window.record(START);
+    window.record(START + HOUR);
+    
+    assert_eq!(window.count(START + DAY + HOUR), 0);
+    
+    // Recording again long after clears the stale buckets
+    window.record(START + 3 * DAY);
+    assert_eq!(window.count(START + 3 * DAY), 1);
+    assert_eq!(window.buckets.iter().map(|&count| count as u32).sum::<u32>(), 1);
+}
+
+#[test]
+fn ignores_events_older_than_the_window() {
+    let mut window = Window::new();
+    window.record(START + DAY);
+    window.record(START - HOUR);
+    
+    assert_eq!(window.count(START + DAY), 1);
+}
@@ -0,0 +1,24 @@
+use siglab_core::reserves::{solvency, Solvency};
+
+const MIN_BPS: u16 = 2_000;
+
+#[test]
+fn reserves_at_the_minimum_are_healthy() {
+    assert_eq!(solvency(200, 1_000, MIN_BPS), Solvency::Healthy);
+    assert_eq!(solvency(1_000, 1_000, MIN_BPS), Solvency::Healthy);
+}
+
+#[test]
+fn reserves_below_the_minimum_are_undercapitalized() {
+    assert_eq!(solvency(199, 1_000, MIN_BPS), Solvency::Undercapitalized);
+}
+
+#[test]
+fn exposure_without_reserves_is_insolvent() {
+    assert_eq!(solvency(0, 1_000, MIN_BPS), Solvency::Insolvent);
+}
+
+#[test]
+fn no_exposure_is_healthy() {
+    assert_eq!(solvency(0, 0, MIN_BPS), Solvency::Healthy);
+}
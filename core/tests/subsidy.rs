@@ -0,0 +1,36 @@
+use siglab_core::subsidy::{split_premium, PremiumSplit};
+
+#[test]
+fn sponsor_covers_its_percentage() {
+    assert_eq!(
+        split_premium(10_000, 7_500, u64::MAX),
+        PremiumSplit { sponsor_share: 7_500, holder_share: 2_500 }
+    );
+}
+
+#[test]
+fn sponsor_share_rounds_down() {
+    assert_eq!(split_premium(999, 5_000, u64::MAX).sponsor_share, 499);
+    assert_eq!(split_premium(999, 5_000, u64::MAX).holder_share, 500);
+}
+
+#[test]
+fn budget_caps_the_sponsor_share() {
+    assert_eq!(
+        split_premium(10_000, 7_500, 1_000),
+        PremiumSplit { sponsor_share: 1_000, holder_share: 9_000 }
+    );
+}
+
+#[test]
+fn exhausted_budget_leaves_the_full_premium() {
+    assert_eq!(
+        split_premium(10_000, 10_000, 0),
+        PremiumSplit { sponsor_share: 0, holder_share: 10_000 }
+    );
+}
+
+#[test]
+fn coverage_above_full_is_capped() {
+    assert_eq!(split_premium(u64::MAX, u16::MAX, u64::MAX).holder_share, 0);
+}
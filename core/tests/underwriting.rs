@@ -0,0 +1,24 @@
+use siglab_core::underwriting::{bond_slash, underwriting_bond};
+
+#[test]
+fn bond_is_a_share_of_the_coverage() {
+    assert_eq!(underwriting_bond(1_000_000_000, 500), 50_000_000);
+    assert_eq!(underwriting_bond(1_000_000_000, 0), 0);
+    // Rounded up
+    assert_eq!(underwriting_bond(1, 1), 1);
+}
+
+#[test]
+fn claims_within_the_loss_ratio_bound_forfeit_nothing() {
+    // 150% bound on 100 of premiums allows 150 of claims
+    assert_eq!(bond_slash(1_000, 100, 150, 15_000), 0);
+    assert_eq!(bond_slash(1_000, 100, 0, 15_000), 0);
+}
+
+#[test]
+fn claims_above_the_bound_come_out_of_the_bond() {
+    assert_eq!(bond_slash(1_000, 100, 400, 15_000), 250);
+    assert_eq!(bond_slash(1_000, 100, 10_000, 15_000), 1_000);
+    // Without premiums every claim is excess
+    assert_eq!(bond_slash(1_000, 0, 300, 15_000), 300);
+}
@@ -0,0 +1,43 @@
+use siglab_core::vesting::{tranche_amount, tranche_release_at, unreleased_amount};
+
+const WEEK: i64 = 7 * 86400;
+
+#[test]
+fn tranches_split_evenly_with_the_remainder_last() {
+    assert_eq!(tranche_amount(1_000, 4, 0), 250);
+    assert_eq!(tranche_amount(1_000, 4, 3), 250);
+    assert_eq!(tranche_amount(1_000, 3, 0), 333);
+    assert_eq!(tranche_amount(1_000, 3, 1), 333);
+    assert_eq!(tranche_amount(1_000, 3, 2), 334);
+}
+
+#[test]
+fn tranches_sum_to_the_total() {
+    for total in [0, 1, 7, 1_000, 999_999_999_999, u64::MAX] {
+        for tranches in 1..=12u8 {
+            let paid: u128 = (0..tranches).map(|index| tranche_amount(total, tranches, index) as u128).sum();
+            assert_eq!(paid, total as u128, "{total} in {tranches} tranches");
+        }
+    }
+}
+
+#[test]
+fn nothing_is_paid_outside_the_schedule() {
+    assert_eq!(tranche_amount(1_000, 4, 4), 0);
+    assert_eq!(tranche_amount(1_000, 0, 0), 0);
+}
+
+#[test]
+fn tranches_release_one_interval_apart() {
+    assert_eq!(tranche_release_at(1_000, WEEK, 0), 1_000);
+    assert_eq!(tranche_release_at(1_000, WEEK, 2), 1_000 + 2 * WEEK);
+    assert_eq!(tranche_release_at(i64::MAX - 1, WEEK, 1), i64::MAX);
+}
+
+#[test]
+fn unreleased_amount_covers_the_remaining_tranches() {
+    assert_eq!(unreleased_amount(1_000, 3, 0), 1_000);
+    assert_eq!(unreleased_amount(1_000, 3, 1), 667);
+    assert_eq!(unreleased_amount(1_000, 3, 2), 334);
+    assert_eq!(unreleased_amount(1_000, 3, 3), 0);
+}
@@ -0,0 +1,32 @@
+use siglab_core::incident::{coverage_start, evidence_within_coverage};
+
+const HOUR: i64 = 3600;
+
+/// Midnight of an arbitrary day
+const START: i64 = 1_700_006_400;
+
+#[test]
+fn cover_starts_after_the_waiting_period() {
+    assert_eq!(coverage_start(START, 0), START);
+    assert_eq!(coverage_start(START, 24), START + 24 * HOUR);
+    assert_eq!(coverage_start(i64::MAX - HOUR, 2), i64::MAX);
+}
+
+#[test]
+fn evidence_from_before_the_policy_is_rejected() {
+    assert!(!evidence_within_coverage(START - 1, START, 0));
+    assert!(!evidence_within_coverage(START - HOUR, START, 24));
+}
+
+#[test]
+fn evidence_during_the_waiting_period_is_rejected() {
+    assert!(!evidence_within_coverage(START, START, 24));
+    assert!(!evidence_within_coverage(START + 24 * HOUR - 1, START, 24));
+}
+
+#[test]
+fn evidence_after_the_waiting_period_is_accepted() {
+    assert!(evidence_within_coverage(START + 24 * HOUR, START, 24));
+    assert!(evidence_within_coverage(START + 30 * HOUR, START, 24));
+    assert!(evidence_within_coverage(START, START, 0));
+}
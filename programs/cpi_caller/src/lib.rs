@@ -0,0 +1,147 @@
+// The IDL instructions generated by `#[program]` still call `AccountInfo::realloc`
+#![allow(deprecated)]
+
+use anchor_lang::prelude::*;
+use siglab_contract::cpi::accounts::{CreatePolicy, PayPremium};
+use siglab_contract::instructions::CreatePolicyParams;
+use siglab_contract::program::SiglabContract;
+
+declare_id!("4wT2ZEjCfaTWaZLkmVNYs5Wz6Cz7ajUQnwfKER7dXp81");
+
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Minimal partner program bundling insurance into its own flow.
+/// A system-owned vault PDA acts as the policyholder and signs via `invoke_signed`.
+#[program]
+pub mod cpi_caller {
+    use super::*;
+
+    pub fn create_policy_via_cpi(
+        ctx: Context<CreatePolicyViaCpi>,
+        params: CreatePolicyParams,
+    ) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, user.as_ref(), &[ctx.bumps.vault]];
+        let signer_seeds = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.siglab_program.to_account_info(),
+            CreatePolicy {
+                policy_holder: ctx.accounts.vault.to_account_info(),
+                rent_payer: ctx.accounts.vault.to_account_info(),
+                master_contract: ctx.accounts.master_contract.to_account_info(),
+                policy_account: ctx.accounts.policy_account.to_account_info(),
+                treasury: ctx.accounts.treasury.to_account_info(),
+                type_config: ctx.accounts.type_config.to_account_info(),
+                co_signer: None,
+                underwriter_ledger: None,
+                region_exposure: None,
+                discount_code: None,
+                discount_redemption: None,
+                policy_mint: None,
+                holder_token_account: None,
+                policy_token_metadata: None,
+                token_program: None,
+                associated_token_program: None,
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        siglab_contract::cpi::create_policy(cpi_ctx, params)
+    }
+
+    pub fn pay_premium_via_cpi(ctx: Context<PayPremiumViaCpi>, amount: u64) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, user.as_ref(), &[ctx.bumps.vault]];
+        let signer_seeds = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.siglab_program.to_account_info(),
+            PayPremium {
+                payer: ctx.accounts.vault.to_account_info(),
+                policy_account: ctx.accounts.policy_account.to_account_info(),
+                policy_history: ctx.accounts.policy_history.to_account_info(),
+                master_contract: ctx.accounts.master_contract.to_account_info(),
+                policy_token_account: None,
+                subsidy: None,
+                treasury: ctx.accounts.treasury.to_account_info(),
+                payer_token_account: None,
+                treasury_usdc_account: None,
+                fee_vault: None,
+                fee_vault_usdc_account: None,
+                underwriter_ledger: None,
+                token_program: None,
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        siglab_contract::cpi::pay_premium(cpi_ctx, amount)
+    }
+}
+
+#[derive(Accounts)]
+pub struct CreatePolicyViaCpi<'info> {
+    pub user: Signer<'info>,
+
+    /// CHECK: System-owned PDA funded by the user; signs as the policyholder
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    #[account(mut)]
+    pub master_contract: UncheckedAccount<'info>,
+
+    /// CHECK: Initialized by siglab_contract
+    #[account(mut)]
+    pub policy_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    pub type_config: UncheckedAccount<'info>,
+
+    pub siglab_program: Program<'info, SiglabContract>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayPremiumViaCpi<'info> {
+    pub user: Signer<'info>,
+
+    /// CHECK: System-owned PDA that holds the policy
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    #[account(mut)]
+    pub master_contract: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    #[account(mut)]
+    pub policy_account: UncheckedAccount<'info>,
+
+    /// CHECK: Initialized by siglab_contract on the first premium
+    #[account(mut)]
+    pub policy_history: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by siglab_contract
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub siglab_program: Program<'info, SiglabContract>,
+
+    pub system_program: Program<'info, System>,
+}
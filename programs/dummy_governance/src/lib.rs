@@ -0,0 +1,52 @@
+// The IDL instructions generated by `#[program]` still call `AccountInfo::realloc`
+#![allow(deprecated)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("FKRwSzK9ASgKbTKdp5E91hA8EJmt5mETTEVjoXm3PyVR");
+
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+
+/// Minimal stand-in for a DAO governance program such as Realms.
+/// Its governance PDA holds the authority of the governed program and signs
+/// whatever instruction `execute` is given via `invoke_signed`; there is no
+/// voting, so it is only fit for tests.
+#[program]
+pub mod dummy_governance {
+    use super::*;
+
+    /// Invoke `target_program` with `data`, passing the remaining accounts in
+    /// order and signing for the governance PDA among them
+    pub fn execute<'info>(ctx: Context<'_, '_, 'info, 'info, Execute<'info>>, data: Vec<u8>) -> Result<()> {
+        let governance = ctx.accounts.governance.key();
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer || *account.key == governance,
+                is_writable: account.is_writable,
+            })
+            .collect();
+        let instruction = Instruction { program_id: ctx.accounts.target_program.key(), accounts, data };
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.target_program.to_account_info());
+        let seeds: &[&[u8]] = &[GOVERNANCE_SEED, &[ctx.bumps.governance]];
+        invoke_signed(&instruction, &account_infos, &[seeds])?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    /// CHECK: Signs only through `invoke_signed`
+    #[account(seeds = [GOVERNANCE_SEED], bump)]
+    pub governance: UncheckedAccount<'info>,
+
+    /// CHECK: Any program the governance PDA holds an authority in
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+}
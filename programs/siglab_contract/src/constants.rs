@@ -1,16 +1,102 @@
-use anchor_lang::prelude::*;
-
 pub const MASTER_CONTRACT_SEED: &[u8] = b"master_contract";
 pub const POLICY_SEED: &[u8] = b"policy";
+pub const POLICY_HISTORY_SEED: &[u8] = b"policy_history";
+pub const PENDING_PAYOUT_SEED: &[u8] = b"pending_payout";
 pub const ORACLE_SEED: &[u8] = b"oracle";
 pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const POLICY_MINT_SEED: &[u8] = b"policy_mint";
+pub const POLICY_TOKEN_METADATA_SEED: &[u8] = b"policy_token_metadata";
+pub const POLICY_TRANSFER_SEED: &[u8] = b"policy_transfer";
+pub const BREACH_WATCH_SEED: &[u8] = b"breach_watch";
+pub const DISCOUNT_CODE_SEED: &[u8] = b"discount_code";
+pub const DISCOUNT_REDEMPTION_SEED: &[u8] = b"discount_redemption";
+pub const CONSENSUS_SEED: &[u8] = b"consensus";
+pub const TYPE_CONFIG_SEED: &[u8] = b"type_config";
+pub const PARAM_PROPOSAL_SEED: &[u8] = b"param_proposal";
+pub const SUBSIDY_SEED: &[u8] = b"subsidy";
+pub const PORTFOLIO_REPORT_SEED: &[u8] = b"portfolio_report";
+pub const VESTING_PAYOUT_SEED: &[u8] = b"vesting_payout";
+pub const PRODUCT_TEMPLATE_SEED: &[u8] = b"product_template";
+pub const ORACLE_RETIREMENT_SEED: &[u8] = b"oracle_retirement";
+pub const OBSERVATION_PROOF_SEED: &[u8] = b"observation_proof";
+pub const REGION_EXPOSURE_SEED: &[u8] = b"region_exposure";
+pub const ORACLE_DIRECTORY_SEED: &[u8] = b"oracle_directory";
+pub const DATA_PROVIDER_SEED: &[u8] = b"data_provider";
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+pub const ADMIN_AUDIT_LOG_SEED: &[u8] = b"admin_audit_log";
+pub const UNDERWRITER_LEDGER_SEED: &[u8] = b"underwriter_ledger";
+
+/// Prefixes every signed oracle update, keeping its signatures out of other protocols
+pub const ORACLE_MESSAGE_DOMAIN: &[u8] = b"siglab_contract:oracle_data:v1";
 
 pub const MAX_ORACLES: usize = 10;
 pub const MIN_ORACLES_FOR_CONSENSUS: usize = 3;
+// `max_oracles` and `min_consensus_threshold` are stored as `u8`, and the
+// threshold floor must leave room for a registry of `MAX_ORACLES`
+const _: () = assert!(MAX_ORACLES <= u8::MAX as usize);
+const _: () = assert!(MIN_ORACLES_FOR_CONSENSUS >= 1 && MIN_ORACLES_FOR_CONSENSUS <= MAX_ORACLES);
 pub const ORACLE_UPDATE_INTERVAL: i64 = 300; // 5 minutes
+pub const MAX_ORACLE_UPDATE_INTERVAL: i64 = 86400; // 24 hours
+pub const MAX_ORACLE_SELF_PAUSE: i64 = 86400; // 24 hours per maintenance pause
+pub const DEFAULT_MIN_ORACLE_REPUTATION: u8 = 70; // Below this an oracle is left out of consensus
+pub const MAX_ORACLE_FEE_LAMPORTS: u64 = 10_000_000; // 0.01 SOL per consumed update
+pub const ORACLE_REGISTRATION_STAKE: u64 = 100_000_000; // 0.1 SOL bonded by self-registered oracles
+pub const ORACLE_RESIGNATION_COOLDOWN: i64 = 7 * 86400; // Before a resigned oracle leaves the registry
+pub const MIN_REVEAL_DELAY_SLOTS: u64 = 2; // Between committing an oracle update and revealing it
+pub const MAX_BOOTSTRAP_ORACLES: usize = 4; // Oracles registered by one `bootstrap_dev_environment`
+const _: () = assert!(MAX_BOOTSTRAP_ORACLES <= MAX_ORACLES);
+pub const MAX_DENIAL_REASON_LENGTH: usize = 128;
+pub const MAX_DONATION_MEMO_LENGTH: usize = 64;
+pub const MAX_SUBSIDY_HOLDERS: usize = 32;
+pub const MAX_CRANK_TIP_LAMPORTS: u64 = 5_000_000; // 0.005 SOL per executed payout
+pub const PRIORITY_AGING_POINTS_PER_HOUR: u8 = 2;
+pub const MAX_PRIORITY_AGING_BOOST: u8 = 50; // Reached after 25 hours
+pub const CONSENSUS_SNAPSHOT_RETENTION: i64 = 30 * 86400; // 30 days
+pub const DEFAULT_CONSENSUS_TIMEOUT: i64 = 600; // 10 minutes
+pub const MAX_CONSENSUS_TIMEOUT: i64 = 86400; // 24 hours
+pub const MAX_CONSENSUS_FALLBACK_AGE: i64 = 7 * 86400; // 7 days
+pub const CONSENSUS_DISPUTE_WINDOW: i64 = 15 * 60; // 15 minutes
+pub const CONSENSUS_DISPUTE_MIN_COVERAGE: u64 = 100_000_000_000; // 100 SOL
+pub const MAX_INCIDENT_TOLERANCE: i64 = 7 * 86400; // 7 days
+pub const MAX_MIN_INCIDENT_GAP: i64 = 30 * 86400; // 30 days
+pub const MAX_MIN_WAITING_PERIOD_HOURS: u32 = 30 * 24; // 30 days
+pub const MAX_PARAM_TIMELOCK: i64 = 30 * 86400; // 30 days
+pub const MAX_PAYOUT_TRANCHES: u8 = 12;
+pub const MAX_VESTING_INTERVAL: i64 = 90 * 86400; // 90 days between tranches
+pub const MAX_VESTING_CANCEL_REASON_LENGTH: usize = 128;
+pub const MAX_PREMIUM_PAUSES: usize = 8; // Premium-blocking pauses kept for delinquency
+pub const MAX_REPORT_REGIONS: usize = 16; // Regions a portfolio report can list
+pub const OBSERVATION_PROOF_RETENTION: i64 = 90 * 86400; // 90 days before a proof may be closed
+pub const FEE_EPOCH_SECS: i64 = 30 * 86400; // Accounting epoch underwriter fee rebates are earned over
+pub const UNDERWRITER_LEDGER_EPOCHS: usize = 6; // Epochs kept per underwriter, so rebates stay claimable this long
+pub const MAX_REBATE_TIERS: usize = 8;
 
+// Defaults of `GlobalConfig`; deployments may configure their own within the ceilings
 pub const MIN_PREMIUM_AMOUNT: u64 = 1_000_000; // 0.001 SOL
 pub const MAX_COVERAGE_AMOUNT: u64 = 1_000_000_000_000; // 1000 SOL
-pub const MIN_RESERVE_RATIO: u64 = 20; // 20%
+pub const MAX_POLICY_DURATION_DAYS: u32 = 365;
+pub const DEFAULT_LATE_FEE_BPS: u16 = 0; // No late fees until configured
+pub const MAX_LATE_FEE_BPS: u16 = 1_000; // 10% of the premium per day overdue
+pub const DEFAULT_MAX_CURRENCY_EXPOSURE: u64 = u64::MAX; // No per-currency cap until configured
+pub const DEFAULT_MIN_PAYOUT_AMOUNT: u64 = 0; // Any payout above the deductible is paid until configured
+pub const DEFAULT_MAX_OPEN_PAYOUTS: u64 = 10_000;
+pub const DEFAULT_UNDERWRITING_BOND_BPS: u16 = 0; // Co-signers lock no bond until configured
+pub const DEFAULT_BOND_MAX_LOSS_RATIO_BPS: u16 = 10_000; // Bonds are slashed once claims exceed premiums
+pub const DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS: u16 = 500; // Oracle downtime beyond 5% of the term is refunded
+pub const DEFAULT_CIRCUIT_BREAKER_FAILURES: u32 = 5; // Failed validations that trip an oracle's circuit breaker
+pub const DEFAULT_REPUTATION_PENALTY: u8 = 3;
+pub const DEFAULT_ACCURACY_PENALTY: u8 = 5;
+pub const DEFAULT_ORACLE_RECOVERY_RATE: u8 = 1; // Reputation and accuracy regained per successful update
+pub const DEFAULT_ORACLE_CLOCK_SKEW_SECS: u32 = 30; // Seconds oracle data may be dated ahead of the cluster clock
+pub const MAX_ORACLE_CLOCK_SKEW_SECS: u32 = 5 * 60;
+pub const MAX_COVERAGE_CEILING: u64 = 1_000_000_000_000_000; // 1,000,000 SOL
+pub const MAX_POLICY_DURATION_CEILING_DAYS: u32 = 5 * 365;
+pub const MIN_RESERVE_RATIO_BPS: u16 = 1_000; // 10%
+pub const MAX_RESERVE_RATIO_BPS: u16 = 5_000; // 50%
+pub const USDC_DECIMALS: u8 = 6; // Decimals of a new treasury's USDC mint
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+pub const MAX_PRICE_AGE_SECS: i64 = 300; // Oldest SOL/USD price used for currency conversion
 
-pub const ADMIN_WITHDRAWAL_DELAY: i64 = 86400; // 24 hours
\ No newline at end of file
+pub const ADMIN_WITHDRAWAL_DELAY: i64 = 86400; // 24 hours
+pub const POLICY_TRANSFER_EXPIRY: i64 = 72 * 3600; // 72 hours
+pub const POLICY_ID_PREFIX: &str = "POL"; // Prefix of the displayed policy id
\ No newline at end of file
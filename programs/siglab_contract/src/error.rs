@@ -119,4 +119,443 @@ pub enum InsuranceError {
     
     #[msg("Invalid admin operation parameters")]
     InvalidAdminOperation,
+    
+    // === Discount Code Errors ===
+    #[msg("Discount code does not match the provided preimage")]
+    InvalidDiscountCode,
+    
+    #[msg("Discount code has expired")]
+    DiscountCodeExpired,
+    
+    #[msg("Discount code has no remaining redemptions")]
+    DiscountCodeExhausted,
+    
+    // === Policy Token Errors ===
+    #[msg("Policy token accounts are required for tokenized policies")]
+    PolicyTokenAccountRequired,
+    
+    #[msg("Policy token account does not hold this policy's token")]
+    InvalidPolicyTokenAccount,
+    
+    #[msg("Policy cannot be closed in its current status")]
+    PolicyNotClosable,
+    
+    // === Policy Transfer Errors ===
+    #[msg("Policy ownership transfer proposal has expired")]
+    TransferProposalExpired,
+    
+    #[msg("Tokenized policies change ownership by transferring the policy token")]
+    PolicyIsTokenized,
+    
+    // === Policy Metadata Errors ===
+    #[msg("Policy metadata exceeds the maximum allowed length")]
+    MetadataTooLong,
+    
+    // === Trigger Grace Period Errors ===
+    #[msg("Trigger breach has not persisted for the policy's grace period")]
+    GracePeriodNotElapsed,
+    
+    // === Cross-Program Invocation Errors ===
+    #[msg("This instruction cannot be invoked via CPI")]
+    CpiNotAllowed,
+    
+    // === Treasury Linkage Errors ===
+    #[msg("Master contract has no treasury account linked")]
+    TreasuryNotLinked,
+    
+    #[msg("Treasury account does not match the one linked to the master contract")]
+    TreasuryAccountMismatch,
+    
+    #[msg("Withdrawal recipient cannot be the treasury itself")]
+    InvalidRecipient,
+    
+    // === Payout Queue Errors ===
+    #[msg("Payout queue has reached its maximum size")]
+    PayoutQueueFull,
+    
+    // === Oracle Attestation Errors ===
+    #[msg("Signer address does not match the oracle's signature scheme")]
+    InvalidSignerAddress,
+    
+    #[msg("Expected a secp256k1 program instruction preceding the oracle update")]
+    MissingSecp256k1Instruction,
+    
+    #[msg("Secp256k1 attestation does not match the oracle signer or update")]
+    Secp256k1AttestationMismatch,
+    
+    // === Trigger Quorum Errors ===
+    #[msg("Not enough oracle authorities co-signed the trigger")]
+    QuorumNotMet,
+    
+    #[msg("Oracle authority co-signed the trigger more than once")]
+    DuplicateQuorumSigner,
+    
+    #[msg("Trigger co-signer is not the authority of a registered, active oracle")]
+    QuorumSignerNotRegistered,
+    
+    #[msg("Ed25519 quorum attestation is malformed")]
+    InvalidQuorumAttestation,
+    
+    // === Oracle Rate Limit Errors ===
+    #[msg("Oracle update arrived before the oracle's minimum update interval elapsed")]
+    OracleUpdateTooFrequent,
+    
+    // === Oracle Fee Errors ===
+    #[msg("Oracle accounts consumed by a trigger must be writable to accrue fees")]
+    OracleAccountNotWritable,
+    
+    #[msg("Oracle has no unclaimed fees")]
+    NoOracleFeesToClaim,
+    
+    // === Consensus Round Errors ===
+    #[msg("Oracle account was passed more than once for a consensus round")]
+    DuplicateOracleAccount,
+    
+    #[msg("Consensus snapshot does not match the referenced round")]
+    ConsensusRoundMismatch,
+    
+    #[msg("Consensus round is older than the policy's staleness threshold")]
+    ConsensusRoundStale,
+    
+    #[msg("Consensus snapshot is still within its retention period")]
+    ConsensusSnapshotRetained,
+    
+    // === Consensus Dispute Errors ===
+    #[msg("Consensus round is disputed and cannot be used")]
+    ConsensusRoundDisputed,
+    
+    #[msg("Consensus round is still within its dispute window")]
+    ConsensusRoundNotFinal,
+    
+    #[msg("Dispute window for the consensus round has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Consensus round is not open to this dispute action")]
+    InvalidDisputeState,
+    
+    #[msg("Slashed oracle does not match the side that deviated most")]
+    SlashedOracleMismatch,
+    
+    // === Oracle Data Versioning Errors ===
+    #[msg("Oracle data was written under an unsupported major version")]
+    UnsupportedOracleDataVersion,
+    
+    // === Incident Window Errors ===
+    #[msg("Claimed incident falls outside the policy's coverage period")]
+    IncidentOutsideCoverage,
+    
+    #[msg("Trigger evidence is too far from the claimed incident")]
+    EvidenceOutsideIncidentWindow,
+    
+    #[msg("Incident was already claimed on this policy")]
+    DuplicateIncident,
+    
+    // === Policy Lifecycle Errors ===
+    #[msg("Policy cannot move to the requested status")]
+    InvalidStatusTransition,
+    
+    // === Parameter Timelock Errors ===
+    #[msg("Parameter change is still timelocked")]
+    ParamChangeTimelocked,
+    
+    #[msg("Account does not match the target of the parameter change")]
+    ParamChangeTargetMismatch,
+    
+    // === Oracle Registration Errors ===
+    #[msg("Oracle registration is awaiting admin approval")]
+    OraclePendingApproval,
+    
+    #[msg("Oracle has no pending registration")]
+    OracleNotPending,
+    
+    // === Subsidy Errors ===
+    #[msg("Subsidy does not cover this policy or holder")]
+    SubsidyNotApplicable,
+    
+    // === Treasury Migration Errors ===
+    #[msg("Triggered payouts must be executed or expired first")]
+    PayoutsOutstanding,
+    
+    #[msg("Treasury version must follow the current one")]
+    InvalidTreasuryVersion,
+    
+    #[msg("Treasury has been retired")]
+    TreasuryRetired,
+    
+    #[msg("Only a retired treasury can be closed")]
+    TreasuryNotRetired,
+    
+    #[msg("A treasury is already linked to the master contract")]
+    TreasuryAlreadyLinked,    
+    // === Oracle Pause Errors ===
+    #[msg("Oracle pause must last between one second and 24 hours")]
+    InvalidPauseDuration,
+    
+    // === Rent Payer Errors ===
+    #[msg("Rent refund account does not match the recorded rent payer")]
+    RentPayerMismatch,
+    
+    // === Installment Errors ===
+    #[msg("Payment exceeds the premium remaining on the installment schedule")]
+    InstallmentOverpayment,
+    
+    #[msg("Policy is behind on its installment schedule")]
+    InstallmentsInArrears,
+    
+    // === Late Fee Errors ===
+    #[msg("Payment does not cover the late fee on the overdue premium")]
+    LateFeeUnpaid,
+    
+    #[msg("No late fee is due on the policy")]
+    NoLateFeeDue,
+    
+    // === Endorsement Errors ===
+    #[msg("Policy has reached its endorsement limit until renewal")]
+    EndorsementLimitReached,
+    
+    // === Trigger Amendment Errors ===
+    #[msg("Policy has no pending trigger amendment")]
+    NoPendingTriggerAmendment,
+    
+    // === Policy Expiry Errors ===
+    #[msg("Policy has not reached its end date")]
+    PolicyNotExpired,
+    
+    // === Currency Errors ===
+    #[msg("Treasury has no account configured for the requested currency")]
+    UnsupportedCurrency,
+    
+    #[msg("Coverage would exceed the exposure limit of the payout currency")]
+    CurrencyExposureLimitExceeded,
+    
+    // === Payout Floor Errors ===
+    #[msg("Payout or severity is below the minimum worth paying out")]
+    PayoutBelowMinimum,
+    
+    // === Winddown Errors ===
+    #[msg("Contract is winding down and sells no new coverage")]
+    ContractInWinddown,
+    
+    #[msg("Contract is not winding down")]
+    WinddownNotStarted,
+    
+    #[msg("Policies are still active")]
+    PoliciesStillActive,
+    
+    // === Oracle Commit-Reveal Errors ===
+    #[msg("Oracle updates must be committed and then revealed")]
+    OracleCommitRevealRequired,
+    
+    #[msg("Oracle is not in commit-reveal mode")]
+    OracleCommitRevealDisabled,
+    
+    #[msg("Oracle already has a commitment awaiting reveal")]
+    OracleCommitmentPending,
+    
+    #[msg("Oracle has no commitment to reveal")]
+    NoOracleCommitment,
+    
+    #[msg("Oracle commitment cannot be revealed yet")]
+    OracleRevealTooEarly,
+    
+    #[msg("Revealed oracle data does not match the commitment")]
+    OracleRevealMismatch,
+    
+    #[msg("Policy was created while the oracle value was committed but unrevealed")]
+    PolicyCreatedDuringCommitment,
+    
+    // === Waiting Period Errors ===
+    #[msg("Evidence was observed before the policy's waiting period ended")]
+    EvidenceBeforeCoverage,
+    
+    // === Currency Errors ===
+    #[msg("No fresh SOL/USD price is available to convert between currencies")]
+    PriceUnavailable,
+    
+    // === Ed25519 Attestation Errors ===
+    #[msg("Expected an ed25519 program instruction preceding the oracle update")]
+    MissingEd25519Instruction,
+    
+    #[msg("Ed25519 attestation does not match the oracle authority or update")]
+    Ed25519AttestationMismatch,
+    
+    // === Payout Vesting Errors ===
+    #[msg("A vesting payout account must be passed exactly when the policy vests payouts")]
+    VestingPayoutMismatch,
+    
+    #[msg("The next payout tranche is not due yet")]
+    TrancheNotDue,
+    
+    // === Payout Destination Errors ===
+    #[msg("Payout destination is not the beneficiary's associated token account")]
+    InvalidPayoutDestination,
+    
+    #[msg("Payout is too small to leave the beneficiary's account rent-exempt")]
+    PayoutBelowRentExemption,
+    
+    // === Consensus Dispersion Errors ===
+    #[msg("Enough fresh oracles reported but their values disagree too much for consensus")]
+    ConsensusDispersionTooHigh,
+    
+    // === Product Template Errors ===
+    #[msg("Product template is not active")]
+    ProductTemplateInactive,
+    
+    #[msg("Threshold, coverage or duration is outside the product template's range")]
+    TemplateParameterOutOfRange,
+    
+    // === Oracle Retirement Errors ===
+    #[msg("Policy coverage is suspended until its retired oracle is replaced")]
+    CoverageSuspended,
+    
+    // === Premium Pause Errors ===
+    #[msg("The contract is paused with premium payments blocked")]
+    PremiumPaymentsPaused,
+    
+    // === Underwriting Errors ===
+    #[msg("Coverage at or above the co-signing threshold needs the underwriter's signature")]
+    CoSignatureRequired,
+    
+    #[msg("Policy has no underwriting bond to release")]
+    NoUnderwritingBond,
+    
+    #[msg("Policy's underwriting bond must be released before it can be closed")]
+    UnderwritingBondLocked,
+    
+    // === Observation Proof Errors ===
+    #[msg("Oracle has no retained reading at the requested time")]
+    ObservationNotFound,
+    
+    #[msg("Observation proofs may only be closed after the retention period")]
+    ObservationProofRetained,
+    
+    #[msg("Observation proof does not match the policy's oracle and trigger value")]
+    ObservationProofMismatch,
+    
+    // === Region Errors ===
+    #[msg("Region code is missing or zero")]
+    InvalidRegionCode,
+    
+    #[msg("Region exposure account is required for this policy")]
+    RegionExposureRequired,
+    
+    #[msg("Region exposure account does not match the policy's region")]
+    RegionMismatch,
+    
+    #[msg("Coverage would exceed the region's exposure cap")]
+    RegionExposureCapExceeded,
+    
+    #[msg("Payout would exceed the region's daily payout cap")]
+    RegionDailyPayoutCapExceeded,
+    
+    // === Oracle Confidence Errors ===
+    #[msg("Oracle confidence interval is too wide relative to the reported value")]
+    OracleConfidenceTooLow,
+    
+    // === Oracle Timestamp Errors ===
+    #[msg("Oracle data carries no timestamp")]
+    OracleTimestampMissing,
+    
+    #[msg("Oracle data is dated in the future")]
+    OracleTimestampInFuture,
+    
+    // === Auto-claim Errors ===
+    #[msg("Policy holder has not enabled automatic claims")]
+    AutoClaimNotEnabled,
+    
+    // === Payout Evidence Errors ===
+    #[msg("Trigger evidence exceeds the space reserved for it")]
+    EvidenceTooLarge,
+    
+    // === Oracle Outage Errors ===
+    #[msg("Oracle is not the one the policy reads")]
+    PolicyOracleMismatch,
+    
+    #[msg("Oracle downtime does not exceed the outage refund threshold")]
+    OutageBelowThreshold,
+    
+    #[msg("Outage refund has already been claimed")]
+    OutageRefundClaimed,
+    
+    // === Oracle Registry Errors ===
+    #[msg("Oracle registry lists an oracle account that no longer exists; sync the registry first")]
+    OracleRegistryStale,
+    
+    // === Data Provider Errors ===
+    #[msg("Data provider name must be 1 to 32 bytes")]
+    InvalidDataProviderName,
+    
+    #[msg("Fresh readings come from too few independent data providers to reach the consensus threshold")]
+    ProviderQuorumNotMet,
+    
+    // === Governance Errors ===
+    #[msg("Governance program account is not an executable program")]
+    InvalidGovernanceProgram,
+    
+    #[msg("A governance authority must accept through its governance program via CPI")]
+    GovernanceInvocationRequired,
+    
+    // === Oracle Limit Errors ===
+    #[msg("max_oracles must be between the consensus threshold floor and MAX_ORACLES")]
+    InvalidMaxOracles,
+    
+    #[msg("min_consensus_threshold is below MIN_ORACLES_FOR_CONSENSUS outside single-oracle mode")]
+    ConsensusThresholdTooLow,
+    
+    #[msg("min_consensus_threshold exceeds max_oracles")]
+    ConsensusThresholdAboveMaxOracles,
+    
+    // === Oracle Resignation Errors ===
+    #[msg("Oracle has resigned")]
+    OracleResigning,
+    
+    #[msg("Oracle has not resigned")]
+    OracleNotResigning,
+    
+    #[msg("Oracle resignation cooldown has not elapsed")]
+    OracleResignationCooldown,
+    
+    // === Treasury Migration Errors ===
+    #[msg("Retired treasury still holds USDC")]
+    RetiredTreasuryHoldsUsdc,
+    
+    // === Fee Vault Errors ===
+    #[msg("An overdue premium's late fee must be paid into the fee vault")]
+    FeeVaultRequired,
+    
+    #[msg("Fee vault USDC account mismatch")]
+    FeeVaultAccountMismatch,
+    
+    // === Coverage Adjustment Errors ===
+    #[msg("Coverage of a policy paid in installments cannot be adjusted")]
+    InstallmentCoverageNotAdjustable,
+    
+    // === Subsidy Errors ===
+    #[msg("Subsidy budgets are in SOL and cannot co-pay a premium in another currency")]
+    SubsidyCurrencyMismatch,
+    
+    // === Fee Rebate Errors ===
+    #[msg("Rebate tiers must rise in coverage, never lower the rate and stay within the fee")]
+    InvalidRebateTiers,
+    
+    #[msg("A co-signing underwriter must pass its ledger")]
+    UnderwriterLedgerRequired,
+    
+    #[msg("Underwriter ledger does not belong to the policy's co-signer")]
+    UnderwriterLedgerMismatch,
+    
+    #[msg("Fee rebates can only be claimed once the epoch has closed")]
+    FeeRebateEpochOpen,
+    
+    #[msg("Underwriter ledger no longer holds this epoch")]
+    FeeRebateEpochUnavailable,
+    
+    #[msg("Fee rebate already claimed for this epoch")]
+    FeeRebateAlreadyClaimed,
+    
+    #[msg("No fee rebate is due for this epoch")]
+    NoFeeRebate,
+    
+    #[msg("Fee vault holds too little to pay the rebate")]
+    InsufficientFeeVault,
 }
\ No newline at end of file
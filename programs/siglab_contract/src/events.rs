@@ -1,11 +1,26 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{
+    ClaimRejection, ConsensusFallbackMode, EndorsementKind, FeeRebateTier, InsuranceType, OracleExclusionReason,
+    ParamChange, ProductTerms, RegionReport, RetirementPlan, TokenType, TypeConfigParams, TypeLimitViolation,
+    TypeReport,
+};
+
+/// Discriminator of the instruction emitting an event
+pub fn instruction_discriminator<T: Discriminator>() -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&T::DISCRIMINATOR[..8]);
+    discriminator
+}
 
 #[event]
 pub struct MasterContractInitialized {
     pub admin: Pubkey,
     pub treasury_mint: Pubkey,
-    pub reserve_ratio: u64,
+    pub reserve_ratio_bps: u16,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
@@ -16,23 +31,48 @@ pub struct PolicyCreated {
     pub coverage_amount: u64,
     pub premium_amount: u64,
     pub expiry_timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct PremiumPaid {
     pub policy_id: u64,
     pub payer: Pubkey,
+    /// Premium applied, excluding `late_fee`
     pub amount: u64,
+    /// Part of `amount` left to the holder
+    pub holder_amount: u64,
+    /// Part of `amount` drawn from `subsidy`
+    pub subsidy_amount: u64,
+    pub subsidy: Option<Pubkey>,
+    /// Running total of premiums paid on the policy, net of refunds
+    pub total_premiums_paid: u64,
+    /// Installment the payment was applied to first, for policies paid by schedule
+    pub installment_index: Option<u16>,
+    /// Late fee charged on top of the premium
+    pub late_fee: u64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct PayoutTriggered {
-    pub policy_id: String,
+    pub policy_id: u64,
     pub beneficiary: Pubkey,
+    /// Keeper that filed the payout for the holder, if the holder did not
+    pub keeper: Option<Pubkey>,
     pub amount: u64,
     pub oracle_value: u64,
+    /// Insurance type's base priority the queue priority was derived from
+    pub base_priority: u8,
+    /// Queue priority assigned from the base priority and severity
+    pub priority: u8,
+    pub incident_timestamp: i64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
@@ -41,56 +81,1136 @@ pub struct OracleDataUpdated {
     pub data_type: String,
     pub value: u64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct ContractPaused {
     pub admin: Pubkey,
+    /// Whether the pause also blocks premium payments
+    pub premiums_blocked: bool,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct ContractResumed {
     pub admin: Pubkey,
+    /// Seconds the pause blocked premium payments, excluded from delinquency
+    pub premium_blocked_secs: i64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct TreasuryWithdrawn {
     pub admin: Pubkey,
     pub amount: u64,
+    pub token_type: TokenType,
+    /// Treasury balance in `token_type` before and after the withdrawal
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    pub reserve_ratio_after: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TreasuryMigrated {
+    pub admin: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub version: u8,
+    pub lamports_moved: u64,
+    pub usdc_moved: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct RetiredTreasuryClosed {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub version: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PayoutPriorityRecomputed {
+    pub policy_id: u64,
+    pub old_priority: u8,
+    pub new_priority: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct CrankTipUpdated {
+    pub admin: Pubkey,
+    pub tip_lamports: u64,
+    pub budget_lamports: u64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct PayoutExecuted {
-    pub policy_id: String,
+    pub policy_id: u64,
     pub beneficiary: Pubkey,
     pub amount: u64,
-    pub transaction_signature: String,
+    /// Part of `amount` the beneficiary can spend: SOL payouts less the
+    /// deposit keeping their account rent-exempt, USDC payouts less the USDC
+    /// value of a token account created for them
+    pub net_amount: u64,
+    /// Lamports of rent readying the beneficiary's account: the deposit
+    /// taken from a SOL payout, or the token account rent reimbursed to the
+    /// executor of a USDC payout and withheld from it
+    pub destination_rent: u64,
+    /// Pending payout account the payout executed, closed by the instruction
+    pub pending_payout: Pubkey,
+    pub incident_timestamp: i64,
+    /// Region of the insured risk, counted against its daily payout cap
+    pub region_code: Option<[u8; 8]>,
+    pub executor: Pubkey,
+    pub crank_tip: u64,
+    /// Treasury balance in the payout currency before and after the payout,
+    /// crank tip and rent reimbursement
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    /// Reserve ratio in basis points once the payout settled
+    pub reserve_ratio_after: u16,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct PayoutApproved {
-    pub policy_id: String,
+    pub policy_id: u64,
     pub admin: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct PayoutRejected {
-    pub policy_id: String,
+    pub policy_id: u64,
     pub admin: Pubkey,
     pub reason: String,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ClaimFiled {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub incident_timestamp: i64,
+    pub evidence_deadline: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ClaimRejected {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub incident_timestamp: i64,
+    pub reason: ClaimRejection,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PayoutExpired {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PayoutVestingStarted {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub first_tranche: u64,
+    pub tranches: u8,
+    pub next_release_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PayoutTrancheReleased {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub tranches_released: u8,
+    pub tranches: u8,
+    pub remaining_amount: u64,
+    pub executor: Pubkey,
+    pub crank_tip: u64,
+    /// Treasury balance in the payout currency before and after the tranche
+    /// and crank tip
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    /// Reserve ratio in basis points once the tranche is paid
+    pub reserve_ratio_after: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct VestingPayoutCancelled {
+    pub policy_id: u64,
+    pub beneficiary: Pubkey,
+    pub admin: Pubkey,
+    /// Unreleased amount returned to the treasury's reserves
+    pub returned_amount: u64,
+    pub tranches_cancelled: u8,
+    pub reason: String,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
 
 #[event]
 pub struct ReserveRatioUpdated {
     pub admin: Pubkey,
-    pub old_ratio: u64,
-    pub new_ratio: u64,
+    pub old_ratio_bps: u16,
+    pub new_ratio_bps: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct DiscountCodeCreated {
+    pub code_hash: [u8; 32],
+    pub admin: Pubkey,
+    pub discount_bps: u16,
+    pub max_redemptions: u32,
+    pub expires_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct DiscountCodeRedeemed {
+    pub code_hash: [u8; 32],
+    pub holder: Pubkey,
+    pub policy_id: u64,
+    pub original_premium: u64,
+    pub discounted_premium: u64,
+    pub remaining_redemptions: u32,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyTokenMinted {
+    pub policy_id: u64,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyClosed {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub token_burned: bool,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyTransferProposed {
+    pub policy_id: u64,
+    pub current_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyTransferred {
+    pub policy_id: u64,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyTransferCancelled {
+    pub policy_id: u64,
+    pub current_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyMetadataUpdated {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub metadata_uri: Option<String>,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct CoverageAdjusted {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub old_coverage_amount: u64,
+    pub new_coverage_amount: u64,
+    pub old_premium_amount: u64,
+    pub new_premium_amount: u64,
+    pub additional_premium: u64,
+    pub refund_amount: u64,
+    /// Treasury balance in the premium currency before and after the
+    /// additional premium or refund
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    pub reserve_ratio_after: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct BreachObserved {
+    pub policy_id: u64,
+    pub observer: Pubkey,
+    pub oracle_value: u64,
+    pub first_observed_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct BreachWatchCleared {
+    pub policy_id: u64,
+    pub oracle_value: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyActivated {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub premium_amount: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleFeeUpdated {
+    pub admin: Pubkey,
+    pub old_fee_lamports: u64,
+    pub new_fee_lamports: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleFeesClaimed {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub remaining_unclaimed: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRegistrySynced {
+    pub admin: Pubkey,
+    pub pruned_count: u8,
+    pub registered_count: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusComputed {
+    pub round: u64,
+    pub snapshot: Pubkey,
+    pub aggregated_value: u64,
+    pub median_value: u64,
+    pub confidence_score: u8,
+    pub oracle_count: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusSnapshotClosed {
+    pub round: u64,
+    pub collector: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusRentCollectorUpdated {
+    pub admin: Pubkey,
+    pub old_collector: Pubkey,
+    pub new_collector: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusFallbackUpdated {
+    pub admin: Pubkey,
+    pub fallback: ConsensusFallbackMode,
+    pub timeout_secs: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusDegraded {
+    pub round: u64,
+    pub fallback: ConsensusFallbackMode,
+    pub required_oracles: u8,
+    pub consensus_age: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+/// Enough fresh oracles reported but too few agreed once outliers were
+/// dropped; diagnostic for the failed round
+#[event]
+pub struct ConsensusDispersionExceeded {
+    pub round: u64,
+    pub fresh_oracles: u8,
+    pub retained_oracles: u8,
+    pub required_oracles: u8,
+    /// Standard deviation of the fresh values over their mean, in basis points
+    pub coefficient_of_variation_bps: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusDisputed {
+    pub round: u64,
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub consensus_value: u64,
+    pub counter_value: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ConsensusDisputeResolved {
+    pub round: u64,
+    pub admin: Pubkey,
+    pub resolved_value: u64,
+    pub slashed_oracle: Pubkey,
+    pub forfeited_fees: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TypeConfigInitialized {
+    pub admin: Pubkey,
+    pub insurance_type: InsuranceType,
+    pub params: TypeConfigParams,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ParamChangeProposed {
+    pub proposal_id: u64,
+    pub admin: Pubkey,
+    pub change: ParamChange,
+    pub executable_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ParamChangeExecuted {
+    pub proposal_id: u64,
+    pub admin: Pubkey,
+    pub change: ParamChange,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ParamChangeCancelled {
+    pub proposal_id: u64,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRegistrationRequested {
+    pub oracle: Pubkey,
+    pub oracle_id: String,
+    pub authority: Pubkey,
+    pub stake_lamports: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRegistrationApproved {
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+    pub registered_count: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRegistrationDenied {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub reason: String,
+    pub refunded_lamports: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleExcludedFromConsensus {
+    pub oracle: Pubkey,
+    pub reason: OracleExclusionReason,
+    pub reputation_score: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OraclePaused {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub paused_until: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleResumed {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    /// Scheduled end of the pause, earlier than `timestamp` when it lapsed on its own
+    pub paused_until: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct MinOracleReputationUpdated {
+    pub admin: Pubkey,
+    pub old_min_reputation: u8,
+    pub new_min_reputation: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct SubsidyCreated {
+    pub subsidy: Pubkey,
+    pub sponsor: Pubkey,
+    pub insurance_type: Option<InsuranceType>,
+    pub holder_count: u8,
+    pub coverage_bps: u16,
+    pub budget: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct SubsidyToppedUp {
+    pub subsidy: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub budget_remaining: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct SubsidyClosed {
+    pub subsidy: Pubkey,
+    pub sponsor: Pubkey,
+    pub reclaimed_budget: u64,
+    pub total_disbursed: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PortfolioReportPublished {
+    pub report: Pubkey,
+    pub report_index: u64,
+    pub types: Vec<TypeReport>,
+    pub open_payout_count: u64,
+    pub regions: Vec<RegionReport>,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct LateFeeWaived {
+    pub policy_id: u64,
+    /// Due date of the premium whose late fee was waived
+    pub due_date: i64,
+    /// Fee accrued when it was waived
+    pub waived_amount: u64,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyEndorsed {
+    pub policy_id: u64,
+    pub kind: EndorsementKind,
+    pub before: [u8; 32],
+    pub after: [u8; 32],
+    pub actor: Pubkey,
+    /// Endorsements on the policy including this one
+    pub endorsement_count: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TriggerAmendmentProposed {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub max_additional_premium: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TriggerAmendmentApproved {
+    pub policy_id: u64,
+    pub admin: Pubkey,
+    pub old_risk_score: u8,
+    pub new_risk_score: u8,
+    pub old_premium_amount: u64,
+    pub new_premium_amount: u64,
+    pub additional_premium: u64,
+    pub refund_amount: u64,
+    /// Treasury balance in the premium currency before and after the
+    /// additional premium or refund
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    pub reserve_ratio_after: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyExpired {
+    pub policy_id: u64,
+    /// In-force coverage released from portfolio exposure
+    pub coverage_released: u64,
+    pub expired_by: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TreasuryDonated {
+    pub donor: Pubkey,
+    pub token_type: TokenType,
+    pub amount: u64,
+    pub total_donations: u64,
+    pub memo: Option<String>,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct TreasurySettled {
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+    pub sol_amount: u64,
+    pub usdc_amount: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+/// Emitted just before a policy outside its type's limits is rejected
+#[event]
+pub struct PolicyLimitViolated {
+    pub insurance_type: InsuranceType,
+    pub violation: TypeLimitViolation,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleDataCommitted {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    pub commitment: [u8; 32],
+    pub slot: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleDataRevealed {
+    pub oracle: Pubkey,
+    pub value: u64,
+    /// Policies created from this time until the reveal cannot claim on the value
+    pub committed_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+/// Emitted by the balance update that takes the reserve ratio below the minimum
+#[event]
+pub struct ReserveRatioBreached {
+    pub treasury: Pubkey,
+    pub reserve_ratio: u16,
+    pub minimum_reserve_ratio: u16,
+    pub usdc_balance: u64,
+    pub sol_balance: u64,
+    pub coverage_exposure: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+/// Emitted by the balance update that brings the reserve ratio back to the minimum
+#[event]
+pub struct ReserveRatioRestored {
+    pub treasury: Pubkey,
+    pub reserve_ratio: u16,
+    pub minimum_reserve_ratio: u16,
+    pub usdc_balance: u64,
+    pub sol_balance: u64,
+    pub coverage_exposure: u64,
+    /// Start of the breach now ended
+    pub breached_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ProductTemplateCreated {
+    pub product_id: u32,
+    pub version: u16,
+    pub admin: Pubkey,
+    pub terms: ProductTerms,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct ProductTemplateStatusChanged {
+    pub product_id: u32,
+    pub version: u16,
+    pub admin: Pubkey,
+    pub active: bool,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyCreatedFromTemplate {
+    pub policy_id: u64,
+    pub holder: Pubkey,
+    pub product_id: u32,
+    pub version: u16,
+    pub threshold: f64,
+    pub coverage_amount: u64,
+    pub duration_days: u32,
+    /// Premium quoted by the template's pricing curve
+    pub premium_amount: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRetired {
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+    pub plan: RetirementPlan,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleResigned {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    /// When `complete_oracle_resignation` may remove the oracle, `timestamp`
+    /// itself when the cooldown was skipped
+    pub retires_at: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleResignationCompleted {
+    pub oracle: Pubkey,
+    pub authority: Pubkey,
+    /// Rent and stake returned to the authority
+    pub refunded_lamports: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+/// Emitted by each `repoint_policies` batch, counting the accounts it changed
+#[event]
+pub struct PoliciesRepointed {
+    pub oracle: Pubkey,
+    /// Oracle policies were moved to, `None` while coverage is suspended
+    pub replacement: Option<Pubkey>,
+    pub policies_repointed: u32,
+    pub policies_suspended: u32,
+    pub templates_affected: u32,
+    /// Running totals over every batch for this oracle
+    pub total_policies_repointed: u32,
+    pub total_policies_suspended: u32,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyCoverageSuspended {
+    pub policy_id: u64,
+    /// Retired oracle the policy references
+    pub oracle: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyCoverageResumed {
+    pub policy_id: u64,
+    /// Oracle the policy was re-pointed to
+    pub oracle: Pubkey,
+    pub suspended_at: i64,
+    /// Length of the suspension, added to the policy's term
+    pub suspended_secs: i64,
+    pub end_date: i64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleReplacementSet {
+    pub oracle: Pubkey,
+    pub replacement: Pubkey,
+    pub admin: Pubkey,
+    /// Policies suspended so far, to be resumed by the crank
+    pub policies_suspended: u32,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleRetirementClosed {
+    pub oracle: Pubkey,
+    pub admin: Pubkey,
+    pub policies_repointed: u32,
+    pub policies_suspended: u32,
+    pub templates_affected: u32,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct UnderwriterUpdated {
+    pub admin: Pubkey,
+    pub old_underwriter: Pubkey,
+    pub new_underwriter: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct PolicyCoSigned {
+    pub policy_id: u64,
+    pub co_signer: Pubkey,
+    pub coverage_amount: u64,
+    pub currency: TokenType,
+    /// Lamports locked in the treasury's bond bucket
+    pub bond: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct UnderwritingBondReleased {
+    pub policy_id: u64,
+    pub co_signer: Pubkey,
+    pub bond: u64,
+    /// Part of the bond kept for claims beyond the loss-ratio bound, added to reserves
+    pub slashed: u64,
+    /// Premiums and claims of the policy, converted to lamports
+    pub premiums: u64,
+    pub claims: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleObservationSnapshotted {
+    /// Observation proof PDA holding the copy
+    pub proof: Pubkey,
+    pub oracle: Pubkey,
+    pub nonce: u64,
+    pub value: u64,
+    pub observed_at: i64,
+    pub reputation_score: u8,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct RegionOpened {
+    pub region_code: [u8; 8],
+    pub region_exposure: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct RegionExposureChanged {
+    pub region_code: [u8; 8],
+    pub policy_id: u64,
+    pub currency: TokenType,
+    /// Region's coverage in force in `currency` before and after the change
+    pub exposure_before: u64,
+    pub exposure_after: u64,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct AutoClaimUpdated {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    pub enabled: bool,
+    /// Keeper allowed to file, default for anyone
+    pub keeper: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleOutageObserved {
+    pub policy_id: u64,
+    pub oracle: Pubkey,
+    pub unavailable: bool,
+    /// Downtime this observation added, and the policy's total after it
+    pub outage_secs_added: u64,
+    pub oracle_outage_secs: u64,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OutageRefundClaimed {
+    pub policy_id: u64,
+    pub owner: Pubkey,
+    /// Downtime refunded against the policy's term
+    pub oracle_outage_secs: u64,
+    pub term_secs: i64,
+    pub threshold_bps: u16,
+    pub refund_amount: u64,
+    pub currency: TokenType,
+    pub treasury_balance_before: u64,
+    pub treasury_balance_after: u64,
+    pub reserve_ratio_after: u16,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleDirectoryInitialized {
+    pub oracle_directory: Pubkey,
+    pub admin: Pubkey,
+    /// Registered oracles copied into the directory
+    pub entry_count: u8,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct DataProviderRegistered {
+    pub data_provider: Pubkey,
+    pub provider_id: u32,
+    pub name: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct OracleProviderChanged {
+    pub oracle: Pubkey,
+    /// Provider the oracle was attributed to before, if any
+    pub previous_provider: Option<Pubkey>,
+    /// Provider the oracle is now attributed to, if any
+    pub provider: Option<Pubkey>,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    /// Authority taking over once it accepts
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    /// Governance program the authority is a PDA of, `None` for a wallet
+    pub governance_program: Option<Pubkey>,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+#[event]
+pub struct FeeRebateTiersUpdated {
+    pub admin: Pubkey,
+    pub tiers: Vec<FeeRebateTier>,
+    pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
+}
+
+#[event]
+pub struct FeeRebateClaimed {
+    pub underwriter: Pubkey,
+    pub epoch: u64,
+    /// Lamports of coverage the underwriter co-signed during the epoch
+    pub placed_coverage: u64,
+    /// Rate of the tier reached, applied to the epoch's late fees
+    pub rebate_bps: u16,
+    /// Lamports paid out of the fee vault
+    pub sol_rebate: u64,
+    /// USDC base units paid out of the fee vault
+    pub usdc_rebate: u64,
     pub timestamp: i64,
+    pub sequence: u64,
+    pub instruction: [u8; 8],
 }
\ No newline at end of file
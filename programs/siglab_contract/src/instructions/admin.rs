@@ -1,13 +1,60 @@
 use anchor_lang::prelude::*;
-use crate::state::{MasterInsuranceContract, Treasury};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{
+    AdminAction, AdminAuditEntry, AdminAuditLog, ConsensusFallbackMode, GlobalConfig, MasterInsuranceContract,
+    OracleRiskParams, Policy, RegionLimits, ReserveRatioBps, Treasury,
+};
 use crate::error::InsuranceError;
-use crate::events::{ContractPaused, ContractResumed, ReserveRatioUpdated, TreasuryWithdrawn};
+use crate::constants::{
+    DEFAULT_CONSENSUS_TIMEOUT, DEFAULT_MIN_ORACLE_REPUTATION, MAX_CONSENSUS_FALLBACK_AGE, MAX_CONSENSUS_TIMEOUT, MAX_ORACLES,
+    MAX_ORACLE_FEE_LAMPORTS, MAX_PARAM_TIMELOCK, POLICY_SEED, ADMIN_AUDIT_LOG_SEED,
+};
+use crate::utils::error_utils::{is_cpi, require_admin_invocation, require_top_level_invocation};
+use crate::utils::clock_utils::current_clock;
+use crate::events::{
+    instruction_discriminator, AuthorityTransferProposed, AuthorityTransferred, ConsensusFallbackUpdated, ConsensusRentCollectorUpdated,
+    ContractPaused, ContractResumed, LateFeeWaived, MinOracleReputationUpdated, OracleFeeUpdated,
+    ReserveRatioUpdated, TreasurySettled, TreasuryWithdrawn,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeParams {
-    pub reserve_ratio: u64,
+    /// Reserve ratio in basis points (1000-5000)
+    pub reserve_ratio_bps: u16,
+    /// Oracles the registry may hold, from the consensus threshold floor up
+    /// to `MAX_ORACLES`
     pub max_oracles: u8,
+    /// Fresh readings a consensus round needs, at least
+    /// `MIN_ORACLES_FOR_CONSENSUS` and at most `max_oracles`
     pub min_consensus_threshold: u8,
+    pub activation_window: i64,
+    /// Seconds a proposed parameter change waits before execution (0-30 days)
+    pub param_timelock_secs: i64,
+    /// Premium, coverage and duration limits; the program defaults when unset
+    pub global_config: Option<GlobalConfig>,
+    /// Let a single oracle reach consensus; for test deployments only
+    pub single_oracle_mode: bool,
+}
+
+impl InitializeParams {
+    /// Check the oracle limits against `MAX_ORACLES` and the consensus
+    /// threshold floor
+    pub fn validate_oracle_limits(&self) -> Result<()> {
+        let floor = MasterInsuranceContract::consensus_threshold_floor(self.single_oracle_mode);
+        require!(
+            (floor..=MAX_ORACLES as u8).contains(&self.max_oracles),
+            InsuranceError::InvalidMaxOracles
+        );
+        require!(
+            self.min_consensus_threshold >= floor,
+            InsuranceError::ConsensusThresholdTooLow
+        );
+        require!(
+            self.min_consensus_threshold <= self.max_oracles,
+            InsuranceError::ConsensusThresholdAboveMaxOracles
+        );
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -42,6 +89,20 @@ pub struct PauseContract<'info> {
     pub admin: Signer<'info>,
 }
 
+#[cfg(feature = "test-clock")]
+#[derive(Accounts)]
+pub struct SetTestClock<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct ResumeContract<'info> {
     #[account(
@@ -69,8 +130,7 @@ pub struct UpdateReserveRatio<'info> {
     
     #[account(
         mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
     )]
     pub treasury: Account<'info, Treasury>,
     
@@ -78,6 +138,91 @@ pub struct UpdateReserveRatio<'info> {
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateOracleFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetConsensusFallback<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMinOracleReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetConsensusRentCollector<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WaiveLateFee<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Admin audit log, opened by the first action it records
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminAuditLog::space(),
+        seeds = [ADMIN_AUDIT_LOG_SEED],
+        bump
+    )]
+    pub admin_audit_log: Account<'info, AdminAuditLog>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct WithdrawTreasury<'info> {
     #[account(
@@ -90,16 +235,61 @@ pub struct WithdrawTreasury<'info> {
     
     #[account(
         mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
     )]
     pub treasury: Account<'info, Treasury>,
     
     #[account(mut)]
     pub admin: Signer<'info>,
     
-    /// CHECK: Recipient account for withdrawal
+    /// CHECK: Recipient wallet; checked by `validate_withdrawal_recipient`
     pub recipient: AccountInfo<'info>,
+    
+    /// Recipient's USDC associated token account, required for USDC withdrawals
+    pub recipient_usdc_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct FinalSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized,
+        constraint = master_contract.winddown @ InsuranceError::WinddownNotStarted
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub admin: Signer<'info>,
+    
+    /// CHECK: Receives the remaining SOL
+    #[account(
+        mut,
+        constraint = recipient.key() != treasury.key() @ InsuranceError::InvalidRecipient
+    )]
+    pub recipient: AccountInfo<'info>,
+    
+    /// Treasury's USDC token account, emptied when given
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Receives the remaining USDC
+    #[account(
+        mut,
+        token::mint = treasury.usdc_mint
+    )]
+    pub recipient_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 #[derive(Accounts)]
@@ -115,77 +305,176 @@ pub struct TransferAuthority<'info> {
     #[account(mut)]
     pub current_admin: Signer<'info>,
     
-    /// CHECK: New admin account
+    /// CHECK: Nominated authority; it need not sign until it accepts, so a
+    /// governance PDA can be nominated
     pub new_admin: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.pending_authority == Some(new_admin.key()) @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Nominated authority; a governance PDA signs through `invoke_signed`
+    pub new_admin: Signer<'info>,
+    
+    /// CHECK: Governance program the new authority is a PDA of; omitted when
+    /// a wallet takes over
+    #[account(constraint = governance_program.executable @ InsuranceError::InvalidGovernanceProgram)]
+    pub governance_program: Option<UncheckedAccount<'info>>,
+}
+
 pub fn initialize_master_contract(
     ctx: Context<InitializeMasterContract>,
     params: InitializeParams,
 ) -> Result<()> {
-    let master_contract = &mut ctx.accounts.master_contract;
-    let clock = Clock::get()?;
-    
+    require_top_level_invocation()?;
+    
+    let timestamp = current_clock(&ctx.accounts.master_contract)?.unix_timestamp;
+    let reserve_ratio = setup_master_contract(
+        &mut ctx.accounts.master_contract,
+        ctx.accounts.admin.key(),
+        ctx.bumps.master_contract,
+        params,
+        timestamp,
+    )?;
+    
+    msg!("Master contract initialized with reserve ratio: {}", reserve_ratio);
+    Ok(())
+}
+
+/// Validate `params` and write a fresh master contract owned by `admin`
+pub(crate) fn setup_master_contract(
+    master_contract: &mut MasterInsuranceContract,
+    admin: Pubkey,
+    bump: u8,
+    params: InitializeParams,
+    timestamp: i64,
+) -> Result<ReserveRatioBps> {
     // Validate parameters
+    let reserve_ratio = ReserveRatioBps::new(params.reserve_ratio_bps).validate()?;
+    params.validate_oracle_limits()?;
+    require!(params.activation_window > 0, InsuranceError::InvalidInput);
     require!(
-        params.reserve_ratio >= 10 && params.reserve_ratio <= 50,
-        InsuranceError::InvalidInput
-    );
-    require!(
-        params.max_oracles >= 1 && params.max_oracles <= 10,
-        InsuranceError::InvalidInput
-    );
-    require!(
-        params.min_consensus_threshold >= 1 && params.min_consensus_threshold <= params.max_oracles,
+        (0..=MAX_PARAM_TIMELOCK).contains(&params.param_timelock_secs),
         InsuranceError::InvalidInput
     );
+    let global_config = params.global_config.unwrap_or_default();
+    global_config.validate()?;
     
     // Initialize master contract
-    master_contract.authority = ctx.accounts.admin.key();
+    master_contract.authority = admin;
     master_contract.policies = Vec::new();
     master_contract.treasury_account = Pubkey::default(); // Will be set when treasury is initialized
     master_contract.total_premiums_collected = 0;
     master_contract.total_payouts_disbursed = 0;
     master_contract.active_policies_count = 0;
-    master_contract.reserve_ratio = params.reserve_ratio;
+    master_contract.reserve_ratio_bps = reserve_ratio.bps();
     master_contract.is_paused = false;
-    master_contract.created_at = clock.unix_timestamp;
-    master_contract.updated_at = clock.unix_timestamp;
+    master_contract.created_at = timestamp;
+    master_contract.updated_at = timestamp;
     master_contract.oracle_registry = Vec::new();
     master_contract.max_oracles = params.max_oracles;
     master_contract.min_consensus_threshold = params.min_consensus_threshold;
-    master_contract.bump = ctx.bumps.master_contract;
+    master_contract.activation_window = params.activation_window;
+    master_contract.event_sequence = 0;
+    master_contract.bump = bump;
+    master_contract.oracle_fee_lamports = 0;
+    master_contract.consensus_round = 0;
+    master_contract.consensus_rent_collector = admin;
+    master_contract.consensus_fallback = ConsensusFallbackMode::HaltPayouts;
+    master_contract.consensus_timeout_secs = DEFAULT_CONSENSUS_TIMEOUT;
+    master_contract.crank_tip_lamports = 0;
+    master_contract.param_timelock_secs = params.param_timelock_secs;
+    master_contract.param_proposal_count = 0;
+    master_contract.global_config = global_config;
+    master_contract.min_oracle_reputation = DEFAULT_MIN_ORACLE_REPUTATION;
+    master_contract.open_payout_count = 0;
+    master_contract.portfolio_stats = Default::default();
+    master_contract.portfolio_report_count = 0;
+    master_contract.next_policy_id = 1;
+    master_contract.late_fees_collected = 0;
+    master_contract.policies_issued = 0;
+    master_contract.winddown = false;
+    master_contract.premiums_paused_at = None;
+    master_contract.premium_pauses = Vec::new();
+    master_contract.underwriter = Pubkey::default();
+    master_contract.oracle_risk_params = OracleRiskParams::default();
+    master_contract.region_limits = RegionLimits::default();
+    master_contract.pending_authority = None;
+    master_contract.governance_program = None;
+    master_contract.single_oracle_mode = params.single_oracle_mode;
+    #[cfg(feature = "test-clock")]
+    {
+        master_contract.clock_override = None;
+    }
+    
+    
+    Ok(reserve_ratio)
+}
+
+/// Pin the time every instruction sees to `timestamp`, or return to the clock
+/// sysvar with `None`
+#[cfg(feature = "test-clock")]
+pub fn set_test_clock(ctx: Context<SetTestClock>, timestamp: Option<i64>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    ctx.accounts.master_contract.clock_override = timestamp;
     
-    msg!("Master contract initialized with reserve ratio: {}%", params.reserve_ratio);
+    msg!("Test clock set to {:?}", timestamp);
     Ok(())
 }
 
-pub fn pause_contract(ctx: Context<PauseContract>) -> Result<()> {
+/// Pause the contract, leaving premium payments open unless `block_premiums`
+///
+/// Holders cannot fall behind while premiums are blocked: the time does not
+/// count towards late fees or installment arrears.
+pub fn pause_contract(ctx: Context<PauseContract>, block_premiums: bool) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
     master_contract.is_paused = true;
+    if block_premiums {
+        master_contract.premiums_paused_at = Some(clock.unix_timestamp);
+    }
     master_contract.updated_at = clock.unix_timestamp;
     
     emit!(ContractPaused {
         admin: ctx.accounts.admin.key(),
+        premiums_blocked: block_premiums,
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::PauseContract>(),
     });
     
     msg!("Contract paused by admin: {}", ctx.accounts.admin.key());
     Ok(())
 }
 
+/// Resume the contract, ending any block on premium payments
 pub fn resume_contract(ctx: Context<ResumeContract>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
     master_contract.is_paused = false;
+    let premium_blocked_secs = master_contract.end_premium_pause(clock.unix_timestamp);
     master_contract.updated_at = clock.unix_timestamp;
     
     emit!(ContractResumed {
         admin: ctx.accounts.admin.key(),
+        premium_blocked_secs,
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ResumeContract>(),
     });
     
     msg!("Contract resumed by admin: {}", ctx.accounts.admin.key());
@@ -194,45 +483,212 @@ pub fn resume_contract(ctx: Context<ResumeContract>) -> Result<()> {
 
 pub fn update_reserve_ratio(
     ctx: Context<UpdateReserveRatio>,
-    new_reserve_ratio: u64,
+    new_reserve_ratio_bps: u16,
 ) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
     let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
     // Validate new reserve ratio
-    require!(
-        new_reserve_ratio >= 10 && new_reserve_ratio <= 50,
-        InsuranceError::InvalidInput
-    );
+    let new_reserve_ratio = ReserveRatioBps::new(new_reserve_ratio_bps).validate()?;
     
     // Check that the new ratio doesn't violate current solvency
     let total_balance = treasury.total_usdc_balance + treasury.total_sol_balance;
     if treasury.total_coverage_exposure > 0 {
-        let required_reserves = (treasury.total_coverage_exposure * new_reserve_ratio) / 100;
+        let required_reserves = new_reserve_ratio.required_reserves(treasury.total_coverage_exposure);
         require!(
             total_balance >= required_reserves,
             InsuranceError::ReserveRatioViolation
         );
     }
     
-    let old_ratio = master_contract.reserve_ratio;
-    master_contract.reserve_ratio = new_reserve_ratio;
+    let old_ratio = master_contract.reserve_ratio();
+    master_contract.reserve_ratio_bps = new_reserve_ratio.bps();
     master_contract.updated_at = clock.unix_timestamp;
     
     // Update treasury minimum reserve ratio
-    treasury.minimum_reserve_ratio = (new_reserve_ratio * 100) as u16; // Convert to basis points
+    treasury.minimum_reserve_ratio = new_reserve_ratio.bps();
     treasury.current_reserve_ratio = treasury.calculate_reserve_ratio();
     treasury.last_update_timestamp = clock.unix_timestamp;
     
     emit!(ReserveRatioUpdated {
         admin: ctx.accounts.admin.key(),
-        old_ratio,
-        new_ratio: new_reserve_ratio,
+        old_ratio_bps: old_ratio.bps(),
+        new_ratio_bps: new_reserve_ratio.bps(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::UpdateReserveRatio>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::UpdateReserveRatio>(),
+    );
+    
+    msg!("Reserve ratio updated from {} to {}", old_ratio, new_reserve_ratio);
+    Ok(())
+}
+
+pub fn update_oracle_fee(ctx: Context<UpdateOracleFee>, new_fee_lamports: u64) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(
+        new_fee_lamports <= MAX_ORACLE_FEE_LAMPORTS,
+        InsuranceError::InvalidInput
+    );
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let old_fee_lamports = master_contract.oracle_fee_lamports;
+    master_contract.oracle_fee_lamports = new_fee_lamports;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(OracleFeeUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_fee_lamports,
+        new_fee_lamports,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::UpdateOracleFee>(),
+    });
+    
+    Ok(())
+}
+
+/// Set how consensus falls back once oracles exceed `timeout_secs` without a reading
+pub fn set_consensus_fallback(
+    ctx: Context<SetConsensusFallback>,
+    fallback: ConsensusFallbackMode,
+    timeout_secs: i64,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(
+        (1..=MAX_CONSENSUS_TIMEOUT).contains(&timeout_secs),
+        InsuranceError::InvalidInput
+    );
+    if let ConsensusFallbackMode::UseLastConsensus { max_age } = fallback {
+        require!(
+            (1..=MAX_CONSENSUS_FALLBACK_AGE).contains(&max_age),
+            InsuranceError::InvalidInput
+        );
+    }
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    master_contract.consensus_fallback = fallback;
+    master_contract.consensus_timeout_secs = timeout_secs;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(ConsensusFallbackUpdated {
+        admin: ctx.accounts.admin.key(),
+        fallback,
+        timeout_secs,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetConsensusFallback>(),
+    });
+    
+    Ok(())
+}
+
+/// Set the reputation below which oracles are left out of consensus
+pub fn set_min_oracle_reputation(
+    ctx: Context<SetMinOracleReputation>,
+    min_reputation: u8,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(min_reputation <= 100, InsuranceError::InvalidInput);
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let old_min_reputation = master_contract.min_oracle_reputation;
+    master_contract.min_oracle_reputation = min_reputation;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(MinOracleReputationUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_min_reputation,
+        new_min_reputation: min_reputation,
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetMinOracleReputation>(),
+    });
+    
+    Ok(())
+}
+
+/// Set the account receiving rent from closed consensus snapshots
+pub fn set_consensus_rent_collector(
+    ctx: Context<SetConsensusRentCollector>,
+    new_collector: Pubkey,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(new_collector != Pubkey::default(), InsuranceError::InvalidInput);
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let old_collector = master_contract.consensus_rent_collector;
+    master_contract.consensus_rent_collector = new_collector;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(ConsensusRentCollectorUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_collector,
+        new_collector,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetConsensusRentCollector>(),
+    });
+    
+    Ok(())
+}
+
+/// Waive the late fee accrued on a policy's overdue premium
+pub fn waive_late_fee(ctx: Context<WaiveLateFee>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let waived_amount = policy.late_fee(master_contract, clock.unix_timestamp);
+    require!(waived_amount > 0, InsuranceError::NoLateFeeDue);
+    let due_date = policy.premium_due_date().ok_or(InsuranceError::NoLateFeeDue)?;
+    
+    policy.late_fee_waived_due = Some(due_date);
+    policy.updated_at = clock.unix_timestamp;
+    
+    let audit_log = &mut ctx.accounts.admin_audit_log;
+    audit_log.bump = ctx.bumps.admin_audit_log;
+    audit_log.append(AdminAuditEntry {
+        action: AdminAction::LateFeeWaived,
+        admin: ctx.accounts.admin.key(),
+        target: policy.key(),
+        amount: waived_amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    emit!(LateFeeWaived {
+        policy_id: policy.id,
+        due_date,
+        waived_amount,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::WaiveLateFee>(),
     });
     
-    msg!("Reserve ratio updated from {}% to {}%", old_ratio, new_reserve_ratio);
     Ok(())
 }
 
@@ -241,9 +697,17 @@ pub fn withdraw_treasury(
     amount: u64,
     token_type: crate::state::TokenType,
 ) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    crate::instructions::treasury::validate_withdrawal_recipient(
+        &ctx.accounts.treasury,
+        &ctx.accounts.recipient,
+        ctx.accounts.recipient_usdc_account.as_ref(),
+        token_type,
+    )?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
     let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
     require!(amount > 0, InsuranceError::InvalidInput);
     
@@ -271,6 +735,7 @@ pub fn withdraw_treasury(
     );
     
     // Update treasury balances (in a full implementation, this would include actual transfers)
+    let treasury_balance_before = treasury.balance(token_type);
     match token_type {
         crate::state::TokenType::USDC => {
             treasury.total_usdc_balance -= amount;
@@ -288,7 +753,13 @@ pub fn withdraw_treasury(
     emit!(TreasuryWithdrawn {
         admin: ctx.accounts.admin.key(),
         amount,
+        token_type,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(token_type),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::WithdrawTreasury>(),
     });
     
     msg!("Treasury withdrawal: {} tokens by admin: {}", amount, ctx.accounts.admin.key());
@@ -298,14 +769,133 @@ pub fn withdraw_treasury(
 pub fn transfer_authority(
     ctx: Context<TransferAuthority>,
 ) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
-    let old_authority = master_contract.authority;
+    master_contract.pending_authority = Some(ctx.accounts.new_admin.key());
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(AuthorityTransferProposed {
+        authority: master_contract.authority,
+        pending_authority: ctx.accounts.new_admin.key(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::TransferAuthority>(),
+    });
+    
+    Ok(())
+}
+
+/// Take over the authority nominated by `transfer_authority`
+///
+/// A wallet accepts in a top-level instruction. A governance PDA accepts
+/// through its program's `invoke_signed`, naming that program; from then on
+/// admin instructions accept the PDA's signature via CPI.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let governance_program = ctx.accounts.governance_program.as_ref().map(|program| program.key());
+    if governance_program.is_some() {
+        require!(is_cpi(), InsuranceError::GovernanceInvocationRequired);
+    } else {
+        require_top_level_invocation()?;
+    }
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let previous_authority = master_contract.authority;
     master_contract.authority = ctx.accounts.new_admin.key();
+    master_contract.pending_authority = None;
+    master_contract.governance_program = governance_program;
     master_contract.updated_at = clock.unix_timestamp;
     
-    msg!("Authority transferred from {} to {}", old_authority, ctx.accounts.new_admin.key());
+    emit!(AuthorityTransferred {
+        previous_authority,
+        authority: master_contract.authority,
+        governance_program,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::AcceptAuthority>(),
+    });
+    
+    Ok(())
+}
+
+/// Withdraw everything left in the treasury once a wind-down has run off
+///
+/// Only available after `BeginWinddown` with no active policies left, so no
+/// reserve ratio applies: nothing remains for the reserves to back.
+pub fn final_settlement(ctx: Context<FinalSettlement>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(
+        ctx.accounts.master_contract.active_policies_count == 0,
+        InsuranceError::PoliciesStillActive
+    );
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    // Empty the USDC token account when its accounts are given
+    let usdc_amount = match (
+        ctx.accounts.treasury_usdc_account.as_ref(),
+        ctx.accounts.recipient_usdc_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        (Some(from), Some(to), Some(token_program)) => {
+            let amount = from.amount;
+            if amount > 0 {
+                crate::instructions::treasury::transfer_treasury_usdc(
+                    &ctx.accounts.treasury,
+                    from,
+                    &to.to_account_info(),
+                    token_program,
+                    amount,
+                )?;
+            }
+            Some(amount)
+        }
+        _ => None,
+    };
+    
+    // Move every lamport above the rent reserve and the underwriting bonds
+    // still owed to co-signers
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let sol_amount = treasury_info.lamports().saturating_sub(rent_reserve + treasury.underwriting_bonds);
+    **treasury_info.try_borrow_mut_lamports()? -= sol_amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += sol_amount;
+    
+    treasury.total_sol_balance = 0;
+    treasury.crank_budget_lamports = 0;
+    if usdc_amount.is_some() {
+        treasury.total_usdc_balance = 0;
+    }
+    treasury.withdrawal_count += 1;
+    treasury.current_reserve_ratio = treasury.calculate_reserve_ratio();
+    treasury.last_update_timestamp = clock.unix_timestamp;
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(TreasurySettled {
+        admin: ctx.accounts.admin.key(),
+        recipient: ctx.accounts.recipient.key(),
+        sol_amount,
+        usdc_amount: usdc_amount.unwrap_or(0),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::FinalSettlement>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::FinalSettlement>(),
+    );
+    
     Ok(())
 }
 
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use crate::state::{MasterInsuranceContract, Oracle, OracleDirectory, OracleType, SignatureScheme, Treasury};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, MAX_BOOTSTRAP_ORACLES, ORACLE_DIRECTORY_SEED, ORACLE_SEED, TREASURY_SEED};
+use crate::utils::error_utils::require_top_level_invocation;
+use crate::utils::clock_utils::now;
+use crate::instructions::admin::{setup_master_contract, InitializeParams};
+use crate::instructions::oracle::{add_to_registry, initialize_oracle};
+use crate::instructions::treasury::setup_treasury;
+
+/// An oracle registered and activated by `bootstrap_dev_environment`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BootstrapOracle {
+    pub oracle_id: String,
+    pub oracle_type: OracleType,
+    pub data_feed_address: String,
+    pub signature_scheme: SignatureScheme,
+    pub signer_address: Vec<u8>,
+    /// Signs the oracle's updates
+    pub authority: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BootstrapParams {
+    pub master_contract: InitializeParams,
+    /// Minimum reserve ratio of the treasury in basis points
+    pub minimum_reserve_ratio: u16,
+    /// At most `MAX_BOOTSTRAP_ORACLES`; their PDAs follow as remaining accounts, in order
+    pub oracles: Vec<BootstrapOracle>,
+}
+
+#[derive(Accounts)]
+pub struct BootstrapDevEnvironment<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = MasterInsuranceContract::space(),
+        seeds = [MASTER_CONTRACT_SEED],
+        bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::space(),
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = OracleDirectory::space(),
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the master contract, treasury and oracle directory and register the given
+/// oracles in one transaction. Development deployments only
+pub fn bootstrap_dev_environment<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BootstrapDevEnvironment<'info>>,
+    params: BootstrapParams,
+) -> Result<()> {
+    require_top_level_invocation()?;
+    require!(
+        params.oracles.len() <= MAX_BOOTSTRAP_ORACLES && params.oracles.len() == ctx.remaining_accounts.len(),
+        InsuranceError::InvalidInput
+    );
+    
+    let timestamp = now(&ctx.accounts.master_contract)?;
+    let admin = ctx.accounts.admin.key();
+    let treasury_key = ctx.accounts.treasury.key();
+    setup_master_contract(
+        &mut ctx.accounts.master_contract,
+        admin,
+        ctx.bumps.master_contract,
+        params.master_contract,
+        timestamp,
+    )?;
+    setup_treasury(
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.master_contract,
+        treasury_key,
+        admin,
+        ctx.bumps.treasury,
+        params.minimum_reserve_ratio,
+        timestamp,
+    )?;
+    
+    let mut directory = ctx.accounts.oracle_directory.load_init()?;
+    directory.bump = ctx.bumps.oracle_directory;
+    
+    let rent = Rent::get()?.minimum_balance(Oracle::space());
+    for (entry, oracle_info) in params.oracles.iter().zip(ctx.remaining_accounts) {
+        // Over-long ids cannot seed a PDA
+        require!(
+            entry.oracle_id.len() <= Oracle::MAX_ORACLE_ID_LENGTH,
+            InsuranceError::InvalidInput
+        );
+        let (oracle_key, bump) = Pubkey::find_program_address(&[ORACLE_SEED, entry.oracle_id.as_bytes()], &crate::ID);
+        require_keys_eq!(oracle_info.key(), oracle_key, InsuranceError::InvalidInput);
+        
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: oracle_info.clone(),
+                },
+                &[&[ORACLE_SEED, entry.oracle_id.as_bytes(), &[bump]]],
+            ),
+            rent,
+            Oracle::space() as u64,
+            &crate::ID,
+        )?;
+        
+        let loader = AccountLoader::<Oracle>::try_from_unchecked(&crate::ID, oracle_info)?;
+        {
+            let mut oracle = loader.load_init()?;
+            initialize_oracle(
+                &mut oracle,
+                &entry.oracle_id,
+                entry.oracle_type,
+                &entry.data_feed_address,
+                entry.signature_scheme,
+                &entry.signer_address,
+                entry.authority,
+                bump,
+            )?;
+            oracle.set_active(true);
+        }
+        // Writes the discriminator, as `init` would
+        loader.exit(&crate::ID)?;
+        
+        add_to_registry(&mut ctx.accounts.master_contract, oracle_key)?;
+        directory.record_registration(&entry.oracle_id, oracle_key, entry.authority)?;
+    }
+    
+    msg!("Bootstrapped development environment with {} oracles", params.oracles.len());
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::{DataProvider, MasterInsuranceContract, Oracle};
+use crate::error::InsuranceError;
+use crate::constants::{DATA_PROVIDER_SEED, MASTER_CONTRACT_SEED, ORACLE_SEED};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{instruction_discriminator, DataProviderRegistered, OracleProviderChanged};
+
+#[derive(Accounts)]
+#[instruction(provider_id: u32)]
+pub struct RegisterDataProvider<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = DataProvider::space(),
+        seeds = [DATA_PROVIDER_SEED, &provider_id.to_le_bytes()],
+        bump
+    )]
+    pub data_provider: Account<'info, DataProvider>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetOracleProvider<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_SEED, oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = !oracle.load()?.is_pending() @ InsuranceError::OraclePendingApproval
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    /// Provider the oracle relays; omit to clear its attribution
+    #[account(
+        seeds = [DATA_PROVIDER_SEED, &data_provider.provider_id.to_le_bytes()],
+        bump = data_provider.bump
+    )]
+    pub data_provider: Option<Account<'info, DataProvider>>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Register an upstream data provider oracles can be attributed to
+///
+/// The provider id is part of the PDA seeds, so each id is registered once.
+pub fn register_data_provider(ctx: Context<RegisterDataProvider>, provider_id: u32, name: String) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    DataProvider::validate_name(&name)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let data_provider = &mut ctx.accounts.data_provider;
+    data_provider.provider_id = provider_id;
+    data_provider.name = name.clone();
+    data_provider.created_at = current_time;
+    data_provider.bump = ctx.bumps.data_provider;
+
+    emit!(DataProviderRegistered {
+        data_provider: data_provider.key(),
+        provider_id,
+        name,
+        admin: ctx.accounts.admin.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RegisterDataProvider>(),
+    });
+
+    Ok(())
+}
+
+/// Attribute a registered oracle to a data provider, or clear its
+/// attribution when no provider is passed
+///
+/// Consensus counts the oracles of one provider once toward its quorum.
+pub fn set_oracle_provider(ctx: Context<SetOracleProvider>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    require!(
+        master_contract.oracle_registry.contains(&ctx.accounts.oracle.key()),
+        InsuranceError::OracleNotRegistered
+    );
+    let current_time = now(master_contract)?;
+
+    let provider = ctx.accounts.data_provider.as_ref().map(|data_provider| data_provider.key());
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    let previous_provider = oracle.provider();
+    oracle.set_provider(provider);
+
+    emit!(OracleProviderChanged {
+        oracle: ctx.accounts.oracle.key(),
+        previous_provider,
+        provider,
+        admin: ctx.accounts.admin.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetOracleProvider>(),
+    });
+
+    Ok(())
+}
\ No newline at end of file
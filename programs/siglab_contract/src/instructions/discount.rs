@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::{DiscountCode, MasterInsuranceContract};
+use crate::error::InsuranceError;
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::current_clock;
+use crate::events::{instruction_discriminator, DiscountCodeCreated, DiscountCodeRedeemed};
+use crate::constants::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateDiscountCodeParams {
+    pub code_hash: [u8; 32],
+    pub discount_bps: u16,
+    pub max_redemptions: u32,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateDiscountCodeParams)]
+pub struct CreateDiscountCode<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = DiscountCode::space(),
+        seeds = [DISCOUNT_CODE_SEED, params.code_hash.as_ref()],
+        bump
+    )]
+    pub discount_code: Account<'info, DiscountCode>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_discount_code(
+    ctx: Context<CreateDiscountCode>,
+    params: CreateDiscountCodeParams,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let discount_code = &mut ctx.accounts.discount_code;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+
+    // Validate parameters
+    require!(
+        params.discount_bps > 0 && params.discount_bps <= DiscountCode::MAX_DISCOUNT_BPS,
+        InsuranceError::InvalidInput
+    );
+    require!(params.max_redemptions > 0, InsuranceError::InvalidInput);
+    require!(
+        params.expires_at > clock.unix_timestamp,
+        InsuranceError::InvalidInput
+    );
+
+    discount_code.code_hash = params.code_hash;
+    discount_code.authority = ctx.accounts.admin.key();
+    discount_code.discount_bps = params.discount_bps;
+    discount_code.max_redemptions = params.max_redemptions;
+    discount_code.remaining_redemptions = params.max_redemptions;
+    discount_code.expires_at = params.expires_at;
+    discount_code.created_at = clock.unix_timestamp;
+    discount_code.bump = ctx.bumps.discount_code;
+
+    emit!(DiscountCodeCreated {
+        code_hash: params.code_hash,
+        admin: ctx.accounts.admin.key(),
+        discount_bps: params.discount_bps,
+        max_redemptions: params.max_redemptions,
+        expires_at: params.expires_at,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CreateDiscountCode>(),
+    });
+
+    Ok(())
+}
+
+/// Verify a discount code preimage and consume one redemption.
+/// Returns the discounted premium amount.
+pub fn redeem_discount_code(
+    discount_code: &mut Account<DiscountCode>,
+    preimage: &str,
+    holder: &Pubkey,
+    policy_id: u64,
+    premium_amount: u64,
+    current_timestamp: i64,
+    sequence: u64,
+) -> Result<u64> {
+    // Verify the preimage matches the stored hash
+    require!(
+        hash(preimage.as_bytes()).to_bytes() == discount_code.code_hash,
+        InsuranceError::InvalidDiscountCode
+    );
+
+    require!(
+        !discount_code.is_expired(current_timestamp),
+        InsuranceError::DiscountCodeExpired
+    );
+
+    require!(
+        discount_code.remaining_redemptions > 0,
+        InsuranceError::DiscountCodeExhausted
+    );
+
+    let discounted_premium = discount_code.apply_discount(premium_amount)?;
+    discount_code.remaining_redemptions -= 1;
+
+    emit!(DiscountCodeRedeemed {
+        code_hash: discount_code.code_hash,
+        holder: *holder,
+        policy_id,
+        original_premium: premium_amount,
+        discounted_premium,
+        remaining_redemptions: discount_code.remaining_redemptions,
+        timestamp: current_timestamp,
+        sequence,
+        instruction: instruction_discriminator::<crate::instruction::CreatePolicy>(),
+    });
+
+    Ok(discounted_premium)
+}
@@ -1,11 +1,47 @@
 pub mod admin;
+#[cfg(feature = "devnet")]
+pub mod bootstrap;
+pub mod data_provider;
+pub mod discount;
+pub mod observation_proof;
 pub mod oracle;
+pub mod oracle_directory;
+pub mod oracle_outage;
+pub mod oracle_retirement;
+pub mod params;
 pub mod payout;
 pub mod policy;
+pub mod policy_token;
+pub mod portfolio;
+pub mod product_template;
+pub mod policy_transfer;
+pub mod region;
+pub mod subsidy;
 pub mod treasury;
+pub mod trigger_amendment;
+pub mod underwriting;
+pub mod view;
 
 pub use admin::*;
+#[cfg(feature = "devnet")]
+pub use bootstrap::*;
+pub use data_provider::*;
+pub use discount::*;
+pub use observation_proof::*;
 pub use oracle::*;
+pub use oracle_directory::*;
+pub use oracle_outage::*;
+pub use oracle_retirement::*;
+pub use params::*;
 pub use payout::*;
 pub use policy::*;
-pub use treasury::*;
\ No newline at end of file
+pub use policy_token::*;
+pub use portfolio::*;
+pub use product_template::*;
+pub use policy_transfer::*;
+pub use region::*;
+pub use subsidy::*;
+pub use treasury::*;
+pub use trigger_amendment::*;
+pub use underwriting::*;
+pub use view::*;
\ No newline at end of file
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::{MasterInsuranceContract, ObservationProof, Oracle};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, OBSERVATION_PROOF_RETENTION, OBSERVATION_PROOF_SEED, ORACLE_SEED};
+use crate::utils::clock_utils::now;
+use crate::events::{instruction_discriminator, OracleObservationSnapshotted};
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct SnapshotOracleObservation<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = ObservationProof::space(),
+        seeds = [OBSERVATION_PROOF_SEED, oracle.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub observation_proof: Account<'info, ObservationProof>,
+
+    #[account(
+        seeds = [ORACLE_SEED, oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Anyone may take a proof, paying its rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseObservationProof<'info> {
+    #[account(
+        mut,
+        close = creator,
+        has_one = creator @ InsuranceError::Unauthorized,
+        seeds = [
+            OBSERVATION_PROOF_SEED,
+            observation_proof.oracle.as_ref(),
+            &observation_proof.nonce.to_le_bytes(),
+        ],
+        bump = observation_proof.bump,
+        constraint = now(&master_contract)? >= observation_proof.created_at + OBSERVATION_PROOF_RETENTION
+            @ InsuranceError::ObservationProofRetained
+    )]
+    pub observation_proof: Account<'info, ObservationProof>,
+
+    #[account(
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// Copy an oracle reading into a proof seeded by the oracle and `nonce`,
+/// with the oracle's reputation and health at this moment
+///
+/// `observed_at` picks the retained reading reported at that time; without
+/// it the latest reading is copied.
+pub fn snapshot_oracle_observation(
+    ctx: Context<SnapshotOracleObservation>,
+    nonce: u64,
+    observed_at: Option<i64>,
+) -> Result<()> {
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    let oracle = ctx.accounts.oracle.load()?;
+    let observation = match observed_at {
+        Some(observed_at) => oracle.history().find(|observation| observation.timestamp == observed_at).copied(),
+        None => oracle.latest_data(),
+    }
+    .ok_or(InsuranceError::ObservationNotFound)?;
+
+    let proof = &mut ctx.accounts.observation_proof;
+    proof.oracle = ctx.accounts.oracle.key();
+    proof.nonce = nonce;
+    proof.value = observation.value;
+    proof.observed_at = observation.timestamp;
+    proof.confidence = observation.confidence;
+    proof.reputation_score = oracle.reputation_score;
+    proof.accuracy_score = oracle.health_metrics.accuracy_score;
+    proof.failed_validations = oracle.health_metrics.failed_validations;
+    proof.updates_last_24h = oracle.health_metrics.updates_in_last_24h(current_time);
+    proof.circuit_breaker = oracle.health_metrics.circuit_breaker_active();
+    proof.creator = ctx.accounts.creator.key();
+    proof.created_at = current_time;
+    proof.bump = ctx.bumps.observation_proof;
+
+    emit!(OracleObservationSnapshotted {
+        proof: proof.key(),
+        oracle: proof.oracle,
+        nonce,
+        value: proof.value,
+        observed_at: proof.observed_at,
+        reputation_score: proof.reputation_score,
+        creator: proof.creator,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SnapshotOracleObservation>(),
+    });
+
+    Ok(())
+}
+
+/// Return a proof's rent to its creator once the retention period is over
+pub fn close_observation_proof(_ctx: Context<CloseObservationProof>) -> Result<()> {
+    Ok(())
+}
\ No newline at end of file
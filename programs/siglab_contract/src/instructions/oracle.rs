@@ -1,7 +1,30 @@
 use anchor_lang::prelude::*;
-use crate::state::{Oracle, OracleData, OracleType, MasterInsuranceContract, ConsensusData};
+use crate::events::{
+    instruction_discriminator, ConsensusComputed, ConsensusDegraded, ConsensusDispersionExceeded,
+    ConsensusDisputeResolved, ConsensusDisputed, ConsensusSnapshotClosed, OracleExcludedFromConsensus,
+    OracleFeesClaimed, OracleDataCommitted, OracleDataRevealed, OraclePaused, OracleRegistrationApproved,
+    OracleResumed, OracleRegistrationDenied, OracleRegistrationRequested, OracleRegistrySynced, OracleResigned,
+    OracleResignationCompleted, OracleRetired,
+};
+use crate::state::{
+    Oracle, OracleData, OracleDirectory, OracleExclusionReason, OracleObservation, OracleType, MasterInsuranceContract, ConsensusData,
+    ConsensusFallbackMode, ConsensusRoundStatus, ConsensusSnapshot, OracleRetirement, RetirementPlan, SignatureScheme,
+    Treasury, OracleRiskParams,
+};
 use crate::error::InsuranceError;
-use anchor_lang::solana_program::ed25519_program;
+use crate::constants::{
+    CONSENSUS_DISPUTE_WINDOW, CONSENSUS_SEED, CONSENSUS_SNAPSHOT_RETENTION, MASTER_CONTRACT_SEED,
+    MAX_DENIAL_REASON_LENGTH, MAX_ORACLES, MAX_ORACLE_SELF_PAUSE, MAX_ORACLE_UPDATE_INTERVAL, MIN_REVEAL_DELAY_SLOTS,
+    ORACLE_DIRECTORY_SEED, ORACLE_MESSAGE_DOMAIN, ORACLE_REGISTRATION_STAKE, ORACLE_RETIREMENT_SEED, ORACLE_UPDATE_INTERVAL,
+};
+use crate::utils::error_utils::{require_admin_invocation, require_top_level_invocation};
+use crate::utils::clock_utils::current_clock;
+use siglab_core::versioning::ORACLE_DATA_V1;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::{ed25519_program, secp256k1_program};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
 
 #[derive(Accounts)]
 #[instruction(oracle_id: String)]
@@ -13,7 +36,7 @@ pub struct RegisterOracle<'info> {
         seeds = [b"oracle", oracle_id.as_bytes()],
         bump
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub oracle: AccountLoader<'info, Oracle>,
     
     #[account(
         mut,
@@ -21,6 +44,13 @@ pub struct RegisterOracle<'info> {
     )]
     pub master_contract: Account<'info, MasterInsuranceContract>,
     
+    #[account(
+        mut,
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
     
@@ -29,15 +59,98 @@ pub struct RegisterOracle<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(oracle_id: String)]
+pub struct RequestOracleRegistration<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = Oracle::space(),
+        seeds = [b"oracle", oracle_id.as_bytes()],
+        bump
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Becomes the oracle authority; pays rent and the registration stake
+    #[account(mut)]
+    pub operator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.is_pending() @ InsuranceError::OracleNotPending
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DenyOracle<'info> {
+    #[account(
+        mut,
+        close = oracle_authority,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.is_pending() @ InsuranceError::OracleNotPending
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Operator that requested the registration, refunded rent and stake
+    #[account(
+        mut,
+        constraint = oracle_authority.key() == oracle.load()?.authority @ InsuranceError::Unauthorized
+    )]
+    pub oracle_authority: SystemAccount<'info>,
+    
+    pub admin: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct UnregisterOracle<'info> {
     #[account(
         mut,
         close = admin,
-        seeds = [b"oracle", oracle.oracle_id.as_bytes()],
-        bump = oracle.bump
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub oracle: AccountLoader<'info, Oracle>,
     
     #[account(
         mut,
@@ -45,32 +158,367 @@ pub struct UnregisterOracle<'info> {
     )]
     pub master_contract: Account<'info, MasterInsuranceContract>,
     
+    /// Record of the retirement, read by the `repoint_policies` crank
+    #[account(
+        init,
+        payer = admin,
+        space = OracleRetirement::space(),
+        seeds = [ORACLE_RETIREMENT_SEED, oracle.key().as_ref()],
+        bump
+    )]
+    pub oracle_retirement: Account<'info, OracleRetirement>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResignOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = !oracle.load()?.is_pending() @ InsuranceError::OraclePendingApproval,
+        constraint = !oracle.load()?.is_resigning() @ InsuranceError::OracleResigning
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    pub oracle_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteOracleResignation<'info> {
+    #[account(
+        mut,
+        close = oracle_authority,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_resigning() @ InsuranceError::OracleNotResigning
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Record of the retirement, read by the `repoint_policies` crank
+    #[account(
+        init,
+        payer = oracle_authority,
+        space = OracleRetirement::space(),
+        seeds = [ORACLE_RETIREMENT_SEED, oracle.key().as_ref()],
+        bump
+    )]
+    pub oracle_retirement: Account<'info, OracleRetirement>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
+    /// Operator leaving the registry, refunded rent and stake
+    #[account(mut)]
+    pub oracle_authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncOracleRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+    
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateOracleData<'info> {
     #[account(
         mut,
-        seeds = [b"oracle", oracle.oracle_id.as_bytes()],
-        bump = oracle.bump,
-        constraint = oracle.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
-        constraint = oracle.is_active @ InsuranceError::OracleInactive
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_active() @ InsuranceError::OracleInactive
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    pub oracle_authority: Signer<'info>,
+    
+    /// Sequences the resume event when the update ends a maintenance pause
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// CHECK: Instructions sysvar, read to find the ed25519 or secp256k1 attestation
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CommitOracleData<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_active() @ InsuranceError::OracleInactive
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    pub oracle_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimOracleFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_active() || oracle.load()?.is_resigning() @ InsuranceError::OracleInactive
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.treasury_account != Pubkey::default() @ InsuranceError::TreasuryNotLinked
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury the fees are paid from
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub treasury: Account<'info, Treasury>,
     
+    #[account(mut)]
     pub oracle_authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ComputeConsensus<'info> {
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = ConsensusSnapshot::space(),
+        seeds = [CONSENSUS_SEED, &master_contract.consensus_round.to_le_bytes()],
+        bump
+    )]
+    pub consensus_snapshot: Account<'info, ConsensusSnapshot>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseConsensusSnapshot<'info> {
+    #[account(
+        mut,
+        close = rent_collector,
+        seeds = [CONSENSUS_SEED, &consensus_snapshot.round.to_le_bytes()],
+        bump = consensus_snapshot.bump
+    )]
+    pub consensus_snapshot: Account<'info, ConsensusSnapshot>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// CHECK: Receives the snapshot rent; validated against the configured collector
+    #[account(
+        mut,
+        address = master_contract.consensus_rent_collector @ InsuranceError::Unauthorized
+    )]
+    pub rent_collector: AccountInfo<'info>,
+    
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(round: u64)]
+pub struct DisputeConsensus<'info> {
+    #[account(
+        mut,
+        seeds = [CONSENSUS_SEED, &round.to_le_bytes()],
+        bump = consensus_snapshot.bump
+    )]
+    pub consensus_snapshot: Account<'info, ConsensusSnapshot>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.oracle_registry.contains(&oracle.key()) @ InsuranceError::OracleNotRegistered
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Registered oracle run by the disputing authority
+    #[account(
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_active() @ InsuranceError::OracleInactive
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    pub oracle_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(round: u64)]
+pub struct ResolveConsensusDispute<'info> {
+    #[account(
+        mut,
+        seeds = [CONSENSUS_SEED, &round.to_le_bytes()],
+        bump = consensus_snapshot.bump
+    )]
+    pub consensus_snapshot: Account<'info, ConsensusSnapshot>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Oracle on the side that deviated most from the resolved value
+    #[account(
+        mut,
+        seeds = [b"oracle", slashed_oracle.load()?.oracle_id().as_bytes()],
+        bump = slashed_oracle.load()?.bump
+    )]
+    pub slashed_oracle: AccountLoader<'info, Oracle>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetOracleUpdateInterval<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = !oracle.load()?.is_pending() @ InsuranceError::OraclePendingApproval
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetOracleCommitReveal<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = !oracle.load()?.is_pending() @ InsuranceError::OraclePendingApproval
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    #[account(
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PauseOracleSelf<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = oracle.load()?.authority == oracle_authority.key() @ InsuranceError::Unauthorized,
+        constraint = oracle.load()?.is_active() @ InsuranceError::OracleInactive
+    )]
+    pub oracle: AccountLoader<'info, Oracle>,
+    
+    pub oracle_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateOracleStatus<'info> {
     #[account(
         mut,
-        seeds = [b"oracle", oracle.oracle_id.as_bytes()],
-        bump = oracle.bump
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump,
+        constraint = !oracle.load()?.is_pending() @ InsuranceError::OraclePendingApproval
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub oracle: AccountLoader<'info, Oracle>,
     
     #[account(
         constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
@@ -80,15 +528,196 @@ pub struct UpdateOracleStatus<'info> {
     pub admin: Signer<'info>,
 }
 
+/// Register an active oracle run by `oracle_authority`
+///
+/// Every oracle already in the registry is passed as a remaining account and
+/// must still exist; registry entries whose accounts are gone are pruned
+/// with `sync_oracle_registry` first.
 pub fn register_oracle(
     ctx: Context<RegisterOracle>,
     oracle_id: String,
     oracle_type: OracleType,
     data_feed_address: String,
+    signature_scheme: SignatureScheme,
+    signer_address: Vec<u8>,
 ) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle;
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    require_live_registry(&ctx.accounts.master_contract, ctx.remaining_accounts)?;
+    
+    let oracle_key = ctx.accounts.oracle.key();
+    let mut oracle = ctx.accounts.oracle.load_init()?;
+    
+    initialize_oracle(
+        &mut oracle,
+        &oracle_id,
+        oracle_type,
+        &data_feed_address,
+        signature_scheme,
+        &signer_address,
+        ctx.accounts.oracle_authority.key(),
+        ctx.bumps.oracle,
+    )?;
+    oracle.set_active(true);
+    
+    add_to_registry(&mut ctx.accounts.master_contract, oracle_key)?;
+    ctx.accounts.oracle_directory.load_mut()?.record_registration(
+        &oracle_id,
+        oracle_key,
+        ctx.accounts.oracle_authority.key(),
+    )
+}
+
+/// Request registration of an operator-run oracle
+///
+/// The operator becomes the oracle authority and bonds
+/// `ORACLE_REGISTRATION_STAKE` in the oracle account. The oracle stays
+/// inactive and outside the registry until the admin approves it.
+pub fn request_oracle_registration(
+    ctx: Context<RequestOracleRegistration>,
+    oracle_id: String,
+    oracle_type: OracleType,
+    data_feed_address: String,
+    signature_scheme: SignatureScheme,
+    signer_address: Vec<u8>,
+) -> Result<()> {
+    require_top_level_invocation()?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.operator.to_account_info(),
+                to: ctx.accounts.oracle.to_account_info(),
+            },
+        ),
+        ORACLE_REGISTRATION_STAKE,
+    )?;
+    
+    let mut oracle = ctx.accounts.oracle.load_init()?;
+    initialize_oracle(
+        &mut oracle,
+        &oracle_id,
+        oracle_type,
+        &data_feed_address,
+        signature_scheme,
+        &signer_address,
+        ctx.accounts.operator.key(),
+        ctx.bumps.oracle,
+    )?;
+    oracle.set_pending(true);
+    oracle.stake_lamports = ORACLE_REGISTRATION_STAKE;
+    
+    emit!(OracleRegistrationRequested {
+        oracle: ctx.accounts.oracle.key(),
+        oracle_id,
+        authority: ctx.accounts.operator.key(),
+        stake_lamports: ORACLE_REGISTRATION_STAKE,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RequestOracleRegistration>(),
+    });
+    
+    Ok(())
+}
+
+/// Activate a pending oracle and add it to the registry (admin only)
+pub fn approve_oracle(ctx: Context<ApproveOracle>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let oracle_key = ctx.accounts.oracle.key();
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
     let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    oracle.set_pending(false);
+    oracle.set_active(true);
+    add_to_registry(master_contract, oracle_key)?;
+    ctx.accounts.oracle_directory.load_mut()?.record_registration(
+        oracle.oracle_id(),
+        oracle_key,
+        oracle.authority,
+    )?;
+    
+    emit!(OracleRegistrationApproved {
+        oracle: oracle_key,
+        admin: ctx.accounts.admin.key(),
+        registered_count: master_contract.oracle_registry.len() as u8,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ApproveOracle>(),
+    });
+    
+    Ok(())
+}
+
+/// Reject a pending oracle, closing it and refunding rent and stake to its
+/// operator (admin only)
+pub fn deny_oracle(ctx: Context<DenyOracle>, reason: String) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(
+        reason.len() <= MAX_DENIAL_REASON_LENGTH,
+        InsuranceError::InvalidInput
+    );
     
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    emit!(OracleRegistrationDenied {
+        oracle: ctx.accounts.oracle.key(),
+        authority: ctx.accounts.oracle_authority.key(),
+        admin: ctx.accounts.admin.key(),
+        reason,
+        refunded_lamports: ctx.accounts.oracle.to_account_info().lamports(),
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::DenyOracle>(),
+    });
+    
+    // Oracle account will be closed automatically due to close constraint
+    
+    Ok(())
+}
+
+/// Add an oracle to the master contract's registry
+pub(crate) fn add_to_registry(master_contract: &mut MasterInsuranceContract, oracle_key: Pubkey) -> Result<()> {
+    // Check if we haven't exceeded max oracles
+    require!(
+        master_contract.has_oracle_capacity(),
+        InsuranceError::MaxOraclesExceeded
+    );
+    
+    // Check for duplicate oracle in registry. Oracle addresses are derived
+    // from `oracle_id`, so this rejects a reused id while still allowing one
+    // authority to run several oracles under different ids.
+    require!(
+        !master_contract.oracle_registry.contains(&oracle_key),
+        InsuranceError::OracleAlreadyRegistered
+    );
+    
+    master_contract.oracle_registry.push(oracle_key);
+    
+    // The check above and the push share one instruction, and registrations
+    // serialize on the master contract, so this only guards later changes
+    require!(
+        master_contract.oracle_registry.len() <= master_contract.oracle_capacity(),
+        InsuranceError::MaxOraclesExceeded
+    );
+    Ok(())
+}
+
+/// Validate registration arguments and initialize an inactive oracle;
+/// the zeroed layout already holds an empty history
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_oracle(
+    oracle: &mut Oracle,
+    oracle_id: &str,
+    oracle_type: OracleType,
+    data_feed_address: &str,
+    signature_scheme: SignatureScheme,
+    signer_address: &[u8],
+    authority: Pubkey,
+    bump: u8,
+) -> Result<()> {
     // Validate oracle_id length
     require!(
         oracle_id.len() <= Oracle::MAX_ORACLE_ID_LENGTH,
@@ -101,16 +730,10 @@ pub fn register_oracle(
         InsuranceError::InvalidInput
     );
     
-    // Check if we haven't exceeded max oracles
-    require!(
-        master_contract.oracle_registry.len() < master_contract.max_oracles as usize,
-        InsuranceError::MaxOraclesExceeded
-    );
-    
-    // Check for duplicate oracle in registry
+    // Ed25519 oracles sign with their authority; secp256k1 oracles need an Ethereum address
     require!(
-        !master_contract.oracle_registry.contains(&oracle.key()),
-        InsuranceError::OracleAlreadyRegistered
+        signer_address.len() == signature_scheme.signer_address_length(),
+        InsuranceError::InvalidSignerAddress
     );
     
     // Ensure only Pyth oracle type is supported
@@ -119,127 +742,584 @@ pub fn register_oracle(
         InsuranceError::InvalidOracleData
     );
     
-    // Initialize oracle account
-    oracle.oracle_id = oracle_id;
-    oracle.authority = ctx.accounts.oracle_authority.key();
-    oracle.oracle_type = oracle_type;
-    oracle.is_active = true;
+    oracle.set_oracle_id(oracle_id);
+    oracle.authority = authority;
+    oracle.oracle_type = oracle_type as u8;
     oracle.last_update_timestamp = 0;
-    oracle.data_feed_address = data_feed_address;
-    oracle.latest_data = None;
+    oracle.min_update_interval_secs = ORACLE_UPDATE_INTERVAL;
+    oracle.set_data_feed_address(data_feed_address);
     oracle.reputation_score = 100; // Start with perfect score
     oracle.update_count = 0;
     oracle.health_metrics = crate::state::OracleHealthMetrics::new();
-    oracle.bump = ctx.bumps.oracle;
+    oracle.bump = bump;
+    oracle.signature_scheme = signature_scheme as u8;
+    oracle.eth_address[..signer_address.len()].copy_from_slice(signer_address);
+    
+    Ok(())
+}
+
+/// Unregister an oracle, deciding what becomes of the policies and
+/// templates that reference it
+///
+/// With `RetirementPlan::Replace` the `repoint_policies` crank moves them to
+/// the replacement, which must be registered; with
+/// `RetirementPlan::SuspendCoverage` it suspends the policies and deactivates
+/// the templates until `set_oracle_replacement` names one.
+pub fn unregister_oracle(ctx: Context<UnregisterOracle>, plan: RetirementPlan) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let oracle_key = ctx.accounts.oracle.key();
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    remove_from_registry(master_contract, &ctx.accounts.oracle_directory, oracle_key)?;
+    
+    if let RetirementPlan::Replace(replacement) = plan {
+        require!(
+            master_contract.oracle_registry.contains(&replacement),
+            InsuranceError::OracleNotRegistered
+        );
+    }
+    
+    open_retirement(
+        &mut ctx.accounts.oracle_retirement,
+        oracle_key,
+        plan,
+        clock.unix_timestamp,
+        ctx.bumps.oracle_retirement,
+    );
+    
+    emit!(OracleRetired {
+        oracle: oracle_key,
+        admin: ctx.accounts.admin.key(),
+        plan,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::UnregisterOracle>(),
+    });
+    
+    // Oracle account will be closed automatically due to close constraint
     
-    // Add to master contract oracle registry
-    master_contract.oracle_registry.push(oracle.key());
+    Ok(())
+}
+
+/// Resign an oracle (oracle authority only)
+///
+/// The oracle leaves consensus at once and `complete_oracle_resignation`
+/// removes it after `ORACLE_RESIGNATION_COOLDOWN`, so claims resting on its
+/// readings can settle first; the cooldown is skipped when it has not
+/// reported within that long. Accrued fees stay claimable meanwhile. The
+/// admin may still remove it at any time with `unregister_oracle`.
+pub fn resign_oracle(ctx: Context<ResignOracle>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.set_active(false);
+    oracle.retires_at = oracle.resignation_effective_at(clock.unix_timestamp);
+    
+    emit!(OracleResigned {
+        oracle: ctx.accounts.oracle.key(),
+        authority: oracle.authority,
+        retires_at: oracle.retires_at,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ResignOracle>(),
+    });
     
     Ok(())
 }
 
-pub fn unregister_oracle(ctx: Context<UnregisterOracle>) -> Result<()> {
-    let oracle = &ctx.accounts.oracle;
+/// Remove a resigned oracle once its cooldown has elapsed, closing it and
+/// refunding rent and stake to its authority
+///
+/// Policies and templates that still reference it have their coverage
+/// suspended by the `repoint_policies` crank until the admin names a
+/// replacement with `set_oracle_replacement`.
+pub fn complete_oracle_resignation(ctx: Context<CompleteOracleResignation>) -> Result<()> {
+    let oracle_key = ctx.accounts.oracle.key();
     let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    require!(
+        clock.unix_timestamp >= ctx.accounts.oracle.load()?.retires_at,
+        InsuranceError::OracleResignationCooldown
+    );
+    
+    remove_from_registry(master_contract, &ctx.accounts.oracle_directory, oracle_key)?;
+    open_retirement(
+        &mut ctx.accounts.oracle_retirement,
+        oracle_key,
+        RetirementPlan::SuspendCoverage,
+        clock.unix_timestamp,
+        ctx.bumps.oracle_retirement,
+    );
     
-    // Remove oracle from registry
-    master_contract.oracle_registry.retain(|&x| x != oracle.key());
+    emit!(OracleResignationCompleted {
+        oracle: oracle_key,
+        authority: ctx.accounts.oracle_authority.key(),
+        refunded_lamports: ctx.accounts.oracle.to_account_info().lamports(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CompleteOracleResignation>(),
+    });
     
     // Oracle account will be closed automatically due to close constraint
     
     Ok(())
 }
 
+/// Remove an oracle from the master contract's registry and the directory
+fn remove_from_registry(
+    master_contract: &mut MasterInsuranceContract,
+    oracle_directory: &AccountLoader<OracleDirectory>,
+    oracle_key: Pubkey,
+) -> Result<()> {
+    let position = master_contract
+        .oracle_registry
+        .iter()
+        .position(|&x| x == oracle_key)
+        .ok_or(InsuranceError::OracleNotRegistered)?;
+    master_contract.oracle_registry.remove(position);
+    oracle_directory.load_mut()?.record_unregistration(&oracle_key);
+    Ok(())
+}
+
+/// Start the record the `repoint_policies` crank works through
+fn open_retirement(
+    oracle_retirement: &mut OracleRetirement,
+    oracle_key: Pubkey,
+    plan: RetirementPlan,
+    retired_at: i64,
+    bump: u8,
+) {
+    oracle_retirement.oracle = oracle_key;
+    oracle_retirement.plan = plan;
+    oracle_retirement.retired_at = retired_at;
+    oracle_retirement.policies_repointed = 0;
+    oracle_retirement.policies_suspended = 0;
+    oracle_retirement.templates_affected = 0;
+    oracle_retirement.bump = bump;
+}
+
+/// Prune registry entries whose oracle accounts no longer exist
+///
+/// The accounts to check are passed as remaining accounts; registry entries
+/// without a matching remaining account are left untouched. Duplicate
+/// entries are collapsed as well.
+pub fn sync_oracle_registry(ctx: Context<SyncOracleRegistry>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    let previous_count = master_contract.oracle_registry.len();
+    
+    let mut retained: Vec<Pubkey> = Vec::with_capacity(previous_count);
+    for &key in master_contract.oracle_registry.iter() {
+        if retained.contains(&key) {
+            continue;
+        }
+        let is_live = match ctx.remaining_accounts.iter().find(|account| account.key == &key) {
+            Some(account) => is_live_oracle(account),
+            None => true,
+        };
+        if is_live {
+            retained.push(key);
+        }
+    }
+    let mut directory = ctx.accounts.oracle_directory.load_mut()?;
+    for key in master_contract.oracle_registry.iter().filter(|key| !retained.contains(key)) {
+        directory.record_unregistration(key);
+    }
+    master_contract.oracle_registry = retained;
+    
+    let pruned_count = previous_count - master_contract.oracle_registry.len();
+    emit!(OracleRegistrySynced {
+        admin: ctx.accounts.admin.key(),
+        pruned_count: pruned_count as u8,
+        registered_count: master_contract.oracle_registry.len() as u8,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SyncOracleRegistry>(),
+    });
+    
+    msg!("Oracle registry synced, pruned {} entries", pruned_count);
+    Ok(())
+}
+
+/// Check every registry entry is among `accounts` and still holds an oracle
+fn require_live_registry(master_contract: &MasterInsuranceContract, accounts: &[AccountInfo]) -> Result<()> {
+    for key in master_contract.oracle_registry.iter() {
+        let account = accounts
+            .iter()
+            .find(|account| account.key == key)
+            .ok_or(InsuranceError::OracleNotRegistered)?;
+        require!(is_live_oracle(account), InsuranceError::OracleRegistryStale);
+    }
+    Ok(())
+}
+
+/// Whether an account still holds an initialized oracle owned by this program
+fn is_live_oracle(account: &AccountInfo) -> bool {
+    if account.owner != &crate::ID || account.lamports() == 0 {
+        return false;
+    }
+    match account.try_borrow_data() {
+        Ok(data) => Oracle::from_account_data(&data).is_some(),
+        Err(_) => false,
+    }
+}
+
 pub fn update_oracle_data(ctx: Context<UpdateOracleData>, data: OracleData) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle;
-    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.oracle.load()?.commit_reveal(),
+        InsuranceError::OracleCommitRevealRequired
+    );
+    apply_oracle_update(
+        ctx.accounts,
+        &data,
+        instruction_discriminator::<crate::instruction::UpdateOracleData>(),
+    )
+}
+
+/// Commit to the next update of a commit-reveal oracle (oracle authority only)
+///
+/// `commitment` is `oracle_commitment(value, salt, nonce)` of the update to be
+/// revealed. Only one commitment may await its reveal at a time.
+pub fn commit_oracle_data(ctx: Context<CommitOracleData>, commitment: [u8; 32]) -> Result<()> {
+    require!(commitment != [0; 32], InsuranceError::InvalidInput);
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    require!(oracle.commit_reveal(), InsuranceError::OracleCommitRevealDisabled);
+    require!(!oracle.has_commitment(), InsuranceError::OracleCommitmentPending);
+    
+    oracle.commitment = commitment;
+    oracle.commitment_slot = clock.slot;
+    oracle.committed_at = clock.unix_timestamp;
+    
+    emit!(OracleDataCommitted {
+        oracle: ctx.accounts.oracle.key(),
+        authority: oracle.authority,
+        commitment,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CommitOracleData>(),
+    });
     
-    // Check data reasonableness and manipulation prevention
-    validate_data_reasonableness(oracle, &data, 50)?; // Max 50% change
+    Ok(())
+}
+
+/// Reveal a committed update (oracle authority only)
+///
+/// At least `MIN_REVEAL_DELAY_SLOTS` after the commitment, `data` and `salt`
+/// must hash to it; the update is then validated and applied like any other.
+/// Policies created between the commitment and the reveal cannot claim on the
+/// revealed value.
+pub fn reveal_oracle_data(ctx: Context<UpdateOracleData>, data: OracleData, salt: [u8; 32]) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let committed_at = {
+        let oracle = ctx.accounts.oracle.load()?;
+        require!(oracle.commit_reveal(), InsuranceError::OracleCommitRevealDisabled);
+        require!(oracle.has_commitment(), InsuranceError::NoOracleCommitment);
+        require!(
+            clock.slot >= oracle.commitment_slot.saturating_add(MIN_REVEAL_DELAY_SLOTS),
+            InsuranceError::OracleRevealTooEarly
+        );
+        require!(
+            oracle_commitment(data.value, &salt, data.nonce) == oracle.commitment,
+            InsuranceError::OracleRevealMismatch
+        );
+        oracle.committed_at
+    };
+    
+    apply_oracle_update(
+        ctx.accounts,
+        &data,
+        instruction_discriminator::<crate::instruction::RevealOracleData>(),
+    )?;
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.latest_committed_at = committed_at;
+    oracle.latest_revealed_at = clock.unix_timestamp;
+    oracle.clear_commitment();
+    
+    emit!(OracleDataRevealed {
+        oracle: ctx.accounts.oracle.key(),
+        value: data.value,
+        committed_at,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RevealOracleData>(),
+    });
+    
+    Ok(())
+}
+
+/// Digest an oracle commits to before revealing an update:
+/// `hash(value || salt || nonce)`
+pub fn oracle_commitment(value: u64, salt: &[u8; 32], nonce: u64) -> [u8; 32] {
+    hashv(&[&value.to_le_bytes(), salt, &nonce.to_le_bytes()]).to_bytes()
+}
+
+/// Validate `data` and record it as the oracle's latest observation
+fn apply_oracle_update(accounts: &mut UpdateOracleData, data: &OracleData, instruction: [u8; 8]) -> Result<()> {
+    let mut oracle = accounts.oracle.load_mut()?;
+    let clock = current_clock(&accounts.master_contract)?;
+    
+    // Rate limit updates; too-frequent updates carry no new information and
+    // are rejected without touching health metrics
+    require!(
+        oracle.update_interval_elapsed(clock.unix_timestamp),
+        InsuranceError::OracleUpdateTooFrequent
+    );
+    
+    // Reject layouts this program cannot read; a feed id, when given, must match the oracle's
+    let extensions = data.extensions()?;
+    require!(
+        extensions.feed_id.is_empty() || extensions.feed_id == oracle.data_feed_address().as_bytes(),
+        InsuranceError::InvalidOracleData
+    );
     
-    // Validate timestamp (data should not be older than 5 minutes)
+    // Check data reasonableness and manipulation prevention
+    validate_data_reasonableness(&oracle, data, 50)?; // Max 50% change
+    
+    // Validate timestamp (data must be dated, not ahead of the clock and not older than 5 minutes)
+    crate::utils::error_utils::validate_oracle_timestamp(
+        data.timestamp,
+        clock.unix_timestamp,
+        accounts.master_contract.oracle_risk_params.max_clock_skew_secs,
+    )?;
     let max_age = 5 * 60; // 5 minutes in seconds
     require!(
         clock.unix_timestamp - data.timestamp <= max_age,
         InsuranceError::OracleDataTooOld
     );
     
-    // Verify signature
-    let signature_result = verify_oracle_signature(&oracle.authority, &data);
+    // Verify signature over the update bound to this program and oracle
+    let message = create_oracle_message(&accounts.oracle.key(), oracle.oracle_id(), data);
+    let signature_result = match oracle.signature_scheme() {
+        SignatureScheme::Ed25519 => verify_ed25519_attestation(
+            &accounts.instructions_sysvar,
+            &oracle.authority,
+            &message,
+            &data.signature,
+        ),
+        SignatureScheme::Secp256k1 => verify_secp256k1_attestation(
+            &accounts.instructions_sysvar,
+            &oracle.eth_address,
+            &message,
+            &data.signature,
+        ),
+    };
     if signature_result.is_err() {
-        update_oracle_health(oracle, false, clock.unix_timestamp)?;
+        // Failures during a maintenance pause carry no reputational penalty
+        if !oracle.is_paused(clock.unix_timestamp) {
+            let risk_params = &accounts.master_contract.oracle_risk_params;
+            update_oracle_health(&mut oracle, false, clock.unix_timestamp, risk_params)?;
+        }
         return signature_result;
     }
     
     // Check for replay attacks using nonce
-    if let Some(ref last_data) = oracle.latest_data {
+    if oracle.latest_data().is_some() {
         require!(
-            data.nonce > last_data.nonce,
+            data.nonce > oracle.last_nonce,
             InsuranceError::InvalidOracleData
         );
     }
     
+    // Update health metrics for successful update, spacing measured from the previous one
+    let risk_params = &accounts.master_contract.oracle_risk_params;
+    update_oracle_health(&mut oracle, true, clock.unix_timestamp, risk_params)?;
+    
     // Update oracle data
-    oracle.latest_data = Some(data);
+    oracle.record_observation(OracleObservation::from(data));
+    oracle.last_nonce = data.nonce;
     oracle.last_update_timestamp = clock.unix_timestamp;
     oracle.update_count += 1;
     
-    // Update health metrics for successful update
-    update_oracle_health(oracle, true, clock.unix_timestamp)?;
+    // An accepted update ends any maintenance pause, whether or not it has lapsed
+    if oracle.paused_until != 0 {
+        let paused_until = oracle.paused_until;
+        oracle.paused_until = 0;
+        emit!(OracleResumed {
+            oracle: accounts.oracle.key(),
+            authority: oracle.authority,
+            paused_until,
+            timestamp: clock.unix_timestamp,
+            sequence: accounts.master_contract.next_event_sequence(),
+            instruction,
+        });
+    }
+    
+    Ok(())
+}
+
+/// Size of one signature's offsets in ed25519 program instruction data
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+/// Offsets follow the signature count and a padding byte
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Verify an ed25519 oracle update through the ed25519 program
+///
+/// The instruction preceding this one must be an ed25519 program instruction
+/// carrying exactly one signature over `message` by `oracle_authority`. The
+/// ed25519 program verifies the signature itself, failing the transaction if
+/// it is invalid; here we check that the embedded key, message and signature
+/// are the ones this oracle update is about.
+fn verify_ed25519_attestation(
+    instructions_sysvar: &AccountInfo,
+    oracle_authority: &Pubkey,
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, InsuranceError::MissingEd25519Instruction);
+    
+    let ed25519_index = current_index - 1;
+    let instruction = load_instruction_at_checked(ed25519_index as usize, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID,
+        InsuranceError::MissingEd25519Instruction
+    );
+    
+    // A single signature, whose offsets follow the signature count
+    let ix_data = &instruction.data;
+    require!(ix_data.first() == Some(&1), InsuranceError::Ed25519AttestationMismatch);
+    let offsets = ix_data
+        .get(ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE)
+        .ok_or(InsuranceError::Ed25519AttestationMismatch)?;
+    let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+    let signature_offset = read_u16(0) as usize;
+    let public_key_offset = read_u16(4) as usize;
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    
+    // All referenced data must live in the ed25519 instruction itself
+    require!(
+        [read_u16(2), read_u16(6), read_u16(12)]
+            .iter()
+            .all(|&ix| ix == u16::MAX || ix == ed25519_index),
+        InsuranceError::Ed25519AttestationMismatch
+    );
+    
+    let signed_public_key = ix_data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = ix_data.get(message_offset..message_offset + message_size);
+    let signed_signature = ix_data.get(signature_offset..signature_offset + 64);
+    require!(
+        signed_public_key == Some(oracle_authority.as_ref())
+            && signed_message == Some(message)
+            && signed_signature == Some(&signature[..]),
+        InsuranceError::Ed25519AttestationMismatch
+    );
     
     Ok(())
 }
 
-/// Verify Ed25519 signature for oracle data
-fn verify_oracle_signature(oracle_authority: &Pubkey, data: &OracleData) -> Result<()> {
-    // Create message to verify (value + timestamp + confidence + nonce)
-    let message = create_oracle_message(data);
+/// Size of one signature's offsets in secp256k1 program instruction data
+const SECP256K1_OFFSETS_SIZE: usize = 11;
+
+/// Verify a secp256k1 oracle update through the secp256k1 program
+///
+/// The instruction preceding this one must be a secp256k1 program instruction
+/// carrying exactly one signature over `message` by `eth_address`. The
+/// secp256k1 program keccak-hashes the message and recovers the signer,
+/// failing the transaction if it differs from the embedded address; here we
+/// check that the embedded address, message and signature are the ones this
+/// oracle update is about.
+fn verify_secp256k1_attestation(
+    instructions_sysvar: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, InsuranceError::MissingSecp256k1Instruction);
     
-    // For now, we'll implement a basic signature check
-    // In a production environment, you would use proper Ed25519 verification
-    // This requires additional dependencies or instruction verification
+    let secp_index = current_index - 1;
+    let instruction = load_instruction_at_checked(secp_index as usize, instructions_sysvar)?;
+    require!(
+        instruction.program_id == secp256k1_program::ID,
+        InsuranceError::MissingSecp256k1Instruction
+    );
     
-    // Placeholder verification - check that signature is not all zeros
-    let signature_valid = !data.signature.iter().all(|&x| x == 0);
+    // A single signature, whose offsets follow the signature count
+    let ix_data = &instruction.data;
+    require!(ix_data.first() == Some(&1), InsuranceError::Secp256k1AttestationMismatch);
+    let offsets = ix_data
+        .get(1..1 + SECP256K1_OFFSETS_SIZE)
+        .ok_or(InsuranceError::Secp256k1AttestationMismatch)?;
+    let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+    let signature_offset = read_u16(0);
+    let eth_address_offset = read_u16(3);
+    let message_offset = read_u16(6);
+    let message_size = read_u16(8);
+    
+    // All referenced data must live in the secp256k1 instruction itself
+    let secp_index = secp_index as u8;
+    require!(
+        offsets[2] == secp_index && offsets[5] == secp_index && offsets[10] == secp_index,
+        InsuranceError::Secp256k1AttestationMismatch
+    );
     
-    require!(signature_valid, InsuranceError::OracleSignatureInvalid);
+    let signed_eth_address = ix_data.get(eth_address_offset..eth_address_offset + 20);
+    let signed_message = ix_data.get(message_offset..message_offset + message_size);
+    let signed_signature = ix_data.get(signature_offset..signature_offset + 64);
+    require!(
+        signed_eth_address == Some(&eth_address[..])
+            && signed_message == Some(message)
+            && signed_signature == Some(&signature[..]),
+        InsuranceError::Secp256k1AttestationMismatch
+    );
     
     Ok(())
 }
 
 /// Create message for signature verification
-fn create_oracle_message(data: &OracleData) -> Vec<u8> {
+///
+/// The update is prefixed with a domain tag, the program id, the oracle
+/// account and its length-prefixed id, so a signature is only valid for the
+/// oracle and deployment it was produced for.
+pub fn create_oracle_message(oracle: &Pubkey, oracle_id: &str, data: &OracleData) -> Vec<u8> {
     let mut message = Vec::new();
+    message.extend_from_slice(ORACLE_MESSAGE_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(oracle.as_ref());
+    message.push(oracle_id.len() as u8);
+    message.extend_from_slice(oracle_id.as_bytes());
     message.extend_from_slice(&data.value.to_le_bytes());
     message.extend_from_slice(&data.timestamp.to_le_bytes());
     message.extend_from_slice(&data.confidence.to_le_bytes());
     message.extend_from_slice(&data.nonce.to_le_bytes());
+    // Extensions are signed too; v1 data has none, keeping its message unchanged
+    message.extend_from_slice(&data.extensions);
     message
 }
 
-/// Parse Pyth oracle data format
-pub fn parse_pyth_format(raw_data: &[u8]) -> Result<OracleData> {
+/// Parse Pyth oracle data format, rejecting readings without a timestamp or
+/// dated more than `max_clock_skew_secs` after `current_timestamp`
+pub fn parse_pyth_format(raw_data: &[u8], current_timestamp: i64, max_clock_skew_secs: u32) -> Result<OracleData> {
     // Pyth Network format: value (8 bytes) + timestamp (8 bytes) + confidence (8 bytes)
     require!(raw_data.len() >= 24, InsuranceError::InvalidOracleData);
     
     let value = u64::from_le_bytes(raw_data[0..8].try_into().unwrap());
     let timestamp = i64::from_le_bytes(raw_data[8..16].try_into().unwrap());
     let confidence = u64::from_le_bytes(raw_data[16..24].try_into().unwrap());
+    crate::utils::error_utils::validate_oracle_timestamp(timestamp, current_timestamp, max_clock_skew_secs)?;
     
     Ok(OracleData {
+        version: ORACLE_DATA_V1,
         value,
         timestamp,
         confidence,
         signature: [0; 64], // Will be set by caller
         nonce: 0, // Will be set by caller
+        extensions: Vec::new(),
     })
 }
 
 /// Validate Pyth price account data format
-pub fn validate_pyth_price_data(
-    price_account_data: &[u8],
-    expected_product_id: &[u8; 32],
-) -> Result<bool> {
+pub fn validate_pyth_price_data(price_account_data: &[u8]) -> Result<bool> {
     // Basic Pyth price account validation
     require!(
         price_account_data.len() >= 208, // Minimum Pyth price account size
@@ -267,9 +1347,17 @@ pub fn validate_pyth_price_data(
 }
 
 /// Extract price data from Pyth price account
-pub fn extract_pyth_price_data(price_account_data: &[u8]) -> Result<(i64, u64, i64)> {
+///
+/// A malformed account can hold a zero publish time, which would pass any
+/// staleness check, so readings without a timestamp or dated more than
+/// `max_clock_skew_secs` after `current_timestamp` are rejected.
+pub fn extract_pyth_price_data(
+    price_account_data: &[u8],
+    current_timestamp: i64,
+    max_clock_skew_secs: u32,
+) -> Result<(i64, u64, i64)> {
     // Validate account format first
-    validate_pyth_price_data(price_account_data, &[0; 32])?;
+    validate_pyth_price_data(price_account_data)?;
     
     // Extract price (bytes 208-215)
     let price = i64::from_le_bytes([
@@ -306,107 +1394,539 @@ pub fn extract_pyth_price_data(price_account_data: &[u8]) -> Result<(i64, u64, i
         price_account_data[262],
         price_account_data[263],
     ]);
+    crate::utils::error_utils::validate_oracle_timestamp(timestamp, current_timestamp, max_clock_skew_secs)?;
     
     Ok((price, confidence, timestamp))
 }
 
 pub fn update_oracle_status(ctx: Context<UpdateOracleStatus>, is_active: bool) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle;
-    oracle.is_active = is_active;
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    require!(!(is_active && oracle.is_resigning()), InsuranceError::OracleResigning);
+    oracle.set_active(is_active);
+    
+    // Deactivation is the penalty for a misbehaving oracle; it forfeits accrued fees
+    if !is_active && oracle.unclaimed_fees > 0 {
+        msg!("Oracle {} forfeits {} unclaimed fee lamports", oracle.oracle_id(), oracle.unclaimed_fees);
+        oracle.unclaimed_fees = 0;
+    }
     Ok(())
 }
 
-/// Get consensus data from multiple oracles
-pub fn get_consensus_data(
-    master_contract: &MasterInsuranceContract,
-    oracle_accounts: &[Account<Oracle>],
-) -> Result<Option<ConsensusData>> {
-    let clock = Clock::get()?;
+/// Pay an oracle's accrued fees from the treasury to its authority
+///
+/// Claims are capped at the treasury's liquidity above its reserve
+/// requirement; any remainder stays claimable.
+pub fn claim_oracle_fees(ctx: Context<ClaimOracleFees>) -> Result<()> {
+    let oracle_key = ctx.accounts.oracle.key();
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    require!(oracle.unclaimed_fees > 0, InsuranceError::NoOracleFeesToClaim);
+    
+    // Never dip into the reserve requirement or the treasury's rent reserve
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let amount = oracle
+        .unclaimed_fees
+        .min(treasury.available_liquidity())
+        .min(treasury_info.lamports().saturating_sub(rent_reserve));
+    require!(amount > 0, InsuranceError::InsufficientTreasury);
+    
+    treasury.update_balances(0, -(amount as i64), clock.unix_timestamp);
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.oracle_authority.try_borrow_mut_lamports()? += amount;
+    
+    oracle.unclaimed_fees -= amount;
+    
+    emit!(OracleFeesClaimed {
+        oracle: oracle_key,
+        authority: ctx.accounts.oracle_authority.key(),
+        amount,
+        remaining_unclaimed: oracle.unclaimed_fees,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ClaimOracleFees>(),
+    });
     
-    // Check if we have minimum consensus threshold
-    let active_oracles: Vec<_> = oracle_accounts
-        .iter()
-        .filter(|oracle| oracle.is_active && oracle.latest_data.is_some())
-        .collect();
+    Ok(())
+}
+
+/// Set the minimum interval between accepted updates of an oracle (admin only)
+pub fn set_oracle_update_interval(
+    ctx: Context<SetOracleUpdateInterval>,
+    min_update_interval_secs: i64,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
     
     require!(
-        active_oracles.len() >= master_contract.min_consensus_threshold as usize,
-        InsuranceError::InsufficientOracles
+        (0..=MAX_ORACLE_UPDATE_INTERVAL).contains(&min_update_interval_secs),
+        InsuranceError::InvalidInput
+    );
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.min_update_interval_secs = min_update_interval_secs;
+    Ok(())
+}
+
+/// Switch an oracle between direct updates and commit-reveal (admin only)
+///
+/// Switching either way drops a commitment awaiting its reveal.
+pub fn set_oracle_commit_reveal(ctx: Context<SetOracleCommitReveal>, enabled: bool) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.set_commit_reveal(enabled);
+    Ok(())
+}
+
+/// Pause an oracle for maintenance for up to 24 hours (oracle authority only)
+///
+/// The oracle abstains from consensus until `duration_secs` elapse or its next
+/// accepted update, without counting as unhealthy or timed out. Pausing again
+/// restarts the window from now.
+pub fn pause_oracle_self(ctx: Context<PauseOracleSelf>, duration_secs: i64) -> Result<()> {
+    require!(
+        (1..=MAX_ORACLE_SELF_PAUSE).contains(&duration_secs),
+        InsuranceError::InvalidPauseDuration
+    );
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    oracle.paused_until = clock.unix_timestamp + duration_secs;
+    
+    emit!(OraclePaused {
+        oracle: ctx.accounts.oracle.key(),
+        authority: oracle.authority,
+        paused_until: oracle.paused_until,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::PauseOracleSelf>(),
+    });
+    
+    Ok(())
+}
+
+/// Fresh readings of active oracles gathered for one consensus round
+pub struct OracleReadings {
+    /// Oracles with a fresh reading, in account order
+    pub oracles: [Pubkey; MAX_ORACLES],
+    /// Reading of each oracle in `oracles`
+    pub values: [u64; MAX_ORACLES],
+    /// Data provider of each oracle in `oracles`, if attributed to one
+    pub providers: [Option<Pubkey>; MAX_ORACLES],
+    /// Number of filled entries
+    pub count: usize,
+    /// Active oracles with any reading, fresh or timed out
+    pub reporting_count: usize,
+}
+
+impl OracleReadings {
+    pub fn oracles(&self) -> &[Pubkey] {
+        &self.oracles[..self.count]
+    }
+    
+    pub fn values(&self) -> &[u64] {
+        &self.values[..self.count]
+    }
+    
+    /// Whether any reporting oracle's latest reading has timed out
+    pub fn timed_out(&self) -> bool {
+        self.count < self.reporting_count
+    }
+    
+    /// Fresh readings counting toward the consensus threshold: one per data
+    /// provider, and one for each oracle without a provider
+    pub fn quorum(&self) -> usize {
+        siglab_core::independent_sources(&self.providers[..self.count])
+    }
+    
+    /// Quorum of the readings left once outliers are dropped
+    pub fn retained_quorum(&self) -> usize {
+        let bounds = siglab_core::outlier_bounds(self.values());
+        let mut providers = [None; MAX_ORACLES];
+        let mut retained = 0;
+        for (value, provider) in self.values().iter().zip(self.providers) {
+            let kept = match bounds {
+                Some((lower, upper)) => (lower..=upper).contains(value),
+                None => true,
+            };
+            if kept {
+                providers[retained] = provider;
+                retained += 1;
+            }
+        }
+        siglab_core::independent_sources(&providers[..retained])
+    }
+}
+
+/// Collect fresh readings from registered oracles passed as raw accounts
+///
+/// Every account must be a registered oracle and appear only once, so a
+/// caller cannot inflate the oracle count of a round. Readings older than
+/// the master contract's consensus timeout are left out, as are paused
+/// oracles and oracles excluded by [`Oracle::consensus_exclusion`].
+pub fn collect_oracle_readings(
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> Result<OracleReadings> {
+    require!(
+        oracle_accounts.len() <= MAX_ORACLES,
+        InsuranceError::MaxOraclesExceeded
     );
     
-    // Extract valid oracle values (not older than 10 minutes)
-    let max_age = 10 * 60; // 10 minutes in seconds
-    let mut valid_values = Vec::new();
+    let mut readings = OracleReadings {
+        oracles: [Pubkey::default(); MAX_ORACLES],
+        values: [0u64; MAX_ORACLES],
+        providers: [None; MAX_ORACLES],
+        count: 0,
+        reporting_count: 0,
+    };
     
-    for oracle in active_oracles {
-        if let Some(ref data) = oracle.latest_data {
-            if clock.unix_timestamp - data.timestamp <= max_age {
-                valid_values.push(data.value);
+    for (index, account_info) in oracle_accounts.iter().enumerate() {
+        require!(
+            account_info.owner == &crate::ID
+                && master_contract.oracle_registry.contains(account_info.key),
+            InsuranceError::OracleNotRegistered
+        );
+        require!(
+            !oracle_accounts[..index].iter().any(|other| other.key == account_info.key),
+            InsuranceError::DuplicateOracleAccount
+        );
+        
+        let data = account_info.try_borrow_data()?;
+        let oracle = Oracle::from_account_data(&data).ok_or(InsuranceError::InvalidOracleData)?;
+        if !oracle.is_active()
+            || oracle.is_paused(current_timestamp)
+            || oracle.consensus_exclusion(master_contract.min_oracle_reputation).is_some()
+        {
+            continue;
+        }
+        if let Some(latest) = oracle.latest_data() {
+            readings.reporting_count += 1;
+            if current_timestamp - latest.timestamp <= master_contract.consensus_timeout_secs {
+                readings.oracles[readings.count] = account_info.key();
+                readings.values[readings.count] = latest.value;
+                readings.providers[readings.count] = oracle.provider();
+                readings.count += 1;
             }
         }
     }
     
+    Ok(readings)
+}
+
+/// Number of fresh oracles a round needs under the configured fallback
+///
+/// Without timed-out oracles this is the master contract's threshold.
+/// Otherwise `HaltPayouts` refuses the round, `DegradeThreshold` lowers the
+/// threshold by one (never below one oracle), and `UseLastConsensus` keeps
+/// the full threshold, leaving triggers to fall back to an earlier round.
+///
+/// Degrading only makes up for oracles lost to timeouts: when the fresh
+/// readings meet the full threshold it stands, so outliers dropped later
+/// cannot be absorbed by a lowered one. Fresh readings are counted once
+/// per data provider, as in [`OracleReadings::quorum`].
+pub fn required_oracle_count(
+    master_contract: &MasterInsuranceContract,
+    readings: &OracleReadings,
+) -> Result<usize> {
+    let min_oracles = master_contract.min_consensus_threshold as usize;
+    
     require!(
-        valid_values.len() >= master_contract.min_consensus_threshold as usize,
+        readings.reporting_count >= min_oracles,
         InsuranceError::InsufficientOracles
     );
     
-    // Remove outliers (values beyond 2 standard deviations)
-    let filtered_values = remove_outliers(&valid_values)?;
+    if !readings.timed_out() {
+        return Ok(min_oracles);
+    }
     
+    match master_contract.consensus_fallback {
+        ConsensusFallbackMode::HaltPayouts => Err(InsuranceError::OracleConsensusFailure.into()),
+        ConsensusFallbackMode::UseLastConsensus { .. } => Ok(min_oracles),
+        ConsensusFallbackMode::DegradeThreshold if readings.quorum() >= min_oracles => Ok(min_oracles),
+        ConsensusFallbackMode::DegradeThreshold => Ok(min_oracles.saturating_sub(1).max(1)),
+    }
+}
+
+/// Get consensus data from multiple oracles
+///
+/// Values are gathered in one pass into a stack buffer bounded by
+/// `MAX_ORACLES`; outlier removal and the median sort then work on a
+/// copy, so no heap allocation happens on this path.
+pub fn get_consensus_data(
+    master_contract: &mut MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    instruction: [u8; 8],
+) -> Result<Option<ConsensusData>> {
+    let clock = current_clock(master_contract)?;
+    let readings = collect_oracle_readings(master_contract, oracle_accounts, clock.unix_timestamp)?;
+    let required = required_oracle_count(master_contract, &readings)?;
+    
+    Ok(Some(consensus_from_readings(master_contract, &readings, required, clock.unix_timestamp, instruction)?))
+}
+
+/// Aggregate collected readings, dropping outliers first
+///
+/// Too few fresh readings is `InsufficientOracles`, and enough readings from
+/// too few data providers is `ProviderQuorumNotMet`; every reading still
+/// feeds the aggregate. Enough fresh readings that fall short once outliers
+/// are dropped is `ConsensusDispersionTooHigh`, logged with the spread of
+/// the values so operators can tell the cases apart.
+fn consensus_from_readings(
+    master_contract: &mut MasterInsuranceContract,
+    readings: &OracleReadings,
+    required: usize,
+    current_timestamp: i64,
+    instruction: [u8; 8],
+) -> Result<ConsensusData> {
     require!(
-        filtered_values.len() >= master_contract.min_consensus_threshold as usize,
+        readings.count >= required,
         InsuranceError::InsufficientOracles
     );
+    require!(
+        readings.quorum() >= required,
+        InsuranceError::ProviderQuorumNotMet
+    );
     
-    // Create consensus data
-    let consensus = ConsensusData::from_oracle_values(&filtered_values, clock.unix_timestamp);
+    let mut values = readings.values;
     
-    Ok(Some(consensus))
+    // Remove outliers (values beyond 2 standard deviations)
+    let retained_count = siglab_core::remove_outliers(&mut values[..readings.count]);
+    
+    if readings.retained_quorum() < required {
+        emit!(ConsensusDispersionExceeded {
+            round: master_contract.consensus_round,
+            fresh_oracles: readings.count as u8,
+            retained_oracles: retained_count as u8,
+            required_oracles: required as u8,
+            coefficient_of_variation_bps: siglab_core::coefficient_of_variation_bps(readings.values()),
+            timestamp: current_timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction,
+        });
+        return err!(InsuranceError::ConsensusDispersionTooHigh);
+    }
+    
+    // Create consensus data
+    Ok(ConsensusData::from_oracle_values(&mut values[..retained_count], current_timestamp))
 }
 
-/// Remove statistical outliers from oracle values
-fn remove_outliers(values: &[u64]) -> Result<Vec<u64>> {
-    if values.len() <= 2 {
-        return Ok(values.to_vec());
+/// Compute consensus over the passed oracles and store it as the next round
+///
+/// Oracles are passed as remaining accounts. The snapshot keeps every
+/// contributing reading, including any later dropped as an outlier, so
+/// disputes can be reasoned about per oracle.
+pub fn compute_consensus(ctx: Context<ComputeConsensus>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let master_contract = &mut ctx.accounts.master_contract;
+    
+    let min_healthy = master_contract.min_consensus_threshold as usize;
+    let exclusions = check_oracle_system_health(
+        master_contract,
+        ctx.remaining_accounts,
+        Some(min_healthy),
+        clock.unix_timestamp,
+    )?;
+    
+    let readings = collect_oracle_readings(master_contract, ctx.remaining_accounts, clock.unix_timestamp)?;
+    let required = required_oracle_count(master_contract, &readings)?;
+    let degraded = required < master_contract.min_consensus_threshold as usize;
+    let consensus = consensus_from_readings(
+        master_contract,
+        &readings,
+        required,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::ComputeConsensus>(),
+    )?;
+    
+    let round = master_contract.consensus_round;
+    let snapshot = &mut ctx.accounts.consensus_snapshot;
+    snapshot.round = round;
+    snapshot.consensus = consensus.clone();
+    snapshot.oracles = readings.oracles().to_vec();
+    snapshot.values = readings.values().to_vec();
+    snapshot.degraded = degraded;
+    snapshot.dispute_status = ConsensusRoundStatus::Pending;
+    snapshot.disputing_oracle = None;
+    snapshot.counter_value = None;
+    snapshot.resolved_value = None;
+    snapshot.payer = ctx.accounts.payer.key();
+    snapshot.created_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.consensus_snapshot;
+    
+    master_contract.consensus_round = round + 1;
+    
+    for (oracle, reason, reputation_score) in exclusions.iter() {
+        emit!(OracleExcludedFromConsensus {
+            oracle,
+            reason,
+            reputation_score,
+            timestamp: clock.unix_timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::ComputeConsensus>(),
+        });
     }
     
-    // Calculate mean and standard deviation
-    let mean = values.iter().sum::<u64>() / values.len() as u64;
-    let variance = values
-        .iter()
-        .map(|&x| {
-            let diff = if x > mean { x - mean } else { mean - x };
-            diff * diff
-        })
-        .sum::<u64>() / values.len() as u64;
+    if degraded {
+        emit!(ConsensusDegraded {
+            round,
+            fallback: master_contract.consensus_fallback,
+            required_oracles: required as u8,
+            consensus_age: 0,
+            timestamp: clock.unix_timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::ComputeConsensus>(),
+        });
+    }
+    
+    emit!(ConsensusComputed {
+        round,
+        snapshot: snapshot.key(),
+        aggregated_value: consensus.aggregated_value,
+        median_value: consensus.median_value,
+        confidence_score: consensus.confidence_score,
+        oracle_count: consensus.oracle_count,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ComputeConsensus>(),
+    });
+    
+    Ok(())
+}
+
+/// Dispute a consensus round within its dispute window (oracle authorities only)
+///
+/// A disputed round cannot be used by triggers; a fresh round must be
+/// computed or the admin must resolve the dispute.
+pub fn dispute_consensus(
+    ctx: Context<DisputeConsensus>,
+    round: u64,
+    counter_value: u64,
+) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let snapshot = &mut ctx.accounts.consensus_snapshot;
     
-    let std_dev = ConsensusData::integer_sqrt(variance);
+    require!(
+        snapshot.dispute_status == ConsensusRoundStatus::Pending,
+        InsuranceError::InvalidDisputeState
+    );
+    require!(
+        clock.unix_timestamp - snapshot.created_at <= CONSENSUS_DISPUTE_WINDOW,
+        InsuranceError::DisputeWindowClosed
+    );
     
-    // Keep values within 2 standard deviations
-    let threshold = std_dev * 2;
-    let lower_bound = if mean > threshold { mean - threshold } else { 0 };
-    let upper_bound = mean + threshold;
+    snapshot.dispute_status = ConsensusRoundStatus::Disputed;
+    snapshot.disputing_oracle = Some(ctx.accounts.oracle.key());
+    snapshot.counter_value = Some(counter_value);
+    
+    emit!(ConsensusDisputed {
+        round,
+        oracle: ctx.accounts.oracle.key(),
+        authority: ctx.accounts.oracle_authority.key(),
+        consensus_value: snapshot.consensus.aggregated_value,
+        counter_value,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::DisputeConsensus>(),
+    });
     
-    let filtered: Vec<u64> = values
-        .iter()
-        .filter(|&&value| value >= lower_bound && value <= upper_bound)
-        .copied()
-        .collect();
+    Ok(())
+}
+
+/// Settle a disputed round on `resolved_value` and slash the side that deviated most (admin only)
+///
+/// Slashing forfeits the oracle's unclaimed fees and counts as a failed
+/// validation against its health metrics.
+pub fn resolve_consensus_dispute(
+    ctx: Context<ResolveConsensusDispute>,
+    round: u64,
+    resolved_value: u64,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let snapshot = &mut ctx.accounts.consensus_snapshot;
+    
+    require!(
+        snapshot.dispute_status == ConsensusRoundStatus::Disputed,
+        InsuranceError::InvalidDisputeState
+    );
+    require!(
+        snapshot.dispute_loser(resolved_value) == Some(ctx.accounts.slashed_oracle.key()),
+        InsuranceError::SlashedOracleMismatch
+    );
+    
+    snapshot.dispute_status = ConsensusRoundStatus::Resolved;
+    snapshot.resolved_value = Some(resolved_value);
+    
+    let mut oracle = ctx.accounts.slashed_oracle.load_mut()?;
+    let forfeited_fees = oracle.unclaimed_fees;
+    oracle.unclaimed_fees = 0;
+    oracle
+        .health_metrics
+        .record_failed_validation(clock.unix_timestamp, &ctx.accounts.master_contract.oracle_risk_params);
+    
+    emit!(ConsensusDisputeResolved {
+        round,
+        admin: ctx.accounts.admin.key(),
+        resolved_value,
+        slashed_oracle: ctx.accounts.slashed_oracle.key(),
+        forfeited_fees,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ResolveConsensusDispute>(),
+    });
+    
+    Ok(())
+}
+
+/// Close a consensus snapshot past its retention period (permissionless crank)
+pub fn close_consensus_snapshot(ctx: Context<CloseConsensusSnapshot>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let snapshot = &ctx.accounts.consensus_snapshot;
+    
+    require!(
+        clock.unix_timestamp - snapshot.created_at >= CONSENSUS_SNAPSHOT_RETENTION,
+        InsuranceError::ConsensusSnapshotRetained
+    );
+    
+    emit!(ConsensusSnapshotClosed {
+        round: snapshot.round,
+        collector: ctx.accounts.rent_collector.key(),
+        closed_by: ctx.accounts.cranker.key(),
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CloseConsensusSnapshot>(),
+    });
     
-    Ok(filtered)
+    Ok(())
 }
 
 /// Check consensus timeout for missing oracle data
+///
+/// Only program-owned oracle accounts that have reported at least once and
+/// are not paused for maintenance are considered; other accounts are skipped
+/// so the check can run over a trigger's remaining accounts.
 pub fn check_consensus_timeout(
-    oracle_accounts: &[Account<Oracle>],
+    oracle_accounts: &[AccountInfo],
     timeout_seconds: i64,
+    current_timestamp: i64,
 ) -> Result<bool> {
-    let clock = Clock::get()?;
-    
-    for oracle in oracle_accounts {
-        if oracle.is_active {
-            let time_since_update = clock.unix_timestamp - oracle.last_update_timestamp;
+    for account_info in oracle_accounts {
+        if account_info.owner != &crate::ID {
+            continue;
+        }
+        let data = account_info.try_borrow_data()?;
+        let Some(oracle) = Oracle::from_account_data(&data) else {
+            continue;
+        };
+        if oracle.is_active() && !oracle.is_paused(current_timestamp) && oracle.latest_data().is_some() {
+            let time_since_update = current_timestamp - oracle.last_update_timestamp;
             if time_since_update > timeout_seconds {
                 return Ok(true); // Timeout detected
             }
@@ -443,12 +1963,12 @@ pub fn validate_data_reasonableness(
 ) -> Result<bool> {
     // Check if circuit breaker is active
     require!(
-        !oracle.health_metrics.circuit_breaker_active,
+        !oracle.health_metrics.circuit_breaker_active(),
         InsuranceError::OracleConsensusFailure
     );
     
     // Check for extreme value swings (max 50% change per update)
-    if let Some(ref last_data) = oracle.latest_data {
+    if let Some(last_data) = oracle.latest_data() {
         let percentage_change = calculate_percentage_change(last_data.value, new_data.value);
         require!(
             percentage_change <= max_change_percentage,
@@ -471,30 +1991,28 @@ fn calculate_percentage_change(old_value: u64, new_value: u64) -> u8 {
         return 100; // Max change if starting from 0
     }
     
-    let difference = if new_value > old_value {
-        new_value - old_value
-    } else {
-        old_value - new_value
-    };
-    
-    let percentage = (difference * 100) / old_value;
+    let percentage = (new_value.abs_diff(old_value) * 100) / old_value;
     std::cmp::min(percentage as u8, 100)
 }
 
-/// Update oracle health metrics and reputation score
-pub fn update_oracle_health(oracle: &mut Oracle, success: bool, current_timestamp: i64) -> Result<()> {
+/// Update oracle health metrics and reputation score under `risk_params`
+pub fn update_oracle_health(
+    oracle: &mut Oracle,
+    success: bool,
+    current_timestamp: i64,
+    risk_params: &OracleRiskParams,
+) -> Result<()> {
     if success {
-        oracle.health_metrics.record_successful_update(current_timestamp);
+        let spaced = oracle.update_interval_elapsed(current_timestamp);
+        oracle.health_metrics.record_successful_update(current_timestamp, spaced, risk_params);
         
         // Improve reputation score for successful updates
-        if oracle.reputation_score < 100 {
-            oracle.reputation_score = std::cmp::min(100, oracle.reputation_score + 1);
-        }
+        oracle.reputation_score = std::cmp::min(100, oracle.reputation_score.saturating_add(risk_params.recovery_rate));
     } else {
-        oracle.health_metrics.record_failed_validation(current_timestamp);
+        oracle.health_metrics.record_failed_validation(current_timestamp, risk_params);
         
         // Decrease reputation score for failures
-        oracle.reputation_score = oracle.reputation_score.saturating_sub(3);
+        oracle.reputation_score = oracle.reputation_score.saturating_sub(risk_params.reputation_penalty);
     }
     
     Ok(())
@@ -505,10 +2023,10 @@ pub fn update_oracle_health(oracle: &mut Oracle, success: bool, current_timestam
 pub struct EmergencyOracleOverride<'info> {
     #[account(
         mut,
-        seeds = [b"oracle", oracle.oracle_id.as_bytes()],
-        bump = oracle.bump
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub oracle: AccountLoader<'info, Oracle>,
     
     #[account(
         constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
@@ -523,18 +2041,28 @@ pub fn emergency_oracle_override(
     corrected_data: OracleData,
     reason: String,
 ) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle;
-    let clock = Clock::get()?;
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    corrected_data.extensions()?;
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    crate::utils::error_utils::validate_oracle_timestamp(
+        corrected_data.timestamp,
+        clock.unix_timestamp,
+        ctx.accounts.master_contract.oracle_risk_params.max_clock_skew_secs,
+    )?;
     
     // Log the override for governance transparency
-    msg!("Emergency oracle override - Oracle: {}, Reason: {}", oracle.oracle_id, reason);
+    msg!("Emergency oracle override - Oracle: {}, Reason: {}", oracle.oracle_id(), reason);
     
     // Apply corrected data
-    oracle.latest_data = Some(corrected_data);
+    oracle.record_observation(OracleObservation::from(&corrected_data));
+    oracle.last_nonce = corrected_data.nonce;
     oracle.last_update_timestamp = clock.unix_timestamp;
     
     // Reset circuit breaker if active
-    oracle.health_metrics.circuit_breaker_active = false;
+    oracle.health_metrics.set_circuit_breaker_active(false);
     
     // Mark as administrative override in metrics
     oracle.health_metrics.failed_validations = 0;
@@ -542,26 +2070,100 @@ pub fn emergency_oracle_override(
     Ok(())
 }
 
+/// Active oracles left out of consensus, with the reason and their reputation
+pub struct OracleExclusions {
+    pub oracles: [Pubkey; MAX_ORACLES],
+    pub reasons: [OracleExclusionReason; MAX_ORACLES],
+    pub reputation_scores: [u8; MAX_ORACLES],
+    /// Number of filled entries
+    pub count: usize,
+}
+
+impl OracleExclusions {
+    pub fn iter(&self) -> impl Iterator<Item = (Pubkey, OracleExclusionReason, u8)> + '_ {
+        (0..self.count).map(|i| (self.oracles[i], self.reasons[i], self.reputation_scores[i]))
+    }
+}
+
+/// Oracles counted by `oracle_system_health`
+pub struct OracleSystemHealth {
+    /// Oracles not paused for maintenance
+    pub oracle_count: usize,
+    /// Of those, active oracles not excluded from consensus
+    pub healthy_oracles: usize,
+    pub exclusions: OracleExclusions,
+}
+
+impl OracleSystemHealth {
+    /// Whether at least `min_healthy_oracles` are healthy, or every oracle when `None`
+    pub fn is_sufficient(&self, min_healthy_oracles: Option<usize>) -> bool {
+        self.healthy_oracles >= min_healthy_oracles.unwrap_or(self.oracle_count)
+    }
+}
+
 /// Check if oracle system has sufficient health for operations
+///
+/// Program-owned oracle accounts among `oracle_accounts` are healthy when
+/// active and not excluded from consensus; other accounts, and oracles paused
+/// for maintenance at `current_timestamp`, are skipped. At
+/// least `min_healthy_oracles` must be healthy, or every oracle passed when
+/// `None`, otherwise this fails with `InsufficientOracles`. Freshness is
+/// left to the consensus timeout and its fallback mode.
 pub fn check_oracle_system_health(
-    oracle_accounts: &[Account<Oracle>],
-    min_healthy_oracles: u8,
-) -> Result<bool> {
-    let healthy_oracles = oracle_accounts
-        .iter()
-        .filter(|oracle| {
-            oracle.is_active && 
-            !oracle.health_metrics.circuit_breaker_active &&
-            oracle.reputation_score >= 70
-        })
-        .count();
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    min_healthy_oracles: Option<usize>,
+    current_timestamp: i64,
+) -> Result<OracleExclusions> {
+    let health = oracle_system_health(master_contract, oracle_accounts, current_timestamp)?;
+    require!(health.is_sufficient(min_healthy_oracles), InsuranceError::InsufficientOracles);
+    Ok(health.exclusions)
+}
+
+/// Count the healthy oracles among `oracle_accounts`, as described on
+/// `check_oracle_system_health`, without requiring any
+pub fn oracle_system_health(
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> Result<OracleSystemHealth> {
+    let mut exclusions = OracleExclusions {
+        oracles: [Pubkey::default(); MAX_ORACLES],
+        reasons: [OracleExclusionReason::CircuitBreaker; MAX_ORACLES],
+        reputation_scores: [0; MAX_ORACLES],
+        count: 0,
+    };
     
-    require!(
-        healthy_oracles >= min_healthy_oracles as usize,
-        InsuranceError::InsufficientOracles
-    );
+    let mut oracle_count = 0;
+    let mut healthy_oracles = 0;
+    for account_info in oracle_accounts {
+        if account_info.owner != &crate::ID {
+            continue;
+        }
+        let data = account_info.try_borrow_data()?;
+        let Some(oracle) = Oracle::from_account_data(&data) else {
+            continue;
+        };
+        if oracle.is_paused(current_timestamp) {
+            continue;
+        }
+        oracle_count += 1;
+        if !oracle.is_active() {
+            continue;
+        }
+        match oracle.consensus_exclusion(master_contract.min_oracle_reputation) {
+            None => healthy_oracles += 1,
+            Some(reason) if exclusions.count < MAX_ORACLES => {
+                exclusions.oracles[exclusions.count] = account_info.key();
+                exclusions.reasons[exclusions.count] = reason;
+                exclusions.reputation_scores[exclusions.count] = oracle.reputation_score;
+                exclusions.count += 1;
+            }
+            Some(_) => {}
+        }
+    }
     
-    Ok(true)
+    Ok(OracleSystemHealth { oracle_count, healthy_oracles, exclusions })
 }
 
 /// Reset circuit breaker for a specific oracle (admin only)
@@ -569,10 +2171,10 @@ pub fn check_oracle_system_health(
 pub struct ResetOracleCircuitBreaker<'info> {
     #[account(
         mut,
-        seeds = [b"oracle", oracle.oracle_id.as_bytes()],
-        bump = oracle.bump
+        seeds = [b"oracle", oracle.load()?.oracle_id().as_bytes()],
+        bump = oracle.load()?.bump
     )]
-    pub oracle: Account<'info, Oracle>,
+    pub oracle: AccountLoader<'info, Oracle>,
     
     #[account(
         constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
@@ -583,12 +2185,14 @@ pub struct ResetOracleCircuitBreaker<'info> {
 }
 
 pub fn reset_oracle_circuit_breaker(ctx: Context<ResetOracleCircuitBreaker>) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle;
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let mut oracle = ctx.accounts.oracle.load_mut()?;
     
-    oracle.health_metrics.circuit_breaker_active = false;
+    oracle.health_metrics.set_circuit_breaker_active(false);
     oracle.health_metrics.failed_validations = 0;
     
-    msg!("Circuit breaker reset for oracle: {}", oracle.oracle_id);
+    msg!("Circuit breaker reset for oracle: {}", oracle.oracle_id());
     
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{MasterInsuranceContract, Oracle, OracleDirectory};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, ORACLE_DIRECTORY_SEED};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{instruction_discriminator, OracleDirectoryInitialized};
+
+#[derive(Accounts)]
+pub struct InitializeOracleDirectory<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = OracleDirectory::space(),
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the oracle directory and fill it from the current registry
+///
+/// Every registered oracle account must be passed as a remaining account,
+/// in any order. From then on registration and unregistration keep the
+/// directory in step with the registry.
+pub fn initialize_oracle_directory(ctx: Context<InitializeOracleDirectory>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let mut directory = ctx.accounts.oracle_directory.load_init()?;
+    directory.bump = ctx.bumps.oracle_directory;
+    for key in master_contract.oracle_registry.iter() {
+        let account = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key == key)
+            .ok_or(InsuranceError::OracleNotRegistered)?;
+        require_keys_eq!(*account.owner, crate::ID, InsuranceError::OracleNotRegistered);
+        let data = account.try_borrow_data()?;
+        let oracle = Oracle::from_account_data(&data).ok_or(InsuranceError::OracleNotRegistered)?;
+        directory.record_registration(oracle.oracle_id(), *key, oracle.authority)?;
+    }
+
+    emit!(OracleDirectoryInitialized {
+        oracle_directory: ctx.accounts.oracle_directory.key(),
+        admin: ctx.accounts.admin.key(),
+        entry_count: master_contract.oracle_registry.len() as u8,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::InitializeOracleDirectory>(),
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{MasterInsuranceContract, Oracle, Policy, PolicyStatus, Treasury};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, POLICY_SEED};
+use crate::utils::clock_utils::now;
+use crate::events::{instruction_discriminator, OracleOutageObserved, OutageRefundClaimed};
+
+#[derive(Accounts)]
+pub struct RecordOracleOutage<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = matches!(policy.status, PolicyStatus::Active | PolicyStatus::PendingPayout)
+            @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+
+    /// Oracle the policy reads
+    #[account(address = policy.oracle_config.oracle_address @ InsuranceError::PolicyOracleMismatch)]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Anyone may run the crank
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimOutageRefund<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.status == PolicyStatus::Expired @ InsuranceError::PolicyNotExpired
+    )]
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub owner: Signer<'info>,
+
+    /// Owner's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Observe whether the policy's oracle can currently be claimed against,
+/// accruing the downtime the policy could not claim through; anyone may
+/// submit it
+///
+/// The oracle is unavailable while inactive, with its circuit breaker
+/// tripped, or once it has gone the consensus timeout without reporting. A
+/// timed-out outage is dated from when the timeout elapsed; others from
+/// this observation.
+pub fn record_oracle_outage(ctx: Context<RecordOracleOutage>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let oracle = ctx.accounts.oracle.load()?;
+    let timed_out_at = oracle.last_update_timestamp.saturating_add(master_contract.consensus_timeout_secs);
+    let timed_out = current_time > timed_out_at;
+    let unavailable = timed_out || !oracle.is_active() || oracle.health_metrics.circuit_breaker_active();
+    let outage_began = if timed_out { timed_out_at } else { current_time };
+
+    let outage_secs_added = policy.observe_oracle_outage(unavailable, outage_began, current_time);
+    policy.updated_at = current_time;
+
+    emit!(OracleOutageObserved {
+        policy_id: policy.id,
+        oracle: ctx.accounts.oracle.key(),
+        unavailable,
+        outage_secs_added,
+        oracle_outage_secs: policy.oracle_outage_secs,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RecordOracleOutage>(),
+    });
+
+    Ok(())
+}
+
+/// Refund the premium for the share of an expired policy's term its oracle
+/// was unavailable, once that share exceeds
+/// `GlobalConfig::outage_refund_threshold_bps`
+///
+/// The refund is paid back from the treasury like any other premium refund,
+/// and only once.
+pub fn claim_outage_refund(ctx: Context<ClaimOutageRefund>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let treasury = &mut ctx.accounts.treasury;
+    let current_time = now(master_contract)?;
+
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
+    require!(ctx.accounts.owner.key() == owner, InsuranceError::Unauthorized);
+    require!(!policy.outage_refunded, InsuranceError::OutageRefundClaimed);
+
+    let term_secs = policy.end_date - policy.start_date;
+    let threshold_bps = master_contract.global_config.outage_refund_threshold_bps;
+    let refund_amount =
+        siglab_core::outage_refund(policy.total_premiums_paid, policy.oracle_outage_secs, term_secs, threshold_bps)
+            .ok_or(InsuranceError::OutageBelowThreshold)?;
+
+    let currency = policy.premium_currency;
+    let treasury_balance_before = treasury.balance(currency);
+    policy.total_premiums_paid -= refund_amount;
+    policy.outage_refunded = true;
+    policy.updated_at = current_time;
+
+    treasury.record_refund(refund_amount, currency.is_usdc(), current_time);
+    treasury.withdrawal_count += 1;
+    master_contract
+        .portfolio_stats_mut(&policy.insurance_type)
+        .record_refund(refund_amount);
+
+    emit!(OutageRefundClaimed {
+        policy_id: policy.id,
+        owner,
+        oracle_outage_secs: policy.oracle_outage_secs,
+        term_secs,
+        threshold_bps,
+        refund_amount,
+        currency,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(currency),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ClaimOutageRefund>(),
+    });
+
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        current_time,
+        instruction_discriminator::<crate::instruction::ClaimOutageRefund>(),
+    );
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,291 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{
+    Endorsement, EndorsementKind, MasterInsuranceContract, OracleRetirement, Policy, PolicyHistory, PolicyStatus,
+    ProductTemplate, RetirementPlan,
+};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, ORACLE_RETIREMENT_SEED, POLICY_HISTORY_SEED};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{
+    instruction_discriminator, OracleReplacementSet, OracleRetirementClosed, PoliciesRepointed, PolicyCoverageResumed,
+    PolicyCoverageSuspended, PolicyEndorsed,
+};
+
+#[derive(Accounts)]
+pub struct RepointPolicies<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_RETIREMENT_SEED, oracle_retirement.oracle.as_ref()],
+        bump = oracle_retirement.bump
+    )]
+    pub oracle_retirement: Account<'info, OracleRetirement>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Anyone may run the crank
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetOracleReplacement<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_RETIREMENT_SEED, oracle_retirement.oracle.as_ref()],
+        bump = oracle_retirement.bump
+    )]
+    pub oracle_retirement: Account<'info, OracleRetirement>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseOracleRetirement<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [ORACLE_RETIREMENT_SEED, oracle_retirement.oracle.as_ref()],
+        bump = oracle_retirement.bump
+    )]
+    pub oracle_retirement: Account<'info, OracleRetirement>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+/// Apply a retirement's plan to the policies and templates passed as
+/// writable remaining accounts; anyone may submit it
+///
+/// Each policy is followed by its `PolicyHistory` account. Policies in force
+/// that still reference the retired oracle are re-pointed to the
+/// replacement, with an endorsement once their history is opened, and have
+/// any suspension lifted.
+/// Without a replacement their coverage is suspended instead. Templates are
+/// re-pointed, or deactivated without a replacement. Accounts that no longer
+/// reference the oracle, or policies no longer in force, are skipped, so
+/// batches may overlap.
+pub fn repoint_policies<'info>(ctx: Context<'_, '_, 'info, 'info, RepointPolicies<'info>>) -> Result<()> {
+    let oracle_retirement = &mut ctx.accounts.oracle_retirement;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    let retired = oracle_retirement.oracle;
+    let replacement = oracle_retirement.replacement();
+    let instruction = instruction_discriminator::<crate::instruction::RepointPolicies>();
+
+    let mut policies_repointed: u32 = 0;
+    let mut policies_suspended: u32 = 0;
+    let mut templates_affected: u32 = 0;
+
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(account_info) = accounts.next() {
+        require!(account_info.is_writable, InsuranceError::InvalidInput);
+        let is_policy = account_info.try_borrow_data()?.starts_with(Policy::DISCRIMINATOR);
+
+        if is_policy {
+            let mut policy = Account::<Policy>::try_from(account_info)?;
+            let history_info = accounts.next().ok_or(InsuranceError::InvalidInput)?;
+            let (history_key, _) =
+                Pubkey::find_program_address(&[POLICY_HISTORY_SEED, policy.key().as_ref()], &crate::ID);
+            require!(
+                history_info.key() == history_key && history_info.is_writable,
+                InsuranceError::InvalidInput
+            );
+            // Policies not yet activated may have no history to endorse
+            let mut history = if history_info.data_is_empty() {
+                None
+            } else {
+                Some(Account::<PolicyHistory>::try_from(history_info)?)
+            };
+            let in_force = matches!(
+                policy.status,
+                PolicyStatus::Active | PolicyStatus::PendingActivation | PolicyStatus::PendingPayout
+            );
+            if !in_force || policy.oracle_config.oracle_address != retired {
+                continue;
+            }
+
+            match replacement {
+                Some(replacement) => {
+                    if let Some(history) = history.as_mut() {
+                        let endorsement = history.endorse(
+                            EndorsementKind::OracleRepoint,
+                            retired.to_bytes(),
+                            replacement.to_bytes(),
+                            ctx.accounts.cranker.key(),
+                            current_time,
+                        )?;
+                        emit_endorsement(&policy, history, endorsement, master_contract, instruction);
+                        history.exit(&crate::ID)?;
+                    }
+                    policy.oracle_config.oracle_address = replacement;
+                    policy.updated_at = current_time;
+
+                    if let Some(suspended_at) = policy.coverage_suspended_at {
+                        let suspended_secs = policy.resume_coverage(current_time);
+                        emit!(PolicyCoverageResumed {
+                            policy_id: policy.id,
+                            oracle: replacement,
+                            suspended_at,
+                            suspended_secs,
+                            end_date: policy.end_date,
+                            timestamp: current_time,
+                            sequence: master_contract.next_event_sequence(),
+                            instruction,
+                        });
+                    }
+                    policies_repointed += 1;
+                }
+                None if policy.coverage_suspended_at.is_none() => {
+                    policy.suspend_coverage(current_time);
+                    emit!(PolicyCoverageSuspended {
+                        policy_id: policy.id,
+                        oracle: retired,
+                        timestamp: current_time,
+                        sequence: master_contract.next_event_sequence(),
+                        instruction,
+                    });
+                    policies_suspended += 1;
+                }
+                None => continue,
+            }
+            policy.exit(&crate::ID)?;
+        } else {
+            let mut template = Account::<ProductTemplate>::try_from(account_info)?;
+            if template.terms.oracle_config.oracle_address != retired {
+                continue;
+            }
+            match replacement {
+                Some(replacement) => template.terms.oracle_config.oracle_address = replacement,
+                None if template.active => template.active = false,
+                None => continue,
+            }
+            template.updated_at = current_time;
+            template.exit(&crate::ID)?;
+            templates_affected += 1;
+        }
+    }
+
+    oracle_retirement.policies_repointed = oracle_retirement.policies_repointed.saturating_add(policies_repointed);
+    oracle_retirement.policies_suspended = oracle_retirement.policies_suspended.saturating_add(policies_suspended);
+    oracle_retirement.templates_affected = oracle_retirement.templates_affected.saturating_add(templates_affected);
+
+    emit!(PoliciesRepointed {
+        oracle: retired,
+        replacement,
+        policies_repointed,
+        policies_suspended,
+        templates_affected,
+        total_policies_repointed: oracle_retirement.policies_repointed,
+        total_policies_suspended: oracle_retirement.policies_suspended,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+
+    msg!(
+        "Oracle {} retirement: {} policies re-pointed, {} suspended, {} templates updated",
+        retired,
+        policies_repointed,
+        policies_suspended,
+        templates_affected
+    );
+    Ok(())
+}
+
+fn emit_endorsement(
+    policy: &Policy,
+    history: &PolicyHistory,
+    endorsement: Endorsement,
+    master_contract: &mut MasterInsuranceContract,
+    instruction: [u8; 8],
+) {
+    emit!(PolicyEndorsed {
+        policy_id: policy.id,
+        kind: endorsement.kind,
+        before: endorsement.before,
+        after: endorsement.after,
+        actor: endorsement.actor,
+        endorsement_count: history.endorsements.len() as u8,
+        timestamp: endorsement.timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+}
+
+/// Name the replacement for an oracle retired with
+/// `RetirementPlan::SuspendCoverage`, so the crank can re-point and resume
+/// its suspended policies
+pub fn set_oracle_replacement(ctx: Context<SetOracleReplacement>, replacement: Pubkey) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let oracle_retirement = &mut ctx.accounts.oracle_retirement;
+    let master_contract = &mut ctx.accounts.master_contract;
+    require!(
+        oracle_retirement.plan == RetirementPlan::SuspendCoverage,
+        InsuranceError::InvalidInput
+    );
+    require!(
+        master_contract.oracle_registry.contains(&replacement),
+        InsuranceError::OracleNotRegistered
+    );
+
+    oracle_retirement.plan = RetirementPlan::Replace(replacement);
+
+    emit!(OracleReplacementSet {
+        oracle: oracle_retirement.oracle,
+        replacement,
+        admin: ctx.accounts.admin.key(),
+        policies_suspended: oracle_retirement.policies_suspended,
+        timestamp: now(master_contract)?,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetOracleReplacement>(),
+    });
+
+    Ok(())
+}
+
+/// Close a retirement record once the crank has covered every policy,
+/// returning its rent; needed before an oracle registered again under the
+/// same id can be unregistered
+pub fn close_oracle_retirement(ctx: Context<CloseOracleRetirement>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let oracle_retirement = &ctx.accounts.oracle_retirement;
+    let master_contract = &mut ctx.accounts.master_contract;
+
+    emit!(OracleRetirementClosed {
+        oracle: oracle_retirement.oracle,
+        admin: ctx.accounts.admin.key(),
+        policies_repointed: oracle_retirement.policies_repointed,
+        policies_suspended: oracle_retirement.policies_suspended,
+        templates_affected: oracle_retirement.templates_affected,
+        timestamp: now(master_contract)?,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CloseOracleRetirement>(),
+    });
+
+    Ok(())
+}
\ No newline at end of file
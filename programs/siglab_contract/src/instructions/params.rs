@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    InsuranceType, InsuranceTypeConfig, MasterInsuranceContract, ParamChange, ParamProposal, TypeConfigParams,
+};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, PARAM_PROPOSAL_SEED, TYPE_CONFIG_SEED};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::current_clock;
+use crate::events::{
+    instruction_discriminator, ParamChangeCancelled, ParamChangeExecuted, ParamChangeProposed,
+    TypeConfigInitialized,
+};
+
+#[derive(Accounts)]
+#[instruction(insurance_type: InsuranceType)]
+pub struct InitTypeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceTypeConfig::space(),
+        seeds = [TYPE_CONFIG_SEED, &insurance_type.seed()],
+        bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeParamChange<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ParamProposal::space(),
+        seeds = [PARAM_PROPOSAL_SEED, &master_contract.param_proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, ParamProposal>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteParamChange<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PARAM_PROPOSAL_SEED, &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ParamProposal>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Configuration changed by a `TypeConfig` proposal
+    #[account(
+        mut,
+        seeds = [TYPE_CONFIG_SEED, &type_config.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Option<Account<'info, InsuranceTypeConfig>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelParamChange<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PARAM_PROPOSAL_SEED, &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ParamProposal>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+/// Create the configuration of `insurance_type` with its default parameters
+pub fn init_type_config(ctx: Context<InitTypeConfig>, insurance_type: InsuranceType) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let params = TypeConfigParams::defaults(&insurance_type);
+    
+    let type_config = &mut ctx.accounts.type_config;
+    type_config.insurance_type = insurance_type.clone();
+    type_config.params = params.clone();
+    type_config.updated_at = clock.unix_timestamp;
+    type_config.bump = ctx.bumps.type_config;
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    emit!(TypeConfigInitialized {
+        admin: ctx.accounts.admin.key(),
+        insurance_type,
+        params,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::InitTypeConfig>(),
+    });
+    
+    Ok(())
+}
+
+/// Queue a parameter change, executable once the master contract's timelock has elapsed
+pub fn propose_param_change(ctx: Context<ProposeParamChange>, change: ParamChange) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    change.validate()?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(master_contract.param_timelock_secs)
+        .ok_or(InsuranceError::MathOverflow)?;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = master_contract.param_proposal_count;
+    proposal.change = change.clone();
+    proposal.proposer = ctx.accounts.admin.key();
+    proposal.proposed_at = clock.unix_timestamp;
+    proposal.executable_at = executable_at;
+    proposal.bump = ctx.bumps.proposal;
+    
+    master_contract.param_proposal_count += 1;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(ParamChangeProposed {
+        proposal_id: proposal.id,
+        admin: ctx.accounts.admin.key(),
+        change,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ProposeParamChange>(),
+    });
+    
+    Ok(())
+}
+
+/// Apply a proposed change whose timelock has elapsed and close the proposal
+pub fn execute_param_change(ctx: Context<ExecuteParamChange>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        proposal.is_executable(clock.unix_timestamp),
+        InsuranceError::ParamChangeTimelocked
+    );
+    
+    match &proposal.change {
+        ParamChange::TypeConfig { insurance_type, params } => {
+            let type_config = ctx
+                .accounts
+                .type_config
+                .as_mut()
+                .ok_or(InsuranceError::ParamChangeTargetMismatch)?;
+            require!(
+                type_config.insurance_type == *insurance_type,
+                InsuranceError::ParamChangeTargetMismatch
+            );
+            
+            type_config.params = params.clone();
+            type_config.updated_at = clock.unix_timestamp;
+        }
+        ParamChange::GlobalConfig(global_config) => {
+            ctx.accounts.master_contract.global_config = *global_config;
+        }
+        ParamChange::OracleRiskParams(risk_params) => {
+            ctx.accounts.master_contract.oracle_risk_params = *risk_params;
+        }
+        ParamChange::RegionLimits(region_limits) => {
+            ctx.accounts.master_contract.region_limits = *region_limits;
+        }
+        ParamChange::BeginWinddown => {
+            ctx.accounts.master_contract.winddown = true;
+        }
+    }
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(ParamChangeExecuted {
+        proposal_id: proposal.id,
+        admin: ctx.accounts.admin.key(),
+        change: proposal.change.clone(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ExecuteParamChange>(),
+    });
+    
+    Ok(())
+}
+
+/// Withdraw a proposed change before it is executed
+pub fn cancel_param_change(ctx: Context<CancelParamChange>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let master_contract = &mut ctx.accounts.master_contract;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(ParamChangeCancelled {
+        proposal_id: ctx.accounts.proposal.id,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CancelParamChange>(),
+    });
+    
+    Ok(())
+}
\ No newline at end of file
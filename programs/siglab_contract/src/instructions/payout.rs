@@ -1,76 +1,519 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::{
-    OracleData, Policy, PolicyStatus, PendingPayout, PayoutStatus, PayoutCalculationData,
-    MasterInsuranceContract, Oracle, ComparisonOperator
+    Policy, PolicyHistory, PolicyStatus, PayoutRecord, PendingPayout, PayoutStatus, PayoutCalculationData,
+    MasterInsuranceContract, Oracle, CompoundTrigger, BreachWatch, PayoutAssessment,
+    PayoutIneligibility, OracleFault, OracleExclusionReason, Treasury, ConsensusSnapshot, ConsensusFallbackMode, ConsensusRoundStatus,
+    InsuranceTypeConfig, TypeConfigParams, ClaimRejection, VestingPayout, ObservationProof, RegionExposure,
+    OracleConfig, CurrencyAmount, TokenType,
+};
+use crate::constants::{
+    BREACH_WATCH_SEED, CONSENSUS_DISPUTE_MIN_COVERAGE, CONSENSUS_SEED, MASTER_CONTRACT_SEED,
+    MAX_VESTING_CANCEL_REASON_LENGTH, PENDING_PAYOUT_SEED, POLICY_HISTORY_SEED, POLICY_SEED, REGION_EXPOSURE_SEED,
+    TYPE_CONFIG_SEED, VESTING_PAYOUT_SEED,
 };
 use crate::error::InsuranceError;
-use crate::events::{PayoutTriggered};
+use crate::utils::clock_utils::{current_clock, now};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::require_not_paused;
+use crate::events::{
+    instruction_discriminator, ClaimFiled, ClaimRejected, ConsensusDegraded, PayoutTrancheReleased,
+    PayoutTriggered, PayoutVestingStarted, VestingPayoutCancelled,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(policy_id: String)]
+#[instruction(policy_id: u64)]
 pub struct TriggerPayout<'info> {
     #[account(
         mut,
-        seeds = [b"policy", policy_id.as_bytes()],
-        bump,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == policy_id @ InsuranceError::PolicyNotFound,
+        constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive,
+        constraint = policy.coverage_suspended_at.is_none() @ InsuranceError::CoverageSuspended
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Policy's history, recording the payout filed
+    #[account(
+        mut,
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(
+        init,
+        payer = rent_payer,
+        space = PendingPayout::space(),
+        seeds = [PENDING_PAYOUT_SEED, &policy_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.treasury_account != Pubkey::default() @ InsuranceError::TreasuryNotLinked
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury that will fund the payout, releasing the exposure of a policy
+    /// found past its end date
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Configuration of the policy's insurance type
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &policy.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
+    /// CHECK: The policy's beneficial owner; signs unless a keeper files for
+    /// a holder who enabled automatic claims
+    #[account(mut)]
+    pub beneficiary: AccountInfo<'info>,
+    
+    /// Pays the payout's rent, refunded to it when the payout closes (may be
+    /// the beneficiary, or the keeper filing for it)
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    
+    /// Policy token mint (required for tokenized policies)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, frozen while the payout is pending
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// Breach observation consumed when the policy has a grace period
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
+        bump = breach_watch.bump
+    )]
+    pub breach_watch: Option<Account<'info, BreachWatch>>,
+    
+    /// Consensus round supplying the trigger value, when one is referenced
+    #[account(
+        seeds = [CONSENSUS_SEED, &consensus_snapshot.round.to_le_bytes()],
+        bump = consensus_snapshot.bump
+    )]
+    pub consensus_snapshot: Option<Account<'info, ConsensusSnapshot>>,
+    
+    /// Snapshot of the policy's oracle the trigger value is drawn from, kept as
+    /// the payout's evidence instead of a copy of the reading
+    #[account(
+        constraint = observation_proof.oracle == policy.oracle_config.oracle_address
+            @ InsuranceError::ObservationProofMismatch
+    )]
+    pub observation_proof: Option<Account<'info, ObservationProof>>,
+    
+    /// Region the coverage is booked in, released if the policy is found expired
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+    
+    /// CHECK: Instructions sysvar, read to find the oracle quorum attestation
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(policy_id: u64)]
+pub struct FileClaim<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == policy_id @ InsuranceError::PolicyNotFound,
         constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive,
-        constraint = policy.end_date > Clock::get()?.unix_timestamp @ InsuranceError::PolicyExpired
+        constraint = policy.coverage_suspended_at.is_none() @ InsuranceError::CoverageSuspended,
+        constraint = policy.end_date > now(&master_contract)? @ InsuranceError::PolicyExpired
     )]
     pub policy: Account<'info, Policy>,
     
+    /// Policy's history, checked for a repeated incident
+    #[account(
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
     #[account(
         init,
         payer = beneficiary,
         space = PendingPayout::space(),
-        seeds = [b"pending_payout", policy_id.as_bytes()],
+        seeds = [PENDING_PAYOUT_SEED, &policy_id.to_le_bytes()],
         bump
     )]
     pub pending_payout: Account<'info, PendingPayout>,
     
     #[account(
-        constraint = master_contract.treasury_account != Pubkey::default() @ InsuranceError::InvalidAdminOperation
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.treasury_account != Pubkey::default() @ InsuranceError::TreasuryNotLinked
     )]
     pub master_contract: Account<'info, MasterInsuranceContract>,
     
+    /// Configuration of the policy's insurance type
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &policy.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
     #[account(mut)]
     pub beneficiary: Signer<'info>,
     
+    /// Policy token mint (required for tokenized policies)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, frozen while the claim is open
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ExecutePayout<'info> {
+pub struct EvaluateClaim<'info> {
     #[account(
         mut,
-        close = beneficiary,
-        constraint = pending_payout.status == PayoutStatus::Ready @ InsuranceError::PayoutConditionsNotMet,
-        constraint = pending_payout.beneficiary == beneficiary.key() @ InsuranceError::Unauthorized
+        seeds = [PENDING_PAYOUT_SEED, &pending_payout.policy_id.to_le_bytes()],
+        bump = pending_payout.bump,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch,
+        constraint = pending_payout.status == PayoutStatus::Pending @ InsuranceError::PayoutConditionsNotMet
     )]
     pub pending_payout: Account<'info, PendingPayout>,
     
     #[account(
         mut,
-        seeds = [b"policy", pending_payout.policy_id.as_bytes()],
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == pending_payout.policy_id @ InsuranceError::PolicyNotFound,
+        constraint = policy.status == PolicyStatus::PendingPayout @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Policy's history, recording the payout once assessed
+    #[account(
+        mut,
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury that will fund the payout, earmarking its amount once assessed
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Configuration of the policy's insurance type
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &policy.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
+    /// CHECK: Receives the breach watch rent; validated against the stored beneficiary
+    #[account(
+        mut,
+        address = pending_payout.beneficiary @ InsuranceError::Unauthorized
+    )]
+    pub beneficiary: AccountInfo<'info>,
+    
+    /// CHECK: Receives the claim rent on rejection; validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    /// Policy token mint (required for tokenized policies)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, thawed if the claim is rejected
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// Breach observation consumed when the policy has a grace period
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
+        bump = breach_watch.bump
+    )]
+    pub breach_watch: Option<Account<'info, BreachWatch>>,
+    
+    /// Consensus round supplying the trigger value, when one is referenced
+    #[account(
+        seeds = [CONSENSUS_SEED, &consensus_snapshot.round.to_le_bytes()],
+        bump = consensus_snapshot.bump
+    )]
+    pub consensus_snapshot: Option<Account<'info, ConsensusSnapshot>>,
+    
+    /// Snapshot of the policy's oracle the trigger value is drawn from, kept as
+    /// the payout's evidence instead of a copy of the reading
+    #[account(
+        constraint = observation_proof.oracle == policy.oracle_config.oracle_address
+            @ InsuranceError::ObservationProofMismatch
+    )]
+    pub observation_proof: Option<Account<'info, ObservationProof>>,
+    
+    /// CHECK: Instructions sysvar, read to find the oracle quorum attestation
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ObserveBreach<'info> {
+    #[account(
+        constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive,
+        constraint = policy.coverage_suspended_at.is_none() @ InsuranceError::CoverageSuspended,
+        constraint = policy.end_date > now(&master_contract)? @ InsuranceError::PolicyExpired
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        init,
+        payer = observer,
+        space = BreachWatch::space(),
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
         bump
     )]
+    pub breach_watch: Account<'info, BreachWatch>,
+    
+    #[account(mut)]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub observer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClearBreachWatch<'info> {
     pub policy: Account<'info, Policy>,
     
+    #[account(
+        mut,
+        close = observer,
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
+        bump = breach_watch.bump
+    )]
+    pub breach_watch: Account<'info, BreachWatch>,
+    
     #[account(mut)]
     pub master_contract: Account<'info, MasterInsuranceContract>,
     
-    /// CHECK: Treasury account for payout transfer
+    /// CHECK: Receives the watch rent; validated against the stored observer
+    #[account(
+        mut,
+        address = breach_watch.observer @ InsuranceError::Unauthorized
+    )]
+    pub observer: AccountInfo<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecutePayout<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch,
+        constraint = pending_payout.status == PayoutStatus::Ready @ InsuranceError::PayoutConditionsNotMet
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
     #[account(
         mut,
-        constraint = treasury_account.key() == master_contract.treasury_account @ InsuranceError::InvalidAdminOperation
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == pending_payout.policy_id @ InsuranceError::PolicyNotFound
     )]
-    pub treasury_account: AccountInfo<'info>,
+    pub policy: Account<'info, Policy>,
+    
+    /// Policy's history, noting the destination rent paid
+    #[account(
+        mut,
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
     
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.treasury_account != Pubkey::default() @ InsuranceError::TreasuryNotLinked
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury funding the payout; its balances are updated with the transfer
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Receives the payout; validated against the stored beneficiary
+    #[account(
+        mut,
+        address = pending_payout.beneficiary @ InsuranceError::Unauthorized
+    )]
+    pub beneficiary: AccountInfo<'info>,
+    
+    /// CHECK: Receives the payout rent; validated against the stored rent payer
     #[account(mut)]
-    pub beneficiary: Signer<'info>,
+    pub rent_payer: AccountInfo<'info>,
+    
+    /// Anyone may execute a ready payout; third parties earn the crank tip
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    /// Tranche schedule the payout becomes, paid for by the executor
+    /// (required exactly when the policy vests payouts)
+    #[account(
+        init,
+        payer = executor,
+        space = VestingPayout::SPACE,
+        seeds = [VESTING_PAYOUT_SEED, &pending_payout.policy_id.to_le_bytes()],
+        bump
+    )]
+    pub vesting_payout: Option<Account<'info, VestingPayout>>,
+    
+    /// Region the payout is counted in (required for tracked policies, and
+    /// for any policy in a region while a daily payout cap is set)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+    
+    /// Treasury's USDC token account (required for USDC payouts)
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Beneficiary's USDC associated token account (required for USDC
+    /// payouts); created with the executor paying its rent when missing
+    #[account(
+        mut,
+        address = get_associated_token_address(&beneficiary.key(), &treasury.usdc_mint)
+            @ InsuranceError::InvalidPayoutDestination
+    )]
+    pub beneficiary_usdc_account: Option<UncheckedAccount<'info>>,
+    
+    /// Treasury's USDC mint (required for USDC payouts)
+    #[account(address = treasury.usdc_mint @ InsuranceError::TreasuryAccountMismatch)]
+    pub usdc_mint: Option<Account<'info, Mint>>,
+    
+    /// Policy token mint (required for tokenized policies the payout leaves in force)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, thawed if the policy stays in force
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpirePayout<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch,
+        constraint = matches!(
+            pending_payout.status,
+            PayoutStatus::PendingApproval | PayoutStatus::Ready
+        ) @ InsuranceError::PayoutConditionsNotMet
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == pending_payout.policy_id @ InsuranceError::PolicyNotFound
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury holding the payout's earmark, released on expiry
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Receives the payout rent; validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    /// Policy token mint (required for tokenized policies)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, frozen while the payout was pending
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ApprovePayout<'info> {
     #[account(
@@ -79,137 +522,1412 @@ pub struct ApprovePayout<'info> {
     )]
     pub pending_payout: Account<'info, PendingPayout>,
     
-    #[account(
-        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
-    )]
-    pub master_contract: Account<'info, MasterInsuranceContract>,
+    #[account(
+        mut,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RejectPayout<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [PENDING_PAYOUT_SEED, &pending_payout.policy_id.to_le_bytes()],
+        bump = pending_payout.bump,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch,
+        constraint = pending_payout.status == PayoutStatus::PendingApproval @ InsuranceError::PayoutConditionsNotMet
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.id == pending_payout.policy_id @ InsuranceError::PolicyNotFound
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury holding the payout's earmark, released on rejection
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Receives the payout rent; validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Policy token mint (required for tokenized policies)
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Beneficiary's policy token account, frozen while the payout was pending
+    #[account(mut)]
+    pub beneficiary_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleasePayoutTranche<'info> {
+    #[account(
+        mut,
+        seeds = [VESTING_PAYOUT_SEED, &vesting_payout.policy_id.to_le_bytes()],
+        bump = vesting_payout.bump,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch
+    )]
+    pub vesting_payout: Account<'info, VestingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury funding the tranche and holding the rest in reserve
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Receives the tranche; validated against the stored beneficiary
+    #[account(
+        mut,
+        address = vesting_payout.beneficiary @ InsuranceError::Unauthorized
+    )]
+    pub beneficiary: AccountInfo<'info>,
+    
+    /// CHECK: Receives the vesting payout's rent after the last tranche;
+    /// validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    /// Anyone may release a due tranche; third parties earn the crank tip
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    /// Treasury's USDC token account (required for USDC payouts)
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Beneficiary's USDC token account (required for USDC payouts)
+    #[account(
+        mut,
+        token::mint = treasury.usdc_mint,
+        token::authority = beneficiary
+    )]
+    pub beneficiary_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelVestingPayout<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [VESTING_PAYOUT_SEED, &vesting_payout.policy_id.to_le_bytes()],
+        bump = vesting_payout.bump,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch
+    )]
+    pub vesting_payout: Account<'info, VestingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury whose reservation of the unreleased tranches is returned
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Receives the vesting payout's rent; validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecomputePriority<'info> {
+    #[account(
+        mut,
+        constraint = matches!(
+            pending_payout.status,
+            PayoutStatus::Pending | PayoutStatus::PendingApproval | PayoutStatus::Ready
+        ) @ InsuranceError::PayoutConditionsNotMet
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SimulatePayout<'info> {
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury whose currencies the payout floor is converted between
+    #[account(address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch)]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Breach observation, considered when the policy has a grace period
+    #[account(
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
+        bump = breach_watch.bump
+    )]
+    pub breach_watch: Option<Account<'info, BreachWatch>>,
+}
+
+pub fn trigger_payout(
+    ctx: Context<TriggerPayout>,
+    policy_id: u64,
+    oracle_value: u64,
+    attested_at: i64,
+    consensus_round: Option<u64>,
+    incident_timestamp: i64,
+) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    require_not_paused!(ctx.accounts.master_contract.is_paused);
+    
+    // A keeper may file for a holder who enabled automatic claims, fronting
+    // the payout's rent, but only on oracle evidence and never on a value of
+    // its own
+    let keeper = (!ctx.accounts.beneficiary.is_signer).then(|| ctx.accounts.rent_payer.key());
+    if let Some(keeper) = keeper {
+        let policy = &ctx.accounts.policy;
+        require!(policy.auto_claim, InsuranceError::AutoClaimNotEnabled);
+        require!(policy.accepts_claim_keeper(&keeper), InsuranceError::Unauthorized);
+        require!(
+            policy.trigger_conditions.compound.is_some()
+                || consensus_round.is_some()
+                || policy.quorum_signatures > 0
+                || ctx.accounts.observation_proof.is_some(),
+            InsuranceError::Unauthorized
+        );
+    }
+    
+    // A policy found past its end date is expired instead, returning the
+    // payout account's rent
+    if clock.unix_timestamp > ctx.accounts.policy.end_date {
+        let coverage_released = crate::instructions::policy::expire_overdue_policy(
+            &mut ctx.accounts.policy,
+            &mut ctx.accounts.master_contract,
+            &mut ctx.accounts.treasury,
+            ctx.accounts.region_exposure.as_mut(),
+            clock.unix_timestamp,
+            instruction_discriminator::<crate::instruction::TriggerPayout>(),
+        )?;
+        ctx.accounts.pending_payout.close(ctx.accounts.rent_payer.to_account_info())?;
+        
+        emit_cpi!(crate::events::PolicyExpired {
+            policy_id,
+            coverage_released,
+            expired_by: ctx.accounts.beneficiary.key(),
+            timestamp: clock.unix_timestamp,
+            sequence: ctx.accounts.master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::TriggerPayout>(),
+        });
+        crate::instructions::treasury::emit_reserve_transition(
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.master_contract,
+            clock.unix_timestamp,
+            instruction_discriminator::<crate::instruction::TriggerPayout>(),
+        );
+        return Ok(());
+    }
+    
+    // Every oracle the trigger reads or is attested by must be fit for consensus,
+    // and timed-out oracles halt triggers when the fallback mode says so
+    check_trigger_oracles(&ctx.accounts.master_contract, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    // A referenced consensus round supplies the value instead of the caller
+    let mut trigger_value = resolve_trigger_value(
+        &ctx.accounts.policy,
+        &mut ctx.accounts.master_contract,
+        ctx.accounts.consensus_snapshot.as_deref(),
+        consensus_round,
+        oracle_value,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::TriggerPayout>(),
+    )?;
+    if let Some(degraded) = trigger_value.degraded.take() {
+        emit_cpi!(degraded);
+    }
+    let oracle_value = trigger_value.value;
+    
+    // Payout receipt follows the policy's beneficial owner; its token is frozen while pending
+    freeze_for_payout(
+        &ctx.accounts.policy,
+        &ctx.accounts.master_contract,
+        &ctx.accounts.beneficiary.key(),
+        ctx.accounts.policy_mint.as_ref(),
+        ctx.accounts.beneficiary_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    let assessment = assess_payout(
+        &ctx.accounts.policy,
+        oracle_value,
+        ctx.remaining_accounts,
+        &ctx.accounts.master_contract,
+        &ctx.accounts.treasury,
+        ctx.accounts.breach_watch.as_deref(),
+        clock.unix_timestamp,
+    )?;
+    if let Some(reason) = assessment.ineligibility {
+        return Err(reason.error().into());
+    }
+    
+    let evidence = verify_trigger_evidence(
+        &ctx.accounts.policy,
+        &ctx.accounts.policy_history,
+        &ctx.accounts.type_config.params,
+        &ctx.accounts.master_contract,
+        &ctx.accounts.instructions_sysvar,
+        ctx.remaining_accounts,
+        policy_id,
+        &trigger_value,
+        attested_at,
+        incident_timestamp,
+        clock.unix_timestamp,
+    )?;
+    
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    open_payout(
+        pending_payout,
+        policy_id,
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.rent_payer.key(),
+        ctx.bumps.pending_payout,
+        &ctx.accounts.type_config.params,
+        incident_timestamp,
+        clock.unix_timestamp,
+    );
+    let payout_key = pending_payout.key();
+    apply_assessment(
+        pending_payout,
+        payout_key,
+        &ctx.accounts.policy,
+        &mut ctx.accounts.policy_history,
+        &mut ctx.accounts.treasury,
+        &ctx.accounts.type_config.params,
+        &assessment,
+        oracle_value,
+        evidence,
+        ctx.accounts.observation_proof.as_ref(),
+        clock.unix_timestamp,
+    )?;
+    
+    // Update policy status
+    ctx.accounts.policy.transition(PolicyStatus::PendingPayout, clock.unix_timestamp)?;
+    ctx.accounts.master_contract.record_payout_opened()?;
+    ctx.accounts
+        .master_contract
+        .portfolio_stats_mut(&ctx.accounts.policy.insurance_type)
+        .record_claim()?;
+    
+    // Emit event
+    emit_cpi!(PayoutTriggered {
+        policy_id,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        keeper,
+        amount: assessment.amount,
+        oracle_value,
+        base_priority: ctx.accounts.type_config.params.base_priority,
+        priority: ctx.accounts.pending_payout.priority,
+        incident_timestamp,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::TriggerPayout>(),
+    });
+    
+    Ok(())
+}
+
+/// File a claim for `incident_timestamp` before the decisive oracle data exists
+///
+/// The claim holds the policy in `PendingPayout` with a zero amount until
+/// `evaluate_claim` assesses it. Evidence must arrive before the claim's
+/// `expires_at`: the incident tolerance plus the policy's staleness threshold
+/// after the incident.
+pub fn file_claim(ctx: Context<FileClaim>, policy_id: u64, incident_timestamp: i64) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let policy = &ctx.accounts.policy;
+    let type_params = &ctx.accounts.type_config.params;
+    
+    validate_incident_coverage(policy, incident_timestamp, clock.unix_timestamp)?;
+    let fingerprint = incident_fingerprint(
+        &policy.oracle_config.data_feed_id,
+        incident_timestamp,
+        type_params.min_incident_gap_secs,
+    );
+    require!(
+        !ctx.accounts.policy_history.repeats_incident(
+            &fingerprint,
+            incident_timestamp,
+            type_params.min_incident_gap_secs,
+        ),
+        InsuranceError::DuplicateIncident
+    );
+    
+    freeze_for_payout(
+        policy,
+        &ctx.accounts.master_contract,
+        &ctx.accounts.beneficiary.key(),
+        ctx.accounts.policy_mint.as_ref(),
+        ctx.accounts.beneficiary_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    let evidence_deadline = incident_timestamp
+        .saturating_add(type_params.incident_tolerance_secs)
+        .saturating_add(policy.oracle_config.staleness_threshold);
+    open_payout(
+        &mut ctx.accounts.pending_payout,
+        policy_id,
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.beneficiary.key(),
+        ctx.bumps.pending_payout,
+        type_params,
+        incident_timestamp,
+        clock.unix_timestamp,
+    );
+    ctx.accounts.pending_payout.expires_at = evidence_deadline;
+    
+    ctx.accounts.policy.transition(PolicyStatus::PendingPayout, clock.unix_timestamp)?;
+    ctx.accounts.master_contract.record_payout_opened()?;
+    ctx.accounts
+        .master_contract
+        .portfolio_stats_mut(&ctx.accounts.policy.insurance_type)
+        .record_claim()?;
+    
+    emit_cpi!(ClaimFiled {
+        policy_id,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        incident_timestamp,
+        evidence_deadline,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::FileClaim>(),
+    });
+    
+    Ok(())
+}
+
+/// Assess a filed claim once oracle data for its incident window exists
+///
+/// Anyone may crank a claim whose evidence is authenticated (compound feeds,
+/// a consensus round or an oracle quorum); a caller-supplied value is only
+/// accepted from the beneficiary. Missing or stale evidence fails and leaves
+/// the claim pending. A claim whose trigger is not met, or whose evidence
+/// deadline passed, is rejected: the policy returns to coverage and the
+/// claim's rent goes back to its rent payer. Expiry of an accepted claim
+/// runs from evaluation.
+pub fn evaluate_claim(
+    ctx: Context<EvaluateClaim>,
+    oracle_value: u64,
+    attested_at: i64,
+    consensus_round: Option<u64>,
+) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let incident_timestamp = ctx.accounts.pending_payout.incident_timestamp;
+    
+    let rejection = if ctx.accounts.pending_payout.is_expired(clock.unix_timestamp) {
+        Some(ClaimRejection::EvidenceDeadlinePassed)
+    } else {
+        check_trigger_oracles(&ctx.accounts.master_contract, ctx.remaining_accounts, clock.unix_timestamp)?;
+        
+        let policy = &ctx.accounts.policy;
+        require!(
+            policy.trigger_conditions.compound.is_some()
+                || consensus_round.is_some()
+                || policy.quorum_signatures > 0
+                || ctx.accounts.cranker.key() == ctx.accounts.pending_payout.beneficiary,
+            InsuranceError::Unauthorized
+        );
+        
+        let mut trigger_value = resolve_trigger_value(
+            &ctx.accounts.policy,
+            &mut ctx.accounts.master_contract,
+            ctx.accounts.consensus_snapshot.as_deref(),
+            consensus_round,
+            oracle_value,
+            clock.unix_timestamp,
+            instruction_discriminator::<crate::instruction::EvaluateClaim>(),
+        )?;
+        if let Some(degraded) = trigger_value.degraded.take() {
+            emit_cpi!(degraded);
+        }
+        
+        // Eligibility was settled at filing; only the trigger is assessed now
+        let assessment = assess_trigger(
+            &ctx.accounts.policy,
+            trigger_value.value,
+            ctx.remaining_accounts,
+            &ctx.accounts.master_contract,
+            &ctx.accounts.treasury,
+            ctx.accounts.breach_watch.as_deref(),
+            clock.unix_timestamp,
+        )?;
+        match assessment.ineligibility {
+            Some(PayoutIneligibility::GracePeriodNotElapsed) => {
+                return Err(InsuranceError::GracePeriodNotElapsed.into());
+            }
+            Some(reason) => Some(ClaimRejection::Ineligible(reason)),
+            None => {
+                let evidence = verify_trigger_evidence(
+                    &ctx.accounts.policy,
+                    &ctx.accounts.policy_history,
+                    &ctx.accounts.type_config.params,
+                    &ctx.accounts.master_contract,
+                    &ctx.accounts.instructions_sysvar,
+                    ctx.remaining_accounts,
+                    ctx.accounts.pending_payout.policy_id,
+                    &trigger_value,
+                    attested_at,
+                    incident_timestamp,
+                    clock.unix_timestamp,
+                )?;
+                let payout_key = ctx.accounts.pending_payout.key();
+                apply_assessment(
+                    &mut ctx.accounts.pending_payout,
+                    payout_key,
+                    &ctx.accounts.policy,
+                    &mut ctx.accounts.policy_history,
+                    &mut ctx.accounts.treasury,
+                    &ctx.accounts.type_config.params,
+                    &assessment,
+                    trigger_value.value,
+                    evidence,
+                    ctx.accounts.observation_proof.as_ref(),
+                    clock.unix_timestamp,
+                )?;
+                
+                emit_cpi!(PayoutTriggered {
+                    policy_id: ctx.accounts.pending_payout.policy_id,
+                    beneficiary: ctx.accounts.pending_payout.beneficiary,
+                    keeper: None,
+                    amount: assessment.amount,
+                    oracle_value: trigger_value.value,
+                    base_priority: ctx.accounts.type_config.params.base_priority,
+                    priority: ctx.accounts.pending_payout.priority,
+                    incident_timestamp,
+                    timestamp: clock.unix_timestamp,
+                    sequence: ctx.accounts.master_contract.next_event_sequence(),
+                    instruction: instruction_discriminator::<crate::instruction::EvaluateClaim>(),
+                });
+                None
+            }
+        }
+    };
+    
+    let Some(reason) = rejection else {
+        return Ok(());
+    };
+    
+    // Release the policy token and return the policy to coverage
+    thaw_after_payout(
+        &ctx.accounts.policy,
+        &ctx.accounts.master_contract,
+        ctx.accounts.policy_mint.as_ref(),
+        ctx.accounts.beneficiary_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    ctx.accounts.policy.transition(PolicyStatus::Active, clock.unix_timestamp)?;
+    ctx.accounts.pending_payout.transition(PayoutStatus::Rejected)?;
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    master_contract.record_payout_closed();
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit_cpi!(ClaimRejected {
+        policy_id: ctx.accounts.pending_payout.policy_id,
+        beneficiary: ctx.accounts.pending_payout.beneficiary,
+        incident_timestamp,
+        reason,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::EvaluateClaim>(),
+    });
+    
+    ctx.accounts.pending_payout.close(ctx.accounts.rent_payer.to_account_info())
+}
+
+/// Fail unless every oracle read is fit for consensus and, under
+/// `HaltPayouts`, none has timed out
+fn check_trigger_oracles(
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> Result<()> {
+    match trigger_oracle_fault(master_contract, oracle_accounts, current_timestamp)? {
+        Some(fault) => Err(fault.error().into()),
+        None => Ok(()),
+    }
+}
+
+/// Why the oracles a trigger reads keep it from being filed, if they do.
+/// Shared by `trigger_payout` and `check_claim_eligibility`.
+pub(crate) fn trigger_oracle_fault(
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> Result<Option<OracleFault>> {
+    let health =
+        crate::instructions::oracle::oracle_system_health(master_contract, oracle_accounts, current_timestamp)?;
+    if !health.is_sufficient(None) {
+        let tripped = health
+            .exclusions
+            .iter()
+            .any(|(_, reason, _)| reason == OracleExclusionReason::CircuitBreaker);
+        return Ok(Some(if tripped { OracleFault::CircuitBreaker } else { OracleFault::Unhealthy }));
+    }
+    
+    if master_contract.consensus_fallback == ConsensusFallbackMode::HaltPayouts
+        && crate::instructions::oracle::check_consensus_timeout(
+            oracle_accounts,
+            master_contract.consensus_timeout_secs,
+            current_timestamp,
+        )?
+    {
+        return Ok(Some(OracleFault::TimedOut));
+    }
+    
+    Ok(None)
+}
+
+/// Value a trigger is assessed against and where it came from
+struct TriggerValue {
+    value: u64,
+    /// Consensus timestamp of the referenced round, evidence for the incident window
+    round_timestamp: Option<i64>,
+    /// Emitted when a stale round stood in under the `UseLastConsensus` fallback
+    degraded: Option<ConsensusDegraded>,
+}
+
+/// Take the value from the referenced consensus round, or `oracle_value` when none is
+fn resolve_trigger_value(
+    policy: &Policy,
+    master_contract: &mut MasterInsuranceContract,
+    consensus_snapshot: Option<&ConsensusSnapshot>,
+    consensus_round: Option<u64>,
+    oracle_value: u64,
+    current_timestamp: i64,
+    instruction: [u8; 8],
+) -> Result<TriggerValue> {
+    let Some(round) = consensus_round else {
+        return Ok(TriggerValue { value: oracle_value, round_timestamp: None, degraded: None });
+    };
+    
+    let snapshot = consensus_snapshot.ok_or(InsuranceError::ConsensusRoundMismatch)?;
+    require!(snapshot.round == round, InsuranceError::ConsensusRoundMismatch);
+    
+    // Large policies wait out the dispute window; disputed rounds are never used
+    match snapshot.status(current_timestamp) {
+        ConsensusRoundStatus::Disputed => {
+            return Err(InsuranceError::ConsensusRoundDisputed.into());
+        }
+        ConsensusRoundStatus::Pending if policy.coverage_amount >= CONSENSUS_DISPUTE_MIN_COVERAGE => {
+            return Err(InsuranceError::ConsensusRoundNotFinal.into());
+        }
+        _ => {}
+    }
+    
+    let mut degraded = None;
+    if !snapshot.is_fresh(policy.oracle_config.staleness_threshold, current_timestamp) {
+        // Only the latest round may stand in for a fresh one
+        let fallback = master_contract.consensus_fallback;
+        let latest_round = master_contract.consensus_round.checked_sub(1);
+        match fallback {
+            ConsensusFallbackMode::UseLastConsensus { max_age }
+                if latest_round == Some(round) && snapshot.is_fresh(max_age, current_timestamp) =>
+            {
+                degraded = Some(ConsensusDegraded {
+                    round,
+                    fallback,
+                    required_oracles: snapshot.consensus.oracle_count,
+                    consensus_age: current_timestamp - snapshot.consensus.consensus_timestamp,
+                    timestamp: current_timestamp,
+                    sequence: master_contract.next_event_sequence(),
+                    instruction,
+                });
+            }
+            _ => return Err(InsuranceError::ConsensusRoundStale.into()),
+        }
+    }
+    
+    Ok(TriggerValue {
+        value: snapshot.settled_value(),
+        round_timestamp: Some(snapshot.consensus.consensus_timestamp),
+        degraded,
+    })
+}
+
+/// Require `beneficiary` to be the policy's beneficial owner, freezing the
+/// policy token of tokenized policies so it cannot move while the payout is pending
+fn freeze_for_payout<'info>(
+    policy: &Policy,
+    master_contract: &Account<'info, MasterInsuranceContract>,
+    beneficiary: &Pubkey,
+    policy_mint: Option<&Account<'info, Mint>>,
+    beneficiary_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+) -> Result<()> {
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(policy, beneficiary_token_account)?;
+    require!(*beneficiary == owner, InsuranceError::Unauthorized);
+    
+    if let Some(mint) = policy.policy_mint {
+        let (policy_mint, beneficiary_token_account, token_program) =
+            match (policy_mint, beneficiary_token_account, token_program) {
+                (Some(m), Some(t), Some(p)) => (m, t, p),
+                _ => return Err(InsuranceError::PolicyTokenAccountRequired.into()),
+            };
+        require!(
+            policy_mint.key() == mint,
+            InsuranceError::InvalidPolicyTokenAccount
+        );
+        
+        crate::instructions::policy_token::freeze_policy_token(
+            master_contract,
+            policy_mint,
+            beneficiary_token_account,
+            token_program,
+        )?;
+    }
+    
+    Ok(())
+}
+
+/// Thaw the policy token of a tokenized policy whose pending payout closed
+/// without paying, so the holder can claim or transfer it again
+fn thaw_after_payout<'info>(
+    policy: &Policy,
+    master_contract: &Account<'info, MasterInsuranceContract>,
+    policy_mint: Option<&Account<'info, Mint>>,
+    beneficiary_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+) -> Result<()> {
+    if let Some(mint) = policy.policy_mint {
+        let (policy_mint, beneficiary_token_account, token_program) =
+            match (policy_mint, beneficiary_token_account, token_program) {
+                (Some(m), Some(t), Some(p)) => (m, t, p),
+                _ => return Err(InsuranceError::PolicyTokenAccountRequired.into()),
+            };
+        require!(
+            policy_mint.key() == mint,
+            InsuranceError::InvalidPolicyTokenAccount
+        );
+        
+        if beneficiary_token_account.is_frozen() {
+            crate::instructions::policy_token::thaw_policy_token(
+                master_contract,
+                policy_mint,
+                beneficiary_token_account,
+                token_program,
+            )?;
+        }
+    }
+    
+    Ok(())
+}
+
+/// Evidence binding an assessed trigger to its incident
+struct TriggerEvidence {
+    fingerprint: [u8; 32],
+    /// Oracle authorities whose co-signatures attested the trigger
+    quorum_signers: Vec<Pubkey>,
+}
+
+/// Check the evidence describes the claimed incident, that the incident was not
+/// claimed before and that any required oracle quorum attested it, then pay
+/// the oracles whose data the trigger consumed
+#[allow(clippy::too_many_arguments)]
+fn verify_trigger_evidence(
+    policy: &Policy,
+    policy_history: &PolicyHistory,
+    type_params: &TypeConfigParams,
+    master_contract: &MasterInsuranceContract,
+    instructions_sysvar: &AccountInfo,
+    oracle_accounts: &[AccountInfo],
+    policy_id: u64,
+    trigger_value: &TriggerValue,
+    attested_at: i64,
+    incident_timestamp: i64,
+    current_timestamp: i64,
+) -> Result<TriggerEvidence> {
+    // Evidence must describe the claimed incident, not an unrelated reading
+    let quorum_timestamp = (policy.quorum_signatures > 0).then_some(attested_at);
+    validate_incident_window(
+        policy,
+        type_params.incident_tolerance_secs,
+        oracle_accounts,
+        incident_timestamp,
+        trigger_value.round_timestamp.into_iter().chain(quorum_timestamp),
+        current_timestamp,
+    )?;
+    
+    // One event may only be claimed once, however its incidents are spaced
+    let min_incident_gap = type_params.min_incident_gap_secs;
+    let fingerprint = incident_fingerprint(
+        &policy.oracle_config.data_feed_id,
+        incident_timestamp,
+        min_incident_gap,
+    );
+    require!(
+        !policy_history.repeats_incident(&fingerprint, incident_timestamp, min_incident_gap),
+        InsuranceError::DuplicateIncident
+    );
+    
+    // High-value policies require oracle authorities to co-sign the trigger
+    let quorum_signers = if policy.quorum_signatures > 0 {
+        crate::utils::error_utils::validate_oracle_freshness(
+            attested_at,
+            current_timestamp,
+            policy.oracle_config.staleness_threshold,
+        )?;
+        verify_trigger_quorum(
+            instructions_sysvar,
+            oracle_accounts,
+            master_contract,
+            &quorum_message(policy_id, trigger_value.value, attested_at),
+            policy.quorum_signatures,
+        )?
+    } else {
+        Vec::new()
+    };
+    
+    // Pay the oracles whose data the trigger consumed
+    if let Some(ref compound) = policy.trigger_conditions.compound {
+        accrue_oracle_fees(compound, oracle_accounts, master_contract.oracle_fee_lamports)?;
+    }
+    
+    Ok(TriggerEvidence { fingerprint, quorum_signers })
+}
+
+/// Initialize a payout in `Pending`, filed now for `incident_timestamp`
+#[allow(clippy::too_many_arguments)]
+fn open_payout(
+    pending_payout: &mut PendingPayout,
+    policy_id: u64,
+    beneficiary: Pubkey,
+    rent_payer: Pubkey,
+    bump: u8,
+    type_params: &TypeConfigParams,
+    incident_timestamp: i64,
+    current_timestamp: i64,
+) {
+    pending_payout.policy_id = policy_id;
+    pending_payout.amount = 0;
+    pending_payout.timestamp = current_timestamp;
+    pending_payout.priority = type_params.base_priority;
+    pending_payout.base_priority = type_params.base_priority;
+    pending_payout.status = PayoutStatus::Pending;
+    pending_payout.beneficiary = beneficiary;
+    pending_payout.trigger_oracle_data = Vec::new();
+    pending_payout.severity_score = 0;
+    pending_payout.approval_timestamp = None;
+    pending_payout.approved_by = None;
+    pending_payout.expires_at = i64::MAX;
+    pending_payout.rejection_reason = None;
+    pending_payout.bump = bump;
+    pending_payout.quorum_signers = Vec::new();
+    pending_payout.incident_timestamp = incident_timestamp;
+    pending_payout.rent_payer = rent_payer;
+    pending_payout.trigger_evidence = None;
+}
+
+/// Queue an assessed payout: record its amount and priority, earmark the
+/// amount in the treasury, start its expiry and add it to the policy's payout
+/// history
+///
+/// With an observation proof the payout references it as its evidence, which
+/// must hold the trigger value, instead of copying the reading.
+#[allow(clippy::too_many_arguments)]
+fn apply_assessment(
+    pending_payout: &mut PendingPayout,
+    payout_key: Pubkey,
+    policy: &Policy,
+    policy_history: &mut PolicyHistory,
+    treasury: &mut Treasury,
+    type_params: &TypeConfigParams,
+    assessment: &PayoutAssessment,
+    oracle_value: u64,
+    evidence: TriggerEvidence,
+    observation_proof: Option<&Account<ObservationProof>>,
+    current_timestamp: i64,
+) -> Result<()> {
+    if let Some(proof) = observation_proof {
+        require!(proof.value == oracle_value, InsuranceError::ObservationProofMismatch);
+        require!(
+            policy.oracle_config.accepts_confidence(proof.value, proof.confidence),
+            InsuranceError::OracleConfidenceTooLow
+        );
+    }
+    let status = if assessment.requires_approval {
+        PayoutStatus::PendingApproval
+    } else {
+        PayoutStatus::Ready
+    };
+    pending_payout.transition(status)?;
+    treasury.reserve_for_payout(assessment.amount)?;
+    
+    pending_payout.amount = assessment.amount;
+    pending_payout.priority = siglab_core::payout_priority(
+        type_params.base_priority,
+        assessment.severity_percentage,
+        type_params.severity_priority_points,
+    );
+    pending_payout.base_priority = pending_payout.priority;
+    match observation_proof {
+        Some(proof) => pending_payout.trigger_evidence = Some(proof.key()),
+        None => pending_payout.set_trigger_oracle_data(oracle_value.to_le_bytes().to_vec())?,
+    }
+    pending_payout.severity_score = assessment.severity_percentage;
+    pending_payout.expires_at = current_timestamp + (24 * 60 * 60); // 24 hour expiration
+    pending_payout.set_quorum_signers(evidence.quorum_signers)?;
+    
+    policy_history.record_payout(PayoutRecord {
+        amount: assessment.amount,
+        timestamp: current_timestamp,
+        transaction_id: payout_key.to_string(),
+        oracle_data: oracle_value.to_string(),
+        incident_timestamp: pending_payout.incident_timestamp,
+        incident_fingerprint: evidence.fingerprint,
+        destination_rent: 0,
+        net_amount: 0,
+    });
+    
+    Ok(())
+}
+
+pub fn observe_breach(
+    ctx: Context<ObserveBreach>,
+    oracle_value: u64,
+) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let breach_watch = &mut ctx.accounts.breach_watch;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    let (breached, _) = evaluate_policy_trigger(
+        policy,
+        oracle_value,
+        ctx.remaining_accounts,
+        &ctx.accounts.master_contract,
+        clock.unix_timestamp,
+    )?;
+    require!(breached, InsuranceError::PayoutConditionsNotMet);
+    
+    breach_watch.policy = policy.key();
+    breach_watch.observer = ctx.accounts.observer.key();
+    breach_watch.first_observed_at = clock.unix_timestamp;
+    breach_watch.last_observed_at = clock.unix_timestamp;
+    breach_watch.observed_value = oracle_value;
+    breach_watch.bump = ctx.bumps.breach_watch;
     
-    pub admin: Signer<'info>,
+    emit!(crate::events::BreachObserved {
+        policy_id: policy.id,
+        observer: ctx.accounts.observer.key(),
+        oracle_value,
+        first_observed_at: clock.unix_timestamp,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ObserveBreach>(),
+    });
+    
+    Ok(())
 }
 
-pub fn trigger_payout(
-    ctx: Context<TriggerPayout>,
-    policy_id: String,
+/// Close a breach watch whose condition has recovered before the grace period elapsed
+pub fn clear_breach_watch(
+    ctx: Context<ClearBreachWatch>,
     oracle_value: u64,
 ) -> Result<()> {
-    let policy = &mut ctx.accounts.policy;
+    let policy = &ctx.accounts.policy;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    let (breached, _) = evaluate_policy_trigger(
+        policy,
+        oracle_value,
+        ctx.remaining_accounts,
+        &ctx.accounts.master_contract,
+        clock.unix_timestamp,
+    )?;
+    require!(!breached, InsuranceError::InvalidParameters);
+    
+    emit!(crate::events::BreachWatchCleared {
+        policy_id: policy.id,
+        oracle_value,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ClearBreachWatch>(),
+    });
+    
+    Ok(())
+}
+
+pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
     let pending_payout = &mut ctx.accounts.pending_payout;
-    let master_contract = &ctx.accounts.master_contract;
-    let clock = Clock::get()?;
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
     
-    // Check waiting period
-    let time_since_start = clock.unix_timestamp - policy.start_date;
-    let waiting_period_seconds = (policy.waiting_period_hours as i64) * 3600;
+    // Check if payout has expired
     require!(
-        time_since_start >= waiting_period_seconds,
+        !pending_payout.is_expired(clock.unix_timestamp),
         InsuranceError::ClaimPeriodExpired
     );
     
-    // For now, use simple oracle value validation instead of consensus
-    // TODO: Implement proper oracle consensus in future version
+    // Vesting policies pay the first tranche now; the payout becomes a
+    // vesting payout whose remaining tranches stay reserved
+    let amount = match (policy.payout_vesting, ctx.accounts.vesting_payout.as_mut()) {
+        (Some(vesting), Some(vesting_payout)) => {
+            vesting_payout.policy_id = pending_payout.policy_id;
+            vesting_payout.beneficiary = pending_payout.beneficiary;
+            vesting_payout.insurance_type = policy.insurance_type.clone();
+            vesting_payout.currency = policy.payout_currency;
+            vesting_payout.total_amount = pending_payout.amount;
+            vesting_payout.released_amount = 0;
+            vesting_payout.tranches = vesting.tranches;
+            vesting_payout.tranches_released = 0;
+            vesting_payout.interval_secs = vesting.interval_secs;
+            vesting_payout.started_at = clock.unix_timestamp;
+            vesting_payout.rent_payer = ctx.accounts.executor.key();
+            vesting_payout.bump = ctx.bumps.vesting_payout.ok_or(InsuranceError::VestingPayoutMismatch)?;
+            
+            let first_tranche = vesting_payout.next_tranche();
+            vesting_payout.record_release(first_tranche);
+            first_tranche
+        }
+        (None, None) => pending_payout.amount,
+        _ => return Err(InsuranceError::VestingPayoutMismatch.into()),
+    };
     
-    // Check trigger conditions against oracle data
-    let trigger_met = evaluate_trigger_conditions(
-        &policy.trigger_conditions,
-        oracle_value,
-    )?;
+    // Consume the earmark of what is paid now and record it against the
+    // treasury balances of its currency (fails if they cannot cover it)
+    let treasury = &mut ctx.accounts.treasury;
+    let is_usdc = policy.payout_currency.is_usdc();
+    let treasury_balance_before = treasury.balance(policy.payout_currency);
     
-    require!(trigger_met, InsuranceError::PayoutConditionsNotMet);
+    // The whole award counts against the region's day it is executed on
+    crate::instructions::region::record_region_payout(
+        policy,
+        ctx.accounts.region_exposure.as_mut(),
+        pending_payout.amount,
+        treasury,
+        master_contract,
+        clock.unix_timestamp,
+    )?;
+    treasury.release_payout_reservation(amount);
     
-    // Calculate payout amount
-    let calculation_data = PayoutCalculationData {
-        coverage_amount: policy.coverage_amount,
-        deductible: policy.deductible,
-        severity_percentage: calculate_severity_percentage(
-            &policy.trigger_conditions,
-            oracle_value,
-        )?,
-        max_payout: policy.max_payout_per_incident,
-        insurance_type: format!("{:?}", policy.insurance_type),
+    // Ready the beneficiary's account first so the transfer neither fails
+    // nor leaves it below rent exemption, at the beneficiary's expense: a SOL
+    // payout's deposit stays in their account, and a new token account's
+    // rent, reimbursed to the executor in lamports, is withheld from a USDC
+    // payout at its USDC value
+    let beneficiary_usdc_account =
+        ctx.accounts.beneficiary_usdc_account.as_ref().map(|account| account.to_account_info());
+    let (destination_rent, withheld) = if is_usdc {
+        let (Some(token_account), Some(mint), Some(token_program), Some(associated_token_program)) = (
+            beneficiary_usdc_account.as_ref(),
+            ctx.accounts.usdc_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        ) else {
+            return Err(InsuranceError::UnsupportedCurrency.into());
+        };
+        let rent = create_beneficiary_token_account(
+            treasury,
+            &ctx.accounts.executor,
+            &ctx.accounts.beneficiary,
+            token_account,
+            mint,
+            token_program,
+            associated_token_program,
+            &ctx.accounts.system_program,
+            clock.unix_timestamp,
+        )?;
+        let withheld = crate::instructions::treasury::currency_converter(
+            treasury,
+            master_contract,
+            ctx.remaining_accounts,
+            clock.unix_timestamp,
+        )
+        .convert(CurrencyAmount::sol(rent), TokenType::USDC)?;
+        require!(amount >= withheld, InsuranceError::PayoutBelowRentExemption);
+        (rent, withheld)
+    } else {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.beneficiary.data_len());
+        let rent_deposit = rent_exempt_minimum.saturating_sub(ctx.accounts.beneficiary.lamports());
+        require!(amount >= rent_deposit, InsuranceError::PayoutBelowRentExemption);
+        (rent_deposit, 0)
     };
+    let net_amount = if is_usdc { amount - withheld } else { amount - destination_rent };
+    ctx.accounts.policy_history.record_destination(destination_rent, net_amount);
+    policy.claims_paid = policy.claims_paid.saturating_add(pending_payout.amount);
     
-    let payout_amount = calculation_data.calculate_payout();
-    require!(payout_amount > 0, InsuranceError::InvalidClaimAmount);
+    // What is withheld never leaves the treasury
+    treasury.record_payout(amount - withheld, is_usdc, clock.unix_timestamp)?;
+    crate::instructions::treasury::disburse(
+        treasury,
+        &ctx.accounts.beneficiary,
+        is_usdc,
+        amount - withheld,
+        ctx.accounts.treasury_usdc_account.as_ref(),
+        beneficiary_usdc_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
     
-    // Determine if admin approval is required (e.g., > 10% of treasury)
-    let approval_threshold = master_contract.total_premiums_collected / 10; // 10% threshold
-    let requires_approval = payout_amount > approval_threshold;
+    let executor = ctx.accounts.executor.key();
+    let crank_tip = pay_crank_tip(
+        treasury,
+        &ctx.accounts.executor,
+        &pending_payout.beneficiary,
+        master_contract.crank_tip_lamports,
+        clock.unix_timestamp,
+    )?;
     
-    let status = if requires_approval {
-        PayoutStatus::PendingApproval
+    // A policy covering further incidents stays in force on the coverage the
+    // payout left, its token thawed for the next claim; otherwise it is paid
+    // out. The payout account closes once executed
+    let terminates = siglab_core::payout_terminates(
+        policy.coverage_amount,
+        policy.max_payout_per_incident,
+        pending_payout.amount,
+    );
+    let released = siglab_core::payout_exposure_release(policy.coverage_amount, pending_payout.amount, terminates);
+    if terminates {
+        policy.transition(PolicyStatus::PaidOut, clock.unix_timestamp)?;
     } else {
-        PayoutStatus::Ready
-    };
+        policy.transition(PolicyStatus::Active, clock.unix_timestamp)?;
+        policy.coverage_amount -= released;
+        thaw_after_payout(
+            policy,
+            master_contract,
+            ctx.accounts.policy_mint.as_ref(),
+            ctx.accounts.beneficiary_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+    }
+    pending_payout.transition(PayoutStatus::Executed)?;
     
-    // Initialize pending payout
-    pending_payout.policy_id = policy_id.clone();
-    pending_payout.amount = payout_amount;
-    pending_payout.timestamp = clock.unix_timestamp;
-    pending_payout.priority = calculate_priority(&policy.insurance_type, calculation_data.severity_percentage);
-    pending_payout.status = status;
-    pending_payout.beneficiary = ctx.accounts.beneficiary.key();
-    pending_payout.trigger_oracle_data = oracle_value.to_le_bytes().to_vec();
-    pending_payout.severity_score = calculation_data.severity_percentage;
-    pending_payout.approval_timestamp = None;
-    pending_payout.approved_by = None;
-    pending_payout.expires_at = clock.unix_timestamp + (24 * 60 * 60); // 24 hour expiration
-    pending_payout.rejection_reason = None;
-    pending_payout.bump = ctx.bumps.pending_payout;
+    // Release the exposure the payout settled
+    treasury.release_coverage_exposure(policy.payout_currency, released);
+    crate::instructions::region::release_region_exposure(
+        policy,
+        ctx.accounts.region_exposure.as_mut(),
+        released,
+        master_contract,
+        instruction_discriminator::<crate::instruction::ExecutePayout>(),
+    )?;
     
-    // Update policy status
-    policy.status = PolicyStatus::PendingPayout;
-    policy.updated_at = clock.unix_timestamp;
+    // Update master contract stats
+    master_contract.total_payouts_disbursed += amount;
+    master_contract.portfolio_stats_mut(&policy.insurance_type).record_payout(
+        amount,
+        pending_payout.severity_score,
+        released,
+    )?;
+    master_contract.record_payout_closed();
+    if terminates {
+        master_contract.active_policies_count = master_contract.active_policies_count.saturating_sub(1);
+    }
+    master_contract.updated_at = clock.unix_timestamp;
     
     // Emit event
-    emit!(PayoutTriggered {
-        policy_id: policy_id,
-        beneficiary: ctx.accounts.beneficiary.key(),
-        amount: payout_amount,
-        oracle_value: oracle_value,
+    emit_cpi!(crate::events::PayoutExecuted {
+        policy_id: pending_payout.policy_id,
+        beneficiary: pending_payout.beneficiary,
+        amount,
+        net_amount,
+        destination_rent,
+        pending_payout: pending_payout.key(),
+        incident_timestamp: pending_payout.incident_timestamp,
+        region_code: policy.region_code,
+        executor,
+        crank_tip,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(policy.payout_currency),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ExecutePayout>(),
     });
     
+    if let Some(vesting_payout) = ctx.accounts.vesting_payout.as_ref() {
+        emit_cpi!(PayoutVestingStarted {
+            policy_id: vesting_payout.policy_id,
+            beneficiary: vesting_payout.beneficiary,
+            total_amount: vesting_payout.total_amount,
+            first_tranche: amount,
+            tranches: vesting_payout.tranches,
+            next_release_at: vesting_payout.next_release_at,
+            timestamp: clock.unix_timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::ExecutePayout>(),
+        });
+    }
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::ExecutePayout>(),
+    );
+    
     Ok(())
 }
 
-pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
-    let pending_payout = &ctx.accounts.pending_payout;
-    let policy = &mut ctx.accounts.policy;
+/// Pay the next due tranche of a vesting payout (permissionless)
+///
+/// The vesting payout closes, refunding its rent payer, once the last
+/// tranche is paid.
+pub fn release_payout_tranche(ctx: Context<ReleasePayoutTranche>) -> Result<()> {
+    let vesting_payout = &mut ctx.accounts.vesting_payout;
     let master_contract = &mut ctx.accounts.master_contract;
-    let clock = Clock::get()?;
+    let clock = current_clock(master_contract)?;
     
-    // Check if payout has expired
+    require!(vesting_payout.is_due(clock.unix_timestamp), InsuranceError::TrancheNotDue);
+    
+    let amount = vesting_payout.next_tranche();
+    let is_usdc = vesting_payout.currency.is_usdc();
+    let treasury = &mut ctx.accounts.treasury;
+    let treasury_balance_before = treasury.balance(vesting_payout.currency);
+    treasury.release_payout_reservation(amount);
+    treasury.record_payout(amount, is_usdc, clock.unix_timestamp)?;
+    crate::instructions::treasury::disburse(
+        treasury,
+        &ctx.accounts.beneficiary,
+        is_usdc,
+        amount,
+        ctx.accounts.treasury_usdc_account.as_ref(),
+        ctx.accounts.beneficiary_usdc_account.as_ref().map(|account| account.to_account_info()).as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    let crank_tip = pay_crank_tip(
+        treasury,
+        &ctx.accounts.executor,
+        &vesting_payout.beneficiary,
+        master_contract.crank_tip_lamports,
+        clock.unix_timestamp,
+    )?;
+    
+    vesting_payout.record_release(amount);
+    master_contract.total_payouts_disbursed += amount;
+    master_contract.portfolio_stats_mut(&vesting_payout.insurance_type).record_tranche(amount)?;
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit_cpi!(PayoutTrancheReleased {
+        policy_id: vesting_payout.policy_id,
+        beneficiary: vesting_payout.beneficiary,
+        amount,
+        tranches_released: vesting_payout.tranches_released,
+        tranches: vesting_payout.tranches,
+        remaining_amount: vesting_payout.unreleased_amount(),
+        executor: ctx.accounts.executor.key(),
+        crank_tip,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(vesting_payout.currency),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ReleasePayoutTranche>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::ReleasePayoutTranche>(),
+    );
+    
+    if vesting_payout.is_complete() {
+        vesting_payout.close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+    
+    Ok(())
+}
+
+/// Stop a vesting payout, e.g. on discovering fraud (admin only)
+///
+/// The unreleased tranches are returned to the treasury's reserves; tranches
+/// already paid are not clawed back.
+pub fn cancel_vesting_payout(ctx: Context<CancelVestingPayout>, reason: String) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
     require!(
-        !pending_payout.is_expired(clock.unix_timestamp),
-        InsuranceError::ClaimPeriodExpired
+        reason.len() <= MAX_VESTING_CANCEL_REASON_LENGTH,
+        InsuranceError::InvalidInput
     );
     
-    // Check treasury has sufficient funds
-    let treasury_balance = ctx.accounts.treasury_account.lamports();
+    let vesting_payout = &ctx.accounts.vesting_payout;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
+    
+    let returned_amount = vesting_payout.unreleased_amount();
+    ctx.accounts.treasury.release_payout_reservation(returned_amount);
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit_cpi!(VestingPayoutCancelled {
+        policy_id: vesting_payout.policy_id,
+        beneficiary: vesting_payout.beneficiary,
+        admin: ctx.accounts.admin.key(),
+        returned_amount,
+        tranches_cancelled: vesting_payout.tranches - vesting_payout.tranches_released,
+        reason,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CancelVestingPayout>(),
+    });
+    
+    Ok(())
+}
+
+/// Create the beneficiary's USDC associated token account if it does not
+/// exist yet, returning the rent the treasury reimbursed the executor
+///
+/// The reimbursement is lamports, recorded as a SOL outflow of the payout;
+/// the caller withholds its USDC value from the beneficiary.
+#[allow(clippy::too_many_arguments)]
+fn create_beneficiary_token_account<'info>(
+    treasury: &mut Account<'info, Treasury>,
+    executor: &Signer<'info>,
+    beneficiary: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    mint: &Account<'info, Mint>,
+    token_program: &Program<'info, Token>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+    current_timestamp: i64,
+) -> Result<u64> {
+    if !token_account.data_is_empty() {
+        return Ok(0);
+    }
+    
+    associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: executor.to_account_info(),
+            associated_token: token_account.clone(),
+            authority: beneficiary.clone(),
+            mint: mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+    
+    let rent = token_account.lamports();
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
     require!(
-        treasury_balance >= pending_payout.amount,
+        treasury_info.lamports().saturating_sub(rent_reserve) >= rent,
         InsuranceError::InsufficientTreasury
     );
+    treasury.record_payout(rent, false, current_timestamp)?;
+    **treasury_info.try_borrow_mut_lamports()? -= rent;
+    **executor.try_borrow_mut_lamports()? += rent;
+    Ok(rent)
+}
+
+/// Tip a third-party executor on top of a payout while the crank budget
+/// lasts, returning the tip paid
+fn pay_crank_tip<'info>(
+    treasury: &mut Account<'info, Treasury>,
+    executor: &Signer<'info>,
+    beneficiary: &Pubkey,
+    tip: u64,
+    current_timestamp: i64,
+) -> Result<u64> {
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let crank_tip = if executor.key() != *beneficiary
+        && treasury_info.lamports().saturating_sub(rent_reserve) >= tip
+    {
+        treasury.draw_crank_tip(tip, current_timestamp)
+    } else {
+        0
+    };
+    if crank_tip > 0 {
+        **treasury_info.try_borrow_mut_lamports()? -= crank_tip;
+        **executor.try_borrow_mut_lamports()? += crank_tip;
+    }
+    Ok(crank_tip)
+}
+
+/// Settle a payout left unexecuted past its expiry, returning the policy to coverage
+///
+/// Permissionless so stale payouts cannot hold up a treasury migration.
+pub fn expire_payout(ctx: Context<ExpirePayout>) -> Result<()> {
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
     
-    // Transfer funds from treasury to beneficiary
-    **ctx.accounts.treasury_account.try_borrow_mut_lamports()? -= pending_payout.amount;
-    **ctx.accounts.beneficiary.try_borrow_mut_lamports()? += pending_payout.amount;
-    
-    // Update policy status
-    policy.status = PolicyStatus::PaidOut;
-    policy.updated_at = clock.unix_timestamp;
+    require!(
+        pending_payout.is_expired(clock.unix_timestamp),
+        InsuranceError::PayoutConditionsNotMet
+    );
     
-    // Update master contract stats
-    master_contract.total_payouts_disbursed += pending_payout.amount;
+    pending_payout.transition(PayoutStatus::Expired)?;
+    ctx.accounts.treasury.release_payout_reservation(pending_payout.amount);
+    thaw_after_payout(
+        &ctx.accounts.policy,
+        master_contract,
+        ctx.accounts.policy_mint.as_ref(),
+        ctx.accounts.beneficiary_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    ctx.accounts.policy.transition(PolicyStatus::Active, clock.unix_timestamp)?;
+    master_contract.record_payout_closed();
     master_contract.updated_at = clock.unix_timestamp;
     
-    // Emit event
-    emit!(crate::events::PayoutExecuted {
-        policy_id: pending_payout.policy_id.clone(),
+    emit_cpi!(crate::events::PayoutExpired {
+        policy_id: pending_payout.policy_id,
         beneficiary: pending_payout.beneficiary,
         amount: pending_payout.amount,
-        transaction_signature: "executed".to_string(), // Would be actual signature in production
         timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ExpirePayout>(),
     });
     
     Ok(())
@@ -217,7 +1935,7 @@ pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
 
 pub fn approve_payout(ctx: Context<ApprovePayout>) -> Result<()> {
     let pending_payout = &mut ctx.accounts.pending_payout;
-    let clock = Clock::get()?;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
     
     // Check if payout has expired
     require!(
@@ -226,204 +1944,578 @@ pub fn approve_payout(ctx: Context<ApprovePayout>) -> Result<()> {
     );
     
     // Update payout status to ready
-    pending_payout.status = PayoutStatus::Ready;
+    pending_payout.transition(PayoutStatus::Ready)?;
     pending_payout.approval_timestamp = Some(clock.unix_timestamp);
     pending_payout.approved_by = Some(ctx.accounts.admin.key());
     
     // Emit event
-    emit!(crate::events::PayoutApproved {
-        policy_id: pending_payout.policy_id.clone(),
+    emit_cpi!(crate::events::PayoutApproved {
+        policy_id: pending_payout.policy_id,
         admin: ctx.accounts.admin.key(),
         amount: pending_payout.amount,
         timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ApprovePayout>(),
     });
     
     Ok(())
 }
 
-/// Evaluate if trigger conditions are met based on oracle data
-fn evaluate_trigger_conditions(
-    conditions: &crate::state::TriggerConditions,
-    oracle_value: u64,
-) -> Result<bool> {
-    let oracle_value_f64 = oracle_value as f64;
-    
-    let condition_met = match conditions.comparison_operator {
-        ComparisonOperator::GreaterThan => oracle_value_f64 > conditions.threshold_value,
-        ComparisonOperator::LessThan => oracle_value_f64 < conditions.threshold_value,
-        ComparisonOperator::Equals => (oracle_value_f64 - conditions.threshold_value).abs() < 0.01,
-        ComparisonOperator::NotEquals => (oracle_value_f64 - conditions.threshold_value).abs() >= 0.01,
-    };
+/// Refuse a payout awaiting approval (admin only)
+///
+/// The earmark returns to the treasury, the policy token is thawed and the
+/// policy goes back to coverage; the payout's rent returns to its rent payer.
+pub fn reject_payout(ctx: Context<RejectPayout>, reason: String) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    require!(
+        reason.len() <= PendingPayout::MAX_REJECTION_REASON_LENGTH,
+        InsuranceError::InvalidInput
+    );
+    
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let clock = current_clock(master_contract)?;
     
-    Ok(condition_met)
+    pending_payout.transition(PayoutStatus::Rejected)?;
+    pending_payout.rejection_reason = Some(reason.clone());
+    ctx.accounts.treasury.release_payout_reservation(pending_payout.amount);
+    thaw_after_payout(
+        &ctx.accounts.policy,
+        master_contract,
+        ctx.accounts.policy_mint.as_ref(),
+        ctx.accounts.beneficiary_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    ctx.accounts.policy.transition(PolicyStatus::Active, clock.unix_timestamp)?;
+    master_contract.record_payout_closed();
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit_cpi!(crate::events::PayoutRejected {
+        policy_id: pending_payout.policy_id,
+        admin: ctx.accounts.admin.key(),
+        reason,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RejectPayout>(),
+    });
+    
+    Ok(())
 }
 
-/// Calculate severity percentage based on how far oracle value deviates from trigger threshold
-fn calculate_severity_percentage(
-    conditions: &crate::state::TriggerConditions,
-    oracle_value: u64,
-) -> Result<u8> {
-    let oracle_value_f64 = oracle_value as f64;
-    let threshold = conditions.threshold_value;
+/// Persist a waiting payout's aged priority so on-chain ordering reflects it
+pub fn recompute_priority(ctx: Context<RecomputePriority>) -> Result<()> {
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    require!(
+        !pending_payout.is_expired(clock.unix_timestamp),
+        InsuranceError::ClaimPeriodExpired
+    );
     
-    // Calculate percentage deviation from threshold
-    let deviation = (oracle_value_f64 - threshold).abs() / threshold;
+    let old_priority = pending_payout.priority;
+    pending_payout.priority = pending_payout.effective_priority(clock.unix_timestamp);
     
-    // Convert to severity percentage (capped at 100%)
-    let severity = (deviation * 100.0).min(100.0) as u8;
+    emit_cpi!(crate::events::PayoutPriorityRecomputed {
+        policy_id: pending_payout.policy_id,
+        old_priority,
+        new_priority: pending_payout.priority,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::RecomputePriority>(),
+    });
     
-    Ok(severity)
+    Ok(())
 }
 
-/// Calculate priority based on insurance type and severity
-fn calculate_priority(insurance_type: &crate::state::InsuranceType, severity: u8) -> u8 {
-    let base_priority = match insurance_type {
-        crate::state::InsuranceType::Weather => 70,
-        crate::state::InsuranceType::Earthquake => 90,
-        crate::state::InsuranceType::Flight => 60,
-        crate::state::InsuranceType::Crop => 80,
-        crate::state::InsuranceType::Custom => 50,
-    };
+/// Quote a claim without mutating state; the result is returned via return data
+pub fn simulate_payout(ctx: Context<SimulatePayout>, oracle_value: u64) -> Result<PayoutAssessment> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
     
-    // Adjust priority based on severity
-    let adjusted_priority = base_priority + (severity / 4); // Add up to 25 points for severity
-    std::cmp::min(adjusted_priority, 100)
+    assess_payout(
+        &ctx.accounts.policy,
+        oracle_value,
+        ctx.remaining_accounts,
+        &ctx.accounts.master_contract,
+        &ctx.accounts.treasury,
+        ctx.accounts.breach_watch.as_deref(),
+        clock.unix_timestamp,
+    )
 }
 
-// ===== PAYOUT QUEUE MANAGEMENT FUNCTIONS =====
+/// Assess a claim: waiting period, trigger evaluation, grace period, severity and
+/// payout amount. Shared by `trigger_payout` and `simulate_payout` so quotes always
+/// match the real path.
+pub fn assess_payout(
+    policy: &Policy,
+    oracle_value: u64,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+    treasury: &Treasury,
+    breach_watch: Option<&BreachWatch>,
+    current_timestamp: i64,
+) -> Result<PayoutAssessment> {
+    if policy.status != PolicyStatus::Active {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::PolicyNotActive));
+    }
+    if policy.coverage_suspended_at.is_some() {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::CoverageSuspended));
+    }
+    if policy.has_ended(current_timestamp) {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::PolicyExpired));
+    }
+    if policy.in_waiting_period(current_timestamp) {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::WaitingPeriod));
+    }
+    
+    assess_trigger(
+        policy,
+        oracle_value,
+        oracle_accounts,
+        master_contract,
+        treasury,
+        breach_watch,
+        current_timestamp,
+    )
+}
 
-/// Add payout to the processing queue (called automatically in trigger_payout)
-/// Queue maintains chronological ordering with priority adjustments
-pub fn add_to_payout_queue(
-    pending_payout: &PendingPayout,
+/// Assess the trigger, grace period, severity and payout amount of a policy whose
+/// eligibility is already established. Filed claims are assessed from here, as
+/// their eligibility was checked when they were filed.
+fn assess_trigger(
+    policy: &Policy,
+    oracle_value: u64,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+    treasury: &Treasury,
+    breach_watch: Option<&BreachWatch>,
     current_timestamp: i64,
-) -> Result<()> {
-    // Validate payout data
-    require!(pending_payout.amount > 0, InsuranceError::InvalidClaimAmount);
-    require!(
-        !pending_payout.is_expired(current_timestamp),
-        InsuranceError::ClaimPeriodExpired
-    );
+) -> Result<PayoutAssessment> {
+    // A value committed before the policy was bought and revealed after is
+    // one its holder may have seen coming
+    if created_during_commitment(policy, oracle_accounts) {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::CreatedDuringCommitment));
+    }
     
-    // Queue ordering is handled by timestamp in PendingPayout struct
-    // Priority affects processing order when timestamps are similar
+    // Check trigger conditions against oracle data
+    let (trigger_met, severity_percentage) = evaluate_policy_trigger(
+        policy,
+        oracle_value,
+        oracle_accounts,
+        master_contract,
+        current_timestamp,
+    )?;
+    if !trigger_met {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::TriggerNotMet));
+    }
     
-    Ok(())
+    // The breach must have persisted for the grace period since first observed
+    let grace_period = policy.trigger_conditions.grace_period;
+    if grace_period > 0
+        && !breach_watch.is_some_and(|watch| watch.grace_period_elapsed(grace_period, current_timestamp))
+    {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::GracePeriodNotElapsed));
+    }
+    
+    // Holders behind on their installments get reduced coverage, or none
+    let Some(coverage_bps) = policy.payable_coverage_bps(master_contract, current_timestamp) else {
+        return Ok(PayoutAssessment::ineligible(PayoutIneligibility::InstallmentsInArrears));
+    };
+    let coverage_amount = (policy.coverage_amount as u128 * coverage_bps as u128
+        / siglab_core::installments::FULL_COVERAGE_BPS as u128) as u64;
+    
+    // Calculate payout amount
+    let calculation_data = PayoutCalculationData {
+        coverage_amount,
+        deductible: policy.deductible,
+        deductible_mode: policy.deductible_mode,
+        severity_percentage,
+        max_payout: policy.max_payout_per_incident,
+        insurance_type: format!("{:?}", policy.insurance_type),
+    };
+    
+    let amount = calculation_data.calculate_payout();
+    if amount == 0 {
+        return Ok(PayoutAssessment {
+            severity_percentage,
+            ..PayoutAssessment::ineligible(PayoutIneligibility::BelowDeductible)
+        });
+    }
+    
+    // Dust claims cost more in fees than they pay out
+    let min_payout = crate::instructions::treasury::currency_converter(
+        treasury,
+        master_contract,
+        oracle_accounts,
+        current_timestamp,
+    )
+    .convert(master_contract.global_config.min_payout, policy.payout_currency)?;
+    if !siglab_core::meets_payout_floor(amount, severity_percentage, min_payout, policy.min_severity_bps) {
+        return Ok(PayoutAssessment {
+            severity_percentage,
+            ..PayoutAssessment::ineligible(PayoutIneligibility::BelowMinimum)
+        });
+    }
+    
+    // Determine if admin approval is required (e.g., > 10% of treasury)
+    let approval_threshold = master_contract.total_premiums_collected / 10; // 10% threshold
+    
+    Ok(PayoutAssessment {
+        amount,
+        severity_percentage,
+        requires_approval: amount > approval_threshold,
+        ineligibility: None,
+    })
 }
 
-/// Get next batch of payouts ready for processing
-/// Returns payouts sorted by priority and timestamp
-pub fn get_next_payout_batch(
-    pending_payouts: &[PendingPayout],
-    batch_size: usize,
+/// Evaluate a policy's trigger (compound or single-condition) and derive severity
+fn evaluate_policy_trigger(
+    policy: &Policy,
+    oracle_value: u64,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
     current_timestamp: i64,
-) -> Vec<PendingPayout> {
-    let mut ready_payouts: Vec<PendingPayout> = pending_payouts
-        .iter()
-        .filter(|payout| {
-            payout.is_ready_for_execution() && !payout.is_expired(current_timestamp)
-        })
-        .cloned()
-        .collect();
-    
-    // Sort by priority (descending) then by timestamp (ascending - older first)
-    ready_payouts.sort_by(|a, b| {
-        match b.priority.cmp(&a.priority) {
-            std::cmp::Ordering::Equal => a.timestamp.cmp(&b.timestamp),
-            other => other,
+) -> Result<(bool, u8)> {
+    match policy.trigger_conditions.compound {
+        Some(ref compound) => evaluate_compound_trigger(
+            compound,
+            oracle_accounts,
+            master_contract,
+            &policy.oracle_config,
+            current_timestamp,
+        ),
+        None => {
+            // Single-condition fast path
+            let conditions = &policy.trigger_conditions;
+            let severity = siglab_core::evaluate_trigger(
+                (&conditions.comparison_operator).into(),
+                oracle_value,
+                conditions.threshold_value,
+            );
+            Ok((severity.is_some(), severity.unwrap_or(0)))
         }
-    });
+    }
+}
+
+/// Evaluate a compound trigger against oracle accounts passed as remaining accounts.
+/// Returns whether the expression is met and the derived severity.
+fn evaluate_compound_trigger(
+    compound: &CompoundTrigger,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+    oracle_config: &OracleConfig,
+    current_timestamp: i64,
+) -> Result<(bool, u8)> {
+    let mut leaf_results = [None; CompoundTrigger::MAX_LEAVES];
+    
+    for (result, leaf) in leaf_results.iter_mut().zip(&compound.leaves) {
+        let value = read_feed_value(
+            &leaf.feed_id,
+            oracle_accounts,
+            master_contract,
+            oracle_config,
+            current_timestamp,
+        )?;
+        *result = siglab_core::evaluate_trigger((&leaf.operator).into(), value, leaf.threshold);
+    }
+    
+    let severity = siglab_core::combine_leaves(
+        (&compound.connective).into(),
+        (&compound.severity_mode).into(),
+        leaf_results[..compound.leaves.len()].iter().copied(),
+    );
     
-    // Return up to batch_size payouts
-    ready_payouts.into_iter().take(batch_size).collect()
+    Ok((severity.is_some(), severity.unwrap_or(0)))
 }
 
-/// Remove processed payout from queue (automatically handled by account closure)
-pub fn remove_from_queue(pending_payout: &PendingPayout) -> Result<()> {
-    // Validate payout is in a final state
+/// Find the registered oracle publishing `feed_id` and return its fresh value,
+/// which must be as precise as the policy's oracle config requires
+fn read_feed_value(
+    feed_id: &str,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+    oracle_config: &OracleConfig,
+    current_timestamp: i64,
+) -> Result<u64> {
+    let account_info = find_feed_oracle(feed_id, oracle_accounts)?;
+    let data = account_info.try_borrow_data()?;
+    let oracle = Oracle::from_account_data(&data).ok_or(InsuranceError::InvalidOracleData)?;
+    
     require!(
-        matches!(
-            pending_payout.status,
-            PayoutStatus::Executed | PayoutStatus::Rejected | PayoutStatus::Expired
-        ),
-        InsuranceError::PayoutConditionsNotMet
+        master_contract.oracle_registry.contains(account_info.key),
+        InsuranceError::OracleNotRegistered
     );
+    require!(oracle.is_active(), InsuranceError::OracleInactive);
     
-    Ok(())
+    let latest = oracle.latest_data().ok_or(InsuranceError::InvalidOracleData)?;
+    crate::utils::error_utils::validate_oracle_freshness(
+        latest.timestamp,
+        current_timestamp,
+        oracle_config.staleness_threshold,
+    )?;
+    require!(
+        oracle_config.accepts_confidence(latest.value, latest.confidence),
+        InsuranceError::OracleConfidenceTooLow
+    );
+    
+    Ok(latest.value)
 }
 
-/// Check queue health and size limits
-pub fn validate_queue_health(
-    queue_size: usize,
-    max_queue_size: usize,
+/// Check the claimed incident lies within coverage and the evidence lies near it
+///
+/// Coverage runs from the end of the waiting period to the policy's end date,
+/// and the incident cannot lie in the future. Evidence is each compound leaf's
+/// latest observation plus `evidence_timestamps` (a referenced consensus round,
+/// a quorum attestation); it must not predate coverage either, so a policy
+/// bought with the event already observed cannot claim on it. A
+/// caller-supplied value carries no timestamp of its own, so only the
+/// coverage check applies to it.
+fn validate_incident_window(
+    policy: &Policy,
+    tolerance: i64,
+    oracle_accounts: &[AccountInfo],
+    incident_timestamp: i64,
+    evidence_timestamps: impl IntoIterator<Item = i64>,
     current_timestamp: i64,
 ) -> Result<()> {
-    // Check queue size limit
+    validate_incident_coverage(policy, incident_timestamp, current_timestamp)?;
+    
+    let check_evidence = |timestamp: i64| -> Result<()> {
+        require!(
+            (timestamp - incident_timestamp).abs() <= tolerance,
+            InsuranceError::EvidenceOutsideIncidentWindow
+        );
+        require!(
+            siglab_core::evidence_within_coverage(timestamp, policy.start_date, policy.waiting_period_hours),
+            InsuranceError::EvidenceBeforeCoverage
+        );
+        Ok(())
+    };
+    
+    if let Some(ref compound) = policy.trigger_conditions.compound {
+        for leaf in &compound.leaves {
+            let account_info = find_feed_oracle(&leaf.feed_id, oracle_accounts)?;
+            let data = account_info.try_borrow_data()?;
+            let oracle = Oracle::from_account_data(&data).ok_or(InsuranceError::InvalidOracleData)?;
+            let latest = oracle.latest_data().ok_or(InsuranceError::InvalidOracleData)?;
+            check_evidence(latest.timestamp)?;
+        }
+    }
+    
+    for timestamp in evidence_timestamps {
+        check_evidence(timestamp)?;
+    }
+    
+    Ok(())
+}
+
+/// Check the incident falls within the policy's coverage so far: after the
+/// waiting period and no later than the policy's end or now
+fn validate_incident_coverage(policy: &Policy, incident_timestamp: i64, current_timestamp: i64) -> Result<()> {
+    let coverage_start = siglab_core::coverage_start(policy.start_date, policy.waiting_period_hours);
+    let coverage_end = std::cmp::min(policy.end_date, current_timestamp);
     require!(
-        queue_size < max_queue_size,
-        InsuranceError::InvalidAdminOperation // Reusing error for queue overflow
+        (coverage_start..=coverage_end).contains(&incident_timestamp),
+        InsuranceError::IncidentOutsideCoverage
     );
     
     Ok(())
 }
 
-/// Cleanup expired payouts from queue
-pub fn cleanup_expired_payouts(
-    pending_payouts: &mut Vec<PendingPayout>,
-    current_timestamp: i64,
-) -> usize {
-    let initial_count = pending_payouts.len();
+/// Check each leaf of `compound` reads a feed published by a registered,
+/// active oracle in `oracle_accounts`
+pub(crate) fn require_registered_feeds(
+    compound: &CompoundTrigger,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+) -> Result<()> {
+    for leaf in &compound.leaves {
+        let account_info = find_feed_oracle(&leaf.feed_id, oracle_accounts)?;
+        require!(
+            master_contract.oracle_registry.contains(account_info.key),
+            InsuranceError::OracleNotRegistered
+        );
+        let data = account_info.try_borrow_data()?;
+        let oracle = Oracle::from_account_data(&data).ok_or(InsuranceError::InvalidOracleData)?;
+        require!(oracle.is_active(), InsuranceError::OracleInactive);
+    }
+    Ok(())
+}
+
+/// Whether an oracle in `oracle_accounts` publishing one of the policy's feeds
+/// revealed its latest value from a commitment made before the policy was created
+///
+/// Single-condition triggers name their feed in the oracle config; an oracle
+/// that was not passed is not checked.
+fn created_during_commitment(policy: &Policy, oracle_accounts: &[AccountInfo]) -> bool {
+    let committed_during = |feed_id: &str| {
+        oracle_accounts.iter().any(|account_info| {
+            account_info.owner == &crate::ID
+                && account_info.try_borrow_data().is_ok_and(|data| {
+                    Oracle::from_account_data(&data).is_some_and(|oracle| {
+                        oracle.data_feed_address() == feed_id && oracle.committed_during(policy.created_at)
+                    })
+                })
+        })
+    };
+    match policy.trigger_conditions.compound {
+        Some(ref compound) => compound.leaves.iter().any(|leaf| committed_during(&leaf.feed_id)),
+        None => committed_during(&policy.oracle_config.data_feed_id),
+    }
+}
+
+/// First oracle account in `oracle_accounts` publishing `feed_id`
+pub(crate) fn find_feed_oracle<'a, 'info>(
+    feed_id: &str,
+    oracle_accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a AccountInfo<'info>> {
+    oracle_accounts
+        .iter()
+        .find(|account_info| {
+            account_info.owner == &crate::ID
+                && account_info.try_borrow_data().is_ok_and(|data| {
+                    Oracle::from_account_data(&data)
+                        .is_some_and(|oracle| oracle.data_feed_address() == feed_id)
+                })
+        })
+        .ok_or_else(|| InsuranceError::OracleNotRegistered.into())
+}
+
+/// Accrue `fee` to each oracle read by a compound trigger
+///
+/// An oracle is paid once per accepted update, however many leaves or
+/// triggers consume it. Oracles are the ones `read_feed_value` already
+/// validated during assessment.
+fn accrue_oracle_fees(
+    compound: &CompoundTrigger,
+    oracle_accounts: &[AccountInfo],
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
     
-    // Remove expired payouts
-    pending_payouts.retain(|payout| !payout.is_expired(current_timestamp));
+    for leaf in &compound.leaves {
+        let account_info = find_feed_oracle(&leaf.feed_id, oracle_accounts)?;
+        require!(account_info.is_writable, InsuranceError::OracleAccountNotWritable);
+        
+        let mut data = account_info.try_borrow_mut_data()?;
+        let oracle = Oracle::from_account_data_mut(&mut data).ok_or(InsuranceError::InvalidOracleData)?;
+        oracle.accrue_fee(fee);
+    }
     
-    // Return number of cleaned up payouts
-    initial_count - pending_payouts.len()
+    Ok(())
 }
 
-/// Get queue statistics for monitoring
-pub fn get_queue_statistics(
-    pending_payouts: &[PendingPayout],
-    current_timestamp: i64,
-) -> QueueStatistics {
-    let total_count = pending_payouts.len();
-    let ready_count = pending_payouts
-        .iter()
-        .filter(|p| p.is_ready_for_execution())
-        .count();
-    let pending_approval_count = pending_payouts
-        .iter()
-        .filter(|p| p.requires_approval())
-        .count();
-    let expired_count = pending_payouts
-        .iter()
-        .filter(|p| p.is_expired(current_timestamp))
-        .count();
+/// Digest oracle authorities co-sign to attest a trigger:
+/// `hash(policy_id || oracle_value || attested_at)`
+pub fn quorum_message(policy_id: u64, oracle_value: u64, attested_at: i64) -> [u8; 32] {
+    hashv(&[
+        &policy_id.to_le_bytes(),
+        &oracle_value.to_le_bytes(),
+        &attested_at.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Digest identifying an incident on a policy:
+/// `hash(feed_id || incident bucket)`, with buckets `min_gap` seconds wide
+pub fn incident_fingerprint(feed_id: &str, incident_timestamp: i64, min_gap: i64) -> [u8; 32] {
+    hashv(&[
+        feed_id.as_bytes(),
+        &siglab_core::incident_bucket(incident_timestamp, min_gap).to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Size of one signature's offsets in ed25519 program instruction data
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+/// Offsets follow the signature count and a padding byte
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Verify the oracle quorum attesting a trigger
+///
+/// Every signature over `message` in the ed25519 program instructions preceding
+/// this one must come from a distinct authority of a registered, active oracle
+/// passed in `oracle_accounts`. The ed25519 program has already verified the
+/// signatures themselves. Returns the verified signers, keeping only the most
+/// reputable when more attested than a payout can record.
+fn verify_trigger_quorum(
+    instructions_sysvar: &AccountInfo,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+    message: &[u8; 32],
+    required: u8,
+) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let mut signers: Vec<(Pubkey, u8)> = Vec::with_capacity(required as usize);
     
-    let total_amount: u64 = pending_payouts.iter().map(|p| p.amount).sum();
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar)?;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+        
+        let ix_data = &instruction.data;
+        let signature_count = *ix_data.first().ok_or(InsuranceError::InvalidQuorumAttestation)?;
+        for signature in 0..signature_count as usize {
+            let start = ED25519_OFFSETS_START + signature * ED25519_OFFSETS_SIZE;
+            let offsets = ix_data
+                .get(start..start + ED25519_OFFSETS_SIZE)
+                .ok_or(InsuranceError::InvalidQuorumAttestation)?;
+            let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+            
+            // All referenced data must live in the ed25519 instruction itself
+            require!(
+                [read_u16(2), read_u16(6), read_u16(12)]
+                    .iter()
+                    .all(|&ix| ix == u16::MAX || ix as usize == index),
+                InsuranceError::InvalidQuorumAttestation
+            );
+            
+            // Signatures over other messages are not part of this attestation
+            let message_offset = read_u16(8) as usize;
+            let message_size = read_u16(10) as usize;
+            if ix_data.get(message_offset..message_offset + message_size) != Some(&message[..]) {
+                continue;
+            }
+            
+            let public_key_offset = read_u16(4) as usize;
+            let signer = ix_data
+                .get(public_key_offset..public_key_offset + 32)
+                .and_then(|bytes| Pubkey::try_from(bytes).ok())
+                .ok_or(InsuranceError::InvalidQuorumAttestation)?;
+            
+            require!(
+                signers.iter().all(|(seen, _)| *seen != signer),
+                InsuranceError::DuplicateQuorumSigner
+            );
+            let reputation = registered_oracle_reputation(&signer, oracle_accounts, master_contract)
+                .ok_or(InsuranceError::QuorumSignerNotRegistered)?;
+            signers.push((signer, reputation));
+        }
+    }
     
-    let oldest_timestamp = pending_payouts
-        .iter()
-        .map(|p| p.timestamp)
-        .min()
-        .unwrap_or(current_timestamp);
-    
-    QueueStatistics {
-        total_count,
-        ready_count,
-        pending_approval_count,
-        expired_count,
-        total_amount,
-        oldest_timestamp,
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct QueueStatistics {
-    pub total_count: usize,
-    pub ready_count: usize,
-    pub pending_approval_count: usize,
-    pub expired_count: usize,
-    pub total_amount: u64,
-    pub oldest_timestamp: i64,
+    require!(signers.len() >= required as usize, InsuranceError::QuorumNotMet);
+    Ok(PendingPayout::most_reputable_signers(signers))
+}
+
+/// Reputation of the registered, active oracle among `oracle_accounts` that
+/// `authority` runs, if any
+fn registered_oracle_reputation(
+    authority: &Pubkey,
+    oracle_accounts: &[AccountInfo],
+    master_contract: &MasterInsuranceContract,
+) -> Option<u8> {
+    oracle_accounts.iter().find_map(|account_info| {
+        if account_info.owner != &crate::ID
+            || !master_contract.oracle_registry.contains(account_info.key)
+        {
+            return None;
+        }
+        let data = account_info.try_borrow_data().ok()?;
+        Oracle::from_account_data(&data)
+            .filter(|oracle| oracle.authority == *authority && oracle.is_active())
+            .map(|oracle| oracle.reputation_score)
+    })
 }
\ No newline at end of file
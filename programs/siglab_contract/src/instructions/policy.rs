@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::error::InsuranceError;
+use crate::utils::clock_utils::now;
 use crate::state::*;
 use crate::constants::*;
-use crate::{require_not_paused, require_sufficient_premium};
+use crate::events::instruction_discriminator;
+use crate::instructions::subsidy::draw_subsidy;
+use crate::{require_not_in_winddown, require_not_paused, require_sufficient_premium};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreatePolicyParams {
@@ -19,14 +24,29 @@ pub struct CreatePolicyParams {
     pub premium_payment_frequency: PremiumFrequency,
     pub auto_renewal: bool,
     pub metadata: String, // JSON string for additional data
+    pub metadata_uri: Option<String>, // e.g. IPFS CID for larger documents
+    pub discount_code: Option<String>, // Promotional code preimage
+    pub mint_policy_token: bool, // Represent the policy as a transferable token
+    pub quorum_signatures: u8, // Oracle authorities that must co-sign a trigger, 0 to disable
+    pub deductible_mode: DeductibleMode, // How the deductible reduces a claim
+    pub installment_plan: Option<ArrearsMode>, // Pay by installment schedule, treating arrears this way
+    pub premium_currency: TokenType, // Currency premiums are paid in
+    pub payout_currency: TokenType, // Currency payouts are made in
+    pub min_severity_bps: Option<u16>, // Smallest severity that pays out, None for no floor
+    pub payout_vesting: Option<PayoutVesting>, // Pay claims in tranches instead of a lump sum
+    pub region_code: Option<[u8; 8]>, // Region insured, required for weather, earthquake and crop
 }
 
 #[derive(Accounts)]
 #[instruction(params: CreatePolicyParams)]
 pub struct CreatePolicy<'info> {
-    #[account(mut)]
+    /// Holder the policy is issued to; needs no lamports when a sponsor pays the rent
     pub policy_holder: Signer<'info>,
     
+    /// Pays the rent of every account created, refunded to it on close (may be the holder)
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    
     /// The master insurance contract account
     #[account(
         mut,
@@ -38,13 +58,107 @@ pub struct CreatePolicy<'info> {
     /// Policy account to be created
     #[account(
         init,
-        payer = policy_holder,
-        space = 8 + std::mem::size_of::<Policy>(),
-        seeds = [POLICY_SEED, policy_holder.key().as_ref(), &master_contract.active_policies_count.to_le_bytes()],
+        payer = rent_payer,
+        space = Policy::space(),
+        seeds = [POLICY_SEED, policy_holder.key().as_ref(), &master_contract.policies_issued.to_le_bytes()],
         bump,
     )]
     pub policy_account: Account<'info, Policy>,
     
+    /// Configuration of the policy's insurance type; unconfigured types cannot be sold
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &params.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
+    /// Treasury backing the policy, which must support its currencies
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Underwriter, or the authority while none is set, co-signing coverage at
+    /// or above the co-signing threshold and paying its bond
+    #[account(mut)]
+    pub co_signer: Option<Signer<'info>>,
+    
+    /// Co-signer's ledger, booking the coverage toward its fee rebate (required when co-signing)
+    #[account(
+        mut,
+        seeds = [UNDERWRITER_LEDGER_SEED, underwriter_ledger.underwriter.as_ref()],
+        bump = underwriter_ledger.bump,
+    )]
+    pub underwriter_ledger: Option<Account<'info, UnderwriterLedger>>,
+    
+    /// Region the coverage is booked in (required for a region while an exposure cap is set)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+    
+    /// Promotional discount code (required when `params.discount_code` is set)
+    #[account(
+        mut,
+        seeds = [DISCOUNT_CODE_SEED, discount_code.code_hash.as_ref()],
+        bump = discount_code.bump,
+    )]
+    pub discount_code: Option<Account<'info, DiscountCode>>,
+    
+    /// Per-holder redemption marker preventing a second redemption of the same code
+    #[account(
+        init,
+        payer = rent_payer,
+        space = DiscountRedemption::space(),
+        seeds = [
+            DISCOUNT_REDEMPTION_SEED,
+            discount_code.as_ref().ok_or(InsuranceError::InvalidDiscountCode)?.key().as_ref(),
+            policy_holder.key().as_ref()
+        ],
+        bump,
+    )]
+    pub discount_redemption: Option<Account<'info, DiscountRedemption>>,
+    
+    /// Single-supply policy token mint (required when `params.mint_policy_token` is set)
+    #[account(
+        init,
+        payer = rent_payer,
+        seeds = [POLICY_MINT_SEED, policy_account.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = master_contract,
+        mint::freeze_authority = master_contract,
+    )]
+    pub policy_mint: Option<Account<'info, Mint>>,
+    
+    /// Policyholder's token account receiving the policy token
+    #[account(
+        init,
+        payer = rent_payer,
+        associated_token::mint = policy_mint,
+        associated_token::authority = policy_holder,
+    )]
+    pub holder_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        init,
+        payer = rent_payer,
+        space = PolicyTokenMetadata::space(),
+        seeds = [
+            POLICY_TOKEN_METADATA_SEED,
+            policy_mint.as_ref().ok_or(InsuranceError::PolicyTokenAccountRequired)?.key().as_ref()
+        ],
+        bump,
+    )]
+    pub policy_token_metadata: Option<Account<'info, PolicyTokenMetadata>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -56,28 +170,386 @@ pub struct PayPremium<'info> {
     #[account(mut)]
     pub policy_account: Account<'info, Policy>,
     
+    /// Policy's history, opened with its first premium payment
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PolicyHistory::space(),
+        seeds = [POLICY_HISTORY_SEED, policy_account.key().as_ref()],
+        bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(mut)]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Payer's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Subsidy co-paying the premium; its share is drawn into the treasury.
+    /// Its budget is lamports, so only SOL premiums can draw on it
     #[account(mut)]
+    pub subsidy: Option<Account<'info, Subsidy>>,
+    
+    /// Treasury receiving the premium, both the holder's and any subsidy's share
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Payer's USDC account (required for USDC-denominated premiums)
+    #[account(
+        mut,
+        token::mint = treasury.usdc_mint,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Treasury's USDC account (required for USDC-denominated premiums)
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Fee vault receiving the late fee (required when a late fee is due)
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Option<Account<'info, FeeVault>>,
+    
+    /// Fee vault's USDC account (required for late fees on USDC-denominated premiums)
+    #[account(mut)]
+    pub fee_vault_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Ledger of the policy's co-signer, booking the late fee toward its fee
+    /// rebate (required when a co-signed policy pays a late fee)
+    #[account(
+        mut,
+        seeds = [UNDERWRITER_LEDGER_SEED, underwriter_ledger.underwriter.as_ref()],
+        bump = underwriter_ledger.bump,
+    )]
+    pub underwriter_ledger: Option<Account<'info, UnderwriterLedger>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePolicyMetadata<'info> {
+    #[account(
+        mut,
+        constraint = policy_account.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive
+    )]
+    pub policy_account: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub owner: Signer<'info>,
+    
+    /// Owner's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SetAutoClaim<'info> {
+    #[account(
+        mut,
+        constraint = policy_account.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive
+    )]
+    pub policy_account: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub owner: Signer<'info>,
+    
+    /// Owner's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustCoverage<'info> {
+    #[account(
+        mut,
+        constraint = policy_account.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive
+    )]
+    pub policy_account: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [POLICY_HISTORY_SEED, policy_account.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// Owner's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Region the coverage is booked in (required for tracked policies)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+    
+    /// Owner's USDC account, paying or refunded (required for USDC premiums)
+    #[account(
+        mut,
+        token::mint = treasury.usdc_mint,
+        token::authority = owner,
+    )]
+    pub owner_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Treasury's USDC account (required for USDC premiums)
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpirePolicy<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.coverage_suspended_at.is_none() @ InsuranceError::CoverageSuspended
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
     pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Anyone may expire a policy past its end date, unless its coverage is
+    /// suspended and the term paused
+    pub cranker: Signer<'info>,
+    
+    /// Region the coverage is booked in (required for tracked policies)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
 }
 
 pub fn create_policy(
     ctx: Context<CreatePolicy>,
-    params: CreatePolicyParams,
+    mut params: CreatePolicyParams,
 ) -> Result<()> {
+    validate_new_policy(
+        &mut ctx.accounts.master_contract,
+        &ctx.accounts.type_config,
+        &mut ctx.accounts.treasury,
+        ctx.remaining_accounts,
+        &mut params,
+        instruction_discriminator::<crate::instruction::CreatePolicy>(),
+    )?;
+    
     let master_contract = &mut ctx.accounts.master_contract;
-    let policy_account = &mut ctx.accounts.policy_account;
     let policy_holder = &ctx.accounts.policy_holder;
+    let policy_id = master_contract.allocate_policy_id()?;
+    let current_time = now(master_contract)?;
+    
+    // Apply promotional discount if a code was provided
+    let premium_amount = match params.discount_code {
+        Some(ref preimage) => {
+            let (discount_code, discount_redemption) = match (
+                ctx.accounts.discount_code.as_mut(),
+                ctx.accounts.discount_redemption.as_mut(),
+            ) {
+                (Some(code), Some(redemption)) => (code, redemption),
+                _ => return Err(InsuranceError::InvalidDiscountCode.into()),
+            };
+            
+            let discounted_premium = crate::instructions::discount::redeem_discount_code(
+                discount_code,
+                preimage,
+                &policy_holder.key(),
+                policy_id,
+                params.premium_amount,
+                current_time,
+                master_contract.next_event_sequence(),
+            )?;
+            
+            discount_redemption.discount_code = discount_code.key();
+            discount_redemption.holder = policy_holder.key();
+            discount_redemption.redeemed_at = current_time;
+            discount_redemption.bump = ctx.bumps.discount_redemption.ok_or(InsuranceError::InvalidDiscountCode)?;
+            
+            discounted_premium
+        }
+        None => params.premium_amount,
+    };
+    
+    let mint_policy_token = params.mint_policy_token;
+    initialize_policy(
+        &mut ctx.accounts.policy_account,
+        master_contract,
+        params,
+        policy_id,
+        premium_amount,
+        policy_holder.key(),
+        ctx.accounts.rent_payer.key(),
+        ctx.bumps.policy_account,
+    )?;
+    crate::instructions::underwriting::co_sign_policy(
+        &mut ctx.accounts.policy_account,
+        &mut ctx.accounts.master_contract,
+        &mut ctx.accounts.treasury,
+        ctx.accounts.co_signer.as_ref(),
+        ctx.accounts.underwriter_ledger.as_mut(),
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        instruction_discriminator::<crate::instruction::CreatePolicy>(),
+    )?;
+    crate::instructions::region::book_new_policy(
+        &mut ctx.accounts.policy_account,
+        ctx.accounts.region_exposure.as_mut(),
+        &ctx.accounts.treasury,
+        &mut ctx.accounts.master_contract,
+        instruction_discriminator::<crate::instruction::CreatePolicy>(),
+    )?;
+    let end_date = ctx.accounts.policy_account.end_date;
+    
+    // Mint the policy token if requested
+    if mint_policy_token {
+        let (policy_mint, holder_token_account, metadata, token_program) = match (
+            ctx.accounts.policy_mint.as_ref(),
+            ctx.accounts.holder_token_account.as_ref(),
+            ctx.accounts.policy_token_metadata.as_mut(),
+            ctx.accounts.token_program.as_ref(),
+        ) {
+            (Some(m), Some(t), Some(d), Some(p)) => (m, t, d, p),
+            _ => return Err(InsuranceError::PolicyTokenAccountRequired.into()),
+        };
+        
+        metadata.policy = ctx.accounts.policy_account.key();
+        metadata.mint = policy_mint.key();
+        metadata.policy_id = policy_id;
+        metadata.insurance_type = ctx.accounts.policy_account.insurance_type.clone();
+        metadata.coverage_amount = ctx.accounts.policy_account.coverage_amount;
+        metadata.start_date = current_time;
+        metadata.end_date = end_date;
+        metadata.bump = ctx.bumps.policy_token_metadata.ok_or(InsuranceError::PolicyTokenAccountRequired)?;
+        
+        crate::instructions::policy_token::mint_policy_token(
+            &ctx.accounts.master_contract,
+            policy_mint,
+            holder_token_account,
+            token_program,
+        )?;
+        
+        ctx.accounts.policy_account.policy_mint = Some(policy_mint.key());
+        
+        emit!(crate::events::PolicyTokenMinted {
+            policy_id,
+            mint: policy_mint.key(),
+            owner: ctx.accounts.policy_holder.key(),
+            timestamp: current_time,
+            sequence: ctx.accounts.master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::CreatePolicy>(),
+        });
+    }
+    
+    msg!("Policy created with ID: {} for user: {}", 
+        policy_id, 
+        ctx.accounts.policy_holder.key()
+    );
     
-    // Check contract is not paused
+    Ok(())
+}
+
+/// Check the parameters of a new policy against the global config, the
+/// treasury and the insurance type's limits, adding its coverage to the
+/// treasury's exposure
+///
+/// Coverage is counted in the payout currency and premiums in the premium
+/// currency, converted by the price oracle among `oracle_accounts` if needed.
+/// An oracle confidence limit left unset takes the insurance type's.
+pub(crate) fn validate_new_policy(
+    master_contract: &mut MasterInsuranceContract,
+    type_config: &InsuranceTypeConfig,
+    treasury: &mut Treasury,
+    oracle_accounts: &[AccountInfo],
+    params: &mut CreatePolicyParams,
+    instruction: [u8; 8],
+) -> Result<()> {
+    // Check contract is not paused or winding down
     require_not_paused!(master_contract.is_paused);
+    require_not_in_winddown!(master_contract.winddown);
     
-    // Validate parameters
+    let global_config = master_contract.global_config;
+    let converter = crate::instructions::treasury::currency_converter(
+        treasury,
+        master_contract,
+        oracle_accounts,
+        now(master_contract)?,
+    );
     require!(
-        params.coverage_amount > 0 && params.coverage_amount <= MAX_COVERAGE_AMOUNT,
+        params.coverage_amount > 0
+            && params.coverage_amount <= converter.convert(global_config.max_coverage, params.payout_currency)?,
         InsuranceError::CoverageExceedsMaximum
     );
     
-    require_sufficient_premium!(params.premium_amount, MIN_PREMIUM_AMOUNT);
+    require_sufficient_premium!(
+        params.premium_amount,
+        converter.convert(global_config.min_premium, params.premium_currency)?
+    );
+    let type_minimum_premium = CurrencyAmount {
+        amount: type_config.params.minimum_premium(params.coverage_amount),
+        currency: params.payout_currency,
+    };
+    require_sufficient_premium!(
+        params.premium_amount,
+        converter.convert(type_minimum_premium, params.premium_currency)?
+    );
     
     require!(
         params.deductible <= params.coverage_amount,
@@ -85,10 +557,26 @@ pub fn create_policy(
     );
     
     require!(
-        params.policy_duration_days > 0 && params.policy_duration_days <= 365,
+        params.policy_duration_days > 0
+            && params.policy_duration_days <= global_config.max_policy_duration_days,
         InsuranceError::InvalidParameters
     );
     
+    // Both currencies must be held by the treasury, and the coverage must fit
+    // the payout currency's exposure limit
+    require!(
+        treasury.supports(params.premium_currency) && treasury.supports(params.payout_currency),
+        InsuranceError::UnsupportedCurrency
+    );
+    require!(
+        treasury
+            .coverage_exposure(params.payout_currency)
+            .checked_add(params.coverage_amount)
+            .is_some_and(|exposure| exposure <= global_config.max_exposure(params.payout_currency)),
+        InsuranceError::CurrencyExposureLimitExceeded
+    );
+    treasury.add_coverage_exposure(params.payout_currency, params.coverage_amount)?;
+    
     // Validate risk assessment score
     require!(
         params.risk_assessment_score <= 100,
@@ -101,47 +589,133 @@ pub fn create_policy(
         InsuranceError::InvalidParameters
     );
     
-    // Generate unique policy ID
-    let policy_id = format!("POL-{}-{}", 
-        Clock::get()?.unix_timestamp,
-        master_contract.active_policies_count
+    // Validate variable-length fields against reserved space
+    params.trigger_conditions.validate()?;
+    
+    // Term, waiting period and trigger must fit the insurance type's limits
+    if let Some(violation) = type_config.params.policy_violation(
+        params.policy_duration_days,
+        params.waiting_period_hours,
+        &params.trigger_conditions,
+    ) {
+        emit!(crate::events::PolicyLimitViolated {
+            insurance_type: params.insurance_type.clone(),
+            violation,
+            timestamp: now(master_contract)?,
+            sequence: master_contract.next_event_sequence(),
+            instruction,
+        });
+        return err!(InsuranceError::InvalidParameters);
+    }
+    require!(
+        params.oracle_config.data_feed_id.len() <= Policy::MAX_DATA_FEED_ID_LENGTH,
+        InsuranceError::InvalidParameters
+    );
+    let max_confidence_ratio_bps = *params
+        .oracle_config
+        .max_confidence_ratio_bps
+        .get_or_insert(type_config.params.max_confidence_ratio_bps);
+    require!(
+        max_confidence_ratio_bps <= siglab_core::reserves::FULL_RESERVE_BPS,
+        InsuranceError::InvalidParameters
+    );
+    Policy::validate_metadata(&params.metadata, &params.metadata_uri)?;
+    
+    require!(
+        params.quorum_signatures <= Policy::MAX_QUORUM_SIGNATURES,
+        InsuranceError::InvalidParameters
     );
+    if let Some(min_severity_bps) = params.min_severity_bps {
+        require!(
+            min_severity_bps <= siglab_core::reserves::FULL_RESERVE_BPS,
+            InsuranceError::InvalidParameters
+        );
+    }
+    if let Some(vesting) = params.payout_vesting {
+        vesting.validate()?;
+    }
+    crate::instructions::region::validate_region_code(&params.insurance_type, params.region_code)?;
     
-    let current_time = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Fill in a newly created policy from validated `params`, charging
+/// `premium_amount`, and count it as issued and active
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_policy(
+    policy_account: &mut Policy,
+    master_contract: &mut MasterInsuranceContract,
+    params: CreatePolicyParams,
+    policy_id: u64,
+    premium_amount: u64,
+    policy_holder: Pubkey,
+    rent_payer: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    let current_time = now(master_contract)?;
     let end_date = current_time + (params.policy_duration_days as i64 * 86400); // Convert days to seconds
     
+    let installment_schedule = params.installment_plan.map(|arrears_mode| {
+        InstallmentSchedule::new(
+            premium_amount,
+            current_time,
+            end_date,
+            &params.premium_payment_frequency,
+            arrears_mode,
+        )
+    });
+    
     // Initialize policy
-    policy_account.id = policy_id.clone();
-    policy_account.user = policy_holder.key();
+    policy_account.id = policy_id;
+    policy_account.user = policy_holder;
     policy_account.insurance_type = params.insurance_type;
     policy_account.coverage_amount = params.coverage_amount;
-    policy_account.premium_amount = params.premium_amount;
+    policy_account.premium_amount = premium_amount;
     policy_account.deductible = params.deductible;
     policy_account.start_date = current_time;
     policy_account.end_date = end_date;
-    policy_account.status = PolicyStatus::Active;
+    policy_account.status = PolicyStatus::PendingActivation; // Activated by the first premium payment
     policy_account.trigger_conditions = params.trigger_conditions;
     policy_account.oracle_config = params.oracle_config;
-    policy_account.last_premium_paid = current_time;
-    policy_account.payout_history = Vec::new();
+    policy_account.last_premium_paid = 0;
+    policy_account.total_premiums_paid = 0;
     policy_account.risk_assessment_score = params.risk_assessment_score;
     policy_account.max_payout_per_incident = params.max_payout_per_incident;
     policy_account.waiting_period_hours = params.waiting_period_hours;
     policy_account.premium_payment_frequency = params.premium_payment_frequency;
     policy_account.auto_renewal = params.auto_renewal;
     policy_account.metadata = params.metadata;
+    policy_account.metadata_uri = params.metadata_uri;
+    policy_account.policy_mint = None;
     policy_account.created_at = current_time;
     policy_account.updated_at = current_time;
+    policy_account.creator = policy_holder;
+    policy_account.index = master_contract.policies_issued;
+    policy_account.bump = bump;
+    policy_account.quorum_signatures = params.quorum_signatures;
+    policy_account.deductible_mode = params.deductible_mode;
+    policy_account.rent_payer = rent_payer;
+    policy_account.installment_schedule = installment_schedule;
+    policy_account.installments_paid = 0;
+    policy_account.installment_partial = 0;
+    policy_account.late_fee_waived_due = None;
+    policy_account.pending_trigger_amendment = None;
+    policy_account.premium_currency = params.premium_currency;
+    policy_account.payout_currency = params.payout_currency;
+    policy_account.min_severity_bps = params.min_severity_bps;
+    policy_account.payout_vesting = params.payout_vesting;
+    policy_account.coverage_suspended_at = None;
+    policy_account.co_signer = None;
+    policy_account.underwriting_bond = 0;
+    policy_account.claims_paid = 0;
+    policy_account.region_code = params.region_code;
+    policy_account.region_tracked = false;
     
     // Update master contract
+    master_contract.policies_issued += 1;
     master_contract.active_policies_count += 1;
     master_contract.updated_at = current_time;
-    
-    msg!("Policy created with ID: {} for user: {}", 
-        policy_account.id, 
-        policy_holder.key()
-    );
-    
+
     Ok(())
 }
 
@@ -150,47 +724,568 @@ pub fn pay_premium(ctx: Context<PayPremium>, amount: u64) -> Result<()> {
     let master_contract = &mut ctx.accounts.master_contract;
     let payer = &ctx.accounts.payer;
     
-    // Check contract is not paused
-    require_not_paused!(master_contract.is_paused);
+    // Premiums stay payable during a pause unless it blocks them
+    require!(!master_contract.premiums_paused(), InsuranceError::PremiumPaymentsPaused);
     
-    // Validate policy is active
+    // Validate policy is active or awaiting its first premium
     require!(
-        matches!(policy_account.status, PolicyStatus::Active),
+        matches!(policy_account.status, PolicyStatus::Active | PolicyStatus::PendingActivation),
         InsuranceError::PolicyNotActive
     );
+    require!(
+        policy_account.coverage_suspended_at.is_none(),
+        InsuranceError::CoverageSuspended
+    );
     
     // Check policy hasn't expired
-    let current_time = Clock::get()?.unix_timestamp;
+    let current_time = now(master_contract)?;
     require!(
         current_time <= policy_account.end_date,
         InsuranceError::PolicyExpired
     );
     
-    // Validate premium amount
-    require!(
-        amount >= policy_account.premium_amount,
-        InsuranceError::InsufficientPremium
-    );
+    // An overdue premium carries a late fee, unless waived; it comes out of the
+    // payment first and goes to the fee vault, apart from premiums and reserves
+    let late_fee = policy_account.late_fee(master_contract, current_time);
+    let amount = amount
+        .checked_sub(late_fee)
+        .ok_or(InsuranceError::LateFeeUnpaid)?;
     
-    // Validate payer is policy holder
+    // Scheduled policies take partial payments toward the earliest unpaid
+    // installment; others pay at least one full premium
+    let installment_index = match policy_account.installment_schedule {
+        Some(schedule) => {
+            require!(amount > 0, InsuranceError::InsufficientPremium);
+            let progress = siglab_core::apply_installment_payment(
+                schedule.amount,
+                schedule.count,
+                policy_account.installments_paid,
+                policy_account.installment_partial,
+                amount,
+            )
+            .ok_or(InsuranceError::InstallmentOverpayment)?;
+            policy_account.installments_paid = progress.paid;
+            policy_account.installment_partial = progress.partial;
+            Some(progress.applied_to)
+        }
+        None => {
+            require!(
+                amount >= policy_account.premium_amount,
+                InsuranceError::InsufficientPremium
+            );
+            let min_premium = crate::instructions::treasury::currency_converter(
+                &ctx.accounts.treasury,
+                master_contract,
+                ctx.remaining_accounts,
+                current_time,
+            )
+            .convert(master_contract.global_config.min_premium, policy_account.premium_currency)?;
+            require_sufficient_premium!(amount, min_premium);
+            None
+        }
+    };
+    
+    // Validate payer is the policy's beneficial owner
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy_account,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
     require!(
-        payer.key() == policy_account.user,
+        payer.key() == owner,
         InsuranceError::Unauthorized
     );
     
+    // An eligible subsidy pays its share into the treasury; once its budget
+    // runs out the holder pays the full premium. The budget is held in
+    // lamports, which cannot stand in for a USDC premium
+    let currency = policy_account.premium_currency;
+    let split = match ctx.accounts.subsidy.as_mut() {
+        Some(subsidy) => {
+            require!(
+                subsidy.covers(&policy_account.insurance_type, &owner),
+                InsuranceError::SubsidyNotApplicable
+            );
+            require!(
+                currency == TokenType::SOL,
+                InsuranceError::SubsidyCurrencyMismatch
+            );
+            let treasury = &mut ctx.accounts.treasury;
+            let split = siglab_core::split_premium(amount, subsidy.coverage_bps, subsidy.budget_remaining);
+            if split.sponsor_share > 0 {
+                draw_subsidy(subsidy, &treasury.to_account_info(), split.sponsor_share)?;
+                treasury.record_premium(split.sponsor_share, currency.is_usdc(), current_time);
+            }
+            split
+        }
+        None => siglab_core::PremiumSplit {
+            sponsor_share: 0,
+            holder_share: amount,
+        },
+    };
+    
+    // The holder's share moves into the treasury in the policy's currency
+    crate::instructions::treasury::collect_payment(
+        &ctx.accounts.treasury.to_account_info(),
+        payer,
+        currency,
+        split.holder_share,
+        ctx.accounts.payer_token_account.as_ref(),
+        ctx.accounts.treasury_usdc_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+    ctx.accounts.treasury.record_premium(split.holder_share, currency.is_usdc(), current_time);
+    
+    if late_fee > 0 {
+        let fee_vault = ctx.accounts.fee_vault.as_mut().ok_or(InsuranceError::FeeVaultRequired)?;
+        if let Some(account) = ctx.accounts.fee_vault_usdc_account.as_ref() {
+            require_keys_eq!(
+                account.key(),
+                fee_vault.usdc_token_account,
+                InsuranceError::FeeVaultAccountMismatch
+            );
+        }
+        crate::instructions::treasury::collect_payment(
+            &fee_vault.to_account_info(),
+            payer,
+            currency,
+            late_fee,
+            ctx.accounts.payer_token_account.as_ref(),
+            ctx.accounts.fee_vault_usdc_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+        )?;
+        fee_vault.record_fee(late_fee, currency.is_usdc())?;
+        
+        // Fees on co-signed policies count toward the co-signer's rebate
+        if let Some(co_signer) = policy_account.co_signer {
+            let ledger = ctx
+                .accounts
+                .underwriter_ledger
+                .as_mut()
+                .ok_or(InsuranceError::UnderwriterLedgerRequired)?;
+            require_keys_eq!(ledger.underwriter, co_signer, InsuranceError::UnderwriterLedgerMismatch);
+            ledger.record_fee(late_fee, currency.is_usdc(), current_time)?;
+        }
+    }
+    
     // Update payment record
+    let policy_history = &mut ctx.accounts.policy_history;
+    policy_history.open(policy_account.key(), ctx.bumps.policy_history);
+    policy_account.record_premium(policy_history, amount, current_time)?;
     policy_account.last_premium_paid = current_time;
     policy_account.updated_at = current_time;
     
+    // First premium, or first installment paid in full, activates coverage
+    let activating = policy_account.status == PolicyStatus::PendingActivation
+        && (policy_account.installment_schedule.is_none() || policy_account.installments_paid > 0);
+    if activating {
+        policy_account.transition(PolicyStatus::Active, current_time)?;
+        
+        emit!(crate::events::PolicyActivated {
+            policy_id: policy_account.id,
+            owner,
+            premium_amount: amount,
+            timestamp: current_time,
+            sequence: master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::PayPremium>(),
+        });
+    }
+    
     // Update master contract financial tracking
     master_contract.total_premiums_collected = master_contract
         .total_premiums_collected
         .checked_add(amount)
         .ok_or(InsuranceError::MathOverflow)?;
+    master_contract.late_fees_collected = master_contract
+        .late_fees_collected
+        .checked_add(late_fee)
+        .ok_or(InsuranceError::MathOverflow)?;
+    let stats = master_contract.portfolio_stats_mut(&policy_account.insurance_type);
+    stats.record_premium(amount)?;
+    if activating {
+        stats.record_activation(policy_account.coverage_amount)?;
+    }
     
     master_contract.updated_at = current_time;
     
+    emit!(crate::events::PremiumPaid {
+        policy_id: policy_account.id,
+        payer: payer.key(),
+        amount,
+        holder_amount: split.holder_share,
+        subsidy_amount: split.sponsor_share,
+        subsidy: ctx.accounts.subsidy.as_ref().map(|subsidy| subsidy.key()),
+        total_premiums_paid: policy_account.total_premiums_paid,
+        installment_index,
+        late_fee,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::PayPremium>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        &mut ctx.accounts.treasury,
+        master_contract,
+        current_time,
+        instruction_discriminator::<crate::instruction::PayPremium>(),
+    );
+    
     msg!("Premium paid: {} lamports for policy: {}", amount, policy_account.id);
     
     Ok(())
+}
+
+pub fn update_policy_metadata(
+    ctx: Context<UpdatePolicyMetadata>,
+    metadata: String,
+    metadata_uri: Option<String>,
+) -> Result<()> {
+    let policy_account = &mut ctx.accounts.policy_account;
+    let current_time = now(&ctx.accounts.master_contract)?;
+    
+    // Only the policy's beneficial owner may update metadata
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy_account,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
+    require!(
+        ctx.accounts.owner.key() == owner,
+        InsuranceError::Unauthorized
+    );
+    
+    Policy::validate_metadata(&metadata, &metadata_uri)?;
+    
+    policy_account.metadata = metadata;
+    policy_account.metadata_uri = metadata_uri;
+    policy_account.updated_at = current_time;
+    
+    emit!(crate::events::PolicyMetadataUpdated {
+        policy_id: policy_account.id,
+        owner,
+        metadata_uri: policy_account.metadata_uri.clone(),
+        timestamp: current_time,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::UpdatePolicyMetadata>(),
+    });
+    
+    Ok(())
+}
+
+/// Let a keeper file payouts for the policy when its trigger fires, or stop
+/// it; `keeper` left default lets anyone file
+pub fn set_auto_claim(ctx: Context<SetAutoClaim>, enabled: bool, keeper: Pubkey) -> Result<()> {
+    let policy_account = &mut ctx.accounts.policy_account;
+    let current_time = now(&ctx.accounts.master_contract)?;
+    
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy_account,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
+    require!(
+        ctx.accounts.owner.key() == owner,
+        InsuranceError::Unauthorized
+    );
+    
+    policy_account.auto_claim = enabled;
+    policy_account.claim_keeper = keeper;
+    policy_account.updated_at = current_time;
+    
+    emit!(crate::events::AutoClaimUpdated {
+        policy_id: policy_account.id,
+        owner,
+        enabled,
+        keeper,
+        timestamp: current_time,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetAutoClaim>(),
+    });
+    
+    Ok(())
+}
+
+pub fn adjust_coverage(
+    ctx: Context<AdjustCoverage>,
+    new_coverage_amount: u64,
+    max_additional_premium: u64,
+) -> Result<()> {
+    let policy_account = &mut ctx.accounts.policy_account;
+    let policy_history = &mut ctx.accounts.policy_history;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let treasury = &mut ctx.accounts.treasury;
+    let current_time = now(master_contract)?;
+    
+    require_not_paused!(master_contract.is_paused);
+    
+    require!(
+        current_time <= policy_account.end_date,
+        InsuranceError::PolicyExpired
+    );
+    
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy_account,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
+    require!(
+        ctx.accounts.owner.key() == owner,
+        InsuranceError::Unauthorized
+    );
+    
+    // The premium difference is settled at once, which an installment
+    // schedule fixed at sale would not reflect
+    require!(
+        policy_account.installment_schedule.is_none(),
+        InsuranceError::InstallmentCoverageNotAdjustable
+    );
+    
+    let treasury_balance_before = treasury.balance(policy_account.premium_currency);
+    
+    // Validate new coverage and re-validate dependent limits
+    let max_coverage = crate::instructions::treasury::currency_converter(
+        treasury,
+        master_contract,
+        ctx.remaining_accounts,
+        current_time,
+    )
+    .convert(master_contract.global_config.max_coverage, policy_account.payout_currency)?;
+    require!(
+        new_coverage_amount > 0 && new_coverage_amount <= max_coverage,
+        InsuranceError::CoverageExceedsMaximum
+    );
+    require!(
+        new_coverage_amount != policy_account.coverage_amount,
+        InsuranceError::InvalidParameters
+    );
+    require!(
+        policy_account.deductible <= new_coverage_amount,
+        InsuranceError::InvalidParameters
+    );
+    require!(
+        policy_account.max_payout_per_incident <= new_coverage_amount,
+        InsuranceError::InvalidParameters
+    );
+    
+    let old_coverage_amount = policy_account.coverage_amount;
+    let old_premium_amount = policy_account.premium_amount;
+    let in_force = matches!(policy_account.status, PolicyStatus::Active | PolicyStatus::PendingPayout);
+    let mut additional_premium = 0;
+    let mut refund_amount = 0;
+    
+    if new_coverage_amount > old_coverage_amount {
+        // Added coverage is a new sale
+        require_not_in_winddown!(master_contract.winddown);
+        let delta = new_coverage_amount - old_coverage_amount;
+        additional_premium = policy_account.pro_rata_premium(delta, current_time)?;
+        require!(
+            additional_premium <= max_additional_premium,
+            InsuranceError::InsufficientPremium
+        );
+        
+        // Fresh solvency check against the added exposure
+        crate::instructions::treasury::validate_treasury_solvency(treasury, delta)?;
+        let payout_currency = policy_account.payout_currency;
+        require!(
+            treasury
+                .coverage_exposure(payout_currency)
+                .checked_add(delta)
+                .is_some_and(|exposure| exposure <= master_contract.global_config.max_exposure(payout_currency)),
+            InsuranceError::CurrencyExposureLimitExceeded
+        );
+        
+        treasury.add_coverage_exposure(payout_currency, delta)?;
+        crate::instructions::region::add_region_exposure(
+            policy_account,
+            ctx.accounts.region_exposure.as_mut(),
+            delta,
+            treasury,
+            master_contract,
+            instruction_discriminator::<crate::instruction::AdjustCoverage>(),
+        )?;
+        crate::instructions::treasury::collect_payment(
+            &treasury.to_account_info(),
+            &ctx.accounts.owner,
+            policy_account.premium_currency,
+            additional_premium,
+            ctx.accounts.owner_usdc_account.as_ref(),
+            ctx.accounts.treasury_usdc_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+        )?;
+        treasury.record_premium(additional_premium, policy_account.premium_currency.is_usdc(), current_time);
+        policy_account.record_premium(policy_history, additional_premium, current_time)?;
+        
+        master_contract.total_premiums_collected = master_contract
+            .total_premiums_collected
+            .checked_add(additional_premium)
+            .ok_or(InsuranceError::MathOverflow)?;
+        let stats = master_contract.portfolio_stats_mut(&policy_account.insurance_type);
+        stats.record_premium(additional_premium)?;
+        if in_force {
+            stats.add_exposure(delta)?;
+        }
+    } else {
+        let delta = old_coverage_amount - new_coverage_amount;
+        refund_amount = policy_account.refundable_premium(policy_history.latest_premium(), delta, current_time)?;
+        policy_account.total_premiums_paid = policy_account
+            .total_premiums_paid
+            .checked_sub(refund_amount)
+            .ok_or(InsuranceError::MathOverflow)?;
+        
+        treasury.release_coverage_exposure(policy_account.payout_currency, delta);
+        crate::instructions::region::release_region_exposure(
+            policy_account,
+            ctx.accounts.region_exposure.as_mut(),
+            delta,
+            master_contract,
+            instruction_discriminator::<crate::instruction::AdjustCoverage>(),
+        )?;
+        // The refund leaves the treasury in the currency the premium was paid in
+        let owner_usdc_account = ctx.accounts.owner_usdc_account.as_ref().map(|account| account.to_account_info());
+        crate::instructions::treasury::disburse(
+            treasury,
+            &ctx.accounts.owner.to_account_info(),
+            policy_account.premium_currency.is_usdc(),
+            refund_amount,
+            ctx.accounts.treasury_usdc_account.as_ref(),
+            owner_usdc_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        treasury.record_refund(refund_amount, policy_account.premium_currency.is_usdc(), current_time);
+        treasury.withdrawal_count += 1;
+        
+        let stats = master_contract.portfolio_stats_mut(&policy_account.insurance_type);
+        stats.record_refund(refund_amount);
+        if in_force {
+            stats.release_exposure(delta);
+        }
+    }
+    
+    // Reprice future premiums at the same rate
+    let new_premium_amount = (old_premium_amount as u128)
+        .checked_mul(new_coverage_amount as u128)
+        .and_then(|x| x.checked_div(old_coverage_amount as u128))
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(InsuranceError::MathOverflow)?;
+    
+    let endorsement = policy_history.endorse(
+        EndorsementKind::CoverageAdjustment,
+        Endorsement::encode_amount(old_coverage_amount),
+        Endorsement::encode_amount(new_coverage_amount),
+        owner,
+        current_time,
+    )?;
+    policy_account.coverage_amount = new_coverage_amount;
+    policy_account.premium_amount = new_premium_amount;
+    policy_account.updated_at = current_time;
+    master_contract.updated_at = current_time;
+    
+    emit!(crate::events::PolicyEndorsed {
+        policy_id: policy_account.id,
+        kind: endorsement.kind,
+        before: endorsement.before,
+        after: endorsement.after,
+        actor: endorsement.actor,
+        endorsement_count: policy_history.endorsements.len() as u8,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::AdjustCoverage>(),
+    });
+    
+    emit!(crate::events::CoverageAdjusted {
+        policy_id: policy_account.id,
+        owner,
+        old_coverage_amount,
+        new_coverage_amount,
+        old_premium_amount,
+        new_premium_amount,
+        additional_premium,
+        refund_amount,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(policy_account.premium_currency),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::AdjustCoverage>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        current_time,
+        instruction_discriminator::<crate::instruction::AdjustCoverage>(),
+    );
+    
+    Ok(())
+}
+
+/// Expire a policy past its end date (permissionless crank)
+pub fn expire_policy(ctx: Context<ExpirePolicy>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    
+    let coverage_released = expire_overdue_policy(
+        policy,
+        master_contract,
+        &mut ctx.accounts.treasury,
+        ctx.accounts.region_exposure.as_mut(),
+        current_time,
+        instruction_discriminator::<crate::instruction::ExpirePolicy>(),
+    )?;
+    
+    emit!(crate::events::PolicyExpired {
+        policy_id: policy.id,
+        coverage_released,
+        expired_by: ctx.accounts.cranker.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ExpirePolicy>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        &mut ctx.accounts.treasury,
+        master_contract,
+        current_time,
+        instruction_discriminator::<crate::instruction::ExpirePolicy>(),
+    );
+    
+    Ok(())
+}
+
+/// Move a policy past its end date to `Expired`, taking it out of the active
+/// count and releasing the portfolio exposure of one that was in force
+///
+/// The coverage booked at sale leaves the treasury's and the region's
+/// exposure whatever the status. Returns the in-force coverage released.
+pub(crate) fn expire_overdue_policy(
+    policy: &mut Policy,
+    master_contract: &mut MasterInsuranceContract,
+    treasury: &mut Treasury,
+    region_exposure: Option<&mut Account<RegionExposure>>,
+    current_time: i64,
+    instruction: [u8; 8],
+) -> Result<u64> {
+    require!(current_time > policy.end_date, InsuranceError::PolicyNotExpired);
+    
+    let coverage_released = match policy.status {
+        PolicyStatus::Active => policy.coverage_amount,
+        _ => 0,
+    };
+    policy.transition(PolicyStatus::Expired, current_time)?;
+    
+    treasury.release_coverage_exposure(policy.payout_currency, policy.coverage_amount);
+    crate::instructions::region::release_region_exposure(
+        policy,
+        region_exposure,
+        policy.coverage_amount,
+        master_contract,
+        instruction,
+    )?;
+    master_contract
+        .portfolio_stats_mut(&policy.insurance_type)
+        .release_exposure(coverage_released);
+    master_contract.active_policies_count = master_contract.active_policies_count.saturating_sub(1);
+    master_contract.updated_at = current_time;
+    
+    Ok(coverage_released)
 }
\ No newline at end of file
@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    self, Burn, CloseAccount, FreezeAccount, Mint, MintTo, SetAuthority, ThawAccount, Token,
+    TokenAccount,
+};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use crate::state::{MasterInsuranceContract, Policy, PolicyStatus, PolicyTokenMetadata, RegionExposure, Treasury};
+use crate::error::InsuranceError;
+use crate::utils::clock_utils::current_clock;
+use crate::events::{instruction_discriminator, PolicyClosed};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct ClosePolicy<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer @ InsuranceError::RentPayerMismatch,
+        constraint = policy.status != PolicyStatus::PendingPayout @ InsuranceError::PolicyNotClosable,
+        constraint = policy.underwriting_bond == 0 @ InsuranceError::UnderwritingBondLocked
+    )]
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Treasury releasing the exposure of a policy closed without expiring
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives the policy rent; validated against the stored rent payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// Policy token mint (required for tokenized policies)
+    #[account(
+        mut,
+        seeds = [POLICY_MINT_SEED, policy.key().as_ref()],
+        bump
+    )]
+    pub policy_mint: Option<Account<'info, Mint>>,
+
+    /// Owner's token account holding the policy token
+    #[account(mut)]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [POLICY_TOKEN_METADATA_SEED, policy_token_metadata.mint.as_ref()],
+        bump = policy_token_metadata.bump
+    )]
+    pub policy_token_metadata: Option<Account<'info, PolicyTokenMetadata>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Region the coverage is booked in (required for tracked policies)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+}
+
+pub fn close_policy(ctx: Context<ClosePolicy>) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+
+    // Only policies that can no longer claim may be closed
+    let closable = match policy.status {
+        PolicyStatus::Expired | PolicyStatus::Cancelled | PolicyStatus::PaidOut => true,
+        PolicyStatus::Active => clock.unix_timestamp > policy.end_date,
+        PolicyStatus::PendingActivation => {
+            clock.unix_timestamp > policy.created_at + ctx.accounts.master_contract.activation_window
+        }
+        PolicyStatus::PendingPayout => false,
+    };
+    require!(closable, InsuranceError::PolicyNotClosable);
+
+    // A policy closed without expiring leaves the active count here, and an
+    // active one past its end date its in-force exposure too
+    if matches!(policy.status, PolicyStatus::Active | PolicyStatus::PendingActivation) {
+        let master_contract = &mut ctx.accounts.master_contract;
+        master_contract.active_policies_count = master_contract.active_policies_count.saturating_sub(1);
+        ctx.accounts
+            .treasury
+            .release_coverage_exposure(policy.payout_currency, policy.coverage_amount);
+        crate::instructions::region::release_region_exposure(
+            policy,
+            ctx.accounts.region_exposure.as_mut(),
+            policy.coverage_amount,
+            &mut ctx.accounts.master_contract,
+            instruction_discriminator::<crate::instruction::ClosePolicy>(),
+        )?;
+    }
+    if policy.status == PolicyStatus::Active {
+        ctx.accounts
+            .master_contract
+            .portfolio_stats_mut(&policy.insurance_type)
+            .release_exposure(policy.coverage_amount);
+    }
+
+    let owner = resolve_beneficial_owner(policy, ctx.accounts.owner_token_account.as_ref())?;
+    require!(
+        owner == ctx.accounts.owner.key(),
+        InsuranceError::Unauthorized
+    );
+
+    // Burn the policy token and close the holder's token account
+    let token_burned = match policy.policy_mint {
+        Some(mint) => {
+            let (policy_mint, owner_token_account, token_program, metadata) = match (
+                ctx.accounts.policy_mint.as_ref(),
+                ctx.accounts.owner_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.policy_token_metadata.as_ref(),
+            ) {
+                (Some(m), Some(t), Some(p), Some(d)) => (m, t, p, d),
+                _ => return Err(InsuranceError::PolicyTokenAccountRequired.into()),
+            };
+            require!(
+                policy_mint.key() == mint && metadata.mint == mint,
+                InsuranceError::InvalidPolicyTokenAccount
+            );
+
+            if owner_token_account.is_frozen() {
+                thaw_policy_token(
+                    &ctx.accounts.master_contract,
+                    policy_mint,
+                    owner_token_account,
+                    token_program,
+                )?;
+            }
+
+            token::burn(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Burn {
+                        mint: policy_mint.to_account_info(),
+                        from: owner_token_account.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+
+            token::close_account(CpiContext::new(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: owner_token_account.to_account_info(),
+                    destination: ctx.accounts.owner.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ))?;
+
+            true
+        }
+        None => false,
+    };
+
+    emit!(PolicyClosed {
+        policy_id: policy.id,
+        owner,
+        token_burned,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ClosePolicy>(),
+    });
+
+    Ok(())
+}
+
+/// Resolve the beneficial owner of a policy: the current token holder for
+/// tokenized policies, the original policyholder otherwise
+pub fn resolve_beneficial_owner(
+    policy: &Policy,
+    policy_token_account: Option<&Account<TokenAccount>>,
+) -> Result<Pubkey> {
+    match policy.policy_mint {
+        Some(mint) => {
+            let token_account =
+                policy_token_account.ok_or(InsuranceError::PolicyTokenAccountRequired)?;
+            require!(
+                token_account.mint == mint && token_account.amount == 1,
+                InsuranceError::InvalidPolicyTokenAccount
+            );
+            Ok(token_account.owner)
+        }
+        None => Ok(policy.user),
+    }
+}
+
+/// Mint the single policy token and permanently remove the mint authority
+pub fn mint_policy_token<'info>(
+    master_contract: &Account<'info, MasterInsuranceContract>,
+    policy_mint: &Account<'info, Mint>,
+    holder_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[MASTER_CONTRACT_SEED, &[master_contract.bump]];
+    let signer_seeds = &[seeds];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: policy_mint.to_account_info(),
+                to: holder_token_account.to_account_info(),
+                authority: master_contract.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: master_contract.to_account_info(),
+                account_or_mint: policy_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    Ok(())
+}
+
+/// Freeze the holder's policy token so it cannot move while a payout is pending
+pub fn freeze_policy_token<'info>(
+    master_contract: &Account<'info, MasterInsuranceContract>,
+    policy_mint: &Account<'info, Mint>,
+    holder_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[MASTER_CONTRACT_SEED, &[master_contract.bump]];
+
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        FreezeAccount {
+            account: holder_token_account.to_account_info(),
+            mint: policy_mint.to_account_info(),
+            authority: master_contract.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
+
+/// Thaw the holder's policy token once the pending payout is settled
+pub fn thaw_policy_token<'info>(
+    master_contract: &Account<'info, MasterInsuranceContract>,
+    policy_mint: &Account<'info, Mint>,
+    holder_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[MASTER_CONTRACT_SEED, &[master_contract.bump]];
+
+    token::thaw_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        ThawAccount {
+            account: holder_token_account.to_account_info(),
+            mint: policy_mint.to_account_info(),
+            authority: master_contract.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
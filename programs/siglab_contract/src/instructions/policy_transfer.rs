@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    EndorsementKind, MasterInsuranceContract, Policy, PolicyHistory, PolicyStatus, PolicyTransferProposal,
+};
+use crate::error::InsuranceError;
+use crate::utils::clock_utils::current_clock;
+use crate::events::{
+    instruction_discriminator, PolicyEndorsed, PolicyTransferCancelled, PolicyTransferProposed, PolicyTransferred,
+};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct ProposePolicyTransfer<'info> {
+    #[account(
+        constraint = policy.user == current_owner.key() @ InsuranceError::Unauthorized,
+        constraint = policy.status != PolicyStatus::PendingPayout @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        init,
+        payer = current_owner,
+        space = PolicyTransferProposal::space(),
+        seeds = [POLICY_TRANSFER_SEED, policy.key().as_ref()],
+        bump
+    )]
+    pub transfer_proposal: Account<'info, PolicyTransferProposal>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub current_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptPolicyTransfer<'info> {
+    #[account(
+        mut,
+        constraint = policy.user == transfer_proposal.proposer @ InsuranceError::Unauthorized,
+        constraint = policy.status != PolicyStatus::PendingPayout @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+
+    /// Policy's history, opened here if the policy has none yet
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = PolicyHistory::space(),
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [POLICY_TRANSFER_SEED, policy.key().as_ref()],
+        bump = transfer_proposal.bump,
+        constraint = transfer_proposal.new_owner == new_owner.key() @ InsuranceError::Unauthorized
+    )]
+    pub transfer_proposal: Account<'info, PolicyTransferProposal>,
+
+    /// CHECK: Receives the proposal rent; validated against the stored proposer
+    #[account(
+        mut,
+        address = transfer_proposal.proposer @ InsuranceError::Unauthorized
+    )]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPolicyTransfer<'info> {
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [POLICY_TRANSFER_SEED, policy.key().as_ref()],
+        bump = transfer_proposal.bump,
+        constraint = transfer_proposal.proposer == proposer.key() @ InsuranceError::Unauthorized
+    )]
+    pub transfer_proposal: Account<'info, PolicyTransferProposal>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+pub fn propose_policy_transfer(
+    ctx: Context<ProposePolicyTransfer>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let transfer_proposal = &mut ctx.accounts.transfer_proposal;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+
+    // Tokenized policies follow the token holder instead
+    require!(policy.policy_mint.is_none(), InsuranceError::PolicyIsTokenized);
+
+    require!(
+        new_owner != Pubkey::default() && new_owner != policy.user,
+        InsuranceError::InvalidInput
+    );
+
+    transfer_proposal.policy = policy.key();
+    transfer_proposal.proposer = ctx.accounts.current_owner.key();
+    transfer_proposal.new_owner = new_owner;
+    transfer_proposal.proposed_at = clock.unix_timestamp;
+    transfer_proposal.expires_at = clock.unix_timestamp + POLICY_TRANSFER_EXPIRY;
+    transfer_proposal.bump = ctx.bumps.transfer_proposal;
+
+    emit!(PolicyTransferProposed {
+        policy_id: policy.id,
+        current_owner: ctx.accounts.current_owner.key(),
+        new_owner,
+        expires_at: transfer_proposal.expires_at,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ProposePolicyTransfer>(),
+    });
+
+    Ok(())
+}
+
+pub fn accept_policy_transfer(ctx: Context<AcceptPolicyTransfer>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let transfer_proposal = &ctx.accounts.transfer_proposal;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+
+    require!(
+        !transfer_proposal.is_expired(clock.unix_timestamp),
+        InsuranceError::TransferProposalExpired
+    );
+
+    let previous_owner = policy.user;
+    let policy_history = &mut ctx.accounts.policy_history;
+    policy_history.open(policy.key(), ctx.bumps.policy_history);
+    let endorsement = policy_history.endorse(
+        EndorsementKind::OwnershipTransfer,
+        previous_owner.to_bytes(),
+        transfer_proposal.new_owner.to_bytes(),
+        ctx.accounts.new_owner.key(),
+        clock.unix_timestamp,
+    )?;
+    policy.user = transfer_proposal.new_owner;
+    policy.updated_at = clock.unix_timestamp;
+
+    emit!(PolicyEndorsed {
+        policy_id: policy.id,
+        kind: endorsement.kind,
+        before: endorsement.before,
+        after: endorsement.after,
+        actor: endorsement.actor,
+        endorsement_count: policy_history.endorsements.len() as u8,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::AcceptPolicyTransfer>(),
+    });
+
+    emit!(PolicyTransferred {
+        policy_id: policy.id,
+        previous_owner,
+        new_owner: policy.user,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::AcceptPolicyTransfer>(),
+    });
+
+    msg!("Policy {} transferred from {} to {}", policy.id, previous_owner, policy.user);
+    Ok(())
+}
+
+pub fn cancel_policy_transfer(ctx: Context<CancelPolicyTransfer>) -> Result<()> {
+    let transfer_proposal = &ctx.accounts.transfer_proposal;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+
+    emit!(PolicyTransferCancelled {
+        policy_id: ctx.accounts.policy.id,
+        current_owner: transfer_proposal.proposer,
+        new_owner: transfer_proposal.new_owner,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CancelPolicyTransfer>(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsuranceType, MasterInsuranceContract, PortfolioReport, RegionExposure, RegionReport, TypeReport};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, MAX_REPORT_REGIONS, PORTFOLIO_REPORT_SEED};
+use crate::utils::clock_utils::current_clock;
+use crate::events::{instruction_discriminator, PortfolioReportPublished};
+
+#[derive(Accounts)]
+pub struct PublishPortfolioReport<'info> {
+    #[account(
+        init,
+        payer = publisher,
+        space = PortfolioReport::space(),
+        seeds = [PORTFOLIO_REPORT_SEED, &master_contract.portfolio_report_count.to_le_bytes()],
+        bump
+    )]
+    pub portfolio_report: Account<'info, PortfolioReport>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Snapshot the per-type underwriting totals and their ratios (permissionless)
+///
+/// The report is written to a new account numbered by the master contract's
+/// `portfolio_report_count` and announced with the same figures, so anyone can
+/// check a published loss ratio against the chain. Regions to report are
+/// passed as remaining accounts.
+pub fn publish_portfolio_report(ctx: Context<PublishPortfolioReport>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let master_contract = &mut ctx.accounts.master_contract;
+    
+    let types: Vec<TypeReport> = InsuranceType::ALL
+        .into_iter()
+        .map(|insurance_type| {
+            let stats = master_contract.portfolio_stats[insurance_type.index()];
+            TypeReport::new(insurance_type, stats)
+        })
+        .collect();
+    let regions = region_reports(ctx.remaining_accounts)?;
+    let report_index = master_contract.portfolio_report_count;
+    let open_payout_count = master_contract.open_payout_count;
+    let sequence = master_contract.next_event_sequence();
+    
+    let report = &mut ctx.accounts.portfolio_report;
+    report.report_index = report_index;
+    report.publisher = ctx.accounts.publisher.key();
+    report.types = types.clone();
+    report.open_payout_count = open_payout_count;
+    report.regions = regions.clone();
+    report.timestamp = clock.unix_timestamp;
+    report.sequence = sequence;
+    report.bump = ctx.bumps.portfolio_report;
+    
+    master_contract.portfolio_report_count = report_index + 1;
+    
+    emit!(PortfolioReportPublished {
+        report: report.key(),
+        report_index,
+        types,
+        open_payout_count,
+        regions,
+        timestamp: clock.unix_timestamp,
+        sequence,
+        instruction: instruction_discriminator::<crate::instruction::PublishPortfolioReport>(),
+    });
+    
+    Ok(())
+}
+
+/// Exposure of each region among `region_accounts`, which must be distinct
+/// region accounts of this program
+fn region_reports(region_accounts: &[AccountInfo]) -> Result<Vec<RegionReport>> {
+    require!(region_accounts.len() <= MAX_REPORT_REGIONS, InsuranceError::InvalidParameters);
+    let mut regions: Vec<RegionReport> = Vec::with_capacity(region_accounts.len());
+    for account_info in region_accounts {
+        require!(account_info.owner == &crate::ID, InsuranceError::InvalidParameters);
+        let region_exposure = RegionExposure::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        require!(
+            regions.iter().all(|region| region.region_code != region_exposure.region_code),
+            InsuranceError::InvalidParameters
+        );
+        regions.push(RegionReport::new(&region_exposure));
+    }
+    Ok(regions)
+}
\ No newline at end of file
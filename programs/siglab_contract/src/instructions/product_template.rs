@@ -0,0 +1,330 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::InsuranceError;
+use crate::constants::{
+    MASTER_CONTRACT_SEED, POLICY_SEED, PRODUCT_TEMPLATE_SEED, REGION_EXPOSURE_SEED, TYPE_CONFIG_SEED,
+    UNDERWRITER_LEDGER_SEED,
+};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{
+    instruction_discriminator, PolicyCreatedFromTemplate, ProductTemplateCreated, ProductTemplateStatusChanged,
+};
+use crate::instructions::policy::{initialize_policy, validate_new_policy, CreatePolicyParams};
+use crate::instructions::region::book_new_policy;
+use crate::instructions::underwriting::co_sign_policy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateProductTemplateParams {
+    pub product_id: u32,
+    pub version: u16,
+    pub terms: ProductTerms,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateProductTemplateParams)]
+pub struct CreateProductTemplate<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ProductTemplate::space(),
+        seeds = [PRODUCT_TEMPLATE_SEED, &params.product_id.to_le_bytes(), &params.version.to_le_bytes()],
+        bump
+    )]
+    pub product_template: Account<'info, ProductTemplate>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetProductTemplateActive<'info> {
+    #[account(
+        mut,
+        seeds = [
+            PRODUCT_TEMPLATE_SEED,
+            &product_template.product_id.to_le_bytes(),
+            &product_template.version.to_le_bytes(),
+        ],
+        bump = product_template.bump
+    )]
+    pub product_template: Account<'info, ProductTemplate>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePolicyFromTemplate<'info> {
+    /// Template the policy is issued from
+    #[account(
+        mut,
+        seeds = [
+            PRODUCT_TEMPLATE_SEED,
+            &product_template.product_id.to_le_bytes(),
+            &product_template.version.to_le_bytes(),
+        ],
+        bump = product_template.bump,
+        constraint = product_template.active @ InsuranceError::ProductTemplateInactive
+    )]
+    pub product_template: Account<'info, ProductTemplate>,
+
+    /// Holder the policy is issued to; needs no lamports when a sponsor pays the rent
+    pub policy_holder: Signer<'info>,
+
+    /// Pays the rent of the policy, refunded to it on close (may be the holder)
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Policy account to be created
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Policy::space(),
+        seeds = [POLICY_SEED, policy_holder.key().as_ref(), &master_contract.policies_issued.to_le_bytes()],
+        bump,
+    )]
+    pub policy_account: Account<'info, Policy>,
+
+    /// Configuration of the template's insurance type
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &product_template.terms.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+
+    /// Treasury backing the policy, which must support the template's currency
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Underwriter, or the authority while none is set, co-signing coverage at
+    /// or above the co-signing threshold and paying its bond
+    #[account(mut)]
+    pub co_signer: Option<Signer<'info>>,
+
+    /// Co-signer's ledger, booking the coverage toward its fee rebate (required when co-signing)
+    #[account(
+        mut,
+        seeds = [UNDERWRITER_LEDGER_SEED, underwriter_ledger.underwriter.as_ref()],
+        bump = underwriter_ledger.bump,
+    )]
+    pub underwriter_ledger: Option<Account<'info, UnderwriterLedger>>,
+
+    /// Region the coverage is booked in (required for a region while an exposure cap is set)
+    #[account(
+        mut,
+        seeds = [REGION_EXPOSURE_SEED, &region_exposure.region_code],
+        bump = region_exposure.bump,
+    )]
+    pub region_exposure: Option<Account<'info, RegionExposure>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish a version of a standard product
+///
+/// The template's oracle must be registered, and its ranges must fit the
+/// global limits; the insurance type's limits are checked per policy.
+pub fn create_product_template(
+    ctx: Context<CreateProductTemplate>,
+    params: CreateProductTemplateParams,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    params.terms.validate(master_contract.global_config.max_policy_duration_days)?;
+    require!(
+        master_contract.oracle_registry.contains(&params.terms.oracle_config.oracle_address),
+        InsuranceError::OracleNotRegistered
+    );
+    let current_time = now(master_contract)?;
+
+    let product_template = &mut ctx.accounts.product_template;
+    product_template.product_id = params.product_id;
+    product_template.version = params.version;
+    product_template.terms = params.terms.clone();
+    product_template.active = true;
+    product_template.policies_issued = 0;
+    product_template.created_at = current_time;
+    product_template.updated_at = current_time;
+    product_template.bump = ctx.bumps.product_template;
+
+    emit!(ProductTemplateCreated {
+        product_id: params.product_id,
+        version: params.version,
+        admin: ctx.accounts.admin.key(),
+        terms: params.terms,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CreateProductTemplate>(),
+    });
+
+    Ok(())
+}
+
+/// Allow or stop issuing policies from a template version; policies already
+/// issued keep their terms either way
+pub fn set_product_template_active(ctx: Context<SetProductTemplateActive>, active: bool) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let product_template = &mut ctx.accounts.product_template;
+    product_template.active = active;
+    product_template.updated_at = current_time;
+
+    emit!(ProductTemplateStatusChanged {
+        product_id: product_template.product_id,
+        version: product_template.version,
+        admin: ctx.accounts.admin.key(),
+        active,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetProductTemplateActive>(),
+    });
+
+    Ok(())
+}
+
+/// Issue a policy from a template, choosing only its threshold, coverage and
+/// duration; the premium is quoted by the template's pricing curve
+///
+/// The holder names the region insured, which weather, earthquake and crop
+/// products require.
+///
+/// The policy is then checked like any other new policy, so the global and
+/// insurance type limits in force at issue still apply.
+pub fn create_policy_from_template(
+    ctx: Context<CreatePolicyFromTemplate>,
+    threshold: f64,
+    coverage_amount: u64,
+    duration_days: u32,
+    region_code: Option<[u8; 8]>,
+) -> Result<()> {
+    let terms = &ctx.accounts.product_template.terms;
+    terms.check_choices(threshold, coverage_amount, duration_days)?;
+    let premium_amount = terms.premium(threshold, coverage_amount, duration_days)?;
+
+    let mut params = CreatePolicyParams {
+        insurance_type: terms.insurance_type.clone(),
+        coverage_amount,
+        premium_amount,
+        deductible: terms.deductible(coverage_amount),
+        policy_duration_days: duration_days,
+        trigger_conditions: terms.trigger_conditions(threshold),
+        oracle_config: terms.oracle_config.clone(),
+        risk_assessment_score: terms.risk_assessment_score,
+        max_payout_per_incident: coverage_amount,
+        waiting_period_hours: terms.waiting_period_hours,
+        premium_payment_frequency: terms.premium_payment_frequency.clone(),
+        auto_renewal: false,
+        metadata: String::new(),
+        metadata_uri: None,
+        discount_code: None,
+        mint_policy_token: false,
+        quorum_signatures: 0,
+        deductible_mode: terms.deductible_mode,
+        installment_plan: None,
+        premium_currency: terms.currency,
+        payout_currency: terms.currency,
+        min_severity_bps: None,
+        payout_vesting: None,
+        region_code,
+    };
+
+    let instruction = instruction_discriminator::<crate::instruction::CreatePolicyFromTemplate>();
+    validate_new_policy(
+        &mut ctx.accounts.master_contract,
+        &ctx.accounts.type_config,
+        &mut ctx.accounts.treasury,
+        ctx.remaining_accounts,
+        &mut params,
+        instruction,
+    )?;
+
+    let policy_id = ctx.accounts.master_contract.allocate_policy_id()?;
+    initialize_policy(
+        &mut ctx.accounts.policy_account,
+        &mut ctx.accounts.master_contract,
+        params,
+        policy_id,
+        premium_amount,
+        ctx.accounts.policy_holder.key(),
+        ctx.accounts.rent_payer.key(),
+        ctx.bumps.policy_account,
+    )?;
+    co_sign_policy(
+        &mut ctx.accounts.policy_account,
+        &mut ctx.accounts.master_contract,
+        &mut ctx.accounts.treasury,
+        ctx.accounts.co_signer.as_ref(),
+        ctx.accounts.underwriter_ledger.as_mut(),
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        instruction,
+    )?;
+    book_new_policy(
+        &mut ctx.accounts.policy_account,
+        ctx.accounts.region_exposure.as_mut(),
+        &ctx.accounts.treasury,
+        &mut ctx.accounts.master_contract,
+        instruction,
+    )?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let product_template = &mut ctx.accounts.product_template;
+    product_template.policies_issued = product_template
+        .policies_issued
+        .checked_add(1)
+        .ok_or(InsuranceError::MathOverflow)?;
+
+    emit!(PolicyCreatedFromTemplate {
+        policy_id,
+        holder: ctx.accounts.policy_holder.key(),
+        product_id: product_template.product_id,
+        version: product_template.version,
+        threshold,
+        coverage_amount,
+        duration_days,
+        premium_amount,
+        timestamp: now(master_contract)?,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+
+    msg!("Policy created from template {} v{} with ID: {}",
+        product_template.product_id,
+        product_template.version,
+        policy_id
+    );
+
+    Ok(())
+}
\ No newline at end of file
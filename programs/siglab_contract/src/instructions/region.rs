@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use crate::state::{MasterInsuranceContract, Policy, RegionExposure, Treasury};
+use crate::error::InsuranceError;
+use crate::constants::{MASTER_CONTRACT_SEED, REGION_EXPOSURE_SEED};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{instruction_discriminator, RegionExposureChanged, RegionOpened};
+
+#[derive(Accounts)]
+#[instruction(region_code: [u8; 8])]
+pub struct OpenRegion<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = RegionExposure::space(),
+        seeds = [REGION_EXPOSURE_SEED, &region_code],
+        bump
+    )]
+    pub region_exposure: Account<'info, RegionExposure>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start tracking the exposure and daily payouts of `region_code`; policies
+/// sold in the region from now on are booked in it
+pub fn open_region(ctx: Context<OpenRegion>, region_code: [u8; 8]) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    require!(region_code != [0; 8], InsuranceError::InvalidRegionCode);
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let region_exposure = &mut ctx.accounts.region_exposure;
+    region_exposure.region_code = region_code;
+    region_exposure.sol_exposure = 0;
+    region_exposure.usdc_exposure = 0;
+    region_exposure.payout_day = siglab_core::payout_day(current_time);
+    region_exposure.sol_paid_today = 0;
+    region_exposure.usdc_paid_today = 0;
+    region_exposure.opened_at = current_time;
+    region_exposure.bump = ctx.bumps.region_exposure;
+
+    emit!(RegionOpened {
+        region_code,
+        region_exposure: region_exposure.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::OpenRegion>(),
+    });
+
+    Ok(())
+}
+
+/// Region code a new policy of `insurance_type` must carry: nonzero when
+/// given, and present for types that insure a place
+pub(crate) fn validate_region_code(
+    insurance_type: &crate::state::InsuranceType,
+    region_code: Option<[u8; 8]>,
+) -> Result<()> {
+    match region_code {
+        Some(region_code) => require!(region_code != [0; 8], InsuranceError::InvalidRegionCode),
+        None => require!(!insurance_type.requires_region(), InsuranceError::InvalidRegionCode),
+    }
+    Ok(())
+}
+
+/// Book a new policy's coverage in its region, within the region's exposure cap
+///
+/// A policy sold in a region without its account goes untracked, which is
+/// only allowed while no exposure cap is set.
+pub(crate) fn book_new_policy(
+    policy: &mut Policy,
+    region_exposure: Option<&mut Account<RegionExposure>>,
+    treasury: &Treasury,
+    master_contract: &mut MasterInsuranceContract,
+    instruction: [u8; 8],
+) -> Result<()> {
+    match (policy.region_code, region_exposure) {
+        (Some(region_code), Some(region_exposure)) => {
+            require!(region_exposure.region_code == region_code, InsuranceError::RegionMismatch);
+            policy.region_tracked = true;
+            add_region_exposure(
+                policy,
+                Some(region_exposure),
+                policy.coverage_amount,
+                treasury,
+                master_contract,
+                instruction,
+            )
+        }
+        (Some(_), None) => {
+            require!(
+                master_contract.region_limits.max_exposure_bps.is_none(),
+                InsuranceError::RegionExposureRequired
+            );
+            Ok(())
+        }
+        (None, Some(_)) => err!(InsuranceError::RegionMismatch),
+        (None, None) => Ok(()),
+    }
+}
+
+/// Add `coverage` to a tracked policy's region, within the region's exposure cap
+pub(crate) fn add_region_exposure(
+    policy: &Policy,
+    region_exposure: Option<&mut Account<RegionExposure>>,
+    coverage: u64,
+    treasury: &Treasury,
+    master_contract: &mut MasterInsuranceContract,
+    instruction: [u8; 8],
+) -> Result<()> {
+    let Some(region_exposure) = tracked_region(policy, region_exposure)? else {
+        return Ok(());
+    };
+    let currency = policy.payout_currency;
+    let cap = master_contract
+        .region_limits
+        .max_exposure_bps
+        .map(|cap_bps| siglab_core::region_cap(treasury.balance(currency), cap_bps));
+    let exposure_before = region_exposure.exposure(currency);
+    region_exposure.add_exposure(currency, coverage, cap)?;
+    emit_exposure_change(region_exposure, policy, exposure_before, master_contract, instruction)
+}
+
+/// Release `coverage` from a tracked policy's region
+pub(crate) fn release_region_exposure(
+    policy: &Policy,
+    region_exposure: Option<&mut Account<RegionExposure>>,
+    coverage: u64,
+    master_contract: &mut MasterInsuranceContract,
+    instruction: [u8; 8],
+) -> Result<()> {
+    let Some(region_exposure) = tracked_region(policy, region_exposure)? else {
+        return Ok(());
+    };
+    let exposure_before = region_exposure.exposure(policy.payout_currency);
+    region_exposure.release_exposure(policy.payout_currency, coverage);
+    emit_exposure_change(region_exposure, policy, exposure_before, master_contract, instruction)
+}
+
+/// Count a payout of `amount` against its region's day, within the daily
+/// payout cap; taken from the treasury balance before the payout
+///
+/// The account may be left out for untracked policies while no daily cap is set.
+pub(crate) fn record_region_payout(
+    policy: &Policy,
+    region_exposure: Option<&mut Account<RegionExposure>>,
+    amount: u64,
+    treasury: &Treasury,
+    master_contract: &MasterInsuranceContract,
+    timestamp: i64,
+) -> Result<()> {
+    let Some(region_exposure) = region_exposure else {
+        require!(
+            !policy.region_tracked
+                && (policy.region_code.is_none() || master_contract.region_limits.max_daily_payout_bps.is_none()),
+            InsuranceError::RegionExposureRequired
+        );
+        return Ok(());
+    };
+    require!(
+        policy.region_code == Some(region_exposure.region_code),
+        InsuranceError::RegionMismatch
+    );
+    let currency = policy.payout_currency;
+    let cap = master_contract
+        .region_limits
+        .max_daily_payout_bps
+        .map(|cap_bps| siglab_core::region_cap(treasury.balance(currency), cap_bps));
+    region_exposure.record_payout(currency, amount, timestamp, cap)
+}
+
+/// The account a tracked policy's coverage is booked in, which must be given
+fn tracked_region<'a, 'info>(
+    policy: &Policy,
+    region_exposure: Option<&'a mut Account<'info, RegionExposure>>,
+) -> Result<Option<&'a mut Account<'info, RegionExposure>>> {
+    if !policy.region_tracked {
+        return Ok(None);
+    }
+    let region_exposure = region_exposure.ok_or(InsuranceError::RegionExposureRequired)?;
+    require!(
+        policy.region_code == Some(region_exposure.region_code),
+        InsuranceError::RegionMismatch
+    );
+    Ok(Some(region_exposure))
+}
+
+fn emit_exposure_change(
+    region_exposure: &RegionExposure,
+    policy: &Policy,
+    exposure_before: u64,
+    master_contract: &mut MasterInsuranceContract,
+    instruction: [u8; 8],
+) -> Result<()> {
+    emit!(RegionExposureChanged {
+        region_code: region_exposure.region_code,
+        policy_id: policy.id,
+        currency: policy.payout_currency,
+        exposure_before,
+        exposure_after: region_exposure.exposure(policy.payout_currency),
+        timestamp: now(master_contract)?,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MASTER_CONTRACT_SEED, MAX_SUBSIDY_HOLDERS, SUBSIDY_SEED};
+use crate::error::InsuranceError;
+use crate::utils::clock_utils::current_clock;
+use crate::events::{instruction_discriminator, SubsidyClosed, SubsidyCreated, SubsidyToppedUp};
+use crate::state::{InsuranceType, MasterInsuranceContract, Subsidy};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSubsidyParams {
+    pub subsidy_id: u64,
+    pub insurance_type: Option<InsuranceType>,
+    pub allowed_holders: Vec<Pubkey>,
+    pub coverage_bps: u16,
+    /// Lamports deposited as the initial budget
+    pub budget: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateSubsidyParams)]
+pub struct CreateSubsidy<'info> {
+    #[account(
+        init,
+        payer = sponsor,
+        space = Subsidy::space(),
+        seeds = [SUBSIDY_SEED, sponsor.key().as_ref(), &params.subsidy_id.to_le_bytes()],
+        bump
+    )]
+    pub subsidy: Account<'info, Subsidy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TopUpSubsidy<'info> {
+    #[account(
+        mut,
+        seeds = [SUBSIDY_SEED, sponsor.key().as_ref(), &subsidy.subsidy_id.to_le_bytes()],
+        bump = subsidy.bump,
+        has_one = sponsor @ InsuranceError::Unauthorized
+    )]
+    pub subsidy: Account<'info, Subsidy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSubsidy<'info> {
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [SUBSIDY_SEED, sponsor.key().as_ref(), &subsidy.subsidy_id.to_le_bytes()],
+        bump = subsidy.bump,
+        has_one = sponsor @ InsuranceError::Unauthorized
+    )]
+    pub subsidy: Account<'info, Subsidy>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+}
+
+/// Create a subsidy and deposit its initial budget
+pub fn create_subsidy(ctx: Context<CreateSubsidy>, params: CreateSubsidyParams) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    require!(
+        params.coverage_bps > 0 && params.coverage_bps <= Subsidy::MAX_COVERAGE_BPS,
+        InsuranceError::InvalidInput
+    );
+    require!(
+        params.allowed_holders.len() <= MAX_SUBSIDY_HOLDERS,
+        InsuranceError::InvalidInput
+    );
+    require!(params.budget > 0, InsuranceError::InvalidInput);
+    
+    deposit(
+        &ctx.accounts.system_program,
+        &ctx.accounts.sponsor,
+        ctx.accounts.subsidy.to_account_info(),
+        params.budget,
+    )?;
+    
+    let subsidy = &mut ctx.accounts.subsidy;
+    subsidy.sponsor = ctx.accounts.sponsor.key();
+    subsidy.subsidy_id = params.subsidy_id;
+    subsidy.insurance_type = params.insurance_type.clone();
+    subsidy.allowed_holders = params.allowed_holders;
+    subsidy.coverage_bps = params.coverage_bps;
+    subsidy.budget_remaining = params.budget;
+    subsidy.total_disbursed = 0;
+    subsidy.created_at = clock.unix_timestamp;
+    subsidy.bump = ctx.bumps.subsidy;
+    
+    emit!(SubsidyCreated {
+        subsidy: subsidy.key(),
+        sponsor: subsidy.sponsor,
+        insurance_type: params.insurance_type,
+        holder_count: subsidy.allowed_holders.len() as u8,
+        coverage_bps: subsidy.coverage_bps,
+        budget: params.budget,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CreateSubsidy>(),
+    });
+    
+    Ok(())
+}
+
+/// Add `amount` lamports to a subsidy's budget (sponsor only)
+pub fn top_up_subsidy(ctx: Context<TopUpSubsidy>, amount: u64) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    require!(amount > 0, InsuranceError::InvalidInput);
+    
+    deposit(
+        &ctx.accounts.system_program,
+        &ctx.accounts.sponsor,
+        ctx.accounts.subsidy.to_account_info(),
+        amount,
+    )?;
+    
+    let subsidy = &mut ctx.accounts.subsidy;
+    subsidy.budget_remaining = subsidy
+        .budget_remaining
+        .checked_add(amount)
+        .ok_or(InsuranceError::MathOverflow)?;
+    
+    emit!(SubsidyToppedUp {
+        subsidy: subsidy.key(),
+        sponsor: subsidy.sponsor,
+        amount,
+        budget_remaining: subsidy.budget_remaining,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::TopUpSubsidy>(),
+    });
+    
+    Ok(())
+}
+
+/// Close a subsidy, returning its unspent budget and rent to the sponsor
+pub fn close_subsidy(ctx: Context<CloseSubsidy>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let subsidy = &ctx.accounts.subsidy;
+    
+    emit!(SubsidyClosed {
+        subsidy: subsidy.key(),
+        sponsor: subsidy.sponsor,
+        reclaimed_budget: subsidy.budget_remaining,
+        total_disbursed: subsidy.total_disbursed,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CloseSubsidy>(),
+    });
+    
+    // Subsidy account will be closed automatically due to close constraint
+    
+    Ok(())
+}
+
+/// Draw `amount` of a subsidy's budget into `recipient`, a program-owned account
+pub fn draw_subsidy(subsidy: &mut Account<Subsidy>, recipient: &AccountInfo, amount: u64) -> Result<()> {
+    subsidy.budget_remaining = subsidy
+        .budget_remaining
+        .checked_sub(amount)
+        .ok_or(InsuranceError::InsufficientTreasury)?;
+    subsidy.total_disbursed = subsidy.total_disbursed.saturating_add(amount);
+    
+    **subsidy.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+fn deposit<'info>(
+    system_program: &Program<'info, System>,
+    sponsor: &Signer<'info>,
+    subsidy: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: sponsor.to_account_info(),
+                to: subsidy,
+            },
+        ),
+        amount,
+    )
+}
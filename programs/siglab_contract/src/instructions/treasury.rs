@@ -1,7 +1,35 @@
 use anchor_lang::prelude::*;
-use crate::state::{Treasury, TokenType, WithdrawalReason};
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, SetAuthority, Token, TokenAccount, Transfer};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use crate::state::{
+    CurrencyConverter, FeeVault, MasterInsuranceContract, Oracle, ReserveRatioBps, ReserveTransition, Treasury,
+    TreasuryStatus, TokenType, WithdrawalReason,
+};
 use crate::error::InsuranceError;
-use crate::events::{TreasuryWithdrawn};
+use crate::utils::error_utils::{require_admin_invocation, require_top_level_invocation};
+use crate::utils::clock_utils::current_clock;
+use crate::constants::{
+    FEE_VAULT_SEED, MAX_CRANK_TIP_LAMPORTS, MAX_DONATION_MEMO_LENGTH, MAX_PRICE_AGE_SECS, MAX_TOKEN_DECIMALS,
+    TREASURY_SEED, USDC_DECIMALS,
+};
+use crate::events::{
+    instruction_discriminator, CrankTipUpdated, ReserveRatioBreached, ReserveRatioRestored, RetiredTreasuryClosed,
+    TreasuryDonated, TreasuryMigrated, TreasuryWithdrawn,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateTreasuryParams {
+    /// Version of the new treasury; must be one past the current treasury's
+    pub version: u8,
+    /// Token accounts and USDC mint for the new treasury; carried over when unset
+    pub usdc_mint: Option<Pubkey>,
+    pub usdc_token_account: Option<Pubkey>,
+    pub sol_token_account: Option<Pubkey>,
+    /// Decimals of the USDC mint; carried over when unset
+    pub usdc_decimals: Option<u8>,
+}
 
 #[derive(Accounts)]
 pub struct InitializeTreasury<'info> {
@@ -14,6 +42,16 @@ pub struct InitializeTreasury<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
     
+    /// Master contract the treasury is linked to
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized,
+        constraint = master_contract.treasury_account == Pubkey::default() @ InsuranceError::TreasuryAlreadyLinked
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
     
@@ -21,61 +59,288 @@ pub struct InitializeTreasury<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FeeVault::space(),
+        seeds = [FEE_VAULT_SEED],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+    
+    #[account(
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch)]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Vault's USDC account for late fees on USDC premiums; SOL only when omitted
+    #[account(
+        associated_token::mint = treasury.usdc_mint,
+        associated_token::authority = fee_vault,
+    )]
+    pub usdc_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct DepositFunds<'info> {
     #[account(
         mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
     )]
     pub treasury: Account<'info, Treasury>,
     
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
     #[account(mut)]
     pub depositor: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct DonateToTreasury<'info> {
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub donor: Signer<'info>,
+    
+    /// Donor's USDC token account (required for USDC donations)
+    #[account(
+        mut,
+        token::mint = treasury.usdc_mint,
+        token::authority = donor
+    )]
+    pub donor_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Treasury's USDC token account (required for USDC donations)
+    #[account(
+        mut,
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct WithdrawFunds<'info> {
     #[account(
         mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
         constraint = treasury.authority == admin.key() @ InsuranceError::Unauthorized
     )]
     pub treasury: Account<'info, Treasury>,
     
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
     #[account(mut)]
     pub admin: Signer<'info>,
     
-    /// CHECK: Recipient account for withdrawal
+    /// CHECK: Recipient wallet; checked by `validate_withdrawal_recipient`
     pub recipient: AccountInfo<'info>,
+    
+    /// Recipient's USDC associated token account, required for USDC withdrawals
+    pub recipient_usdc_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SetCrankTip<'info> {
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateTreasuryParams)]
+pub struct MigrateTreasury<'info> {
+    /// Treasury being retired
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub old_treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::space(),
+        seeds = [TREASURY_SEED, &params.version.to_le_bytes()],
+        bump
+    )]
+    pub new_treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized,
+        constraint = master_contract.is_paused @ InsuranceError::ContractMustBePaused,
+        constraint = master_contract.open_payout_count == 0 @ InsuranceError::PayoutsOutstanding
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Old treasury's USDC account (required once it has one)
+    #[account(
+        mut,
+        address = old_treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub old_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    /// USDC account `params.usdc_token_account` re-keys to, held by the new
+    /// treasury (required when re-keying an old account that exists)
+    #[account(
+        mut,
+        token::authority = new_treasury
+    )]
+    pub new_usdc_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRetiredTreasury<'info> {
+    #[account(
+        mut,
+        close = admin,
+        constraint = treasury.status == TreasuryStatus::Retired @ InsuranceError::TreasuryNotRetired
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Retired treasury's USDC account (required while it has one)
+    #[account(
+        address = treasury.usdc_token_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub usdc_account: Option<Account<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateTreasuryBalance<'info> {
     #[account(
         mut,
-        seeds = [b"treasury"],
-        bump = treasury.bump
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
     )]
     pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        mut,
+        seeds = [b"master_contract"],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
 }
 
 pub fn initialize_treasury(
     ctx: Context<InitializeTreasury>,
     minimum_reserve_ratio: u16,
 ) -> Result<()> {
-    let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let timestamp = current_clock(&ctx.accounts.master_contract)?.unix_timestamp;
+    let treasury_key = ctx.accounts.treasury.key();
+    setup_treasury(
+        &mut ctx.accounts.treasury,
+        &mut ctx.accounts.master_contract,
+        treasury_key,
+        ctx.accounts.admin.key(),
+        ctx.bumps.treasury,
+        minimum_reserve_ratio,
+        timestamp,
+    )
+}
+
+/// Open the vault that overdue premiums pay their late fees into
+pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    fee_vault.usdc_token_account =
+        ctx.accounts.usdc_token_account.as_ref().map_or(Pubkey::default(), |account| account.key());
+    fee_vault.sol_collected = 0;
+    fee_vault.usdc_collected = 0;
+    fee_vault.sol_rebated = 0;
+    fee_vault.usdc_rebated = 0;
+    fee_vault.rebate_tiers = Vec::new();
+    fee_vault.bump = ctx.bumps.fee_vault;
     
+    Ok(())
+}
+
+/// Write a fresh treasury owned by `admin` and link it to the master contract
+pub(crate) fn setup_treasury(
+    treasury: &mut Treasury,
+    master_contract: &mut MasterInsuranceContract,
+    treasury_key: Pubkey,
+    admin: Pubkey,
+    bump: u8,
+    minimum_reserve_ratio: u16,
+    timestamp: i64,
+) -> Result<()> {
     // Validate minimum reserve ratio (should be between 10% and 50%)
-    require!(
-        minimum_reserve_ratio >= 1000 && minimum_reserve_ratio <= 5000,
-        InsuranceError::InvalidInput
-    );
+    ReserveRatioBps::new(minimum_reserve_ratio).validate()?;
     
     // Initialize treasury
-    treasury.authority = ctx.accounts.admin.key();
+    treasury.authority = admin;
     treasury.usdc_token_account = Pubkey::default(); // Will be set later when tokens are integrated
     treasury.sol_token_account = Pubkey::default(); // Will be set later when tokens are integrated
     treasury.usdc_mint = Pubkey::default(); // Will be set later when tokens are integrated
@@ -90,9 +355,26 @@ pub fn initialize_treasury(
     treasury.total_coverage_exposure = 0;
     treasury.deposit_count = 0;
     treasury.withdrawal_count = 0;
-    treasury.last_update_timestamp = clock.unix_timestamp;
-    treasury.created_at = clock.unix_timestamp;
-    treasury.bump = ctx.bumps.treasury;
+    treasury.last_update_timestamp = timestamp;
+    treasury.created_at = timestamp;
+    treasury.bump = bump;
+    treasury.crank_budget_lamports = 0;
+    treasury.sol_coverage_exposure = 0;
+    treasury.usdc_coverage_exposure = 0;
+    treasury.total_donations_sol = 0;
+    treasury.total_donations_usdc = 0;
+    treasury.reserve_breached = false;
+    treasury.last_breach_at = 0;
+    treasury.usdc_decimals = USDC_DECIMALS;
+    treasury.reserved_for_payouts = 0;
+    treasury.underwriting_bonds = 0;
+    treasury.version = 0;
+    treasury.status = TreasuryStatus::Active;
+    treasury.successor = Pubkey::default();
+    
+    // Link the treasury so payouts can verify they draw from it
+    master_contract.treasury_account = treasury_key;
+    master_contract.updated_at = timestamp;
     
     Ok(())
 }
@@ -103,7 +385,7 @@ pub fn deposit_funds(
     token_type: TokenType,
 ) -> Result<()> {
     let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
     
     require!(amount > 0, InsuranceError::InvalidInput);
     
@@ -122,6 +404,71 @@ pub fn deposit_funds(
     treasury.current_reserve_ratio = treasury.calculate_reserve_ratio();
     treasury.last_update_timestamp = clock.unix_timestamp;
     
+    emit_reserve_transition(
+        treasury,
+        &mut ctx.accounts.master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::DepositFunds>(),
+    );
+    
+    Ok(())
+}
+
+/// Gift funds to the pool
+///
+/// Unlike a deposit, the funds move into the treasury and belong to the pool:
+/// they count toward reserves but give the donor no claim or withdrawal rights.
+pub fn donate_to_treasury(
+    ctx: Context<DonateToTreasury>,
+    amount: u64,
+    token_type: TokenType,
+    memo: Option<String>,
+) -> Result<()> {
+    require!(amount > 0, InsuranceError::InvalidInput);
+    require!(
+        memo.as_ref().map_or(0, String::len) <= MAX_DONATION_MEMO_LENGTH,
+        InsuranceError::InvalidInput
+    );
+    require!(ctx.accounts.treasury.supports(token_type), InsuranceError::UnsupportedCurrency);
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    collect_payment(
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.donor,
+        token_type,
+        amount,
+        ctx.accounts.donor_token_account.as_ref(),
+        ctx.accounts.treasury_usdc_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.record_donation(amount, token_type.is_usdc(), clock.unix_timestamp);
+    let total_donations = if token_type.is_usdc() {
+        treasury.total_donations_usdc
+    } else {
+        treasury.total_donations_sol
+    };
+    emit_reserve_transition(
+        treasury,
+        &mut ctx.accounts.master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::DonateToTreasury>(),
+    );
+    
+    emit!(TreasuryDonated {
+        donor: ctx.accounts.donor.key(),
+        token_type,
+        amount,
+        total_donations,
+        memo,
+        timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::DonateToTreasury>(),
+    });
+    
     Ok(())
 }
 
@@ -131,8 +478,16 @@ pub fn withdraw_funds(
     token_type: TokenType,
     reason: WithdrawalReason,
 ) -> Result<()> {
+    require_top_level_invocation()?;
+    validate_withdrawal_recipient(
+        &ctx.accounts.treasury,
+        &ctx.accounts.recipient,
+        ctx.accounts.recipient_usdc_account.as_ref(),
+        token_type,
+    )?;
+    
     let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
     
     require!(amount > 0, InsuranceError::InvalidInput);
     
@@ -152,6 +507,12 @@ pub fn withdraw_funds(
         }
     }
     
+    // Balance earmarked for triggered payouts stays put whatever the reason
+    require!(
+        amount <= treasury.unreserved_balance(),
+        InsuranceError::InsufficientTreasury
+    );
+    
     // For admin withdrawals, check that it doesn't violate reserve requirements
     if matches!(reason, WithdrawalReason::AdminWithdrawal) {
         let available_liquidity = treasury.available_liquidity();
@@ -162,6 +523,7 @@ pub fn withdraw_funds(
     }
     
     // Update treasury balances (in a full implementation, this would include actual transfers)
+    let treasury_balance_before = treasury.balance(token_type);
     match token_type {
         TokenType::USDC => {
             treasury.total_usdc_balance -= amount;
@@ -175,11 +537,62 @@ pub fn withdraw_funds(
     treasury.current_reserve_ratio = treasury.calculate_reserve_ratio();
     treasury.last_update_timestamp = clock.unix_timestamp;
     
+    emit_reserve_transition(
+        treasury,
+        &mut ctx.accounts.master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::WithdrawFunds>(),
+    );
+    
     // Emit withdrawal event
     emit!(TreasuryWithdrawn {
         admin: ctx.accounts.admin.key(),
         amount,
+        token_type,
+        treasury_balance_before,
+        treasury_balance_after: ctx.accounts.treasury.balance(token_type),
+        reserve_ratio_after: ctx.accounts.treasury.calculate_reserve_ratio(),
         timestamp: clock.unix_timestamp,
+        sequence: ctx.accounts.master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::WithdrawFunds>(),
+    });
+    
+    Ok(())
+}
+
+/// Set the tip for third-party payout executors and the treasury budget funding it
+///
+/// The budget is drawn from the treasury's SOL balance as tips are paid;
+/// execution proceeds untipped once it runs out.
+pub fn set_crank_tip(
+    ctx: Context<SetCrankTip>,
+    tip_lamports: u64,
+    budget_lamports: u64,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    require!(tip_lamports <= MAX_CRANK_TIP_LAMPORTS, InsuranceError::InvalidInput);
+    require!(
+        budget_lamports <= ctx.accounts.treasury.total_sol_balance,
+        InsuranceError::InsufficientTreasury
+    );
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    let treasury = &mut ctx.accounts.treasury;
+    let clock = current_clock(master_contract)?;
+    
+    master_contract.crank_tip_lamports = tip_lamports;
+    master_contract.updated_at = clock.unix_timestamp;
+    treasury.crank_budget_lamports = budget_lamports;
+    treasury.last_update_timestamp = clock.unix_timestamp;
+    
+    emit!(CrankTipUpdated {
+        admin: ctx.accounts.admin.key(),
+        tip_lamports,
+        budget_lamports,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetCrankTip>(),
     });
     
     Ok(())
@@ -187,12 +600,201 @@ pub fn withdraw_funds(
 
 pub fn update_treasury_balance(ctx: Context<UpdateTreasuryBalance>) -> Result<()> {
     let treasury = &mut ctx.accounts.treasury;
-    let clock = Clock::get()?;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    // Lamports beyond the rent reserve and the tracked balance were sent
+    // without an instruction; book them as donations so the books match
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let surplus = treasury_info
+        .lamports()
+        .saturating_sub(rent_reserve)
+        .saturating_sub(treasury.total_sol_balance);
+    if surplus > 0 {
+        treasury.record_donation(surplus, false, clock.unix_timestamp);
+    }
     
     // Refresh reserve ratio calculation
     treasury.current_reserve_ratio = treasury.calculate_reserve_ratio();
     treasury.last_update_timestamp = clock.unix_timestamp;
     
+    emit_reserve_transition(
+        treasury,
+        &mut ctx.accounts.master_contract,
+        clock.unix_timestamp,
+        instruction_discriminator::<crate::instruction::UpdateTreasuryBalance>(),
+    );
+    
+    Ok(())
+}
+
+/// Move the treasury's balances to a new versioned treasury and retire the old one
+///
+/// Requires a paused contract with no open payouts, so nothing draws on either
+/// treasury mid-move. The retired account keeps its history and rent until closed.
+/// A carried-over USDC account is handed to the new treasury; a re-keyed one
+/// receives the old account's whole balance.
+pub fn migrate_treasury(ctx: Context<MigrateTreasury>, params: MigrateTreasuryParams) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let old_treasury = &mut ctx.accounts.old_treasury;
+    let new_treasury = &mut ctx.accounts.new_treasury;
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    require!(
+        old_treasury.version.checked_add(1) == Some(params.version),
+        InsuranceError::InvalidTreasuryVersion
+    );
+    let usdc_decimals = params.usdc_decimals.unwrap_or(old_treasury.usdc_decimals);
+    require!(usdc_decimals <= MAX_TOKEN_DECIMALS, InsuranceError::InvalidInput);
+    
+    // Carry the balances and history over, re-keying token accounts where given
+    new_treasury.authority = old_treasury.authority;
+    new_treasury.usdc_token_account = params.usdc_token_account.unwrap_or(old_treasury.usdc_token_account);
+    new_treasury.sol_token_account = params.sol_token_account.unwrap_or(old_treasury.sol_token_account);
+    new_treasury.usdc_mint = params.usdc_mint.unwrap_or(old_treasury.usdc_mint);
+    new_treasury.total_usdc_balance = old_treasury.total_usdc_balance;
+    new_treasury.total_sol_balance = old_treasury.total_sol_balance;
+    new_treasury.total_premiums_collected_usdc = old_treasury.total_premiums_collected_usdc;
+    new_treasury.total_premiums_collected_sol = old_treasury.total_premiums_collected_sol;
+    new_treasury.total_payouts_disbursed_usdc = old_treasury.total_payouts_disbursed_usdc;
+    new_treasury.total_payouts_disbursed_sol = old_treasury.total_payouts_disbursed_sol;
+    new_treasury.current_reserve_ratio = old_treasury.current_reserve_ratio;
+    new_treasury.minimum_reserve_ratio = old_treasury.minimum_reserve_ratio;
+    new_treasury.total_coverage_exposure = old_treasury.total_coverage_exposure;
+    new_treasury.deposit_count = old_treasury.deposit_count;
+    new_treasury.withdrawal_count = old_treasury.withdrawal_count;
+    new_treasury.last_update_timestamp = clock.unix_timestamp;
+    new_treasury.created_at = clock.unix_timestamp;
+    new_treasury.bump = ctx.bumps.new_treasury;
+    new_treasury.crank_budget_lamports = old_treasury.crank_budget_lamports;
+    new_treasury.version = params.version;
+    new_treasury.status = TreasuryStatus::Active;
+    new_treasury.successor = Pubkey::default();
+    new_treasury.sol_coverage_exposure = old_treasury.sol_coverage_exposure;
+    new_treasury.usdc_coverage_exposure = old_treasury.usdc_coverage_exposure;
+    new_treasury.total_donations_sol = old_treasury.total_donations_sol;
+    new_treasury.total_donations_usdc = old_treasury.total_donations_usdc;
+    new_treasury.reserve_breached = old_treasury.reserve_breached;
+    new_treasury.last_breach_at = old_treasury.last_breach_at;
+    new_treasury.usdc_decimals = usdc_decimals;
+    new_treasury.reserved_for_payouts = old_treasury.reserved_for_payouts;
+    new_treasury.underwriting_bonds = old_treasury.underwriting_bonds;
+    
+    // Move every lamport above the old account's rent reserve
+    let old_info = old_treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(old_info.data_len());
+    let lamports_moved = old_info.lamports().saturating_sub(rent_reserve);
+    **old_info.try_borrow_mut_lamports()? -= lamports_moved;
+    **new_treasury.to_account_info().try_borrow_mut_lamports()? += lamports_moved;
+    
+    // USDC sits in a token account the old treasury PDA controls, so it
+    // moves by CPI rather than with the books
+    let usdc_moved = if old_treasury.usdc_token_account == Pubkey::default() {
+        0
+    } else {
+        let (Some(old_usdc_account), Some(token_program)) =
+            (ctx.accounts.old_usdc_account.as_ref(), ctx.accounts.token_program.as_ref())
+        else {
+            return Err(InsuranceError::TreasuryAccountMismatch.into());
+        };
+        let balance = old_usdc_account.amount;
+        if new_treasury.usdc_token_account == old_treasury.usdc_token_account {
+            with_treasury_signer(old_treasury, |signer_seeds| {
+                token::set_authority(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        SetAuthority {
+                            current_authority: old_treasury.to_account_info(),
+                            account_or_mint: old_usdc_account.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    AuthorityType::AccountOwner,
+                    Some(new_treasury.key()),
+                )
+            })?;
+            // The account now belongs to the new treasury alone
+            old_treasury.usdc_token_account = Pubkey::default();
+        } else {
+            let new_usdc_account = ctx
+                .accounts
+                .new_usdc_account
+                .as_ref()
+                .filter(|account| account.key() == new_treasury.usdc_token_account)
+                .ok_or(InsuranceError::TreasuryAccountMismatch)?;
+            require_keys_eq!(
+                new_usdc_account.mint,
+                old_usdc_account.mint,
+                InsuranceError::TreasuryAccountMismatch
+            );
+            transfer_treasury_usdc(
+                old_treasury,
+                old_usdc_account,
+                &new_usdc_account.to_account_info(),
+                token_program,
+                balance,
+            )?;
+        }
+        balance
+    };
+    
+    old_treasury.total_usdc_balance = 0;
+    old_treasury.total_sol_balance = 0;
+    old_treasury.total_coverage_exposure = 0;
+    old_treasury.sol_coverage_exposure = 0;
+    old_treasury.usdc_coverage_exposure = 0;
+    old_treasury.crank_budget_lamports = 0;
+    old_treasury.underwriting_bonds = 0;
+    old_treasury.status = TreasuryStatus::Retired;
+    old_treasury.successor = new_treasury.key();
+    old_treasury.last_update_timestamp = clock.unix_timestamp;
+    
+    let master_contract = &mut ctx.accounts.master_contract;
+    master_contract.treasury_account = new_treasury.key();
+    master_contract.updated_at = clock.unix_timestamp;
+    
+    emit!(TreasuryMigrated {
+        admin: ctx.accounts.admin.key(),
+        old_treasury: old_treasury.key(),
+        new_treasury: new_treasury.key(),
+        version: params.version,
+        lamports_moved,
+        usdc_moved,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::MigrateTreasury>(),
+    });
+    
+    Ok(())
+}
+
+/// Close a retired treasury, returning its rent to the admin
+pub fn close_retired_treasury(ctx: Context<CloseRetiredTreasury>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    // Closing would strand USDC still held under the retired treasury's authority
+    if ctx.accounts.treasury.usdc_token_account != Pubkey::default() {
+        let usdc_account = ctx
+            .accounts
+            .usdc_account
+            .as_ref()
+            .ok_or(InsuranceError::TreasuryAccountMismatch)?;
+        require!(usdc_account.amount == 0, InsuranceError::RetiredTreasuryHoldsUsdc);
+    }
+    
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let master_contract = &mut ctx.accounts.master_contract;
+    
+    emit!(RetiredTreasuryClosed {
+        admin: ctx.accounts.admin.key(),
+        treasury: ctx.accounts.treasury.key(),
+        version: ctx.accounts.treasury.version,
+        timestamp: clock.unix_timestamp,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::CloseRetiredTreasury>(),
+    });
+    
     Ok(())
 }
 
@@ -202,7 +804,7 @@ pub fn validate_treasury_solvency(treasury: &Treasury, additional_exposure: u64)
     let total_balance = treasury.total_usdc_balance + treasury.total_sol_balance;
     
     if new_exposure > 0 {
-        let required_reserves = (new_exposure * treasury.minimum_reserve_ratio as u64) / 10000;
+        let required_reserves = treasury.minimum_reserve().required_reserves(new_exposure);
         require!(
             total_balance >= required_reserves,
             InsuranceError::SolvencyCheckFailed
@@ -212,6 +814,104 @@ pub fn validate_treasury_solvency(treasury: &Treasury, additional_exposure: u64)
     Ok(())
 }
 
+/// Emit `ReserveRatioBreached` or `ReserveRatioRestored` when the instruction's
+/// balance updates took the reserves across the minimum reserve ratio
+pub(crate) fn emit_reserve_transition(
+    treasury: &mut Account<Treasury>,
+    master_contract: &mut MasterInsuranceContract,
+    timestamp: i64,
+    instruction: [u8; 8],
+) {
+    let breached_at = treasury.last_breach_at;
+    let Some(transition) = treasury.track_reserve_breach(timestamp) else {
+        return;
+    };
+    
+    let treasury_key = treasury.key();
+    let reserve_ratio = treasury.calculate_reserve_ratio();
+    match transition {
+        ReserveTransition::Breached => emit!(ReserveRatioBreached {
+            treasury: treasury_key,
+            reserve_ratio,
+            minimum_reserve_ratio: treasury.minimum_reserve_ratio,
+            usdc_balance: treasury.total_usdc_balance,
+            sol_balance: treasury.total_sol_balance,
+            coverage_exposure: treasury.total_coverage_exposure,
+            timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction,
+        }),
+        ReserveTransition::Restored => emit!(ReserveRatioRestored {
+            treasury: treasury_key,
+            reserve_ratio,
+            minimum_reserve_ratio: treasury.minimum_reserve_ratio,
+            usdc_balance: treasury.total_usdc_balance,
+            sol_balance: treasury.total_sol_balance,
+            coverage_exposure: treasury.total_coverage_exposure,
+            breached_at,
+            timestamp,
+            sequence: master_contract.next_event_sequence(),
+            instruction,
+        }),
+    }
+}
+
+/// Converter between the treasury's currencies, priced by the configured
+/// SOL/USD oracle when it is among `oracle_accounts` with a fresh value
+pub(crate) fn currency_converter(
+    treasury: &Treasury,
+    master_contract: &MasterInsuranceContract,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> CurrencyConverter {
+    let price_oracle = master_contract.global_config.price_oracle;
+    let sol_price = oracle_accounts
+        .iter()
+        .find(|account_info| {
+            price_oracle != Pubkey::default()
+                && account_info.key() == price_oracle
+                && account_info.owner == &crate::ID
+                && master_contract.oracle_registry.contains(&price_oracle)
+        })
+        .and_then(|account_info| {
+            let data = account_info.try_borrow_data().ok()?;
+            let oracle = Oracle::from_account_data(&data)?;
+            let latest = oracle.latest_data()?;
+            (oracle.is_active() && latest.value > 0 && current_timestamp - latest.timestamp <= MAX_PRICE_AGE_SECS)
+                .then_some(latest.value)
+        });
+    CurrencyConverter::new(treasury, sol_price)
+}
+
+/// Check that a withdrawal goes to a wallet outside the treasury: a system-owned
+/// recipient for SOL, plus its associated token account of the treasury's mint for USDC
+pub(crate) fn validate_withdrawal_recipient(
+    treasury: &Account<Treasury>,
+    recipient: &AccountInfo,
+    recipient_usdc_account: Option<&Account<TokenAccount>>,
+    token_type: TokenType,
+) -> Result<()> {
+    require!(
+        recipient.key() != treasury.key()
+            && recipient.key() != treasury.sol_token_account
+            && recipient.owner == &system_program::ID,
+        InsuranceError::InvalidRecipient
+    );
+    
+    if token_type == TokenType::USDC {
+        let recipient_usdc_account = recipient_usdc_account.ok_or(InsuranceError::InvalidRecipient)?;
+        require!(
+            recipient_usdc_account.key() != treasury.usdc_token_account
+                && recipient_usdc_account.mint == treasury.usdc_mint
+                && recipient_usdc_account.owner == recipient.key()
+                && recipient_usdc_account.key() == get_associated_token_address(&recipient.key(), &treasury.usdc_mint),
+            InsuranceError::InvalidRecipient
+        );
+    }
+    
+    Ok(())
+}
+
 /// Helper function for premium collection (to be used in policy instructions)
 pub fn process_premium_payment(
     treasury: &mut Treasury,
@@ -232,4 +932,122 @@ pub fn process_payout_disbursement(
 ) -> Result<()> {
     treasury.record_payout(amount, is_usdc, timestamp)?;
     Ok(())
+}
+
+/// Move `amount` from `payer` into `destination`, the treasury or the fee
+/// vault: lamports for SOL, or USDC from the payer's token account into the
+/// destination's
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn collect_payment<'info>(
+    destination: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    currency: TokenType,
+    amount: u64,
+    payer_token_account: Option<&Account<'info, TokenAccount>>,
+    destination_usdc_account: Option<&Account<'info, TokenAccount>>,
+    token_program: Option<&Program<'info, Token>>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    if currency.is_usdc() {
+        let (Some(from), Some(to), Some(token_program)) = (payer_token_account, destination_usdc_account, token_program)
+        else {
+            return Err(InsuranceError::UnsupportedCurrency.into());
+        };
+        return token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            amount,
+        );
+    }
+    
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: destination.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Move `amount` from the treasury to `beneficiary`, for a payout or a refund
+///
+/// USDC goes between the given token accounts; lamports may not dip into
+/// the treasury's rent reserve.
+pub(crate) fn disburse<'info>(
+    treasury: &Account<'info, Treasury>,
+    beneficiary: &AccountInfo<'info>,
+    is_usdc: bool,
+    amount: u64,
+    treasury_usdc_account: Option<&Account<'info, TokenAccount>>,
+    beneficiary_usdc_account: Option<&AccountInfo<'info>>,
+    token_program: Option<&Program<'info, Token>>,
+) -> Result<()> {
+    if is_usdc {
+        let (Some(from), Some(to), Some(token_program)) =
+            (treasury_usdc_account, beneficiary_usdc_account, token_program)
+        else {
+            return Err(InsuranceError::UnsupportedCurrency.into());
+        };
+        return transfer_treasury_usdc(treasury, from, to, token_program, amount);
+    }
+    
+    let treasury_info = treasury.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    require!(
+        treasury_info.lamports().saturating_sub(rent_reserve) >= amount,
+        InsuranceError::InsufficientTreasury
+    );
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **beneficiary.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Transfer USDC out of the treasury's token account, signed by the treasury PDA
+pub(crate) fn transfer_treasury_usdc<'info>(
+    treasury: &Account<'info, Treasury>,
+    from: &Account<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    with_treasury_signer(treasury, |signer_seeds| {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.clone(),
+                    authority: treasury.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    })
+}
+
+/// Run `f` with the signer seeds of the treasury PDA; version 0 predates the
+/// version seed
+fn with_treasury_signer<R>(treasury: &Treasury, f: impl FnOnce(&[&[&[u8]]]) -> R) -> R {
+    let version = treasury.version.to_le_bytes();
+    let bump = [treasury.bump];
+    let seeds: &[&[u8]] = if treasury.version == 0 {
+        &[TREASURY_SEED, &bump]
+    } else {
+        &[TREASURY_SEED, &version, &bump]
+    };
+    f(&[seeds])
 }
\ No newline at end of file
@@ -0,0 +1,280 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token::TokenAccount;
+use crate::state::{
+    CurrencyAmount, EndorsementKind, InsuranceTypeConfig, MasterInsuranceContract, Policy, PolicyHistory, PolicyStatus,
+    Treasury, TriggerAmendment, TriggerConditions,
+};
+use crate::constants::{MASTER_CONTRACT_SEED, POLICY_HISTORY_SEED, POLICY_SEED, TYPE_CONFIG_SEED};
+use crate::error::InsuranceError;
+use crate::events::{
+    instruction_discriminator, PolicyEndorsed, PolicyLimitViolated, TriggerAmendmentApproved, TriggerAmendmentProposed,
+};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::require_not_paused;
+
+#[derive(Accounts)]
+pub struct ProposeTriggerAmendment<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    /// Policy's history, for room to endorse the amendment
+    #[account(
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    pub owner: Signer<'info>,
+    
+    /// Owner's policy token account (required for tokenized policies)
+    pub policy_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTriggerAmendment<'info> {
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = policy.status == PolicyStatus::Active @ InsuranceError::PolicyNotActive
+    )]
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        mut,
+        seeds = [POLICY_HISTORY_SEED, policy.key().as_ref()],
+        bump = policy_history.bump
+    )]
+    pub policy_history: Account<'info, PolicyHistory>,
+    
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Configuration of the policy's insurance type, for its premium floor
+    #[account(
+        seeds = [TYPE_CONFIG_SEED, &policy.insurance_type.seed()],
+        bump = type_config.bump
+    )]
+    pub type_config: Account<'info, InsuranceTypeConfig>,
+    
+    pub admin: Signer<'info>,
+}
+
+/// Propose replacing the policy's trigger conditions, replacing any earlier proposal
+pub fn propose_trigger_amendment(
+    ctx: Context<ProposeTriggerAmendment>,
+    new_conditions: TriggerConditions,
+    max_additional_premium: u64,
+) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    
+    require_not_paused!(master_contract.is_paused);
+    require!(current_time <= policy.end_date, InsuranceError::PolicyExpired);
+    
+    let owner = crate::instructions::policy_token::resolve_beneficial_owner(
+        policy,
+        ctx.accounts.policy_token_account.as_ref(),
+    )?;
+    require!(ctx.accounts.owner.key() == owner, InsuranceError::Unauthorized);
+    
+    new_conditions.validate()?;
+    require!(
+        ctx.accounts.policy_history.can_endorse(),
+        InsuranceError::EndorsementLimitReached
+    );
+    
+    policy.pending_trigger_amendment = Some(TriggerAmendment {
+        conditions: new_conditions,
+        max_additional_premium,
+        proposed_at: current_time,
+    });
+    policy.updated_at = current_time;
+    
+    emit!(TriggerAmendmentProposed {
+        policy_id: policy.id,
+        owner,
+        max_additional_premium,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ProposeTriggerAmendment>(),
+    });
+    
+    Ok(())
+}
+
+/// Apply the pending trigger amendment at `risk_assessment_score`
+///
+/// The premium is re-priced for the new score, and the difference over the
+/// unexpired term is charged or refunded through the treasury. Oracles read
+/// by a compound trigger are passed as remaining accounts and must be
+/// registered and active.
+pub fn approve_trigger_amendment(
+    ctx: Context<ApproveTriggerAmendment>,
+    risk_assessment_score: u8,
+) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+    
+    let policy = &mut ctx.accounts.policy;
+    let policy_history = &mut ctx.accounts.policy_history;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let treasury = &mut ctx.accounts.treasury;
+    let current_time = now(master_contract)?;
+    
+    require!(current_time <= policy.end_date, InsuranceError::PolicyExpired);
+    require!(risk_assessment_score <= 100, InsuranceError::InvalidParameters);
+    
+    let amendment = policy
+        .pending_trigger_amendment
+        .take()
+        .ok_or(InsuranceError::NoPendingTriggerAmendment)?;
+    if let Some(ref compound) = amendment.conditions.compound {
+        crate::instructions::payout::require_registered_feeds(compound, ctx.remaining_accounts, master_contract)?;
+    }
+    if let Some(violation) = ctx.accounts.type_config.params.trigger_violation(&amendment.conditions) {
+        emit!(PolicyLimitViolated {
+            insurance_type: policy.insurance_type.clone(),
+            violation,
+            timestamp: current_time,
+            sequence: master_contract.next_event_sequence(),
+            instruction: instruction_discriminator::<crate::instruction::ApproveTriggerAmendment>(),
+        });
+        return err!(InsuranceError::InvalidParameters);
+    }
+    
+    // Re-price for the new risk score, never below the premium floors
+    let treasury_balance_before = treasury.balance(policy.premium_currency);
+    let old_premium_amount = policy.premium_amount;
+    let old_risk_score = policy.risk_assessment_score;
+    let converter = crate::instructions::treasury::currency_converter(
+        treasury,
+        master_contract,
+        ctx.remaining_accounts,
+        current_time,
+    );
+    let type_minimum_premium = CurrencyAmount {
+        amount: ctx.accounts.type_config.params.minimum_premium(policy.coverage_amount),
+        currency: policy.payout_currency,
+    };
+    let new_premium_amount = siglab_core::reprice_for_risk(old_premium_amount, old_risk_score, risk_assessment_score)
+        .ok_or(InsuranceError::MathOverflow)?
+        .max(converter.convert(type_minimum_premium, policy.premium_currency)?)
+        .max(converter.convert(master_contract.global_config.min_premium, policy.premium_currency)?);
+    
+    let mut additional_premium = 0;
+    let mut refund_amount = 0;
+    if new_premium_amount > old_premium_amount {
+        additional_premium = siglab_core::unexpired_share(
+            new_premium_amount - old_premium_amount,
+            policy.start_date,
+            policy.end_date,
+            current_time,
+        );
+        require!(
+            additional_premium <= amendment.max_additional_premium,
+            InsuranceError::InsufficientPremium
+        );
+        
+        treasury.record_premium(additional_premium, policy.premium_currency.is_usdc(), current_time);
+        policy.record_premium(policy_history, additional_premium, current_time)?;
+        master_contract.total_premiums_collected = master_contract
+            .total_premiums_collected
+            .checked_add(additional_premium)
+            .ok_or(InsuranceError::MathOverflow)?;
+        master_contract
+            .portfolio_stats_mut(&policy.insurance_type)
+            .record_premium(additional_premium)?;
+    } else if new_premium_amount < old_premium_amount {
+        refund_amount = siglab_core::unexpired_share(
+            old_premium_amount - new_premium_amount,
+            policy.start_date,
+            policy.end_date,
+            current_time,
+        )
+        .min(policy.total_premiums_paid);
+        policy.total_premiums_paid -= refund_amount;
+        
+        treasury.record_refund(refund_amount, policy.premium_currency.is_usdc(), current_time);
+        treasury.withdrawal_count += 1;
+        master_contract
+            .portfolio_stats_mut(&policy.insurance_type)
+            .record_refund(refund_amount);
+    }
+    
+    let before = hash(&policy.trigger_conditions.try_to_vec()?).to_bytes();
+    let endorsement = policy_history.endorse(
+        EndorsementKind::TriggerAmendment,
+        before,
+        hash(&amendment.conditions.try_to_vec()?).to_bytes(),
+        ctx.accounts.admin.key(),
+        current_time,
+    )?;
+    policy.trigger_conditions = amendment.conditions;
+    policy.risk_assessment_score = risk_assessment_score;
+    policy.premium_amount = new_premium_amount;
+    policy.updated_at = current_time;
+    master_contract.updated_at = current_time;
+    
+    emit!(PolicyEndorsed {
+        policy_id: policy.id,
+        kind: endorsement.kind,
+        before: endorsement.before,
+        after: endorsement.after,
+        actor: endorsement.actor,
+        endorsement_count: policy_history.endorsements.len() as u8,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ApproveTriggerAmendment>(),
+    });
+    emit!(TriggerAmendmentApproved {
+        policy_id: policy.id,
+        admin: ctx.accounts.admin.key(),
+        old_risk_score,
+        new_risk_score: risk_assessment_score,
+        old_premium_amount,
+        new_premium_amount,
+        additional_premium,
+        refund_amount,
+        treasury_balance_before,
+        treasury_balance_after: treasury.balance(policy.premium_currency),
+        reserve_ratio_after: treasury.calculate_reserve_ratio(),
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ApproveTriggerAmendment>(),
+    });
+    
+    crate::instructions::treasury::emit_reserve_transition(
+        treasury,
+        master_contract,
+        current_time,
+        instruction_discriminator::<crate::instruction::ApproveTriggerAmendment>(),
+    );
+    
+    Ok(())
+}
\ No newline at end of file
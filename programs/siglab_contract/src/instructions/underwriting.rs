@@ -0,0 +1,430 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{
+    CurrencyAmount, FeeRebateTier, FeeVault, MasterInsuranceContract, Policy, PolicyStatus, TokenType, Treasury,
+    UnderwriterLedger,
+};
+use crate::error::InsuranceError;
+use crate::constants::{
+    FEE_VAULT_SEED, MASTER_CONTRACT_SEED, MAX_REBATE_TIERS, POLICY_SEED, UNDERWRITER_LEDGER_SEED,
+};
+use crate::instructions::treasury::{currency_converter, emit_reserve_transition};
+use crate::utils::error_utils::require_admin_invocation;
+use crate::utils::clock_utils::now;
+use crate::events::{
+    instruction_discriminator, FeeRebateClaimed, FeeRebateTiersUpdated, PolicyCoSigned, UnderwriterUpdated,
+    UnderwritingBondReleased,
+};
+
+#[derive(Accounts)]
+pub struct SetUnderwriter<'info> {
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseUnderwritingBond<'info> {
+    /// Policy that has expired, paid out or been cancelled
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, policy.creator.as_ref(), &policy.index.to_le_bytes()],
+        bump = policy.bump,
+        constraint = matches!(
+            policy.status,
+            PolicyStatus::Expired | PolicyStatus::PaidOut | PolicyStatus::Cancelled
+        ) @ InsuranceError::PolicyNotExpired
+    )]
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Treasury holding the bond; bonds move with the treasury when it migrates
+    #[account(
+        mut,
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Receives what is left of the bond; validated against the policy's co-signer
+    #[account(
+        mut,
+        constraint = policy.co_signer == Some(co_signer.key()) @ InsuranceError::Unauthorized
+    )]
+    pub co_signer: AccountInfo<'info>,
+
+    /// Anyone may release a bond once the policy has ended
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeRebateTiers<'info> {
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+        constraint = master_contract.authority == admin.key() @ InsuranceError::Unauthorized
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenUnderwriterLedger<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = UnderwriterLedger::space(),
+        seeds = [UNDERWRITER_LEDGER_SEED, underwriter.key().as_ref()],
+        bump
+    )]
+    pub underwriter_ledger: Account<'info, UnderwriterLedger>,
+
+    /// CHECK: Underwriter the ledger is opened for; it need not sign, so the
+    /// holder of a policy it co-signed can open the ledger its late fees need
+    pub underwriter: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFeeRebate<'info> {
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_SEED],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [UNDERWRITER_LEDGER_SEED, underwriter.key().as_ref()],
+        bump = underwriter_ledger.bump,
+        has_one = underwriter @ InsuranceError::Unauthorized
+    )]
+    pub underwriter_ledger: Account<'info, UnderwriterLedger>,
+
+    #[account(
+        mut,
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump,
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+
+    /// Underwriter claiming, paid the SOL part of the rebate
+    #[account(mut)]
+    pub underwriter: Signer<'info>,
+
+    /// Fee vault's USDC account (required when a USDC rebate is due)
+    #[account(
+        mut,
+        address = fee_vault.usdc_token_account @ InsuranceError::FeeVaultAccountMismatch,
+    )]
+    pub fee_vault_usdc_account: Option<Account<'info, TokenAccount>>,
+
+    /// Underwriter's USDC account receiving the USDC part of the rebate
+    #[account(
+        mut,
+        token::authority = underwriter,
+    )]
+    pub underwriter_usdc_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Name the key that co-signs policies at or above the co-signing
+/// threshold; the default key hands the role back to the authority
+pub fn set_underwriter(ctx: Context<SetUnderwriter>, underwriter: Pubkey) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+
+    let old_underwriter = master_contract.underwriter;
+    master_contract.underwriter = underwriter;
+    master_contract.updated_at = current_time;
+
+    emit!(UnderwriterUpdated {
+        admin: ctx.accounts.admin.key(),
+        old_underwriter,
+        new_underwriter: underwriter,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetUnderwriter>(),
+    });
+
+    Ok(())
+}
+
+/// Return a co-signer's bond once its policy has ended, slashing the part
+/// covering claims beyond `GlobalConfig::bond_max_loss_ratio_bps` into the
+/// reserves
+///
+/// Premiums and claims are weighed in lamports, converted by the price oracle
+/// among the remaining accounts when the policy is in USDC.
+pub fn release_underwriting_bond(ctx: Context<ReleaseUnderwritingBond>) -> Result<()> {
+    let policy = &mut ctx.accounts.policy;
+    let master_contract = &mut ctx.accounts.master_contract;
+    let treasury = &mut ctx.accounts.treasury;
+    let current_time = now(master_contract)?;
+
+    let bond = policy.underwriting_bond;
+    require!(bond > 0, InsuranceError::NoUnderwritingBond);
+
+    let converter = currency_converter(treasury, master_contract, ctx.remaining_accounts, current_time);
+    let premiums = converter.convert(
+        CurrencyAmount { amount: policy.total_premiums_paid, currency: policy.premium_currency },
+        TokenType::SOL,
+    )?;
+    let claims = converter.convert(
+        CurrencyAmount { amount: policy.claims_paid, currency: policy.payout_currency },
+        TokenType::SOL,
+    )?;
+    let slashed = siglab_core::bond_slash(
+        bond,
+        premiums,
+        claims,
+        master_contract.global_config.bond_max_loss_ratio_bps,
+    );
+    let returned = bond - slashed;
+
+    // The slashed part joins the reserves; the rest goes back to the co-signer
+    treasury.underwriting_bonds = treasury.underwriting_bonds.saturating_sub(bond);
+    treasury.update_balances(0, slashed as i64, current_time);
+    **treasury.to_account_info().try_borrow_mut_lamports()? -= returned;
+    **ctx.accounts.co_signer.try_borrow_mut_lamports()? += returned;
+    let instruction = instruction_discriminator::<crate::instruction::ReleaseUnderwritingBond>();
+    emit_reserve_transition(treasury, master_contract, current_time, instruction);
+
+    policy.underwriting_bond = 0;
+    policy.updated_at = current_time;
+
+    emit!(UnderwritingBondReleased {
+        policy_id: policy.id,
+        co_signer: ctx.accounts.co_signer.key(),
+        bond,
+        slashed,
+        premiums,
+        claims,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+
+    Ok(())
+}
+
+/// Require the co-signature a new policy's coverage calls for, locking the
+/// co-signer's bond in the treasury and booking the coverage in its ledger
+///
+/// Policies below `GlobalConfig::cosign_coverage_threshold`, or sold while
+/// none is set, need no co-signer. The bond and the booked coverage are
+/// counted in lamports whatever the policy's currency.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn co_sign_policy<'info>(
+    policy: &mut Policy,
+    master_contract: &mut MasterInsuranceContract,
+    treasury: &mut Account<'info, Treasury>,
+    co_signer: Option<&Signer<'info>>,
+    underwriter_ledger: Option<&mut Account<'info, UnderwriterLedger>>,
+    system_program: &Program<'info, System>,
+    oracle_accounts: &[AccountInfo],
+    instruction: [u8; 8],
+) -> Result<()> {
+    let global_config = master_contract.global_config;
+    let Some(threshold) = global_config.cosign_coverage_threshold else {
+        return Ok(());
+    };
+    let current_time = now(master_contract)?;
+    let converter = currency_converter(treasury, master_contract, oracle_accounts, current_time);
+    if policy.coverage_amount < converter.convert(threshold, policy.payout_currency)? {
+        return Ok(());
+    }
+
+    let co_signer = co_signer
+        .filter(|co_signer| co_signer.key() == master_contract.policy_co_signer())
+        .ok_or(InsuranceError::CoSignatureRequired)?;
+    let underwriter_ledger = underwriter_ledger.ok_or(InsuranceError::UnderwriterLedgerRequired)?;
+    require_keys_eq!(
+        underwriter_ledger.underwriter,
+        co_signer.key(),
+        InsuranceError::UnderwriterLedgerMismatch
+    );
+    let coverage = converter.convert(
+        CurrencyAmount { amount: policy.coverage_amount, currency: policy.payout_currency },
+        TokenType::SOL,
+    )?;
+    underwriter_ledger.record_placement(coverage, current_time)?;
+    let bond = siglab_core::underwriting_bond(coverage, global_config.underwriting_bond_bps);
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: co_signer.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+        treasury.underwriting_bonds = treasury
+            .underwriting_bonds
+            .checked_add(bond)
+            .ok_or(InsuranceError::MathOverflow)?;
+    }
+
+    policy.co_signer = Some(co_signer.key());
+    policy.underwriting_bond = bond;
+
+    emit!(PolicyCoSigned {
+        policy_id: policy.id,
+        co_signer: co_signer.key(),
+        coverage_amount: policy.coverage_amount,
+        currency: policy.payout_currency,
+        bond,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction,
+    });
+
+    Ok(())
+}
+
+/// Replace the underwriter fee rebate schedule; an empty schedule ends rebates
+///
+/// Epochs not yet claimed are rebated under the schedule in force when
+/// claimed.
+pub fn set_fee_rebate_tiers(ctx: Context<SetFeeRebateTiers>, tiers: Vec<FeeRebateTier>) -> Result<()> {
+    require_admin_invocation(&ctx.accounts.master_contract)?;
+
+    require!(tiers.len() <= MAX_REBATE_TIERS, InsuranceError::InvalidRebateTiers);
+    let schedule: Vec<siglab_core::RebateTier> = tiers.iter().map(|&tier| tier.into()).collect();
+    require!(siglab_core::rebate_tiers_valid(&schedule), InsuranceError::InvalidRebateTiers);
+
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    ctx.accounts.fee_vault.rebate_tiers = tiers.clone();
+
+    emit!(FeeRebateTiersUpdated {
+        admin: ctx.accounts.admin.key(),
+        tiers,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::SetFeeRebateTiers>(),
+    });
+
+    Ok(())
+}
+
+/// Open the ledger an underwriter's co-signed coverage and late fees are
+/// booked in; an underwriter must have one before it co-signs, and anyone
+/// may pay to open it
+pub fn open_underwriter_ledger(ctx: Context<OpenUnderwriterLedger>) -> Result<()> {
+    let ledger = &mut ctx.accounts.underwriter_ledger;
+    ledger.underwriter = ctx.accounts.underwriter.key();
+    ledger.epochs = Default::default();
+    ledger.bump = ctx.bumps.underwriter_ledger;
+
+    Ok(())
+}
+
+/// Pay an underwriter its fee rebate for a closed `epoch`, once
+///
+/// The rate of the tier its placed coverage reaches applies to the late fees
+/// its policies paid in each currency during the epoch. Rebates come out of
+/// the fee vault only, never past the fees it collected or its rent.
+pub fn claim_fee_rebate(ctx: Context<ClaimFeeRebate>, epoch: u64) -> Result<()> {
+    let master_contract = &mut ctx.accounts.master_contract;
+    let current_time = now(master_contract)?;
+    require!(
+        epoch < UnderwriterLedger::epoch_at(current_time),
+        InsuranceError::FeeRebateEpochOpen
+    );
+
+    let ledger = &mut ctx.accounts.underwriter_ledger;
+    let entry = *ledger.entry(epoch).ok_or(InsuranceError::FeeRebateEpochUnavailable)?;
+    require!(!entry.claimed, InsuranceError::FeeRebateAlreadyClaimed);
+
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    let tiers = fee_vault.tiers();
+    let rebate_bps = siglab_core::rebate_rate_bps(&tiers, entry.placed_coverage);
+    let sol_rebate = siglab_core::fee_rebate(&tiers, entry.placed_coverage, entry.sol_fees);
+    let usdc_rebate = siglab_core::fee_rebate(&tiers, entry.placed_coverage, entry.usdc_fees);
+    require!(sol_rebate > 0 || usdc_rebate > 0, InsuranceError::NoFeeRebate);
+    fee_vault.record_rebate(sol_rebate, false)?;
+    fee_vault.record_rebate(usdc_rebate, true)?;
+
+    if sol_rebate > 0 {
+        let vault_info = fee_vault.to_account_info();
+        let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+        require!(
+            vault_info.lamports().saturating_sub(rent_reserve) >= sol_rebate,
+            InsuranceError::InsufficientFeeVault
+        );
+        **vault_info.try_borrow_mut_lamports()? -= sol_rebate;
+        **ctx.accounts.underwriter.try_borrow_mut_lamports()? += sol_rebate;
+    }
+
+    if usdc_rebate > 0 {
+        let (Some(from), Some(to), Some(token_program)) = (
+            ctx.accounts.fee_vault_usdc_account.as_ref(),
+            ctx.accounts.underwriter_usdc_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return Err(InsuranceError::UnsupportedCurrency.into());
+        };
+        let bump = [fee_vault.bump];
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: fee_vault.to_account_info(),
+                },
+                &[&[FEE_VAULT_SEED, &bump]],
+            ),
+            usdc_rebate,
+        )?;
+    }
+
+    let slot = epoch as usize % ledger.epochs.len();
+    ledger.epochs[slot].claimed = true;
+
+    emit!(FeeRebateClaimed {
+        underwriter: ledger.underwriter,
+        epoch,
+        placed_coverage: entry.placed_coverage,
+        rebate_bps,
+        sol_rebate,
+        usdc_rebate,
+        timestamp: current_time,
+        sequence: master_contract.next_event_sequence(),
+        instruction: instruction_discriminator::<crate::instruction::ClaimFeeRebate>(),
+    });
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BREACH_WATCH_SEED, MASTER_CONTRACT_SEED, ORACLE_DIRECTORY_SEED, PENDING_PAYOUT_SEED};
+use crate::error::InsuranceError;
+use crate::instructions::payout::{assess_payout, find_feed_oracle, trigger_oracle_fault};
+use crate::utils::clock_utils::current_clock;
+use crate::state::{
+    BreachWatch, ClaimEligibilityView, ClaimVerdict, MasterInsuranceContract, Oracle, OracleDirectory,
+    OracleDirectoryView, OracleFault, OracleLookup, PayoutIneligibility, PendingPayout, Policy, PolicyStatus,
+    PolicyStatusView, Treasury, TreasuryHealthView, ViewResponse,
+};
+
+#[derive(Accounts)]
+pub struct GetPolicyStatus<'info> {
+    pub policy: Account<'info, Policy>,
+    
+    /// Pending payout filed against the policy, when one exists
+    #[account(
+        seeds = [PENDING_PAYOUT_SEED, &policy.id.to_le_bytes()],
+        bump = pending_payout.bump
+    )]
+    pub pending_payout: Option<Account<'info, PendingPayout>>,
+    
+    /// Source of the current time
+    #[account(
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+}
+
+#[derive(Accounts)]
+pub struct GetTreasuryHealth<'info> {
+    #[account(
+        address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+}
+
+#[derive(Accounts)]
+pub struct CheckClaimEligibility<'info> {
+    pub policy: Account<'info, Policy>,
+    
+    #[account(
+        seeds = [MASTER_CONTRACT_SEED],
+        bump = master_contract.bump
+    )]
+    pub master_contract: Account<'info, MasterInsuranceContract>,
+    
+    /// Treasury that would fund the payout
+    #[account(address = master_contract.treasury_account @ InsuranceError::TreasuryAccountMismatch)]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Breach observation, considered when the policy has a grace period
+    #[account(
+        seeds = [BREACH_WATCH_SEED, policy.key().as_ref()],
+        bump = breach_watch.bump
+    )]
+    pub breach_watch: Option<Account<'info, BreachWatch>>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveOracle<'info> {
+    #[account(
+        seeds = [ORACLE_DIRECTORY_SEED],
+        bump = oracle_directory.load()?.bump
+    )]
+    pub oracle_directory: AccountLoader<'info, OracleDirectory>,
+}
+
+/// Report a policy's status; the `PolicyStatusView` is returned via return data
+pub fn get_policy_status(ctx: Context<GetPolicyStatus>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    let policy = &ctx.accounts.policy;
+    
+    ViewResponse::new(PolicyStatusView {
+        status: policy.status.clone(),
+        days_remaining: policy.days_remaining(clock.unix_timestamp),
+        premium_due_date: policy.premium_due_date(),
+        pending_payout: ctx.accounts.pending_payout.as_ref().map(|payout| payout.key()),
+    })
+    .set_return_data()
+}
+
+/// Report treasury solvency; the `TreasuryHealthView` is returned via return data
+pub fn get_treasury_health(ctx: Context<GetTreasuryHealth>) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+    let master_contract = &ctx.accounts.master_contract;
+    
+    ViewResponse::new(TreasuryHealthView {
+        reserve_ratio: treasury.calculate_reserve_ratio(),
+        minimum_reserve_ratio: treasury.minimum_reserve_ratio,
+        solvency: treasury.solvency().into(),
+        available_liquidity: treasury.available_liquidity(),
+        open_payout_count: master_contract.open_payout_count,
+        max_open_payouts: master_contract.global_config.max_open_payouts,
+    })
+    .set_return_data()
+}
+
+/// Report whether `trigger_payout` would accept a claim on the policy now, or
+/// the first reason it would not; the `ClaimEligibilityView` is returned via
+/// return data
+///
+/// The trigger is assessed against the latest reading of the oracle
+/// publishing the policy's feed, which must be among the remaining accounts
+/// with every other oracle the trigger reads.
+pub fn check_claim_eligibility(ctx: Context<CheckClaimEligibility>) -> Result<()> {
+    let clock = current_clock(&ctx.accounts.master_contract)?;
+    
+    assess_claim(ctx.accounts, ctx.remaining_accounts, clock.unix_timestamp)?.set_return_data()
+}
+
+/// Run the checks `trigger_payout` makes, through the predicates it shares
+fn assess_claim(
+    accounts: &CheckClaimEligibility,
+    oracle_accounts: &[AccountInfo],
+    current_timestamp: i64,
+) -> Result<ViewResponse<ClaimEligibilityView>> {
+    let refuse = |verdict| {
+        Ok(ViewResponse::new(ClaimEligibilityView { verdict, oracle_value: None, payout_amount: 0 }))
+    };
+    let policy = &accounts.policy;
+    let master_contract = &accounts.master_contract;
+    
+    if master_contract.is_paused {
+        return refuse(ClaimVerdict::ContractPaused);
+    }
+    match policy.status {
+        PolicyStatus::Active => {}
+        PolicyStatus::PendingPayout => return refuse(ClaimVerdict::PayoutPending),
+        PolicyStatus::Expired => return refuse(ClaimVerdict::PolicyExpired),
+        _ => return refuse(ClaimVerdict::PolicyLapsed),
+    }
+    if policy.has_ended(current_timestamp) {
+        return refuse(ClaimVerdict::PolicyExpired);
+    }
+    match trigger_oracle_fault(master_contract, oracle_accounts, current_timestamp)? {
+        Some(OracleFault::CircuitBreaker) => return refuse(ClaimVerdict::CircuitBreaker),
+        Some(OracleFault::Unhealthy) => return refuse(ClaimVerdict::OracleUnhealthy),
+        Some(OracleFault::TimedOut) => return refuse(ClaimVerdict::OracleStale),
+        None => {}
+    }
+    
+    let feed_oracle = find_feed_oracle(&policy.oracle_config.data_feed_id, oracle_accounts)?;
+    let reading = Oracle::from_account_data(&feed_oracle.try_borrow_data()?)
+        .and_then(|oracle| oracle.latest_data())
+        .filter(|reading| current_timestamp - reading.timestamp <= policy.oracle_config.staleness_threshold);
+    let Some(reading) = reading else {
+        return refuse(ClaimVerdict::OracleStale);
+    };
+    
+    let assessment = assess_payout(
+        policy,
+        reading.value,
+        oracle_accounts,
+        master_contract,
+        &accounts.treasury,
+        accounts.breach_watch.as_deref(),
+        current_timestamp,
+    )?;
+    let verdict = match assessment.ineligibility {
+        Some(PayoutIneligibility::WaitingPeriod) => ClaimVerdict::WaitingPeriod,
+        Some(PayoutIneligibility::TriggerNotMet) => ClaimVerdict::TriggerNotMet,
+        Some(reason) => ClaimVerdict::Ineligible(reason),
+        None if !accounts.treasury.can_reserve(assessment.amount) => ClaimVerdict::TreasuryInsolvent,
+        None if !master_contract.has_payout_capacity() => ClaimVerdict::PayoutQueueFull,
+        None => ClaimVerdict::Eligible,
+    };
+    
+    Ok(ViewResponse::new(ClaimEligibilityView {
+        verdict,
+        oracle_value: Some(reading.value),
+        payout_amount: assessment.amount,
+    }))
+}
+
+/// Resolve an oracle id to its account, or an account to its id hash; the
+/// `OracleDirectoryView` is returned via return data
+pub fn resolve_oracle(ctx: Context<ResolveOracle>, lookup: OracleLookup) -> Result<()> {
+    let directory = ctx.accounts.oracle_directory.load()?;
+    let entry = match &lookup {
+        OracleLookup::Id(oracle_id) => directory.find_by_id(oracle_id),
+        OracleLookup::Address(oracle) => directory.find_by_address(oracle),
+    }
+    .ok_or(InsuranceError::OracleNotRegistered)?;
+    
+    ViewResponse::new(OracleDirectoryView {
+        id_hash: entry.id_hash,
+        oracle: entry.oracle,
+        authority: entry.authority,
+        registered: entry.is_registered(),
+    })
+    .set_return_data()
+}
\ No newline at end of file
@@ -1,3 +1,6 @@
+// The IDL instructions generated by `#[program]` still call `AccountInfo::realloc`
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 declare_id!("8epbA4eCd1ieFndY5y8gZzNqmu91rMUdaY3rDVX5tZKj");
@@ -9,9 +12,22 @@ pub mod instructions;
 pub mod state;
 pub mod utils;
 
+/// Whether this build carries `bootstrap_dev_environment`
+pub const DEVNET_BUILD: bool = cfg!(feature = "devnet");
+
+#[cfg(all(feature = "test-clock", not(debug_assertions)))]
+compile_error!("the `test-clock` feature lets the admin move time and must not reach a release build");
+
 use instructions::*;
 use state::*;
 
+/// Cross-program invocation
+///
+/// Build with the `cpi` feature to use the generated client (`cpi::accounts`, `cpi::*`).
+/// Policy and user instructions (`create_policy`, `pay_premium`, `update_policy_metadata`,
+/// `adjust_coverage`, `close_policy`, policy transfers, payouts) are CPI-safe and accept
+/// PDA signers. Admin instructions (master contract, oracle registry, treasury and
+/// discount code management) reject CPI so a PDA can never act as the authority.
 #[program]
 pub mod siglab_contract {
     use super::*;
@@ -30,6 +46,22 @@ pub mod siglab_contract {
         instructions::policy::create_policy(ctx, params)
     }
 
+    pub fn create_policy_from_template(
+        ctx: Context<CreatePolicyFromTemplate>,
+        threshold: f64,
+        coverage_amount: u64,
+        duration_days: u32,
+        region_code: Option<[u8; 8]>,
+    ) -> Result<()> {
+        instructions::product_template::create_policy_from_template(
+            ctx,
+            threshold,
+            coverage_amount,
+            duration_days,
+            region_code,
+        )
+    }
+
     pub fn pay_premium(
         ctx: Context<PayPremium>,
         amount: u64,
@@ -37,33 +69,204 @@ pub mod siglab_contract {
         instructions::policy::pay_premium(ctx, amount)
     }
 
+    pub fn update_policy_metadata(
+        ctx: Context<UpdatePolicyMetadata>,
+        metadata: String,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::policy::update_policy_metadata(ctx, metadata, metadata_uri)
+    }
+
+    pub fn set_auto_claim(ctx: Context<SetAutoClaim>, enabled: bool, keeper: Pubkey) -> Result<()> {
+        instructions::policy::set_auto_claim(ctx, enabled, keeper)
+    }
+
+    pub fn adjust_coverage(
+        ctx: Context<AdjustCoverage>,
+        new_coverage_amount: u64,
+        max_additional_premium: u64,
+    ) -> Result<()> {
+        instructions::policy::adjust_coverage(ctx, new_coverage_amount, max_additional_premium)
+    }
+
+    pub fn expire_policy(ctx: Context<ExpirePolicy>) -> Result<()> {
+        instructions::policy::expire_policy(ctx)
+    }
+
+    pub fn release_underwriting_bond(ctx: Context<ReleaseUnderwritingBond>) -> Result<()> {
+        instructions::underwriting::release_underwriting_bond(ctx)
+    }
+
     pub fn trigger_payout(
         ctx: Context<TriggerPayout>,
-        policy_id: String,
+        policy_id: u64,
+        oracle_value: u64,
+        attested_at: i64,
+        consensus_round: Option<u64>,
+        incident_timestamp: i64,
+    ) -> Result<()> {
+        instructions::payout::trigger_payout(
+            ctx,
+            policy_id,
+            oracle_value,
+            attested_at,
+            consensus_round,
+            incident_timestamp,
+        )
+    }
+
+    pub fn file_claim(ctx: Context<FileClaim>, policy_id: u64, incident_timestamp: i64) -> Result<()> {
+        instructions::payout::file_claim(ctx, policy_id, incident_timestamp)
+    }
+
+    pub fn evaluate_claim(
+        ctx: Context<EvaluateClaim>,
+        oracle_value: u64,
+        attested_at: i64,
+        consensus_round: Option<u64>,
+    ) -> Result<()> {
+        instructions::payout::evaluate_claim(ctx, oracle_value, attested_at, consensus_round)
+    }
+
+    pub fn observe_breach(
+        ctx: Context<ObserveBreach>,
         oracle_value: u64,
     ) -> Result<()> {
-        instructions::payout::trigger_payout(ctx, policy_id, oracle_value)
+        instructions::payout::observe_breach(ctx, oracle_value)
+    }
+
+    pub fn clear_breach_watch(
+        ctx: Context<ClearBreachWatch>,
+        oracle_value: u64,
+    ) -> Result<()> {
+        instructions::payout::clear_breach_watch(ctx, oracle_value)
     }
 
     pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
         instructions::payout::execute_payout(ctx)
     }
 
+    pub fn expire_payout(ctx: Context<ExpirePayout>) -> Result<()> {
+        instructions::payout::expire_payout(ctx)
+    }
+
     pub fn approve_payout(ctx: Context<ApprovePayout>) -> Result<()> {
         instructions::payout::approve_payout(ctx)
     }
 
+    pub fn reject_payout(ctx: Context<RejectPayout>, reason: String) -> Result<()> {
+        instructions::payout::reject_payout(ctx, reason)
+    }
+
+    pub fn release_payout_tranche(ctx: Context<ReleasePayoutTranche>) -> Result<()> {
+        instructions::payout::release_payout_tranche(ctx)
+    }
+
+    pub fn cancel_vesting_payout(ctx: Context<CancelVestingPayout>, reason: String) -> Result<()> {
+        instructions::payout::cancel_vesting_payout(ctx, reason)
+    }
+
+    pub fn recompute_priority(ctx: Context<RecomputePriority>) -> Result<()> {
+        instructions::payout::recompute_priority(ctx)
+    }
+
+    pub fn simulate_payout(
+        ctx: Context<SimulatePayout>,
+        oracle_value: u64,
+    ) -> Result<PayoutAssessment> {
+        instructions::payout::simulate_payout(ctx, oracle_value)
+    }
+
+    pub fn get_policy_status(ctx: Context<GetPolicyStatus>) -> Result<()> {
+        instructions::view::get_policy_status(ctx)
+    }
+
+    pub fn get_treasury_health(ctx: Context<GetTreasuryHealth>) -> Result<()> {
+        instructions::view::get_treasury_health(ctx)
+    }
+
+    pub fn check_claim_eligibility(ctx: Context<CheckClaimEligibility>) -> Result<()> {
+        instructions::view::check_claim_eligibility(ctx)
+    }
+
+    pub fn resolve_oracle(ctx: Context<ResolveOracle>, lookup: OracleLookup) -> Result<()> {
+        instructions::view::resolve_oracle(ctx, lookup)
+    }
+
     pub fn register_oracle(
         ctx: Context<RegisterOracle>,
         oracle_id: String,
         oracle_type: OracleType,
         data_feed_address: String,
+        signature_scheme: SignatureScheme,
+        signer_address: Vec<u8>,
+    ) -> Result<()> {
+        instructions::oracle::register_oracle(
+            ctx,
+            oracle_id,
+            oracle_type,
+            data_feed_address,
+            signature_scheme,
+            signer_address,
+        )
+    }
+
+    pub fn request_oracle_registration(
+        ctx: Context<RequestOracleRegistration>,
+        oracle_id: String,
+        oracle_type: OracleType,
+        data_feed_address: String,
+        signature_scheme: SignatureScheme,
+        signer_address: Vec<u8>,
     ) -> Result<()> {
-        instructions::oracle::register_oracle(ctx, oracle_id, oracle_type, data_feed_address)
+        instructions::oracle::request_oracle_registration(
+            ctx,
+            oracle_id,
+            oracle_type,
+            data_feed_address,
+            signature_scheme,
+            signer_address,
+        )
+    }
+
+    pub fn approve_oracle(ctx: Context<ApproveOracle>) -> Result<()> {
+        instructions::oracle::approve_oracle(ctx)
+    }
+
+    pub fn deny_oracle(ctx: Context<DenyOracle>, reason: String) -> Result<()> {
+        instructions::oracle::deny_oracle(ctx, reason)
+    }
+
+    pub fn unregister_oracle(ctx: Context<UnregisterOracle>, plan: RetirementPlan) -> Result<()> {
+        instructions::oracle::unregister_oracle(ctx, plan)
     }
 
-    pub fn unregister_oracle(ctx: Context<UnregisterOracle>) -> Result<()> {
-        instructions::oracle::unregister_oracle(ctx)
+    pub fn repoint_policies<'info>(ctx: Context<'_, '_, 'info, 'info, RepointPolicies<'info>>) -> Result<()> {
+        instructions::oracle_retirement::repoint_policies(ctx)
+    }
+
+    pub fn set_oracle_replacement(ctx: Context<SetOracleReplacement>, replacement: Pubkey) -> Result<()> {
+        instructions::oracle_retirement::set_oracle_replacement(ctx, replacement)
+    }
+
+    pub fn close_oracle_retirement(ctx: Context<CloseOracleRetirement>) -> Result<()> {
+        instructions::oracle_retirement::close_oracle_retirement(ctx)
+    }
+
+    pub fn record_oracle_outage(ctx: Context<RecordOracleOutage>) -> Result<()> {
+        instructions::oracle_outage::record_oracle_outage(ctx)
+    }
+
+    pub fn claim_outage_refund(ctx: Context<ClaimOutageRefund>) -> Result<()> {
+        instructions::oracle_outage::claim_outage_refund(ctx)
+    }
+
+    pub fn sync_oracle_registry(ctx: Context<SyncOracleRegistry>) -> Result<()> {
+        instructions::oracle::sync_oracle_registry(ctx)
+    }
+
+    pub fn initialize_oracle_directory(ctx: Context<InitializeOracleDirectory>) -> Result<()> {
+        instructions::oracle_directory::initialize_oracle_directory(ctx)
     }
 
     pub fn update_oracle_data(
@@ -73,6 +276,18 @@ pub mod siglab_contract {
         instructions::oracle::update_oracle_data(ctx, data)
     }
 
+    pub fn commit_oracle_data(ctx: Context<CommitOracleData>, commitment: [u8; 32]) -> Result<()> {
+        instructions::oracle::commit_oracle_data(ctx, commitment)
+    }
+
+    pub fn reveal_oracle_data(
+        ctx: Context<UpdateOracleData>,
+        data: OracleData,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::oracle::reveal_oracle_data(ctx, data, salt)
+    }
+
     pub fn update_oracle_status(
         ctx: Context<UpdateOracleStatus>,
         is_active: bool,
@@ -80,6 +295,81 @@ pub mod siglab_contract {
         instructions::oracle::update_oracle_status(ctx, is_active)
     }
 
+    pub fn set_oracle_update_interval(
+        ctx: Context<SetOracleUpdateInterval>,
+        min_update_interval_secs: i64,
+    ) -> Result<()> {
+        instructions::oracle::set_oracle_update_interval(ctx, min_update_interval_secs)
+    }
+
+    pub fn set_oracle_commit_reveal(ctx: Context<SetOracleCommitReveal>, enabled: bool) -> Result<()> {
+        instructions::oracle::set_oracle_commit_reveal(ctx, enabled)
+    }
+
+    pub fn register_data_provider(
+        ctx: Context<RegisterDataProvider>,
+        provider_id: u32,
+        name: String,
+    ) -> Result<()> {
+        instructions::data_provider::register_data_provider(ctx, provider_id, name)
+    }
+
+    pub fn set_oracle_provider(ctx: Context<SetOracleProvider>) -> Result<()> {
+        instructions::data_provider::set_oracle_provider(ctx)
+    }
+
+    pub fn pause_oracle_self(ctx: Context<PauseOracleSelf>, duration_secs: i64) -> Result<()> {
+        instructions::oracle::pause_oracle_self(ctx, duration_secs)
+    }
+
+    pub fn resign_oracle(ctx: Context<ResignOracle>) -> Result<()> {
+        instructions::oracle::resign_oracle(ctx)
+    }
+
+    pub fn complete_oracle_resignation(ctx: Context<CompleteOracleResignation>) -> Result<()> {
+        instructions::oracle::complete_oracle_resignation(ctx)
+    }
+
+    pub fn claim_oracle_fees(ctx: Context<ClaimOracleFees>) -> Result<()> {
+        instructions::oracle::claim_oracle_fees(ctx)
+    }
+
+    pub fn compute_consensus(ctx: Context<ComputeConsensus>) -> Result<()> {
+        instructions::oracle::compute_consensus(ctx)
+    }
+
+    pub fn dispute_consensus(
+        ctx: Context<DisputeConsensus>,
+        round: u64,
+        counter_value: u64,
+    ) -> Result<()> {
+        instructions::oracle::dispute_consensus(ctx, round, counter_value)
+    }
+
+    pub fn resolve_consensus_dispute(
+        ctx: Context<ResolveConsensusDispute>,
+        round: u64,
+        resolved_value: u64,
+    ) -> Result<()> {
+        instructions::oracle::resolve_consensus_dispute(ctx, round, resolved_value)
+    }
+
+    pub fn close_consensus_snapshot(ctx: Context<CloseConsensusSnapshot>) -> Result<()> {
+        instructions::oracle::close_consensus_snapshot(ctx)
+    }
+
+    pub fn snapshot_oracle_observation(
+        ctx: Context<SnapshotOracleObservation>,
+        nonce: u64,
+        observed_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::observation_proof::snapshot_oracle_observation(ctx, nonce, observed_at)
+    }
+
+    pub fn close_observation_proof(ctx: Context<CloseObservationProof>) -> Result<()> {
+        instructions::observation_proof::close_observation_proof(ctx)
+    }
+
     pub fn emergency_oracle_override(
         ctx: Context<EmergencyOracleOverride>,
         corrected_data: OracleData,
@@ -92,8 +382,8 @@ pub mod siglab_contract {
         instructions::oracle::reset_oracle_circuit_breaker(ctx)
     }
 
-    pub fn pause_contract(ctx: Context<PauseContract>) -> Result<()> {
-        instructions::admin::pause_contract(ctx)
+    pub fn pause_contract(ctx: Context<PauseContract>, block_premiums: bool) -> Result<()> {
+        instructions::admin::pause_contract(ctx, block_premiums)
     }
 
     pub fn resume_contract(ctx: Context<ResumeContract>) -> Result<()> {
@@ -107,6 +397,10 @@ pub mod siglab_contract {
         instructions::treasury::initialize_treasury(ctx, minimum_reserve_ratio)
     }
 
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::treasury::initialize_fee_vault(ctx)
+    }
+
     pub fn deposit_funds(
         ctx: Context<DepositFunds>,
         amount: u64,
@@ -115,6 +409,15 @@ pub mod siglab_contract {
         instructions::treasury::deposit_funds(ctx, amount, token_type)
     }
 
+    pub fn donate_to_treasury(
+        ctx: Context<DonateToTreasury>,
+        amount: u64,
+        token_type: TokenType,
+        memo: Option<String>,
+    ) -> Result<()> {
+        instructions::treasury::donate_to_treasury(ctx, amount, token_type, memo)
+    }
+
     pub fn withdraw_funds(
         ctx: Context<WithdrawFunds>,
         amount: u64,
@@ -124,6 +427,25 @@ pub mod siglab_contract {
         instructions::treasury::withdraw_funds(ctx, amount, token_type, reason)
     }
 
+    pub fn set_crank_tip(
+        ctx: Context<SetCrankTip>,
+        tip_lamports: u64,
+        budget_lamports: u64,
+    ) -> Result<()> {
+        instructions::treasury::set_crank_tip(ctx, tip_lamports, budget_lamports)
+    }
+
+    pub fn migrate_treasury(
+        ctx: Context<MigrateTreasury>,
+        params: MigrateTreasuryParams,
+    ) -> Result<()> {
+        instructions::treasury::migrate_treasury(ctx, params)
+    }
+
+    pub fn close_retired_treasury(ctx: Context<CloseRetiredTreasury>) -> Result<()> {
+        instructions::treasury::close_retired_treasury(ctx)
+    }
+
     pub fn update_treasury_balance(ctx: Context<UpdateTreasuryBalance>) -> Result<()> {
         instructions::treasury::update_treasury_balance(ctx)
     }
@@ -136,11 +458,101 @@ pub mod siglab_contract {
         instructions::admin::withdraw_treasury(ctx, amount, token_type)
     }
 
+    pub fn final_settlement(ctx: Context<FinalSettlement>) -> Result<()> {
+        instructions::admin::final_settlement(ctx)
+    }
+
     pub fn update_reserve_ratio(
         ctx: Context<UpdateReserveRatio>,
-        new_reserve_ratio: u64,
+        new_reserve_ratio_bps: u16,
+    ) -> Result<()> {
+        instructions::admin::update_reserve_ratio(ctx, new_reserve_ratio_bps)
+    }
+
+    pub fn update_oracle_fee(
+        ctx: Context<UpdateOracleFee>,
+        new_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::admin::update_oracle_fee(ctx, new_fee_lamports)
+    }
+
+    pub fn set_consensus_fallback(
+        ctx: Context<SetConsensusFallback>,
+        fallback: ConsensusFallbackMode,
+        timeout_secs: i64,
+    ) -> Result<()> {
+        instructions::admin::set_consensus_fallback(ctx, fallback, timeout_secs)
+    }
+
+    pub fn set_min_oracle_reputation(
+        ctx: Context<SetMinOracleReputation>,
+        min_reputation: u8,
+    ) -> Result<()> {
+        instructions::admin::set_min_oracle_reputation(ctx, min_reputation)
+    }
+
+    pub fn waive_late_fee(ctx: Context<WaiveLateFee>) -> Result<()> {
+        instructions::admin::waive_late_fee(ctx)
+    }
+
+    pub fn init_type_config(
+        ctx: Context<InitTypeConfig>,
+        insurance_type: InsuranceType,
+    ) -> Result<()> {
+        instructions::params::init_type_config(ctx, insurance_type)
+    }
+
+    pub fn create_product_template(
+        ctx: Context<CreateProductTemplate>,
+        params: CreateProductTemplateParams,
+    ) -> Result<()> {
+        instructions::product_template::create_product_template(ctx, params)
+    }
+
+    pub fn set_product_template_active(ctx: Context<SetProductTemplateActive>, active: bool) -> Result<()> {
+        instructions::product_template::set_product_template_active(ctx, active)
+    }
+
+    pub fn propose_param_change(
+        ctx: Context<ProposeParamChange>,
+        change: ParamChange,
     ) -> Result<()> {
-        instructions::admin::update_reserve_ratio(ctx, new_reserve_ratio)
+        instructions::params::propose_param_change(ctx, change)
+    }
+
+    pub fn execute_param_change(ctx: Context<ExecuteParamChange>) -> Result<()> {
+        instructions::params::execute_param_change(ctx)
+    }
+
+    pub fn cancel_param_change(ctx: Context<CancelParamChange>) -> Result<()> {
+        instructions::params::cancel_param_change(ctx)
+    }
+
+    pub fn set_consensus_rent_collector(
+        ctx: Context<SetConsensusRentCollector>,
+        new_collector: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::set_consensus_rent_collector(ctx, new_collector)
+    }
+
+    pub fn set_underwriter(ctx: Context<SetUnderwriter>, underwriter: Pubkey) -> Result<()> {
+        instructions::underwriting::set_underwriter(ctx, underwriter)
+    }
+
+    pub fn set_fee_rebate_tiers(ctx: Context<SetFeeRebateTiers>, tiers: Vec<FeeRebateTier>) -> Result<()> {
+        instructions::underwriting::set_fee_rebate_tiers(ctx, tiers)
+    }
+
+    pub fn open_underwriter_ledger(ctx: Context<OpenUnderwriterLedger>) -> Result<()> {
+        instructions::underwriting::open_underwriter_ledger(ctx)
+    }
+
+    pub fn claim_fee_rebate(ctx: Context<ClaimFeeRebate>, epoch: u64) -> Result<()> {
+        instructions::underwriting::claim_fee_rebate(ctx, epoch)
+    }
+
+    pub fn open_region(ctx: Context<OpenRegion>, region_code: [u8; 8]) -> Result<()> {
+        instructions::region::open_region(ctx, region_code)
     }
 
     pub fn transfer_authority(
@@ -148,4 +560,81 @@ pub mod siglab_contract {
     ) -> Result<()> {
         instructions::admin::transfer_authority(ctx)
     }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::admin::accept_authority(ctx)
+    }
+
+    pub fn create_subsidy(
+        ctx: Context<CreateSubsidy>,
+        params: CreateSubsidyParams,
+    ) -> Result<()> {
+        instructions::subsidy::create_subsidy(ctx, params)
+    }
+
+    pub fn top_up_subsidy(ctx: Context<TopUpSubsidy>, amount: u64) -> Result<()> {
+        instructions::subsidy::top_up_subsidy(ctx, amount)
+    }
+
+    pub fn close_subsidy(ctx: Context<CloseSubsidy>) -> Result<()> {
+        instructions::subsidy::close_subsidy(ctx)
+    }
+
+    pub fn create_discount_code(
+        ctx: Context<CreateDiscountCode>,
+        params: CreateDiscountCodeParams,
+    ) -> Result<()> {
+        instructions::discount::create_discount_code(ctx, params)
+    }
+
+    pub fn close_policy(ctx: Context<ClosePolicy>) -> Result<()> {
+        instructions::policy_token::close_policy(ctx)
+    }
+
+    pub fn propose_policy_transfer(
+        ctx: Context<ProposePolicyTransfer>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::policy_transfer::propose_policy_transfer(ctx, new_owner)
+    }
+
+    pub fn accept_policy_transfer(ctx: Context<AcceptPolicyTransfer>) -> Result<()> {
+        instructions::policy_transfer::accept_policy_transfer(ctx)
+    }
+
+    pub fn propose_trigger_amendment(
+        ctx: Context<ProposeTriggerAmendment>,
+        new_conditions: TriggerConditions,
+        max_additional_premium: u64,
+    ) -> Result<()> {
+        instructions::trigger_amendment::propose_trigger_amendment(ctx, new_conditions, max_additional_premium)
+    }
+
+    pub fn approve_trigger_amendment(
+        ctx: Context<ApproveTriggerAmendment>,
+        risk_assessment_score: u8,
+    ) -> Result<()> {
+        instructions::trigger_amendment::approve_trigger_amendment(ctx, risk_assessment_score)
+    }
+
+    pub fn cancel_policy_transfer(ctx: Context<CancelPolicyTransfer>) -> Result<()> {
+        instructions::policy_transfer::cancel_policy_transfer(ctx)
+    }
+
+    pub fn publish_portfolio_report(ctx: Context<PublishPortfolioReport>) -> Result<()> {
+        instructions::portfolio::publish_portfolio_report(ctx)
+    }
+
+    #[cfg(feature = "devnet")]
+    pub fn bootstrap_dev_environment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BootstrapDevEnvironment<'info>>,
+        params: BootstrapParams,
+    ) -> Result<()> {
+        instructions::bootstrap::bootstrap_dev_environment(ctx, params)
+    }
+
+    #[cfg(feature = "test-clock")]
+    pub fn set_test_clock(ctx: Context<SetTestClock>, timestamp: Option<i64>) -> Result<()> {
+        instructions::admin::set_test_clock(ctx, timestamp)
+    }
 }
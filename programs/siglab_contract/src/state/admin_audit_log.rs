@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// Admin action recorded in the audit log
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    /// A policy's late fee was waived; the amount is the fee waived
+    LateFeeWaived,
+}
+
+/// One admin action, kept on chain alongside the event it emitted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AdminAuditEntry {
+    pub action: AdminAction,
+    
+    /// Admin that took the action
+    pub admin: Pubkey,
+    
+    /// Account the action applied to
+    pub target: Pubkey,
+    
+    /// Amount the action moved or forgave
+    pub amount: u64,
+    
+    pub timestamp: i64,
+}
+
+impl AdminAuditEntry {
+    pub const SPACE: usize = 1 + // action
+        32 + // admin
+        32 + // target
+        8 + // amount
+        8; // timestamp
+}
+
+/// Most recent admin actions, oldest first
+///
+/// Opened by the first action it records.
+#[account]
+#[derive(Debug)]
+pub struct AdminAuditLog {
+    pub entries: Vec<AdminAuditEntry>,
+    
+    /// Entries appended since the log was opened, including those dropped
+    pub total_entries: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AdminAuditLog {
+    pub const MAX_ENTRIES: usize = 32;
+    
+    pub fn space() -> usize {
+        8 + // discriminator
+        4 + Self::MAX_ENTRIES * AdminAuditEntry::SPACE + // entries (Vec<AdminAuditEntry>)
+        8 + // total_entries
+        1 // bump
+    }
+    
+    /// Append an entry, dropping the oldest once the log is full
+    pub fn append(&mut self, entry: AdminAuditEntry) {
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+        self.total_entries = self.total_entries.saturating_add(1);
+    }
+}
\ No newline at end of file
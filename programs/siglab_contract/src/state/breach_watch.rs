@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Pending breach observation used to enforce a policy's trigger grace period
+#[account]
+#[derive(Debug)]
+pub struct BreachWatch {
+    /// Policy whose trigger condition was observed breached
+    pub policy: Pubkey,
+
+    /// Account that recorded the observation and paid rent
+    pub observer: Pubkey,
+
+    /// Timestamp when the breach was first observed
+    pub first_observed_at: i64,
+
+    /// Timestamp of the most recent confirming observation
+    pub last_observed_at: i64,
+
+    /// Oracle value at the most recent observation
+    pub observed_value: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BreachWatch {
+    /// Calculate space required for BreachWatch account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // policy
+        32 + // observer
+        8 + // first_observed_at
+        8 + // last_observed_at
+        8 + // observed_value
+        1   // bump
+    }
+
+    /// Check whether the breach has persisted for the full grace period
+    pub fn grace_period_elapsed(&self, grace_period: i64, current_timestamp: i64) -> bool {
+        current_timestamp - self.first_observed_at >= grace_period
+    }
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use super::oracle::ConsensusData;
+use crate::constants::{CONSENSUS_DISPUTE_WINDOW, MAX_ORACLES};
+
+/// How consensus proceeds when active oracles have timed out
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConsensusFallbackMode {
+    /// Refuse new rounds and triggers while a required oracle is timed out
+    HaltPayouts,
+    /// Let triggers use the latest round if it is at most `max_age` seconds old
+    UseLastConsensus { max_age: i64 },
+    /// Require one oracle fewer for a round, never dropping further
+    DegradeThreshold,
+}
+
+impl ConsensusFallbackMode {
+    /// Serialized size of the largest variant
+    pub const SPACE: usize = 1 + 8;
+}
+
+/// Dispute lifecycle of a consensus round
+///
+/// Only `Pending`, `Disputed` and `Resolved` are stored; an undisputed round
+/// reads as `Final` once the dispute window has passed.
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConsensusRoundStatus {
+    /// Within the dispute window and not disputed
+    Pending,
+    /// Disputed by an oracle; unusable until resolved
+    Disputed,
+    /// Dispute settled by the admin with a chosen value
+    Resolved,
+    /// Dispute window passed without a dispute
+    Final,
+}
+
+/// Consensus result of one round, addressable by its round number
+#[account]
+#[derive(Debug)]
+pub struct ConsensusSnapshot {
+    /// Round number the snapshot was created for
+    pub round: u64,
+
+    /// Aggregated consensus over the contributing oracles
+    pub consensus: ConsensusData,
+
+    /// Oracles whose fresh readings entered the round
+    pub oracles: Vec<Pubkey>,
+
+    /// Reading of each contributing oracle, in the same order as `oracles`
+    pub values: Vec<u64>,
+
+    /// Whether the round was formed with a degraded oracle threshold
+    pub degraded: bool,
+
+    /// Stored dispute state of the round
+    pub dispute_status: ConsensusRoundStatus,
+
+    /// Oracle that disputed the round
+    pub disputing_oracle: Option<Pubkey>,
+
+    /// Value proposed by the disputing oracle
+    pub counter_value: Option<u64>,
+
+    /// Value chosen by the admin when resolving the dispute
+    pub resolved_value: Option<u64>,
+
+    /// Account that paid the snapshot's rent
+    pub payer: Pubkey,
+
+    /// Timestamp when the snapshot was created
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ConsensusSnapshot {
+    /// Calculate space required for ConsensusSnapshot account
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // round
+        8 + 1 + 1 + 8 + 8 + 8 + // consensus
+        4 + (32 * MAX_ORACLES) + // oracles
+        4 + (8 * MAX_ORACLES) + // values
+        1 + // degraded
+        1 + // dispute_status
+        1 + 32 + // disputing_oracle
+        1 + 8 + // counter_value
+        1 + 8 + // resolved_value
+        32 + // payer
+        8 + // created_at
+        1   // bump
+    }
+
+    /// Whether the round is recent enough to be used under `staleness_threshold`
+    pub fn is_fresh(&self, staleness_threshold: i64, current_timestamp: i64) -> bool {
+        current_timestamp - self.consensus.consensus_timestamp <= staleness_threshold
+    }
+    
+    /// Dispute status, with undisputed rounds reading as final after the window
+    pub fn status(&self, current_timestamp: i64) -> ConsensusRoundStatus {
+        match self.dispute_status {
+            ConsensusRoundStatus::Pending
+                if current_timestamp - self.created_at > CONSENSUS_DISPUTE_WINDOW =>
+            {
+                ConsensusRoundStatus::Final
+            }
+            status => status,
+        }
+    }
+
+    /// Value the round settles on: the resolved value once a dispute is settled
+    pub fn settled_value(&self) -> u64 {
+        self.resolved_value.unwrap_or(self.consensus.aggregated_value)
+    }
+
+    /// Oracle to slash when a dispute is resolved to `resolved_value`
+    ///
+    /// The disputing oracle is slashed unless its counter value is strictly
+    /// closer to the resolution than the consensus was; otherwise the
+    /// contributing oracle that deviated most is.
+    pub fn dispute_loser(&self, resolved_value: u64) -> Option<Pubkey> {
+        let disputing_oracle = self.disputing_oracle?;
+        let counter_value = self.counter_value?;
+        if counter_value.abs_diff(resolved_value)
+            >= self.consensus.aggregated_value.abs_diff(resolved_value)
+        {
+            return Some(disputing_oracle);
+        }
+        self.oracles
+            .iter()
+            .zip(&self.values)
+            .max_by_key(|(_, value)| value.abs_diff(resolved_value))
+            .map(|(oracle, _)| *oracle)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::error::InsuranceError;
+
+/// Upstream source of data that one or more oracles relay
+///
+/// Oracles attributed to the same provider carry the same data, so
+/// consensus counts them once toward its quorum. Providers are registered
+/// by the admin; oracles without one count on their own.
+#[account]
+#[derive(Debug)]
+pub struct DataProvider {
+    /// Admin-chosen provider identifier, part of the PDA seeds
+    pub provider_id: u32,
+
+    /// Name of the provider, e.g. the data vendor or station network
+    pub name: String,
+
+    /// Registration timestamp
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DataProvider {
+    pub const MAX_NAME_LENGTH: usize = 32;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        4 + // provider_id
+        4 + Self::MAX_NAME_LENGTH + // name
+        8 + // created_at
+        1 // bump
+    }
+
+    pub fn validate_name(name: &str) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= Self::MAX_NAME_LENGTH,
+            InsuranceError::InvalidDataProviderName
+        );
+        Ok(())
+    }
+}
\ No newline at end of file
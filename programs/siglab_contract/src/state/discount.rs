@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug)]
+pub struct DiscountCode {
+    /// SHA-256 hash of the promotional code preimage
+    pub code_hash: [u8; 32],
+
+    /// Admin that created the code
+    pub authority: Pubkey,
+
+    /// Discount applied to the required premium (basis points: 1000 = 10%)
+    pub discount_bps: u16,
+
+    /// Maximum number of redemptions allowed
+    pub max_redemptions: u32,
+
+    /// Redemptions still available
+    pub remaining_redemptions: u32,
+
+    /// Expiration timestamp after which the code can no longer be redeemed
+    pub expires_at: i64,
+
+    /// Code creation timestamp
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DiscountCode {
+    pub const MAX_DISCOUNT_BPS: u16 = 10000;
+
+    /// Calculate space required for DiscountCode account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // code_hash
+        32 + // authority
+        2 + // discount_bps
+        4 + // max_redemptions
+        4 + // remaining_redemptions
+        8 + // expires_at
+        8 + // created_at
+        1   // bump
+    }
+
+    /// Check if the code has expired
+    pub fn is_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp > self.expires_at
+    }
+
+    /// Apply the discount to a premium amount
+    pub fn apply_discount(&self, premium_amount: u64) -> Result<u64> {
+        let discount = premium_amount
+            .checked_mul(self.discount_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+
+        Ok(premium_amount - discount)
+    }
+}
+
+/// Marker account proving a holder has already redeemed a specific code
+#[account]
+#[derive(Debug)]
+pub struct DiscountRedemption {
+    /// Discount code account that was redeemed
+    pub discount_code: Pubkey,
+
+    /// Holder that redeemed the code
+    pub holder: Pubkey,
+
+    /// Redemption timestamp
+    pub redeemed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DiscountRedemption {
+    /// Calculate space required for DiscountRedemption account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // discount_code
+        32 + // holder
+        8 + // redeemed_at
+        1   // bump
+    }
+}
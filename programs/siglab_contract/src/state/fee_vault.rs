@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_REBATE_TIERS;
+use crate::error::InsuranceError;
+
+/// Late fees collected on overdue premiums, held apart from the treasury so
+/// they never count toward premiums or reserves
+///
+/// SOL fees are the account's lamports on top of its rent; USDC fees sit in
+/// the vault's associated token account. Part of them is rebated to
+/// high-volume underwriters under `rebate_tiers`.
+#[account]
+#[derive(Debug)]
+pub struct FeeVault {
+    /// Vault's USDC associated token account; unset until USDC is configured
+    pub usdc_token_account: Pubkey,
+    
+    /// Late fees collected in lamports
+    pub sol_collected: u64,
+    
+    /// Late fees collected in USDC base units
+    pub usdc_collected: u64,
+    
+    /// Lamports rebated to underwriters out of `sol_collected`
+    pub sol_rebated: u64,
+    
+    /// USDC base units rebated to underwriters out of `usdc_collected`
+    pub usdc_rebated: u64,
+    
+    /// Underwriter fee rebate schedule, thresholds rising; no rebates when empty
+    pub rebate_tiers: Vec<FeeRebateTier>,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// Underwriters placing at least `min_placed_coverage` lamports of coverage
+/// in an epoch are rebated `rebate_bps` of the late fees their policies paid
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeRebateTier {
+    pub min_placed_coverage: u64,
+    pub rebate_bps: u16,
+}
+
+impl From<FeeRebateTier> for siglab_core::RebateTier {
+    fn from(tier: FeeRebateTier) -> Self {
+        Self {
+            min_placed: tier.min_placed_coverage,
+            rebate_bps: tier.rebate_bps,
+        }
+    }
+}
+
+impl FeeVault {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // usdc_token_account
+        8 + // sol_collected
+        8 + // usdc_collected
+        8 + // sol_rebated
+        8 + // usdc_rebated
+        4 + (10 * MAX_REBATE_TIERS) + // rebate_tiers
+        1 // bump
+    }
+    
+    /// Book a late fee moved into the vault
+    pub fn record_fee(&mut self, amount: u64, is_usdc: bool) -> Result<()> {
+        let collected = if is_usdc { &mut self.usdc_collected } else { &mut self.sol_collected };
+        *collected = collected.checked_add(amount).ok_or(InsuranceError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Book a rebate paid out of the vault; rebates never exceed the fees
+    /// collected
+    pub fn record_rebate(&mut self, amount: u64, is_usdc: bool) -> Result<()> {
+        let (collected, rebated) = if is_usdc {
+            (self.usdc_collected, &mut self.usdc_rebated)
+        } else {
+            (self.sol_collected, &mut self.sol_rebated)
+        };
+        let total = rebated.checked_add(amount).ok_or(InsuranceError::MathOverflow)?;
+        require!(total <= collected, InsuranceError::InsufficientFeeVault);
+        *rebated = total;
+        Ok(())
+    }
+    
+    /// Rebate schedule in the form the rebate math takes
+    pub fn tiers(&self) -> Vec<siglab_core::RebateTier> {
+        self.rebate_tiers.iter().map(|&tier| tier.into()).collect()
+    }
+}
\ No newline at end of file
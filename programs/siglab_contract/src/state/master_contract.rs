@@ -1,5 +1,209 @@
 use anchor_lang::prelude::*;
-use super::policy::Policy;
+use super::policy::{InsuranceType, Policy};
+use super::portfolio::TypeStats;
+use super::consensus::ConsensusFallbackMode;
+use super::reserve_ratio::ReserveRatioBps;
+use super::treasury::{CurrencyAmount, TokenType};
+use crate::constants::{
+    DEFAULT_ACCURACY_PENALTY, DEFAULT_BOND_MAX_LOSS_RATIO_BPS, DEFAULT_CIRCUIT_BREAKER_FAILURES, DEFAULT_LATE_FEE_BPS, DEFAULT_MAX_CURRENCY_EXPOSURE, DEFAULT_MAX_OPEN_PAYOUTS, DEFAULT_MIN_PAYOUT_AMOUNT, DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS, MAX_COVERAGE_AMOUNT, MAX_COVERAGE_CEILING, MAX_ORACLES, MAX_POLICY_DURATION_CEILING_DAYS,
+    MAX_LATE_FEE_BPS, MAX_POLICY_DURATION_DAYS, MAX_PREMIUM_PAUSES, MIN_ORACLES_FOR_CONSENSUS, MIN_PREMIUM_AMOUNT, DEFAULT_UNDERWRITING_BOND_BPS,
+    DEFAULT_ORACLE_RECOVERY_RATE, DEFAULT_REPUTATION_PENALTY, DEFAULT_ORACLE_CLOCK_SKEW_SECS, MAX_ORACLE_CLOCK_SKEW_SECS,
+};
+use crate::error::InsuranceError;
+
+/// Deployment-level economics, enforced when policies are sold and paid
+///
+/// Monetary limits carry their currency and are converted at the SOL/USD
+/// price of `price_oracle` when applied to a policy in the other currency.
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct GlobalConfig {
+    /// Smallest premium accepted
+    pub min_premium: CurrencyAmount,
+    
+    /// Largest coverage a policy may carry
+    pub max_coverage: CurrencyAmount,
+    
+    /// Longest policy term in days
+    pub max_policy_duration_days: u32,
+    
+    /// Late fee per whole day a premium is overdue, in basis points of the premium
+    pub late_fee_bps: u16,
+    
+    /// Most coverage that may pay out in SOL across live policies, in lamports
+    pub max_sol_exposure: u64,
+    
+    /// Most coverage that may pay out in USDC across live policies, in base units
+    pub max_usdc_exposure: u64,
+    
+    /// Smallest payout worth creating; claims below it are rejected as dust
+    pub min_payout: CurrencyAmount,
+    
+    /// Oracle publishing the USD price of one SOL with 6 decimals; unset
+    /// until policies may be priced across currencies
+    pub price_oracle: Pubkey,
+    
+    /// Most pending payouts that may be open at once; claims beyond it are
+    /// refused until the queue drains
+    pub max_open_payouts: u64,
+    
+    /// Coverage from which a policy must be co-signed by the underwriter at
+    /// creation; `None` lets any policy be sold unsigned
+    pub cosign_coverage_threshold: Option<CurrencyAmount>,
+    
+    /// Bond a co-signer locks, in basis points of the policy's coverage
+    pub underwriting_bond_bps: u16,
+    
+    /// Loss ratio, claims over premiums in basis points, beyond which the
+    /// excess claims are slashed from the co-signer's bond
+    pub bond_max_loss_ratio_bps: u16,
+    
+    /// Share of a policy's term, in basis points, its oracle must have been
+    /// unavailable before the premium for the downtime is refunded at expiry;
+    /// 0 disables outage refunds
+    pub outage_refund_threshold_bps: u16,
+}
+
+impl GlobalConfig {
+    pub const SPACE: usize = CurrencyAmount::SPACE * 3 + 4 + 2 + 8 + 8 + 32 + 8 + 1 + CurrencyAmount::SPACE + 2 + 2 + 2;
+    
+    /// Exposure limit of policies paying out in `currency`
+    pub fn max_exposure(&self, currency: TokenType) -> u64 {
+        match currency {
+            TokenType::SOL => self.max_sol_exposure,
+            TokenType::USDC => self.max_usdc_exposure,
+        }
+    }
+    
+    /// Keep the configuration within the program's sanity rails
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_premium.amount > 0, InsuranceError::InvalidInput);
+        require!(
+            self.max_coverage.amount > 0 && self.max_coverage.amount <= MAX_COVERAGE_CEILING,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.max_policy_duration_days > 0
+                && self.max_policy_duration_days <= MAX_POLICY_DURATION_CEILING_DAYS,
+            InsuranceError::InvalidInput
+        );
+        require!(self.late_fee_bps <= MAX_LATE_FEE_BPS, InsuranceError::InvalidInput);
+        require!(self.max_open_payouts > 0, InsuranceError::InvalidInput);
+        if let Some(threshold) = self.cosign_coverage_threshold {
+            require!(threshold.amount > 0, InsuranceError::InvalidInput);
+        }
+        require!(
+            self.underwriting_bond_bps as u64 <= siglab_core::underwriting::FULL_COVERAGE_BPS,
+            InsuranceError::InvalidInput
+        );
+        require!(self.bond_max_loss_ratio_bps > 0, InsuranceError::InvalidInput);
+        require!(
+            self.outage_refund_threshold_bps as u64 <= siglab_core::underwriting::FULL_COVERAGE_BPS,
+            InsuranceError::InvalidInput
+        );
+        Ok(())
+    }
+}
+
+impl Default for GlobalConfig {
+    fn default() -> Self {
+        Self {
+            min_premium: CurrencyAmount::sol(MIN_PREMIUM_AMOUNT),
+            max_coverage: CurrencyAmount::sol(MAX_COVERAGE_AMOUNT),
+            max_policy_duration_days: MAX_POLICY_DURATION_DAYS,
+            late_fee_bps: DEFAULT_LATE_FEE_BPS,
+            max_sol_exposure: DEFAULT_MAX_CURRENCY_EXPOSURE,
+            max_usdc_exposure: DEFAULT_MAX_CURRENCY_EXPOSURE,
+            min_payout: CurrencyAmount::sol(DEFAULT_MIN_PAYOUT_AMOUNT),
+            price_oracle: Pubkey::default(),
+            max_open_payouts: DEFAULT_MAX_OPEN_PAYOUTS,
+            cosign_coverage_threshold: None,
+            underwriting_bond_bps: DEFAULT_UNDERWRITING_BOND_BPS,
+            bond_max_loss_ratio_bps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+            outage_refund_threshold_bps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+        }
+    }
+}
+
+/// How oracle health reacts to failed validations and successful updates
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleRiskParams {
+    /// Failed validations that trip an oracle's circuit breaker
+    pub failure_threshold: u32,
+    
+    /// Reputation lost per failed validation
+    pub reputation_penalty: u8,
+    
+    /// Accuracy lost per failed validation
+    pub accuracy_penalty: u8,
+    
+    /// Reputation and accuracy regained per successful update, up to 100
+    pub recovery_rate: u8,
+    
+    /// Seconds oracle data may be dated ahead of the cluster clock
+    pub max_clock_skew_secs: u32,
+}
+
+impl OracleRiskParams {
+    pub const SPACE: usize = 4 + 1 + 1 + 1 + 4;
+    
+    /// Keep scores on their 0-100 scale, the breaker reachable and the skew
+    /// within a few minutes
+    pub fn validate(&self) -> Result<()> {
+        require!(self.failure_threshold > 0, InsuranceError::InvalidInput);
+        require!(
+            self.reputation_penalty <= 100 && self.accuracy_penalty <= 100 && self.recovery_rate <= 100,
+            InsuranceError::InvalidInput
+        );
+        require!(self.max_clock_skew_secs <= MAX_ORACLE_CLOCK_SKEW_SECS, InsuranceError::InvalidInput);
+        Ok(())
+    }
+}
+
+impl Default for OracleRiskParams {
+    fn default() -> Self {
+        Self {
+            failure_threshold: DEFAULT_CIRCUIT_BREAKER_FAILURES,
+            reputation_penalty: DEFAULT_REPUTATION_PENALTY,
+            accuracy_penalty: DEFAULT_ACCURACY_PENALTY,
+            recovery_rate: DEFAULT_ORACLE_RECOVERY_RATE,
+            max_clock_skew_secs: DEFAULT_ORACLE_CLOCK_SKEW_SECS,
+        }
+    }
+}
+
+/// Caps on what one region may carry, in basis points of the treasury balance
+/// of the policy's payout currency; `None` leaves it uncapped
+#[derive(Debug, Clone, Copy, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct RegionLimits {
+    /// Coverage in force per region; may exceed 10,000 bps since exposure is
+    /// only partly reserved. While set, every sale in a region is booked in it
+    pub max_exposure_bps: Option<u16>,
+    
+    /// Payouts executed per region per UTC day
+    pub max_daily_payout_bps: Option<u16>,
+}
+
+impl RegionLimits {
+    pub const SPACE: usize = (1 + 2) * 2;
+    
+    /// A zero cap would close every region
+    pub fn validate(&self) -> Result<()> {
+        require!(self.max_exposure_bps != Some(0), InsuranceError::InvalidInput);
+        require!(self.max_daily_payout_bps != Some(0), InsuranceError::InvalidInput);
+        Ok(())
+    }
+}
+
+/// Span of a contract pause during which premiums could not be paid
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct PremiumPause {
+    pub started_at: i64,
+    pub ended_at: i64,
+}
+
+impl PremiumPause {
+    pub const SPACE: usize = 8 + 8;
+}
 
 #[account]
 #[derive(Debug)]
@@ -19,11 +223,11 @@ pub struct MasterInsuranceContract {
     /// Total payouts disbursed to policyholders
     pub total_payouts_disbursed: u64,
     
-    /// Number of currently active policies
+    /// Policies not yet expired, paid out or closed
     pub active_policies_count: u64,
     
-    /// Reserve ratio as percentage (e.g., 20 = 20%)
-    pub reserve_ratio: u64,
+    /// Reserve ratio in basis points (e.g., 2000 = 20%)
+    pub reserve_ratio_bps: u16,
     
     /// Contract pause state
     pub is_paused: bool,
@@ -43,8 +247,100 @@ pub struct MasterInsuranceContract {
     /// Minimum oracle consensus threshold
     pub min_consensus_threshold: u8,
     
+    /// Seconds a new policy may remain unpaid before it can be closed
+    pub activation_window: i64,
+    
+    /// Sequence number of the last emitted event
+    pub event_sequence: u64,
+    
     /// Bump seed for PDA
     pub bump: u8,
+    
+    /// Fee paid to an oracle for each accepted update consumed by a trigger
+    pub oracle_fee_lamports: u64,
+    
+    /// Number of the next consensus round
+    pub consensus_round: u64,
+    
+    /// Receives the rent of closed consensus snapshots
+    pub consensus_rent_collector: Pubkey,
+    
+    /// Behavior when oracles contributing to consensus have timed out
+    pub consensus_fallback: ConsensusFallbackMode,
+    
+    /// Seconds after which an oracle's latest reading counts as timed out
+    pub consensus_timeout_secs: i64,
+    
+    /// Tip paid to a third party executing a ready payout (0 disables tips)
+    pub crank_tip_lamports: u64,
+    
+    /// Seconds a proposed parameter change waits before it can be executed
+    pub param_timelock_secs: i64,
+    
+    /// Number of the next parameter change proposal
+    pub param_proposal_count: u64,
+    
+    /// Premium, coverage and duration limits of this deployment
+    pub global_config: GlobalConfig,
+    
+    /// Reputation an oracle needs to take part in consensus (0-100)
+    pub min_oracle_reputation: u8,
+    
+    /// Triggered payouts not yet executed or expired; the treasury only migrates at zero
+    pub open_payout_count: u64,
+    
+    /// Underwriting totals per insurance type, in `InsuranceType::index` order
+    pub portfolio_stats: [TypeStats; InsuranceType::COUNT],
+    
+    /// Number of the next portfolio report
+    pub portfolio_report_count: u64,
+    
+    /// Id assigned to the next policy created, starting at 1
+    pub next_policy_id: u64,
+    
+    /// Late fees collected across currencies; the funds sit in the fee vault
+    pub late_fees_collected: u64,
+    
+    /// Policies created so far; the next policy's PDA index
+    pub policies_issued: u64,
+    
+    /// Product is being sunset: no new sales, existing policies run off. Irreversible
+    pub winddown: bool,
+    
+    /// When the current pause started blocking premium payments; `None`
+    /// while premiums can be paid, including during pauses that allow them
+    pub premiums_paused_at: Option<i64>,
+    
+    /// Ended premium-blocking pauses, oldest first, up to `MAX_PREMIUM_PAUSES`;
+    /// delinquency excludes the time they cover
+    pub premium_pauses: Vec<PremiumPause>,
+    
+    /// Co-signs policies from `GlobalConfig::cosign_coverage_threshold`;
+    /// unset while the authority co-signs them
+    pub underwriter: Pubkey,
+    
+    /// Circuit breaker threshold and reputation penalties applied to oracles
+    pub oracle_risk_params: OracleRiskParams,
+    
+    /// Per-region exposure and daily payout caps
+    pub region_limits: RegionLimits,
+    
+    /// Authority nominated by `transfer_authority`, taking over once it
+    /// accepts; `None` when no transfer is pending
+    pub pending_authority: Option<Pubkey>,
+    
+    /// Governance program whose PDA is the authority, so indexers can tell
+    /// the contract is DAO-controlled; `None` while a wallet holds it
+    pub governance_program: Option<Pubkey>,
+    
+    /// Set at initialization for test deployments, letting a single oracle
+    /// reach consensus; otherwise the threshold is at least
+    /// `MIN_ORACLES_FOR_CONSENSUS`
+    pub single_oracle_mode: bool,
+    
+    /// Timestamp `now` reports instead of the clock sysvar; test builds only
+    #[cfg(feature = "test-clock")]
+    pub clock_override: Option<i64>,
 }
 
 impl MasterInsuranceContract {
@@ -56,14 +352,145 @@ impl MasterInsuranceContract {
         8 + // total_premiums_collected
         8 + // total_payouts_disbursed
         8 + // active_policies_count
-        8 + // reserve_ratio
+        2 + // reserve_ratio_bps
         1 + // is_paused
         8 + // created_at
         8 + // updated_at
-        4 + (32 * 10) + // oracle_registry (max 10 oracles)
+        4 + (32 * MAX_ORACLES) + // oracle_registry
         1 + // max_oracles
         1 + // min_consensus_threshold
-        1 // bump
+        8 + // activation_window
+        8 + // event_sequence
+        1 + // bump
+        8 + // oracle_fee_lamports
+        8 + // consensus_round
+        32 + // consensus_rent_collector
+        ConsensusFallbackMode::SPACE + // consensus_fallback
+        8 + // consensus_timeout_secs
+        8 + // crank_tip_lamports
+        8 + // param_timelock_secs
+        8 + // param_proposal_count
+        GlobalConfig::SPACE + // global_config
+        1 + // min_oracle_reputation
+        8 + // open_payout_count
+        InsuranceType::COUNT * TypeStats::SPACE + // portfolio_stats
+        8 + // portfolio_report_count
+        8 + // next_policy_id
+        8 + // late_fees_collected
+        8 + // policies_issued
+        1 + // winddown
+        1 + 8 + // premiums_paused_at
+        4 + MAX_PREMIUM_PAUSES * PremiumPause::SPACE + // premium_pauses
+        32 + // underwriter
+        OracleRiskParams::SPACE + // oracle_risk_params
+        RegionLimits::SPACE + // region_limits
+        1 + 32 + // pending_authority
+        1 + 32 + // governance_program
+        1 + // single_oracle_mode
+        if cfg!(feature = "test-clock") { 1 + 8 } else { 0 } // clock_override
+    }
+    
+    pub fn reserve_ratio(&self) -> ReserveRatioBps {
+        ReserveRatioBps::new(self.reserve_ratio_bps)
+    }
+    
+    /// Lowest consensus threshold a deployment may set
+    pub const fn consensus_threshold_floor(single_oracle_mode: bool) -> u8 {
+        if single_oracle_mode {
+            1
+        } else {
+            MIN_ORACLES_FOR_CONSENSUS as u8
+        }
+    }
+    
+    /// Oracles the registry may hold: the configured limit, within the reserved space
+    pub fn oracle_capacity(&self) -> usize {
+        std::cmp::min(self.max_oracles as usize, MAX_ORACLES)
+    }
+    
+    /// Whether another oracle fits both the configured limit and the reserved space
+    pub fn has_oracle_capacity(&self) -> bool {
+        self.oracle_registry.len() < self.oracle_capacity()
+    }
+    
+    /// Underwriting totals of `insurance_type`
+    pub fn portfolio_stats_mut(&mut self, insurance_type: &InsuranceType) -> &mut TypeStats {
+        &mut self.portfolio_stats[insurance_type.index()]
+    }
+    
+    /// Whether another pending payout fits under `max_open_payouts`
+    pub fn has_payout_capacity(&self) -> bool {
+        self.open_payout_count < self.global_config.max_open_payouts
+    }
+    
+    /// Count a newly opened pending payout against `max_open_payouts`
+    pub fn record_payout_opened(&mut self) -> Result<()> {
+        require!(self.has_payout_capacity(), InsuranceError::PayoutQueueFull);
+        self.open_payout_count += 1;
+        Ok(())
+    }
+    
+    /// Stop counting a pending payout that was executed, rejected or expired
+    pub fn record_payout_closed(&mut self) {
+        self.open_payout_count = self.open_payout_count.saturating_sub(1);
+    }
+    
+    /// Take the next id in the global policy sequence
+    pub fn allocate_policy_id(&mut self) -> Result<u64> {
+        let policy_id = self.next_policy_id;
+        self.next_policy_id = policy_id
+            .checked_add(1)
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+        Ok(policy_id)
+    }
+    
+    /// Key that must co-sign policies at or above the co-signing threshold
+    pub fn policy_co_signer(&self) -> Pubkey {
+        if self.underwriter == Pubkey::default() {
+            self.authority
+        } else {
+            self.underwriter
+        }
+    }
+    
+    /// Whether the current pause blocks premium payments
+    pub fn premiums_paused(&self) -> bool {
+        self.premiums_paused_at.is_some()
+    }
+    
+    /// Record the end of a premium-blocking pause, forgetting the oldest one
+    /// kept when full
+    ///
+    /// Returns the seconds premiums were blocked, 0 if they were not.
+    pub fn end_premium_pause(&mut self, current_timestamp: i64) -> i64 {
+        let Some(started_at) = self.premiums_paused_at.take() else {
+            return 0;
+        };
+        if self.premium_pauses.len() >= MAX_PREMIUM_PAUSES {
+            self.premium_pauses.remove(0);
+        }
+        self.premium_pauses.push(PremiumPause {
+            started_at,
+            ended_at: current_timestamp,
+        });
+        (current_timestamp - started_at).max(0)
+    }
+    
+    /// Seconds of `from..to` during which a pause blocked premium payments
+    pub fn premium_blocked_secs(&self, from: i64, to: i64) -> i64 {
+        let windows: Vec<(i64, i64)> = self
+            .premium_pauses
+            .iter()
+            .map(|pause| (pause.started_at, pause.ended_at))
+            .chain(self.premiums_paused_at.map(|started_at| (started_at, i64::MAX)))
+            .collect();
+        siglab_core::blocked_secs(&windows, from, to)
+    }
+    
+    /// Advance and return the sequence number for the next emitted event
+    pub fn next_event_sequence(&mut self) -> u64 {
+        self.event_sequence += 1;
+        self.event_sequence
     }
 }
 
@@ -1,11 +1,53 @@
+pub mod admin_audit_log;
+pub mod breach_watch;
+pub mod consensus;
+pub mod data_provider;
+pub mod discount;
+pub mod fee_vault;
 pub mod master_contract;
+pub mod observation_proof;
 pub mod oracle;
+pub mod oracle_directory;
+pub mod oracle_retirement;
+pub mod param_proposal;
 pub mod payout;
 pub mod policy;
+pub mod policy_history;
+pub mod portfolio;
+pub mod product_template;
+pub mod region;
+pub mod policy_token;
+pub mod policy_transfer;
+pub mod reserve_ratio;
+pub mod subsidy;
 pub mod treasury;
+pub mod type_config;
+pub mod underwriter_ledger;
+pub mod view;
 
+pub use admin_audit_log::*;
+pub use breach_watch::*;
+pub use consensus::*;
+pub use data_provider::*;
+pub use discount::*;
+pub use fee_vault::*;
 pub use master_contract::*;
+pub use observation_proof::*;
 pub use oracle::*;
+pub use oracle_directory::*;
+pub use oracle_retirement::*;
+pub use param_proposal::*;
 pub use payout::*;
 pub use policy::*;
-pub use treasury::*;
\ No newline at end of file
+pub use policy_history::*;
+pub use portfolio::*;
+pub use product_template::*;
+pub use region::*;
+pub use policy_token::*;
+pub use policy_transfer::*;
+pub use reserve_ratio::*;
+pub use subsidy::*;
+pub use treasury::*;
+pub use type_config::*;
+pub use underwriter_ledger::*;
+pub use view::*;
\ No newline at end of file
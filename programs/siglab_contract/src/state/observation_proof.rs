@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+/// Immutable copy of one oracle reading and the oracle's standing when it
+/// was taken, kept as evidence for off-chain claim disputes
+#[account]
+#[derive(Debug)]
+pub struct ObservationProof {
+    /// Oracle whose reading was copied
+    pub oracle: Pubkey,
+
+    /// Seed chosen by the creator, allowing many proofs per oracle
+    pub nonce: u64,
+
+    /// Reported value
+    pub value: u64,
+
+    /// When the oracle reported the value
+    pub observed_at: i64,
+
+    /// Confidence interval of the value
+    pub confidence: u64,
+
+    /// Oracle reputation (0-100) when the proof was taken
+    pub reputation_score: u8,
+
+    /// Oracle accuracy score (0-100) when the proof was taken
+    pub accuracy_score: u8,
+
+    /// Validations the oracle had failed when the proof was taken
+    pub failed_validations: u32,
+
+    /// Updates the oracle made in the 24 hours before the proof
+    pub updates_last_24h: u32,
+
+    /// Whether the oracle's circuit breaker was tripped
+    pub circuit_breaker: bool,
+
+    /// Paid the rent and may close the proof after the retention period
+    pub creator: Pubkey,
+
+    /// When the proof was taken
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ObservationProof {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // oracle
+        8 + // nonce
+        8 + // value
+        8 + // observed_at
+        8 + // confidence
+        1 + // reputation_score
+        1 + // accuracy_score
+        4 + // failed_validations
+        4 + // updates_last_24h
+        1 + // circuit_breaker
+        32 + // creator
+        8 + // created_at
+        1 // bump
+    }
+}
\ No newline at end of file
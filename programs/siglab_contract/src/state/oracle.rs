@@ -1,12 +1,50 @@
 use anchor_lang::prelude::*;
+use siglab_core::versioning::{OracleDataExtensions, VersionError};
+use crate::constants::ORACLE_RESIGNATION_COOLDOWN;
+use crate::error::InsuranceError;
+use super::master_contract::OracleRiskParams;
 
 #[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
 pub enum OracleType {
     Pyth,
 }
 
+/// Key type an oracle signs its updates with
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum SignatureScheme {
+    /// Signed by the oracle authority's Solana key
+    Ed25519,
+    /// Signed by an Ethereum key, verified through the secp256k1 program
+    Secp256k1,
+}
+
+impl SignatureScheme {
+    /// Length of the signer address stored on the oracle for this scheme
+    pub fn signer_address_length(self) -> usize {
+        match self {
+            SignatureScheme::Ed25519 => 0,
+            SignatureScheme::Secp256k1 => Oracle::ETH_ADDRESS_LENGTH,
+        }
+    }
+}
+
+/// Why a healthy-looking oracle was left out of consensus
+#[derive(Clone, Copy, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum OracleExclusionReason {
+    /// The oracle's circuit breaker has tripped
+    CircuitBreaker,
+    /// The oracle's reputation is below the master contract's floor
+    LowReputation,
+}
+
+/// Oracle reading as submitted by an oracle authority
+///
+/// `version` comes first so readers can tell layouts apart; see
+/// [`siglab_core::versioning`] for how minor versions extend the data.
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
 pub struct OracleData {
+    /// Layout version (major in the high nibble, minor in the low nibble)
+    pub version: u8,
     /// Oracle data value (price/event data)
     pub value: u64,
     /// Timestamp when data was created
@@ -17,53 +55,312 @@ pub struct OracleData {
     pub signature: [u8; 64],
     /// Nonce to prevent replay attacks
     pub nonce: u64,
+    /// Fields added after v1, encoded per `version`
+    pub extensions: Vec<u8>,
 }
 
-#[account]
+impl OracleData {
+    /// Decode the fields added after v1
+    ///
+    /// Unknown major versions fail with `UnsupportedOracleDataVersion`;
+    /// extension bytes from newer minor versions are ignored.
+    pub fn extensions(&self) -> Result<OracleDataExtensions<'_>> {
+        OracleDataExtensions::decode(self.version, &self.extensions).map_err(|err| match err {
+            VersionError::UnsupportedMajor(_) => InsuranceError::UnsupportedOracleDataVersion.into(),
+            VersionError::Malformed => InsuranceError::InvalidOracleData.into(),
+        })
+    }
+}
+
+/// Single oracle reading retained in the on-chain history
+#[zero_copy]
+#[derive(Debug, Default, PartialEq)]
+pub struct OracleObservation {
+    /// Oracle data value (price/event data)
+    pub value: u64,
+    /// Timestamp when data was created
+    pub timestamp: i64,
+    /// Confidence interval for the data
+    pub confidence: u64,
+}
+
+impl From<&OracleData> for OracleObservation {
+    fn from(data: &OracleData) -> Self {
+        Self {
+            value: data.value,
+            timestamp: data.timestamp,
+            confidence: data.confidence,
+        }
+    }
+}
+
+/// Oracles are updated on every feed tick, so the account uses a fixed
+/// zero-copy layout: identifiers are zero-padded byte arrays, flags are `u8`
+/// and recent readings live in a ring buffer that is overwritten in place.
+#[account(zero_copy)]
 #[derive(Debug)]
 pub struct Oracle {
-    /// Unique oracle identifier
-    pub oracle_id: String,
     /// Authority pubkey that can update this oracle
     pub authority: Pubkey,
-    /// Type of oracle (Pyth Network only)
-    pub oracle_type: OracleType,
-    /// Whether this oracle is currently active
-    pub is_active: bool,
+    /// Unique oracle identifier, zero-padded to `MAX_ORACLE_ID_LENGTH`
+    pub oracle_id: [u8; 32],
+    /// Data feed address for external oracle sources, zero-padded to `MAX_DATA_FEED_ADDRESS_LENGTH`
+    pub data_feed_address: [u8; 64],
     /// Timestamp of last data update
     pub last_update_timestamp: i64,
-    /// Data feed address for external oracle sources
-    pub data_feed_address: String,
-    /// Latest oracle data
-    pub latest_data: Option<OracleData>,
-    /// Oracle reputation score (0-100)
-    pub reputation_score: u8,
     /// Total number of updates provided
     pub update_count: u64,
+    /// Nonce of the latest accepted data, to prevent replay attacks
+    pub last_nonce: u64,
+    /// Minimum seconds between accepted updates
+    pub min_update_interval_secs: i64,
+    /// Fees accrued for consumed updates, claimable from the treasury
+    pub unclaimed_fees: u64,
+    /// `update_count` when a fee was last accrued, so each update is paid once
+    pub fee_paid_update_count: u64,
+    /// Lamports bonded by the operator when requesting registration
+    pub stake_lamports: u64,
+    /// End of the operator's maintenance pause, 0 when none was set
+    pub paused_until: i64,
     /// Health metrics for this oracle
     pub health_metrics: OracleHealthMetrics,
+    /// Ring buffer of recent observations, the oldest is overwritten first
+    pub history: [OracleObservation; 32],
+    /// Slot the next observation is written to
+    pub history_head: u16,
+    /// Number of populated history slots
+    pub history_len: u16,
+    /// Length of `oracle_id` in bytes
+    pub oracle_id_len: u8,
+    /// Length of `data_feed_address` in bytes
+    pub data_feed_address_len: u8,
+    /// Type of oracle as an `OracleType` discriminant (Pyth Network only)
+    pub oracle_type: u8,
+    /// Whether this oracle is currently active (0 or 1)
+    pub active: u8,
+    /// Oracle reputation score (0-100)
+    pub reputation_score: u8,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Scheme updates are signed with, as a `SignatureScheme` discriminant
+    pub signature_scheme: u8,
+    /// Ethereum address of the signer for secp256k1 oracles, zeroed otherwise
+    pub eth_address: [u8; 20],
+    /// Whether the registration awaits admin approval (0 or 1)
+    pub pending: u8,
+    /// Hash of the committed, not yet revealed update, zeroed when none is pending
+    pub commitment: [u8; 32],
+    /// Slot the pending commitment was made in
+    pub commitment_slot: u64,
+    /// Timestamp the pending commitment was made at
+    pub committed_at: i64,
+    /// Commit timestamp of the latest observation if it was revealed, 0 otherwise
+    pub latest_committed_at: i64,
+    /// Timestamp the latest observation was revealed at, 0 unless it was
+    pub latest_revealed_at: i64,
+    /// `DataProvider` the oracle relays, the default key when none is set
+    pub provider: Pubkey,
+    /// When the operator's resignation takes effect, 0 unless it resigned
+    pub retires_at: i64,
+    /// Whether updates must be committed before they are revealed (0 or 1)
+    pub commit_reveal: u8,
+    pub _padding: [u8; 7],
 }
 
 impl Oracle {
     pub const MAX_ORACLE_ID_LENGTH: usize = 32;
     pub const MAX_DATA_FEED_ADDRESS_LENGTH: usize = 64;
+    pub const HISTORY_LENGTH: usize = 32;
+    pub const ETH_ADDRESS_LENGTH: usize = 20;
     
     /// Calculate space required for Oracle account
     pub fn space() -> usize {
         8 + // discriminator
-        4 + Self::MAX_ORACLE_ID_LENGTH + // oracle_id (String)
-        32 + // authority
-        1 + // oracle_type
-        1 + // is_active
-        8 + // last_update_timestamp
-        4 + Self::MAX_DATA_FEED_ADDRESS_LENGTH + // data_feed_address (String)
-        1 + 8 + 8 + 8 + 64 + 8 + // latest_data (Option<OracleData>)
-        1 + // reputation_score
-        8 + // update_count
-        4 + 1 + 8 + 4 + 1 + // health_metrics (OracleHealthMetrics)
-        1   // bump
+        std::mem::size_of::<Self>() // fixed zero-copy layout
+    }
+    
+    /// Borrow an oracle from raw account data without copying it
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        let discriminator = Self::DISCRIMINATOR;
+        if data.len() < Self::space() || &data[..discriminator.len()] != discriminator {
+            return None;
+        }
+        bytemuck::try_from_bytes(&data[8..Self::space()]).ok()
+    }
+    
+    /// Mutably borrow an oracle from raw account data without copying it
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        let discriminator = Self::DISCRIMINATOR;
+        if data.len() < Self::space() || &data[..discriminator.len()] != discriminator {
+            return None;
+        }
+        bytemuck::try_from_bytes_mut(&mut data[8..Self::space()]).ok()
+    }
+    
+    pub fn oracle_id(&self) -> &str {
+        std::str::from_utf8(&self.oracle_id[..self.oracle_id_len as usize]).unwrap_or_default()
+    }
+    
+    pub fn set_oracle_id(&mut self, oracle_id: &str) {
+        self.oracle_id = [0; 32];
+        self.oracle_id[..oracle_id.len()].copy_from_slice(oracle_id.as_bytes());
+        self.oracle_id_len = oracle_id.len() as u8;
+    }
+    
+    pub fn data_feed_address(&self) -> &str {
+        std::str::from_utf8(&self.data_feed_address[..self.data_feed_address_len as usize])
+            .unwrap_or_default()
+    }
+    
+    pub fn set_data_feed_address(&mut self, data_feed_address: &str) {
+        self.data_feed_address = [0; 64];
+        self.data_feed_address[..data_feed_address.len()].copy_from_slice(data_feed_address.as_bytes());
+        self.data_feed_address_len = data_feed_address.len() as u8;
+    }
+    
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+    
+    pub fn set_active(&mut self, is_active: bool) {
+        self.active = is_active as u8;
+    }
+    
+    /// Whether the operator has paused the oracle for maintenance at `current_timestamp`
+    ///
+    /// Paused oracles abstain from consensus without counting as unhealthy.
+    pub fn is_paused(&self, current_timestamp: i64) -> bool {
+        current_timestamp < self.paused_until
+    }
+    
+    /// Why this oracle may not take part in consensus, if it may not
+    ///
+    /// Inactive and paused oracles are not reported here; they are skipped silently.
+    pub fn consensus_exclusion(&self, min_reputation: u8) -> Option<OracleExclusionReason> {
+        if self.health_metrics.circuit_breaker_active() {
+            Some(OracleExclusionReason::CircuitBreaker)
+        } else if self.reputation_score < min_reputation {
+            Some(OracleExclusionReason::LowReputation)
+        } else {
+            None
+        }
+    }
+    
+    /// Requested by an operator and not yet approved; pending oracles are
+    /// inactive and absent from the registry
+    pub fn is_pending(&self) -> bool {
+        self.pending != 0
+    }
+    
+    pub fn set_pending(&mut self, is_pending: bool) {
+        self.pending = is_pending as u8;
+    }
+    
+    /// Resigned by its operator; resigning oracles are inactive until they
+    /// leave the registry
+    pub fn is_resigning(&self) -> bool {
+        self.retires_at != 0
+    }
+    
+    /// When a resignation at `current_timestamp` takes effect
+    ///
+    /// Claims settling against the oracle's readings get
+    /// `ORACLE_RESIGNATION_COOLDOWN` to do so, unless it has not reported
+    /// within that long, in which case none can rest on them.
+    pub fn resignation_effective_at(&self, current_timestamp: i64) -> i64 {
+        if current_timestamp - self.last_update_timestamp >= ORACLE_RESIGNATION_COOLDOWN {
+            current_timestamp
+        } else {
+            current_timestamp + ORACLE_RESIGNATION_COOLDOWN
+        }
+    }
+    
+    /// Accrue `fee` for the latest update unless it has already been paid for
+    pub fn accrue_fee(&mut self, fee: u64) {
+        if self.update_count > self.fee_paid_update_count {
+            self.unclaimed_fees = self.unclaimed_fees.saturating_add(fee);
+            self.fee_paid_update_count = self.update_count;
+        }
+    }
+    
+    /// Whether an update at `current_timestamp` respects the minimum update interval
+    pub fn update_interval_elapsed(&self, current_timestamp: i64) -> bool {
+        self.last_update_timestamp == 0
+            || current_timestamp - self.last_update_timestamp >= self.min_update_interval_secs
+    }
+    
+    pub fn signature_scheme(&self) -> SignatureScheme {
+        match self.signature_scheme {
+            1 => SignatureScheme::Secp256k1,
+            _ => SignatureScheme::Ed25519,
+        }
+    }
+    
+    /// Most recent observation, if any data has been reported
+    pub fn latest_data(&self) -> Option<OracleObservation> {
+        if self.history_len == 0 {
+            return None;
+        }
+        let latest = (self.history_head as usize + Self::HISTORY_LENGTH - 1) % Self::HISTORY_LENGTH;
+        Some(self.history[latest])
+    }
+    
+    /// Whether updates must go through `commit_oracle_data` and `reveal_oracle_data`
+    pub fn commit_reveal(&self) -> bool {
+        self.commit_reveal != 0
+    }
+    
+    /// Switch commit-reveal mode, dropping any pending commitment
+    pub fn set_commit_reveal(&mut self, enabled: bool) {
+        self.commit_reveal = enabled as u8;
+        self.clear_commitment();
+    }
+    
+    /// Data provider the oracle is attributed to, if any
+    pub fn provider(&self) -> Option<Pubkey> {
+        (self.provider != Pubkey::default()).then_some(self.provider)
+    }
+    
+    pub fn set_provider(&mut self, provider: Option<Pubkey>) {
+        self.provider = provider.unwrap_or_default();
+    }
+    
+    pub fn has_commitment(&self) -> bool {
+        self.commitment != [0; 32]
+    }
+    
+    pub fn clear_commitment(&mut self) {
+        self.commitment = [0; 32];
+        self.commitment_slot = 0;
+        self.committed_at = 0;
+    }
+    
+    /// Whether a policy created at `created_at` was bought after the latest
+    /// observation was committed and before it was revealed
+    pub fn committed_during(&self, created_at: i64) -> bool {
+        self.latest_committed_at != 0
+            && (self.latest_committed_at..=self.latest_revealed_at).contains(&created_at)
+    }
+    
+    /// Append an observation, overwriting the oldest once the buffer is full
+    ///
+    /// The observation counts as unrevealed; `reveal_oracle_data` marks it
+    /// revealed afterwards.
+    pub fn record_observation(&mut self, observation: OracleObservation) {
+        self.latest_committed_at = 0;
+        self.latest_revealed_at = 0;
+        self.history[self.history_head as usize] = observation;
+        self.history_head = ((self.history_head as usize + 1) % Self::HISTORY_LENGTH) as u16;
+        if (self.history_len as usize) < Self::HISTORY_LENGTH {
+            self.history_len += 1;
+        }
+    }
+    
+    /// Retained observations from oldest to newest
+    pub fn history(&self) -> impl Iterator<Item = &OracleObservation> {
+        let len = self.history_len as usize;
+        let oldest = (self.history_head as usize + Self::HISTORY_LENGTH - len) % Self::HISTORY_LENGTH;
+        (0..len).map(move |i| &self.history[(oldest + i) % Self::HISTORY_LENGTH])
     }
 }
 
@@ -83,177 +380,126 @@ pub struct ConsensusData {
     pub standard_deviation: u64,
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug)]
+#[zero_copy]
+#[derive(Debug)]
 pub struct OracleHealthMetrics {
-    /// Number of successful updates in the last 24 hours
-    pub updates_24h: u32,
-    /// Average accuracy score (0-100)
-    pub accuracy_score: u8,
     /// Last health check timestamp
     pub last_health_check: i64,
+    /// Hour (unix timestamp / 3600) of the newest counted update
+    pub latest_update_hour: i64,
+    /// Successful updates per hour over the last 24 hours, indexed by `hour % 24`
+    pub hourly_updates: [u16; 24],
     /// Number of failed validations
     pub failed_validations: u32,
-    /// Circuit breaker status
-    pub circuit_breaker_active: bool,
+    /// Average accuracy score (0-100)
+    pub accuracy_score: u8,
+    /// Circuit breaker status (0 or 1)
+    pub circuit_breaker: u8,
+    /// Explicit padding to keep the layout free of implicit gaps
+    pub _padding: [u8; 2],
+}
+
+impl Default for OracleHealthMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl OracleHealthMetrics {
     pub fn new() -> Self {
         Self {
-            updates_24h: 0,
-            accuracy_score: 100,
             last_health_check: 0,
+            latest_update_hour: 0,
+            hourly_updates: [0; 24],
             failed_validations: 0,
-            circuit_breaker_active: false,
+            accuracy_score: 100,
+            circuit_breaker: 0,
+            _padding: [0; 2],
         }
     }
     
+    /// Successful updates in the 24 hours up to `current_timestamp`
+    pub fn updates_in_last_24h(&self, current_timestamp: i64) -> u32 {
+        siglab_core::rolling::count_in_window(
+            &self.hourly_updates,
+            self.latest_update_hour,
+            current_timestamp,
+        )
+    }
+    
+    pub fn circuit_breaker_active(&self) -> bool {
+        self.circuit_breaker != 0
+    }
+    
+    pub fn set_circuit_breaker_active(&mut self, active: bool) {
+        self.circuit_breaker = active as u8;
+    }
+    
     /// Update metrics after a successful oracle update
-    pub fn record_successful_update(&mut self, current_timestamp: i64) {
-        self.updates_24h += 1;
+    ///
+    /// Only updates spaced by the oracle's minimum update interval count
+    /// toward the rolling 24-hour window.
+    pub fn record_successful_update(
+        &mut self,
+        current_timestamp: i64,
+        spaced: bool,
+        risk_params: &OracleRiskParams,
+    ) {
+        if spaced {
+            siglab_core::rolling::record(
+                &mut self.hourly_updates,
+                &mut self.latest_update_hour,
+                current_timestamp,
+            );
+        }
         self.last_health_check = current_timestamp;
         
         // Improve accuracy score for successful updates (max 100)
-        if self.accuracy_score < 100 {
-            self.accuracy_score = std::cmp::min(100, self.accuracy_score + 1);
-        }
+        self.accuracy_score = std::cmp::min(100, self.accuracy_score.saturating_add(risk_params.recovery_rate));
     }
     
     /// Record a failed validation
-    pub fn record_failed_validation(&mut self, current_timestamp: i64) {
+    pub fn record_failed_validation(&mut self, current_timestamp: i64, risk_params: &OracleRiskParams) {
         self.failed_validations += 1;
         self.last_health_check = current_timestamp;
         
         // Decrease accuracy score for failures
-        if self.accuracy_score > 0 {
-            self.accuracy_score = self.accuracy_score.saturating_sub(5);
-        }
+        self.accuracy_score = self.accuracy_score.saturating_sub(risk_params.accuracy_penalty);
         
         // Activate circuit breaker if too many failures
-        if self.failed_validations >= 5 {
-            self.circuit_breaker_active = true;
+        if self.failed_validations >= risk_params.failure_threshold {
+            self.set_circuit_breaker_active(true);
         }
     }
     
-    /// Reset daily metrics (should be called every 24 hours)
+    /// Reset daily failure metrics (should be called every 24 hours)
+    ///
+    /// Update counts roll over on their own and are left untouched.
     pub fn reset_daily_metrics(&mut self, current_timestamp: i64) {
-        self.updates_24h = 0;
         self.last_health_check = current_timestamp;
         
         // Reset failed validations if oracle is performing well
         if self.accuracy_score > 80 {
             self.failed_validations = 0;
-            self.circuit_breaker_active = false;
+            self.set_circuit_breaker_active(false);
         }
     }
 }
 
 impl ConsensusData {
     /// Create consensus data from multiple oracle values
-    pub fn from_oracle_values(values: &[u64], timestamp: i64) -> Self {
-        let oracle_count = values.len() as u8;
-        let aggregated_value = Self::calculate_weighted_average(values);
-        let median_value = Self::calculate_median(values);
-        let standard_deviation = Self::calculate_standard_deviation(values, aggregated_value);
-        let confidence_score = Self::calculate_confidence_score(values, standard_deviation);
+    ///
+    /// `values` is sorted in place to find the median.
+    pub fn from_oracle_values(values: &mut [u64], timestamp: i64) -> Self {
+        let stats = siglab_core::consensus_from_values(values);
         
         Self {
-            aggregated_value,
-            confidence_score,
-            oracle_count,
+            aggregated_value: stats.aggregated_value,
+            confidence_score: stats.confidence_score,
+            oracle_count: values.len() as u8,
             consensus_timestamp: timestamp,
-            median_value,
-            standard_deviation,
-        }
-    }
-    
-    /// Calculate weighted average (for now, simple mean)
-    fn calculate_weighted_average(values: &[u64]) -> u64 {
-        if values.is_empty() {
-            return 0;
-        }
-        let sum: u64 = values.iter().sum();
-        sum / values.len() as u64
-    }
-    
-    /// Calculate median value
-    fn calculate_median(values: &[u64]) -> u64 {
-        if values.is_empty() {
-            return 0;
-        }
-        
-        let mut sorted_values = values.to_vec();
-        sorted_values.sort();
-        
-        let len = sorted_values.len();
-        if len % 2 == 0 {
-            (sorted_values[len / 2 - 1] + sorted_values[len / 2]) / 2
-        } else {
-            sorted_values[len / 2]
-        }
-    }
-    
-    /// Calculate standard deviation
-    fn calculate_standard_deviation(values: &[u64], mean: u64) -> u64 {
-        if values.len() <= 1 {
-            return 0;
+            median_value: stats.median_value,
+            standard_deviation: stats.standard_deviation,
         }
-        
-        let variance: u64 = values
-            .iter()
-            .map(|&value| {
-                let diff = if value > mean { value - mean } else { mean - value };
-                diff * diff
-            })
-            .sum::<u64>() / values.len() as u64;
-        
-        // Simple integer square root approximation
-        Self::integer_sqrt(variance)
-    }
-    
-    /// Calculate confidence score based on agreement level
-    fn calculate_confidence_score(values: &[u64], std_dev: u64) -> u8 {
-        if values.is_empty() {
-            return 0;
-        }
-        
-        let mean = values.iter().sum::<u64>() / values.len() as u64;
-        if mean == 0 {
-            return 0;
-        }
-        
-        // Confidence decreases as standard deviation increases relative to mean
-        let coefficient_of_variation = (std_dev * 100) / mean;
-        
-        // Confidence score: higher CV means lower confidence
-        if coefficient_of_variation > 100 {
-            0
-        } else {
-            (100 - coefficient_of_variation) as u8
-        }
-    }
-    
-    /// Simple integer square root using binary search
-    pub fn integer_sqrt(n: u64) -> u64 {
-        if n == 0 {
-            return 0;
-        }
-        
-        let mut left = 1u64;
-        let mut right = n;
-        let mut result = 0u64;
-        
-        while left <= right {
-            let mid = left + (right - left) / 2;
-            
-            if mid <= n / mid {
-                result = mid;
-                left = mid + 1;
-            } else {
-                right = mid - 1;
-            }
-        }
-        
-        result
     }
 }
\ No newline at end of file
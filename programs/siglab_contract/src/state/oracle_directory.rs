@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::constants::MAX_ORACLES;
+use crate::error::InsuranceError;
+
+/// One oracle known to the directory
+#[zero_copy]
+#[derive(Debug, Default, PartialEq)]
+pub struct OracleDirectoryEntry {
+    /// SHA-256 of the oracle id, all zeroes for a slot never used
+    pub id_hash: [u8; 32],
+    /// Oracle account, the PDA of the id
+    pub oracle: Pubkey,
+    /// Authority that signs the oracle's updates
+    pub authority: Pubkey,
+    /// 1 while the oracle is in the registry; unregistered entries keep
+    /// their id so a re-registration lands in the same slot
+    pub registered: u8,
+}
+
+impl OracleDirectoryEntry {
+    pub fn is_registered(&self) -> bool {
+        self.registered != 0
+    }
+    
+    fn is_empty(&self) -> bool {
+        self.id_hash == [0; 32]
+    }
+}
+
+/// Reverse index of the oracle registry, resolving an oracle id to its
+/// account and an account to its id hash without loading every oracle
+///
+/// Slots are fixed at `MAX_ORACLES`. An id keeps its slot across
+/// unregistration, and a slot is only handed to another id once every
+/// slot has been used.
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct OracleDirectory {
+    pub entries: [OracleDirectoryEntry; MAX_ORACLES],
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl OracleDirectory {
+    pub fn space() -> usize {
+        8 + // discriminator
+        std::mem::size_of::<Self>() // fixed zero-copy layout
+    }
+    
+    /// Key of an oracle id in the directory
+    pub fn id_hash(oracle_id: &str) -> [u8; 32] {
+        hash(oracle_id.as_bytes()).to_bytes()
+    }
+    
+    pub fn find_by_id(&self, oracle_id: &str) -> Option<&OracleDirectoryEntry> {
+        let id_hash = Self::id_hash(oracle_id);
+        self.entries.iter().find(|entry| !entry.is_empty() && entry.id_hash == id_hash)
+    }
+    
+    pub fn find_by_address(&self, oracle: &Pubkey) -> Option<&OracleDirectoryEntry> {
+        self.entries.iter().find(|entry| !entry.is_empty() && entry.oracle == *oracle)
+    }
+    
+    /// Record a registered oracle, reusing the slot of an earlier
+    /// registration of the same id
+    pub fn record_registration(&mut self, oracle_id: &str, oracle: Pubkey, authority: Pubkey) -> Result<()> {
+        let id_hash = Self::id_hash(oracle_id);
+        let slot = self
+            .entries
+            .iter()
+            .position(|entry| entry.id_hash == id_hash)
+            .or_else(|| self.entries.iter().position(OracleDirectoryEntry::is_empty))
+            .or_else(|| self.entries.iter().position(|entry| !entry.is_registered()))
+            .ok_or(InsuranceError::MaxOraclesExceeded)?;
+        
+        self.entries[slot] = OracleDirectoryEntry { id_hash, oracle, authority, registered: 1 };
+        Ok(())
+    }
+    
+    /// Mark an oracle unregistered, keeping its slot for a re-registration
+    pub fn record_unregistration(&mut self, oracle: &Pubkey) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| !entry.is_empty() && entry.oracle == *oracle) {
+            entry.registered = 0;
+        }
+    }
+}
\ No newline at end of file
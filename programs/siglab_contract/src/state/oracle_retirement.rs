@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// What happens to the policies and templates of an unregistered oracle
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum RetirementPlan {
+    /// Re-point them at this registered oracle
+    Replace(Pubkey),
+    /// Suspend the coverage of policies and deactivate templates until a
+    /// replacement is set
+    SuspendCoverage,
+}
+
+/// Record of an unregistered oracle, driving the `repoint_policies` crank
+/// over the policies and templates that still reference it
+#[account]
+#[derive(Debug)]
+pub struct OracleRetirement {
+    /// Oracle that was unregistered
+    pub oracle: Pubkey,
+
+    pub plan: RetirementPlan,
+
+    /// When the oracle was unregistered
+    pub retired_at: i64,
+
+    /// Policies moved to the replacement oracle so far
+    pub policies_repointed: u32,
+
+    /// Policies whose coverage was suspended so far, including ones since
+    /// re-pointed
+    pub policies_suspended: u32,
+
+    /// Templates re-pointed or deactivated so far
+    pub templates_affected: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl OracleRetirement {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // oracle
+        1 + 32 + // plan
+        8 + // retired_at
+        4 + // policies_repointed
+        4 + // policies_suspended
+        4 + // templates_affected
+        1 // bump
+    }
+
+    /// Oracle policies are re-pointed to, if one was set
+    pub fn replacement(&self) -> Option<Pubkey> {
+        match self.plan {
+            RetirementPlan::Replace(replacement) => Some(replacement),
+            RetirementPlan::SuspendCoverage => None,
+        }
+    }
+}
\ No newline at end of file
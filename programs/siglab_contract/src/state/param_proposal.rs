@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use super::policy::InsuranceType;
+use super::master_contract::{GlobalConfig, OracleRiskParams, RegionLimits};
+use super::type_config::TypeConfigParams;
+
+/// Parameter change applied through the timelock
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ParamChange {
+    /// Replace the parameters of one insurance type
+    TypeConfig {
+        insurance_type: InsuranceType,
+        params: TypeConfigParams,
+    },
+    
+    /// Replace the deployment's premium, coverage and duration limits
+    GlobalConfig(GlobalConfig),
+    
+    /// Replace the oracle circuit breaker threshold and health penalties
+    OracleRiskParams(OracleRiskParams),
+    
+    /// Replace the per-region exposure and daily payout caps
+    RegionLimits(RegionLimits),
+    
+    /// Permanently stop selling policies and let the existing ones run off
+    BeginWinddown,
+}
+
+impl ParamChange {
+    /// Size of the largest variant
+    pub const SPACE: usize = 1 + {
+        let type_config = 1 + TypeConfigParams::SPACE;
+        let global_config = GlobalConfig::SPACE;
+        if type_config > global_config { type_config } else { global_config }
+    };
+    
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ParamChange::TypeConfig { params, .. } => params.validate(),
+            ParamChange::GlobalConfig(global_config) => global_config.validate(),
+            ParamChange::OracleRiskParams(risk_params) => risk_params.validate(),
+            ParamChange::RegionLimits(region_limits) => region_limits.validate(),
+            ParamChange::BeginWinddown => Ok(()),
+        }
+    }
+}
+
+/// Parameter change proposed by the admin, executable once its timelock elapses
+#[account]
+#[derive(Debug)]
+pub struct ParamProposal {
+    /// Sequential proposal number, part of the PDA seeds
+    pub id: u64,
+    
+    pub change: ParamChange,
+    
+    pub proposer: Pubkey,
+    
+    pub proposed_at: i64,
+    
+    /// Earliest time the change may be executed
+    pub executable_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParamProposal {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // id
+        ParamChange::SPACE + // change
+        32 + // proposer
+        8 + // proposed_at
+        8 + // executable_at
+        1   // bump
+    }
+    
+    pub fn is_executable(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.executable_at
+    }
+}
\ No newline at end of file
@@ -1,20 +1,23 @@
 use anchor_lang::prelude::*;
 
-#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
-pub enum PayoutStatus {
-    Pending,
-    PendingApproval,
-    Ready,
-    Executed,
-    Rejected,
-    Expired,
+crate::stable_enum! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PayoutStatus {
+        /// Filed but not yet assessed against oracle data
+        Pending = 0,
+        PendingApproval = 1,
+        Ready = 2,
+        Executed = 3,
+        Rejected = 4,
+        Expired = 5,
+    }
 }
 
 #[account]
 #[derive(Debug)]
 pub struct PendingPayout {
     /// Policy ID this payout is for
-    pub policy_id: String,
+    pub policy_id: u64,
     
     /// Payout amount in lamports
     pub amount: u64,
@@ -22,7 +25,7 @@ pub struct PendingPayout {
     /// Timestamp when payout was triggered
     pub timestamp: i64,
     
-    /// Priority level for processing order
+    /// Priority level for processing order, including any persisted aging boost
     pub priority: u8,
     
     /// Current payout status
@@ -51,17 +54,32 @@ pub struct PendingPayout {
     
     /// Bump seed for PDA
     pub bump: u8,
+    
+    /// Oracle authorities whose co-signatures attested the trigger
+    pub quorum_signers: Vec<Pubkey>,
+    
+    /// Claimed time of the insured event the trigger evidence is bound to
+    pub incident_timestamp: i64,
+    
+    /// Priority assigned at trigger time, before aging
+    pub base_priority: u8,
+    
+    /// Account that paid the payout's rent and receives it back on close
+    pub rent_payer: Pubkey,
+    
+    /// Observation proof the trigger value was drawn from, referenced in place
+    /// of `trigger_oracle_data`
+    pub trigger_evidence: Option<Pubkey>,
 }
 
 impl PendingPayout {
-    pub const MAX_POLICY_ID_LENGTH: usize = 32;
     pub const MAX_ORACLE_DATA_LENGTH: usize = 256;
     pub const MAX_REJECTION_REASON_LENGTH: usize = 128;
     
     /// Calculate space required for PendingPayout account
     pub fn space() -> usize {
         8 + // discriminator
-        4 + Self::MAX_POLICY_ID_LENGTH + // policy_id (String)
+        8 + // policy_id
         8 + // amount
         8 + // timestamp
         1 + // priority
@@ -73,7 +91,71 @@ impl PendingPayout {
         1 + 32 + // approved_by (Option<Pubkey>)
         8 + // expires_at
         1 + 4 + Self::MAX_REJECTION_REASON_LENGTH + // rejection_reason (Option<String>)
-        1   // bump
+        1 + // bump
+        4 + crate::state::Policy::MAX_QUORUM_SIGNATURES as usize * 32 + // quorum_signers (Vec<Pubkey>)
+        8 + // incident_timestamp
+        1 + // base_priority
+        32 + // rent_payer
+        1 + 32 // trigger_evidence (Option<Pubkey>)
+    }
+    
+    /// Record the oracle data that triggered the payout, rejecting data that
+    /// would not fit the space reserved for it
+    pub fn set_trigger_oracle_data(&mut self, data: Vec<u8>) -> Result<()> {
+        require!(
+            data.len() <= Self::MAX_ORACLE_DATA_LENGTH,
+            crate::error::InsuranceError::EvidenceTooLarge
+        );
+        
+        self.trigger_oracle_data = data;
+        Ok(())
+    }
+    
+    /// Record the quorum that attested the trigger, rejecting more signers than
+    /// the space reserved for them holds
+    pub fn set_quorum_signers(&mut self, signers: Vec<Pubkey>) -> Result<()> {
+        require!(
+            signers.len() <= crate::state::Policy::MAX_QUORUM_SIGNATURES as usize,
+            crate::error::InsuranceError::EvidenceTooLarge
+        );
+        
+        self.quorum_signers = signers;
+        Ok(())
+    }
+    
+    /// The signers worth recording from an oversized attestation: the
+    /// `MAX_QUORUM_SIGNATURES` with the highest reputation, ties kept in
+    /// attestation order
+    pub fn most_reputable_signers(mut signers: Vec<(Pubkey, u8)>) -> Vec<Pubkey> {
+        signers.sort_by_key(|&(_, reputation)| std::cmp::Reverse(reputation));
+        signers.truncate(crate::state::Policy::MAX_QUORUM_SIGNATURES as usize);
+        signers.into_iter().map(|(signer, _)| signer).collect()
+    }
+    
+    /// Move the payout to `new_status`, rejecting transitions the lifecycle forbids
+    pub fn transition(&mut self, new_status: PayoutStatus) -> Result<()> {
+        require!(
+            siglab_core::is_allowed_payout_transition((&self.status).into(), (&new_status).into()),
+            crate::error::InsuranceError::InvalidStatusTransition
+        );
+        
+        self.status = new_status;
+        Ok(())
+    }
+    
+    /// Priority after aging: `base_priority` plus points per hour waited, capped
+    ///
+    /// Lets long-waiting low-priority payouts overtake a stream of fresh
+    /// high-priority ones.
+    pub fn effective_priority(&self, current_timestamp: i64) -> u8 {
+        use crate::constants::{MAX_PRIORITY_AGING_BOOST, PRIORITY_AGING_POINTS_PER_HOUR};
+        
+        let hours_waited = (current_timestamp - self.timestamp).max(0) / 3600;
+        let boost = std::cmp::min(
+            hours_waited.saturating_mul(PRIORITY_AGING_POINTS_PER_HOUR as i64),
+            MAX_PRIORITY_AGING_BOOST as i64,
+        ) as u8;
+        self.base_priority.saturating_add(boost)
     }
     
     /// Check if payout has expired
@@ -92,6 +174,112 @@ impl PendingPayout {
     }
 }
 
+impl From<&PayoutStatus> for siglab_core::PayoutState {
+    fn from(status: &PayoutStatus) -> Self {
+        match status {
+            PayoutStatus::Pending => Self::Pending,
+            PayoutStatus::PendingApproval => Self::PendingApproval,
+            PayoutStatus::Ready => Self::Ready,
+            PayoutStatus::Executed => Self::Executed,
+            PayoutStatus::Rejected => Self::Rejected,
+            PayoutStatus::Expired => Self::Expired,
+        }
+    }
+}
+
+/// An executed payout still being disbursed in tranches
+///
+/// Created from the `PendingPayout` of a policy with `payout_vesting` when it
+/// executes, and closed once its last tranche is released or an admin
+/// cancels it. The treasury keeps the unreleased amount reserved throughout.
+#[account]
+#[derive(Debug)]
+pub struct VestingPayout {
+    /// Policy ID this payout is for
+    pub policy_id: u64,
+    
+    /// Account receiving each tranche
+    pub beneficiary: Pubkey,
+    
+    /// Insurance type of the policy, for portfolio statistics
+    pub insurance_type: crate::state::InsuranceType,
+    
+    /// Currency the tranches are paid in
+    pub currency: crate::state::TokenType,
+    
+    /// Full payout amount across all tranches
+    pub total_amount: u64,
+    
+    /// Amount paid so far
+    pub released_amount: u64,
+    
+    /// Number of tranches
+    pub tranches: u8,
+    
+    /// Tranches paid so far, the first on execution
+    pub tranches_released: u8,
+    
+    /// Time between tranche releases
+    pub interval_secs: i64,
+    
+    /// When the first tranche was paid
+    pub started_at: i64,
+    
+    /// Earliest time the next tranche may be released
+    pub next_release_at: i64,
+    
+    /// Account that paid the rent and receives it back on close
+    pub rent_payer: Pubkey,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VestingPayout {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // policy_id
+        32 + // beneficiary
+        1 + // insurance_type
+        1 + // currency
+        8 + // total_amount
+        8 + // released_amount
+        1 + // tranches
+        1 + // tranches_released
+        8 + // interval_secs
+        8 + // started_at
+        8 + // next_release_at
+        32 + // rent_payer
+        1; // bump
+    
+    /// Amount of the next unreleased tranche, 0 once all are paid
+    pub fn next_tranche(&self) -> u64 {
+        siglab_core::tranche_amount(self.total_amount, self.tranches, self.tranches_released)
+    }
+    
+    /// Amount not yet paid, still reserved in the treasury
+    pub fn unreleased_amount(&self) -> u64 {
+        siglab_core::unreleased_amount(self.total_amount, self.tranches, self.tranches_released)
+    }
+    
+    /// Whether the next tranche may be released at `current_timestamp`
+    pub fn is_due(&self, current_timestamp: i64) -> bool {
+        self.tranches_released < self.tranches && current_timestamp >= self.next_release_at
+    }
+    
+    /// Record the next tranche as paid and schedule the one after it
+    pub fn record_release(&mut self, amount: u64) {
+        self.released_amount += amount;
+        self.tranches_released += 1;
+        self.next_release_at =
+            siglab_core::tranche_release_at(self.started_at, self.interval_secs, self.tranches_released);
+    }
+    
+    /// Whether every tranche has been paid
+    pub fn is_complete(&self) -> bool {
+        self.tranches_released >= self.tranches
+    }
+}
+
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct PayoutCalculationData {
     /// Base coverage amount
@@ -100,6 +288,9 @@ pub struct PayoutCalculationData {
     /// Deductible to subtract
     pub deductible: u64,
     
+    /// How the deductible reduces the claim
+    pub deductible_mode: crate::state::DeductibleMode,
+    
     /// Severity percentage (0-100)
     pub severity_percentage: u8,
     
@@ -113,24 +304,110 @@ pub struct PayoutCalculationData {
 impl PayoutCalculationData {
     /// Calculate final payout amount
     pub fn calculate_payout(&self) -> u64 {
-        // Start with coverage amount
-        let mut payout = self.coverage_amount;
-        
-        // Apply severity percentage
-        payout = (payout * self.severity_percentage as u64) / 100;
-        
-        // Subtract deductible
-        if payout > self.deductible {
-            payout -= self.deductible;
-        } else {
-            return 0; // Payout below deductible threshold
+        siglab_core::calculate_payout_with_mode(
+            self.deductible_mode.into(),
+            self.coverage_amount,
+            self.deductible,
+            self.severity_percentage,
+            self.max_payout,
+        )
+    }
+}
+
+/// Reason a claim would not be payable
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum PayoutIneligibility {
+    PolicyNotActive,
+    PolicyExpired,
+    WaitingPeriod,
+    TriggerNotMet,
+    GracePeriodNotElapsed,
+    BelowDeductible,
+    InstallmentsInArrears,
+    BelowMinimum,
+    /// The policy was bought while the oracle value it claims on was committed
+    CreatedDuringCommitment,
+    /// The policy's oracle was retired and no replacement set yet
+    CoverageSuspended,
+}
+
+impl PayoutIneligibility {
+    /// Error surfaced by `trigger_payout` for this reason
+    pub fn error(&self) -> crate::error::InsuranceError {
+        use crate::error::InsuranceError;
+        match self {
+            PayoutIneligibility::PolicyNotActive => InsuranceError::PolicyNotActive,
+            PayoutIneligibility::PolicyExpired => InsuranceError::PolicyExpired,
+            PayoutIneligibility::WaitingPeriod => InsuranceError::ClaimPeriodExpired,
+            PayoutIneligibility::TriggerNotMet => InsuranceError::PayoutConditionsNotMet,
+            PayoutIneligibility::GracePeriodNotElapsed => InsuranceError::GracePeriodNotElapsed,
+            PayoutIneligibility::BelowDeductible => InsuranceError::InvalidClaimAmount,
+            PayoutIneligibility::InstallmentsInArrears => InsuranceError::InstallmentsInArrears,
+            PayoutIneligibility::BelowMinimum => InsuranceError::PayoutBelowMinimum,
+            PayoutIneligibility::CreatedDuringCommitment => InsuranceError::PolicyCreatedDuringCommitment,
+            PayoutIneligibility::CoverageSuspended => InsuranceError::CoverageSuspended,
         }
-        
-        // Apply maximum payout limit
-        if payout > self.max_payout {
-            payout = self.max_payout;
+    }
+}
+
+/// Condition of the oracles a trigger reads that keeps it from being filed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OracleFault {
+    /// An oracle read has its circuit breaker tripped
+    CircuitBreaker,
+    /// An oracle read is inactive or below the reputation floor
+    Unhealthy,
+    /// An oracle has gone the consensus timeout without reporting under `HaltPayouts`
+    TimedOut,
+}
+
+impl OracleFault {
+    /// Error surfaced by `trigger_payout` for this fault
+    pub fn error(&self) -> crate::error::InsuranceError {
+        use crate::error::InsuranceError;
+        match self {
+            OracleFault::CircuitBreaker | OracleFault::Unhealthy => InsuranceError::InsufficientOracles,
+            OracleFault::TimedOut => InsuranceError::OracleConsensusFailure,
         }
-        
-        payout
+    }
+}
+
+/// Reason a filed claim was rejected at evaluation
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ClaimRejection {
+    /// No usable evidence arrived before the claim's evidence deadline
+    EvidenceDeadlinePassed,
+    /// The evidence did not make the claim payable
+    Ineligible(PayoutIneligibility),
+}
+
+/// Outcome of assessing a claim against a policy, shared by the payout and simulation paths
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct PayoutAssessment {
+    /// Payout amount in lamports (0 when not payable)
+    pub amount: u64,
+    
+    /// Severity percentage (0-100)
+    pub severity_percentage: u8,
+    
+    /// Whether admin approval would be required
+    pub requires_approval: bool,
+    
+    /// Why the claim is not payable, if it isn't
+    pub ineligibility: Option<PayoutIneligibility>,
+}
+
+impl PayoutAssessment {
+    pub fn ineligible(reason: PayoutIneligibility) -> Self {
+        Self {
+            amount: 0,
+            severity_percentage: 0,
+            requires_approval: false,
+            ineligibility: Some(reason),
+        }
+    }
+    
+    pub fn is_payable(&self) -> bool {
+        self.ineligibility.is_none()
     }
 }
\ No newline at end of file
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use super::master_contract::MasterInsuranceContract;
+use super::policy_history::PolicyHistory;
+use super::treasury::TokenType;
 
 #[account]
 #[derive(Debug)]
 pub struct Policy {
-    /// Unique policy identifier
-    pub id: String,
+    /// Unique policy identifier, assigned from the master contract's sequence
+    pub id: u64,
     
     /// Policyholder's public key
     pub user: Pubkey,
@@ -39,9 +42,6 @@ pub struct Policy {
     /// Last premium payment timestamp
     pub last_premium_paid: i64,
     
-    /// History of payouts made
-    pub payout_history: Vec<PayoutRecord>,
-    
     /// Risk assessment score (0-100)
     pub risk_assessment_score: u8,
     
@@ -57,33 +57,578 @@ pub struct Policy {
     /// Auto renewal enabled
     pub auto_renewal: bool,
     
-    /// Additional metadata as JSON string
+    /// Additional metadata as JSON string (max 256 bytes)
     pub metadata: String,
     
+    /// Optional URI for larger documents, e.g. an IPFS CID (max 64 bytes)
+    pub metadata_uri: Option<String>,
+    
+    /// Mint of the policy token; when set, the token holder is the beneficial owner
+    pub policy_mint: Option<Pubkey>,
+    
     /// Policy creation timestamp
     pub created_at: i64,
     
     /// Last update timestamp
     pub updated_at: i64,
+    
+    /// Original policyholder, part of the PDA seeds (unchanged by transfers)
+    pub creator: Pubkey,
+    
+    /// Master contract policy counter at creation, part of the PDA seeds
+    pub index: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    /// Oracle authorities that must co-sign a trigger (0 disables the quorum)
+    pub quorum_signatures: u8,
+    
+    /// How the deductible reduces a claim
+    pub deductible_mode: DeductibleMode,
+    
+    /// Premiums paid over the policy's life, net of refunds
+    pub total_premiums_paid: u64,
+    
+    /// Account that paid the policy's rent and receives it back on close
+    pub rent_payer: Pubkey,
+    
+    /// Premium installments generated at creation, if the policy is paid by schedule
+    pub installment_schedule: Option<InstallmentSchedule>,
+    
+    /// Installments paid in full
+    pub installments_paid: u16,
+    
+    /// Amount paid toward the earliest unpaid installment
+    pub installment_partial: u64,
+    
+    /// Due date whose late fee an admin waived
+    pub late_fee_waived_due: Option<i64>,
+    
+    /// Trigger-condition change proposed by the holder, awaiting approval
+    pub pending_trigger_amendment: Option<TriggerAmendment>,
+    
+    /// Currency premiums are paid in
+    pub premium_currency: TokenType,
+    
+    /// Currency payouts are made in
+    pub payout_currency: TokenType,
+    
+    /// Smallest severity a claim must reach to pay out, in basis points
+    pub min_severity_bps: Option<u16>,
+    
+    /// Tranches payouts are disbursed in, if not paid as one lump sum
+    pub payout_vesting: Option<PayoutVesting>,
+    
+    /// When coverage was suspended because the policy's oracle was
+    /// unregistered without a replacement; premiums and the term are paused
+    /// until the policy is re-pointed
+    pub coverage_suspended_at: Option<i64>,
+    
+    /// Underwriter or authority that co-signed the policy at creation
+    pub co_signer: Option<Pubkey>,
+    
+    /// Lamports the co-signer locked in the treasury, returned less any
+    /// slash once the policy ends
+    pub underwriting_bond: u64,
+    
+    /// Claims awarded, in the payout currency, including vesting tranches
+    /// not yet released
+    pub claims_paid: u64,
+    
+    /// Region the insured risk lies in, required for weather, earthquake and
+    /// crop policies
+    pub region_code: Option<[u8; 8]>,
+    
+    /// Whether the coverage is booked in the region's `RegionExposure`, which
+    /// must then accompany every change to it
+    pub region_tracked: bool,
+    
+    /// Whether the holder lets a keeper file payouts on their behalf
+    pub auto_claim: bool,
+    
+    /// Keeper allowed to file automatic claims (default for anyone)
+    pub claim_keeper: Pubkey,
+    
+    /// Seconds of the term the policy's oracle was observed unavailable
+    pub oracle_outage_secs: u64,
+    
+    /// Time the open outage has been accounted up to, while the oracle is
+    /// observed unavailable
+    pub oracle_outage_since: Option<i64>,
+    
+    /// Whether the premium for oracle downtime has been refunded
+    pub outage_refunded: bool,
+}
+
+impl Policy {
+    pub const MAX_DATA_SOURCE_LENGTH: usize = 64;
+    pub const MAX_DATA_FEED_ID_LENGTH: usize = 64;
+    pub const MAX_TRANSACTION_ID_LENGTH: usize = 64;
+    pub const MAX_PAYOUT_ORACLE_DATA_LENGTH: usize = 64;
+    pub const MAX_METADATA_LENGTH: usize = 256;
+    pub const MAX_METADATA_URI_LENGTH: usize = 64;
+    pub const MAX_QUORUM_SIGNATURES: u8 = 4;
+    
+    /// Calculate space required for Policy account
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // id
+        32 + // user
+        1 + // insurance_type
+        8 + // coverage_amount
+        8 + // premium_amount
+        8 + // deductible
+        8 + // start_date
+        8 + // end_date
+        1 + // status
+        TriggerConditions::space() + // trigger_conditions
+        32 + 4 + Self::MAX_DATA_FEED_ID_LENGTH + 1 + 8 + 1 + 2 + // oracle_config
+        8 + // last_premium_paid
+        1 + // risk_assessment_score
+        8 + // max_payout_per_incident
+        4 + // waiting_period_hours
+        1 + // premium_payment_frequency
+        1 + // auto_renewal
+        4 + Self::MAX_METADATA_LENGTH + // metadata (String)
+        1 + 4 + Self::MAX_METADATA_URI_LENGTH + // metadata_uri (Option<String>)
+        1 + 32 + // policy_mint (Option<Pubkey>)
+        8 + // created_at
+        8 + // updated_at
+        32 + // creator
+        8 + // index
+        1 + // bump
+        1 + // quorum_signatures
+        1 + // deductible_mode
+        8 + // total_premiums_paid
+        32 + // rent_payer
+        1 + InstallmentSchedule::SPACE + // installment_schedule (Option<InstallmentSchedule>)
+        2 + // installments_paid
+        8 + // installment_partial
+        1 + 8 + // late_fee_waived_due (Option<i64>)
+        1 + TriggerAmendment::space() + // pending_trigger_amendment (Option<TriggerAmendment>)
+        1 + // premium_currency
+        1 + // payout_currency
+        1 + 2 + // min_severity_bps (Option<u16>)
+        1 + PayoutVesting::SPACE + // payout_vesting (Option<PayoutVesting>)
+        1 + 8 + // coverage_suspended_at (Option<i64>)
+        1 + 32 + // co_signer (Option<Pubkey>)
+        8 + // underwriting_bond
+        8 + // claims_paid
+        1 + 8 + // region_code (Option<[u8; 8]>)
+        1 + // region_tracked
+        1 + // auto_claim
+        32 + // claim_keeper
+        8 + // oracle_outage_secs
+        1 + 8 + // oracle_outage_since (Option<i64>)
+        1 // outage_refunded
+    }
+    
+    /// Human-readable id, e.g. `POL-42`
+    pub fn display_id(&self) -> String {
+        format!("{}-{}", crate::constants::POLICY_ID_PREFIX, self.id)
+    }
+    
+    /// Id in the `POL-<created_at>-<index>` format policies were issued under
+    /// before numeric ids
+    pub fn legacy_id(&self) -> String {
+        format!("{}-{}-{}", crate::constants::POLICY_ID_PREFIX, self.created_at, self.index)
+    }
+    
+    /// Region the coverage is booked in, whose account must accompany any
+    /// instruction releasing it
+    pub fn booked_region(&self) -> Option<&[u8; 8]> {
+        self.region_code.as_ref().filter(|_| self.region_tracked)
+    }
+    
+    /// Whether `caller` may file a claim on the holder's behalf
+    pub fn accepts_claim_keeper(&self, caller: &Pubkey) -> bool {
+        self.auto_claim && (self.claim_keeper == Pubkey::default() || self.claim_keeper == *caller)
+    }
+    
+    /// Move the policy to `new_status`, rejecting transitions the lifecycle forbids
+    pub fn transition(&mut self, new_status: PolicyStatus, current_timestamp: i64) -> Result<()> {
+        require!(
+            siglab_core::is_allowed_transition((&self.status).into(), (&new_status).into()),
+            crate::error::InsuranceError::InvalidStatusTransition
+        );
+        
+        self.status = new_status;
+        self.updated_at = current_timestamp;
+        Ok(())
+    }
+    
+    /// Record a premium payment in the total and in the history's ledger
+    pub fn record_premium(&mut self, history: &mut PolicyHistory, amount: u64, current_timestamp: i64) -> Result<()> {
+        self.total_premiums_paid = self
+            .total_premiums_paid
+            .checked_add(amount)
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+        
+        history.record_premium(amount, current_timestamp);
+        Ok(())
+    }
+    
+    /// Refund owed for giving up `coverage_delta` of coverage: that share of the
+    /// unearned part of the `latest` premium payment, capped at the premiums paid
+    ///
+    /// A payment covers one payment period from when it was made, and is earned
+    /// evenly over that period.
+    pub fn refundable_premium(
+        &self,
+        latest: Option<&PremiumPayment>,
+        coverage_delta: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let Some(latest) = latest else {
+            return Ok(0);
+        };
+        let period = self.premium_payment_frequency.period_secs();
+        let unearned_secs = (latest.timestamp + period - current_timestamp).clamp(0, period) as u128;
+        
+        let refund = (latest.amount as u128)
+            .checked_mul(unearned_secs)
+            .and_then(|x| x.checked_mul(coverage_delta as u128))
+            .and_then(|x| x.checked_div((period as u128).checked_mul(self.coverage_amount as u128)?))
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+        
+        Ok(u64::try_from(refund).unwrap_or(u64::MAX).min(self.total_premiums_paid))
+    }
+    
+    /// Whether the policy's term is over at `current_timestamp`
+    pub fn has_ended(&self, current_timestamp: i64) -> bool {
+        self.end_date <= current_timestamp
+    }
+    
+    /// Whether claims are still held back by the waiting period after the start date
+    pub fn in_waiting_period(&self, current_timestamp: i64) -> bool {
+        current_timestamp - self.start_date < (self.waiting_period_hours as i64) * 3600
+    }
+    
+    /// Whole days until the policy ends, 0 once it has ended
+    pub fn days_remaining(&self, current_timestamp: i64) -> u32 {
+        let remaining = (self.end_date - current_timestamp).max(0) / 86400;
+        u32::try_from(remaining).unwrap_or(u32::MAX)
+    }
+    
+    /// When the next premium is due: the start date until the first payment,
+    /// then one payment period after the last, while that falls within the term
+    ///
+    /// Scheduled policies are due at their earliest unpaid installment.
+    pub fn premium_due_date(&self) -> Option<i64> {
+        if self.coverage_suspended_at.is_some() {
+            return None;
+        }
+        if let Some(schedule) = self.installment_schedule {
+            return match self.status {
+                PolicyStatus::PendingActivation | PolicyStatus::Active | PolicyStatus::PendingPayout => {
+                    (self.installments_paid < schedule.count).then(|| schedule.due_date(self.installments_paid))
+                }
+                _ => None,
+            };
+        }
+        match self.status {
+            PolicyStatus::PendingActivation => Some(self.start_date),
+            PolicyStatus::Active | PolicyStatus::PendingPayout => {
+                let due = self.last_premium_paid + self.premium_payment_frequency.period_secs();
+                (due < self.end_date).then_some(due)
+            }
+            _ => None,
+        }
+    }
+    
+    /// Account an observation of the policy's oracle, `unavailable` or not,
+    /// returning the seconds of downtime added
+    ///
+    /// Downtime accrues between consecutive observations of an outage, from
+    /// `outage_began` for the first, within the term. Seeing the oracle back
+    /// closes the outage without counting the time since it was last seen
+    /// down. Suspended coverage is made up by extending the term, so it
+    /// accrues nothing.
+    pub fn observe_oracle_outage(&mut self, unavailable: bool, outage_began: i64, current_timestamp: i64) -> u64 {
+        if !unavailable || self.coverage_suspended_at.is_some() {
+            self.oracle_outage_since = None;
+            return 0;
+        }
+        let since = self.oracle_outage_since.unwrap_or(outage_began).max(self.start_date);
+        let until = current_timestamp.min(self.end_date).max(since);
+        let added = (until - since) as u64;
+        self.oracle_outage_secs = self.oracle_outage_secs.saturating_add(added);
+        self.oracle_outage_since = Some(until);
+        added
+    }
+    
+    /// Pause coverage while the policy's oracle has no replacement
+    pub fn suspend_coverage(&mut self, current_timestamp: i64) {
+        if self.coverage_suspended_at.is_none() {
+            self.coverage_suspended_at = Some(current_timestamp);
+            self.updated_at = current_timestamp;
+        }
+    }
+    
+    /// Lift a coverage suspension, pushing the end date and premium due dates
+    /// back by its length so the holder loses none of the term paid for
+    ///
+    /// Returns the seconds the coverage was suspended.
+    pub fn resume_coverage(&mut self, current_timestamp: i64) -> i64 {
+        let Some(suspended_at) = self.coverage_suspended_at.take() else {
+            return 0;
+        };
+        let suspended_secs = (current_timestamp - suspended_at).max(0);
+        self.end_date = self.end_date.saturating_add(suspended_secs);
+        if self.last_premium_paid > 0 {
+            self.last_premium_paid = self.last_premium_paid.saturating_add(suspended_secs);
+        }
+        if let Some(schedule) = self.installment_schedule.as_mut() {
+            schedule.first_due = schedule.first_due.saturating_add(suspended_secs);
+        }
+        self.updated_at = current_timestamp;
+        suspended_secs
+    }
+    
+    /// Late fee owed at `current_timestamp` on the overdue premium of an
+    /// in-force policy, unless an admin waived it
+    ///
+    /// Time since the due date during which a pause blocked premium payments
+    /// does not count as overdue.
+    pub fn late_fee(&self, master_contract: &MasterInsuranceContract, current_timestamp: i64) -> u64 {
+        if !matches!(self.status, PolicyStatus::Active | PolicyStatus::PendingPayout) {
+            return 0;
+        }
+        match self.premium_due_date() {
+            Some(due) if self.late_fee_waived_due != Some(due) => {
+                let blocked = master_contract.premium_blocked_secs(due, current_timestamp);
+                siglab_core::late_fee(
+                    self.premium_amount,
+                    master_contract.global_config.late_fee_bps,
+                    due.saturating_add(blocked),
+                    current_timestamp,
+                )
+            }
+            _ => 0,
+        }
+    }
+    
+    /// Share of the coverage payable at `current_timestamp`, in basis points
+    ///
+    /// Holders behind on their installment schedule get coverage in proportion
+    /// to the installments paid, or none, per the schedule's arrears mode.
+    /// Time since the next installment fell due during which a pause blocked
+    /// premium payments does not count towards arrears, so an installment
+    /// falling due while blocked is not behind until payments reopen.
+    pub fn payable_coverage_bps(
+        &self,
+        master_contract: &MasterInsuranceContract,
+        current_timestamp: i64,
+    ) -> Option<u32> {
+        let Some(schedule) = self.installment_schedule else {
+            return Some(siglab_core::installments::FULL_COVERAGE_BPS);
+        };
+        let next_due = schedule.due_date(self.installments_paid);
+        let blocked = master_contract.premium_blocked_secs(next_due, current_timestamp);
+        let due = if blocked > 0 && current_timestamp - blocked <= next_due {
+            self.installments_paid
+        } else {
+            schedule.installments_due(current_timestamp - blocked)
+        };
+        match schedule.arrears_mode {
+            ArrearsMode::NoCoverageWhenBehind if self.installments_paid < due => None,
+            _ => Some(siglab_core::pro_rata_coverage_bps(self.installments_paid, due)),
+        }
+    }
+    
+    /// Premium owed for added coverage over the remaining term, priced at the
+    /// policy's current premium rate
+    pub fn pro_rata_premium(&self, coverage_delta: u64, current_timestamp: i64) -> Result<u64> {
+        let term = (self.end_date - self.start_date).max(1) as u128;
+        let remaining = (self.end_date - current_timestamp).clamp(0, self.end_date - self.start_date) as u128;
+        
+        let premium = (coverage_delta as u128)
+            .checked_mul(self.premium_amount as u128)
+            .and_then(|x| x.checked_mul(remaining))
+            .and_then(|x| x.checked_div((self.coverage_amount as u128).checked_mul(term)?))
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+        
+        u64::try_from(premium).map_err(|_| crate::error::InsuranceError::MathOverflow.into())
+    }
+    
+    /// Validate metadata fields against their reserved space
+    pub fn validate_metadata(metadata: &str, metadata_uri: &Option<String>) -> Result<()> {
+        require!(
+            metadata.len() <= Self::MAX_METADATA_LENGTH,
+            crate::error::InsuranceError::MetadataTooLong
+        );
+        
+        if let Some(uri) = metadata_uri {
+            require!(
+                uri.len() <= Self::MAX_METADATA_URI_LENGTH,
+                crate::error::InsuranceError::MetadataTooLong
+            );
+        }
+        
+        Ok(())
+    }
 }
 
 // Forward declarations - will be implemented in following subtasks
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
-pub enum InsuranceType {
-    Weather,
-    Earthquake,
-    Flight,
-    Crop,
-    Custom,
+crate::stable_enum! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum InsuranceType {
+        Weather = 0,
+        Earthquake = 1,
+        Flight = 2,
+        Crop = 3,
+        Custom = 4,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
-pub enum PolicyStatus {
-    Active,
-    Expired,
-    Cancelled,
-    PendingPayout,
-    PaidOut,
+impl InsuranceType {
+    pub const COUNT: usize = 5;
+    
+    /// Every type, in `index` order
+    pub const ALL: [InsuranceType; Self::COUNT] = [
+        InsuranceType::Weather,
+        InsuranceType::Earthquake,
+        InsuranceType::Flight,
+        InsuranceType::Crop,
+        InsuranceType::Custom,
+    ];
+    
+    /// Position of this type in per-type configuration tables
+    pub fn index(&self) -> usize {
+        match self {
+            InsuranceType::Weather => 0,
+            InsuranceType::Earthquake => 1,
+            InsuranceType::Flight => 2,
+            InsuranceType::Crop => 3,
+            InsuranceType::Custom => 4,
+        }
+    }
+    
+    /// Seed of the type's `InsuranceTypeConfig` PDA
+    pub fn seed(&self) -> [u8; 1] {
+        [self.index() as u8]
+    }
+    
+    /// Whether policies of this type insure a place and must name its region
+    pub fn requires_region(&self) -> bool {
+        matches!(self, InsuranceType::Weather | InsuranceType::Earthquake | InsuranceType::Crop)
+    }
+}
+
+crate::stable_enum! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PolicyStatus {
+        Active = 0,
+        Expired = 1,
+        Cancelled = 2,
+        PendingPayout = 3,
+        PaidOut = 4,
+        PendingActivation = 5,
+    }
+}
+
+/// How the deductible reduces a claim; see `siglab_core::DeductibleMode`
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum DeductibleMode {
+    /// Scale the coverage by severity, then subtract the deductible
+    SubtractAfterScaling,
+    /// Subtract the deductible from the coverage, then scale by severity
+    SubtractBeforeScaling,
+    /// Pay the full scaled amount once it exceeds the deductible, else nothing
+    Franchise,
+}
+
+/// How claims are paid while the holder is behind on installments
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ArrearsMode {
+    /// Scale the coverage by installments paid over installments due
+    ProRataCoverage,
+    /// Block claims until the holder catches up
+    NoCoverageWhenBehind,
+}
+
+/// Premium installments of a policy paid by schedule
+///
+/// Installment `i` is due `i` payment periods after the first.
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct InstallmentSchedule {
+    /// Number of installments covering the term
+    pub count: u16,
+    
+    /// Amount of each installment in lamports
+    pub amount: u64,
+    
+    /// Due date of the first installment
+    pub first_due: i64,
+    
+    /// Time between due dates, from the policy's payment frequency
+    pub period_secs: i64,
+    
+    /// How claims are paid while the holder is behind
+    pub arrears_mode: ArrearsMode,
+}
+
+impl InstallmentSchedule {
+    pub const SPACE: usize = 2 + 8 + 8 + 8 + 1;
+    
+    /// Schedule of `amount` installments covering `start_date..end_date` at `frequency`
+    pub fn new(
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+        frequency: &PremiumFrequency,
+        arrears_mode: ArrearsMode,
+    ) -> Self {
+        let period_secs = frequency.period_secs();
+        Self {
+            count: siglab_core::installments::installment_count(end_date - start_date, period_secs),
+            amount,
+            first_due: start_date,
+            period_secs,
+            arrears_mode,
+        }
+    }
+    
+    /// Due date of installment `index`
+    pub fn due_date(&self, index: u16) -> i64 {
+        self.first_due + index as i64 * self.period_secs
+    }
+    
+    /// Installments due by `current_timestamp`
+    pub fn installments_due(&self, current_timestamp: i64) -> u16 {
+        siglab_core::installments_due(self.first_due, self.period_secs, self.count, current_timestamp)
+    }
+}
+
+/// Schedule a policy's payouts are disbursed on
+///
+/// Executing a payout pays the first tranche; the rest are released one
+/// `interval_secs` apart.
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct PayoutVesting {
+    /// Number of tranches, at least 2
+    pub tranches: u8,
+    
+    /// Time between tranche releases
+    pub interval_secs: i64,
+}
+
+impl PayoutVesting {
+    pub const SPACE: usize = 1 + 8;
+    
+    /// Reject schedules outside `2..=MAX_PAYOUT_TRANCHES` tranches or with an
+    /// interval outside `1..=MAX_VESTING_INTERVAL`
+    pub fn validate(&self) -> Result<()> {
+        use crate::constants::{MAX_PAYOUT_TRANCHES, MAX_VESTING_INTERVAL};
+        
+        require!(
+            (2..=MAX_PAYOUT_TRANCHES).contains(&self.tranches)
+                && (1..=MAX_VESTING_INTERVAL).contains(&self.interval_secs),
+            crate::error::InsuranceError::InvalidParameters
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -93,12 +638,123 @@ pub enum PremiumFrequency {
     Annual,
 }
 
+impl PremiumFrequency {
+    /// Length of one payment period in seconds
+    pub fn period_secs(&self) -> i64 {
+        const DAY: i64 = 86400;
+        match self {
+            PremiumFrequency::Monthly => 30 * DAY,
+            PremiumFrequency::Quarterly => 91 * DAY,
+            PremiumFrequency::Annual => 365 * DAY,
+        }
+    }
+}
+
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct TriggerConditions {
     pub threshold_value: f64,
     pub comparison_operator: ComparisonOperator,
     pub data_source: String,
     pub grace_period: i64,
+    /// Multi-feed expression; when set, it replaces the single threshold above
+    pub compound: Option<CompoundTrigger>,
+}
+
+impl TriggerConditions {
+    /// Serialized size at maximum capacity
+    pub fn space() -> usize {
+        8 + 1 + 4 + Policy::MAX_DATA_SOURCE_LENGTH + 8 + // threshold, operator, data_source, grace_period
+        1 + CompoundTrigger::space() // compound
+    }
+    
+    /// Validate variable-length fields against reserved space
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.data_source.len() <= Policy::MAX_DATA_SOURCE_LENGTH,
+            crate::error::InsuranceError::InvalidParameters
+        );
+        if let Some(ref compound) = self.compound {
+            compound.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Holder's proposed replacement for a policy's trigger conditions
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TriggerAmendment {
+    pub conditions: TriggerConditions,
+    /// Most the holder agrees to pay if the amendment re-prices upward
+    pub max_additional_premium: u64,
+    pub proposed_at: i64,
+}
+
+impl TriggerAmendment {
+    pub fn space() -> usize {
+        TriggerConditions::space() + 8 + 8
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CompoundTrigger {
+    /// How leaf results are combined
+    pub connective: TriggerConnective,
+    /// Individual feed conditions (max 4)
+    pub leaves: Vec<TriggerLeaf>,
+    /// How severity is derived from the satisfied leaves
+    pub severity_mode: SeverityMode,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TriggerLeaf {
+    /// Oracle data feed address this condition reads
+    pub feed_id: String,
+    pub operator: ComparisonOperator,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum TriggerConnective {
+    All,
+    Any,
+}
+
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum SeverityMode {
+    Worst,
+    Average,
+}
+
+impl CompoundTrigger {
+    pub const MAX_LEAVES: usize = 4;
+    
+    /// Serialized size of a CompoundTrigger at maximum capacity
+    pub fn space() -> usize {
+        1 + // connective
+        4 + Self::MAX_LEAVES * (4 + Policy::MAX_DATA_FEED_ID_LENGTH + 1 + 8) + // leaves
+        1   // severity_mode
+    }
+    
+    /// Validate the expression structure at policy creation
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.leaves.is_empty() && self.leaves.len() <= Self::MAX_LEAVES,
+            crate::error::InsuranceError::InvalidParameters
+        );
+        
+        for leaf in &self.leaves {
+            require!(
+                !leaf.feed_id.is_empty() && leaf.feed_id.len() <= Policy::MAX_DATA_FEED_ID_LENGTH,
+                crate::error::InsuranceError::InvalidParameters
+            );
+            require!(
+                leaf.threshold.is_finite() && leaf.threshold > 0.0,
+                crate::error::InsuranceError::InvalidParameters
+            );
+        }
+        
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -107,14 +763,43 @@ pub struct OracleConfig {
     pub data_feed_id: String,
     pub required_confirmations: u8,
     pub staleness_threshold: i64,
+    /// Widest confidence interval accepted as trigger evidence, in basis
+    /// points of the reported value (0 for no limit); `None` at creation
+    /// takes the insurance type's limit
+    pub max_confidence_ratio_bps: Option<u16>,
 }
 
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
-pub enum ComparisonOperator {
-    GreaterThan,
-    LessThan,
-    Equals,
-    NotEquals,
+impl OracleConfig {
+    /// Whether a reading of `value` with `confidence` is precise enough to
+    /// evaluate this policy's trigger
+    pub fn accepts_confidence(&self, value: u64, confidence: u64) -> bool {
+        siglab_core::confidence_within(value, confidence, self.max_confidence_ratio_bps.unwrap_or(0))
+    }
+}
+
+crate::stable_enum! {
+    #[derive(Debug, Clone)]
+    pub enum ComparisonOperator {
+        GreaterThan = 0,
+        LessThan = 1,
+        Equals = 2,
+        NotEquals = 3,
+    }
+}
+
+impl ComparisonOperator {
+    /// Every operator's bit, see [`Self::bit`]
+    pub const ALL: u8 = 0b1111;
+    
+    /// Bit of this operator in an insurance type's allowed operators
+    pub fn bit(&self) -> u8 {
+        match self {
+            ComparisonOperator::GreaterThan => 1 << 0,
+            ComparisonOperator::LessThan => 1 << 1,
+            ComparisonOperator::Equals => 1 << 2,
+            ComparisonOperator::NotEquals => 1 << 3,
+        }
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -123,4 +808,132 @@ pub struct PayoutRecord {
     pub timestamp: i64,
     pub transaction_id: String,
     pub oracle_data: String,
+    /// Claimed time of the incident the payout was filed for
+    pub incident_timestamp: i64,
+    /// `hash(feed_id || incident bucket)` identifying the incident
+    pub incident_fingerprint: [u8; 32],
+    /// Lamports spent on the beneficiary's account when the payout executed:
+    /// the rent-exempt deposit taken from a SOL payout, or the rent of the
+    /// token account created for a USDC payout
+    pub destination_rent: u64,
+    /// Part of the payout the beneficiary can spend, once it executes: the
+    /// amount less the destination rent, at its USDC value for USDC payouts
+    pub net_amount: u64,
+}
+
+/// Kind of mid-term change recorded by an endorsement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum EndorsementKind {
+    /// `before`/`after` hold the coverage amount, little-endian
+    CoverageAdjustment,
+    /// `before`/`after` hold the owner's public key
+    OwnershipTransfer,
+    /// `before`/`after` hold the SHA-256 hash of the serialized trigger conditions
+    TriggerAmendment,
+    /// `before`/`after` hold the oracle's public key
+    OracleRepoint,
+}
+
+/// Mid-term change to a policy, retained until renewal
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct Endorsement {
+    pub kind: EndorsementKind,
+    /// Changed value before the endorsement, encoded per `kind`
+    pub before: [u8; 32],
+    /// Changed value after the endorsement, encoded per `kind`
+    pub after: [u8; 32],
+    /// Key that made the change
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+impl Endorsement {
+    pub const SPACE: usize = 1 + 32 + 32 + 32 + 8;
+    
+    /// Compact encoding of an amount for `before`/`after`
+    pub fn encode_amount(amount: u64) -> [u8; 32] {
+        let mut encoded = [0u8; 32];
+        encoded[..8].copy_from_slice(&amount.to_le_bytes());
+        encoded
+    }
+}
+
+/// Premium payment retained in the policy's ledger
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct PremiumPayment {
+    pub timestamp: i64,
+    pub amount: u64,
+}
+
+impl PremiumPayment {
+    pub fn space() -> usize {
+        8 + // timestamp
+        8 // amount
+    }
+}
+
+impl PayoutRecord {
+    /// Calculate serialized size of a PayoutRecord
+    pub fn space() -> usize {
+        8 + // amount
+        8 + // timestamp
+        4 + Policy::MAX_TRANSACTION_ID_LENGTH + // transaction_id (String)
+        4 + Policy::MAX_PAYOUT_ORACLE_DATA_LENGTH + // oracle_data (String)
+        8 + // incident_timestamp
+        32 + // incident_fingerprint
+        8 + // destination_rent
+        8 // net_amount
+    }
+}
+
+impl From<&PolicyStatus> for siglab_core::PolicyState {
+    fn from(status: &PolicyStatus) -> Self {
+        match status {
+            PolicyStatus::Active => Self::Active,
+            PolicyStatus::Expired => Self::Expired,
+            PolicyStatus::Cancelled => Self::Cancelled,
+            PolicyStatus::PendingPayout => Self::PendingPayout,
+            PolicyStatus::PaidOut => Self::PaidOut,
+            PolicyStatus::PendingActivation => Self::PendingActivation,
+        }
+    }
+}
+
+impl From<DeductibleMode> for siglab_core::DeductibleMode {
+    fn from(mode: DeductibleMode) -> Self {
+        match mode {
+            DeductibleMode::SubtractAfterScaling => Self::SubtractAfterScaling,
+            DeductibleMode::SubtractBeforeScaling => Self::SubtractBeforeScaling,
+            DeductibleMode::Franchise => Self::Franchise,
+        }
+    }
+}
+
+impl From<&ComparisonOperator> for siglab_core::Comparison {
+    fn from(operator: &ComparisonOperator) -> Self {
+        match operator {
+            ComparisonOperator::GreaterThan => Self::GreaterThan,
+            ComparisonOperator::LessThan => Self::LessThan,
+            ComparisonOperator::Equals => Self::Equals,
+            ComparisonOperator::NotEquals => Self::NotEquals,
+        }
+    }
+}
+
+impl From<&TriggerConnective> for siglab_core::Connective {
+    fn from(connective: &TriggerConnective) -> Self {
+        match connective {
+            TriggerConnective::All => Self::All,
+            TriggerConnective::Any => Self::Any,
+        }
+    }
+}
+
+impl From<&SeverityMode> for siglab_core::SeverityMode {
+    fn from(mode: &SeverityMode) -> Self {
+        match mode {
+            SeverityMode::Worst => Self::Worst,
+            SeverityMode::Average => Self::Average,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use super::policy::{Endorsement, EndorsementKind, PayoutRecord, PremiumPayment};
+
+/// Append-only records of a policy, kept out of `Policy` so instructions that
+/// only read its terms neither load nor pay rent for them
+///
+/// Opened by the policy's first premium payment, or by whichever record is
+/// written first.
+#[account]
+#[derive(Debug)]
+pub struct PolicyHistory {
+    /// Policy the records belong to
+    pub policy: Pubkey,
+
+    /// History of payouts made
+    pub payout_history: Vec<PayoutRecord>,
+
+    /// Most recent premium payments, oldest first
+    pub premium_history: Vec<PremiumPayment>,
+
+    /// Mid-term changes made since issue, oldest first
+    pub endorsements: Vec<Endorsement>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PolicyHistory {
+    pub const MAX_PAYOUT_HISTORY: usize = 4;
+    pub const MAX_PREMIUM_HISTORY: usize = 12;
+    pub const MAX_ENDORSEMENTS: usize = 8;
+
+    /// Calculate space required for PolicyHistory account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // policy
+        4 + Self::MAX_PAYOUT_HISTORY * PayoutRecord::space() + // payout_history (Vec<PayoutRecord>)
+        4 + Self::MAX_PREMIUM_HISTORY * PremiumPayment::space() + // premium_history (Vec<PremiumPayment>)
+        4 + Self::MAX_ENDORSEMENTS * Endorsement::SPACE + // endorsements (Vec<Endorsement>)
+        1 // bump
+    }
+
+    /// Bind a history opened for `policy`; a no-op on one already bound
+    pub fn open(&mut self, policy: Pubkey, bump: u8) {
+        self.policy = policy;
+        self.bump = bump;
+    }
+
+    /// Whether an incident was already filed with `fingerprint` or within `min_gap` of `incident_timestamp`
+    pub fn repeats_incident(&self, fingerprint: &[u8; 32], incident_timestamp: i64, min_gap: i64) -> bool {
+        self.payout_history.iter().any(|record| {
+            record.incident_fingerprint == *fingerprint
+                || siglab_core::repeats_incident(incident_timestamp, record.incident_timestamp, min_gap)
+        })
+    }
+
+    /// Append a filed payout, dropping the oldest once the history is full
+    pub fn record_payout(&mut self, record: PayoutRecord) {
+        if self.payout_history.len() >= Self::MAX_PAYOUT_HISTORY {
+            self.payout_history.remove(0);
+        }
+        self.payout_history.push(record);
+    }
+
+    /// Note the destination rent and net amount of the payout filed last,
+    /// once it executes
+    pub fn record_destination(&mut self, destination_rent: u64, net_amount: u64) {
+        if let Some(record) = self.payout_history.last_mut() {
+            record.destination_rent = destination_rent;
+            record.net_amount = net_amount;
+        }
+    }
+
+    /// Append a premium payment to the ledger, dropping the oldest entry once
+    /// the history is full
+    pub fn record_premium(&mut self, amount: u64, current_timestamp: i64) {
+        if self.premium_history.len() >= Self::MAX_PREMIUM_HISTORY {
+            self.premium_history.remove(0);
+        }
+        self.premium_history.push(PremiumPayment {
+            timestamp: current_timestamp,
+            amount,
+        });
+    }
+
+    /// Latest premium payment, if any
+    pub fn latest_premium(&self) -> Option<&PremiumPayment> {
+        self.premium_history.last()
+    }
+
+    /// Whether another endorsement still fits
+    pub fn can_endorse(&self) -> bool {
+        self.endorsements.len() < Self::MAX_ENDORSEMENTS
+    }
+
+    /// Record a mid-term change, rejecting it once the policy holds
+    /// `MAX_ENDORSEMENTS` of them
+    pub fn endorse(
+        &mut self,
+        kind: EndorsementKind,
+        before: [u8; 32],
+        after: [u8; 32],
+        actor: Pubkey,
+        current_timestamp: i64,
+    ) -> Result<Endorsement> {
+        require!(self.can_endorse(), crate::error::InsuranceError::EndorsementLimitReached);
+
+        let endorsement = Endorsement {
+            kind,
+            before,
+            after,
+            actor,
+            timestamp: current_timestamp,
+        };
+        self.endorsements.push(endorsement.clone());
+        Ok(endorsement)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use super::policy::InsuranceType;
+
+/// Minimal on-chain metadata for a tokenized policy
+#[account]
+#[derive(Debug)]
+pub struct PolicyTokenMetadata {
+    /// Policy account represented by the token
+    pub policy: Pubkey,
+
+    /// Single-supply mint representing the policy
+    pub mint: Pubkey,
+
+    /// Numeric policy identifier
+    pub policy_id: u64,
+
+    /// Type of insurance covered
+    pub insurance_type: InsuranceType,
+
+    /// Coverage amount in lamports
+    pub coverage_amount: u64,
+
+    /// Coverage start date (Unix timestamp)
+    pub start_date: i64,
+
+    /// Coverage end date (Unix timestamp)
+    pub end_date: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PolicyTokenMetadata {
+    /// Calculate space required for PolicyTokenMetadata account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // policy
+        32 + // mint
+        8 + // policy_id
+        1 + // insurance_type
+        8 + // coverage_amount
+        8 + // start_date
+        8 + // end_date
+        1   // bump
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Pending proposal to reassign a policy to a new owner
+#[account]
+#[derive(Debug)]
+pub struct PolicyTransferProposal {
+    /// Policy being transferred
+    pub policy: Pubkey,
+
+    /// Current owner who proposed the transfer
+    pub proposer: Pubkey,
+
+    /// Proposed new owner who must accept
+    pub new_owner: Pubkey,
+
+    /// Proposal creation timestamp
+    pub proposed_at: i64,
+
+    /// Timestamp after which the proposal can no longer be accepted
+    pub expires_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PolicyTransferProposal {
+    /// Calculate space required for PolicyTransferProposal account
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // policy
+        32 + // proposer
+        32 + // new_owner
+        8 + // proposed_at
+        8 + // expires_at
+        1   // bump
+    }
+
+    /// Check if the proposal has expired
+    pub fn is_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp > self.expires_at
+    }
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::error::InsuranceError;
+use crate::constants::MAX_REPORT_REGIONS;
+use super::policy::InsuranceType;
+use super::region::RegionReport;
+
+/// Running underwriting totals for one insurance type, kept on the master contract
+///
+/// Increments use checked math. Decrements saturate instead: policies written
+/// before the totals were kept were never added, and must still be able to
+/// take refunds and end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct TypeStats {
+    /// Premiums collected, net of refunds
+    pub premiums_collected: u64,
+    
+    /// Payouts disbursed to beneficiaries
+    pub payouts_disbursed: u64,
+    
+    /// Number of executed payouts
+    pub payouts_executed: u64,
+    
+    /// Sum of the severity scores of executed payouts
+    pub severity_sum: u64,
+    
+    /// Number of payouts triggered or claims filed
+    pub claims_filed: u64,
+    
+    /// Number of policies activated by their first premium
+    pub policies_activated: u64,
+    
+    /// Coverage of policies currently in force
+    pub active_exposure: u64,
+}
+
+impl TypeStats {
+    pub const SPACE: usize = 7 * 8;
+    
+    pub fn record_premium(&mut self, amount: u64) -> Result<()> {
+        self.premiums_collected = checked_add(self.premiums_collected, amount)?;
+        Ok(())
+    }
+    
+    pub fn record_refund(&mut self, amount: u64) {
+        self.premiums_collected = self.premiums_collected.saturating_sub(amount);
+    }
+    
+    /// Count a newly activated policy and put its coverage in force
+    pub fn record_activation(&mut self, coverage: u64) -> Result<()> {
+        self.policies_activated = checked_add(self.policies_activated, 1)?;
+        self.add_exposure(coverage)
+    }
+    
+    pub fn add_exposure(&mut self, coverage: u64) -> Result<()> {
+        self.active_exposure = checked_add(self.active_exposure, coverage)?;
+        Ok(())
+    }
+    
+    /// Take coverage out of force
+    pub fn release_exposure(&mut self, coverage: u64) {
+        self.active_exposure = self.active_exposure.saturating_sub(coverage);
+    }
+    
+    pub fn record_claim(&mut self) -> Result<()> {
+        self.claims_filed = checked_add(self.claims_filed, 1)?;
+        Ok(())
+    }
+    
+    /// Count an executed payout, taking the coverage it settled out of force
+    pub fn record_payout(&mut self, amount: u64, severity: u8, coverage: u64) -> Result<()> {
+        self.payouts_disbursed = checked_add(self.payouts_disbursed, amount)?;
+        self.payouts_executed = checked_add(self.payouts_executed, 1)?;
+        self.severity_sum = checked_add(self.severity_sum, severity as u64)?;
+        self.release_exposure(coverage);
+        Ok(())
+    }
+    
+    /// Add a later tranche of a vesting payout to the amount disbursed
+    pub fn record_tranche(&mut self, amount: u64) -> Result<()> {
+        self.payouts_disbursed = checked_add(self.payouts_disbursed, amount)?;
+        Ok(())
+    }
+}
+
+fn checked_add(total: u64, amount: u64) -> Result<u64> {
+    total.checked_add(amount).ok_or(InsuranceError::MathOverflow.into())
+}
+
+/// Totals and derived ratios of one insurance type at report time
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct TypeReport {
+    pub insurance_type: InsuranceType,
+    
+    pub stats: TypeStats,
+    
+    /// Payouts disbursed per premium collected, in basis points
+    pub loss_ratio_bps: u32,
+    
+    /// Mean severity (0-100) of executed payouts
+    pub average_severity: u8,
+    
+    /// Claims filed per policy activated, in basis points
+    pub claim_frequency_bps: u32,
+}
+
+impl TypeReport {
+    pub const SPACE: usize = 1 + TypeStats::SPACE + 4 + 1 + 4;
+    
+    pub fn new(insurance_type: InsuranceType, stats: TypeStats) -> Self {
+        Self {
+            insurance_type,
+            loss_ratio_bps: siglab_core::loss_ratio_bps(stats.payouts_disbursed, stats.premiums_collected),
+            average_severity: siglab_core::average_severity(stats.severity_sum, stats.payouts_executed),
+            claim_frequency_bps: siglab_core::claim_frequency_bps(stats.claims_filed, stats.policies_activated),
+            stats,
+        }
+    }
+}
+
+/// Published snapshot of the portfolio, one account per report
+#[account]
+#[derive(Debug)]
+pub struct PortfolioReport {
+    /// Report number, part of the PDA seeds
+    pub report_index: u64,
+    
+    /// Who published the report and paid its rent
+    pub publisher: Pubkey,
+    
+    /// One entry per insurance type, in `InsuranceType::index` order
+    pub types: Vec<TypeReport>,
+    
+    /// Pending payouts open when the snapshot was taken
+    pub open_payout_count: u64,
+    
+    /// Exposure of the regions the publisher chose to report, up to `MAX_REPORT_REGIONS`
+    pub regions: Vec<RegionReport>,
+    
+    /// When the snapshot was taken
+    pub timestamp: i64,
+    
+    /// Sequence of the event announcing the report
+    pub sequence: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PortfolioReport {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // report_index
+        32 + // publisher
+        4 + InsuranceType::COUNT * TypeReport::SPACE + // types
+        8 + // open_payout_count
+        4 + MAX_REPORT_REGIONS * RegionReport::SPACE + // regions
+        8 + // timestamp
+        8 + // sequence
+        1 // bump
+    }
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use crate::error::InsuranceError;
+use super::policy::{
+    ComparisonOperator, DeductibleMode, InsuranceType, OracleConfig, Policy, PremiumFrequency, TriggerConditions,
+};
+use super::treasury::TokenType;
+
+/// Standard insurance product that policies are issued from
+///
+/// Each version of a product is its own account. Policies copy their terms
+/// on creation, so publishing a new version or deactivating one leaves the
+/// policies already issued untouched.
+#[account]
+#[derive(Debug)]
+pub struct ProductTemplate {
+    /// Admin-chosen product identifier, part of the PDA seeds
+    pub product_id: u32,
+
+    /// Version of the product, part of the PDA seeds
+    pub version: u16,
+
+    pub terms: ProductTerms,
+
+    /// Whether new policies may be issued from this version
+    pub active: bool,
+
+    /// Policies issued from this version so far
+    pub policies_issued: u64,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Last time the template was activated or deactivated
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// Everything a template fixes about its policies, and the ranges of the
+/// threshold, coverage and duration each holder chooses
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ProductTerms {
+    pub insurance_type: InsuranceType,
+
+    /// Trigger of every policy, apart from its threshold
+    pub comparison_operator: ComparisonOperator,
+    pub data_source: String,
+    pub grace_period: i64,
+
+    /// Trigger thresholds a holder may choose, inclusive
+    pub min_threshold: f64,
+    pub max_threshold: f64,
+
+    /// Feed policies settle against; the oracle must be registered
+    pub oracle_config: OracleConfig,
+
+    pub pricing: PricingCurve,
+
+    /// Coverage a holder may choose, inclusive, in the payout currency
+    pub min_coverage: u64,
+    pub max_coverage: u64,
+
+    /// Policy terms a holder may choose, inclusive
+    pub min_duration_days: u32,
+    pub max_duration_days: u32,
+
+    /// Deductible as a share of the coverage (basis points: 500 = 5%)
+    pub deductible_bps: u16,
+    pub deductible_mode: DeductibleMode,
+
+    pub waiting_period_hours: u32,
+    pub premium_payment_frequency: PremiumFrequency,
+
+    /// Premiums are charged in the payout currency, so no price is needed
+    /// to quote them
+    pub currency: TokenType,
+
+    pub risk_assessment_score: u8,
+}
+
+/// Annual premium rate across a template's threshold range, linear between
+/// its ends, with a floor on each premium
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct PricingCurve {
+    /// Annual rate at `min_threshold` (basis points of the coverage)
+    pub rate_at_min_threshold_bps: u16,
+
+    /// Annual rate at `max_threshold` (basis points of the coverage)
+    pub rate_at_max_threshold_bps: u16,
+
+    /// Smallest premium charged, in the template's currency
+    pub minimum_premium: u64,
+}
+
+impl PricingCurve {
+    pub const SPACE: usize = 2 + 2 + 8;
+}
+
+impl ProductTemplate {
+    pub fn space() -> usize {
+        8 + // discriminator
+        4 + // product_id
+        2 + // version
+        ProductTerms::space() + // terms
+        1 + // active
+        8 + // policies_issued
+        8 + // created_at
+        8 + // updated_at
+        1 // bump
+    }
+}
+
+impl ProductTerms {
+    pub const MAX_RATE_BPS: u16 = 10_000;
+
+    /// Serialized size at maximum capacity
+    pub fn space() -> usize {
+        1 + // insurance_type
+        1 + 4 + Policy::MAX_DATA_SOURCE_LENGTH + 8 + // comparison_operator, data_source, grace_period
+        8 + 8 + // min_threshold, max_threshold
+        32 + 4 + Policy::MAX_DATA_FEED_ID_LENGTH + 1 + 8 + 1 + 2 + // oracle_config
+        PricingCurve::SPACE + // pricing
+        8 + 8 + // min_coverage, max_coverage
+        4 + 4 + // min_duration_days, max_duration_days
+        2 + 1 + // deductible_bps, deductible_mode
+        4 + 1 + // waiting_period_hours, premium_payment_frequency
+        1 + // currency
+        1 // risk_assessment_score
+    }
+
+    /// Check the terms are self-consistent and fit the reserved space
+    pub fn validate(&self, max_policy_duration_days: u32) -> Result<()> {
+        require!(
+            self.min_threshold.is_finite()
+                && self.max_threshold.is_finite()
+                && self.min_threshold <= self.max_threshold,
+            InsuranceError::InvalidParameters
+        );
+        require!(
+            self.data_source.len() <= Policy::MAX_DATA_SOURCE_LENGTH
+                && self.oracle_config.data_feed_id.len() <= Policy::MAX_DATA_FEED_ID_LENGTH,
+            InsuranceError::InvalidParameters
+        );
+        require!(
+            self.oracle_config.max_confidence_ratio_bps.unwrap_or(0) <= siglab_core::reserves::FULL_RESERVE_BPS,
+            InsuranceError::InvalidParameters
+        );
+        require!(
+            self.min_coverage > 0 && self.min_coverage <= self.max_coverage,
+            InsuranceError::InvalidParameters
+        );
+        require!(
+            self.min_duration_days > 0
+                && self.min_duration_days <= self.max_duration_days
+                && self.max_duration_days <= max_policy_duration_days,
+            InsuranceError::InvalidParameters
+        );
+        require!(
+            self.pricing.rate_at_min_threshold_bps <= Self::MAX_RATE_BPS
+                && self.pricing.rate_at_max_threshold_bps <= Self::MAX_RATE_BPS
+                && self.deductible_bps <= 10_000
+                && self.risk_assessment_score <= 100,
+            InsuranceError::InvalidParameters
+        );
+        Ok(())
+    }
+
+    /// Check a holder's choices fall within the template's ranges
+    pub fn check_choices(&self, threshold: f64, coverage_amount: u64, duration_days: u32) -> Result<()> {
+        require!(
+            threshold >= self.min_threshold
+                && threshold <= self.max_threshold
+                && (self.min_coverage..=self.max_coverage).contains(&coverage_amount)
+                && (self.min_duration_days..=self.max_duration_days).contains(&duration_days),
+            InsuranceError::TemplateParameterOutOfRange
+        );
+        Ok(())
+    }
+
+    /// Premium of a policy with the given choices, from the pricing curve
+    pub fn premium(&self, threshold: f64, coverage_amount: u64, duration_days: u32) -> Result<u64> {
+        let range = self.max_threshold - self.min_threshold;
+        let position_bps = if range > 0.0 {
+            ((threshold - self.min_threshold) / range * 10_000.0) as u16
+        } else {
+            0
+        };
+        let rate_bps = siglab_core::interpolated_rate_bps(
+            self.pricing.rate_at_min_threshold_bps,
+            self.pricing.rate_at_max_threshold_bps,
+            position_bps,
+        );
+        let premium = siglab_core::term_premium(coverage_amount, duration_days, rate_bps)
+            .ok_or(InsuranceError::MathOverflow)?;
+        Ok(premium.max(self.pricing.minimum_premium))
+    }
+
+    /// Trigger of a policy with the given threshold
+    pub fn trigger_conditions(&self, threshold: f64) -> TriggerConditions {
+        TriggerConditions {
+            threshold_value: threshold,
+            comparison_operator: self.comparison_operator.clone(),
+            data_source: self.data_source.clone(),
+            grace_period: self.grace_period,
+            compound: None,
+        }
+    }
+
+    /// Deductible of a policy with the given coverage, rounded down
+    pub fn deductible(&self, coverage_amount: u64) -> u64 {
+        (coverage_amount as u128 * self.deductible_bps as u128 / 10_000) as u64
+    }
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::error::InsuranceError;
+use super::treasury::TokenType;
+
+/// Live coverage and the day's payouts in one region, kept so a single
+/// catastrophe cannot draw more than its configured share of the treasury
+///
+/// Policies sold while the region's account exists have their coverage booked
+/// here until it is released by a payout, expiry, close or reduction.
+#[account]
+#[derive(Debug)]
+pub struct RegionExposure {
+    /// Region code, part of the PDA seeds
+    pub region_code: [u8; 8],
+
+    /// Coverage in force of SOL policies, in lamports
+    pub sol_exposure: u64,
+
+    /// Coverage in force of USDC policies, in base units
+    pub usdc_exposure: u64,
+
+    /// Day (unix time / 86400) the daily payout totals count
+    pub payout_day: i64,
+
+    /// SOL paid out on `payout_day`, in lamports
+    pub sol_paid_today: u64,
+
+    /// USDC paid out on `payout_day`, in base units
+    pub usdc_paid_today: u64,
+
+    /// When the region was opened
+    pub opened_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RegionExposure {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // region_code
+        8 + // sol_exposure
+        8 + // usdc_exposure
+        8 + // payout_day
+        8 + // sol_paid_today
+        8 + // usdc_paid_today
+        8 + // opened_at
+        1 // bump
+    }
+
+    /// Coverage in force paying out in `currency`
+    pub fn exposure(&self, currency: TokenType) -> u64 {
+        match currency {
+            TokenType::SOL => self.sol_exposure,
+            TokenType::USDC => self.usdc_exposure,
+        }
+    }
+
+    /// Add `coverage` to the exposure of `currency`, refusing it above `cap`
+    pub fn add_exposure(&mut self, currency: TokenType, coverage: u64, cap: Option<u64>) -> Result<()> {
+        let exposure = match currency {
+            TokenType::SOL => &mut self.sol_exposure,
+            TokenType::USDC => &mut self.usdc_exposure,
+        };
+        require!(
+            siglab_core::fits_region_cap(*exposure, coverage, cap),
+            InsuranceError::RegionExposureCapExceeded
+        );
+        *exposure += coverage;
+        Ok(())
+    }
+
+    /// Release `coverage` from the exposure of `currency`
+    pub fn release_exposure(&mut self, currency: TokenType, coverage: u64) {
+        let exposure = match currency {
+            TokenType::SOL => &mut self.sol_exposure,
+            TokenType::USDC => &mut self.usdc_exposure,
+        };
+        *exposure = exposure.saturating_sub(coverage);
+    }
+
+    /// Amount of `currency` paid out so far on the day of `timestamp`
+    pub fn paid_today(&self, currency: TokenType, timestamp: i64) -> u64 {
+        let paid = match currency {
+            TokenType::SOL => self.sol_paid_today,
+            TokenType::USDC => self.usdc_paid_today,
+        };
+        siglab_core::paid_today(self.payout_day, paid, timestamp)
+    }
+
+    /// Count a payout of `amount` against the day of `timestamp`, refusing it
+    /// once the day's payouts would exceed `cap`
+    pub fn record_payout(
+        &mut self,
+        currency: TokenType,
+        amount: u64,
+        timestamp: i64,
+        cap: Option<u64>,
+    ) -> Result<()> {
+        // Totals of an earlier day restart from zero
+        let sol_paid = self.paid_today(TokenType::SOL, timestamp);
+        let usdc_paid = self.paid_today(TokenType::USDC, timestamp);
+        let paid = match currency {
+            TokenType::SOL => sol_paid,
+            TokenType::USDC => usdc_paid,
+        };
+        require!(
+            siglab_core::fits_region_cap(paid, amount, cap),
+            InsuranceError::RegionDailyPayoutCapExceeded
+        );
+        self.payout_day = siglab_core::payout_day(timestamp);
+        self.sol_paid_today = sol_paid;
+        self.usdc_paid_today = usdc_paid;
+        match currency {
+            TokenType::SOL => self.sol_paid_today += amount,
+            TokenType::USDC => self.usdc_paid_today += amount,
+        }
+        Ok(())
+    }
+}
+
+/// Coverage in force in one region at report time
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct RegionReport {
+    pub region_code: [u8; 8],
+
+    /// Coverage in force of SOL policies, in lamports
+    pub sol_exposure: u64,
+
+    /// Coverage in force of USDC policies, in base units
+    pub usdc_exposure: u64,
+}
+
+impl RegionReport {
+    pub const SPACE: usize = 8 + 8 + 8;
+
+    pub fn new(region_exposure: &RegionExposure) -> Self {
+        Self {
+            region_code: region_exposure.region_code,
+            sol_exposure: region_exposure.sol_exposure,
+            usdc_exposure: region_exposure.usdc_exposure,
+        }
+    }
+}
\ No newline at end of file
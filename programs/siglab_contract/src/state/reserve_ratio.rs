@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_RESERVE_RATIO_BPS, MIN_RESERVE_RATIO_BPS};
+use crate::error::InsuranceError;
+
+/// Reserve ratio in basis points (10_000 = 100%)
+///
+/// Accounts and instruction arguments store the raw `u16`; this type is
+/// the single place that validates and applies it.
+///
+/// ```
+/// use siglab_contract::state::ReserveRatioBps;
+///
+/// let ratio = ReserveRatioBps::from_percent(20).unwrap();
+/// assert_eq!(ratio.bps(), 2_000);
+/// assert_eq!(ratio.required_reserves(1_000_000_000), 200_000_000);
+/// assert_eq!(ReserveRatioBps::new(1_250).required_reserves(1_000_000_000), 125_000_000);
+/// assert_eq!(ReserveRatioBps::new(5_000).required_reserves(3), 1);
+/// assert_eq!(ReserveRatioBps::new(5_000).required_reserves(u64::MAX), u64::MAX / 2);
+///
+/// assert_eq!(ReserveRatioBps::of(250_000_000, 1_000_000_000).bps(), 2_500);
+/// assert_eq!(ReserveRatioBps::of(u64::MAX, 1), ReserveRatioBps::FULL);
+/// assert_eq!(ReserveRatioBps::of(0, 0), ReserveRatioBps::FULL);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReserveRatioBps(u16);
+
+impl ReserveRatioBps {
+    /// Fully reserved
+    pub const FULL: Self = Self(siglab_core::reserves::FULL_RESERVE_BPS);
+    
+    pub const fn new(bps: u16) -> Self {
+        Self(bps)
+    }
+    
+    /// Convert a whole percentage, rejecting values above 100%
+    pub fn from_percent(percent: u16) -> Result<Self> {
+        require!(percent <= 100, InsuranceError::InvalidInput);
+        Ok(Self(percent * 100))
+    }
+    
+    /// Ratio of `balance` to `exposure`, capped at 100%
+    pub fn of(balance: u64, exposure: u64) -> Self {
+        Self(siglab_core::reserve_ratio_bps(balance, exposure))
+    }
+    
+    pub fn bps(self) -> u16 {
+        self.0
+    }
+    
+    /// Ensure the ratio is within the range an admin may configure
+    pub fn validate(self) -> Result<Self> {
+        require!(
+            self.0 >= MIN_RESERVE_RATIO_BPS && self.0 <= MAX_RESERVE_RATIO_BPS,
+            InsuranceError::InvalidInput
+        );
+        Ok(self)
+    }
+    
+    /// Reserves required to back `exposure`, rounded down
+    pub fn required_reserves(self, exposure: u64) -> u64 {
+        siglab_core::required_reserves(self.0, exposure)
+    }
+}
+
+impl std::fmt::Display for ReserveRatioBps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{:02}%", self.0 / 100, self.0 % 100)
+    }
+}
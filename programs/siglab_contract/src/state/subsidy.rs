@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_SUBSIDY_HOLDERS;
+use super::policy::InsuranceType;
+
+/// Premium co-payment funded by a sponsor, such as a government or NGO
+/// program for smallholder farmers
+///
+/// The account holds the unspent budget in lamports on top of its rent.
+#[account]
+#[derive(Debug)]
+pub struct Subsidy {
+    /// Sponsor that funds the subsidy and may top it up or close it
+    pub sponsor: Pubkey,
+    
+    /// Sponsor-chosen identifier, part of the PDA seeds
+    pub subsidy_id: u64,
+    
+    /// Only policies of this type are subsidized; any type when unset
+    pub insurance_type: Option<InsuranceType>,
+    
+    /// Only these holders are subsidized; any holder when empty
+    pub allowed_holders: Vec<Pubkey>,
+    
+    /// Share of each premium paid by the sponsor (basis points: 7500 = 75%)
+    pub coverage_bps: u16,
+    
+    /// Lamports left to draw
+    pub budget_remaining: u64,
+    
+    /// Lamports drawn for premiums so far
+    pub total_disbursed: u64,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Subsidy {
+    pub const MAX_COVERAGE_BPS: u16 = 10_000;
+    
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // sponsor
+        8 + // subsidy_id
+        1 + 1 + // insurance_type
+        4 + (32 * MAX_SUBSIDY_HOLDERS) + // allowed_holders
+        2 + // coverage_bps
+        8 + // budget_remaining
+        8 + // total_disbursed
+        8 + // created_at
+        1 // bump
+    }
+    
+    /// Whether premiums of `holder`'s policy of `insurance_type` qualify
+    pub fn covers(&self, insurance_type: &InsuranceType, holder: &Pubkey) -> bool {
+        (self.insurance_type.is_none() || self.insurance_type.as_ref() == Some(insurance_type))
+            && (self.allowed_holders.is_empty() || self.allowed_holders.contains(holder))
+    }
+}
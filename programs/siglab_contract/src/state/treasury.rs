@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
+use super::reserve_ratio::ReserveRatioBps;
 
 #[account]
 #[derive(Debug)]
@@ -34,10 +34,10 @@ pub struct Treasury {
     /// Total payouts disbursed in SOL
     pub total_payouts_disbursed_sol: u64,
     
-    /// Current reserve ratio percentage (basis points: 2000 = 20%)
+    /// Current reserve ratio in basis points (2000 = 20%)
     pub current_reserve_ratio: u16,
     
-    /// Minimum reserve ratio required (basis points)
+    /// Minimum reserve ratio required in basis points
     pub minimum_reserve_ratio: u16,
     
     /// Total coverage exposure across all active policies
@@ -57,6 +57,46 @@ pub struct Treasury {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Lamports set aside for tipping third-party payout executors
+    pub crank_budget_lamports: u64,
+    
+    /// Migration generation; version 0 lives at `[b"treasury"]`, later ones at `[b"treasury", version]`
+    pub version: u8,
+    
+    /// Whether the treasury still backs the contract
+    pub status: TreasuryStatus,
+    
+    /// Treasury the balances were migrated to once retired
+    pub successor: Pubkey,
+    
+    /// Coverage of live policies paying out in SOL
+    pub sol_coverage_exposure: u64,
+    
+    /// Coverage of live policies paying out in USDC
+    pub usdc_coverage_exposure: u64,
+    
+    /// Total gifted to the pool in SOL, with no claim on it
+    pub total_donations_sol: u64,
+    
+    /// Total gifted to the pool in USDC, with no claim on it
+    pub total_donations_usdc: u64,
+    
+    /// Whether the reserve ratio was below the minimum at the last balance update
+    pub reserve_breached: bool,
+    
+    /// When the reserve ratio last fell below the minimum (0 if it never has)
+    pub last_breach_at: i64,
+    
+    /// Decimals of the USDC mint; USDC amounts are counted in its base units
+    pub usdc_decimals: u8,
+    
+    /// Balance earmarked for triggered payouts not yet executed, rejected or expired
+    pub reserved_for_payouts: u64,
+    
+    /// Lamports co-signers locked as underwriting bonds; held apart from the
+    /// balances and never counted toward reserves
+    pub underwriting_bonds: u64,
 }
 
 impl Treasury {
@@ -80,23 +120,38 @@ impl Treasury {
         8 + // withdrawal_count
         8 + // last_update_timestamp
         8 + // created_at
-        1   // bump
+        1 + // bump
+        8 + // crank_budget_lamports
+        1 + // version
+        1 + // status
+        32 + // successor
+        8 + // sol_coverage_exposure
+        8 + // usdc_coverage_exposure
+        8 + // total_donations_sol
+        8 + // total_donations_usdc
+        1 + // reserve_breached
+        8 + // last_breach_at
+        1 + // usdc_decimals
+        8 + // reserved_for_payouts
+        8 // underwriting_bonds
     }
     
-    /// Calculate current reserve ratio in basis points
-    pub fn calculate_reserve_ratio(&self) -> u16 {
-        if self.total_coverage_exposure == 0 {
-            return 10000; // 100% if no exposure
+    /// Units amounts of `currency` are counted in
+    pub fn denomination(&self, currency: TokenType) -> siglab_core::Denomination {
+        match currency {
+            TokenType::SOL => siglab_core::Denomination::Lamports,
+            TokenType::USDC => siglab_core::Denomination::Usd { decimals: self.usdc_decimals },
         }
-        
+    }
+    
+    pub fn minimum_reserve(&self) -> ReserveRatioBps {
+        ReserveRatioBps::new(self.minimum_reserve_ratio)
+    }
+    
+    /// Calculate current reserve ratio in basis points (100% if no exposure)
+    pub fn calculate_reserve_ratio(&self) -> u16 {
         let total_balance = self.total_usdc_balance + self.total_sol_balance;
-        if total_balance == 0 {
-            return 0;
-        }
-        
-        // Calculate ratio in basis points (10000 = 100%)
-        let ratio = (total_balance * 10000) / self.total_coverage_exposure;
-        std::cmp::min(ratio as u16, 10000)
+        ReserveRatioBps::of(total_balance, self.total_coverage_exposure).bps()
     }
     
     /// Check if treasury meets minimum reserve requirements
@@ -104,18 +159,67 @@ impl Treasury {
         self.calculate_reserve_ratio() >= self.minimum_reserve_ratio
     }
     
-    /// Calculate available liquidity for new policies
-    pub fn available_liquidity(&self) -> u64 {
-        let total_balance = self.total_usdc_balance + self.total_sol_balance;
-        let required_reserves = (self.total_coverage_exposure * self.minimum_reserve_ratio as u64) / 10000;
+    /// Record whether the reserves now meet the minimum, returning the
+    /// transition when they crossed it since the last call
+    pub fn track_reserve_breach(&mut self, timestamp: i64) -> Option<ReserveTransition> {
+        let breached = !self.meets_reserve_requirement();
+        if breached == self.reserve_breached {
+            return None;
+        }
         
-        if total_balance > required_reserves {
-            total_balance - required_reserves
+        self.reserve_breached = breached;
+        if breached {
+            self.last_breach_at = timestamp;
+            Some(ReserveTransition::Breached)
+        } else {
+            Some(ReserveTransition::Restored)
+        }
+    }
+    
+    /// Seconds the reserves have been below the minimum, 0 when they meet it
+    pub fn time_in_breach(&self, now: i64) -> i64 {
+        if self.reserve_breached {
+            now.saturating_sub(self.last_breach_at)
         } else {
             0
         }
     }
     
+    /// Classify the reserves against the minimum reserve ratio
+    pub fn solvency(&self) -> siglab_core::Solvency {
+        let total_balance = self.total_usdc_balance + self.total_sol_balance;
+        siglab_core::solvency(total_balance, self.total_coverage_exposure, self.minimum_reserve_ratio)
+    }
+    
+    /// Calculate available liquidity for new policies
+    pub fn available_liquidity(&self) -> u64 {
+        let required_reserves = self.minimum_reserve().required_reserves(self.total_coverage_exposure);
+        self.unreserved_balance().saturating_sub(required_reserves)
+    }
+    
+    /// Balance not earmarked for triggered payouts
+    pub fn unreserved_balance(&self) -> u64 {
+        (self.total_usdc_balance + self.total_sol_balance).saturating_sub(self.reserved_for_payouts)
+    }
+    
+    /// Whether the unreserved balance covers a payout of `amount`
+    pub fn can_reserve(&self, amount: u64) -> bool {
+        amount <= self.unreserved_balance()
+    }
+    
+    /// Earmark `amount` for a triggered payout, failing when the unreserved
+    /// balance cannot cover it
+    pub fn reserve_for_payout(&mut self, amount: u64) -> Result<()> {
+        require!(self.can_reserve(amount), crate::error::InsuranceError::InsufficientTreasury);
+        self.reserved_for_payouts += amount;
+        Ok(())
+    }
+    
+    /// Return a payout's earmark, when it is executed, rejected or expired
+    pub fn release_payout_reservation(&mut self, amount: u64) {
+        self.reserved_for_payouts = self.reserved_for_payouts.saturating_sub(amount);
+    }
+    
     /// Update balances after a transaction
     pub fn update_balances(&mut self, usdc_change: i64, sol_change: i64, timestamp: i64) {
         // Update USDC balance
@@ -151,6 +255,98 @@ impl Treasury {
         self.last_update_timestamp = timestamp;
     }
     
+    /// Draw `tip` from the crank budget, or nothing when the budget cannot cover it
+    pub fn draw_crank_tip(&mut self, tip: u64, timestamp: i64) -> u64 {
+        if tip == 0 || self.crank_budget_lamports < tip || self.total_sol_balance < tip {
+            return 0;
+        }
+        
+        self.crank_budget_lamports -= tip;
+        self.update_balances(0, -(tip as i64), timestamp);
+        tip
+    }
+    
+    /// Whether the treasury can hold `currency`: SOL always, USDC once its
+    /// mint and token account are configured
+    pub fn supports(&self, currency: TokenType) -> bool {
+        match currency {
+            TokenType::SOL => true,
+            TokenType::USDC => {
+                self.usdc_mint != Pubkey::default() && self.usdc_token_account != Pubkey::default()
+            }
+        }
+    }
+    
+    /// Tracked balance held in `currency`
+    pub fn balance(&self, currency: TokenType) -> u64 {
+        match currency {
+            TokenType::SOL => self.total_sol_balance,
+            TokenType::USDC => self.total_usdc_balance,
+        }
+    }
+    
+    /// Coverage of live policies paying out in `currency`
+    pub fn coverage_exposure(&self, currency: TokenType) -> u64 {
+        match currency {
+            TokenType::SOL => self.sol_coverage_exposure,
+            TokenType::USDC => self.usdc_coverage_exposure,
+        }
+    }
+    
+    /// Coverage exposure of live policies, per currency and in total
+    pub fn exposure_ledger(&self) -> siglab_core::CoverageExposure {
+        siglab_core::CoverageExposure {
+            total: self.total_coverage_exposure,
+            sol: self.sol_coverage_exposure,
+            usdc: self.usdc_coverage_exposure,
+        }
+    }
+    
+    fn set_exposure_ledger(&mut self, exposure: siglab_core::CoverageExposure) {
+        self.total_coverage_exposure = exposure.total;
+        self.sol_coverage_exposure = exposure.sol;
+        self.usdc_coverage_exposure = exposure.usdc;
+    }
+    
+    /// Add `coverage` to the exposure of `currency` and to the total
+    pub fn add_coverage_exposure(&mut self, currency: TokenType, coverage: u64) -> Result<()> {
+        let exposure = self
+            .exposure_ledger()
+            .book(currency.is_usdc(), coverage)
+            .ok_or(crate::error::InsuranceError::MathOverflow)?;
+        self.set_exposure_ledger(exposure);
+        Ok(())
+    }
+    
+    /// Release `coverage` from the exposure of `currency` and from the total
+    pub fn release_coverage_exposure(&mut self, currency: TokenType, coverage: u64) {
+        let exposure = self.exposure_ledger().release(currency.is_usdc(), coverage);
+        self.set_exposure_ledger(exposure);
+    }
+    
+    /// Record a donation; it backs reserves but creates no withdrawal rights
+    pub fn record_donation(&mut self, amount: u64, is_usdc: bool, timestamp: i64) {
+        if is_usdc {
+            self.total_donations_usdc += amount;
+            self.total_usdc_balance += amount;
+        } else {
+            self.total_donations_sol += amount;
+            self.total_sol_balance += amount;
+        }
+        
+        self.current_reserve_ratio = self.calculate_reserve_ratio();
+        self.last_update_timestamp = timestamp;
+    }
+    
+    /// Record a premium refund paid back in the policy's premium currency
+    pub fn record_refund(&mut self, amount: u64, is_usdc: bool, timestamp: i64) {
+        if is_usdc {
+            self.update_balances(-(amount as i64), 0, timestamp);
+        } else {
+            self.update_balances(0, -(amount as i64), timestamp);
+        }
+    }
+    
     /// Record payout disbursement
     pub fn record_payout(&mut self, amount: u64, is_usdc: bool, timestamp: i64) -> Result<()> {
         if is_usdc {
@@ -195,10 +391,93 @@ pub struct WithdrawalInfo {
     pub reason: WithdrawalReason,
 }
 
-#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
-pub enum TokenType {
-    USDC,
-    SOL,
+/// Direction in which the reserve ratio crossed its minimum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReserveTransition {
+    Breached,
+    Restored,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum TreasuryStatus {
+    Active,
+    Retired,
+}
+
+crate::stable_enum! {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum TokenType {
+        USDC = 0,
+        SOL = 1,
+    }
+}
+
+impl TokenType {
+    pub fn is_usdc(&self) -> bool {
+        *self == TokenType::USDC
+    }
+}
+
+/// An amount tagged with the currency it is counted in
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct CurrencyAmount {
+    /// Base units of `currency`: lamports, or USDC at the treasury's decimals
+    pub amount: u64,
+    pub currency: TokenType,
+}
+
+impl CurrencyAmount {
+    pub const SPACE: usize = 8 + 1;
+    
+    pub const fn sol(amount: u64) -> Self {
+        Self {
+            amount,
+            currency: TokenType::SOL,
+        }
+    }
+}
+
+/// Converts amounts between the treasury's currencies
+#[derive(Debug, Clone, Copy)]
+pub struct CurrencyConverter {
+    sol: siglab_core::Denomination,
+    usdc: siglab_core::Denomination,
+    /// USD price of one SOL with `siglab_core::currency::PRICE_DECIMALS` decimals
+    sol_price: Option<u64>,
+}
+
+impl CurrencyConverter {
+    pub fn new(treasury: &Treasury, sol_price: Option<u64>) -> Self {
+        Self {
+            sol: treasury.denomination(TokenType::SOL),
+            usdc: treasury.denomination(TokenType::USDC),
+            sol_price,
+        }
+    }
+    
+    /// Express `amount` in base units of `currency`, rounding down
+    ///
+    /// Amounts already in `currency`, and zero, need no price.
+    pub fn convert(&self, amount: CurrencyAmount, currency: TokenType) -> Result<u64> {
+        if amount.currency == currency || amount.amount == 0 {
+            return Ok(amount.amount);
+        }
+        let sol_price = self.sol_price.ok_or(crate::error::InsuranceError::PriceUnavailable)?;
+        siglab_core::convert(
+            amount.amount,
+            self.denomination(amount.currency),
+            self.denomination(currency),
+            sol_price,
+        )
+        .ok_or(crate::error::InsuranceError::MathOverflow.into())
+    }
+    
+    fn denomination(&self, currency: TokenType) -> siglab_core::Denomination {
+        match currency {
+            TokenType::SOL => self.sol,
+            TokenType::USDC => self.usdc,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
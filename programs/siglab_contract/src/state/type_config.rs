@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use super::policy::{ComparisonOperator, InsuranceType, TriggerConditions};
+use crate::constants::{
+    MAX_INCIDENT_TOLERANCE, MAX_MIN_INCIDENT_GAP, MAX_MIN_WAITING_PERIOD_HOURS, MAX_POLICY_DURATION_CEILING_DAYS,
+    MAX_POLICY_DURATION_DAYS,
+};
+use crate::error::InsuranceError;
+
+/// Policy field outside its insurance type's limits
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum TypeLimitViolation {
+    /// Term longer than the type allows
+    Duration,
+    /// Waiting period shorter than the type requires
+    WaitingPeriod,
+    /// Trigger threshold outside the type's range
+    Threshold,
+    /// Trigger comparison the type does not allow
+    ComparisonOperator,
+}
+
+/// Parameters that vary by insurance type
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct TypeConfigParams {
+    /// Queue priority of a payout before severity and aging (0-100)
+    pub base_priority: u8,
+    
+    /// Priority points a payout gains at 100% severity, scaled down with
+    /// severity (0-100); the sum with `base_priority` is capped at 100
+    pub severity_priority_points: u8,
+    
+    /// Minimum premium in basis points of coverage (0 for no floor)
+    pub premium_base_rate_bps: u16,
+    
+    /// Seconds trigger evidence may sit from the claimed incident
+    pub incident_tolerance_secs: i64,
+    
+    /// Minimum seconds between incidents claimed on one policy
+    pub min_incident_gap_secs: i64,
+    
+    /// Longest policy term in days
+    pub max_duration_days: u32,
+    
+    /// Lowest trigger threshold accepted
+    pub min_threshold: f64,
+    
+    /// Highest trigger threshold accepted
+    pub max_threshold: f64,
+    
+    /// Comparisons triggers may use, one `ComparisonOperator::bit` each
+    pub allowed_operators: u8,
+    
+    /// Shortest waiting period in hours before cover starts
+    pub min_waiting_period_hours: u32,
+    
+    /// Widest oracle confidence interval new policies accept as trigger
+    /// evidence, in basis points of the reported value (0 for no limit)
+    pub max_confidence_ratio_bps: u16,
+}
+
+impl TypeConfigParams {
+    pub const SPACE: usize = 1 + 1 + 2 + 8 + 8 + 4 + 8 + 8 + 1 + 4 + 2;
+    
+    /// Priority points at 100% severity a newly configured type starts with
+    pub const DEFAULT_SEVERITY_PRIORITY_POINTS: u8 = 25;
+    
+    /// Starting parameters for a newly configured type
+    pub fn defaults(insurance_type: &InsuranceType) -> Self {
+        let (base_priority, incident_tolerance_secs, min_incident_gap_secs) = match insurance_type {
+            InsuranceType::Weather => (70, 6 * 3600, 24 * 3600),
+            InsuranceType::Earthquake => (90, 3600, 72 * 3600),
+            InsuranceType::Flight => (60, 3 * 3600, 24 * 3600),
+            InsuranceType::Crop => (80, 72 * 3600, 14 * 86400),
+            InsuranceType::Custom => (50, 24 * 3600, 24 * 3600),
+        };
+        
+        let above = ComparisonOperator::GreaterThan.bit();
+        let above_or_below = above | ComparisonOperator::LessThan.bit();
+        // Rainfall in mm, magnitude, delay in minutes, crop index; custom feeds are unconstrained
+        let (max_duration_days, min_threshold, max_threshold, allowed_operators) = match insurance_type {
+            InsuranceType::Weather => (MAX_POLICY_DURATION_DAYS, 0.1, 10_000.0, above_or_below),
+            InsuranceType::Earthquake => (MAX_POLICY_DURATION_DAYS, 1.0, 10.0, above),
+            InsuranceType::Flight => (7, 1.0, 1_440.0, above),
+            InsuranceType::Crop => (MAX_POLICY_DURATION_DAYS, 0.1, 1_000_000.0, above_or_below),
+            InsuranceType::Custom => (MAX_POLICY_DURATION_CEILING_DAYS, f64::MIN, f64::MAX, ComparisonOperator::ALL),
+        };
+        
+        // Long enough that a storm on the radar or a failing harvest cannot be insured
+        let min_waiting_period_hours = match insurance_type {
+            InsuranceType::Weather => 24,
+            InsuranceType::Earthquake => 24,
+            InsuranceType::Flight => 2,
+            InsuranceType::Crop => 72,
+            InsuranceType::Custom => 0,
+        };
+        
+        // Seismic feeds are precise; yield indices are estimates
+        let max_confidence_ratio_bps = match insurance_type {
+            InsuranceType::Weather => 1_000,
+            InsuranceType::Earthquake => 500,
+            InsuranceType::Flight => 1_000,
+            InsuranceType::Crop => 2_000,
+            InsuranceType::Custom => 0,
+        };
+        
+        Self {
+            base_priority,
+            severity_priority_points: Self::DEFAULT_SEVERITY_PRIORITY_POINTS,
+            premium_base_rate_bps: 0,
+            incident_tolerance_secs,
+            min_incident_gap_secs,
+            max_duration_days,
+            min_threshold,
+            max_threshold,
+            allowed_operators,
+            min_waiting_period_hours,
+            max_confidence_ratio_bps,
+        }
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        require!(self.base_priority <= siglab_core::payout::MAX_PRIORITY, InsuranceError::InvalidInput);
+        require!(
+            self.severity_priority_points <= siglab_core::payout::MAX_PRIORITY,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.premium_base_rate_bps <= siglab_core::reserves::FULL_RESERVE_BPS,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            (1..=MAX_INCIDENT_TOLERANCE).contains(&self.incident_tolerance_secs),
+            InsuranceError::InvalidInput
+        );
+        require!(
+            (0..=MAX_MIN_INCIDENT_GAP).contains(&self.min_incident_gap_secs),
+            InsuranceError::InvalidInput
+        );
+        require!(
+            (1..=MAX_POLICY_DURATION_CEILING_DAYS).contains(&self.max_duration_days),
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.min_threshold.is_finite()
+                && self.max_threshold.is_finite()
+                && self.min_threshold <= self.max_threshold,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.allowed_operators != 0 && self.allowed_operators & !ComparisonOperator::ALL == 0,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.min_waiting_period_hours <= MAX_MIN_WAITING_PERIOD_HOURS,
+            InsuranceError::InvalidInput
+        );
+        require!(
+            self.max_confidence_ratio_bps <= siglab_core::reserves::FULL_RESERVE_BPS,
+            InsuranceError::InvalidInput
+        );
+        Ok(())
+    }
+    
+    /// First of a new policy's term, waiting period and trigger outside this type's limits
+    pub fn policy_violation(
+        &self,
+        duration_days: u32,
+        waiting_period_hours: u32,
+        trigger: &TriggerConditions,
+    ) -> Option<TypeLimitViolation> {
+        if duration_days > self.max_duration_days {
+            return Some(TypeLimitViolation::Duration);
+        }
+        if waiting_period_hours < self.min_waiting_period_hours {
+            return Some(TypeLimitViolation::WaitingPeriod);
+        }
+        self.trigger_violation(trigger)
+    }
+    
+    /// First trigger condition outside this type's limits; compound triggers
+    /// are checked leaf by leaf
+    pub fn trigger_violation(&self, trigger: &TriggerConditions) -> Option<TypeLimitViolation> {
+        match &trigger.compound {
+            Some(compound) => compound
+                .leaves
+                .iter()
+                .find_map(|leaf| self.condition_violation(&leaf.operator, leaf.threshold)),
+            None => self.condition_violation(&trigger.comparison_operator, trigger.threshold_value),
+        }
+    }
+    
+    fn condition_violation(&self, operator: &ComparisonOperator, threshold: f64) -> Option<TypeLimitViolation> {
+        if !(self.min_threshold..=self.max_threshold).contains(&threshold) {
+            Some(TypeLimitViolation::Threshold)
+        } else if self.allowed_operators & operator.bit() == 0 {
+            Some(TypeLimitViolation::ComparisonOperator)
+        } else {
+            None
+        }
+    }
+    
+    /// Lowest premium accepted for `coverage_amount`, rounded down
+    pub fn minimum_premium(&self, coverage_amount: u64) -> u64 {
+        siglab_core::required_reserves(self.premium_base_rate_bps, coverage_amount)
+    }
+}
+
+/// Configuration of one insurance type; policies of a type cannot be sold until it exists
+#[account]
+#[derive(Debug)]
+pub struct InsuranceTypeConfig {
+    pub insurance_type: InsuranceType,
+    
+    pub params: TypeConfigParams,
+    
+    /// Last time the parameters changed
+    pub updated_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InsuranceTypeConfig {
+    pub fn space() -> usize {
+        8 + // discriminator
+        1 + // insurance_type
+        TypeConfigParams::SPACE + // params
+        8 + // updated_at
+        1   // bump
+    }
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::constants::{FEE_EPOCH_SECS, UNDERWRITER_LEDGER_EPOCHS};
+use crate::error::InsuranceError;
+
+/// Coverage an underwriter co-signed and the late fees its policies paid,
+/// per accounting epoch, the basis of its fee rebate
+///
+/// Epochs are kept in a ring of `UNDERWRITER_LEDGER_EPOCHS` slots, so a
+/// rebate left unclaimed that long is overwritten and forfeited.
+#[account]
+#[derive(Debug)]
+pub struct UnderwriterLedger {
+    /// Underwriter the ledger belongs to, part of the PDA seeds
+    pub underwriter: Pubkey,
+    
+    /// Epoch totals, slot `epoch % UNDERWRITER_LEDGER_EPOCHS`
+    pub epochs: [UnderwriterEpoch; UNDERWRITER_LEDGER_EPOCHS],
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// One accounting epoch of an underwriter's business
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnderwriterEpoch {
+    /// Epoch number, `timestamp / FEE_EPOCH_SECS`
+    pub epoch: u64,
+    
+    /// Coverage co-signed during the epoch, in lamports whatever the policy's currency
+    pub placed_coverage: u64,
+    
+    /// Late fees paid in lamports by the underwriter's policies during the epoch
+    pub sol_fees: u64,
+    
+    /// Late fees paid in USDC base units by the underwriter's policies during the epoch
+    pub usdc_fees: u64,
+    
+    /// Whether the epoch's rebate has been paid
+    pub claimed: bool,
+}
+
+impl UnderwriterLedger {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // underwriter
+        (8 + 8 + 8 + 8 + 1) * UNDERWRITER_LEDGER_EPOCHS + // epochs
+        1 // bump
+    }
+    
+    /// Accounting epoch `timestamp` falls in
+    pub fn epoch_at(timestamp: i64) -> u64 {
+        (timestamp.max(0) / FEE_EPOCH_SECS) as u64
+    }
+    
+    /// Totals of `epoch`, if the ring still holds it
+    pub fn entry(&self, epoch: u64) -> Option<&UnderwriterEpoch> {
+        self.epochs
+            .get(epoch as usize % UNDERWRITER_LEDGER_EPOCHS)
+            .filter(|entry| entry.epoch == epoch)
+    }
+    
+    /// Totals of the epoch `timestamp` falls in, starting them over the
+    /// oldest epoch in the ring when it is new
+    fn current_mut(&mut self, timestamp: i64) -> &mut UnderwriterEpoch {
+        let epoch = Self::epoch_at(timestamp);
+        let entry = &mut self.epochs[epoch as usize % UNDERWRITER_LEDGER_EPOCHS];
+        if entry.epoch != epoch {
+            *entry = UnderwriterEpoch { epoch, ..UnderwriterEpoch::default() };
+        }
+        entry
+    }
+    
+    /// Book `coverage` lamports co-signed at `timestamp`
+    pub fn record_placement(&mut self, coverage: u64, timestamp: i64) -> Result<()> {
+        let entry = self.current_mut(timestamp);
+        entry.placed_coverage = entry.placed_coverage.checked_add(coverage).ok_or(InsuranceError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Book a late fee one of the underwriter's policies paid at `timestamp`
+    pub fn record_fee(&mut self, amount: u64, is_usdc: bool, timestamp: i64) -> Result<()> {
+        let entry = self.current_mut(timestamp);
+        let fees = if is_usdc { &mut entry.usdc_fees } else { &mut entry.sol_fees };
+        *fees = fees.checked_add(amount).ok_or(InsuranceError::MathOverflow)?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use super::payout::PayoutIneligibility;
+use super::policy::PolicyStatus;
+
+/// Response of a view instruction
+///
+/// View instructions mutate nothing; they Borsh-encode a `ViewResponse` as
+/// return data, read back by simulating the transaction. The leading version
+/// byte lets clients reject a layout they do not understand.
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct ViewResponse<T> {
+    /// Schema version of `data`
+    pub version: u8,
+    
+    pub data: T,
+}
+
+/// Payload of a view instruction
+pub trait View: AnchorSerialize + AnchorDeserialize {
+    /// Schema version, bumped whenever the layout changes
+    const VERSION: u8;
+}
+
+impl<T: View> ViewResponse<T> {
+    pub fn new(data: T) -> Self {
+        Self { version: T::VERSION, data }
+    }
+    
+    /// Encode the response and set it as the instruction's return data
+    pub fn set_return_data(&self) -> Result<()> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+}
+
+/// Returned by `get_policy_status`
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyStatusView {
+    pub status: PolicyStatus,
+    
+    /// Whole days until the policy ends (0 once ended)
+    pub days_remaining: u32,
+    
+    /// When the next premium is due, if one is
+    pub premium_due_date: Option<i64>,
+    
+    /// Pending payout filed against the policy, if any
+    pub pending_payout: Option<Pubkey>,
+}
+
+impl View for PolicyStatusView {
+    const VERSION: u8 = 1;
+}
+
+/// Returned by `get_treasury_health`
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct TreasuryHealthView {
+    /// Reserve ratio in basis points
+    pub reserve_ratio: u16,
+    
+    /// Minimum reserve ratio in basis points
+    pub minimum_reserve_ratio: u16,
+    
+    pub solvency: SolvencyState,
+    
+    /// Balance above the required reserves
+    pub available_liquidity: u64,
+    
+    /// Pending payouts not yet executed, rejected or expired
+    pub open_payout_count: u64,
+    
+    /// Configured limit on `open_payout_count`
+    pub max_open_payouts: u64,
+}
+
+impl View for TreasuryHealthView {
+    const VERSION: u8 = 2;
+}
+
+/// See `siglab_core::Solvency`
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum SolvencyState {
+    Healthy,
+    Undercapitalized,
+    Insolvent,
+}
+
+impl From<siglab_core::Solvency> for SolvencyState {
+    fn from(solvency: siglab_core::Solvency) -> Self {
+        match solvency {
+            siglab_core::Solvency::Healthy => Self::Healthy,
+            siglab_core::Solvency::Undercapitalized => Self::Undercapitalized,
+            siglab_core::Solvency::Insolvent => Self::Insolvent,
+        }
+    }
+}
+
+/// Oracle to look up with `resolve_oracle`
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum OracleLookup {
+    Id(String),
+    Address(Pubkey),
+}
+
+/// Returned by `resolve_oracle`
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleDirectoryView {
+    /// SHA-256 of the oracle id
+    pub id_hash: [u8; 32],
+    
+    pub oracle: Pubkey,
+    
+    pub authority: Pubkey,
+    
+    /// Whether the oracle is in the registry; an unregistered id keeps its
+    /// last mapping
+    pub registered: bool,
+}
+
+impl View for OracleDirectoryView {
+    const VERSION: u8 = 1;
+}
+
+/// Whether `trigger_payout` would accept a claim on a policy, or the first
+/// reason it would refuse one, checked in the order `trigger_payout` does
+#[derive(Debug, Clone, Copy, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ClaimVerdict {
+    Eligible,
+    ContractPaused,
+    /// A payout filed against the policy is still pending
+    PayoutPending,
+    /// The policy is cancelled, paid out or otherwise no longer active
+    PolicyLapsed,
+    /// The policy's term has ended
+    PolicyExpired,
+    /// An oracle has gone the consensus timeout without reporting, or the
+    /// policy's oracle holds no reading within its staleness threshold
+    OracleStale,
+    /// An oracle read has its circuit breaker tripped
+    CircuitBreaker,
+    /// An oracle read is inactive or below the reputation floor
+    OracleUnhealthy,
+    WaitingPeriod,
+    /// The trigger is not met by `ClaimEligibilityView::oracle_value`
+    TriggerNotMet,
+    /// `max_open_payouts` payouts are already pending
+    PayoutQueueFull,
+    /// The treasury's unreserved balance cannot cover the payout
+    TreasuryInsolvent,
+    /// Any other reason the payout assessment refuses the claim
+    Ineligible(PayoutIneligibility),
+}
+
+/// Returned by `check_claim_eligibility`
+#[derive(Debug, Clone, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimEligibilityView {
+    pub verdict: ClaimVerdict,
+    
+    /// Latest reading of the policy's oracle the trigger was assessed
+    /// against, once the checks got that far
+    pub oracle_value: Option<u64>,
+    
+    /// Payout a claim would file, 0 when the assessment refuses it
+    pub payout_amount: u64,
+}
+
+impl View for ClaimEligibilityView {
+    const VERSION: u8 = 1;
+}
\ No newline at end of file
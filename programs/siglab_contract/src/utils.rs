@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::error::InsuranceError;
+use crate::state::ReserveRatioBps;
 
 /// Utility macros for common error checking patterns
 pub mod macros {
@@ -7,10 +8,10 @@ pub mod macros {
     #[macro_export]
     macro_rules! require_authorized {
         ($condition:expr) => {
-            require!($condition, crate::error::InsuranceError::Unauthorized)
+            require!($condition, $crate::error::InsuranceError::Unauthorized)
         };
         ($condition:expr, $msg:expr) => {
-            require!($condition, crate::error::InsuranceError::Unauthorized)
+            require!($condition, $crate::error::InsuranceError::Unauthorized)
         };
     }
 
@@ -20,7 +21,7 @@ pub mod macros {
         ($treasury_balance:expr, $required_reserves:expr) => {
             require!(
                 $treasury_balance >= $required_reserves,
-                crate::error::InsuranceError::SolvencyCheckFailed
+                $crate::error::InsuranceError::SolvencyCheckFailed
             )
         };
     }
@@ -31,7 +32,7 @@ pub mod macros {
         ($oracle_timestamp:expr, $current_time:expr, $staleness_threshold:expr) => {
             require!(
                 $current_time - $oracle_timestamp <= $staleness_threshold,
-                crate::error::InsuranceError::OracleDataStale
+                $crate::error::InsuranceError::OracleDataStale
             )
         };
     }
@@ -41,8 +42,8 @@ pub mod macros {
     macro_rules! require_policy_active {
         ($policy_status:expr) => {
             require!(
-                matches!($policy_status, crate::state::PolicyStatus::Active),
-                crate::error::InsuranceError::PolicyNotActive
+                matches!($policy_status, $crate::state::PolicyStatus::Active),
+                $crate::error::InsuranceError::PolicyNotActive
             )
         };
     }
@@ -51,7 +52,15 @@ pub mod macros {
     #[macro_export]
     macro_rules! require_not_paused {
         ($is_paused:expr) => {
-            require!(!$is_paused, crate::error::InsuranceError::ContractPaused)
+            require!(!$is_paused, $crate::error::InsuranceError::ContractPaused)
+        };
+    }
+
+    /// Require that the contract still sells new coverage
+    #[macro_export]
+    macro_rules! require_not_in_winddown {
+        ($winddown:expr) => {
+            require!(!$winddown, $crate::error::InsuranceError::ContractInWinddown)
         };
     }
 
@@ -61,10 +70,118 @@ pub mod macros {
         ($premium_amount:expr, $minimum_premium:expr) => {
             require!(
                 $premium_amount >= $minimum_premium,
-                crate::error::InsuranceError::InsufficientPremium
+                $crate::error::InsuranceError::InsufficientPremium
             )
         };
     }
+    
+    /// Declare a fieldless enum stored in accounts, encoded as a one-byte
+    /// tag fixed by each variant's explicit discriminant rather than by its
+    /// position
+    ///
+    /// Tags are assigned densely from 0 in declaration order, so the IDL's
+    /// variant list still matches them; the build fails if a variant is
+    /// inserted or reordered. New variants are appended with the next free
+    /// tag, and a retired variant keeps its tag and stays declared. Tags
+    /// `0xF0..=0xFF` are reserved for a future encoding escape and are never
+    /// assigned. Decoding an unknown tag fails instead of picking a variant.
+    #[macro_export]
+    macro_rules! stable_enum {
+        (
+            $(#[doc = $doc:literal])*
+            #[derive($($derive:path),* $(,)?)]
+            $vis:vis enum $name:ident {
+                $(
+                    $(#[doc = $variant_doc:literal])*
+                    $variant:ident = $tag:literal
+                ),* $(,)?
+            }
+        ) => {
+            $(#[doc = $doc])*
+            #[derive($($derive),*)]
+            #[repr(u8)]
+            $vis enum $name {
+                $(
+                    $(#[doc = $variant_doc])*
+                    $variant = $tag
+                ),*
+            }
+            
+            const _: () = {
+                let mut next_tag = 0u8;
+                $(
+                    assert!($tag == next_tag, concat!("tags of ", stringify!($name), " must follow declaration order"));
+                    next_tag += 1;
+                )*
+                assert!(next_tag <= 0xF0, concat!(stringify!($name), " uses a reserved tag"));
+            };
+            
+            impl $name {
+                /// Every variant, in tag order
+                pub const VARIANTS: &'static [$name] = &[$($name::$variant),*];
+                
+                /// Stored byte of this variant
+                pub const fn tag(&self) -> u8 {
+                    match self {
+                        $($name::$variant => $tag),*
+                    }
+                }
+                
+                pub const fn from_tag(tag: u8) -> Option<Self> {
+                    match tag {
+                        $($tag => Some($name::$variant),)*
+                        _ => None,
+                    }
+                }
+            }
+            
+            impl anchor_lang::AnchorSerialize for $name {
+                fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                    writer.write_all(&[self.tag()])
+                }
+            }
+            
+            impl anchor_lang::AnchorDeserialize for $name {
+                fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                    let tag = <u8 as anchor_lang::AnchorDeserialize>::deserialize_reader(reader)?;
+                    Self::from_tag(tag).ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!("Unknown {} tag: {}", stringify!($name), tag),
+                        )
+                    })
+                }
+            }
+            
+            #[cfg(feature = "idl-build")]
+            impl anchor_lang::idl::build::IdlBuild for $name {
+                fn create_type() -> Option<anchor_lang::idl::types::IdlTypeDef> {
+                    Some(anchor_lang::idl::types::IdlTypeDef {
+                        name: Self::get_full_path(),
+                        docs: vec![$($doc.trim().into()),*],
+                        serialization: Default::default(),
+                        repr: None,
+                        generics: vec![],
+                        ty: anchor_lang::idl::types::IdlTypeDefTy::Enum {
+                            variants: vec![$(anchor_lang::idl::types::IdlEnumVariant {
+                                name: stringify!($variant).into(),
+                                fields: None,
+                            }),*],
+                        },
+                    })
+                }
+                
+                fn insert_types(
+                    _types: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlTypeDef>,
+                ) {
+                }
+                
+                fn get_full_path() -> String {
+                    format!("{}::{}", module_path!(), stringify!($name))
+                }
+            }
+        };
+    }
 }
 
 /// Error handling utilities
@@ -86,16 +203,13 @@ pub mod error_utils {
     pub fn validate_treasury_balance(
         treasury_balance: u64,
         required_amount: u64,
-        reserve_ratio: u64,
+        reserve_ratio: ReserveRatioBps,
     ) -> Result<()> {
         if treasury_balance < required_amount {
             return Err(InsuranceError::InsufficientTreasury.into());
         }
 
-        let required_reserves = treasury_balance
-            .checked_mul(reserve_ratio)
-            .and_then(|x| x.checked_div(100))
-            .ok_or(InsuranceError::MathOverflow)?;
+        let required_reserves = reserve_ratio.required_reserves(treasury_balance);
 
         if treasury_balance - required_amount < required_reserves {
             return Err(InsuranceError::ReserveRatioBelowMinimum.into());
@@ -104,6 +218,28 @@ pub mod error_utils {
         Ok(())
     }
 
+    /// Reject invocations arriving via CPI, so PDA signers cannot act as admin
+    pub fn require_top_level_invocation() -> Result<()> {
+        require!(!is_cpi(), InsuranceError::CpiNotAllowed);
+        Ok(())
+    }
+    
+    /// Reject admin instructions arriving via CPI unless the contract is
+    /// governed, its authority then being a PDA that can only sign through
+    /// the governance program's `invoke_signed`
+    pub fn require_admin_invocation(master_contract: &crate::state::MasterInsuranceContract) -> Result<()> {
+        if master_contract.governance_program.is_some() {
+            return Ok(());
+        }
+        require_top_level_invocation()
+    }
+    
+    /// Whether the current instruction was invoked by another program
+    pub fn is_cpi() -> bool {
+        anchor_lang::solana_program::instruction::get_stack_height()
+            > anchor_lang::solana_program::instruction::TRANSACTION_LEVEL_STACK_HEIGHT
+    }
+
     /// Validate oracle data freshness
     pub fn validate_oracle_freshness(
         oracle_timestamp: i64,
@@ -117,6 +253,21 @@ pub mod error_utils {
         Ok(())
     }
 
+    /// Validate an oracle reading carries a timestamp and is not dated more
+    /// than `max_clock_skew_secs` ahead of the cluster clock
+    pub fn validate_oracle_timestamp(
+        oracle_timestamp: i64,
+        current_timestamp: i64,
+        max_clock_skew_secs: u32,
+    ) -> Result<()> {
+        require!(oracle_timestamp > 0, InsuranceError::OracleTimestampMissing);
+        require!(
+            oracle_timestamp <= current_timestamp.saturating_add(max_clock_skew_secs as i64),
+            InsuranceError::OracleTimestampInFuture
+        );
+        Ok(())
+    }
+
     /// Validate policy eligibility for claims
     pub fn validate_policy_claim_eligibility(
         policy_end_date: i64,
@@ -137,6 +288,31 @@ pub mod error_utils {
     }
 }
 
+/// Time as the program sees it
+pub mod clock_utils {
+    use super::*;
+    use crate::state::MasterInsuranceContract;
+
+    /// Current unix timestamp; the master contract's test clock override
+    /// takes precedence in `test-clock` builds
+    #[cfg_attr(not(feature = "test-clock"), allow(unused_variables))]
+    pub fn now(master_contract: &MasterInsuranceContract) -> Result<i64> {
+        #[cfg(feature = "test-clock")]
+        if let Some(timestamp) = master_contract.clock_override {
+            return Ok(timestamp);
+        }
+        Ok(Clock::get()?.unix_timestamp)
+    }
+
+    /// The clock sysvar with its unix timestamp taken from [`now`]
+    pub fn current_clock(master_contract: &MasterInsuranceContract) -> Result<Clock> {
+        Ok(Clock {
+            unix_timestamp: now(master_contract)?,
+            ..Clock::get()?
+        })
+    }
+}
+
 /// Helper trait for adding context to Results
 pub trait ResultExt<T> {
     fn with_context(self, context: &str) -> Result<T>;
@@ -0,0 +1,240 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("automatic claims", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const keeper = Keypair.generate();
+  let treasury: PublicKey;
+
+  const premiumAmount = new BN(10_000_000);
+  const oracleId = `auto-claim-${Date.now()}`;
+  const feedId = `auto-claim-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  let nonce = 0;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const pendingPayoutPda = (policyId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  /// Policy paying out on the test feed, 20% severe at a reading of 60
+  const compoundPolicy = (coverageAmount: BN) =>
+    createActivePolicy(program, premiumAmount, {
+      coverageAmount,
+      maxPayoutPerIncident: coverageAmount,
+      triggerConditions: {
+        ...defaultPolicyParams(premiumAmount).triggerConditions,
+        compound: {
+          connective: { all: {} },
+          leaves: [{ feedId, operator: { greaterThan: {} }, threshold: 50 }],
+          severityMode: { worst: {} },
+        },
+      },
+    });
+
+  const setAutoClaim = (policy: PublicKey, enabled: boolean, keeperKey: PublicKey) =>
+    program.methods
+      .setAutoClaim(enabled, keeperKey)
+      .accountsPartial({ policyAccount: policy, masterContract, owner: admin, policyTokenAccount: null })
+      .rpc();
+
+  const updateOracle = (timestamp: BN) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(60),
+      timestamp,
+      confidence: new BN(1),
+      nonce: new BN(++nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  /// Trigger the policy's payout with `filer` signing and paying alone
+  const fileAs = async (filer: Keypair, policy: PublicKey) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    const tx = await program.methods
+      .triggerPayout(id, new BN(0), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        rentPayer: filer.publicKey,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+        observationProof: null,
+        regionExposure: null,
+      })
+      .remainingAccounts([{ pubkey: oracle, isSigner: false, isWritable: true }])
+      .transaction();
+    tx.feePayer = filer.publicKey;
+    try {
+      await sendAndConfirmTransaction(provider.connection, tx, [filer], { commitment: "confirmed" });
+    } catch (err) {
+      throw AnchorError.parse((err as { logs?: string[] }).logs ?? []) ?? err;
+    }
+    return pendingPayoutPda(id);
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: keeper.publicKey, lamports: LAMPORTS_PER_SOL / 10 })
+      )
+    );
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+  });
+
+  after(async () => {
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("lets only the designated keeper file once the holder opts in", async () => {
+    const policy = await compoundPolicy(new BN(LAMPORTS_PER_SOL));
+    await updateOracle((await program.account.policy.fetch(policy)).startDate);
+
+    await expectError(fileAs(keeper, policy), "AutoClaimNotEnabled");
+    await setAutoClaim(policy, true, Keypair.generate().publicKey);
+    await expectError(fileAs(keeper, policy), "Unauthorized");
+
+    await setAutoClaim(policy, true, keeper.publicKey);
+    const pendingPayout = await fileAs(keeper, policy);
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.beneficiary.toBase58()).to.equal(admin.toBase58());
+    expect(payout.rentPayer.toBase58()).to.equal(keeper.publicKey.toBase58());
+    expect(payout.amount.toNumber()).to.equal(LAMPORTS_PER_SOL / 5);
+  });
+
+  it("lets anyone file when no keeper is designated, and the holder opt out again", async () => {
+    const policy = await compoundPolicy(new BN(LAMPORTS_PER_SOL));
+    await updateOracle((await program.account.policy.fetch(policy)).startDate);
+    await setAutoClaim(policy, true, PublicKey.default);
+    await setAutoClaim(policy, false, PublicKey.default);
+    await expectError(fileAs(keeper, policy), "AutoClaimNotEnabled");
+
+    await setAutoClaim(policy, true, PublicKey.default);
+    await fileAs(keeper, policy);
+    expect((await program.account.policy.fetch(policy)).status).to.deep.equal({ pendingPayout: {} });
+  });
+
+  it("refunds the keeper's rent in full even when the payout is smaller", async () => {
+    // 20% of 0.01 SOL pays less than the payout account's rent
+    const policy = await compoundPolicy(new BN(LAMPORTS_PER_SOL / 100));
+    await updateOracle((await program.account.policy.fetch(policy)).startDate);
+    await setAutoClaim(policy, true, keeper.publicKey);
+
+    const keeperBefore = await provider.connection.getBalance(keeper.publicKey, "confirmed");
+    const pendingPayout = await fileAs(keeper, policy);
+    const payoutRent = await provider.connection.getBalance(pendingPayout, "confirmed");
+    const { status, amount } = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(amount.toNumber()).to.equal(LAMPORTS_PER_SOL / 500);
+    expect(amount.toNumber()).to.be.lessThan(payoutRent);
+    if ("pendingApproval" in status) {
+      await program.methods.approvePayout().accountsPartial({ pendingPayout, masterContract, admin }).rpc();
+    }
+
+    // Back the payout with real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: amount.toNumber() })
+      )
+    );
+    await program.methods
+      .depositFunds(amount, { sol: {} })
+      .accountsPartial({ treasury, masterContract, depositor: admin })
+      .rpc();
+
+    const beneficiaryBefore = await provider.connection.getBalance(admin, "confirmed");
+    await program.methods
+      .executePayout()
+      .accountsPartial({
+        pendingPayout,
+        policy,
+        masterContract,
+        treasury,
+        beneficiary: admin,
+        rentPayer: keeper.publicKey,
+        executor: admin,
+        vestingPayout: null,
+        regionExposure: null,
+        treasuryUsdcAccount: null,
+        beneficiaryUsdcAccount: null,
+        usdcMint: null,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    // The keeper is out only its transaction fee; the holder gets the whole award
+    const keeperAfter = await provider.connection.getBalance(keeper.publicKey, "confirmed");
+    expect(keeperBefore - keeperAfter).to.equal(5_000);
+    const beneficiaryAfter = await provider.connection.getBalance(admin, "confirmed");
+    expect(beneficiaryAfter - beneficiaryBefore).to.equal(amount.toNumber() - 5_000);
+  });
+});
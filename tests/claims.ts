@@ -0,0 +1,259 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("claims intake", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const typeConfig = typeConfigPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const weatherTolerance = 6 * 3600;
+  const oracleId = `claims-${Date.now()}`;
+  const feedId = `claims-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const setTolerance = async (toleranceSecs: number) => {
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfig);
+    await applyParamChange(
+      program,
+      {
+        typeConfig: {
+          insuranceType: { weather: {} },
+          params: { ...params, incidentToleranceSecs: new BN(toleranceSecs) },
+        },
+      },
+      typeConfig
+    );
+  };
+
+  const pendingPayoutFor = async (policy: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_payout"),
+        (await program.account.policy.fetch(policy)).id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  const updateOracle = (value: number, timestamp: BN, nonce: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(value),
+      timestamp,
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const fileClaim = async (policy: PublicKey, incidentTimestamp: BN) => {
+    const { id } = await program.account.policy.fetch(policy);
+    return program.methods
+      .fileClaim(id, incidentTimestamp)
+      .accountsPartial({
+        policy,
+        masterContract,
+        typeConfig,
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+  };
+
+  const evaluateClaim = async (
+    policy: PublicKey,
+    oracleValue: number,
+    feedOracles: PublicKey[] = [],
+    cranker?: Keypair
+  ) =>
+    program.methods
+      .evaluateClaim(new BN(oracleValue), new BN(0), null)
+      .accountsPartial({
+        pendingPayout: await pendingPayoutFor(policy),
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig,
+        beneficiary: admin,
+        cranker: cranker?.publicKey ?? admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .remainingAccounts(feedOracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .signers(cranker ? [cranker] : [])
+      .rpc();
+
+  const staleClaimPolicy = (stalenessThreshold: number, compound: boolean) =>
+    createActivePolicy(program, premiumAmount, {
+      triggerConditions: {
+        ...defaultPolicyParams(premiumAmount).triggerConditions,
+        compound: compound
+          ? {
+              connective: { all: {} },
+              leaves: [{ feedId, operator: { greaterThan: {} }, threshold: 50 }],
+              severityMode: { worst: {} },
+            }
+          : null,
+      },
+      oracleConfig: {
+        ...defaultPolicyParams(premiumAmount).oracleConfig,
+        stalenessThreshold: new BN(stalenessThreshold),
+      },
+    });
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+    await setTolerance(120);
+  });
+
+  after(async () => {
+    await setTolerance(weatherTolerance);
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("files claims in Pending with no amount", async () => {
+    const policy = await staleClaimPolicy(60, true);
+    const { startDate } = await program.account.policy.fetch(policy);
+    const openBefore = (await program.account.masterInsuranceContract.fetch(masterContract)).openPayoutCount;
+
+    await fileClaim(policy, startDate);
+
+    const payout = await program.account.pendingPayout.fetch(await pendingPayoutFor(policy));
+    expect(payout.status).to.deep.equal({ pending: {} });
+    expect(payout.amount.toNumber()).to.equal(0);
+    expect(payout.incidentTimestamp.toString()).to.equal(startDate.toString());
+    // Evidence deadline: incident tolerance plus the policy's staleness threshold
+    expect(payout.expiresAt.toNumber()).to.equal(startDate.toNumber() + 120 + 60);
+    expect((await program.account.policy.fetch(policy)).status).to.deep.equal({ pendingPayout: {} });
+    const openAfter = (await program.account.masterInsuranceContract.fetch(masterContract)).openPayoutCount;
+    expect(openAfter.toNumber()).to.equal(openBefore.toNumber() + 1);
+
+    // Data just outside the staleness limit leaves the claim pending
+    await updateOracle(120, startDate.subn(90), 1);
+    await expectError(evaluateClaim(policy, 0, [oracle]), "OracleDataStale");
+    expect((await program.account.pendingPayout.fetch(await pendingPayoutFor(policy))).status).to.deep.equal({
+      pending: {},
+    });
+
+    // Data just inside it, observed once cover started, settles the claim;
+    // compound evidence may be cranked by anyone
+    await updateOracle(125, startDate, 2);
+    const cranker = Keypair.generate();
+    await evaluateClaim(policy, 0, [oracle], cranker);
+
+    const settled = await program.account.pendingPayout.fetch(await pendingPayoutFor(policy));
+    expect(settled.status).to.satisfy((status: object) => "ready" in status || "pendingApproval" in status);
+    expect(settled.amount.toNumber()).to.be.greaterThan(0);
+    // Expiry runs from evaluation, not filing
+    expect(settled.expiresAt.toNumber()).to.be.greaterThan(startDate.toNumber() + 24 * 3600 - 60);
+  });
+
+  it("rejects claims whose trigger is not met", async () => {
+    const policy = await staleClaimPolicy(300, false);
+    const { startDate } = await program.account.policy.fetch(policy);
+    await fileClaim(policy, startDate);
+    const pendingPayout = await pendingPayoutFor(policy);
+
+    // A caller-supplied value is only accepted from the beneficiary
+    await expectError(evaluateClaim(policy, 120, [], Keypair.generate()), "Unauthorized");
+
+    await evaluateClaim(policy, 10);
+    expect(await provider.connection.getAccountInfo(pendingPayout)).to.be.null;
+    expect((await program.account.policy.fetch(policy)).status).to.deep.equal({ active: {} });
+  });
+
+  it("refuses claims while the payout queue is full", async () => {
+    // One claim of our own keeps the queue non-empty, so the limit can equal it
+    const first = await createActivePolicy(program, premiumAmount);
+    await fileClaim(first, (await program.account.policy.fetch(first)).startDate);
+    const { globalConfig, openPayoutCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { startDate } = await program.account.policy.fetch(policy);
+
+    await applyParamChange(program, { globalConfig: [{ ...globalConfig, maxOpenPayouts: openPayoutCount }] }, null);
+    try {
+      await expectError(fileClaim(policy, startDate), "PayoutQueueFull");
+    } finally {
+      await applyParamChange(program, { globalConfig: [globalConfig] }, null);
+    }
+
+    await fileClaim(policy, startDate);
+    const { openPayoutCount: openAfter } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(openAfter.toNumber()).to.equal(openPayoutCount.toNumber() + 1);
+  });
+
+  it("rejects claims whose evidence deadline passed", async () => {
+    await setTolerance(1);
+    try {
+      const policy = await staleClaimPolicy(1, false);
+      const { startDate } = await program.account.policy.fetch(policy);
+      await fileClaim(policy, startDate);
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      await evaluateClaim(policy, 120);
+      expect(await provider.connection.getAccountInfo(await pendingPayoutFor(policy))).to.be.null;
+      expect((await program.account.policy.fetch(policy)).status).to.deep.equal({ active: {} });
+    } finally {
+      await setTolerance(120);
+    }
+  });
+});
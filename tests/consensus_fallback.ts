@@ -0,0 +1,220 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+/// Consensus timeout used by this suite, short enough to wait out
+const TIMEOUT_SECS = 2;
+
+describe("consensus fallback", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const suffix = Date.now();
+  const oracleIds = [0, 1, 2, 3].map((i) => `fallback-${i}-${suffix}`);
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle"), Buffer.from(oracleId)],
+        program.programId
+      )[0]
+  );
+  const nonces = [0, 0, 0, 0];
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const eventNames = async (signature: string) => {
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(confirmed!.meta!.logMessages!)].map((e) => e.name);
+  };
+
+  const setFallback = (fallback: object, timeoutSecs = TIMEOUT_SECS) =>
+    program.methods
+      .setConsensusFallback(fallback as any, new BN(timeoutSecs))
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+
+  const update = (i: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleIds[i], {
+      version: 0x10,
+      value: new BN(100 + i),
+      timestamp: new BN(Math.floor(Date.now() / 1000)),
+      confidence: new BN(95),
+      nonce: new BN(++nonces[i]),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle: oracles[i], oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  /// Let every oracle time out, then refresh only the first `fresh` ones
+  const timeOutAllBut = async (fresh: number) => {
+    await sleep((TIMEOUT_SECS + 1) * 1000);
+    for (let i = 0; i < fresh; i++) {
+      await update(i);
+    }
+  };
+
+  const snapshotPda = (round: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus"), round.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const computeConsensus = async () => {
+    const { consensusRound } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const consensusSnapshot = snapshotPda(consensusRound);
+    const signature = await program.methods
+      .computeConsensus()
+      .accountsPartial({ masterContract, consensusSnapshot, payer: admin })
+      .remainingAccounts(oracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc({ commitment: "confirmed" });
+    return { round: consensusRound, consensusSnapshot, signature };
+  };
+
+  const trigger = async (
+    policy: PublicKey,
+    round: BN | null,
+    consensusSnapshot: PublicKey | null,
+    feedOracles: PublicKey[] = []
+  ) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(id, new BN(75), new BN(0), round, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot,
+      })
+      .remainingAccounts(feedOracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc({ commitment: "confirmed" });
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+      await program.methods
+        .setOracleUpdateInterval(new BN(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin })
+        .rpc();
+      await update(i);
+    }
+  });
+
+  after(async () => {
+    await setFallback({ haltPayouts: {} }, 600);
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("halts rounds and triggers while an oracle is timed out", async () => {
+    await setFallback({ haltPayouts: {} });
+    await timeOutAllBut(2);
+
+    await expectError(computeConsensus(), "OracleConsensusFailure");
+
+    const policy = await createActivePolicy(program, premiumAmount);
+    await expectError(trigger(policy, null, null, [oracles[2]]), "OracleConsensusFailure");
+  });
+
+  it("degrades the oracle threshold by one at most", async () => {
+    await setFallback({ degradeThreshold: {} });
+
+    const { consensusSnapshot, signature } = await computeConsensus();
+    const snapshot = await program.account.consensusSnapshot.fetch(consensusSnapshot);
+    expect(snapshot.degraded).to.equal(true);
+    expect(snapshot.consensus.oracleCount).to.equal(2);
+    expect(await eventNames(signature)).to.include("consensusDegraded");
+
+    // Two timed-out oracles exceed what degrading allows
+    await timeOutAllBut(1);
+    await expectError(computeConsensus(), "InsufficientOracles");
+  });
+
+  it("keeps the full threshold when enough oracles are fresh", async () => {
+    await setFallback({ degradeThreshold: {} });
+    await timeOutAllBut(3);
+
+    const { consensusSnapshot, signature } = await computeConsensus();
+    const snapshot = await program.account.consensusSnapshot.fetch(consensusSnapshot);
+    expect(snapshot.degraded).to.equal(false);
+    expect(snapshot.consensus.oracleCount).to.equal(3);
+    expect(await eventNames(signature)).to.not.include("consensusDegraded");
+  });
+
+  it("falls back to the latest round within its max age", async () => {
+    await setFallback({ haltPayouts: {} });
+    const policy = await createActivePolicy(program, premiumAmount, {
+      oracleConfig: {
+        ...defaultPolicyParams(premiumAmount).oracleConfig,
+        stalenessThreshold: new BN(1),
+      },
+    });
+    for (let i = 0; i < oracles.length; i++) {
+      await update(i);
+    }
+    const { round, consensusSnapshot } = await computeConsensus();
+    await sleep(3000);
+
+    await expectError(trigger(policy, round, consensusSnapshot), "ConsensusRoundStale");
+
+    await setFallback({ useLastConsensus: { maxAge: new BN(3600) } });
+    const signature = await trigger(policy, round, consensusSnapshot);
+    expect(await eventNames(signature)).to.include("consensusDegraded");
+  });
+});
@@ -0,0 +1,223 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  currentTreasury,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("consensus rounds", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const suffix = Date.now();
+  const readings = [100, 102, 104];
+  const oracleIds = readings.map((_, i) => `consensus-${i}-${suffix}`);
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle"), Buffer.from(oracleId)],
+        program.programId
+      )[0]
+  );
+
+  const snapshotPda = (round: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus"), round.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const computeConsensus = async (accounts: PublicKey[]) => {
+    const { consensusRound } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const consensusSnapshot = snapshotPda(consensusRound);
+    await program.methods
+      .computeConsensus()
+      .accountsPartial({ masterContract, consensusSnapshot, payer: admin })
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc();
+    return { round: consensusRound, consensusSnapshot };
+  };
+
+  const trigger = async (policy: PublicKey, round: BN, consensusSnapshot: PublicKey) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(id, new BN(0), new BN(0), round, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot,
+      })
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+      const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+        version: 0x10,
+        value: new BN(readings[i]),
+        timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+        confidence: new BN(95),
+        nonce: new BN(1),
+        extensions: Buffer.alloc(0),
+      });
+      await program.methods
+        .updateOracleData(data)
+        .accountsPartial({ oracle: oracles[i], oracleAuthority: admin })
+        .preInstructions([attestation])
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("stores the round with its contributing readings", async () => {
+    const { round, consensusSnapshot } = await computeConsensus(oracles);
+
+    const snapshot = await program.account.consensusSnapshot.fetch(consensusSnapshot);
+    expect(snapshot.round.toString()).to.equal(round.toString());
+    expect(snapshot.oracles.map((key) => key.toBase58())).to.deep.equal(
+      oracles.map((key) => key.toBase58())
+    );
+    expect(snapshot.values.map((value) => value.toNumber())).to.deep.equal(readings);
+    expect(snapshot.consensus.oracleCount).to.equal(readings.length);
+    expect(snapshot.consensus.medianValue.toNumber()).to.equal(102);
+
+    const master = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.consensusRound.toString()).to.equal(round.addn(1).toString());
+  });
+
+  it("rejects an oracle passed twice", async () => {
+    await expectError(
+      computeConsensus([oracles[0], oracles[0], oracles[1]]),
+      "DuplicateOracleAccount"
+    );
+  });
+
+  it("triggers a payout from a referenced round", async () => {
+    // Rounds computed before a policy was bought are no evidence for it
+    const policy = await createActivePolicy(program, new BN(10_000_000));
+    const { round, consensusSnapshot } = await computeConsensus(oracles);
+
+    await expectError(trigger(policy, round.addn(1), consensusSnapshot), "ConsensusRoundMismatch");
+
+    await trigger(policy, round, consensusSnapshot);
+    const { id } = await program.account.policy.fetch(policy);
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(Buffer.from(payout.triggerOracleData).readBigUInt64LE()).to.equal(102n);
+  });
+
+  it("blocks a disputed round until the admin resolves it", async () => {
+    const policy = await createActivePolicy(program, new BN(10_000_000));
+    const { round, consensusSnapshot } = await computeConsensus(oracles);
+
+    await program.methods
+      .disputeConsensus(round, new BN(200))
+      .accountsPartial({ consensusSnapshot, masterContract, oracle: oracles[0], oracleAuthority: admin })
+      .rpc();
+    await expectError(trigger(policy, round, consensusSnapshot), "ConsensusRoundDisputed");
+
+    const resolve = (slashedOracle: PublicKey) =>
+      program.methods
+        .resolveConsensusDispute(round, new BN(103))
+        .accountsPartial({ consensusSnapshot, masterContract, slashedOracle, admin })
+        .rpc();
+
+    // The counter value deviates most from the resolution, so the disputer is slashed
+    await expectError(resolve(oracles[1]), "SlashedOracleMismatch");
+    await resolve(oracles[0]);
+
+    const snapshot = await program.account.consensusSnapshot.fetch(consensusSnapshot);
+    expect(snapshot.disputeStatus).to.deep.equal({ resolved: {} });
+    expect(snapshot.disputingOracle!.toBase58()).to.equal(oracles[0].toBase58());
+    expect(snapshot.resolvedValue!.toNumber()).to.equal(103);
+
+    await trigger(policy, round, consensusSnapshot);
+    const { id } = await program.account.policy.fetch(policy);
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(Buffer.from(payout.triggerOracleData).readBigUInt64LE()).to.equal(103n);
+  });
+
+  it("holds large policies until the dispute window has passed", async () => {
+    const coverage = new BN(100 * LAMPORTS_PER_SOL);
+    const policy = await createActivePolicy(program, new BN(10_000_000), {
+      coverageAmount: coverage,
+      maxPayoutPerIncident: coverage,
+    });
+    const { round, consensusSnapshot } = await computeConsensus(oracles);
+
+    await expectError(trigger(policy, round, consensusSnapshot), "ConsensusRoundNotFinal");
+  });
+
+  it("keeps snapshots until the retention period has passed", async () => {
+    const { round } = await computeConsensus(oracles);
+    const master = await program.account.masterInsuranceContract.fetch(masterContract);
+
+    await expectError(
+      program.methods
+        .closeConsensusSnapshot()
+        .accountsPartial({
+          consensusSnapshot: snapshotPda(round),
+          masterContract,
+          rentCollector: master.consensusRentCollector,
+          cranker: admin,
+        })
+        .rpc(),
+      "ConsensusSnapshotRetained"
+    );
+  });
+});
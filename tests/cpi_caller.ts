@@ -0,0 +1,89 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { CpiCaller } from "../target/types/cpi_caller";
+import {
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyHistoryPda,
+  policyPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("cpi_caller", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const siglab = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const caller = anchor.workspace.cpiCaller as Program<CpiCaller>;
+  const user = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(siglab);
+  const [vault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), user.toBuffer()],
+    caller.programId
+  );
+
+  const premiumAmount = new BN(10_000_000);
+  let policyAccount: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    await ensureMasterContract(siglab);
+    treasury = await ensureTreasury(siglab);
+
+    // Fund the vault PDA that will act as policyholder
+    const tx = new anchor.web3.Transaction().add(
+      SystemProgram.transfer({
+        fromPubkey: user,
+        toPubkey: vault,
+        lamports: LAMPORTS_PER_SOL,
+      })
+    );
+    await provider.sendAndConfirm(tx);
+  });
+
+  it("creates a policy with a PDA policyholder via CPI", async () => {
+    const master = await siglab.account.masterInsuranceContract.fetch(masterContract);
+    policyAccount = policyPda(siglab, vault, master.policiesIssued);
+
+    await caller.methods
+      .createPolicyViaCpi(defaultPolicyParams(premiumAmount))
+      .accountsPartial({
+        user,
+        vault,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(siglab),
+        siglabProgram: siglab.programId,
+      })
+      .rpc();
+
+    const policy = await siglab.account.policy.fetch(policyAccount);
+    expect(policy.user.toBase58()).to.equal(vault.toBase58());
+    expect(policy.status).to.deep.equal({ pendingActivation: {} });
+  });
+
+  it("pays the first premium via CPI and activates the policy", async () => {
+    await caller.methods
+      .payPremiumViaCpi(premiumAmount)
+      .accountsPartial({
+        user,
+        vault,
+        masterContract,
+        policyAccount,
+        policyHistory: policyHistoryPda(siglab, policyAccount),
+        treasury,
+        siglabProgram: siglab.programId,
+      })
+      .rpc();
+
+    const policy = await siglab.account.policy.fetch(policyAccount);
+    expect(policy.status).to.deep.equal({ active: {} });
+  });
+});
@@ -0,0 +1,197 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  LAMPORTS_PER_SOL,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("crank tips", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const tip = 1_000_000;
+  const premiumAmount = new BN(10_000_000);
+  const cranker = Keypair.generate();
+  let treasury: PublicKey;
+
+  const setCrankTip = (tipLamports: number, budgetLamports: number) =>
+    program.methods
+      .setCrankTip(new BN(tipLamports), new BN(budgetLamports))
+      .accountsPartial({ treasury, masterContract, admin })
+      .rpc();
+
+  const pendingPayoutPda = (policyId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  /// Trigger a payout and approve it if needed, leaving it ready to execute
+  const readyPayout = async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+
+    const pendingPayout = pendingPayoutPda(id);
+    const { status, amount } = await program.account.pendingPayout.fetch(pendingPayout);
+    if ("pendingApproval" in status) {
+      await program.methods
+        .approvePayout()
+        .accountsPartial({ pendingPayout, masterContract, admin })
+        .rpc();
+    }
+    return { policy, pendingPayout, amount: amount.toNumber() };
+  };
+
+  /// Execute with `executor` paying the fee, returning the balance changes
+  const execute = async (executor: Keypair | null) => {
+    const { policy, pendingPayout, amount } = await readyPayout();
+    const payoutRent = await provider.connection.getBalance(pendingPayout);
+    const executorKey = executor ? executor.publicKey : admin;
+    const beneficiaryBefore = await provider.connection.getBalance(admin);
+    const executorBefore = await provider.connection.getBalance(executorKey);
+
+    const builder = program.methods
+      .executePayout()
+      .accountsPartial({
+        pendingPayout,
+        policy,
+        masterContract,
+        treasury,
+        beneficiary: admin,
+        executor: executorKey,
+        vestingPayout: null,
+        treasuryUsdcAccount: null,
+        beneficiaryUsdcAccount: null,
+        usdcMint: null,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      });
+    let signature: string;
+    if (executor) {
+      const tx = await builder.transaction();
+      tx.feePayer = executor.publicKey;
+      signature = await sendAndConfirmTransaction(provider.connection, tx, [executor], { commitment: "confirmed" });
+    } else {
+      signature = await builder.rpc({ commitment: "confirmed" });
+    }
+
+    return {
+      signature,
+      pendingPayout,
+      amount,
+      payoutRent,
+      beneficiaryGain: (await provider.connection.getBalance(admin, "confirmed")) - beneficiaryBefore,
+      executorGain: (await provider.connection.getBalance(executorKey, "confirmed")) - executorBefore,
+    };
+  };
+
+  /// The PayoutExecuted event of a confirmed transaction
+  const payoutExecuted = async (signature: string) => {
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(confirmed!.meta!.logMessages!)].find((e) => e.name === "payoutExecuted")!.data;
+  };
+
+  const crankBudget = async () =>
+    (await program.account.treasury.fetch(treasury)).crankBudgetLamports.toNumber();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+
+    // Back the tracked treasury balance with real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: 2 * LAMPORTS_PER_SOL }),
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: cranker.publicKey, lamports: LAMPORTS_PER_SOL / 10 })
+      )
+    );
+    await program.methods
+      .depositFunds(new BN(2 * LAMPORTS_PER_SOL), { sol: {} })
+      .accountsPartial({ treasury, depositor: admin })
+      .rpc();
+  });
+
+  after(async () => {
+    await setCrankTip(0, 0);
+  });
+
+  it("caps the tip", async () => {
+    try {
+      await setCrankTip(5_000_001, 0);
+      expect.fail("accepted a tip above the cap");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidInput");
+    }
+  });
+
+  it("pays no tip when the beneficiary executes", async () => {
+    await setCrankTip(tip, tip);
+
+    const { amount, payoutRent, beneficiaryGain } = await execute(null);
+    expect(beneficiaryGain).to.equal(amount + payoutRent - 5_000);
+    expect(await crankBudget()).to.equal(tip);
+  });
+
+  it("tips a third-party executor on top of the payout", async () => {
+    const { signature, pendingPayout, amount, payoutRent, beneficiaryGain, executorGain } = await execute(cranker);
+
+    expect(beneficiaryGain).to.equal(amount + payoutRent);
+    expect(executorGain).to.equal(tip - 5_000);
+    expect(await crankBudget()).to.equal(0);
+
+    // The event snapshots the treasury around both the payout and the tip
+    const event = await payoutExecuted(signature);
+    const after = await program.account.treasury.fetch(treasury, "confirmed");
+    expect(event.pendingPayout.toBase58()).to.equal(pendingPayout.toBase58());
+    expect(event.treasuryBalanceBefore.sub(event.treasuryBalanceAfter).toNumber()).to.equal(amount + tip);
+    expect(event.treasuryBalanceAfter.toString()).to.equal(after.totalSolBalance.toString());
+    expect(event.reserveRatioAfter).to.equal(after.currentReserveRatio);
+  });
+
+  it("executes untipped once the budget is exhausted", async () => {
+    const { amount, payoutRent, beneficiaryGain, executorGain } = await execute(cranker);
+
+    expect(beneficiaryGain).to.equal(amount + payoutRent);
+    expect(executorGain).to.equal(-5_000);
+    expect(await crankBudget()).to.equal(0);
+  });
+});
@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  ensureMasterContract,
+  ensureTreasury,
+  solAmount,
+  UNCAPPED_EXPOSURE,
+} from "./helpers";
+
+describe("policy currencies", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const premiumAmount = new BN(10_000_000);
+
+  const globalConfig = (maxSolExposure: BN) => ({
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps: 0,
+    maxSolExposure,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  after(async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(UNCAPPED_EXPOSURE)] }, null);
+  });
+
+  it("tracks SOL coverage against the SOL exposure bucket", async () => {
+    const before = await program.account.treasury.fetch(treasury);
+    const policy = await program.account.policy.fetch(await createActivePolicy(program, premiumAmount));
+
+    const after = await program.account.treasury.fetch(treasury);
+    expect(policy.premiumCurrency).to.deep.equal({ sol: {} });
+    expect(policy.payoutCurrency).to.deep.equal({ sol: {} });
+    expect(after.solCoverageExposure.sub(before.solCoverageExposure).toString()).to.equal(
+      policy.coverageAmount.toString()
+    );
+    expect(after.usdcCoverageExposure.toString()).to.equal(before.usdcCoverageExposure.toString());
+  });
+
+  it("rejects USDC payouts until the treasury has a USDC account", async () => {
+    const { usdcTokenAccount } = await program.account.treasury.fetch(treasury);
+    if (!usdcTokenAccount.equals(PublicKey.default)) {
+      return;
+    }
+    await expectError(
+      createActivePolicy(program, premiumAmount, { payoutCurrency: { usdc: {} } }),
+      "UnsupportedCurrency"
+    );
+  });
+
+  it("rejects coverage beyond the currency's exposure limit", async () => {
+    const { solCoverageExposure } = await program.account.treasury.fetch(treasury);
+    await applyParamChange(program, { globalConfig: [globalConfig(solCoverageExposure)] }, null);
+
+    await expectError(createActivePolicy(program, premiumAmount), "CurrencyExposureLimitExceeded");
+  });
+});
@@ -0,0 +1,174 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  policyPda,
+  registeredOracles,
+  signOracleData,
+  solAmount,
+  typeConfigPda,
+  UNCAPPED_EXPOSURE,
+  walletKeypair,
+} from "./helpers";
+
+/// $150 per SOL, with 6 decimals
+const SOL_PRICE = 150_000_000;
+
+describe("currency normalization", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const oracleId = `sol-usd-${Date.now()}`;
+  const [priceOracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  // $1.50 is 0.01 SOL at $150
+  const usdMinimum = { amount: new BN(1_500_000), currency: { usdc: {} } as { sol: {} } | { usdc: {} } };
+  const lamportMinimum = new BN(LAMPORTS_PER_SOL / 100);
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const globalConfig = (minPremium: typeof usdMinimum) => ({
+    minPremium,
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps: 0,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+
+  /// Buy and pay for a half-SOL SOL policy, passing the price oracle unless told not to
+  const buy = async (premiumAmount: BN, withPrice = true) => {
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policyAccount = policyPda(program, admin, policiesIssued);
+    const remaining = withPrice ? [{ pubkey: priceOracle, isSigner: false, isWritable: false }] : [];
+
+    // A small policy keeps the type's own premium floor below the global one
+    await program.methods
+      .createPolicy({
+        ...defaultPolicyParams(premiumAmount),
+        coverageAmount: new BN(LAMPORTS_PER_SOL / 2),
+        maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL / 2),
+      })
+      .accountsPartial({
+        policyHolder: admin,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+      })
+      .remainingAccounts(remaining)
+      .rpc();
+    await program.methods
+      .payPremium(premiumAmount)
+      .accountsPartial({
+        payer: admin,
+        policyAccount,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury,
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .remainingAccounts(remaining)
+      .rpc();
+    return policyAccount;
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "SOL/USD", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle: priceOracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(SOL_PRICE),
+      timestamp: new BN(Math.floor(Date.now() / 1000)),
+      confidence: new BN(95),
+      nonce: new BN(1),
+      extensions: Buffer.alloc(0),
+    });
+    await program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle: priceOracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+    await applyParamChange(program, { globalConfig: [globalConfig(usdMinimum)] }, null);
+  });
+
+  after(async () => {
+    await applyParamChange(
+      program,
+      { globalConfig: [{ ...globalConfig(solAmount(new BN(1_000_000))), priceOracle: PublicKey.default }] },
+      null
+    );
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({
+        oracle: priceOracle,
+        oracleRetirement: oracleRetirementPda(program, priceOracle),
+        masterContract,
+        admin,
+      })
+      .rpc();
+  });
+
+  it("prices a USD minimum premium in lamports at the oracle price", async () => {
+    await expectError(buy(lamportMinimum.subn(1)), "InsufficientPremium");
+    await buy(lamportMinimum);
+  });
+
+  it("requires the price oracle to convert", async () => {
+    await expectError(buy(lamportMinimum, false), "PriceUnavailable");
+  });
+
+  it("accepts the same premiums as the equivalent lamport minimum", async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(solAmount(lamportMinimum))] }, null);
+
+    // No conversion, so no price needed
+    await expectError(buy(lamportMinimum.subn(1), false), "InsufficientPremium");
+    await buy(lamportMinimum, false);
+  });
+});
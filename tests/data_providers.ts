@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  dataProviderPda,
+  ensureMasterContract,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+describe("data providers", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  // Provider ids are seeds, so each run registers fresh ones
+  const suffix = Date.now();
+  const [noaa, ecmwf] = [0, 1].map((i) => (suffix % 1_000_000_000) * 2 + i);
+  const readings = [100, 100, 130];
+  const oracleIds = readings.map((_, i) => `provider-${i}-${suffix}`);
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync([Buffer.from("oracle"), Buffer.from(oracleId)], program.programId)[0]
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const registerProvider = (providerId: number, name: string) =>
+    program.methods
+      .registerDataProvider(providerId, name)
+      .accountsPartial({ dataProvider: dataProviderPda(program, providerId), masterContract, admin })
+      .rpc({ commitment: "confirmed" });
+
+  const attribute = (oracle: PublicKey, providerId: number | null) =>
+    program.methods
+      .setOracleProvider()
+      .accountsPartial({
+        oracle,
+        dataProvider: providerId === null ? null : dataProviderPda(program, providerId),
+        masterContract,
+        admin,
+      })
+      .rpc();
+
+  const computeConsensus = async () => {
+    const { consensusRound } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const [consensusSnapshot] = PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus"), consensusRound.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .computeConsensus()
+      .accountsPartial({ masterContract, consensusSnapshot, payer: admin })
+      .remainingAccounts(oracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc();
+    return consensusSnapshot;
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+      const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+        version: 0x10,
+        value: new BN(readings[i]),
+        timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+        confidence: new BN(95),
+        nonce: new BN(1),
+        extensions: Buffer.alloc(0),
+      });
+      await program.methods
+        .updateOracleData(data)
+        .accountsPartial({ oracle: oracles[i], oracleAuthority: admin })
+        .preInstructions([attestation])
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("registers providers by id", async () => {
+    await expectError(registerProvider(noaa, ""), "InvalidDataProviderName");
+    await expectError(registerProvider(noaa, "n".repeat(33)), "InvalidDataProviderName");
+
+    const signature = await registerProvider(noaa, "NOAA");
+    await registerProvider(ecmwf, "ECMWF");
+
+    const state = await program.account.dataProvider.fetch(dataProviderPda(program, noaa));
+    expect(state.providerId).to.equal(noaa);
+    expect(state.name).to.equal("NOAA");
+
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    const registered = [...parser.parseLogs(confirmed!.meta!.logMessages!)].find(
+      (e) => e.name === "dataProviderRegistered"
+    )!;
+    expect(registered.data.providerId).to.equal(noaa);
+  });
+
+  it("fails the quorum when enough oracles relay too few providers", async () => {
+    // Three fresh readings, but only two independent sources against a threshold of three
+    await attribute(oracles[0], noaa);
+    await attribute(oracles[1], noaa);
+    await attribute(oracles[2], ecmwf);
+    const oracle = await program.account.oracle.fetch(oracles[1]);
+    expect(oracle.provider.toBase58()).to.equal(dataProviderPda(program, noaa).toBase58());
+
+    await expectError(computeConsensus(), "ProviderQuorumNotMet");
+  });
+
+  it("counts oracles without a provider on their own", async () => {
+    await attribute(oracles[1], null);
+
+    const snapshot = await program.account.consensusSnapshot.fetch(await computeConsensus());
+    expect(snapshot.consensus.oracleCount).to.equal(3);
+    expect(snapshot.consensus.aggregatedValue.toNumber()).to.equal(110);
+  });
+});
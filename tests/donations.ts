@@ -0,0 +1,100 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("treasury donations", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const donor = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const amount = new BN(5_000_000);
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const donate = (tokenType: object, memo: string | null) =>
+    program.methods
+      .donateToTreasury(amount, tokenType as any, memo)
+      .accountsPartial({
+        treasury,
+        masterContract,
+        donor,
+        donorTokenAccount: null,
+        treasuryUsdcAccount: null,
+        tokenProgram: null,
+      });
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("moves SOL into the treasury and counts it toward reserves", async () => {
+    const before = await program.account.treasury.fetch(treasury);
+    const lamportsBefore = await provider.connection.getBalance(treasury);
+
+    const signature = await donate({ sol: {} }, "for the flood fund").rpc({ commitment: "confirmed" });
+
+    const after = await program.account.treasury.fetch(treasury);
+    expect((await provider.connection.getBalance(treasury, "confirmed")) - lamportsBefore).to.equal(amount.toNumber());
+    expect(after.totalDonationsSol.sub(before.totalDonationsSol).toString()).to.equal(amount.toString());
+    expect(after.totalSolBalance.sub(before.totalSolBalance).toString()).to.equal(amount.toString());
+    // Donations are not deposits
+    expect(after.depositCount.toString()).to.equal(before.depositCount.toString());
+
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    const donated = [...parser.parseLogs(confirmed!.meta!.logMessages!)].find((e) => e.name === "treasuryDonated")!;
+    expect(donated.data.donor.toBase58()).to.equal(donor.toBase58());
+    expect(donated.data.memo).to.equal("for the flood fund");
+    expect(donated.data.totalDonations.toString()).to.equal(after.totalDonationsSol.toString());
+  });
+
+  it("rejects memos longer than 64 bytes", async () => {
+    await expectError(donate({ sol: {} }, "x".repeat(65)).rpc(), "InvalidInput");
+  });
+
+  it("rejects USDC until the treasury has a USDC account", async () => {
+    const { usdcTokenAccount } = await program.account.treasury.fetch(treasury);
+    if (!usdcTokenAccount.equals(PublicKey.default)) {
+      return;
+    }
+    await expectError(donate({ usdc: {} }, null).rpc(), "UnsupportedCurrency");
+  });
+
+  it("books unexplained lamports as donations on reconcile", async () => {
+    await program.methods.updateTreasuryBalance().accountsPartial({ treasury, masterContract }).rpc();
+    const before = await program.account.treasury.fetch(treasury);
+
+    // Premiums are bookkept without lamports, so top up any shortfall first
+    const info = (await provider.connection.getAccountInfo(treasury))!;
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(info.data.length);
+    const shortfall = Math.max(0, before.totalSolBalance.toNumber() - (info.lamports - rent));
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: donor, toPubkey: treasury, lamports: shortfall + 1_000_000 })
+      )
+    );
+    await program.methods.updateTreasuryBalance().accountsPartial({ treasury, masterContract }).rpc();
+
+    const after = await program.account.treasury.fetch(treasury);
+    expect(after.totalDonationsSol.sub(before.totalDonationsSol).toNumber()).to.equal(1_000_000);
+    expect(after.totalSolBalance.sub(before.totalSolBalance).toNumber()).to.equal(1_000_000);
+  });
+});
@@ -0,0 +1,102 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyHistoryPda,
+} from "./helpers";
+
+describe("policy endorsements", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const holder = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  const step = new BN(LAMPORTS_PER_SOL / 20);
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const reduceCoverage = async (policyAccount: PublicKey) => {
+    const { coverageAmount } = await program.account.policy.fetch(policyAccount);
+    return program.methods
+      .adjustCoverage(coverageAmount.sub(step), new BN(0))
+      .accountsPartial({ policyAccount, masterContract, treasury, owner: holder, policyTokenAccount: null })
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("records coverage adjustments with before and after amounts", async () => {
+    const policyAccount = await createActivePolicy(program, premiumAmount, {
+      maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL / 4),
+    });
+    const before = await program.account.policy.fetch(policyAccount);
+    await reduceCoverage(policyAccount);
+
+    const policy = await program.account.policy.fetch(policyAccount);
+    const { endorsements } = await program.account.policyHistory.fetch(policyHistoryPda(program, policyAccount));
+    expect(endorsements).to.have.length(1);
+    const [endorsement] = endorsements;
+    expect(endorsement.kind).to.deep.equal({ coverageAdjustment: {} });
+    expect(new BN(endorsement.before.slice(0, 8), "le").toString()).to.equal(before.coverageAmount.toString());
+    expect(new BN(endorsement.after.slice(0, 8), "le").toString()).to.equal(policy.coverageAmount.toString());
+    expect(endorsement.actor.toBase58()).to.equal(holder.toBase58());
+  });
+
+  it("rejects changes once eight endorsements are recorded", async () => {
+    const policyAccount = await createActivePolicy(program, premiumAmount, {
+      maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL / 4),
+    });
+    for (let i = 0; i < 8; i++) {
+      await reduceCoverage(policyAccount);
+    }
+
+    await expectError(reduceCoverage(policyAccount), "EndorsementLimitReached");
+    const history = await program.account.policyHistory.fetch(policyHistoryPda(program, policyAccount));
+    expect(history.endorsements).to.have.length(8);
+  });
+
+  it("records ownership transfers", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const newOwner = Keypair.generate();
+    const [transferProposal] = PublicKey.findProgramAddressSync(
+      [Buffer.from("policy_transfer"), policy.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .proposePolicyTransfer(newOwner.publicKey)
+      .accountsPartial({ policy, transferProposal, masterContract, currentOwner: holder })
+      .rpc();
+    await program.methods
+      .acceptPolicyTransfer()
+      .accountsPartial({ policy, transferProposal, proposer: holder, masterContract, newOwner: newOwner.publicKey })
+      .signers([newOwner])
+      .rpc();
+
+    const [endorsement] = (await program.account.policyHistory.fetch(policyHistoryPda(program, policy))).endorsements;
+    expect(endorsement.kind).to.deep.equal({ ownershipTransfer: {} });
+    expect(new PublicKey(endorsement.before).toBase58()).to.equal(holder.toBase58());
+    expect(new PublicKey(endorsement.after).toBase58()).to.equal(newOwner.publicKey.toBase58());
+    expect(endorsement.actor.toBase58()).to.equal(newOwner.publicKey.toBase58());
+  });
+});
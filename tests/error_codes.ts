@@ -0,0 +1,47 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, ensureTreasury } from "./helpers";
+
+describe("error codes", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+
+  // Compare case-insensitively: the IDL may camelCase error names
+  const codeOf = (name: string) =>
+    program.idl.errors!.find((error) => error.name.toLowerCase() === name.toLowerCase())!.code;
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  it("keeps existing codes stable and appends the specific variants", () => {
+    expect(codeOf("InvalidAdminOperation")).to.equal(6036);
+    expect(codeOf("CpiNotAllowed")).to.equal(6047);
+    expect(codeOf("TreasuryNotLinked")).to.equal(6048);
+    expect(codeOf("TreasuryAccountMismatch")).to.equal(6049);
+    expect(codeOf("InvalidRecipient")).to.equal(6050);
+    expect(codeOf("PayoutQueueFull")).to.equal(6051);
+  });
+
+  it("surfaces InvalidRecipient when withdrawing to the treasury itself", async () => {
+    const treasury = await ensureTreasury(program);
+
+    try {
+      await program.methods
+        .withdrawTreasury(new BN(1), { sol: {} })
+        .accountsPartial({ admin, treasury, recipient: treasury, recipientUsdcAccount: null })
+        .rpc();
+      expect.fail("withdrawal to the treasury succeeded");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      const { errorCode } = (err as AnchorError).error;
+      expect(errorCode.code).to.equal("InvalidRecipient");
+      expect(errorCode.number).to.equal(codeOf("InvalidRecipient"));
+    }
+  });
+});
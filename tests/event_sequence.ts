@@ -0,0 +1,106 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, EventParser } from "@coral-xyz/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("event sequence", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const holder = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  let policyAccount: PublicKey;
+
+  const discriminatorOf = (name: string) =>
+    program.idl.instructions.find((ix) => ix.name === name)!.discriminator;
+
+  before(async () => {
+    await ensureMasterContract(program);
+    const treasury = await ensureTreasury(program);
+    const master = await program.account.masterInsuranceContract.fetch(masterContract);
+    policyAccount = policyPda(program, holder, master.policiesIssued);
+
+    await program.methods
+      .createPolicy(defaultPolicyParams(premiumAmount))
+      .accountsPartial({
+        policyHolder: holder,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+      })
+      .rpc();
+  });
+
+  it("emits strictly increasing sequences within one transaction", async () => {
+    const before = await program.account.masterInsuranceContract.fetch(masterContract);
+
+    const tx = new Transaction().add(
+      await program.methods
+        .payPremium(premiumAmount)
+        .accountsPartial({
+          payer: holder,
+          policyAccount,
+          masterContract,
+          policyTokenAccount: null,
+          subsidy: null,
+          treasury: await currentTreasury(program),
+          payerTokenAccount: null,
+          treasuryUsdcAccount: null,
+          feeVault: null,
+          feeVaultUsdcAccount: null,
+          underwriterLedger: null,
+          tokenProgram: null,
+        })
+        .instruction(),
+      await program.methods
+        .updatePolicyMetadata("{\"v\":1}", null)
+        .accountsPartial({ policyAccount, masterContract, owner: holder, policyTokenAccount: null })
+        .instruction(),
+      await program.methods
+        .updatePolicyMetadata("{\"v\":2}", "ipfs://example")
+        .accountsPartial({ policyAccount, masterContract, owner: holder, policyTokenAccount: null })
+        .instruction()
+    );
+    const signature = await provider.sendAndConfirm(tx, [], { commitment: "confirmed" });
+
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    const events = [...parser.parseLogs(confirmed!.meta!.logMessages!)];
+
+    expect(events.map((e) => e.name)).to.deep.equal([
+      "policyActivated",
+      "premiumPaid",
+      "policyMetadataUpdated",
+      "policyMetadataUpdated",
+    ]);
+
+    const sequences = events.map((e) => (e.data.sequence as BN).toNumber());
+    const first = before.eventSequence.toNumber() + 1;
+    expect(sequences).to.deep.equal([first, first + 1, first + 2, first + 3]);
+
+    expect(events[0].data.instruction).to.deep.equal(discriminatorOf("payPremium"));
+    expect(events[1].data.instruction).to.deep.equal(discriminatorOf("payPremium"));
+    expect(events[2].data.instruction).to.deep.equal(discriminatorOf("updatePolicyMetadata"));
+
+    const after = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(after.eventSequence.toNumber()).to.equal(first + 3);
+  });
+});
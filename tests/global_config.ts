@@ -0,0 +1,149 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  ensureMasterContract,
+  masterContractPda,
+  solAmount,
+  UNCAPPED_EXPOSURE,
+} from "./helpers";
+
+describe("global configuration", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const defaults = {
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps: 0,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null as ReturnType<typeof solAmount> | null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const setGlobalConfig = (config: typeof defaults) => applyParamChange(program, { globalConfig: [config] }, null);
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  after(async () => {
+    await setGlobalConfig(defaults);
+  });
+
+  it("starts from the program defaults", async () => {
+    const { globalConfig } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(globalConfig.minPremium.amount.eq(defaults.minPremium.amount)).to.be.true;
+    expect(globalConfig.minPremium.currency).to.deep.equal({ sol: {} });
+    expect(globalConfig.maxCoverage.amount.eq(defaults.maxCoverage.amount)).to.be.true;
+    expect(globalConfig.maxPolicyDurationDays).to.equal(365);
+    expect(globalConfig.lateFeeBps).to.equal(0);
+    expect(globalConfig.maxSolExposure.eq(UNCAPPED_EXPOSURE)).to.be.true;
+    expect(globalConfig.maxUsdcExposure.eq(UNCAPPED_EXPOSURE)).to.be.true;
+    expect(globalConfig.minPayout.amount.toNumber()).to.equal(0);
+    expect(globalConfig.priceOracle.equals(PublicKey.default)).to.be.true;
+    expect(globalConfig.maxOpenPayouts.eq(DEFAULT_MAX_OPEN_PAYOUTS)).to.be.true;
+    expect(globalConfig.cosignCoverageThreshold).to.be.null;
+    expect(globalConfig.underwritingBondBps).to.equal(0);
+    expect(globalConfig.bondMaxLossRatioBps).to.equal(DEFAULT_BOND_MAX_LOSS_RATIO_BPS);
+    expect(globalConfig.outageRefundThresholdBps).to.equal(DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS);
+  });
+
+  it("rejects a zero minimum premium", async () => {
+    await expectError(setGlobalConfig({ ...defaults, minPremium: solAmount(new BN(0)) }), "InvalidInput");
+  });
+
+  it("rejects a coverage limit above the program ceiling", async () => {
+    await expectError(
+      setGlobalConfig({ ...defaults, maxCoverage: solAmount(new BN(1_000_001).mul(new BN(LAMPORTS_PER_SOL)) })),
+      "InvalidInput"
+    );
+  });
+
+  it("rejects a late fee above 10% per day", async () => {
+    await expectError(setGlobalConfig({ ...defaults, lateFeeBps: 1_001 }), "InvalidInput");
+  });
+
+  it("rejects a zero limit on open payouts", async () => {
+    await expectError(setGlobalConfig({ ...defaults, maxOpenPayouts: new BN(0) }), "InvalidInput");
+  });
+
+  it("rejects an underwriting bond above the coverage", async () => {
+    await expectError(setGlobalConfig({ ...defaults, underwritingBondBps: 10_001 }), "InvalidInput");
+  });
+
+  it("rejects an outage refund threshold above the whole term", async () => {
+    await expectError(setGlobalConfig({ ...defaults, outageRefundThresholdBps: 10_001 }), "InvalidInput");
+  });
+
+  it("enforces updated limits on new policies", async () => {
+    await setGlobalConfig({
+      minPremium: solAmount(new BN(20_000_000)),
+      maxCoverage: solAmount(new BN(LAMPORTS_PER_SOL / 2)),
+      maxPolicyDurationDays: 10,
+      lateFeeBps: 0,
+      maxSolExposure: UNCAPPED_EXPOSURE,
+      maxUsdcExposure: UNCAPPED_EXPOSURE,
+      minPayout: solAmount(new BN(0)),
+      priceOracle: PublicKey.default,
+      maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+      cosignCoverageThreshold: null,
+      underwritingBondBps: 0,
+      bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+      outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+    });
+
+    await expectError(createActivePolicy(program, new BN(30_000_000)), "CoverageExceedsMaximum");
+    await expectError(
+      createActivePolicy(program, new BN(30_000_000), { coverageAmount: new BN(LAMPORTS_PER_SOL / 2) }),
+      "InvalidParameters"
+    );
+    await expectError(
+      createActivePolicy(program, premiumAmount, {
+        coverageAmount: new BN(LAMPORTS_PER_SOL / 2),
+        policyDurationDays: 10,
+      }),
+      "InsufficientPremium"
+    );
+    await createActivePolicy(program, new BN(30_000_000), {
+      coverageAmount: new BN(LAMPORTS_PER_SOL / 2),
+      maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL / 2),
+      policyDurationDays: 10,
+    });
+  });
+
+  it("allows raising the coverage limit above the old default", async () => {
+    await setGlobalConfig({ ...defaults, maxCoverage: solAmount(new BN(2_000 * LAMPORTS_PER_SOL)) });
+
+    const { globalConfig } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(globalConfig.maxCoverage.amount.eq(new BN(2_000 * LAMPORTS_PER_SOL))).to.be.true;
+  });
+});
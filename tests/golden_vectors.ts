@@ -0,0 +1,37 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, currentTreasury, defaultPolicyParams, masterContractPda } from "./helpers";
+import goldenVectors from "./fixtures/golden_vectors.json";
+
+/// The same vectors are checked against siglab-core in core/tests/golden_vectors.rs
+describe("golden vectors", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  for (const vector of goldenVectors) {
+    it(vector.name, async () => {
+      const policy = await createActivePolicy(program, premiumAmount, {
+        coverageAmount: new BN(vector.coverage_amount),
+        deductible: new BN(vector.deductible),
+        maxPayoutPerIncident: new BN(vector.max_payout),
+        triggerConditions: {
+          ...defaultPolicyParams(premiumAmount).triggerConditions,
+          thresholdValue: vector.threshold,
+        },
+      });
+
+      const quote = await program.methods
+        .simulatePayout(new BN(vector.oracle_value))
+        .accountsPartial({ policy, masterContract, treasury: await currentTreasury(program), breachWatch: null })
+        .view();
+
+      expect(quote.severityPercentage).to.equal(vector.severity_percentage);
+      expect(quote.amount.toString()).to.equal(new BN(vector.amount).toString());
+    });
+  }
+});
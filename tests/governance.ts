@@ -0,0 +1,98 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { DummyGovernance } from "../target/types/dummy_governance";
+import { ensureMasterContract, masterContractPda } from "./helpers";
+
+describe("governance authority", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const siglab = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const dao = anchor.workspace.dummyGovernance as Program<DummyGovernance>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(siglab);
+  const [governance] = PublicKey.findProgramAddressSync([Buffer.from("governance")], dao.programId);
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  // Run a siglab instruction through the governance program, which signs for its PDA
+  const execute = (ix: TransactionInstruction) =>
+    dao.methods
+      .execute(ix.data)
+      .accountsPartial({ governance, targetProgram: siglab.programId })
+      .remainingAccounts(ix.keys.map(({ pubkey, isWritable }) => ({ pubkey, isSigner: false, isWritable })))
+      .rpc();
+
+  const accept = (newAdmin: PublicKey, governanceProgram: PublicKey | null) =>
+    siglab.methods.acceptAuthority().accountsPartial({ masterContract, newAdmin, governanceProgram });
+
+  before(async () => {
+    await ensureMasterContract(siglab);
+  });
+
+  after(async () => {
+    // Hand the authority back to the wallet for later suites
+    await execute(
+      await siglab.methods
+        .transferAuthority()
+        .accountsPartial({ masterContract, currentAdmin: governance, newAdmin: admin })
+        .instruction()
+    );
+    await accept(admin, null).rpc();
+
+    const master = await siglab.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.authority.toBase58()).to.equal(admin.toBase58());
+    expect(master.governanceProgram).to.equal(null);
+  });
+
+  it("nominates the governance PDA without its signature", async () => {
+    await siglab.methods
+      .transferAuthority()
+      .accountsPartial({ masterContract, currentAdmin: admin, newAdmin: governance })
+      .rpc();
+
+    const master = await siglab.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.authority.toBase58()).to.equal(admin.toBase58());
+    expect(master.pendingAuthority!.toBase58()).to.equal(governance.toBase58());
+  });
+
+  it("accepts through the governance program via CPI", async () => {
+    // Without naming its program, the PDA is treated like a wallet and must not come via CPI
+    await expectError(execute(await accept(governance, null).instruction()), "CpiNotAllowed");
+
+    await execute(await accept(governance, dao.programId).instruction());
+
+    const master = await siglab.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.authority.toBase58()).to.equal(governance.toBase58());
+    expect(master.pendingAuthority).to.equal(null);
+    expect(master.governanceProgram!.toBase58()).to.equal(dao.programId.toBase58());
+  });
+
+  it("pauses and resumes the contract through a governance CPI", async () => {
+    await expectError(
+      siglab.methods.pauseContract(false).accountsPartial({ masterContract, admin }).rpc(),
+      "Unauthorized"
+    );
+
+    await execute(
+      await siglab.methods.pauseContract(false).accountsPartial({ masterContract, admin: governance }).instruction()
+    );
+    expect((await siglab.account.masterInsuranceContract.fetch(masterContract)).isPaused).to.equal(true);
+
+    await execute(
+      await siglab.methods.resumeContract().accountsPartial({ masterContract, admin: governance }).instruction()
+    );
+    expect((await siglab.account.masterInsuranceContract.fetch(masterContract)).isPaused).to.equal(false);
+  });
+});
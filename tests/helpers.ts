@@ -0,0 +1,341 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { Ed25519Program, Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { ed25519 } from "@noble/curves/ed25519";
+import { SiglabContract } from "../target/types/siglab_contract";
+
+export const masterContractPda = (program: Program<SiglabContract>) =>
+  PublicKey.findProgramAddressSync([Buffer.from("master_contract")], program.programId)[0];
+
+export const policyPda = (program: Program<SiglabContract>, holder: PublicKey, index: BN) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("policy"), holder.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+
+/// Payout, premium and endorsement records of `policy`
+export const policyHistoryPda = (program: Program<SiglabContract>, policy: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("policy_history"), policy.toBuffer()], program.programId)[0];
+
+/// Oracles in the master contract's registry, as the remaining accounts
+/// `register_oracle` verifies
+export const registeredOracles = async (program: Program<SiglabContract>) =>
+  (await program.account.masterInsuranceContract.fetch(masterContractPda(program))).oracleRegistry.map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+/// Retirement record left by unregistering `oracle`
+export const oracleRetirementPda = (program: Program<SiglabContract>, oracle: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("oracle_retirement"), oracle.toBuffer()], program.programId)[0];
+
+/// Proof of a reading of `oracle`, taken under `nonce`
+export const observationProofPda = (program: Program<SiglabContract>, oracle: PublicKey, nonce: BN) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("observation_proof"), oracle.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+
+/// Reverse index of the oracle registry
+export const oracleDirectoryPda = (program: Program<SiglabContract>) =>
+  PublicKey.findProgramAddressSync([Buffer.from("oracle_directory")], program.programId)[0];
+
+/// Upstream data provider registered as `providerId`
+export const dataProviderPda = (program: Program<SiglabContract>, providerId: number) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("data_provider"), new BN(providerId).toArrayLike(Buffer, "le", 4)],
+    program.programId
+  )[0];
+
+/// Exposure and daily payouts of the region `regionCode`
+export const regionExposurePda = (program: Program<SiglabContract>, regionCode: number[]) =>
+  PublicKey.findProgramAddressSync([Buffer.from("region_exposure"), Buffer.from(regionCode)], program.programId)[0];
+
+/// Region of the default weather policy, zero-padded to 8 bytes
+export const DEFAULT_REGION_CODE = [...Buffer.from("US-CA\0\0\0")];
+
+const INSURANCE_TYPES = ["weather", "earthquake", "flight", "crop", "custom"];
+
+/// Configuration PDA of an insurance type, seeded by its discriminant
+export const typeConfigPda = (program: Program<SiglabContract>, insuranceType: object = { weather: {} }) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("type_config"), Buffer.from([INSURANCE_TYPES.indexOf(Object.keys(insuranceType)[0])])],
+    program.programId
+  )[0];
+
+export const paramProposalPda = (program: Program<SiglabContract>, id: BN) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("param_proposal"), id.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+
+/// Same tag as `ORACLE_MESSAGE_DOMAIN`
+const ORACLE_MESSAGE_DOMAIN = Buffer.from("siglab_contract:oracle_data:v1");
+
+export type UnsignedOracleData = {
+  version: number;
+  value: BN;
+  timestamp: BN;
+  confidence: BN;
+  nonce: BN;
+  extensions: Buffer;
+};
+
+/// Same layout as `create_oracle_message`: domain tag, program id, oracle and its
+/// length-prefixed id, then value, timestamp, confidence, nonce and extensions
+export const oracleMessage = (program: Program<SiglabContract>, oracleId: string, data: UnsignedOracleData) => {
+  const id = Buffer.from(oracleId);
+  const [oracle] = PublicKey.findProgramAddressSync([Buffer.from("oracle"), id], program.programId);
+  return Buffer.concat([
+    ORACLE_MESSAGE_DOMAIN,
+    program.programId.toBuffer(),
+    oracle.toBuffer(),
+    Buffer.from([id.length]),
+    id,
+    data.value.toArrayLike(Buffer, "le", 8),
+    data.timestamp.toTwos(64).toArrayLike(Buffer, "le", 8),
+    data.confidence.toArrayLike(Buffer, "le", 8),
+    data.nonce.toArrayLike(Buffer, "le", 8),
+    data.extensions,
+  ]);
+};
+
+/// Sign `data` for `oracleId` as `authority`, returning the signed update and
+/// the ed25519 program instruction that must directly precede it
+export function signOracleData(
+  program: Program<SiglabContract>,
+  authority: Keypair,
+  oracleId: string,
+  data: UnsignedOracleData
+) {
+  const message = oracleMessage(program, oracleId, data);
+  const signature = ed25519.sign(message, authority.secretKey.slice(0, 32));
+  return {
+    data: { ...data, signature: Array.from(signature) },
+    attestation: Ed25519Program.createInstructionWithPublicKey({
+      publicKey: authority.publicKey.toBytes(),
+      message,
+      signature,
+    }),
+  };
+}
+
+/// Keypair behind the provider wallet, which registers and signs for most test oracles
+export const walletKeypair = (program: Program<SiglabContract>) =>
+  ((program.provider as anchor.AnchorProvider).wallet as anchor.Wallet).payer;
+
+/// Initialize the master contract, the oracle directory and the weather configuration unless a previous suite
+/// already did
+///
+/// Parameter changes carry no timelock so suites can apply them immediately.
+export async function ensureMasterContract(program: Program<SiglabContract>) {
+  const provider = program.provider as anchor.AnchorProvider;
+  const masterContract = masterContractPda(program);
+  if (!(await provider.connection.getAccountInfo(masterContract))) {
+    await program.methods
+      .initializeMasterContract({
+        reserveRatioBps: 2000,
+        maxOracles: 10,
+        minConsensusThreshold: 3,
+        activationWindow: new BN(7 * 86400),
+        paramTimelockSecs: new BN(0),
+        globalConfig: null,
+        singleOracleMode: false,
+      })
+      .accounts({ admin: provider.wallet.publicKey })
+      .rpc();
+  }
+  if (!(await provider.connection.getAccountInfo(oracleDirectoryPda(program)))) {
+    const { oracleRegistry } = await program.account.masterInsuranceContract.fetch(masterContract);
+    await program.methods
+      .initializeOracleDirectory()
+      .accounts({ admin: provider.wallet.publicKey })
+      .remainingAccounts(oracleRegistry.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc();
+  }
+  const typeConfig = typeConfigPda(program);
+  if (!(await provider.connection.getAccountInfo(typeConfig))) {
+    await program.methods
+      .initTypeConfig({ weather: {} })
+      .accountsPartial({ typeConfig, masterContract, admin: provider.wallet.publicKey })
+      .rpc();
+    // Suites claim right after purchase, so weather cover starts at once, and
+    // their oracle readings carry no meaningful confidence interval
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfig);
+    await applyParamChange(
+      program,
+      {
+        typeConfig: {
+          insuranceType: { weather: {} },
+          params: { ...params, minWaitingPeriodHours: 0, maxConfidenceRatioBps: 0 },
+        },
+      },
+      typeConfig
+    );
+  }
+  return masterContract;
+}
+
+/// Propose `change` and execute it at once, relying on the zero test timelock
+export async function applyParamChange(program: Program<SiglabContract>, change: any, typeConfig: PublicKey | null) {
+  const provider = program.provider as anchor.AnchorProvider;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const { paramProposalCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+  const proposal = paramProposalPda(program, paramProposalCount);
+
+  await program.methods
+    .proposeParamChange(change)
+    .accountsPartial({ proposal, masterContract, admin })
+    .rpc();
+  await program.methods
+    .executeParamChange()
+    .accountsPartial({ proposal, masterContract, typeConfig, admin })
+    .rpc();
+}
+
+/// Original (version 0) treasury; migrations move it to `[b"treasury", version]`
+export const treasuryPda = (program: Program<SiglabContract>) =>
+  PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId)[0];
+
+/// Treasury currently linked to the master contract
+export const currentTreasury = async (program: Program<SiglabContract>) =>
+  (await program.account.masterInsuranceContract.fetch(masterContractPda(program))).treasuryAccount;
+
+/// Initialize the treasury unless a previous suite already did, returning the current one
+export async function ensureTreasury(program: Program<SiglabContract>) {
+  const provider = program.provider as anchor.AnchorProvider;
+  if ((await currentTreasury(program)).equals(PublicKey.default)) {
+    await program.methods
+      .initializeTreasury(2000)
+      .accounts({ admin: provider.wallet.publicKey })
+      .rpc();
+  }
+  return currentTreasury(program);
+}
+
+/// Vault holding late fees paid on overdue premiums
+export const feeVaultPda = (program: Program<SiglabContract>) =>
+  PublicKey.findProgramAddressSync([Buffer.from("fee_vault")], program.programId)[0];
+
+/// Per-epoch ledger of the coverage an underwriter co-signed and the fees it earned
+export const underwriterLedgerPda = (program: Program<SiglabContract>, underwriter: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("underwriter_ledger"), underwriter.toBuffer()], program.programId)[0];
+
+/// Open the SOL-only fee vault unless a previous suite already did, returning it
+export async function ensureFeeVault(program: Program<SiglabContract>) {
+  const provider = program.provider as anchor.AnchorProvider;
+  const feeVault = feeVaultPda(program);
+  if ((await program.account.feeVault.fetchNullable(feeVault)) === null) {
+    await program.methods
+      .initializeFeeVault()
+      .accountsPartial({
+        masterContract: masterContractPda(program),
+        treasury: await ensureTreasury(program),
+        usdcTokenAccount: null,
+        admin: provider.wallet.publicKey,
+      })
+      .rpc();
+  }
+  return feeVault;
+}
+
+/// Default per-currency exposure limit (u64::MAX, i.e. uncapped)
+export const UNCAPPED_EXPOSURE = new BN("18446744073709551615");
+
+/// Default limit on pending payouts open at once
+export const DEFAULT_MAX_OPEN_PAYOUTS = new BN(10_000);
+
+/// Default loss ratio beyond which underwriting bonds are slashed (claims above premiums)
+export const DEFAULT_BOND_MAX_LOSS_RATIO_BPS = 10_000;
+
+/// Default share of a term an oracle may be down before its premium is refunded (5%)
+export const DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS = 500;
+
+/// Monetary config amount counted in lamports
+export const solAmount = (amount: BN) => ({ amount, currency: { sol: {} } as { sol: {} } | { usdc: {} } });
+
+/// Weather policy paying out when rainfall exceeds 50
+export const defaultPolicyParams = (premiumAmount: BN) => ({
+  insuranceType: { weather: {} },
+  coverageAmount: new BN(LAMPORTS_PER_SOL),
+  premiumAmount,
+  deductible: new BN(0),
+  policyDurationDays: 30,
+  triggerConditions: {
+    thresholdValue: 50.0,
+    comparisonOperator: { greaterThan: {} } as { greaterThan: {} } | { lessThan: {} },
+    dataSource: "rainfall",
+    gracePeriod: new BN(0),
+    compound: null,
+  },
+  oracleConfig: {
+    oracleAddress: PublicKey.default,
+    dataFeedId: "rainfall-mm",
+    requiredConfirmations: 1,
+    stalenessThreshold: new BN(300),
+    maxConfidenceRatioBps: null as number | null,
+  },
+  riskAssessmentScore: 10,
+  maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL),
+  waitingPeriodHours: 0,
+  premiumPaymentFrequency: { monthly: {} },
+  autoRenewal: false,
+  metadata: "{}",
+  metadataUri: null,
+  discountCode: null,
+  mintPolicyToken: false,
+  quorumSignatures: 0,
+  deductibleMode: { subtractAfterScaling: {} },
+  installmentPlan: null,
+  premiumCurrency: { sol: {} },
+  payoutCurrency: { sol: {} } as { sol: {} } | { usdc: {} },
+  minSeverityBps: null as number | null,
+  payoutVesting: null as { tranches: number; intervalSecs: BN } | null,
+  regionCode: DEFAULT_REGION_CODE as number[] | null,
+});
+
+/// Create a policy for the provider wallet and pay its first premium
+export async function createActivePolicy(
+  program: Program<SiglabContract>,
+  premiumAmount: BN,
+  overrides: Partial<ReturnType<typeof defaultPolicyParams>> = {}
+) {
+  const provider = program.provider as anchor.AnchorProvider;
+  const holder = provider.wallet.publicKey;
+  const masterContract = await ensureMasterContract(program);
+  const treasury = await ensureTreasury(program);
+  const master = await program.account.masterInsuranceContract.fetch(masterContract);
+  const policyAccount = policyPda(program, holder, master.policiesIssued);
+
+  await program.methods
+    .createPolicy({ ...defaultPolicyParams(premiumAmount), ...overrides })
+    .accountsPartial({
+      policyHolder: holder,
+      masterContract,
+      policyAccount,
+      treasury,
+      typeConfig: typeConfigPda(program, overrides.insuranceType),
+    })
+    .rpc();
+  await program.methods
+    .payPremium(premiumAmount)
+    .accountsPartial({
+      payer: holder,
+      policyAccount,
+      masterContract,
+      policyTokenAccount: null,
+      subsidy: null,
+      treasury,
+      payerTokenAccount: null,
+      treasuryUsdcAccount: null,
+      feeVault: null,
+      feeVaultUsdcAccount: null,
+      underwriterLedger: null,
+      tokenProgram: null,
+    })
+    .rpc();
+
+  return policyAccount;
+}
@@ -0,0 +1,208 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  policyHistoryPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("incident windows", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const weatherTolerance = 6 * 3600;
+  const oracleId = `incident-${Date.now()}`;
+  const feedId = `incident-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const typeConfig = typeConfigPda(program);
+
+  /// Change the weather configuration through the (zero) parameter timelock
+  const setTypeParams = async (overrides: { incidentToleranceSecs?: BN; minIncidentGapSecs?: BN }) => {
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfig);
+    await applyParamChange(
+      program,
+      { typeConfig: { insuranceType: { weather: {} }, params: { ...params, ...overrides } } },
+      typeConfig
+    );
+  };
+  const setTolerance = (toleranceSecs: number) => setTypeParams({ incidentToleranceSecs: new BN(toleranceSecs) });
+
+  const trigger = async (policy: PublicKey, incidentTimestamp: BN, feedOracles: PublicKey[] = []) => {
+    const { id } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, incidentTimestamp)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig,
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .remainingAccounts(feedOracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+  };
+
+  let nonce = 0;
+  const report = (timestamp: BN) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(120),
+      timestamp,
+      confidence: new BN(95),
+      nonce: new BN(++nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const compoundPolicy = (waitingPeriodHours = 0) =>
+    createActivePolicy(program, premiumAmount, {
+      waitingPeriodHours,
+      triggerConditions: {
+        ...defaultPolicyParams(premiumAmount).triggerConditions,
+        compound: {
+          connective: { all: {} },
+          leaves: [{ feedId, operator: { greaterThan: {} }, threshold: 50 }],
+          severityMode: { worst: {} },
+        },
+      },
+    });
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+  });
+
+  after(async () => {
+    await setTolerance(weatherTolerance);
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("rejects tolerances outside the allowed range", async () => {
+    await expectError(setTolerance(0), "InvalidInput");
+    await expectError(setTolerance(7 * 86400 + 1), "InvalidInput");
+  });
+
+  it("bounds the minimum incident gap", async () => {
+    const setGap = (gapSecs: number) => setTypeParams({ minIncidentGapSecs: new BN(gapSecs) });
+
+    await expectError(setGap(-1), "InvalidInput");
+    await expectError(setGap(30 * 86400 + 1), "InvalidInput");
+    await setGap(24 * 3600);
+  });
+
+  it("records the claimed incident in the payout history", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { startDate } = await program.account.policy.fetch(policy);
+    await trigger(policy, startDate);
+
+    const { payoutHistory } = await program.account.policyHistory.fetch(policyHistoryPda(program, policy));
+    expect(payoutHistory).to.have.length(1);
+    expect(payoutHistory[0].incidentTimestamp.toString()).to.equal(startDate.toString());
+    expect(payoutHistory[0].incidentFingerprint).to.have.length(32);
+  });
+
+  it("rejects incidents outside the coverage period", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { startDate } = await program.account.policy.fetch(policy);
+
+    await expectError(trigger(policy, startDate.subn(1)), "IncidentOutsideCoverage");
+    await expectError(trigger(policy, new BN(Math.floor(Date.now() / 1000) + 3600)), "IncidentOutsideCoverage");
+  });
+
+  it("binds compound evidence to the incident tolerance", async () => {
+    const policy = await compoundPolicy();
+    const { startDate } = await program.account.policy.fetch(policy);
+    // A reading two seconds after the incident, outside a one-second tolerance
+    await report(startDate.addn(2));
+
+    await setTolerance(1);
+    await expectError(trigger(policy, startDate, [oracle]), "EvidenceOutsideIncidentWindow");
+
+    await setTolerance(weatherTolerance);
+    await trigger(policy, startDate, [oracle]);
+    const pendingPayout = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_payout"),
+        (await program.account.policy.fetch(policy)).id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.incidentTimestamp.toString()).to.equal(startDate.toString());
+  });
+
+  it("rejects evidence observed before cover started", async () => {
+    const policy = await compoundPolicy();
+    const { startDate } = await program.account.policy.fetch(policy);
+
+    await report(startDate.subn(1));
+    await expectError(trigger(policy, startDate, [oracle]), "EvidenceBeforeCoverage");
+
+    await report(startDate);
+    await trigger(policy, startDate, [oracle]);
+  });
+
+  it("rejects evidence observed during the waiting period", async () => {
+    const policy = await compoundPolicy(1);
+    const { startDate } = await program.account.policy.fetch(policy);
+
+    await report(startDate);
+    await expectError(trigger(policy, startDate.addn(3600), [oracle]), "ClaimPeriodExpired");
+  });
+});
@@ -0,0 +1,125 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("premium installments", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const holder = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const installment = new BN(10_000_000);
+  let policyAccount: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  /// Pay `amount` and return the installment index reported by `PremiumPaid`
+  const pay = async (amount: BN) => {
+    const signature = await program.methods
+      .payPremium(amount)
+      .accountsPartial({
+        payer: holder,
+        policyAccount,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury: await currentTreasury(program),
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    const paid = [...parser.parseLogs(confirmed!.meta!.logMessages!)].find((e) => e.name === "premiumPaid")!;
+    return paid.data.installmentIndex as number | null;
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    const treasury = await ensureTreasury(program);
+
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    policyAccount = policyPda(program, holder, policiesIssued);
+    await program.methods
+      .createPolicy({
+        ...defaultPolicyParams(installment),
+        policyDurationDays: 60,
+        installmentPlan: { noCoverageWhenBehind: {} },
+      })
+      .accountsPartial({
+        policyHolder: holder,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+      })
+      .rpc();
+  });
+
+  it("generates a schedule from the term and payment frequency", async () => {
+    const policy = await program.account.policy.fetch(policyAccount);
+
+    // 60 days paid monthly
+    expect(policy.installmentSchedule!.count).to.equal(2);
+    expect(policy.installmentSchedule!.amount.toString()).to.equal(installment.toString());
+    expect(policy.installmentSchedule!.firstDue.toString()).to.equal(policy.startDate.toString());
+    expect(policy.installmentSchedule!.arrearsMode).to.deep.equal({ noCoverageWhenBehind: {} });
+    expect(policy.installmentsPaid).to.equal(0);
+  });
+
+  it("applies partial payments to the earliest unpaid installment", async () => {
+    expect(await pay(installment.divn(2))).to.equal(0);
+
+    let policy = await program.account.policy.fetch(policyAccount);
+    expect(policy.status).to.deep.equal({ pendingActivation: {} });
+    expect(policy.installmentsPaid).to.equal(0);
+    expect(policy.installmentPartial.toString()).to.equal(installment.divn(2).toString());
+
+    // Completing the first installment activates the policy; the excess carries over
+    expect(await pay(installment)).to.equal(0);
+
+    policy = await program.account.policy.fetch(policyAccount);
+    expect(policy.status).to.deep.equal({ active: {} });
+    expect(policy.installmentsPaid).to.equal(1);
+    expect(policy.installmentPartial.toString()).to.equal(installment.divn(2).toString());
+  });
+
+  it("rejects payments beyond the schedule", async () => {
+    await expectError(pay(installment), "InstallmentOverpayment");
+
+    expect(await pay(installment.divn(2))).to.equal(1);
+    const policy = await program.account.policy.fetch(policyAccount);
+    expect(policy.installmentsPaid).to.equal(2);
+    expect(policy.installmentPartial.toNumber()).to.equal(0);
+    expect(policy.totalPremiumsPaid.toString()).to.equal(installment.muln(2).toString());
+  });
+});
@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  ensureMasterContract,
+  masterContractPda,
+  solAmount,
+  UNCAPPED_EXPOSURE,
+} from "./helpers";
+
+describe("late fees", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const globalConfig = (lateFeeBps: number) => ({
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+  let policy: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await applyParamChange(program, { globalConfig: [globalConfig(100)] }, null);
+    policy = await createActivePolicy(program, premiumAmount);
+  });
+
+  after(async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(0)] }, null);
+  });
+
+  it("charges no fee on a premium paid on time", async () => {
+    const { lateFeesCollected } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const signature = await program.methods
+      .payPremium(premiumAmount)
+      .accountsPartial({
+        payer: admin,
+        policyAccount: policy,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury: await currentTreasury(program),
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    const paid = [...parser.parseLogs(confirmed!.meta!.logMessages!)].find((e) => e.name === "premiumPaid")!;
+    expect(paid.data.lateFee.toNumber()).to.equal(0);
+    expect(paid.data.amount.toString()).to.equal(premiumAmount.toString());
+
+    const master = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.lateFeesCollected.toString()).to.equal(lateFeesCollected.toString());
+  });
+
+  it("rejects waiving when no fee is due", async () => {
+    await expectError(
+      program.methods.waiveLateFee().accountsPartial({ policy, masterContract, admin }).rpc(),
+      "NoLateFeeDue"
+    );
+  });
+
+  it("only lets the admin waive fees", async () => {
+    const outsider = Keypair.generate();
+    await expectError(
+      program.methods
+        .waiveLateFee()
+        .accountsPartial({ policy, masterContract, admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+});
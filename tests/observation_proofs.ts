@@ -0,0 +1,99 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  ensureMasterContract,
+  masterContractPda,
+  observationProofPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle observation proofs", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleId = `proof-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  const readingTime = new BN(Math.floor(Date.now() / 1000) - 5);
+
+  const snapshot = (nonce: BN, observedAt: BN | null) =>
+    program.methods
+      .snapshotOracleObservation(nonce, observedAt)
+      .accountsPartial({ observationProof: observationProofPda(program, oracle, nonce), oracle, masterContract })
+      .rpc();
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin: authority, oracleAuthority: authority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+  });
+
+  it("refuses to prove a reading the oracle never reported", async () => {
+    await expectError(snapshot(new BN(1), null), "ObservationNotFound");
+  });
+
+  it("copies the reading with the oracle's reputation and health", async () => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(120),
+      timestamp: readingTime,
+      confidence: new BN(95),
+      nonce: new BN(1),
+      extensions: Buffer.alloc(0),
+    });
+    await program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: authority })
+      .preInstructions([attestation])
+      .rpc();
+
+    await snapshot(new BN(1), null);
+    await snapshot(new BN(2), readingTime);
+    await expectError(snapshot(new BN(3), readingTime.subn(1)), "ObservationNotFound");
+
+    const oracleAccount = await program.account.oracle.fetch(oracle);
+    for (const nonce of [1, 2]) {
+      const proof = await program.account.observationProof.fetch(observationProofPda(program, oracle, new BN(nonce)));
+      expect(proof.oracle.toBase58()).to.equal(oracle.toBase58());
+      expect(proof.value.toNumber()).to.equal(120);
+      expect(proof.observedAt.eq(readingTime)).to.be.true;
+      expect(proof.reputationScore).to.equal(oracleAccount.reputationScore);
+      expect(proof.updatesLast24h).to.equal(1);
+      expect(proof.creator.toBase58()).to.equal(authority.toBase58());
+    }
+  });
+
+  it("keeps a proof for the retention period", async () => {
+    await expectError(
+      program.methods
+        .closeObservationProof()
+        .accountsPartial({ observationProof: observationProofPda(program, oracle, new BN(1)), masterContract })
+        .rpc(),
+      "ObservationProofRetained"
+    );
+  });
+});
@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  ensureMasterContract,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle attestation", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  // Two oracles run by the same authority
+  const suffix = Date.now();
+  const oracleIds = [`attest-a-${suffix}`, `attest-b-${suffix}`];
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync([Buffer.from("oracle"), Buffer.from(oracleId)], program.programId)[0]
+  );
+
+  const signed = (oracleId: string, nonce: number) =>
+    signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(100),
+      timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin: authority, oracleAuthority: authority })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({
+          oracle,
+          oracleRetirement: oracleRetirementPda(program, oracle),
+          masterContract,
+          admin: authority,
+        })
+        .rpc();
+    }
+  });
+
+  it("rejects an update without an ed25519 attestation", async () => {
+    const { data } = signed(oracleIds[0], 1);
+    await expectError(
+      program.methods.updateOracleData(data).accountsPartial({ oracle: oracles[0], oracleAuthority: authority }).rpc(),
+      "MissingEd25519Instruction"
+    );
+  });
+
+  it("rejects a signature made for another oracle", async () => {
+    const { data, attestation } = signed(oracleIds[0], 1);
+    await expectError(
+      program.methods
+        .updateOracleData(data)
+        .accountsPartial({ oracle: oracles[1], oracleAuthority: authority })
+        .preInstructions([attestation])
+        .rpc(),
+      "Ed25519AttestationMismatch"
+    );
+
+    // The same attestation is good for the oracle it was made for
+    await program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle: oracles[0], oracleAuthority: authority })
+      .preInstructions([attestation])
+      .rpc();
+    expect((await program.account.oracle.fetch(oracles[0])).lastNonce.toNumber()).to.equal(1);
+    expect((await program.account.oracle.fetch(oracles[1])).updateCount.toNumber()).to.equal(0);
+  });
+});
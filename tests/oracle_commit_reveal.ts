@@ -0,0 +1,178 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { createHash, randomBytes } from "crypto";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+/// Mirrors `MIN_REVEAL_DELAY_SLOTS`
+const MIN_REVEAL_DELAY_SLOTS = 2;
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("oracle commit-reveal", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const oracleId = `commit-${Date.now()}`;
+  const feedId = `commit-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  const premiumAmount = new BN(10_000_000);
+  let boughtDuringCommitment: PublicKey;
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const signedData = (value: number, nonce: number) =>
+    signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(value),
+      timestamp: new BN(Math.floor(Date.now() / 1000)),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+
+  /// Same digest as `oracle_commitment`: sha256(value || salt || nonce)
+  const commitmentOf = (value: number, salt: Buffer, nonce: number) => [
+    ...createHash("sha256")
+      .update(new BN(value).toArrayLike(Buffer, "le", 8))
+      .update(salt)
+      .update(new BN(nonce).toArrayLike(Buffer, "le", 8))
+      .digest(),
+  ];
+
+  const update = (value: number, nonce: number) => {
+    const { data, attestation } = signedData(value, nonce);
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation]);
+  };
+
+  const commit = (commitment: number[]) =>
+    program.methods.commitOracleData(commitment).accountsPartial({ oracle, oracleAuthority: admin, masterContract });
+
+  const reveal = (value: number, nonce: number, salt: Buffer) => {
+    const { data, attestation } = signedData(value, nonce);
+    return program.methods
+      .revealOracleData(data, [...salt])
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation]);
+  };
+
+  const setCommitReveal = (enabled: boolean) =>
+    program.methods.setOracleCommitReveal(enabled).accountsPartial({ oracle, masterContract, admin }).rpc();
+
+  const simulate = (policy: PublicKey) =>
+    program.methods
+      .simulatePayout(new BN(75))
+      .accountsPartial({ policy, masterContract, treasury, breachWatch: null })
+      .remainingAccounts([{ pubkey: oracle, isSigner: false, isWritable: false }])
+      .view();
+
+  const createPolicy = () =>
+    createActivePolicy(program, premiumAmount, {
+      oracleConfig: { ...defaultPolicyParams(premiumAmount).oracleConfig, dataFeedId: feedId },
+    });
+
+  const waitForSlot = async (slot: number) => {
+    while ((await provider.connection.getSlot("confirmed")) < slot) {
+      await sleep(200);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+  });
+
+  after(async () => {
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("applies direct updates until commit-reveal is enabled", async () => {
+    await update(100, 1).rpc();
+    await expectError(commit(commitmentOf(100, randomBytes(32), 2)).rpc(), "OracleCommitRevealDisabled");
+
+    await setCommitReveal(true);
+    await expectError(update(110, 2).rpc(), "OracleCommitRevealRequired");
+    await expectError(reveal(110, 2, randomBytes(32)).rpc(), "NoOracleCommitment");
+  });
+
+  it("reveals a committed value and withholds it from policies bought in between", async () => {
+    const before = await createPolicy();
+    // Policies are timestamped to the second; keep this one clear of the commitment
+    await sleep(1_100);
+
+    const salt = randomBytes(32);
+    const commitIx = await commit(commitmentOf(120, salt, 2)).instruction();
+    await expectError(reveal(120, 2, salt).preInstructions([commitIx], true).rpc(), "OracleRevealTooEarly");
+
+    await commit(commitmentOf(120, salt, 2)).rpc();
+    await expectError(commit(commitmentOf(130, salt, 3)).rpc(), "OracleCommitmentPending");
+    boughtDuringCommitment = await createPolicy();
+
+    const { commitmentSlot } = await program.account.oracle.fetch(oracle);
+    await waitForSlot(commitmentSlot.toNumber() + MIN_REVEAL_DELAY_SLOTS);
+    await expectError(reveal(120, 2, randomBytes(32)).rpc(), "OracleRevealMismatch");
+    await expectError(reveal(121, 2, salt).rpc(), "OracleRevealMismatch");
+    await reveal(120, 2, salt).rpc();
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.updateCount.toNumber()).to.equal(2);
+    expect(account.lastNonce.toNumber()).to.equal(2);
+    expect(account.commitment).to.deep.equal(Array(32).fill(0));
+    expect(account.latestCommittedAt.toNumber()).to.be.greaterThan(0);
+    expect(account.latestRevealedAt.toNumber()).to.be.at.least(account.latestCommittedAt.toNumber());
+
+    expect((await simulate(before)).ineligibility).to.equal(null);
+    expect((await simulate(boughtDuringCommitment)).ineligibility).to.deep.equal({ createdDuringCommitment: {} });
+  });
+
+  it("lifts the restriction once a direct update replaces the revealed value", async () => {
+    await setCommitReveal(false);
+    await update(125, 3).rpc();
+
+    expect((await simulate(boughtDuringCommitment)).ineligibility).to.equal(null);
+  });
+});
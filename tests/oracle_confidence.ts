@@ -0,0 +1,200 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle confidence", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const typeConfig = typeConfigPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const oracleId = `confidence-${Date.now()}`;
+  const feedId = `confidence-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  // Rainfall of 60 mm against a 50 mm trigger, reported within ±10%
+  const threshold = 50;
+  const value = 60;
+  const maxRatioBps = 1_000;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const setMaxRatio = async (maxConfidenceRatioBps: number) => {
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfig);
+    await applyParamChange(
+      program,
+      { typeConfig: { insuranceType: { weather: {} }, params: { ...params, maxConfidenceRatioBps } } },
+      typeConfig
+    );
+  };
+
+  const updateOracle = (confidence: number, timestamp: BN, nonce: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(value),
+      timestamp,
+      confidence: new BN(confidence),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const pendingPayoutFor = async (policy: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_payout"),
+        (await program.account.policy.fetch(policy)).id.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  const compoundPolicy = (maxConfidenceRatioBps: number | null) =>
+    createActivePolicy(program, premiumAmount, {
+      triggerConditions: {
+        ...defaultPolicyParams(premiumAmount).triggerConditions,
+        compound: {
+          connective: { all: {} },
+          leaves: [{ feedId, operator: { greaterThan: {} }, threshold }],
+          severityMode: { worst: {} },
+        },
+      },
+      oracleConfig: { ...defaultPolicyParams(premiumAmount).oracleConfig, maxConfidenceRatioBps },
+    });
+
+  const fileClaim = async (policy: PublicKey) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .fileClaim(id, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        typeConfig,
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+    return startDate;
+  };
+
+  const evaluateClaim = async (policy: PublicKey) =>
+    program.methods
+      .evaluateClaim(new BN(0), new BN(0), null)
+      .accountsPartial({
+        pendingPayout: await pendingPayoutFor(policy),
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig,
+        beneficiary: admin,
+        cranker: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .remainingAccounts([{ pubkey: oracle, isSigner: false, isWritable: true }])
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+    await setMaxRatio(maxRatioBps);
+  });
+
+  after(async () => {
+    await setMaxRatio(0);
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("takes the insurance type's limit when the policy sets none", async () => {
+    const policy = await compoundPolicy(null);
+    const { oracleConfig } = await program.account.policy.fetch(policy);
+    expect(oracleConfig.maxConfidenceRatioBps).to.equal(maxRatioBps);
+
+    const own = await compoundPolicy(250);
+    expect((await program.account.policy.fetch(own)).oracleConfig.maxConfidenceRatioBps).to.equal(250);
+  });
+
+  it("rejects limits above the reported value", async () => {
+    await expectError(compoundPolicy(10_001), "InvalidParameters");
+  });
+
+  it("rejects readings too imprecise to tell which side of the threshold they fall", async () => {
+    const policy = await compoundPolicy(null);
+    const startDate = await fileClaim(policy);
+
+    // 60 ± 12 mm may well be below the 50 mm threshold
+    await updateOracle(12, startDate, 1);
+    await expectError(evaluateClaim(policy), "OracleConfidenceTooLow");
+    expect((await program.account.pendingPayout.fetch(await pendingPayoutFor(policy))).status).to.deep.equal({
+      pending: {},
+    });
+
+    // 60 ± 6 mm sits within the policy's limit and settles the claim
+    await updateOracle(6, startDate, 2);
+    await evaluateClaim(policy);
+    const settled = await program.account.pendingPayout.fetch(await pendingPayoutFor(policy));
+    expect(settled.status).to.satisfy((status: object) => "ready" in status || "pendingApproval" in status);
+  });
+
+  it("accepts any interval when the policy has no limit", async () => {
+    const policy = await compoundPolicy(0);
+    const startDate = await fileClaim(policy);
+
+    await updateOracle(600, startDate, 3);
+    await evaluateClaim(policy);
+    const settled = await program.account.pendingPayout.fetch(await pendingPayoutFor(policy));
+    expect(settled.amount.toNumber()).to.be.greaterThan(0);
+  });
+});
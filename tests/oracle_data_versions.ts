@@ -0,0 +1,92 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, registeredOracles, signOracleData, walletKeypair } from "./helpers";
+
+const ORACLE_DATA_V1 = 0x10;
+const ORACLE_DATA_V2 = 0x11;
+
+describe("oracle data versions", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleId = `versions-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  let nonce = 0;
+
+  /// v2 extensions: exponent (i32) then the length-prefixed feed id
+  const v2Extensions = (exponent: number, feedId: string) => {
+    const exponentBytes = Buffer.alloc(4);
+    exponentBytes.writeInt32LE(exponent);
+    return Buffer.concat([exponentBytes, Buffer.from([feedId.length]), Buffer.from(feedId)]);
+  };
+
+  const update = (version: number, extensions: Buffer) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version,
+      value: new BN(100),
+      timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+      confidence: new BN(95),
+      nonce: new BN(++nonce),
+      extensions,
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: authority })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin: authority, oracleAuthority: authority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin: authority })
+      .rpc();
+  });
+
+  it("accepts v1 and v2 data", async () => {
+    await update(ORACLE_DATA_V1, Buffer.alloc(0));
+    await update(ORACLE_DATA_V2, v2Extensions(-2, "rainfall-mm"));
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.lastNonce.toNumber()).to.equal(nonce);
+  });
+
+  it("ignores extension bytes from newer minor versions", async () => {
+    const extensions = Buffer.concat([v2Extensions(0, "rainfall-mm"), Buffer.alloc(8, 0xaa)]);
+    await update(ORACLE_DATA_V2 + 1, extensions);
+  });
+
+  it("rejects a feed id that does not match the oracle", async () => {
+    await expectError(update(ORACLE_DATA_V2, v2Extensions(0, "wind-speed")), "InvalidOracleData");
+  });
+
+  it("rejects unknown major versions", async () => {
+    await expectError(update(0x20, Buffer.alloc(0)), "UnsupportedOracleDataVersion");
+  });
+});
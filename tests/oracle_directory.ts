@@ -0,0 +1,109 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  ensureMasterContract,
+  masterContractPda,
+  oracleDirectoryPda,
+  oracleRetirementPda,
+  registeredOracles,
+} from "./helpers";
+
+describe("oracle directory", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleDirectory = oracleDirectoryPda(program);
+  const oracleId = `directory-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  const idHash = createHash("sha256").update(oracleId).digest();
+
+  const register = async (oracleAuthority: PublicKey) =>
+    program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, oracleDirectory, admin, oracleAuthority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+
+  /// Simulate `resolve_oracle` and decode its `OracleDirectoryView`
+  const resolve = async (lookup: { id: [string] } | { address: [PublicKey] }) => {
+    const tx = await program.methods.resolveOracle(lookup).accountsPartial({ oracleDirectory }).transaction();
+    const { returnData } = await provider.simulate(tx);
+    const data = Buffer.from(returnData.data[0], "base64");
+    expect(data.length).to.equal(1 + 32 + 32 + 32 + 1);
+    expect(data[0]).to.equal(1);
+    return {
+      idHash: data.subarray(1, 33),
+      oracle: new PublicKey(data.subarray(33, 65)),
+      authority: new PublicKey(data.subarray(65, 97)),
+      registered: data[97] === 1,
+    };
+  };
+
+  const entriesFor = async (key: PublicKey) =>
+    (await program.account.oracleDirectory.fetch(oracleDirectory)).entries.filter((entry) => entry.oracle.equals(key));
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  it("resolves a registered oracle by id and by address", async () => {
+    await register(admin);
+
+    const byId = await resolve({ id: [oracleId] });
+    expect(byId.idHash.equals(idHash)).to.be.true;
+    expect(byId.oracle.equals(oracle)).to.be.true;
+    expect(byId.authority.equals(admin)).to.be.true;
+    expect(byId.registered).to.be.true;
+
+    const byAddress = await resolve({ address: [oracle] });
+    expect(byAddress).to.deep.equal(byId);
+  });
+
+  it("keeps the mapping of an unregistered oracle", async () => {
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({
+        oracle,
+        oracleRetirement: oracleRetirementPda(program, oracle),
+        masterContract,
+        oracleDirectory,
+        admin,
+      })
+      .rpc();
+
+    const view = await resolve({ id: [oracleId] });
+    expect(view.oracle.equals(oracle)).to.be.true;
+    expect(view.registered).to.be.false;
+  });
+
+  it("reuses the entry when the id is registered again", async () => {
+    const authority = Keypair.generate().publicKey;
+    await register(authority);
+
+    const view = await resolve({ id: [oracleId] });
+    expect(view.authority.equals(authority)).to.be.true;
+    expect(view.registered).to.be.true;
+    expect(await entriesFor(oracle)).to.have.length(1);
+  });
+
+  it("rejects unknown oracles", async () => {
+    let rejected = false;
+    try {
+      await resolve({ id: [`unknown-${oracleId}`] });
+    } catch {
+      rejected = true;
+    }
+    expect(rejected).to.equal(true);
+  });
+});
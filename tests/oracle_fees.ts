@@ -0,0 +1,170 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle fees", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const fee = 5_000;
+  const premiumAmount = new BN(10_000_000);
+  const oracleId = `fees-${Date.now()}`;
+  const feedId = `fee-feed-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+  let treasury: PublicKey;
+  let nonce = 0;
+
+  const compoundPolicy = () =>
+    createActivePolicy(program, premiumAmount, {
+      triggerConditions: {
+        ...defaultPolicyParams(premiumAmount).triggerConditions,
+        compound: {
+          connective: { all: {} },
+          leaves: [{ feedId, operator: { greaterThan: {} }, threshold: 50 }],
+          severityMode: { worst: {} },
+        },
+      },
+    });
+
+  /// Report a reading observed at the start of `policy`'s cover, as claims need
+  const update = async (policy: PublicKey) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(120),
+      timestamp: (await program.account.policy.fetch(policy)).startDate,
+      confidence: new BN(95),
+      nonce: new BN(++nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const trigger = async (policy: PublicKey, isWritable: boolean) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(id, new BN(0), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .remainingAccounts([{ pubkey: oracle, isSigner: false, isWritable }])
+      .rpc();
+  };
+
+  const unclaimedFees = async () =>
+    (await program.account.oracle.fetch(oracle)).unclaimedFees.toNumber();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+
+    // Back the tracked treasury balance with real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: LAMPORTS_PER_SOL })
+      )
+    );
+    await program.methods
+      .depositFunds(new BN(LAMPORTS_PER_SOL), { sol: {} })
+      .accountsPartial({ treasury, depositor: admin })
+      .rpc();
+
+    await program.methods.updateOracleFee(new BN(fee)).accountsPartial({ masterContract, admin }).rpc();
+
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, feedId, { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+  });
+
+  after(async () => {
+    await program.methods.updateOracleFee(new BN(0)).accountsPartial({ masterContract, admin }).rpc();
+  });
+
+  it("requires consumed oracles to be writable", async () => {
+    const policy = await compoundPolicy();
+    await update(policy);
+    try {
+      await trigger(policy, false);
+      expect.fail("trigger with a read-only oracle was accepted");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("OracleAccountNotWritable");
+    }
+  });
+
+  it("accrues the fee once per consumed update", async () => {
+    const first = await compoundPolicy();
+    const second = await compoundPolicy();
+    await update(second);
+    await trigger(first, true);
+    expect(await unclaimedFees()).to.equal(fee);
+
+    // A second trigger reading the same update pays nothing more
+    await trigger(second, true);
+    expect(await unclaimedFees()).to.equal(fee);
+  });
+
+  it("pays accrued fees from the treasury", async () => {
+    const before = await program.account.treasury.fetch(treasury);
+
+    await program.methods
+      .claimOracleFees()
+      .accountsPartial({ oracle, masterContract, treasury, oracleAuthority: admin })
+      .rpc();
+
+    const after = await program.account.treasury.fetch(treasury);
+    expect(await unclaimedFees()).to.equal(0);
+    expect(before.totalSolBalance.sub(after.totalSolBalance).toNumber()).to.equal(fee);
+  });
+
+  it("forfeits unclaimed fees when the oracle is deactivated", async () => {
+    const policy = await compoundPolicy();
+    await update(policy);
+    await trigger(policy, true);
+    expect(await unclaimedFees()).to.equal(fee);
+
+    await program.methods
+      .updateOracleStatus(false)
+      .accountsPartial({ oracle, masterContract, admin })
+      .rpc();
+    expect(await unclaimedFees()).to.equal(0);
+  });
+});
@@ -0,0 +1,161 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  currentTreasury,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  typeConfigPda,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle health gating", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const suffix = Date.now();
+  const oracleIds = [0, 1, 2].map((i) => `health-${i}-${suffix}`);
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle"), Buffer.from(oracleId)],
+        program.programId
+      )[0]
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const setMinReputation = (minReputation: number) =>
+    program.methods
+      .setMinOracleReputation(minReputation)
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+
+  const setActive = (i: number, isActive: boolean) =>
+    program.methods
+      .updateOracleStatus(isActive)
+      .accountsPartial({ oracle: oracles[i], masterContract, admin })
+      .rpc();
+
+  const computeConsensus = async () => {
+    const { consensusRound } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const consensusSnapshot = PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus"), consensusRound.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    return program.methods
+      .computeConsensus()
+      .accountsPartial({ masterContract, consensusSnapshot, payer: admin })
+      .remainingAccounts(oracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+      const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+        version: 0x10,
+        value: new BN(100 + i),
+        timestamp: new BN(Math.floor(Date.now() / 1000)),
+        confidence: new BN(95),
+        nonce: new BN(1),
+        extensions: Buffer.alloc(0),
+      });
+      await program.methods
+        .updateOracleData(data)
+        .accountsPartial({ oracle: oracles[i], oracleAuthority: admin })
+        .preInstructions([attestation])
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    await setMinReputation(70);
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("defaults the reputation floor and bounds updates", async () => {
+    const { minOracleReputation } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(minOracleReputation).to.equal(70);
+
+    await expectError(setMinReputation(101), "InvalidInput");
+    await setMinReputation(100);
+    expect((await program.account.masterInsuranceContract.fetch(masterContract)).minOracleReputation).to.equal(100);
+  });
+
+  it("computes consensus when every oracle is healthy", async () => {
+    await computeConsensus();
+  });
+
+  it("fails rounds fast once too few oracles are healthy", async () => {
+    await setActive(2, false);
+    try {
+      await expectError(computeConsensus(), "InsufficientOracles");
+    } finally {
+      await setActive(2, true);
+    }
+  });
+
+  it("fails triggers reading an unhealthy oracle", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+
+    await setActive(0, false);
+    try {
+      await expectError(
+        program.methods
+          .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+          .accountsPartial({
+            policy,
+            masterContract,
+            treasury: await currentTreasury(program),
+            typeConfig: typeConfigPda(program),
+            beneficiary: admin,
+            policyMint: null,
+            beneficiaryTokenAccount: null,
+            tokenProgram: null,
+            breachWatch: null,
+            consensusSnapshot: null,
+          })
+          .remainingAccounts([{ pubkey: oracles[0], isSigner: false, isWritable: false }])
+          .rpc(),
+        "InsufficientOracles"
+      );
+    } finally {
+      await setActive(0, true);
+    }
+  });
+});
@@ -0,0 +1,78 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, registeredOracles, signOracleData, walletKeypair } from "./helpers";
+
+/// Upper bound for a single oracle update with the zero-copy layout
+const UPDATE_COMPUTE_BUDGET = 25_000;
+const HISTORY_LENGTH = 32;
+
+describe("oracle history", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleId = `history-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+
+  const update = (nonce: number, value: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(value),
+      timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: authority })
+      .preInstructions([attestation])
+      .rpc({ commitment: "confirmed" });
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin: authority, oracleAuthority: authority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+    // Lift the rate limit so the ring buffer can be filled in one run
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle, masterContract, admin: authority })
+      .rpc();
+  });
+
+  it("wraps the observation ring buffer once it is full", async () => {
+    for (let nonce = 1; nonce <= HISTORY_LENGTH + 3; nonce++) {
+      await update(nonce, 100 + nonce);
+    }
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.historyLen).to.equal(HISTORY_LENGTH);
+    expect(account.historyHead).to.equal(3);
+    expect(account.lastNonce.toNumber()).to.equal(HISTORY_LENGTH + 3);
+    // The oldest three slots were overwritten by the newest readings
+    expect(account.history[2].value.toNumber()).to.equal(100 + HISTORY_LENGTH + 3);
+    expect(account.history[3].value.toNumber()).to.equal(104);
+  });
+
+  it("keeps oracle updates within the compute budget", async () => {
+    const signature = await update(100, 140);
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    expect(confirmed!.meta!.computeUnitsConsumed).to.be.lessThan(UPDATE_COMPUTE_BUDGET);
+  });
+});
@@ -0,0 +1,133 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  ensureMasterContract,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle maintenance pause", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const suffix = Date.now();
+  const oracleIds = [0, 1, 2, 3].map((i) => `pause-${i}-${suffix}`);
+  const oracles = oracleIds.map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("oracle"), Buffer.from(oracleId)],
+        program.programId
+      )[0]
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const update = (i: number, nonce: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleIds[i], {
+      version: 0x10,
+      value: new BN(100 + i),
+      timestamp: new BN(Math.floor(Date.now() / 1000)),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle: oracles[i], oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const pause = (i: number, durationSecs: number, authority?: Keypair) =>
+    program.methods
+      .pauseOracleSelf(new BN(durationSecs))
+      .accountsPartial({ oracle: oracles[i], oracleAuthority: authority?.publicKey ?? admin, masterContract })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+  const computeConsensus = async () => {
+    const { consensusRound } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const consensusSnapshot = PublicKey.findProgramAddressSync(
+      [Buffer.from("consensus"), consensusRound.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    await program.methods
+      .computeConsensus()
+      .accountsPartial({ masterContract, consensusSnapshot, payer: admin })
+      .remainingAccounts(oracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+      .rpc();
+    return program.account.consensusSnapshot.fetch(consensusSnapshot);
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+
+    for (const [i, oracleId] of oracleIds.entries()) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oracles[i], masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+      await update(i, 1);
+    }
+  });
+
+  after(async () => {
+    // Free the registry slots for later suites
+    for (const oracle of oracles) {
+      await program.methods
+        .unregisterOracle({ suspendCoverage: {} })
+        .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("bounds pauses to 24 hours", async () => {
+    await expectError(pause(0, 0), "InvalidPauseDuration");
+    await expectError(pause(0, 24 * 3600 + 1), "InvalidPauseDuration");
+  });
+
+  it("only lets the oracle authority pause", async () => {
+    await expectError(pause(0, 3600, Keypair.generate()), "Unauthorized");
+  });
+
+  it("leaves paused oracles out of consensus without failing the round", async () => {
+    await pause(3, 3600);
+    const { pausedUntil, reputationScore } = await program.account.oracle.fetch(oracles[3]);
+    expect(pausedUntil.toNumber()).to.be.greaterThan(Math.floor(Date.now() / 1000));
+
+    const snapshot = await computeConsensus();
+    expect(snapshot.oracles.map((oracle) => oracle.toBase58())).to.not.include(oracles[3].toBase58());
+    expect(snapshot.oracles).to.have.length(3);
+    expect((await program.account.oracle.fetch(oracles[3])).reputationScore).to.equal(reputationScore);
+  });
+
+  it("resumes on the next accepted update", async () => {
+    await program.methods
+      .setOracleUpdateInterval(new BN(0))
+      .accountsPartial({ oracle: oracles[3], masterContract, admin })
+      .rpc();
+    await update(3, 2);
+
+    expect((await program.account.oracle.fetch(oracles[3])).pausedUntil.toNumber()).to.equal(0);
+    expect(await computeConsensus().then((snapshot) => snapshot.oracles)).to.have.length(4);
+  });
+});
@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, registeredOracles, signOracleData, walletKeypair } from "./helpers";
+
+/// Mirrors `ORACLE_UPDATE_INTERVAL`
+const DEFAULT_UPDATE_INTERVAL = 300;
+
+/// Updates in the hourly histogram; a fresh oracle has nothing older than 24h
+const recentUpdates = (metrics: { hourlyUpdates: number[] }) =>
+  metrics.hourlyUpdates.reduce((sum, count) => sum + count, 0);
+
+describe("oracle rate limit", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleId = `rate-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+
+  const update = (nonce: number) => {
+    const { data, attestation } = signOracleData(program, walletKeypair(program), oracleId, {
+      version: 0x10,
+      value: new BN(100),
+      timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    });
+    return program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle, oracleAuthority: authority })
+      .preInstructions([attestation])
+      .rpc();
+  };
+
+  const setInterval = (seconds: number, admin = provider.wallet.publicKey, signers: Keypair[] = []) =>
+    program.methods
+      .setOracleUpdateInterval(new BN(seconds))
+      .accountsPartial({ oracle, masterContract, admin })
+      .signers(signers)
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin: authority, oracleAuthority: authority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+  });
+
+  it("defaults the interval to ORACLE_UPDATE_INTERVAL", async () => {
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.minUpdateIntervalSecs.toNumber()).to.equal(DEFAULT_UPDATE_INTERVAL);
+  });
+
+  it("rejects an update inside the interval without penalizing health", async () => {
+    await update(1);
+    const before = await program.account.oracle.fetch(oracle);
+
+    try {
+      await update(2);
+      expect.fail("update inside the interval was accepted");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("OracleUpdateTooFrequent");
+    }
+
+    const after = await program.account.oracle.fetch(oracle);
+    expect(after.updateCount.toNumber()).to.equal(1);
+    expect(recentUpdates(after.healthMetrics)).to.equal(recentUpdates(before.healthMetrics));
+    expect(after.healthMetrics.failedValidations).to.equal(before.healthMetrics.failedValidations);
+    expect(after.reputationScore).to.equal(before.reputationScore);
+  });
+
+  it("only lets the oracle manager change the interval", async () => {
+    const outsider = Keypair.generate();
+    try {
+      await setInterval(0, outsider.publicKey, [outsider]);
+      expect.fail("non-admin changed the update interval");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("Unauthorized");
+    }
+  });
+
+  it("accepts updates once the manager lowers the interval", async () => {
+    await setInterval(0);
+    await update(2);
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.minUpdateIntervalSecs.toNumber()).to.equal(0);
+    expect(account.updateCount.toNumber()).to.equal(2);
+    expect(recentUpdates(account.healthMetrics)).to.equal(2);
+  });
+});
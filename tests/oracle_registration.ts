@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, oracleRetirementPda } from "./helpers";
+
+describe("oracle registration requests", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const stake = LAMPORTS_PER_SOL / 10;
+  const operator = Keypair.generate();
+  const suffix = Date.now();
+  const approvedId = `operator-a-${suffix}`;
+  const deniedId = `operator-b-${suffix}`;
+
+  const oraclePda = (oracleId: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), Buffer.from(oracleId)],
+      program.programId
+    )[0];
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const request = (oracleId: string) =>
+    program.methods
+      .requestOracleRegistration(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle: oraclePda(oracleId), masterContract, operator: operator.publicKey })
+      .signers([operator])
+      .rpc();
+
+  const approve = (oracleId: string) =>
+    program.methods
+      .approveOracle()
+      .accountsPartial({ oracle: oraclePda(oracleId), masterContract, admin })
+      .rpc();
+
+  const deny = (oracleId: string, reason: string) =>
+    program.methods
+      .denyOracle(reason)
+      .accountsPartial({ oracle: oraclePda(oracleId), masterContract, oracleAuthority: operator.publicKey, admin })
+      .rpc();
+
+  const registry = async () =>
+    (await program.account.masterInsuranceContract.fetch(masterContract)).oracleRegistry.map(
+      (key) => key.toBase58()
+    );
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: operator.publicKey, lamports: LAMPORTS_PER_SOL })
+      )
+    );
+  });
+
+  after(async () => {
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({
+        oracle: oraclePda(approvedId),
+        oracleRetirement: oracleRetirementPda(program, oraclePda(approvedId)),
+        masterContract,
+        admin,
+      })
+      .rpc();
+  });
+
+  it("creates a pending oracle holding the operator's stake", async () => {
+    await request(approvedId);
+
+    const oracle = await program.account.oracle.fetch(oraclePda(approvedId));
+    expect(oracle.pending).to.equal(1);
+    expect(oracle.active).to.equal(0);
+    expect(oracle.authority.toBase58()).to.equal(operator.publicKey.toBase58());
+    expect(oracle.stakeLamports.toNumber()).to.equal(stake);
+    expect(await registry()).to.not.include(oraclePda(approvedId).toBase58());
+
+    const balance = await provider.connection.getBalance(oraclePda(approvedId));
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(oraclePda(approvedId)))!.data.length
+    );
+    expect(balance).to.equal(rent + stake);
+  });
+
+  it("keeps pending oracles from being updated", async () => {
+    await expectError(
+      program.methods
+        .updateOracleStatus(true)
+        .accountsPartial({ oracle: oraclePda(approvedId), masterContract, admin })
+        .rpc(),
+      "OraclePendingApproval"
+    );
+    await expectError(
+      program.methods
+        .setOracleUpdateInterval(new BN(60))
+        .accountsPartial({ oracle: oraclePda(approvedId), masterContract, admin })
+        .rpc(),
+      "OraclePendingApproval"
+    );
+  });
+
+  it("only lets the admin approve", async () => {
+    await expectError(
+      program.methods
+        .approveOracle()
+        .accountsPartial({ oracle: oraclePda(approvedId), masterContract, admin: operator.publicKey })
+        .signers([operator])
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+
+  it("activates an approved oracle and adds it to the registry", async () => {
+    await approve(approvedId);
+
+    const oracle = await program.account.oracle.fetch(oraclePda(approvedId));
+    expect(oracle.pending).to.equal(0);
+    expect(oracle.active).to.equal(1);
+    expect(await registry()).to.include(oraclePda(approvedId).toBase58());
+
+    await expectError(approve(approvedId), "OracleNotPending");
+  });
+
+  it("rejects overlong denial reasons", async () => {
+    await request(deniedId);
+    await expectError(deny(deniedId, "x".repeat(129)), "InvalidInput");
+  });
+
+  it("closes a denied oracle and refunds rent and stake", async () => {
+    const oracle = oraclePda(deniedId);
+    const held = await provider.connection.getBalance(oracle);
+    const before = await provider.connection.getBalance(operator.publicKey);
+
+    await deny(deniedId, "feed not on the approved source list");
+
+    expect(await provider.connection.getAccountInfo(oracle)).to.be.null;
+    expect(await provider.connection.getBalance(operator.publicKey)).to.equal(before + held);
+    expect(await registry()).to.not.include(oracle.toBase58());
+  });
+
+  it("does not deny approved oracles", async () => {
+    await expectError(deny(approvedId, "too late"), "OracleNotPending");
+  });
+});
@@ -0,0 +1,110 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, oracleRetirementPda, registeredOracles } from "./helpers";
+
+describe("oracle registry", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const suffix = Date.now();
+  const firstId = `registry-a-${suffix}`;
+  const secondId = `registry-b-${suffix}`;
+
+  const oraclePda = (oracleId: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), Buffer.from(oracleId)],
+      program.programId
+    )[0];
+
+  const register = async (oracleId: string) =>
+    program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({
+        oracle: oraclePda(oracleId),
+        masterContract,
+        admin,
+        oracleAuthority: admin,
+      })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+
+  const registry = async () =>
+    (await program.account.masterInsuranceContract.fetch(masterContract)).oracleRegistry.map(
+      (key) => key.toBase58()
+    );
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  it("allows one authority to register oracles under different ids", async () => {
+    await register(firstId);
+    await register(secondId);
+
+    const keys = await registry();
+    expect(keys).to.include(oraclePda(firstId).toBase58());
+    expect(keys).to.include(oraclePda(secondId).toBase58());
+  });
+
+  it("rejects registrations that leave out registered oracles", async () => {
+    let rejected = false;
+    try {
+      await program.methods
+        .registerOracle(`registry-c-${suffix}`, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: oraclePda(`registry-c-${suffix}`), masterContract, admin, oracleAuthority: admin })
+        .rpc();
+    } catch (err) {
+      rejected = true;
+      expect(String(err)).to.include("OracleNotRegistered");
+    }
+    expect(rejected).to.equal(true);
+  });
+
+  it("rejects registering the same oracle id twice", async () => {
+    let rejected = false;
+    try {
+      await register(firstId);
+    } catch {
+      rejected = true;
+    }
+    expect(rejected).to.equal(true);
+  });
+
+  it("frees an oracle id once it is unregistered", async () => {
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({
+        oracle: oraclePda(secondId),
+        oracleRetirement: oracleRetirementPda(program, oraclePda(secondId)),
+        masterContract,
+        admin,
+      })
+      .rpc();
+    expect(await registry()).to.not.include(oraclePda(secondId).toBase58());
+
+    await register(secondId);
+    expect(await registry()).to.include(oraclePda(secondId).toBase58());
+  });
+
+  it("keeps live oracles when syncing the registry", async () => {
+    const before = await registry();
+
+    await program.methods
+      .syncOracleRegistry()
+      .accountsPartial({ masterContract, admin })
+      .remainingAccounts([
+        { pubkey: oraclePda(firstId), isSigner: false, isWritable: false },
+        { pubkey: oraclePda(secondId), isSigner: false, isWritable: false },
+      ])
+      .rpc();
+
+    expect(await registry()).to.deep.equal(before);
+  });
+});
@@ -0,0 +1,122 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  ensureMasterContract,
+  masterContractPda,
+  oracleRetirementPda,
+  registeredOracles,
+  signOracleData,
+  walletKeypair,
+} from "./helpers";
+
+describe("oracle resignation", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const suffix = Date.now();
+  const [silentId, reportingId] = ["silent", "reporting"].map((name) => `resign-${name}-${suffix}`);
+  const [silent, reporting] = [silentId, reportingId].map(
+    (oracleId) =>
+      PublicKey.findProgramAddressSync([Buffer.from("oracle"), Buffer.from(oracleId)], program.programId)[0]
+  );
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const resign = (oracle: PublicKey, authority?: Keypair) =>
+    program.methods
+      .resignOracle()
+      .accountsPartial({ oracle, oracleAuthority: authority?.publicKey ?? admin, masterContract })
+      .signers(authority ? [authority] : [])
+      .rpc();
+
+  const complete = (oracle: PublicKey) =>
+    program.methods
+      .completeOracleResignation()
+      .accountsPartial({
+        oracle,
+        masterContract,
+        oracleRetirement: oracleRetirementPda(program, oracle),
+        oracleAuthority: admin,
+      })
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+
+    for (const [oracleId, oracle] of [[silentId, silent], [reportingId, reporting]] as const) {
+      await program.methods
+        .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+    }
+
+    const { data, attestation } = signOracleData(program, walletKeypair(program), reportingId, {
+      version: 0x10,
+      value: new BN(100),
+      timestamp: new BN(Math.floor(Date.now() / 1000)),
+      confidence: new BN(95),
+      nonce: new BN(1),
+      extensions: Buffer.alloc(0),
+    });
+    await program.methods
+      .updateOracleData(data)
+      .accountsPartial({ oracle: reporting, oracleAuthority: admin })
+      .preInstructions([attestation])
+      .rpc();
+  });
+
+  after(async () => {
+    // The admin can still remove a resigning oracle outright
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({
+        oracle: reporting,
+        oracleRetirement: oracleRetirementPda(program, reporting),
+        masterContract,
+        admin,
+      })
+      .rpc();
+  });
+
+  it("only lets the oracle authority resign", async () => {
+    await expectError(resign(silent, Keypair.generate()), "Unauthorized");
+    await expectError(complete(silent), "OracleNotResigning");
+  });
+
+  it("holds a recent reporter in the registry until the cooldown elapses", async () => {
+    await resign(reporting);
+    const oracle = await program.account.oracle.fetch(reporting);
+    expect(oracle.active).to.equal(0);
+    expect(oracle.retiresAt.toNumber()).to.be.greaterThan(Math.floor(Date.now() / 1000) + 6 * 86400);
+
+    await expectError(resign(reporting), "OracleResigning");
+    await expectError(complete(reporting), "OracleResignationCooldown");
+  });
+
+  it("lets an oracle that never reported leave at once", async () => {
+    await resign(silent);
+    await complete(silent);
+
+    expect(await provider.connection.getAccountInfo(silent)).to.be.null;
+    const { oracleRegistry } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(oracleRegistry.map((oracle) => oracle.toBase58())).to.not.include(silent.toBase58());
+    const retirement = await program.account.oracleRetirement.fetch(oracleRetirementPda(program, silent));
+    expect(retirement.plan).to.deep.equal({ suspendCoverage: {} });
+  });
+});
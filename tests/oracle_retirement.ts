@@ -0,0 +1,194 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  currentTreasury,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  policyHistoryPda,
+  registeredOracles,
+} from "./helpers";
+
+describe("oracle retirement", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const suffix = Date.now();
+  const oraclePda = (oracleId: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("oracle"), Buffer.from(oracleId)], program.programId)[0];
+  const [retiredId, suspendedId, replacementId] = ["retired", "suspended", "replacement"].map(
+    (name) => `${name}-${suffix}`
+  );
+  const [retired, suspended, replacement] = [retiredId, suspendedId, replacementId].map(oraclePda);
+
+  const register = async (oracleId: string) =>
+    program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle: oraclePda(oracleId), masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+
+  const unregister = (oracle: PublicKey, plan: { replace: [PublicKey] } | { suspendCoverage: {} }) =>
+    program.methods
+      .unregisterOracle(plan)
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+
+  // Each policy is followed by its history, then come the templates
+  const repoint = (oracle: PublicKey, policies: PublicKey[], templates: PublicKey[] = []) => {
+    const accounts = [...policies.flatMap((policy) => [policy, policyHistoryPda(program, policy)]), ...templates];
+    return program.methods
+      .repointPolicies()
+      .accountsPartial({ oracleRetirement: oracleRetirementPda(program, oracle), masterContract, cranker: admin })
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+  };
+
+  const closeRetirement = (oracle: PublicKey) =>
+    program.methods
+      .closeOracleRetirement()
+      .accountsPartial({ oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+
+  // Template version 1 of a product settled by `oracle`
+  const templateOn = async (oracle: PublicKey) => {
+    const productId = suffix % 1_000_000_000;
+    const productTemplate = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_template"),
+        new BN(productId).toArrayLike(Buffer, "le", 4),
+        new BN(1).toArrayLike(Buffer, "le", 2),
+      ],
+      program.programId
+    )[0];
+    const { triggerConditions, oracleConfig, ...params } = defaultPolicyParams(premiumAmount);
+    await program.methods
+      .createProductTemplate({
+        productId,
+        version: 1,
+        terms: {
+          insuranceType: params.insuranceType,
+          comparisonOperator: triggerConditions.comparisonOperator,
+          dataSource: triggerConditions.dataSource,
+          gracePeriod: triggerConditions.gracePeriod,
+          minThreshold: 40.0,
+          maxThreshold: 80.0,
+          oracleConfig: { ...oracleConfig, oracleAddress: oracle },
+          pricing: { rateAtMinThresholdBps: 1_000, rateAtMaxThresholdBps: 500, minimumPremium: premiumAmount },
+          minCoverage: params.coverageAmount,
+          maxCoverage: params.coverageAmount,
+          minDurationDays: 30,
+          maxDurationDays: 30,
+          deductibleBps: 0,
+          deductibleMode: params.deductibleMode,
+          waitingPeriodHours: 0,
+          premiumPaymentFrequency: params.premiumPaymentFrequency,
+          currency: params.payoutCurrency,
+          riskAssessmentScore: params.riskAssessmentScore,
+        },
+      })
+      .accountsPartial({ productTemplate, masterContract, admin })
+      .rpc();
+    return productTemplate;
+  };
+
+  const policyOn = (oracle: PublicKey) =>
+    createActivePolicy(program, premiumAmount, {
+      oracleConfig: { ...defaultPolicyParams(premiumAmount).oracleConfig, oracleAddress: oracle },
+    });
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+    for (const oracleId of [retiredId, suspendedId, replacementId]) {
+      await register(oracleId);
+    }
+  });
+
+  after(async () => {
+    // Free the registry slot for later suites
+    await unregister(replacement, { suspendCoverage: {} });
+    for (const oracle of [retired, suspended, replacement]) {
+      await closeRetirement(oracle);
+    }
+  });
+
+  it("re-points policies to a replacement with an endorsement", async () => {
+    const policy = await policyOn(retired);
+    await unregister(retired, { replace: [replacement] });
+
+    await repoint(retired, [policy]);
+    const { oracleConfig } = await program.account.policy.fetch(policy);
+    const { endorsements } = await program.account.policyHistory.fetch(policyHistoryPda(program, policy));
+    expect(oracleConfig.oracleAddress.toBase58()).to.equal(replacement.toBase58());
+    expect(endorsements[endorsements.length - 1].kind).to.deep.equal({ oracleRepoint: {} });
+
+    // A second pass finds nothing left to change
+    await repoint(retired, [policy]);
+    const retirement = await program.account.oracleRetirement.fetch(oracleRetirementPda(program, retired));
+    expect(retirement.policiesRepointed).to.equal(1);
+  });
+
+  it("suspends coverage and deactivates templates until a replacement is set", async () => {
+    const policy = await policyOn(suspended);
+    const template = await templateOn(suspended);
+    await unregister(suspended, { suspendCoverage: {} });
+    await repoint(suspended, [policy], [template]);
+    expect((await program.account.productTemplate.fetch(template)).active).to.equal(false);
+
+    const { coverageSuspendedAt, endDate } = await program.account.policy.fetch(policy);
+    expect(coverageSuspendedAt).to.not.be.null;
+    await expectError(
+      program.methods
+        .payPremium(premiumAmount)
+        .accountsPartial({
+          payer: admin,
+          policyAccount: policy,
+          masterContract,
+          policyTokenAccount: null,
+          subsidy: null,
+          treasury: await currentTreasury(program),
+          payerTokenAccount: null,
+          treasuryUsdcAccount: null,
+          feeVault: null,
+          feeVaultUsdcAccount: null,
+          underwriterLedger: null,
+          tokenProgram: null,
+        })
+        .rpc(),
+      "CoverageSuspended"
+    );
+
+    await program.methods
+      .setOracleReplacement(replacement)
+      .accountsPartial({ oracleRetirement: oracleRetirementPda(program, suspended), masterContract, admin })
+      .rpc();
+    await repoint(suspended, [policy]);
+
+    const resumed = await program.account.policy.fetch(policy);
+    expect(resumed.coverageSuspendedAt).to.be.null;
+    expect(resumed.oracleConfig.oracleAddress.toBase58()).to.equal(replacement.toBase58());
+    expect(resumed.endDate.gte(endDate)).to.equal(true);
+  });
+});
@@ -0,0 +1,72 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, AnchorError } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { applyParamChange, ensureMasterContract, masterContractPda } from "./helpers";
+
+describe("oracle risk parameters", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const masterContract = masterContractPda(program);
+
+  const defaults = {
+    failureThreshold: 5,
+    reputationPenalty: 3,
+    accuracyPenalty: 5,
+    recoveryRate: 1,
+    maxClockSkewSecs: 30,
+  };
+  const setRiskParams = (params: typeof defaults) =>
+    applyParamChange(program, { oracleRiskParams: [params] }, null);
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  after(async () => {
+    await setRiskParams(defaults);
+  });
+
+  it("starts from the original circuit breaker settings", async () => {
+    const { oracleRiskParams } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(oracleRiskParams).to.deep.equal(defaults);
+  });
+
+  it("rejects a breaker that can never trip and penalties off the 0-100 scale", async () => {
+    await expectError(setRiskParams({ ...defaults, failureThreshold: 0 }), "InvalidInput");
+    await expectError(setRiskParams({ ...defaults, reputationPenalty: 101 }), "InvalidInput");
+    await expectError(setRiskParams({ ...defaults, recoveryRate: 101 }), "InvalidInput");
+  });
+
+  it("caps the clock skew allowed on oracle timestamps at five minutes", async () => {
+    await expectError(setRiskParams({ ...defaults, maxClockSkewSecs: 301 }), "InvalidInput");
+    await setRiskParams({ ...defaults, maxClockSkewSecs: 300 });
+    const { oracleRiskParams } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(oracleRiskParams.maxClockSkewSecs).to.equal(300);
+  });
+
+  it("applies tuned settings through the timelock", async () => {
+    const tuned = {
+      failureThreshold: 3,
+      reputationPenalty: 10,
+      accuracyPenalty: 20,
+      recoveryRate: 2,
+      maxClockSkewSecs: 10,
+    };
+    await setRiskParams(tuned);
+    const { oracleRiskParams } = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(oracleRiskParams).to.deep.equal(tuned);
+  });
+});
@@ -0,0 +1,107 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey, Secp256k1Program, Transaction } from "@solana/web3.js";
+import { secp256k1 } from "@noble/curves/secp256k1";
+import { keccak_256 } from "@noble/hashes/sha3";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, masterContractPda, oracleMessage, registeredOracles } from "./helpers";
+
+describe("secp256k1 oracle attestation", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const authority = provider.wallet.publicKey;
+
+  const masterContract = masterContractPda(program);
+  const oracleId = `secp-${Date.now()}`;
+  const [oracle] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  );
+
+  const privateKey = secp256k1.utils.randomPrivateKey();
+  const ethAddress = Secp256k1Program.publicKeyToEthAddress(
+    secp256k1.getPublicKey(privateKey, false).slice(1)
+  );
+
+  const signedUpdate = async (nonce: number, recoveryIdOffset = 0) => {
+    const data = {
+      version: 0x10,
+      value: new BN(120),
+      timestamp: new BN(Math.floor(Date.now() / 1000) - 5),
+      confidence: new BN(95),
+      nonce: new BN(nonce),
+      extensions: Buffer.alloc(0),
+    };
+    const message = oracleMessage(program, oracleId, data);
+    const signed = secp256k1.sign(keccak_256(message), privateKey);
+    const signature = signed.toCompactRawBytes();
+
+    return new Transaction().add(
+      Secp256k1Program.createInstructionWithEthAddress({
+        ethAddress,
+        message,
+        signature,
+        recoveryId: signed.recovery ^ recoveryIdOffset,
+      }),
+      await program.methods
+        .updateOracleData({ ...data, signature: Array.from(signature) })
+        .accountsPartial({ oracle, oracleAuthority: authority })
+        .instruction()
+    );
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { secp256k1: {} }, ethAddress)
+      .accountsPartial({ oracle, masterContract, admin: authority, oracleAuthority: authority })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+  });
+
+  it("rejects a secp256k1 registration without a 20-byte address", async () => {
+    const badId = `secp-bad-${Date.now()}`;
+    const [badOracle] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), Buffer.from(badId)],
+      program.programId
+    );
+    let rejected = false;
+    try {
+      await program.methods
+        .registerOracle(badId, { pyth: {} }, "rainfall-mm", { secp256k1: {} }, Buffer.alloc(0))
+        .accountsPartial({ oracle: badOracle, masterContract, admin: authority, oracleAuthority: authority })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+    } catch (err) {
+      rejected = true;
+      expect(String(err)).to.include("InvalidSignerAddress");
+    }
+    expect(rejected).to.equal(true);
+  });
+
+  it("accepts an update signed by the registered Ethereum key", async () => {
+    await provider.sendAndConfirm(await signedUpdate(1));
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.signatureScheme).to.equal(1);
+    expect(Buffer.from(account.ethAddress).equals(ethAddress)).to.equal(true);
+    expect(account.lastNonce.toNumber()).to.equal(1);
+    expect(account.updateCount.toNumber()).to.equal(1);
+  });
+
+  it("rejects an update with the wrong recovery id", async () => {
+    let rejected = false;
+    try {
+      await provider.sendAndConfirm(await signedUpdate(2, 1));
+    } catch {
+      rejected = true;
+    }
+    expect(rejected).to.equal(true);
+
+    const account = await program.account.oracle.fetch(oracle);
+    expect(account.lastNonce.toNumber()).to.equal(1);
+  });
+});
@@ -0,0 +1,122 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  typeConfigPda,
+  solAmount,
+  UNCAPPED_EXPOSURE,
+} from "./helpers";
+
+describe("payout floors", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  // 75 against a threshold of 50 is a 50% deviation, paying half the coverage
+  const payout = new BN(LAMPORTS_PER_SOL / 2);
+
+  const globalConfig = (minPayoutLamports: BN) => ({
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps: 0,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(minPayoutLamports),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const simulate = (policy: PublicKey) =>
+    program.methods
+      .simulatePayout(new BN(75))
+      .accountsPartial({ policy, masterContract, treasury, breachWatch: null })
+      .view();
+
+  const trigger = async (policy: PublicKey) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  after(async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(new BN(0))] }, null);
+  });
+
+  it("pays a claim at exactly the severity floor", async () => {
+    const policy = await createActivePolicy(program, premiumAmount, { minSeverityBps: 5_000 });
+    const quote = await simulate(policy);
+    expect(quote.ineligibility).to.equal(null);
+    expect(quote.amount.toString()).to.equal(payout.toString());
+  });
+
+  it("rejects a claim one basis point below the severity floor", async () => {
+    const policy = await createActivePolicy(program, premiumAmount, { minSeverityBps: 5_001 });
+    const quote = await simulate(policy);
+    expect(quote.ineligibility).to.deep.equal({ belowMinimum: {} });
+    expect(quote.severityPercentage).to.equal(50);
+    expect(quote.amount.toNumber()).to.equal(0);
+
+    await expectError(trigger(policy), "PayoutBelowMinimum");
+  });
+
+  it("pays a claim at exactly the minimum payout", async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(payout)] }, null);
+    const policy = await createActivePolicy(program, premiumAmount);
+    expect((await simulate(policy)).ineligibility).to.equal(null);
+  });
+
+  it("rejects a claim one lamport below the minimum payout", async () => {
+    await applyParamChange(program, { globalConfig: [globalConfig(payout.addn(1))] }, null);
+    const policy = await createActivePolicy(program, premiumAmount);
+    expect((await simulate(policy)).ineligibility).to.deep.equal({ belowMinimum: {} });
+
+    await expectError(trigger(policy), "PayoutBelowMinimum");
+  });
+});
@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("payout priority aging", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+  });
+
+  /// Trigger a payout on a fresh policy and return its pending payout
+  const triggerPayout = async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    return pendingPayout;
+  };
+
+  it("lets anyone persist the aged priority of a waiting payout", async () => {
+    const pendingPayout = await triggerPayout();
+
+    const cranker = Keypair.generate();
+    await program.methods
+      .recomputePriority()
+      .accountsPartial({ pendingPayout, masterContract, cranker: cranker.publicKey })
+      .signers([cranker])
+      .rpc();
+
+    // A payout that has waited less than an hour keeps its base priority
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.basePriority).to.be.greaterThan(0);
+    expect(payout.priority).to.equal(payout.basePriority);
+  });
+
+  it("derives the priority from the type's severity mapping", async () => {
+    const typeConfig = typeConfigPda(program);
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfig);
+    const setPoints = (severityPriorityPoints: number) =>
+      applyParamChange(
+        program,
+        { typeConfig: { insuranceType: { weather: {} }, params: { ...params, severityPriorityPoints } } },
+        typeConfig
+      );
+
+    await setPoints(0);
+    try {
+      const payout = await program.account.pendingPayout.fetch(await triggerPayout());
+      expect(payout.priority).to.equal(params.basePriority);
+    } finally {
+      await setPoints(params.severityPriorityPoints);
+    }
+  });
+});
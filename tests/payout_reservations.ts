@@ -0,0 +1,122 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda, typeConfigPda } from "./helpers";
+
+describe("payout reservations", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+
+  const pendingPayoutPda = (policyId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const trigger = async (policy: PublicKey) => {
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+    return pendingPayoutPda(id);
+  };
+
+  const unreserved = async () => {
+    const { totalSolBalance, totalUsdcBalance, reservedForPayouts } = await program.account.treasury.fetch(treasury);
+    return totalSolBalance.add(totalUsdcBalance).sub(reservedForPayouts);
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("rejects a second trigger the unreserved balance cannot cover", async function () {
+    // Identical policies and oracle values assess to identical payouts
+    const first = await createActivePolicy(program, premiumAmount);
+    const second = await createActivePolicy(program, premiumAmount);
+
+    const { reservedForPayouts: reservedBefore } = await program.account.treasury.fetch(treasury);
+    const pendingPayout = await trigger(first);
+    const { amount, status } = await program.account.pendingPayout.fetch(pendingPayout);
+    const { reservedForPayouts } = await program.account.treasury.fetch(treasury);
+    expect(reservedForPayouts.sub(reservedBefore).toString()).to.equal(amount.toString());
+
+    // Leave one lamport less than the second payout unreserved
+    const drain = (await unreserved()).sub(amount).addn(1);
+    const { totalSolBalance } = await program.account.treasury.fetch(treasury);
+    if (drain.gt(totalSolBalance)) {
+      this.skip();
+    }
+    if (drain.gtn(0)) {
+      await program.methods
+        .withdrawFunds(drain, { sol: {} }, { emergencyWithdrawal: {} })
+        .accountsPartial({ treasury, masterContract, admin, recipient: admin, recipientUsdcAccount: null })
+        .rpc();
+    }
+
+    try {
+      await trigger(second);
+      expect.fail("triggered a payout the treasury cannot cover");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InsufficientTreasury");
+    } finally {
+      if (drain.gtn(0)) {
+        await program.methods.depositFunds(drain, { sol: {} }).accountsPartial({ treasury, depositor: admin }).rpc();
+      }
+    }
+
+    // The reserved payout still executes, backed by real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: amount.toNumber() })
+      )
+    );
+    if ("pendingApproval" in status) {
+      await program.methods.approvePayout().accountsPartial({ pendingPayout, masterContract, admin }).rpc();
+    }
+    await program.methods
+      .executePayout()
+      .accountsPartial({
+        pendingPayout,
+        policy: first,
+        masterContract,
+        treasury,
+        beneficiary: admin,
+        executor: admin,
+        vestingPayout: null,
+        treasuryUsdcAccount: null,
+        beneficiaryUsdcAccount: null,
+        usdcMint: null,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .rpc();
+    expect((await program.account.treasury.fetch(treasury)).reservedForPayouts.toString()).to.equal(
+      reservedBefore.toString()
+    );
+  });
+});
@@ -0,0 +1,149 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda, typeConfigPda } from "./helpers";
+
+describe("payout vesting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+
+  const pendingPayoutPda = (policyId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  const vestingPayoutPda = (policyId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("vesting_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  // Trigger and execute a payout on a fresh vesting policy, returning its vesting payout
+  const startVesting = async (tranches: number, intervalSecs: number) => {
+    const policy = await createActivePolicy(program, premiumAmount, {
+      payoutVesting: { tranches, intervalSecs: new BN(intervalSecs) },
+    });
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+    const pendingPayout = pendingPayoutPda(id);
+    const { amount, status } = await program.account.pendingPayout.fetch(pendingPayout);
+
+    // Back the payout with real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: amount.toNumber() })
+      )
+    );
+    if ("pendingApproval" in status) {
+      await program.methods.approvePayout().accountsPartial({ pendingPayout, masterContract, admin }).rpc();
+    }
+
+    const { reservedForPayouts: reservedBefore } = await program.account.treasury.fetch(treasury);
+    const vestingPayout = vestingPayoutPda(id);
+    await program.methods
+      .executePayout()
+      .accountsPartial({
+        pendingPayout,
+        policy,
+        masterContract,
+        treasury,
+        beneficiary: admin,
+        executor: admin,
+        vestingPayout,
+        treasuryUsdcAccount: null,
+        beneficiaryUsdcAccount: null,
+        usdcMint: null,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .rpc();
+    return { vestingPayout, amount, reservedBefore };
+  };
+
+  const release = (vestingPayout: PublicKey) =>
+    program.methods
+      .releasePayoutTranche()
+      .accountsPartial({
+        vestingPayout,
+        masterContract,
+        treasury,
+        beneficiary: admin,
+        rentPayer: admin,
+        executor: admin,
+        treasuryUsdcAccount: null,
+        beneficiaryUsdcAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("pays the first tranche and keeps the rest reserved", async () => {
+    const { vestingPayout, amount, reservedBefore } = await startVesting(3, 3_600);
+
+    const state = await program.account.vestingPayout.fetch(vestingPayout);
+    expect(state.totalAmount.toString()).to.equal(amount.toString());
+    expect(state.tranchesReleased).to.equal(1);
+    expect(state.releasedAmount.toString()).to.equal(amount.divn(3).toString());
+
+    const { reservedForPayouts } = await program.account.treasury.fetch(treasury);
+    expect(reservedBefore.sub(reservedForPayouts).toString()).to.equal(state.releasedAmount.toString());
+
+    try {
+      await release(vestingPayout);
+      expect.fail("released a tranche before it was due");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("TrancheNotDue");
+    }
+
+    // Cancelling returns the unreleased tranches to reserves
+    await program.methods
+      .cancelVestingPayout("fraud review")
+      .accountsPartial({ vestingPayout, masterContract, treasury, rentPayer: admin, admin })
+      .rpc();
+    const { reservedForPayouts: reservedAfter } = await program.account.treasury.fetch(treasury);
+    expect(reservedBefore.sub(reservedAfter).toString()).to.equal(amount.toString());
+    expect(await provider.connection.getAccountInfo(vestingPayout)).to.be.null;
+  });
+
+  it("streams the remaining tranches on schedule and closes", async () => {
+    const { vestingPayout, amount, reservedBefore } = await startVesting(2, 1);
+
+    await sleep(2_000);
+    await release(vestingPayout);
+
+    const { reservedForPayouts } = await program.account.treasury.fetch(treasury);
+    expect(reservedBefore.sub(reservedForPayouts).toString()).to.equal(amount.toString());
+    expect(await provider.connection.getAccountInfo(vestingPayout)).to.be.null;
+  });
+});
@@ -0,0 +1,56 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("policy expiry", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("issues policy indexes independently of the active count", async () => {
+    const before = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policy = await createActivePolicy(program, premiumAmount);
+
+    const after = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(after.policiesIssued.toNumber()).to.equal(before.policiesIssued.toNumber() + 1);
+    expect(after.activePoliciesCount.toNumber()).to.equal(before.activePoliciesCount.toNumber() + 1);
+    expect((await program.account.policy.fetch(policy)).index.toString()).to.equal(before.policiesIssued.toString());
+  });
+
+  it("refuses to expire a policy before its end date", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const cranker = Keypair.generate();
+
+    await expectError(
+      program.methods
+        .expirePolicy()
+        .accountsPartial({ policy, masterContract, treasury, cranker: cranker.publicKey })
+        .signers([cranker])
+        .rpc(),
+      "PolicyNotExpired"
+    );
+    expect((await program.account.policy.fetch(policy)).status).to.deep.equal({ active: {} });
+  });
+});
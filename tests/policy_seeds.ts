@@ -0,0 +1,89 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, currentTreasury, ensureTreasury, masterContractPda, typeConfigPda } from "./helpers";
+
+describe("policy seeds", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const beneficiary = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  let claimed: PublicKey;
+  let other: PublicKey;
+
+  const triggerPayout = async (policy: PublicKey, policyId: BN) => {
+    const { startDate } = await program.account.policy.fetch(policy);
+    return program.methods
+      .triggerPayout(policyId, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: await currentTreasury(program),
+        typeConfig: typeConfigPda(program),
+        beneficiary,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureTreasury(program);
+    claimed = await createActivePolicy(program, premiumAmount);
+    other = await createActivePolicy(program, premiumAmount);
+  });
+
+  it("stores the canonical bump of the policy address", async () => {
+    const policy = await program.account.policy.fetch(claimed);
+    const [address, bump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("policy"), policy.creator.toBuffer(), policy.index.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    expect(address.toBase58()).to.equal(claimed.toBase58());
+    expect(policy.bump).to.equal(bump);
+  });
+
+  it("numbers policies from the master contract's sequence", async () => {
+    const first = await program.account.policy.fetch(claimed);
+    const second = await program.account.policy.fetch(other);
+    const { nextPolicyId } = await program.account.masterInsuranceContract.fetch(masterContract);
+
+    expect(first.id.toNumber()).to.be.greaterThan(0);
+    expect(second.id.toNumber()).to.equal(first.id.toNumber() + 1);
+    expect(nextPolicyId.toNumber()).to.be.greaterThan(second.id.toNumber());
+  });
+
+  it("rejects a payout for a policy passed under another policy's id", async () => {
+    const policy = await program.account.policy.fetch(claimed);
+
+    try {
+      await triggerPayout(other, policy.id);
+      expect.fail("payout was triggered against the wrong policy account");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("PolicyNotFound");
+    }
+  });
+
+  it("accepts a payout at the canonical policy address", async () => {
+    const policy = await program.account.policy.fetch(claimed);
+    await triggerPayout(claimed, policy.id);
+
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policy.id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.policyId.toString()).to.equal(policy.id.toString());
+  });
+});
@@ -0,0 +1,74 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("portfolio report", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const publisher = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const weatherStats = async () =>
+    (await program.account.masterInsuranceContract.fetch(masterContract)).portfolioStats[0];
+
+  const publish = async () => {
+    const { portfolioReportCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const [portfolioReport] = PublicKey.findProgramAddressSync(
+      [Buffer.from("portfolio_report"), portfolioReportCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .publishPortfolioReport()
+      .accountsPartial({ portfolioReport, masterContract, publisher })
+      .rpc();
+    return program.account.portfolioReport.fetch(portfolioReport);
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+  });
+
+  it("tracks premiums and exposure per insurance type", async () => {
+    const before = await weatherStats();
+
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { coverageAmount } = await program.account.policy.fetch(policy);
+
+    const after = await weatherStats();
+    expect(after.premiumsCollected.sub(before.premiumsCollected).toNumber()).to.equal(premiumAmount.toNumber());
+    expect(after.policiesActivated.sub(before.policiesActivated).toNumber()).to.equal(1);
+    expect(after.activeExposure.sub(before.activeExposure).toString()).to.equal(coverageAmount.toString());
+  });
+
+  it("publishes a numbered report covering every insurance type", async () => {
+    const { portfolioReportCount, openPayoutCount } = await program.account.masterInsuranceContract.fetch(
+      masterContract
+    );
+    const stats = await weatherStats();
+
+    const report = await publish();
+    expect(report.reportIndex.toString()).to.equal(portfolioReportCount.toString());
+    expect(report.publisher.toBase58()).to.equal(publisher.toBase58());
+    expect(report.types).to.have.length(5);
+    expect(report.types[0].insuranceType).to.deep.equal({ weather: {} });
+    expect(report.types[0].stats.premiumsCollected.toString()).to.equal(stats.premiumsCollected.toString());
+
+    const expectedLossRatio = stats.premiumsCollected.isZero()
+      ? 0
+      : stats.payoutsDisbursed.muln(10_000).div(stats.premiumsCollected).toNumber();
+    expect(report.types[0].lossRatioBps).to.equal(expectedLossRatio);
+    expect(report.openPayoutCount.toString()).to.equal(openPayoutCount.toString());
+    expect(report.timestamp.toNumber()).to.be.greaterThan(0);
+
+    const next = await publish();
+    expect(next.reportIndex.toNumber()).to.equal(portfolioReportCount.toNumber() + 1);
+    expect(next.sequence.gt(report.sequence)).to.be.true;
+  });
+});
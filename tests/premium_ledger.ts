@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyHistoryPda,
+} from "./helpers";
+
+describe("premium ledger", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const holder = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+  let policyAccount: PublicKey;
+
+  const payPremium = () =>
+    program.methods
+      .payPremium(premiumAmount)
+      .accountsPartial({
+        payer: holder,
+        policyAccount,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury,
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    policyAccount = await createActivePolicy(program, premiumAmount, {
+      maxPayoutPerIncident: new BN(LAMPORTS_PER_SOL / 4),
+    });
+  });
+
+  it("records each payment with a running total", async () => {
+    await payPremium();
+
+    const policy = await program.account.policy.fetch(policyAccount);
+    const { premiumHistory } = await program.account.policyHistory.fetch(policyHistoryPda(program, policyAccount));
+    expect(policy.totalPremiumsPaid.toNumber()).to.equal(2 * premiumAmount.toNumber());
+    expect(premiumHistory).to.have.length(2);
+    expect(premiumHistory[1].amount.toNumber()).to.equal(premiumAmount.toNumber());
+    expect(premiumHistory[1].timestamp.toString()).to.equal(policy.lastPremiumPaid.toString());
+  });
+
+  it("keeps the last twelve payments", async () => {
+    for (let i = 0; i < 11; i++) {
+      await payPremium();
+    }
+
+    const policy = await program.account.policy.fetch(policyAccount);
+    const { premiumHistory } = await program.account.policyHistory.fetch(policyHistoryPda(program, policyAccount));
+    expect(premiumHistory).to.have.length(12);
+    expect(policy.totalPremiumsPaid.toNumber()).to.equal(13 * premiumAmount.toNumber());
+  });
+
+  it("refunds reduced coverage from the unearned part of the latest payment", async () => {
+    const before = await program.account.policy.fetch(policyAccount);
+    const treasuryLamports = await provider.connection.getBalance(treasury);
+
+    await program.methods
+      .adjustCoverage(before.coverageAmount.divn(2), new BN(0))
+      .accountsPartial({ policyAccount, masterContract, treasury, owner: holder, policyTokenAccount: null })
+      .rpc();
+
+    // Half the coverage of a payment made moments ago refunds about half of it
+    const refund = before.totalPremiumsPaid.sub((await program.account.policy.fetch(policyAccount)).totalPremiumsPaid);
+    expect(refund.toNumber()).to.be.at.most(premiumAmount.toNumber() / 2);
+    expect(refund.toNumber()).to.be.at.least(premiumAmount.toNumber() / 2 - 1_000);
+    // The refund leaves the treasury rather than just its books
+    expect(treasuryLamports - (await provider.connection.getBalance(treasury))).to.equal(refund.toNumber());
+  });
+});
@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  currentTreasury,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  ensureFeeVault,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  solAmount,
+  UNCAPPED_EXPOSURE,
+} from "./helpers";
+
+/// `set_test_clock` only exists in `test-clock` builds, so it is missing from the generated types
+type TestClockMethods = {
+  setTestClock(timestamp: BN | null): {
+    accountsPartial(accounts: { masterContract: PublicKey; admin: PublicKey }): { rpc(): Promise<string> };
+  };
+};
+
+describe("premium payments during pauses", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  const DAY = 86_400;
+
+  const globalConfig = (lateFeeBps: number) => ({
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold: null,
+    underwritingBondBps: 0,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+
+  const setTestClock = (timestamp: number | null) =>
+    (program.methods as unknown as TestClockMethods)
+      .setTestClock(timestamp === null ? null : new BN(timestamp))
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+
+  const pause = (blockPremiums: boolean) =>
+    program.methods.pauseContract(blockPremiums).accountsPartial({ masterContract, admin }).rpc();
+
+  const resume = () =>
+    program.methods.resumeContract().accountsPartial({ masterContract, admin }).rpc({ commitment: "confirmed" });
+
+  const payPremium = async (policyAccount: PublicKey, amount: BN, feeVault: PublicKey | null = null) =>
+    program.methods
+      .payPremium(amount)
+      .accountsPartial({
+        payer: admin,
+        policyAccount,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury: await currentTreasury(program),
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc({ commitment: "confirmed" });
+
+  /// The first event of one name emitted by a confirmed transaction
+  const eventOf = async (signature: string, name: string) => {
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(confirmed!.meta!.logMessages!)].find((e) => e.name === name)!.data;
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+    await applyParamChange(program, { globalConfig: [globalConfig(100)] }, null);
+  });
+
+  after(async () => {
+    if ((await program.account.masterInsuranceContract.fetch(masterContract)).isPaused) {
+      await resume();
+    }
+    if (program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      await setTestClock(null);
+    }
+    await applyParamChange(program, { globalConfig: [globalConfig(0)] }, null);
+  });
+
+  it("keeps premiums payable during a pause that allows them", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    await pause(false);
+    try {
+      await payPremium(policy, premiumAmount);
+    } finally {
+      await resume();
+    }
+  });
+
+  it("excludes a 3-day pause spanning the due date from the late fee", async function () {
+    if (!program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      this.skip();
+    }
+    const policy = await createActivePolicy(program, premiumAmount, { policyDurationDays: 90 });
+    const { lastPremiumPaid } = await program.account.policy.fetch(policy);
+    const due = lastPremiumPaid.toNumber() + 30 * DAY;
+
+    // Blocked from a day before the due date to two days after
+    await setTestClock(due - DAY);
+    await pause(true);
+    await expectError(payPremium(policy, premiumAmount), "PremiumPaymentsPaused");
+
+    await setTestClock(due + 2 * DAY);
+    const resumed = await eventOf(await resume(), "contractResumed");
+    expect(resumed.premiumBlockedSecs.toNumber()).to.equal(3 * DAY);
+
+    // Three days past due, only the one while payments were open is charged
+    await setTestClock(due + 3 * DAY + 60);
+    const fee = premiumAmount.divn(100);
+    const feeVault = await ensureFeeVault(program);
+    const vaultLamports = await provider.connection.getBalance(feeVault);
+    const paid = await eventOf(await payPremium(policy, premiumAmount.add(fee), feeVault), "premiumPaid");
+    expect(paid.lateFee.toString()).to.equal(fee.toString());
+    expect(await provider.connection.getBalance(feeVault, "confirmed")).to.equal(vaultLamports + fee.toNumber());
+    const { solCollected } = await program.account.feeVault.fetch(feeVault, "confirmed");
+    expect(solCollected.gte(fee)).to.equal(true);
+  });
+});
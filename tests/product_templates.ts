@@ -0,0 +1,191 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  DEFAULT_REGION_CODE,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  oracleRetirementPda,
+  policyPda,
+  registeredOracles,
+  typeConfigPda,
+} from "./helpers";
+
+describe("product templates", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  let treasury: PublicKey;
+
+  const productId = Date.now() % 1_000_000_000;
+  const oracleId = `template-${productId}`;
+  const oracle = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle"), Buffer.from(oracleId)],
+    program.programId
+  )[0];
+
+  const templatePda = (version: number) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_template"),
+        new BN(productId).toArrayLike(Buffer, "le", 4),
+        new BN(version).toArrayLike(Buffer, "le", 2),
+      ],
+      program.programId
+    )[0];
+
+  const terms = (rateAtMinThresholdBps: number) => ({
+    insuranceType: { weather: {} },
+    comparisonOperator: { greaterThan: {} },
+    dataSource: "rainfall",
+    gracePeriod: new BN(0),
+    minThreshold: 40.0,
+    maxThreshold: 80.0,
+    oracleConfig: {
+      oracleAddress: oracle,
+      dataFeedId: "rainfall-mm",
+      requiredConfirmations: 1,
+      stalenessThreshold: new BN(300),
+      maxConfidenceRatioBps: null,
+    },
+    pricing: {
+      rateAtMinThresholdBps,
+      rateAtMaxThresholdBps: 400,
+      minimumPremium: new BN(10_000_000),
+    },
+    minCoverage: new BN(LAMPORTS_PER_SOL / 10),
+    maxCoverage: new BN(LAMPORTS_PER_SOL),
+    minDurationDays: 30,
+    maxDurationDays: 90,
+    deductibleBps: 500,
+    deductibleMode: { subtractAfterScaling: {} },
+    waitingPeriodHours: 0,
+    premiumPaymentFrequency: { monthly: {} },
+    currency: { sol: {} },
+    riskAssessmentScore: 10,
+  });
+
+  const createTemplate = (version: number, rateAtMinThresholdBps = 1_200) =>
+    program.methods
+      .createProductTemplate({ productId, version, terms: terms(rateAtMinThresholdBps) })
+      .accountsPartial({ productTemplate: templatePda(version), masterContract, admin })
+      .rpc();
+
+  const setActive = (version: number, active: boolean) =>
+    program.methods
+      .setProductTemplateActive(active)
+      .accountsPartial({ productTemplate: templatePda(version), masterContract, admin })
+      .rpc();
+
+  const createPolicy = async (version: number, threshold: number, coverage: BN, durationDays: number) => {
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policyAccount = policyPda(program, admin, policiesIssued);
+    await program.methods
+      .createPolicyFromTemplate(threshold, coverage, durationDays, DEFAULT_REGION_CODE)
+      .accountsPartial({
+        productTemplate: templatePda(version),
+        policyHolder: admin,
+        rentPayer: admin,
+        masterContract,
+        policyAccount,
+        typeConfig: typeConfigPda(program),
+        treasury,
+      })
+      .rpc();
+    return policyAccount;
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    await program.methods
+      .registerOracle(oracleId, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+      .accountsPartial({ oracle, masterContract, admin, oracleAuthority: admin })
+      .remainingAccounts(await registeredOracles(program))
+      .rpc();
+  });
+
+  after(async () => {
+    // Free the registry slot for later suites
+    await program.methods
+      .unregisterOracle({ suspendCoverage: {} })
+      .accountsPartial({ oracle, oracleRetirement: oracleRetirementPda(program, oracle), masterContract, admin })
+      .rpc();
+  });
+
+  it("issues policies with the template's terms and quoted premium", async () => {
+    await createTemplate(1);
+
+    const coverage = new BN(LAMPORTS_PER_SOL);
+    const policy = await program.account.policy.fetch(await createPolicy(1, 60.0, coverage, 90));
+
+    // Halfway along the threshold range the rate is halfway between its ends
+    const premium = coverage.muln(800).muln(90).divn(10_000 * 365);
+    expect(policy.premiumAmount.toString()).to.equal(premium.toString());
+    expect(policy.deductible.toString()).to.equal(coverage.muln(500).divn(10_000).toString());
+    expect(policy.triggerConditions.thresholdValue).to.equal(60.0);
+    expect(policy.triggerConditions.dataSource).to.equal("rainfall");
+    expect(policy.oracleConfig.oracleAddress.toBase58()).to.equal(oracle.toBase58());
+
+    // Short, small policies pay the minimum premium
+    const small = await program.account.policy.fetch(await createPolicy(1, 80.0, coverage.divn(10), 30));
+    expect(small.premiumAmount.toString()).to.equal("10000000");
+
+    const { policiesIssued } = await program.account.productTemplate.fetch(templatePda(1));
+    expect(policiesIssued.toNumber()).to.equal(2);
+  });
+
+  it("rejects choices outside the template's ranges", async () => {
+    const coverage = new BN(LAMPORTS_PER_SOL);
+    await expectError(createPolicy(1, 90.0, coverage, 30), "TemplateParameterOutOfRange");
+    await expectError(createPolicy(1, 60.0, coverage.muln(2), 30), "TemplateParameterOutOfRange");
+    await expectError(createPolicy(1, 60.0, coverage, 365), "TemplateParameterOutOfRange");
+  });
+
+  it("retires a version without touching its policies", async () => {
+    const coverage = new BN(LAMPORTS_PER_SOL);
+    const issued = await createPolicy(1, 50.0, coverage, 30);
+    const before = await program.account.policy.fetch(issued);
+
+    await createTemplate(2, 1_600);
+    await setActive(1, false);
+    await expectError(createPolicy(1, 50.0, coverage, 30), "ProductTemplateInactive");
+
+    const repriced = await program.account.policy.fetch(await createPolicy(2, 40.0, coverage, 90));
+    expect(repriced.premiumAmount.toString()).to.equal(coverage.muln(1_600).muln(90).divn(10_000 * 365).toString());
+
+    const after = await program.account.policy.fetch(issued);
+    expect(after.premiumAmount.toString()).to.equal(before.premiumAmount.toString());
+    expect(after.triggerConditions.thresholdValue).to.equal(before.triggerConditions.thresholdValue);
+
+    await setActive(1, true);
+    await createPolicy(1, 50.0, coverage, 30);
+  });
+
+  it("requires a registered oracle", async () => {
+    const unregistered = { ...terms(1_200), oracleConfig: { ...terms(1_200).oracleConfig, oracleAddress: admin } };
+    await expectError(
+      program.methods
+        .createProductTemplate({ productId, version: 3, terms: unregistered })
+        .accountsPartial({ productTemplate: templatePda(3), masterContract, admin })
+        .rpc(),
+      "OracleNotRegistered"
+    );
+  });
+});
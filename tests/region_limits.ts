@@ -0,0 +1,167 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyPda,
+  regionExposurePda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("region limits", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+
+  // A code of its own per run, so the region starts out empty
+  const regionCode = [...Buffer.from("RG"), ...new BN(Date.now() % 1_000_000).toArrayLike(Buffer, "le", 6)];
+  const regionExposure = regionExposurePda(program, regionCode);
+
+  const setRegionLimits = (maxExposureBps: number | null, maxDailyPayoutBps: number | null) =>
+    applyParamChange(program, { regionLimits: [{ maxExposureBps, maxDailyPayoutBps }] }, null);
+
+  /// Create a policy in the region, booked in its account if `tracked`
+  const createPolicy = async (tracked: boolean, coverageAmount = new BN(LAMPORTS_PER_SOL)) => {
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policyAccount = policyPda(program, admin, policiesIssued);
+    await program.methods
+      .createPolicy({
+        ...defaultPolicyParams(premiumAmount),
+        coverageAmount,
+        maxPayoutPerIncident: coverageAmount,
+        regionCode,
+      })
+      .accountsPartial({
+        policyHolder: admin,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        regionExposure: tracked ? regionExposure : null,
+      })
+      .rpc();
+    return policyAccount;
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  after(async () => {
+    await setRegionLimits(null, null);
+  });
+
+  it("opens regions by the authority under a nonzero code", async () => {
+    const zeroCode = new Array(8).fill(0);
+    await expectError(
+      program.methods
+        .openRegion(zeroCode)
+        .accountsPartial({ regionExposure: regionExposurePda(program, zeroCode), masterContract, admin })
+        .rpc(),
+      "InvalidRegionCode"
+    );
+
+    await program.methods.openRegion(regionCode).accountsPartial({ regionExposure, masterContract, admin }).rpc();
+    const region = await program.account.regionExposure.fetch(regionExposure);
+    expect(region.regionCode).to.deep.equal(regionCode);
+    expect(region.solExposure.toNumber()).to.equal(0);
+  });
+
+  it("requires a region for weather, earthquake and crop cover", async () => {
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    await expectError(
+      program.methods
+        .createPolicy({ ...defaultPolicyParams(premiumAmount), regionCode: null })
+        .accountsPartial({
+          policyHolder: admin,
+          masterContract,
+          policyAccount: policyPda(program, admin, policiesIssued),
+          treasury,
+          typeConfig: typeConfigPda(program),
+        })
+        .rpc(),
+      "InvalidRegionCode"
+    );
+  });
+
+  it("books coverage in the region and releases what is taken off", async () => {
+    const policyAccount = await createPolicy(true);
+    const policy = await program.account.policy.fetch(policyAccount);
+    expect(policy.regionCode).to.deep.equal(regionCode);
+    expect(policy.regionTracked).to.be.true;
+    expect((await program.account.regionExposure.fetch(regionExposure)).solExposure.toNumber()).to.equal(
+      LAMPORTS_PER_SOL
+    );
+
+    const adjust = (region: PublicKey | null) =>
+      program.methods
+        .adjustCoverage(new BN(LAMPORTS_PER_SOL / 2), new BN(0))
+        .accountsPartial({
+          policyAccount,
+          masterContract,
+          treasury,
+          owner: admin,
+          policyTokenAccount: null,
+          regionExposure: region,
+        })
+        .rpc();
+    await expectError(adjust(null), "RegionExposureRequired");
+    await adjust(regionExposure);
+    expect((await program.account.regionExposure.fetch(regionExposure)).solExposure.toNumber()).to.equal(
+      LAMPORTS_PER_SOL / 2
+    );
+  });
+
+  it("refuses coverage beyond the region's share of the treasury", async () => {
+    // One basis point of the treasury is far below a policy's coverage
+    await setRegionLimits(1, null);
+    await expectError(createPolicy(true), "RegionExposureCapExceeded");
+    // While capped, a sale in the region cannot skip its account
+    await expectError(createPolicy(false), "RegionExposureRequired");
+
+    await setRegionLimits(null, null);
+    const policyAccount = await createPolicy(false);
+    expect((await program.account.policy.fetch(policyAccount)).regionTracked).to.be.false;
+  });
+
+  it("reports the exposure of the regions passed to the portfolio report", async () => {
+    const { portfolioReportCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const [portfolioReport] = PublicKey.findProgramAddressSync(
+      [Buffer.from("portfolio_report"), portfolioReportCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    await program.methods
+      .publishPortfolioReport()
+      .accountsPartial({ portfolioReport, masterContract, publisher: admin })
+      .remainingAccounts([{ pubkey: regionExposure, isSigner: false, isWritable: false }])
+      .rpc();
+
+    const { regions } = await program.account.portfolioReport.fetch(portfolioReport);
+    const { solExposure } = await program.account.regionExposure.fetch(regionExposure);
+    expect(regions).to.have.length(1);
+    expect(regions[0].regionCode).to.deep.equal(regionCode);
+    expect(regions[0].solExposure.eq(solExposure)).to.be.true;
+  });
+});
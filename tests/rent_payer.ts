@@ -0,0 +1,149 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("rent payer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  const sponsor = Keypair.generate();
+  let treasury: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: sponsor.publicKey, lamports: LAMPORTS_PER_SOL / 10 })
+      )
+    );
+  });
+
+  it("lets a sponsor pay the rent for a holder without lamports", async () => {
+    const holder = Keypair.generate();
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policyAccount = policyPda(program, holder.publicKey, policiesIssued);
+    const sponsorBefore = await provider.connection.getBalance(sponsor.publicKey);
+
+    await program.methods
+      .createPolicy(defaultPolicyParams(premiumAmount))
+      .accountsPartial({
+        policyHolder: holder.publicKey,
+        rentPayer: sponsor.publicKey,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+      })
+      .signers([holder, sponsor])
+      .rpc();
+
+    const policy = await program.account.policy.fetch(policyAccount);
+    expect(policy.user.toBase58()).to.equal(holder.publicKey.toBase58());
+    expect(policy.rentPayer.toBase58()).to.equal(sponsor.publicKey.toBase58());
+    expect(await provider.connection.getBalance(holder.publicKey)).to.equal(0);
+    expect(sponsorBefore - (await provider.connection.getBalance(sponsor.publicKey))).to.equal(
+      await provider.connection.getBalance(policyAccount)
+    );
+  });
+
+  it("refunds the payout rent to the sponsor that paid it", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        rentPayer: sponsor.publicKey,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .signers([sponsor])
+      .rpc();
+
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.beneficiary.toBase58()).to.equal(admin.toBase58());
+    expect(payout.rentPayer.toBase58()).to.equal(sponsor.publicKey.toBase58());
+    if ("pendingApproval" in payout.status) {
+      await program.methods.approvePayout().accountsPartial({ pendingPayout, masterContract, admin }).rpc();
+    }
+
+    // Back the payout with real lamports
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: treasury, lamports: payout.amount.toNumber() })
+      )
+    );
+    await program.methods
+      .depositFunds(payout.amount, { sol: {} })
+      .accountsPartial({ treasury, masterContract, depositor: admin })
+      .rpc();
+
+    const execute = (rentPayer: PublicKey) =>
+      program.methods
+        .executePayout()
+        .accountsPartial({
+          pendingPayout,
+          policy,
+          masterContract,
+          treasury,
+          beneficiary: admin,
+          rentPayer,
+          executor: admin,
+          vestingPayout: null,
+          treasuryUsdcAccount: null,
+          beneficiaryUsdcAccount: null,
+          usdcMint: null,
+          policyMint: null,
+          beneficiaryTokenAccount: null,
+          tokenProgram: null,
+          associatedTokenProgram: null,
+        })
+        .rpc();
+
+    await expectError(execute(admin), "RentPayerMismatch");
+
+    const payoutRent = await provider.connection.getBalance(pendingPayout);
+    const sponsorBefore = await provider.connection.getBalance(sponsor.publicKey);
+    await execute(sponsor.publicKey);
+    expect((await provider.connection.getBalance(sponsor.publicKey)) - sponsorBefore).to.equal(payoutRent);
+  });
+});
@@ -0,0 +1,115 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, EventParser } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("reserve ratio breaches", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+  let drained: BN;
+
+  /// Events of one name emitted by a confirmed transaction
+  const eventsOf = async (signature: string, name: string) => {
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(confirmed!.meta!.logMessages!)].filter((e) => e.name === name);
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+
+    // Raising a policy's coverage adds to the exposure the reserve ratio is measured against
+    const policyAccount = await createActivePolicy(program, premiumAmount);
+    const { coverageAmount } = await program.account.policy.fetch(policyAccount);
+    await program.methods
+      .adjustCoverage(coverageAmount.addn(LAMPORTS_PER_SOL / 10), new BN(LAMPORTS_PER_SOL))
+      .accountsPartial({ policyAccount, masterContract, treasury, owner: admin, policyTokenAccount: null })
+      .rpc();
+  });
+
+  it("emits ReserveRatioBreached when a withdrawal takes the reserves below the minimum", async function () {
+    const before = await program.account.treasury.fetch(treasury);
+    expect(before.reserveBreached).to.be.false;
+
+    // Leave two lamports less than the required reserves
+    const required = before.totalCoverageExposure.muln(before.minimumReserveRatio).divn(10_000);
+    const shortfall = before.totalUsdcBalance.add(before.totalSolBalance).sub(required).addn(2);
+    if (shortfall.gt(before.totalSolBalance)) {
+      this.skip();
+    }
+
+    const signature = await program.methods
+      .withdrawFunds(shortfall, { sol: {} }, { emergencyWithdrawal: {} })
+      .accountsPartial({ treasury, masterContract, admin, recipient: admin, recipientUsdcAccount: null })
+      .rpc({ commitment: "confirmed" });
+    drained = shortfall;
+
+    const after = await program.account.treasury.fetch(treasury, "confirmed");
+    expect(after.reserveBreached).to.be.true;
+    expect(after.lastBreachAt.toNumber()).to.be.greaterThan(0);
+
+    const [withdrawn] = await eventsOf(signature, "treasuryWithdrawn");
+    expect(withdrawn.data.treasuryBalanceBefore.toString()).to.equal(before.totalSolBalance.toString());
+    expect(withdrawn.data.treasuryBalanceAfter.toString()).to.equal(after.totalSolBalance.toString());
+    expect(withdrawn.data.reserveRatioAfter).to.equal(after.currentReserveRatio);
+
+    const [breached] = await eventsOf(signature, "reserveRatioBreached");
+    expect(breached.data.treasury.toBase58()).to.equal(treasury.toBase58());
+    expect(breached.data.reserveRatio).to.be.lessThan(after.minimumReserveRatio);
+    expect(breached.data.minimumReserveRatio).to.equal(after.minimumReserveRatio);
+    expect(breached.data.solBalance.toString()).to.equal(after.totalSolBalance.toString());
+    expect(breached.data.usdcBalance.toString()).to.equal(after.totalUsdcBalance.toString());
+    expect(breached.data.coverageExposure.toString()).to.equal(after.totalCoverageExposure.toString());
+    expect(breached.data.timestamp.toString()).to.equal(after.lastBreachAt.toString());
+  });
+
+  it("stays quiet while the reserves remain below the minimum", async function () {
+    if (!drained) {
+      this.skip();
+    }
+
+    const signature = await program.methods
+      .depositFunds(new BN(1), { sol: {} })
+      .accountsPartial({ treasury, depositor: admin })
+      .rpc({ commitment: "confirmed" });
+    drained = drained.subn(1);
+
+    expect(await eventsOf(signature, "reserveRatioRestored")).to.be.empty;
+    expect(await eventsOf(signature, "reserveRatioBreached")).to.be.empty;
+    expect((await program.account.treasury.fetch(treasury, "confirmed")).reserveBreached).to.be.true;
+  });
+
+  it("emits ReserveRatioRestored when a deposit brings the reserves back", async function () {
+    if (!drained) {
+      this.skip();
+    }
+    const { lastBreachAt } = await program.account.treasury.fetch(treasury);
+
+    const signature = await program.methods
+      .depositFunds(drained, { sol: {} })
+      .accountsPartial({ treasury, depositor: admin })
+      .rpc({ commitment: "confirmed" });
+
+    const after = await program.account.treasury.fetch(treasury, "confirmed");
+    expect(after.reserveBreached).to.be.false;
+    // The last breach stays on record for time-in-breach reporting
+    expect(after.lastBreachAt.toString()).to.equal(lastBreachAt.toString());
+
+    const [restored] = await eventsOf(signature, "reserveRatioRestored");
+    expect(restored.data.reserveRatio).to.be.at.least(after.minimumReserveRatio);
+    expect(restored.data.breachedAt.toString()).to.equal(lastBreachAt.toString());
+    expect(restored.data.solBalance.toString()).to.equal(after.totalSolBalance.toString());
+  });
+});
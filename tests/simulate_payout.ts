@@ -0,0 +1,44 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, currentTreasury, masterContractPda } from "./helpers";
+
+describe("simulate_payout", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const masterContract = masterContractPda(program);
+  let policy: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    policy = await createActivePolicy(program, new BN(10_000_000));
+    treasury = await currentTreasury(program);
+  });
+
+  const simulate = (oracleValue: number) =>
+    program.methods
+      .simulatePayout(new BN(oracleValue))
+      .accountsPartial({ policy, masterContract, treasury, breachWatch: null })
+      .view();
+
+  it("quotes a payable claim without creating a pending payout", async () => {
+    // 75 against a threshold of 50 is a 50% deviation
+    const quote = await simulate(75);
+    expect(quote.ineligibility).to.equal(null);
+    expect(quote.severityPercentage).to.equal(50);
+    expect(quote.amount.toString()).to.equal(new BN(LAMPORTS_PER_SOL / 2).toString());
+    expect(quote.requiresApproval).to.equal(true);
+
+    const after = await program.account.policy.fetch(policy);
+    expect(after.status).to.deep.equal({ active: {} });
+  });
+
+  it("reports why a claim is not payable", async () => {
+    const quote = await simulate(40);
+    expect(quote.ineligibility).to.deep.equal({ triggerNotMet: {} });
+    expect(quote.amount.toNumber()).to.equal(0);
+  });
+});
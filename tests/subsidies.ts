@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("premium subsidies", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const holder = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const sponsor = Keypair.generate();
+  let treasury: PublicKey;
+  let policyAccount: PublicKey;
+
+  const subsidyPda = (subsidyId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("subsidy"), sponsor.publicKey.toBuffer(), new BN(subsidyId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const createSubsidy = (subsidyId: number, overrides: object = {}) =>
+    program.methods
+      .createSubsidy({
+        subsidyId: new BN(subsidyId),
+        insuranceType: { weather: {} },
+        allowedHolders: [holder],
+        coverageBps: 7_500,
+        budget: new BN(10_000_000),
+        ...overrides,
+      })
+      .accountsPartial({ subsidy: subsidyPda(subsidyId), masterContract, sponsor: sponsor.publicKey })
+      .signers([sponsor])
+      .rpc();
+
+  const payPremium = (subsidy: PublicKey) =>
+    program.methods
+      .payPremium(premiumAmount)
+      .accountsPartial({
+        payer: holder,
+        policyAccount,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy,
+        treasury,
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const budgetOf = async (subsidyId: number) =>
+    (await program.account.subsidy.fetch(subsidyPda(subsidyId))).budgetRemaining.toNumber();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    policyAccount = await createActivePolicy(program, premiumAmount);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: holder, toPubkey: sponsor.publicKey, lamports: LAMPORTS_PER_SOL })
+      )
+    );
+  });
+
+  it("holds the deposited budget in the subsidy account", async () => {
+    await createSubsidy(1);
+
+    const subsidy = subsidyPda(1);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(subsidy))!.data.length
+    );
+    expect(await provider.connection.getBalance(subsidy)).to.equal(rent + 10_000_000);
+    expect(await budgetOf(1)).to.equal(10_000_000);
+  });
+
+  it("collects the full premium into the treasury, the sponsor's share from the subsidy", async () => {
+    const treasuryLamports = await provider.connection.getBalance(treasury);
+    const { totalSolBalance } = await program.account.treasury.fetch(treasury);
+
+    await payPremium(subsidyPda(1));
+
+    expect(await budgetOf(1)).to.equal(2_500_000);
+    expect(await provider.connection.getBalance(treasury)).to.equal(treasuryLamports + 10_000_000);
+    const after = await program.account.treasury.fetch(treasury);
+    expect(after.totalSolBalance.sub(totalSolBalance).toNumber()).to.equal(10_000_000);
+  });
+
+  it("falls back to full-price premiums once the budget is exhausted", async () => {
+    await payPremium(subsidyPda(1));
+    expect(await budgetOf(1)).to.equal(0);
+
+    const treasuryLamports = await provider.connection.getBalance(treasury);
+    await payPremium(subsidyPda(1));
+    expect(await provider.connection.getBalance(treasury)).to.equal(treasuryLamports + 10_000_000);
+    expect((await program.account.subsidy.fetch(subsidyPda(1))).totalDisbursed.toNumber()).to.equal(10_000_000);
+  });
+
+  it("rejects holders and types outside the target filter", async () => {
+    await createSubsidy(2, { allowedHolders: [Keypair.generate().publicKey] });
+    await expectError(payPremium(subsidyPda(2)), "SubsidyNotApplicable");
+
+    await createSubsidy(3, { insuranceType: { crop: {} }, allowedHolders: [] });
+    await expectError(payPremium(subsidyPda(3)), "SubsidyNotApplicable");
+  });
+
+  it("tops up the budget", async () => {
+    await program.methods
+      .topUpSubsidy(new BN(5_000_000))
+      .accountsPartial({ subsidy: subsidyPda(1), masterContract, sponsor: sponsor.publicKey })
+      .signers([sponsor])
+      .rpc();
+    expect(await budgetOf(1)).to.equal(5_000_000);
+  });
+
+  it("returns the unspent budget and rent on close", async () => {
+    for (const subsidyId of [1, 2, 3]) {
+      const subsidy = subsidyPda(subsidyId);
+      const held = await provider.connection.getBalance(subsidy);
+      const before = await provider.connection.getBalance(sponsor.publicKey);
+
+      await program.methods
+        .closeSubsidy()
+        .accountsPartial({ subsidy, masterContract, sponsor: sponsor.publicKey })
+        .signers([sponsor])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(subsidy)).to.be.null;
+      expect(await provider.connection.getBalance(sponsor.publicKey)).to.equal(before + held);
+    }
+  });
+});
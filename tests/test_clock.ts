@@ -0,0 +1,58 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+/// `set_test_clock` only exists in `test-clock` builds, so it is missing from the generated types
+type TestClockMethods = {
+  setTestClock(timestamp: BN | null): {
+    accountsPartial(accounts: { masterContract: PublicKey; admin: PublicKey }): { rpc(): Promise<string> };
+  };
+};
+
+describe("test clock", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  const setTestClock = (timestamp: BN | null) =>
+    (program.methods as unknown as TestClockMethods)
+      .setTestClock(timestamp)
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+
+  before(async function () {
+    if (!program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      this.skip();
+    }
+    await ensureMasterContract(program);
+    await ensureTreasury(program);
+  });
+
+  after(async function () {
+    if (program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      await setTestClock(null);
+    }
+  });
+
+  it("stamps policies with the overridden time", async () => {
+    const pinned = new BN(Math.floor(Date.now() / 1000) + 30 * 86_400);
+    await setTestClock(pinned);
+
+    const policy = await program.account.policy.fetch(await createActivePolicy(program, premiumAmount));
+    expect(policy.createdAt.toString()).to.equal(pinned.toString());
+  });
+
+  it("returns to the clock sysvar once cleared", async () => {
+    await setTestClock(null);
+
+    const policy = await program.account.policy.fetch(await createActivePolicy(program, premiumAmount));
+    expect(Math.abs(policy.createdAt.toNumber() - Date.now() / 1000)).to.be.lessThan(120);
+  });
+});
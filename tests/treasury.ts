@@ -0,0 +1,20 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+describe("treasury", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+
+  it("links the treasury PDA to the master contract on initialization", async () => {
+    await ensureMasterContract(program);
+    const treasury = await ensureTreasury(program);
+
+    const master = await program.account.masterInsuranceContract.fetch(masterContractPda(program));
+    expect(master.treasuryAccount.toBase58()).to.equal(treasury.toBase58());
+  });
+});
@@ -0,0 +1,214 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("treasury migration", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+
+  let oldTreasury: PublicKey;
+  let version: number;
+
+  const treasuryPdaV = (treasuryVersion: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), Buffer.from([treasuryVersion])],
+      program.programId
+    )[0];
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const migrate = (treasuryVersion: number) =>
+    program.methods
+      .migrateTreasury({
+        version: treasuryVersion,
+        usdcMint: null,
+        usdcTokenAccount: null,
+        solTokenAccount: null,
+        usdcDecimals: null,
+      })
+      .accountsPartial({
+        oldTreasury,
+        newTreasury: treasuryPdaV(treasuryVersion),
+        masterContract,
+        admin,
+        oldUsdcAccount: null,
+        newUsdcAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  const setPaused = (paused: boolean) =>
+    (paused ? program.methods.pauseContract(false) : program.methods.resumeContract())
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+
+  const openPayouts = async () =>
+    (await program.account.masterInsuranceContract.fetch(masterContract)).openPayoutCount.toNumber();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    oldTreasury = await ensureTreasury(program);
+    version = (await program.account.treasury.fetch(oldTreasury)).version + 1;
+  });
+
+  after(async () => {
+    if ((await program.account.masterInsuranceContract.fetch(masterContract)).isPaused) {
+      await setPaused(false);
+    }
+  });
+
+  it("refuses to migrate while the contract is live", async () => {
+    await expectError(migrate(version), "ContractMustBePaused");
+  });
+
+  it("refuses to migrate while payouts are open", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury: oldTreasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+    expect(await openPayouts()).to.be.greaterThan(0);
+
+    await setPaused(true);
+    try {
+      await expectError(migrate(version), "PayoutsOutstanding");
+    } finally {
+      await setPaused(false);
+    }
+  });
+
+  it("counts executed payouts as settled", async () => {
+    // Settle every payout earlier suites left open, funding them first
+    const payouts = await program.account.pendingPayout.all();
+    const policies = await program.account.policy.all();
+    const total = payouts.reduce((sum, { account }) => sum + account.amount.toNumber(), 0);
+    await provider.sendAndConfirm(
+      new Transaction().add(SystemProgram.transfer({ fromPubkey: admin, toPubkey: oldTreasury, lamports: total }))
+    );
+    await program.methods
+      .depositFunds(new BN(total), { sol: {} })
+      .accountsPartial({ treasury: oldTreasury, masterContract, depositor: admin })
+      .rpc();
+
+    for (const { publicKey: pendingPayout, account } of payouts) {
+      if ("pendingApproval" in account.status) {
+        await program.methods.approvePayout().accountsPartial({ pendingPayout, masterContract, admin }).rpc();
+      }
+      const policy = policies.find((candidate) => candidate.account.id.eq(account.policyId))!.publicKey;
+      await program.methods
+        .executePayout()
+        .accountsPartial({
+          pendingPayout,
+          policy,
+          masterContract,
+          treasury: oldTreasury,
+          beneficiary: account.beneficiary,
+          executor: admin,
+          vestingPayout: null,
+          treasuryUsdcAccount: null,
+          beneficiaryUsdcAccount: null,
+          usdcMint: null,
+          policyMint: null,
+          beneficiaryTokenAccount: null,
+          tokenProgram: null,
+          associatedTokenProgram: null,
+        })
+        .rpc();
+    }
+
+    expect(await openPayouts()).to.equal(0);
+  });
+
+  it("only accepts the next treasury version", async () => {
+    await setPaused(true);
+    await expectError(migrate(version + 1), "InvalidTreasuryVersion");
+  });
+
+  it("moves the balances to the versioned treasury and retires the old one", async () => {
+    const before = await program.account.treasury.fetch(oldTreasury);
+    const oldLamports = await provider.connection.getBalance(oldTreasury);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(oldTreasury))!.data.length
+    );
+
+    await migrate(version);
+
+    const newTreasury = treasuryPdaV(version);
+    const master = await program.account.masterInsuranceContract.fetch(masterContract);
+    expect(master.treasuryAccount.toBase58()).to.equal(newTreasury.toBase58());
+
+    const migrated = await program.account.treasury.fetch(newTreasury);
+    expect(migrated.version).to.equal(version);
+    expect(migrated.status).to.deep.equal({ active: {} });
+    expect(migrated.totalSolBalance.eq(before.totalSolBalance)).to.be.true;
+    expect(migrated.totalPremiumsCollectedSol.eq(before.totalPremiumsCollectedSol)).to.be.true;
+    // Same layout, so the new account's rent plus the moved lamports equals the old balance
+    expect(await provider.connection.getBalance(newTreasury)).to.equal(oldLamports);
+
+    const retired = await program.account.treasury.fetch(oldTreasury);
+    expect(retired.status).to.deep.equal({ retired: {} });
+    expect(retired.successor.toBase58()).to.equal(newTreasury.toBase58());
+    expect(retired.totalSolBalance.toNumber()).to.equal(0);
+    expect(await provider.connection.getBalance(oldTreasury)).to.equal(rent);
+  });
+
+  it("stops resolving the retired treasury", async () => {
+    await expectError(
+      program.methods
+        .depositFunds(new BN(1), { sol: {} })
+        .accountsPartial({ treasury: oldTreasury, masterContract, depositor: admin })
+        .rpc(),
+      "TreasuryAccountMismatch"
+    );
+  });
+
+  it("closes only retired treasuries", async () => {
+    await expectError(
+      program.methods
+        .closeRetiredTreasury()
+        .accountsPartial({ treasury: treasuryPdaV(version), masterContract, admin, usdcAccount: null })
+        .rpc(),
+      "TreasuryNotRetired"
+    );
+
+    await program.methods
+      .closeRetiredTreasury()
+      .accountsPartial({ treasury: oldTreasury, masterContract, admin, usdcAccount: null })
+      .rpc();
+    expect(await provider.connection.getAccountInfo(oldTreasury)).to.be.null;
+  });
+});
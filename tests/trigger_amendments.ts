@@ -0,0 +1,100 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyHistoryPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("trigger amendments", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  const newConditions = { ...defaultPolicyParams(premiumAmount).triggerConditions, thresholdValue: 80.0 };
+  let treasury: PublicKey;
+  let policy: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const propose = (maxAdditionalPremium: BN) =>
+    program.methods
+      .proposeTriggerAmendment(newConditions, maxAdditionalPremium)
+      .accountsPartial({ policy, masterContract, owner: admin, policyTokenAccount: null })
+      .rpc();
+
+  const approve = (riskAssessmentScore: number) =>
+    program.methods
+      .approveTriggerAmendment(riskAssessmentScore)
+      .accountsPartial({ policy, masterContract, treasury, typeConfig: typeConfigPda(program), admin })
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    policy = await createActivePolicy(program, premiumAmount);
+  });
+
+  it("only lets the holder propose an amendment", async () => {
+    const outsider = Keypair.generate();
+    await expectError(
+      program.methods
+        .proposeTriggerAmendment(newConditions, new BN(0))
+        .accountsPartial({ policy, masterContract, owner: outsider.publicKey, policyTokenAccount: null })
+        .signers([outsider])
+        .rpc(),
+      "Unauthorized"
+    );
+  });
+
+  it("rejects approval without a pending amendment", async () => {
+    await expectError(approve(10), "NoPendingTriggerAmendment");
+  });
+
+  it("rejects a re-pricing above the holder's limit", async () => {
+    await propose(new BN(1));
+    await expectError(approve(40), "InsufficientPremium");
+  });
+
+  it("applies the amendment, re-prices and endorses it", async () => {
+    await propose(premiumAmount);
+    const before = await program.account.policy.fetch(policy);
+    expect(before.pendingTriggerAmendment!.conditions.thresholdValue).to.equal(80.0);
+
+    await approve(40);
+
+    const after = await program.account.policy.fetch(policy);
+    expect(after.pendingTriggerAmendment).to.be.null;
+    expect(after.triggerConditions.thresholdValue).to.equal(80.0);
+    expect(after.riskAssessmentScore).to.equal(40);
+    // Premium scales with 100 + score
+    expect(after.premiumAmount.toString()).to.equal(premiumAmount.muln(140).divn(110).toString());
+
+    const charged = after.totalPremiumsPaid.sub(before.totalPremiumsPaid);
+    expect(charged.toNumber()).to.be.greaterThan(0);
+    expect(charged.lte(after.premiumAmount.sub(premiumAmount))).to.be.true;
+
+    const { endorsements } = await program.account.policyHistory.fetch(policyHistoryPda(program, policy));
+    const endorsement = endorsements[endorsements.length - 1];
+    expect(endorsement.kind).to.deep.equal({ triggerAmendment: {} });
+    expect(endorsement.actor.toBase58()).to.equal(admin.toBase58());
+  });
+});
@@ -0,0 +1,129 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Ed25519Program, Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  currentTreasury,
+  ensureTreasury,
+  masterContractPda,
+  registeredOracles,
+  typeConfigPda,
+} from "./helpers";
+
+describe("trigger quorum", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const oracleValue = new BN(75);
+  const suffix = Date.now();
+  const authorities = [Keypair.generate(), Keypair.generate()];
+  const outsider = Keypair.generate();
+  const oracles = authorities.map((_, i) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle"), Buffer.from(`quorum-${i}-${suffix}`)],
+      program.programId
+    )[0]
+  );
+
+  let policy: PublicKey;
+  let policyId: BN;
+
+  /// Same digest as `quorum_message`: sha256(policy_id || oracle_value || attested_at)
+  const quorumMessage = (attestedAt: BN) =>
+    createHash("sha256")
+      .update(policyId.toArrayLike(Buffer, "le", 8))
+      .update(oracleValue.toArrayLike(Buffer, "le", 8))
+      .update(attestedAt.toTwos(64).toArrayLike(Buffer, "le", 8))
+      .digest();
+
+  const trigger = async (signers: Keypair[]) => {
+    const attestedAt = new BN(Math.floor(Date.now() / 1000));
+    const message = quorumMessage(attestedAt);
+    const { startDate } = await program.account.policy.fetch(policy);
+    const tx = new Transaction().add(
+      ...signers.map((signer) =>
+        Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message })
+      ),
+      await program.methods
+        .triggerPayout(policyId, oracleValue, attestedAt, null, startDate)
+        .accountsPartial({
+          policy,
+          masterContract,
+          treasury: await currentTreasury(program),
+          typeConfig: typeConfigPda(program),
+          beneficiary: admin,
+          policyMint: null,
+          beneficiaryTokenAccount: null,
+          tokenProgram: null,
+          breachWatch: null,
+          consensusSnapshot: null,
+        })
+        .remainingAccounts(
+          oracles.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .instruction()
+    );
+    return provider.sendAndConfirm(tx);
+  };
+
+  const expectRejected = async (signers: Keypair[], code: string) => {
+    try {
+      await trigger(signers);
+      expect.fail("trigger was accepted");
+    } catch (err) {
+      const anchorError = AnchorError.parse((err as { logs?: string[] }).logs ?? []);
+      expect(anchorError?.error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureTreasury(program);
+    for (const [i, authority] of authorities.entries()) {
+      await program.methods
+        .registerOracle(`quorum-${i}-${suffix}`, { pyth: {} }, "rainfall-mm", { ed25519: {} }, Buffer.alloc(0))
+        .accountsPartial({
+          oracle: oracles[i],
+          masterContract,
+          admin,
+          oracleAuthority: authority.publicKey,
+        })
+        .remainingAccounts(await registeredOracles(program))
+        .rpc();
+    }
+
+    policy = await createActivePolicy(program, new BN(10_000_000), { quorumSignatures: 2 });
+    policyId = (await program.account.policy.fetch(policy)).id;
+  });
+
+  it("rejects a trigger without enough co-signers", async () => {
+    await expectRejected([authorities[0]], "QuorumNotMet");
+  });
+
+  it("rejects the same authority co-signing twice", async () => {
+    await expectRejected([authorities[0], authorities[0]], "DuplicateQuorumSigner");
+  });
+
+  it("rejects a co-signer that runs no registered oracle", async () => {
+    await expectRejected([authorities[0], outsider], "QuorumSignerNotRegistered");
+  });
+
+  it("records the verified co-signers on the pending payout", async () => {
+    await trigger(authorities);
+
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), policyId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const payout = await program.account.pendingPayout.fetch(pendingPayout);
+    expect(payout.quorumSigners.map((key) => key.toBase58())).to.deep.equal(
+      authorities.map((authority) => authority.publicKey.toBase58())
+    );
+  });
+});
@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  ensureMasterContract,
+  masterContractPda,
+  paramProposalPda,
+  typeConfigPda,
+} from "./helpers";
+
+describe("insurance type configuration", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const custom = { custom: {} };
+  const customConfig = typeConfigPda(program, custom);
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const customParams = async () => (await program.account.insuranceTypeConfig.fetch(customConfig)).params;
+
+  before(async () => {
+    await ensureMasterContract(program);
+  });
+
+  it("blocks policies of an unconfigured type", async () => {
+    await expectError(
+      createActivePolicy(program, premiumAmount, { insuranceType: custom }),
+      "AccountNotInitialized"
+    );
+  });
+
+  it("initializes a type with its defaults", async () => {
+    await program.methods
+      .initTypeConfig(custom)
+      .accountsPartial({ typeConfig: customConfig, masterContract, admin })
+      .rpc();
+
+    const config = await program.account.insuranceTypeConfig.fetch(customConfig);
+    expect(config.insuranceType).to.deep.equal(custom);
+    expect(config.params.basePriority).to.equal(50);
+    expect(config.params.severityPriorityPoints).to.equal(25);
+    expect(config.params.premiumBaseRateBps).to.equal(0);
+    expect(config.params.incidentToleranceSecs.toNumber()).to.equal(24 * 3600);
+    expect(config.params.minIncidentGapSecs.toNumber()).to.equal(24 * 3600);
+    expect(config.params.maxDurationDays).to.equal(5 * 365);
+    expect(config.params.minThreshold).to.equal(-Number.MAX_VALUE);
+    expect(config.params.maxThreshold).to.equal(Number.MAX_VALUE);
+    expect(config.params.allowedOperators).to.equal(0b1111);
+    expect(config.params.minWaitingPeriodHours).to.equal(0);
+    expect(config.params.maxConfidenceRatioBps).to.equal(0);
+
+    await createActivePolicy(program, premiumAmount, { insuranceType: custom });
+  });
+
+  it("rejects invalid parameters when proposed", async () => {
+    const params = { ...(await customParams()), basePriority: 101 };
+    await expectError(
+      applyParamChange(program, { typeConfig: { insuranceType: custom, params } }, customConfig),
+      "InvalidInput"
+    );
+    const steepPriority = { ...(await customParams()), severityPriorityPoints: 101 };
+    await expectError(
+      applyParamChange(program, { typeConfig: { insuranceType: custom, params: steepPriority } }, customConfig),
+      "InvalidInput"
+    );
+    const invertedRange = { ...(await customParams()), minThreshold: 10, maxThreshold: 1 };
+    await expectError(
+      applyParamChange(program, { typeConfig: { insuranceType: custom, params: invertedRange } }, customConfig),
+      "InvalidInput"
+    );
+  });
+
+  it("applies a proposed change once the timelock elapses", async () => {
+    // 2% of coverage, so the shared 0.01 SOL premium no longer covers 1 SOL
+    const params = { ...(await customParams()), premiumBaseRateBps: 200 };
+    await applyParamChange(program, { typeConfig: { insuranceType: custom, params } }, customConfig);
+
+    expect((await customParams()).premiumBaseRateBps).to.equal(200);
+    await expectError(
+      createActivePolicy(program, premiumAmount, { insuranceType: custom }),
+      "InsufficientPremium"
+    );
+    await createActivePolicy(program, new BN(LAMPORTS_PER_SOL / 50), { insuranceType: custom });
+  });
+
+  it("refuses to apply a change to another type's configuration", async () => {
+    const params = await customParams();
+    await expectError(
+      applyParamChange(program, { typeConfig: { insuranceType: custom, params } }, typeConfigPda(program)),
+      "ParamChangeTargetMismatch"
+    );
+
+    // The proposal survives the failed execution
+    const { paramProposalCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+    await program.methods
+      .cancelParamChange()
+      .accountsPartial({ proposal: paramProposalPda(program, paramProposalCount.subn(1)), masterContract, admin })
+      .rpc();
+  });
+
+  it("cancels a proposal", async () => {
+    const { paramProposalCount } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const proposal = paramProposalPda(program, paramProposalCount);
+    const params = { ...(await customParams()), basePriority: 10 };
+
+    await program.methods
+      .proposeParamChange({ typeConfig: { insuranceType: custom, params } })
+      .accountsPartial({ proposal, masterContract, admin })
+      .rpc();
+    await program.methods
+      .cancelParamChange()
+      .accountsPartial({ proposal, masterContract, admin })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(proposal)).to.be.null;
+    expect((await customParams()).basePriority).to.equal(50);
+  });
+});
@@ -0,0 +1,142 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError, EventParser } from "@coral-xyz/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { createActivePolicy, defaultPolicyParams, ensureMasterContract, masterContractPda, typeConfigPda } from "./helpers";
+
+describe("per-type policy limits", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  const weather = { weather: {} };
+  const earthquake = { earthquake: {} };
+  const flight = { flight: {} };
+  const crop = { crop: {} };
+  const custom = { custom: {} };
+
+  type Operator = { greaterThan: {} } | { lessThan: {} };
+  const trigger = (thresholdValue: number, comparisonOperator: Operator = { greaterThan: {} }) => ({
+    ...defaultPolicyParams(premiumAmount).triggerConditions,
+    thresholdValue,
+    comparisonOperator,
+  });
+
+  /// Expects InvalidParameters with the violation named by the emitted event
+  const expectViolation = async (promise: Promise<unknown>, violation: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${violation} violation`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidParameters");
+      const parser = new EventParser(program.programId, program.coder);
+      const events = [...parser.parseLogs((err as AnchorError).logs)].filter((e) => e.name === "policyLimitViolated");
+      expect(events).to.have.length(1);
+      expect(Object.keys(events[0].data.violation as object)).to.deep.equal([violation]);
+    }
+  };
+
+  /// Create a policy that waits as long as its type requires
+  const create = async (overrides: Partial<ReturnType<typeof defaultPolicyParams>>, premium = premiumAmount) => {
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfigPda(program, overrides.insuranceType));
+    return createActivePolicy(program, premium, { waitingPeriodHours: params.minWaitingPeriodHours, ...overrides });
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    for (const insuranceType of [earthquake, flight, crop, custom]) {
+      const typeConfig = typeConfigPda(program, insuranceType);
+      if (await provider.connection.getAccountInfo(typeConfig)) continue;
+      await program.methods
+        .initTypeConfig(insuranceType)
+        .accountsPartial({ typeConfig, masterContract, admin })
+        .rpc();
+    }
+  });
+
+  it("caps flight policies at seven days", async () => {
+    const params = { insuranceType: flight, triggerConditions: trigger(120) };
+    await expectViolation(create({ ...params, policyDurationDays: 8 }), "duration");
+    await create({ ...params, policyDurationDays: 7 });
+  });
+
+  it("requires flight triggers to fire on delays above the threshold", async () => {
+    await expectViolation(
+      create({
+        insuranceType: flight,
+        policyDurationDays: 7,
+        triggerConditions: trigger(120, { lessThan: {} }),
+      }),
+      "comparisonOperator"
+    );
+    await expectViolation(
+      create({
+        insuranceType: flight,
+        policyDurationDays: 7,
+        triggerConditions: trigger(2_000),
+      }),
+      "threshold"
+    );
+  });
+
+  it("bounds weather thresholds but allows either direction", async () => {
+    await expectViolation(
+      create({ insuranceType: weather, triggerConditions: trigger(0) }),
+      "threshold"
+    );
+    await create({
+      insuranceType: weather,
+      triggerConditions: trigger(5, { lessThan: {} }),
+    });
+  });
+
+  it("requires each type's minimum waiting period", async () => {
+    const { params } = await program.account.insuranceTypeConfig.fetch(typeConfigPda(program, earthquake));
+    expect(params.minWaitingPeriodHours).to.equal(24);
+    await expectViolation(
+      create({ insuranceType: earthquake, triggerConditions: trigger(6.5), waitingPeriodHours: 23 }),
+      "waitingPeriod"
+    );
+    await create({ insuranceType: earthquake, triggerConditions: trigger(6.5), waitingPeriodHours: 48 });
+  });
+
+  it("keeps earthquake thresholds on the magnitude scale", async () => {
+    await expectViolation(
+      create({ insuranceType: earthquake, triggerConditions: trigger(11) }),
+      "threshold"
+    );
+    await expectViolation(
+      create({
+        insuranceType: earthquake,
+        triggerConditions: trigger(6, { lessThan: {} }),
+      }),
+      "comparisonOperator"
+    );
+    await create({ insuranceType: earthquake, triggerConditions: trigger(6.5) });
+  });
+
+  it("applies crop limits", async () => {
+    await expectViolation(
+      create({ insuranceType: crop, triggerConditions: trigger(-1) }),
+      "threshold"
+    );
+    await create({
+      insuranceType: crop,
+      triggerConditions: trigger(40, { lessThan: {} }),
+    });
+  });
+
+  it("leaves custom feeds unconstrained", async () => {
+    // Covers the custom base rate set in type_config.ts
+    await create(
+      { insuranceType: custom, policyDurationDays: 365, triggerConditions: trigger(-1_000, { lessThan: {} }) },
+      new BN(LAMPORTS_PER_SOL / 50)
+    );
+  });
+});
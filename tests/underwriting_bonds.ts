@@ -0,0 +1,194 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+  DEFAULT_MAX_OPEN_PAYOUTS,
+  DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  defaultPolicyParams,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  policyPda,
+  solAmount,
+  typeConfigPda,
+  UNCAPPED_EXPOSURE,
+  underwriterLedgerPda,
+  walletKeypair,
+} from "./helpers";
+
+/// `set_test_clock` only exists in `test-clock` builds, so it is missing from the generated types
+type TestClockMethods = {
+  setTestClock(timestamp: BN | null): {
+    accountsPartial(accounts: { masterContract: PublicKey; admin: PublicKey }): { rpc(): Promise<string> };
+  };
+};
+
+describe("underwriting co-signatures and bonds", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  const underwriter = Keypair.generate();
+  const underwriterLedger = underwriterLedgerPda(program, underwriter.publicKey);
+  let treasury: PublicKey;
+
+  // Policies of 1 SOL or more need a co-signer locking 10% of the coverage
+  const bond = new BN(LAMPORTS_PER_SOL / 10);
+  const globalConfig = (
+    cosignCoverageThreshold: ReturnType<typeof solAmount> | null,
+    underwritingBondBps: number
+  ) => ({
+    minPremium: solAmount(new BN(1_000_000)),
+    maxCoverage: solAmount(new BN(1_000 * LAMPORTS_PER_SOL)),
+    maxPolicyDurationDays: 365,
+    lateFeeBps: 0,
+    maxSolExposure: UNCAPPED_EXPOSURE,
+    maxUsdcExposure: UNCAPPED_EXPOSURE,
+    minPayout: solAmount(new BN(0)),
+    priceOracle: PublicKey.default,
+    maxOpenPayouts: DEFAULT_MAX_OPEN_PAYOUTS,
+    cosignCoverageThreshold,
+    underwritingBondBps,
+    bondMaxLossRatioBps: DEFAULT_BOND_MAX_LOSS_RATIO_BPS,
+    outageRefundThresholdBps: DEFAULT_OUTAGE_REFUND_THRESHOLD_BPS,
+  });
+
+  const setUnderwriter = (key: PublicKey) =>
+    program.methods.setUnderwriter(key).accountsPartial({ masterContract, admin }).rpc();
+
+  /// Create a policy for the provider wallet, co-signed by `coSigner` if given
+  const createPolicy = async (coSigner: Keypair | null) => {
+    const { policiesIssued } = await program.account.masterInsuranceContract.fetch(masterContract);
+    const policyAccount = policyPda(program, admin, policiesIssued);
+    await program.methods
+      .createPolicy(defaultPolicyParams(premiumAmount))
+      .accountsPartial({
+        policyHolder: admin,
+        masterContract,
+        policyAccount,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        coSigner: coSigner?.publicKey ?? null,
+        // Only the named underwriter has a ledger to book the coverage in
+        underwriterLedger: coSigner === underwriter ? underwriterLedger : null,
+      })
+      .signers(coSigner ? [coSigner] : [])
+      .rpc();
+    return policyAccount;
+  };
+
+  const release = (policy: PublicKey) =>
+    program.methods
+      .releaseUnderwritingBond()
+      .accountsPartial({ policy, masterContract, treasury, coSigner: underwriter.publicKey, cranker: admin })
+      .rpc();
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: admin, toPubkey: underwriter.publicKey, lamports: LAMPORTS_PER_SOL })
+      )
+    );
+    await setUnderwriter(underwriter.publicKey);
+    await program.methods
+      .openUnderwriterLedger()
+      .accountsPartial({ underwriter: underwriter.publicKey, payer: admin })
+      .rpc();
+    const threshold = solAmount(new BN(LAMPORTS_PER_SOL));
+    await applyParamChange(program, { globalConfig: [globalConfig(threshold, 1_000)] }, null);
+  });
+
+  after(async () => {
+    if (program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      await (program.methods as unknown as TestClockMethods)
+        .setTestClock(null)
+        .accountsPartial({ masterContract, admin })
+        .rpc();
+    }
+    await applyParamChange(program, { globalConfig: [globalConfig(null, 0)] }, null);
+    await setUnderwriter(PublicKey.default);
+  });
+
+  it("refuses large coverage without the underwriter's signature", async () => {
+    await expectError(createPolicy(null), "CoSignatureRequired");
+    // The authority stands in only while no underwriter is named
+    await expectError(createPolicy(walletKeypair(program)), "CoSignatureRequired");
+  });
+
+  it("records the co-signer and locks its bond outside the reserves", async () => {
+    const before = await program.account.treasury.fetch(treasury);
+    const ledgerBefore = await program.account.underwriterLedger.fetch(underwriterLedger);
+    const policy = await createPolicy(underwriter);
+
+    const { coSigner, underwritingBond } = await program.account.policy.fetch(policy);
+    expect(coSigner!.toBase58()).to.equal(underwriter.publicKey.toBase58());
+    expect(underwritingBond.eq(bond)).to.be.true;
+
+    const after = await program.account.treasury.fetch(treasury);
+    expect(after.underwritingBonds.sub(before.underwritingBonds).eq(bond)).to.be.true;
+    expect(after.totalSolBalance.eq(before.totalSolBalance)).to.be.true;
+
+    // The coverage counts towards the underwriter's fee rebate tier for this epoch
+    const placed = (ledger: { epochs: { placedCoverage: BN }[] }) =>
+      ledger.epochs.reduce((total, epoch) => total.add(epoch.placedCoverage), new BN(0));
+    const ledgerAfter = await program.account.underwriterLedger.fetch(underwriterLedger);
+    const { coverageAmount } = defaultPolicyParams(premiumAmount);
+    expect(placed(ledgerAfter).sub(placed(ledgerBefore)).eq(coverageAmount)).to.be.true;
+  });
+
+  it("returns the bond once a policy without claims has expired", async function () {
+    if (!program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      this.skip();
+    }
+    const policy = await createPolicy(underwriter);
+    await expectError(release(policy), "PolicyNotExpired");
+
+    const { endDate } = await program.account.policy.fetch(policy);
+    await (program.methods as unknown as TestClockMethods)
+      .setTestClock(endDate.addn(1))
+      .accountsPartial({ masterContract, admin })
+      .rpc();
+    await program.methods.expirePolicy().accountsPartial({ policy, masterContract, treasury, cranker: admin }).rpc();
+    await expectError(
+      program.methods
+        .closePolicy()
+        .accountsPartial({
+          policy,
+          masterContract,
+          treasury,
+          owner: admin,
+          rentPayer: admin,
+          policyMint: null,
+          ownerTokenAccount: null,
+          policyTokenMetadata: null,
+        })
+        .rpc(),
+      "UnderwritingBondLocked"
+    );
+
+    const balanceBefore = await provider.connection.getBalance(underwriter.publicKey);
+    await release(policy);
+    expect(await provider.connection.getBalance(underwriter.publicKey)).to.equal(balanceBefore + bond.toNumber());
+    expect((await program.account.policy.fetch(policy)).underwritingBond.toNumber()).to.equal(0);
+    await expectError(release(policy), "NoUnderwritingBond");
+  });
+});
@@ -0,0 +1,190 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+  typeConfigPda,
+} from "./helpers";
+
+/// Minimal Borsh reader for the `ViewResponse` layouts
+class Reader {
+  private offset = 0;
+  constructor(private readonly data: Buffer) {}
+
+  u8() {
+    return this.data.readUInt8(this.offset++);
+  }
+  u16() {
+    const value = this.data.readUInt16LE(this.offset);
+    this.offset += 2;
+    return value;
+  }
+  u32() {
+    const value = this.data.readUInt32LE(this.offset);
+    this.offset += 4;
+    return value;
+  }
+  i64() {
+    const value = new BN(this.data.subarray(this.offset, this.offset + 8), "le").fromTwos(64);
+    this.offset += 8;
+    return value;
+  }
+  pubkey() {
+    const value = new PublicKey(this.data.subarray(this.offset, this.offset + 32));
+    this.offset += 32;
+    return value;
+  }
+  option<T>(read: () => T): T | null {
+    return this.u8() === 1 ? read() : null;
+  }
+  done() {
+    expect(this.offset).to.equal(this.data.length);
+  }
+}
+
+const POLICY_STATUSES = ["active", "expired", "cancelled", "pendingPayout", "paidOut", "pendingActivation"];
+const SOLVENCY_STATES = ["healthy", "undercapitalized", "insolvent"];
+const CLAIM_VERDICTS = [
+  "eligible",
+  "contractPaused",
+  "payoutPending",
+  "policyLapsed",
+  "policyExpired",
+  "oracleStale",
+  "circuitBreaker",
+  "oracleUnhealthy",
+  "waitingPeriod",
+  "triggerNotMet",
+  "payoutQueueFull",
+  "treasuryInsolvent",
+  "ineligible",
+];
+
+describe("view instructions", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+  let claimedPolicy: PublicKey;
+
+  /// Simulate a view and return its response payload after checking the version
+  const simulateView = async (tx: Transaction) => {
+    const { returnData } = await provider.simulate(tx);
+    expect(returnData.programId).to.equal(program.programId.toBase58());
+    const reader = new Reader(Buffer.from(returnData.data[0], "base64"));
+    expect(reader.u8()).to.equal(1);
+    return reader;
+  };
+
+  const policyStatus = async (policy: PublicKey, pendingPayout: PublicKey | null = null) => {
+    const reader = await simulateView(
+      await program.methods.getPolicyStatus().accountsPartial({ policy, pendingPayout }).transaction()
+    );
+    const view = {
+      status: POLICY_STATUSES[reader.u8()],
+      daysRemaining: reader.u32(),
+      premiumDueDate: reader.option(() => reader.i64()),
+      pendingPayout: reader.option(() => reader.pubkey()),
+    };
+    reader.done();
+    return view;
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("reports an active policy's term and next premium", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { endDate, lastPremiumPaid } = await program.account.policy.fetch(policy);
+
+    const view = await policyStatus(policy);
+    expect(view.status).to.equal("active");
+    expect(view.daysRemaining).to.equal(
+      Math.floor((endDate.toNumber() - Math.floor(Date.now() / 1000)) / 86400)
+    );
+    expect(view.premiumDueDate.toString()).to.equal(lastPremiumPaid.addn(30 * 86400).toString());
+    expect(view.pendingPayout).to.be.null;
+  });
+
+  it("references the pending payout of a claimed policy", async () => {
+    const policy = await createActivePolicy(program, premiumAmount);
+    const { id, startDate } = await program.account.policy.fetch(policy);
+    await program.methods
+      .triggerPayout(id, new BN(75), new BN(0), null, startDate)
+      .accountsPartial({
+        policy,
+        masterContract,
+        treasury,
+        typeConfig: typeConfigPda(program),
+        beneficiary: admin,
+        policyMint: null,
+        beneficiaryTokenAccount: null,
+        tokenProgram: null,
+        breachWatch: null,
+        consensusSnapshot: null,
+      })
+      .rpc();
+    const [pendingPayout] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payout"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    const view = await policyStatus(policy, pendingPayout);
+    expect(view.status).to.equal("pendingPayout");
+    expect(view.pendingPayout.equals(pendingPayout)).to.be.true;
+    claimedPolicy = policy;
+  });
+
+  it("reports why a claimed policy cannot be claimed again", async () => {
+    const reader = await simulateView(
+      await program.methods
+        .checkClaimEligibility()
+        .accountsPartial({ policy: claimedPolicy, treasury, breachWatch: null })
+        .transaction()
+    );
+    const view = {
+      verdict: CLAIM_VERDICTS[reader.u8()],
+      oracleValue: reader.option(() => reader.i64()),
+      payoutAmount: reader.i64(),
+    };
+    reader.done();
+
+    expect(view.verdict).to.equal("payoutPending");
+    expect(view.oracleValue).to.be.null;
+    expect(view.payoutAmount.isZero()).to.be.true;
+  });
+
+  it("reports treasury health", async () => {
+    const reader = await simulateView(
+      await program.methods.getTreasuryHealth().accountsPartial({ treasury }).transaction()
+    );
+    const view = {
+      reserveRatio: reader.u16(),
+      minimumReserveRatio: reader.u16(),
+      solvency: SOLVENCY_STATES[reader.u8()],
+      availableLiquidity: reader.i64(),
+    };
+    reader.done();
+
+    const account = await program.account.treasury.fetch(treasury);
+    const balance = account.totalUsdcBalance.add(account.totalSolBalance);
+    const exposure = account.totalCoverageExposure;
+    const expectedRatio = exposure.isZero() ? 10_000 : Math.min(balance.muln(10_000).div(exposure).toNumber(), 10_000);
+    expect(view.reserveRatio).to.equal(expectedRatio);
+    expect(view.minimumReserveRatio).to.equal(account.minimumReserveRatio);
+    expect(view.solvency).to.equal(expectedRatio >= account.minimumReserveRatio ? "healthy" : "undercapitalized");
+    expect(view.availableLiquidity.lte(balance)).to.be.true;
+  });
+});
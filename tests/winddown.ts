@@ -0,0 +1,103 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import {
+  applyParamChange,
+  createActivePolicy,
+  ensureMasterContract,
+  ensureTreasury,
+  masterContractPda,
+} from "./helpers";
+
+// A wind-down cannot be undone, so this suite must stay the last to run
+describe("winddown", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  const premiumAmount = new BN(10_000_000);
+  let treasury: PublicKey;
+  let policy: PublicKey;
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      expect.fail(`expected ${code}`);
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+    }
+  };
+
+  const finalSettlement = () =>
+    program.methods
+      .finalSettlement()
+      .accountsPartial({
+        masterContract,
+        treasury,
+        admin,
+        recipient: Keypair.generate().publicKey,
+        treasuryUsdcAccount: null,
+        recipientUsdcAccount: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+    policy = await createActivePolicy(program, premiumAmount);
+  });
+
+  it("refuses a final settlement before the wind-down", async () => {
+    await expectError(finalSettlement(), "WinddownNotStarted");
+  });
+
+  it("stops new sales once the wind-down executes", async () => {
+    await applyParamChange(program, { beginWinddown: {} }, null);
+    expect((await program.account.masterInsuranceContract.fetch(masterContract)).winddown).to.be.true;
+
+    await expectError(createActivePolicy(program, premiumAmount), "ContractInWinddown");
+
+    const { coverageAmount } = await program.account.policy.fetch(policy);
+    await expectError(
+      program.methods
+        .adjustCoverage(coverageAmount.addn(1_000_000), premiumAmount)
+        .accountsPartial({ policyAccount: policy, masterContract, treasury, owner: admin, policyTokenAccount: null })
+        .rpc(),
+      "ContractInWinddown"
+    );
+  });
+
+  it("keeps collecting premiums on existing policies", async () => {
+    const before = await program.account.policy.fetch(policy);
+    await program.methods
+      .payPremium(premiumAmount)
+      .accountsPartial({
+        payer: admin,
+        policyAccount: policy,
+        masterContract,
+        policyTokenAccount: null,
+        subsidy: null,
+        treasury,
+        payerTokenAccount: null,
+        treasuryUsdcAccount: null,
+        feeVault: null,
+        feeVaultUsdcAccount: null,
+        underwriterLedger: null,
+        tokenProgram: null,
+      })
+      .rpc();
+
+    const after = await program.account.policy.fetch(policy);
+    expect(after.totalPremiumsPaid.sub(before.totalPremiumsPaid).toString()).to.equal(premiumAmount.toString());
+  });
+
+  it("holds the final settlement while policies are active", async () => {
+    await expectError(finalSettlement(), "PoliciesStillActive");
+  });
+});
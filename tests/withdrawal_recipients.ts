@@ -0,0 +1,95 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN, AnchorError } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import { SiglabContract } from "../target/types/siglab_contract";
+import { ensureMasterContract, ensureTreasury, masterContractPda } from "./helpers";
+
+const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const MINT_SIZE = 82;
+const ACCOUNT_SIZE = 165;
+
+describe("withdrawal recipients", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.siglabContract as Program<SiglabContract>;
+  const admin = provider.wallet.publicKey;
+  const masterContract = masterContractPda(program);
+  let treasury: PublicKey;
+
+  const expectInvalidRecipient = async (promise: Promise<unknown>) => {
+    try {
+      await promise;
+      expect.fail("withdrawal to an invalid recipient succeeded");
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal("InvalidRecipient");
+    }
+  };
+
+  const withdraw = (recipient: PublicKey, recipientUsdcAccount: PublicKey | null, currency: object) =>
+    program.methods
+      .withdrawFunds(new BN(1), currency as { sol: {} }, { emergencyWithdrawal: {} })
+      .accountsPartial({ treasury, masterContract, admin, recipient, recipientUsdcAccount })
+      .rpc();
+
+  /// A token-program-owned account of `size` bytes, initialized by `data`
+  const createTokenAccount = async (size: number, data: Buffer, keys: PublicKey[]) => {
+    const account = Keypair.generate();
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(size);
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: admin,
+        newAccountPubkey: account.publicKey,
+        lamports,
+        space: size,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      new TransactionInstruction({
+        programId: TOKEN_PROGRAM_ID,
+        keys: [
+          { pubkey: account.publicKey, isSigner: false, isWritable: true },
+          ...keys.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })),
+        ],
+        data,
+      })
+    );
+    await provider.sendAndConfirm(tx, [account]);
+    return account.publicKey;
+  };
+
+  before(async () => {
+    await ensureMasterContract(program);
+    treasury = await ensureTreasury(program);
+  });
+
+  it("rejects withdrawing to the treasury itself", async () => {
+    await expectInvalidRecipient(withdraw(treasury, null, { sol: {} }));
+  });
+
+  it("rejects a SOL recipient owned by another program", async () => {
+    // The master contract PDA belongs to this program, not the system program
+    await expectInvalidRecipient(withdraw(masterContract, null, { sol: {} }));
+  });
+
+  it("requires a token account for USDC withdrawals", async () => {
+    await expectInvalidRecipient(withdraw(admin, null, { usdc: {} }));
+  });
+
+  it("rejects a token account of the wrong mint", async () => {
+    // InitializeMint2 with no freeze authority, then InitializeAccount3 owned by the recipient
+    const mint = await createTokenAccount(
+      MINT_SIZE,
+      Buffer.concat([Buffer.from([20, 6]), admin.toBuffer(), Buffer.from([0]), Buffer.alloc(32)]),
+      []
+    );
+    const tokenAccount = await createTokenAccount(
+      ACCOUNT_SIZE,
+      Buffer.concat([Buffer.from([18]), admin.toBuffer()]),
+      [mint]
+    );
+
+    await expectInvalidRecipient(withdraw(admin, tokenAccount, { usdc: {} }));
+  });
+});